@@ -0,0 +1,23 @@
+#![no_main]
+
+use bytes::BytesMut;
+use ctm::cisco::{codec::CtiCodec, pretty};
+use libfuzzer_sys::fuzz_target;
+use tokio_util::codec::Decoder;
+
+// 임의의 바이트를 코덱과 메시지 역직렬화에 통과시켜 패닉이 발생하지 않는지 검증한다
+//
+// 역직렬화는 firehose 와 ctm-decode 가 사용하는 pretty::decode_message 를 그대로 거치므로
+// 역직렬화를 지원하는 모든 메시지 유형이 대상이 된다.
+fuzz_target!(|data: &[u8]| {
+    let mut codec = CtiCodec::default();
+    let mut src = BytesMut::from(data);
+
+    while let Ok(Some(frame)) = codec.decode(&mut src) {
+        let Ok((message_type, data)) = frame else {
+            continue;
+        };
+
+        let _ = pretty::decode_message(&message_type, &data);
+    }
+});
@@ -0,0 +1,94 @@
+use std::{error::Error, sync::Arc, time::Duration};
+
+use ctm::ctm::{
+    bench::{bench_script, run_bench, BenchConfig, BenchProtocol},
+    config::{LoggingConfig, SimulatorConfig},
+    logging::init_logging,
+    simulator::CtiSimulator,
+};
+use tokio::net::TcpListener;
+
+///
+/// `--name value` 형식의 인자 값
+///
+fn arg_value(args: &[String], name: &str) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == name)
+        .and_then(|index| args.get(index + 1))
+        .cloned()
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let config = SimulatorConfig::load()?;
+    init_logging(None, &LoggingConfig::load()?, None)?;
+
+    // ctm-bench [--protocol tcp|websocket] [--address host:port] [--connections N] [--duration 초]
+    //           [--agents N] [--interval ms] [--team id]...
+    // CTM 이 접속할 CTI 서버 시뮬레이터를 CTI_SIM_PORT 로 함께 실행하고, 시뮬레이터 이벤트가
+    // 클라이언트에 도착하기까지의 지연과 누락률을 측정한다
+    let args = std::env::args().collect::<Vec<_>>();
+
+    let protocol = match arg_value(&args, "--protocol") {
+        Some(protocol) => BenchProtocol::parse(&protocol).ok_or("Unknown bench protocol")?,
+        None => BenchProtocol::TCP,
+    };
+    let address = arg_value(&args, "--address").unwrap_or(match protocol {
+        BenchProtocol::TCP => format!(
+            "127.0.0.1:{}",
+            dotenv::var("TCP_ACCEPTOR_PORT").unwrap_or("5110".to_string())
+        ),
+        BenchProtocol::WEBSOCKET => format!(
+            "127.0.0.1:{}",
+            dotenv::var("WEBSOCKET_ACCEPTOR_PORT").unwrap_or("8085".to_string())
+        ),
+    });
+    let connections = arg_value(&args, "--connections")
+        .and_then(|connections| connections.parse::<usize>().ok())
+        .unwrap_or(100);
+    let duration = arg_value(&args, "--duration")
+        .and_then(|duration| duration.parse::<u64>().ok())
+        .unwrap_or(30);
+    let agent_count = arg_value(&args, "--agents")
+        .and_then(|agent_count| agent_count.parse::<u32>().ok())
+        .unwrap_or(config.agent_count);
+    let event_interval = arg_value(&args, "--interval")
+        .and_then(|event_interval| event_interval.parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(config.event_interval);
+    let team_ids = args
+        .iter()
+        .enumerate()
+        .filter(|(_, arg)| *arg == "--team")
+        .filter_map(|(index, _)| args.get(index + 1)?.parse::<u32>().ok())
+        .collect::<Vec<_>>();
+
+    let sim_port = config.port;
+
+    let simulator = Arc::new(CtiSimulator::new(
+        bench_script(agent_count, event_interval),
+        config.peripheral_id,
+        event_interval,
+    ));
+    let listener = TcpListener::bind(("0.0.0.0", sim_port)).await?;
+    tokio::spawn(simulator.clone().serve(listener));
+    log::info!("Bench simulator listening. port: {}", sim_port);
+
+    let report = run_bench(
+        BenchConfig {
+            protocol,
+            address,
+            websocket_path: dotenv::var("WEBSOCKET_ACCEPTOR_PATH")
+                .unwrap_or("/ctmonitor".to_string()),
+            connections,
+            duration: Duration::from_secs(duration),
+            drain_timeout: Duration::from_secs(5),
+            team_ids,
+        },
+        &simulator,
+    )
+    .await;
+    println!("{}", report);
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use std::{
+    error::Error,
+    io::{self, Read},
+};
+
+use ctm::cisco::pretty;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // ctm-decode [--binary] [<file>]: 파일이 없거나 "-" 이면 표준 입력을 읽는다
+    // 16진수 문자열로 해석할 수 없으면 바이너리로 간주하며, --binary 지정 시 항상 바이너리로 읽는다
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let binary = args.iter().any(|arg| arg == "--binary");
+    let input = args.iter().find(|arg| !arg.starts_with("--"));
+
+    let data = match input.map(String::as_str) {
+        Some("-") | None => {
+            let mut data = vec![];
+            io::stdin().read_to_end(&mut data)?;
+            data
+        }
+        Some(path) => std::fs::read(path)?,
+    };
+
+    let message = match binary {
+        true => data,
+        false => std::str::from_utf8(&data)
+            .ok()
+            .and_then(pretty::parse_hex)
+            .unwrap_or(data),
+    };
+    if message.is_empty() {
+        return Err("No CTI message to decode".into());
+    }
+
+    print!("{}", pretty::format_messages(&message));
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use std::{error::Error, sync::Arc};
+
+use ctm::ctm::{
+    config::{LoggingConfig, SimulatorConfig},
+    logging::init_logging,
+    simulator::CtiSimulator,
+};
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let config = SimulatorConfig::load()?;
+    init_logging(None, &LoggingConfig::load()?, None)?;
+
+    // --script <file>: CTI_SIM_SCRIPT_FILE 대신 지정한 시나리오 파일을 사용한다
+    let args = std::env::args().collect::<Vec<_>>();
+    let script_file = match args.iter().position(|arg| arg == "--script") {
+        Some(index) => Some(
+            args.get(index + 1)
+                .ok_or("Missing scenario file for --script")?
+                .clone(),
+        ),
+        None => None,
+    };
+
+    let port = config.port;
+    let simulator = Arc::new(CtiSimulator::load(script_file, &config)?);
+    let listener = TcpListener::bind(("0.0.0.0", port)).await?;
+    log::info!("CTI simulator listening. port: {}", port);
+
+    simulator.serve(listener).await?;
+
+    Ok(())
+}
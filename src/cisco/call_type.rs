@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Deserializable, Serializable};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Cisco CTI 프로토콜 호 유형
+///
+pub enum CallType {
+    ACD_IN,
+    PREROUTE_ACD_IN,
+    PREROUTE_DIRECT_AGENT,
+    TRANSFER_IN,
+    OVERFLOW_IN,
+    OTHER_IN,
+    AUTO_OUT,
+    AGENT_OUT,
+    OUT,
+    AGENT_INSIDE,
+    OFFERED,
+    CONSULT,
+    CONSULT_OFFERED,
+    CONSULT_CONFERENCE,
+    CONFERENCE,
+    // 정의되지 않은 호 유형은 원래 값을 보존한다
+    UNKNOWN(u16),
+}
+
+impl From<u16> for CallType {
+    fn from(value: u16) -> Self {
+        match value {
+            1 => CallType::ACD_IN,
+            2 => CallType::PREROUTE_ACD_IN,
+            3 => CallType::PREROUTE_DIRECT_AGENT,
+            4 => CallType::TRANSFER_IN,
+            5 => CallType::OVERFLOW_IN,
+            6 => CallType::OTHER_IN,
+            7 => CallType::AUTO_OUT,
+            8 => CallType::AGENT_OUT,
+            9 => CallType::OUT,
+            10 => CallType::AGENT_INSIDE,
+            11 => CallType::OFFERED,
+            12 => CallType::CONSULT,
+            13 => CallType::CONSULT_OFFERED,
+            14 => CallType::CONSULT_CONFERENCE,
+            15 => CallType::CONFERENCE,
+            n => CallType::UNKNOWN(n),
+        }
+    }
+}
+
+impl From<CallType> for u16 {
+    fn from(value: CallType) -> Self {
+        match value {
+            CallType::ACD_IN => 1,
+            CallType::PREROUTE_ACD_IN => 2,
+            CallType::PREROUTE_DIRECT_AGENT => 3,
+            CallType::TRANSFER_IN => 4,
+            CallType::OVERFLOW_IN => 5,
+            CallType::OTHER_IN => 6,
+            CallType::AUTO_OUT => 7,
+            CallType::AGENT_OUT => 8,
+            CallType::OUT => 9,
+            CallType::AGENT_INSIDE => 10,
+            CallType::OFFERED => 11,
+            CallType::CONSULT => 12,
+            CallType::CONSULT_OFFERED => 13,
+            CallType::CONSULT_CONFERENCE => 14,
+            CallType::CONFERENCE => 15,
+            CallType::UNKNOWN(n) => n,
+        }
+    }
+}
+
+impl Serializable for CallType {
+    fn serialize(self) -> Vec<u8> {
+        u16::from(self).serialize()
+    }
+}
+
+impl Deserializable for CallType {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (buffer, result) = <u16 as Deserializable>::deserialize(buffer);
+        (buffer, result.into())
+    }
+}
@@ -1,16 +1,22 @@
-use crate::cisco::{Deserializable, FloatingField, TagValue, MHDR};
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, CtiMessage, DecodeError, Deserializable, Direction, FloatingField,
+    MessageType, MonitorId, PeripheralId, PeripheralType, RepeatedGroups, Serializable,
+    SkillGroupAssignment, TagValue, MHDR,
+};
 
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 ///
 /// Cisco CTI 프로토콜 AGENT_STATE_EVENT 메시지
 ///
 pub struct AgentStateEvent {
     pub mhdr: MHDR,
-    pub monitor_id: u32,
-    pub peripheral_id: u32,
+    pub monitor_id: MonitorId,
+    pub peripheral_id: PeripheralId,
     pub session_id: u32,
-    pub peripheral_type: u16,
+    pub peripheral_type: PeripheralType,
     pub skill_group_state: u16,
     pub state_duration: u32,
     pub skill_group_number: u32,
@@ -33,21 +39,22 @@ pub struct AgentStateEvent {
     pub agent_instrument: Option<FloatingField<String>>,
     pub duration: Option<FloatingField<u32>>,
     pub next_agent_state: Option<FloatingField<u16>>,
-    pub direction: Option<FloatingField<u32>>,
-    pub flt_skill_group_number: Option<FloatingField<i32>>,
-    pub flt_skill_group_id: Option<FloatingField<u32>>,
-    pub flt_skill_group_priority: Option<FloatingField<u16>>,
-    pub flt_skill_group_state: Option<FloatingField<u16>>,
+    pub direction: Option<FloatingField<Direction>>,
+    // 가변 필드로 전달된 스킬그룹 목록 (num_flt_skill_groups 개)
+    pub skill_groups: Vec<SkillGroupAssignment>,
     pub max_beyond_task_limit: Option<FloatingField<u32>>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
 }
 
-impl Deserializable for AgentStateEvent {
-    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+impl CtiMessage for AgentStateEvent {
+    const FIXED_PART_LENGTH: usize = 70;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
         let (mut buffer, mhdr) = MHDR::deserialize(buffer);
-        let (mut buffer, monitor_id) = u32::deserialize(&mut buffer);
-        let (mut buffer, peripheral_id) = u32::deserialize(&mut buffer);
+        let (mut buffer, monitor_id) = MonitorId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_id) = PeripheralId::deserialize(&mut buffer);
         let (mut buffer, session_id) = u32::deserialize(&mut buffer);
-        let (mut buffer, peripheral_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, peripheral_type) = PeripheralType::deserialize(&mut buffer);
         let (mut buffer, skill_group_state) = u16::deserialize(&mut buffer);
         let (mut buffer, state_duration) = u32::deserialize(&mut buffer);
         let (mut buffer, skill_group_number) = u32::deserialize(&mut buffer);
@@ -72,20 +79,19 @@ impl Deserializable for AgentStateEvent {
         #[allow(unused)]
         let mut next_agent_state = None;
         let mut direction = None;
-        let mut flt_skill_group_number = None;
-        let mut flt_skill_group_id = None;
-        let mut flt_skill_group_priority = None;
-        let mut flt_skill_group_state = None;
+        let mut skill_groups =
+            RepeatedGroups::<SkillGroupAssignment>::new(TagValue::SKILL_GROUP_NUMBER_TAG);
         let mut max_beyond_task_limit = None;
+        let mut unknown_fields = vec![];
 
         loop {
             let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
 
             match floating_field {
                 Some(field) if field.length == 0 => buffer = field.data,
-                Some(mut field) => match field.tag {
+                Some(field) => match field.tag {
                     TagValue::CTI_CLIENT_SIGNATURE_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         cti_client_signature = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -94,7 +100,7 @@ impl Deserializable for AgentStateEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::AGENT_ID_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_id = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -103,7 +109,7 @@ impl Deserializable for AgentStateEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::AGENT_EXTENSION_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_extension = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -112,7 +118,7 @@ impl Deserializable for AgentStateEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::ACTIVE_CONN_DEVID_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         active_terminal = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -121,7 +127,7 @@ impl Deserializable for AgentStateEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::AGENT_INSTRUMENT_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_instrument = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -130,7 +136,7 @@ impl Deserializable for AgentStateEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::DURATION_TAG => {
-                        let (sub_buffer, sub_result) = u32::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
                         duration = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -139,7 +145,7 @@ impl Deserializable for AgentStateEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::DIRECTION_TAG => {
-                        let (sub_buffer, sub_result) = u32::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<Direction>()?;
                         direction = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -148,43 +154,27 @@ impl Deserializable for AgentStateEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::SKILL_GROUP_NUMBER_TAG => {
-                        let (sub_buffer, sub_result) = i32::deserialize(&mut field.data);
-                        flt_skill_group_number = Some(FloatingField {
-                            tag: field.tag,
-                            length: field.length,
-                            data: sub_result,
-                        });
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        skill_groups.group(field.tag).skill_group_number = sub_result;
                         buffer = sub_buffer;
                     }
                     TagValue::SKILL_GROUP_ID_TAG => {
-                        let (sub_buffer, sub_result) = u32::deserialize(&mut field.data);
-                        flt_skill_group_id = Some(FloatingField {
-                            tag: field.tag,
-                            length: field.length,
-                            data: sub_result,
-                        });
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        skill_groups.group(field.tag).skill_group_id = sub_result;
                         buffer = sub_buffer;
                     }
                     TagValue::SKILL_GROUP_PRIORITY_TAG => {
-                        let (sub_buffer, sub_result) = u16::deserialize(&mut field.data);
-                        flt_skill_group_priority = Some(FloatingField {
-                            tag: field.tag,
-                            length: field.length,
-                            data: sub_result,
-                        });
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
+                        skill_groups.group(field.tag).skill_group_priority = sub_result;
                         buffer = sub_buffer;
                     }
                     TagValue::SKILL_GROUP_STATE_TAG => {
-                        let (sub_buffer, sub_result) = u16::deserialize(&mut field.data);
-                        flt_skill_group_state = Some(FloatingField {
-                            tag: field.tag,
-                            length: field.length,
-                            data: sub_result,
-                        });
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
+                        skill_groups.group(field.tag).skill_group_state = sub_result;
                         buffer = sub_buffer;
                     }
                     TagValue::MAX_BEYOND_TASK_LIMIT_TAG => {
-                        let (sub_buffer, sub_result) = u32::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
                         max_beyond_task_limit = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -193,50 +183,94 @@ impl Deserializable for AgentStateEvent {
                         buffer = sub_buffer;
                     }
                     _ => {
-                        buffer = field.data[field.length as usize..].to_vec();
+                        buffer = preserve_unknown_field(&mut unknown_fields, &field);
                     }
                 },
                 None => break,
             };
         }
 
-        (
-            buffer,
-            Self {
-                mhdr,
-                monitor_id,
-                peripheral_id,
-                session_id,
-                peripheral_type,
-                skill_group_state,
-                state_duration,
-                skill_group_number,
-                skill_group_id,
-                skill_group_priority,
-                agent_state,
-                event_reason_code,
-                mrd_id,
-                num_tasks,
-                agent_mode,
-                max_task_limit,
-                icm_agent_id,
-                agent_availability_status,
-                num_flt_skill_groups,
-                department_id,
-                cti_client_signature,
-                agent_id,
-                agent_extension,
-                active_terminal,
-                agent_instrument,
-                duration,
-                next_agent_state,
-                direction,
-                flt_skill_group_number,
-                flt_skill_group_id,
-                flt_skill_group_priority,
-                flt_skill_group_state,
-                max_beyond_task_limit,
-            },
-        )
+        Ok(Self {
+            mhdr,
+            monitor_id,
+            peripheral_id,
+            session_id,
+            peripheral_type,
+            skill_group_state,
+            state_duration,
+            skill_group_number,
+            skill_group_id,
+            skill_group_priority,
+            agent_state,
+            event_reason_code,
+            mrd_id,
+            num_tasks,
+            agent_mode,
+            max_task_limit,
+            icm_agent_id,
+            agent_availability_status,
+            num_flt_skill_groups,
+            department_id,
+            cti_client_signature,
+            agent_id,
+            agent_extension,
+            active_terminal,
+            agent_instrument,
+            duration,
+            next_agent_state,
+            direction,
+            skill_groups: skill_groups.into_vec(),
+            max_beyond_task_limit,
+            unknown_fields,
+        })
+    }
+}
+
+impl Serializable for AgentStateEvent {
+    fn serialize(self) -> Vec<u8> {
+        let mut buffer = self.monitor_id.serialize();
+        buffer.append(&mut self.peripheral_id.serialize());
+        buffer.append(&mut self.session_id.serialize());
+        buffer.append(&mut self.peripheral_type.serialize());
+        buffer.append(&mut self.skill_group_state.serialize());
+        buffer.append(&mut self.state_duration.serialize());
+        buffer.append(&mut self.skill_group_number.serialize());
+        buffer.append(&mut self.skill_group_id.serialize());
+        buffer.append(&mut self.skill_group_priority.serialize());
+        buffer.append(&mut self.agent_state.serialize());
+        buffer.append(&mut self.event_reason_code.serialize());
+        buffer.append(&mut self.mrd_id.serialize());
+        buffer.append(&mut self.num_tasks.serialize());
+        buffer.append(&mut self.agent_mode.serialize());
+        buffer.append(&mut self.max_task_limit.serialize());
+        buffer.append(&mut self.icm_agent_id.serialize());
+        buffer.append(&mut self.agent_availability_status.serialize());
+        buffer.append(&mut self.num_flt_skill_groups.serialize());
+        buffer.append(&mut self.department_id.serialize());
+        buffer.append(&mut self.cti_client_signature.serialize());
+        buffer.append(&mut self.agent_id.serialize());
+        buffer.append(&mut self.agent_extension.serialize());
+        buffer.append(&mut self.active_terminal.serialize());
+        buffer.append(&mut self.agent_instrument.serialize());
+        buffer.append(&mut self.duration.serialize());
+        buffer.append(&mut self.next_agent_state.serialize());
+        buffer.append(&mut self.direction.serialize());
+        for skill_group in self.skill_groups {
+            buffer.append(&mut skill_group.serialize());
+        }
+        buffer.append(&mut self.max_beyond_task_limit.serialize());
+        for field in self.unknown_fields {
+            buffer.append(&mut field.serialize());
+        }
+
+        let mhdr = MHDR {
+            length: buffer.len() as u32,
+            message_type: MessageType::AGENT_STATE_EVENT,
+        };
+
+        let mut result = mhdr.serialize();
+        result.append(&mut buffer);
+
+        result
     }
 }
@@ -0,0 +1,170 @@
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, CallType, ConnectionCallId, CtiMessage, DecodeError, Deserializable,
+    FloatingField, MonitorId, PeripheralId, PeripheralType, TagValue, MHDR,
+};
+
+#[allow(unused)]
+#[derive(Debug, Serialize)]
+///
+/// Cisco CTI 프로토콜 BEGIN_CALL_EVENT 메시지
+///
+pub struct BeginCallEvent {
+    pub mhdr: MHDR,
+    pub monitor_id: MonitorId,
+    pub peripheral_id: PeripheralId,
+    pub peripheral_type: PeripheralType,
+    pub num_cti_clients: u16,
+    pub num_named_variables: u16,
+    pub num_named_arrays: u16,
+    pub call_type: CallType,
+    pub connection_device_id_type: u16,
+    pub connection_call_id: ConnectionCallId,
+    pub called_party_disposition: u16,
+    pub connection_device_id: Option<FloatingField<String>>,
+    pub ani: Option<FloatingField<String>>,
+    pub dnis: Option<FloatingField<String>>,
+    pub dialed_number: Option<FloatingField<String>>,
+    pub caller_entered_digits: Option<FloatingField<String>>,
+    pub router_call_key_day: Option<FloatingField<u32>>,
+    pub router_call_key_call_id: Option<FloatingField<u32>>,
+    pub router_call_key_sequence_number: Option<FloatingField<u32>>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
+}
+
+impl CtiMessage for BeginCallEvent {
+    const FIXED_PART_LENGTH: usize = 34;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let (mut buffer, mhdr) = MHDR::deserialize(buffer);
+        let (mut buffer, monitor_id) = MonitorId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_id) = PeripheralId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_type) = PeripheralType::deserialize(&mut buffer);
+        let (mut buffer, num_cti_clients) = u16::deserialize(&mut buffer);
+        let (mut buffer, num_named_variables) = u16::deserialize(&mut buffer);
+        let (mut buffer, num_named_arrays) = u16::deserialize(&mut buffer);
+        let (mut buffer, call_type) = CallType::deserialize(&mut buffer);
+        let (mut buffer, connection_device_id_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, connection_call_id) = ConnectionCallId::deserialize(&mut buffer);
+        let (mut buffer, called_party_disposition) = u16::deserialize(&mut buffer);
+        let mut connection_device_id = None;
+        let mut ani = None;
+        let mut dnis = None;
+        let mut dialed_number = None;
+        let mut caller_entered_digits = None;
+        let mut router_call_key_day = None;
+        let mut router_call_key_call_id = None;
+        let mut router_call_key_sequence_number = None;
+        let mut unknown_fields = vec![];
+
+        loop {
+            let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
+
+            match floating_field {
+                Some(field) if field.length == 0 => buffer = field.data,
+                Some(field) => match field.tag {
+                    TagValue::CONNECTION_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        connection_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::ANI_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        ani = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::DNIS_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        dnis = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::DIALED_NUMBER_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        dialed_number = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::CED_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        caller_entered_digits = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::ROUTER_CALL_KEY_DAY_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        router_call_key_day = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::ROUTER_CALL_KEY_CALLID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        router_call_key_call_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::ROUTER_CALL_KEY_SEQUENCE_NUM_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        router_call_key_sequence_number = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    _ => {
+                        buffer = preserve_unknown_field(&mut unknown_fields, &field);
+                    }
+                },
+                None => break,
+            };
+        }
+
+        Ok(Self {
+            mhdr,
+            monitor_id,
+            peripheral_id,
+            peripheral_type,
+            num_cti_clients,
+            num_named_variables,
+            num_named_arrays,
+            call_type,
+            connection_device_id_type,
+            connection_call_id,
+            called_party_disposition,
+            connection_device_id,
+            ani,
+            dnis,
+            dialed_number,
+            caller_entered_digits,
+            router_call_key_day,
+            router_call_key_call_id,
+            router_call_key_sequence_number,
+            unknown_fields,
+        })
+    }
+}
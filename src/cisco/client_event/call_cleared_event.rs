@@ -0,0 +1,77 @@
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, ConnectionCallId, CtiMessage, DecodeError, Deserializable,
+    FloatingField, MonitorId, PeripheralId, PeripheralType, TagValue, MHDR,
+};
+
+#[allow(unused)]
+#[derive(Debug, Serialize)]
+///
+/// Cisco CTI 프로토콜 CALL_CLEARED_EVENT 메시지
+///
+pub struct CallClearedEvent {
+    pub mhdr: MHDR,
+    pub monitor_id: MonitorId,
+    pub peripheral_id: PeripheralId,
+    pub peripheral_type: PeripheralType,
+    pub connection_device_id_type: u16,
+    pub connection_call_id: ConnectionCallId,
+    pub local_connection_state: u16,
+    pub event_cause: u16,
+    pub connection_device_id: Option<FloatingField<String>>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
+}
+
+impl CtiMessage for CallClearedEvent {
+    const FIXED_PART_LENGTH: usize = 28;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let (mut buffer, mhdr) = MHDR::deserialize(buffer);
+        let (mut buffer, monitor_id) = MonitorId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_id) = PeripheralId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_type) = PeripheralType::deserialize(&mut buffer);
+        let (mut buffer, connection_device_id_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, connection_call_id) = ConnectionCallId::deserialize(&mut buffer);
+        let (mut buffer, local_connection_state) = u16::deserialize(&mut buffer);
+        let (mut buffer, event_cause) = u16::deserialize(&mut buffer);
+        let mut connection_device_id = None;
+        let mut unknown_fields = vec![];
+
+        loop {
+            let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
+
+            match floating_field {
+                Some(field) if field.length == 0 => buffer = field.data,
+                Some(field) => match field.tag {
+                    TagValue::CONNECTION_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        connection_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    _ => {
+                        buffer = preserve_unknown_field(&mut unknown_fields, &field);
+                    }
+                },
+                None => break,
+            };
+        }
+
+        Ok(Self {
+            mhdr,
+            monitor_id,
+            peripheral_id,
+            peripheral_type,
+            connection_device_id_type,
+            connection_call_id,
+            local_connection_state,
+            event_cause,
+            connection_device_id,
+            unknown_fields,
+        })
+    }
+}
@@ -0,0 +1,182 @@
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, ConnectionCallId, CtiMessage, DecodeError, Deserializable,
+    FloatingField, MonitorId, PeripheralId, PeripheralType, TagValue, MHDR,
+};
+
+#[allow(unused)]
+#[derive(Debug, Serialize)]
+///
+/// Cisco CTI 프로토콜 CALL_ESTABLISHED_EVENT 메시지
+///
+pub struct CallEstablishedEvent {
+    pub mhdr: MHDR,
+    pub monitor_id: MonitorId,
+    pub peripheral_id: PeripheralId,
+    pub peripheral_type: PeripheralType,
+    pub connection_device_id_type: u16,
+    pub connection_call_id: ConnectionCallId,
+    pub line_handle: u16,
+    pub line_type: u16,
+    pub service_number: u32,
+    pub service_id: u32,
+    pub skill_group_number: u32,
+    pub skill_group_id: u32,
+    pub skill_group_priority: u16,
+    pub answering_device_type: u16,
+    pub calling_device_type: u16,
+    pub called_device_type: u16,
+    pub last_redirect_device_type: u16,
+    pub local_connection_state: u16,
+    pub event_cause: u16,
+    pub connection_device_id: Option<FloatingField<String>>,
+    pub answering_device_id: Option<FloatingField<String>>,
+    pub calling_device_id: Option<FloatingField<String>>,
+    pub called_device_id: Option<FloatingField<String>>,
+    pub last_redirect_device_id: Option<FloatingField<String>>,
+    pub trunk_number: Option<FloatingField<u32>>,
+    pub trunk_group_number: Option<FloatingField<u32>>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
+}
+
+impl CtiMessage for CallEstablishedEvent {
+    const FIXED_PART_LENGTH: usize = 58;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let (mut buffer, mhdr) = MHDR::deserialize(buffer);
+        let (mut buffer, monitor_id) = MonitorId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_id) = PeripheralId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_type) = PeripheralType::deserialize(&mut buffer);
+        let (mut buffer, connection_device_id_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, connection_call_id) = ConnectionCallId::deserialize(&mut buffer);
+        let (mut buffer, line_handle) = u16::deserialize(&mut buffer);
+        let (mut buffer, line_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, service_number) = u32::deserialize(&mut buffer);
+        let (mut buffer, service_id) = u32::deserialize(&mut buffer);
+        let (mut buffer, skill_group_number) = u32::deserialize(&mut buffer);
+        let (mut buffer, skill_group_id) = u32::deserialize(&mut buffer);
+        let (mut buffer, skill_group_priority) = u16::deserialize(&mut buffer);
+        let (mut buffer, answering_device_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, calling_device_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, called_device_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, last_redirect_device_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, local_connection_state) = u16::deserialize(&mut buffer);
+        let (mut buffer, event_cause) = u16::deserialize(&mut buffer);
+        let mut connection_device_id = None;
+        let mut answering_device_id = None;
+        let mut calling_device_id = None;
+        let mut called_device_id = None;
+        let mut last_redirect_device_id = None;
+        let mut trunk_number = None;
+        let mut trunk_group_number = None;
+        let mut unknown_fields = vec![];
+
+        loop {
+            let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
+
+            match floating_field {
+                Some(field) if field.length == 0 => buffer = field.data,
+                Some(field) => match field.tag {
+                    TagValue::CONNECTION_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        connection_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::ANSWERING_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        answering_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::CALLING_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        calling_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::CALLED_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        called_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::LAST_REDIRECT_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        last_redirect_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::TRUNK_NUMBER_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        trunk_number = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::TRUNK_GROUP_NUMBER_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        trunk_group_number = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    _ => {
+                        buffer = preserve_unknown_field(&mut unknown_fields, &field);
+                    }
+                },
+                None => break,
+            };
+        }
+
+        Ok(Self {
+            mhdr,
+            monitor_id,
+            peripheral_id,
+            peripheral_type,
+            connection_device_id_type,
+            connection_call_id,
+            line_handle,
+            line_type,
+            service_number,
+            service_id,
+            skill_group_number,
+            skill_group_id,
+            skill_group_priority,
+            answering_device_type,
+            calling_device_type,
+            called_device_type,
+            last_redirect_device_type,
+            local_connection_state,
+            event_cause,
+            connection_device_id,
+            answering_device_id,
+            calling_device_id,
+            called_device_id,
+            last_redirect_device_id,
+            trunk_number,
+            trunk_group_number,
+            unknown_fields,
+        })
+    }
+}
@@ -0,0 +1,186 @@
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, ConnectionCallId, CtiMessage, DecodeError, Deserializable,
+    FloatingField, MonitorId, PeripheralId, PeripheralType, TagValue, MHDR,
+};
+
+#[allow(unused)]
+#[derive(Debug, Serialize)]
+///
+/// Cisco CTI 프로토콜 CALL_QUEUED_EVENT 메시지
+///
+pub struct CallQueuedEvent {
+    pub mhdr: MHDR,
+    pub monitor_id: MonitorId,
+    pub peripheral_id: PeripheralId,
+    pub peripheral_type: PeripheralType,
+    pub connection_device_id_type: u16,
+    pub connection_call_id: ConnectionCallId,
+    pub service_number: u32,
+    pub service_id: u32,
+    pub queue_device_type: u16,
+    pub calling_device_type: u16,
+    pub called_device_type: u16,
+    pub last_redirect_device_type: u16,
+    pub num_queued: u16,
+    pub num_skill_groups: u16,
+    pub local_connection_state: u16,
+    pub event_cause: u16,
+    pub connection_device_id: Option<FloatingField<String>>,
+    pub queue_device_id: Option<FloatingField<String>>,
+    pub calling_device_id: Option<FloatingField<String>>,
+    pub called_device_id: Option<FloatingField<String>>,
+    pub last_redirect_device_id: Option<FloatingField<String>>,
+    pub skill_group_numbers: Vec<FloatingField<u32>>,
+    pub skill_group_ids: Vec<FloatingField<u32>>,
+    pub skill_group_priorities: Vec<FloatingField<u16>>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
+}
+
+impl CtiMessage for CallQueuedEvent {
+    const FIXED_PART_LENGTH: usize = 48;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let (mut buffer, mhdr) = MHDR::deserialize(buffer);
+        let (mut buffer, monitor_id) = MonitorId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_id) = PeripheralId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_type) = PeripheralType::deserialize(&mut buffer);
+        let (mut buffer, connection_device_id_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, connection_call_id) = ConnectionCallId::deserialize(&mut buffer);
+        let (mut buffer, service_number) = u32::deserialize(&mut buffer);
+        let (mut buffer, service_id) = u32::deserialize(&mut buffer);
+        let (mut buffer, queue_device_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, calling_device_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, called_device_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, last_redirect_device_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, num_queued) = u16::deserialize(&mut buffer);
+        let (mut buffer, num_skill_groups) = u16::deserialize(&mut buffer);
+        let (mut buffer, local_connection_state) = u16::deserialize(&mut buffer);
+        let (mut buffer, event_cause) = u16::deserialize(&mut buffer);
+        let mut connection_device_id = None;
+        let mut queue_device_id = None;
+        let mut calling_device_id = None;
+        let mut called_device_id = None;
+        let mut last_redirect_device_id = None;
+        let mut skill_group_numbers = vec![];
+        let mut skill_group_ids = vec![];
+        let mut skill_group_priorities = vec![];
+        let mut unknown_fields = vec![];
+
+        loop {
+            let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
+
+            match floating_field {
+                Some(field) if field.length == 0 => buffer = field.data,
+                Some(field) => match field.tag {
+                    TagValue::CONNECTION_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        connection_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::QUEUE_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        queue_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::CALLING_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        calling_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::CALLED_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        called_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::LAST_REDIRECT_DEVID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        last_redirect_device_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    // 스킬그룹 필드는 NumSkillGroups 만큼 반복된다
+                    TagValue::SKILL_GROUP_NUMBER_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        skill_group_numbers.push(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::SKILL_GROUP_ID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        skill_group_ids.push(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::SKILL_GROUP_PRIORITY_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
+                        skill_group_priorities.push(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    _ => {
+                        buffer = preserve_unknown_field(&mut unknown_fields, &field);
+                    }
+                },
+                None => break,
+            };
+        }
+
+        Ok(Self {
+            mhdr,
+            monitor_id,
+            peripheral_id,
+            peripheral_type,
+            connection_device_id_type,
+            connection_call_id,
+            service_number,
+            service_id,
+            queue_device_type,
+            calling_device_type,
+            called_device_type,
+            last_redirect_device_type,
+            num_queued,
+            num_skill_groups,
+            local_connection_state,
+            event_cause,
+            connection_device_id,
+            queue_device_id,
+            calling_device_id,
+            called_device_id,
+            last_redirect_device_id,
+            skill_group_numbers,
+            skill_group_ids,
+            skill_group_priorities,
+            unknown_fields,
+        })
+    }
+}
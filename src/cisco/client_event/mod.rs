@@ -1 +1,5 @@
-pub mod agent_state_event;
\ No newline at end of file
+pub mod agent_state_event;
+pub mod begin_call_event;
+pub mod call_cleared_event;
+pub mod call_established_event;
+pub mod call_queued_event;
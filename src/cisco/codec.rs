@@ -0,0 +1,176 @@
+use std::io;
+
+use bytes::BytesMut;
+use encoding_rs::{Encoding, EUC_KR, UTF_8};
+use tokio_util::codec::{Decoder, Encoder};
+
+use super::{
+    deserializable::with_string_encoding, CtiMessage, DecodeError, Deserializable, MessageType,
+    Serializable, MHDR,
+};
+
+///
+/// 메시지 헤더 길이 (length + message_type)
+///
+pub const MHDR_LENGTH: usize = 8;
+
+///
+/// 메시지 헤더를 제외한 최대 메시지 길이
+///
+pub const MAX_MESSAGE_LENGTH: usize = 65_536;
+
+///
+/// 네트워크 바이트 순서(빅 엔디언) 숫자 변환
+///
+/// CTI 프로토콜의 숫자 필드는 모두 네트워크 바이트 순서로 전송된다.
+/// 숫자 타입의 직렬화/역직렬화는 이 트레잇으로만 바이트 순서를 다루며,
+/// 새 숫자 타입은 impl_network_byte_order! 에 추가한다.
+///
+pub trait NetworkByteOrder: Sized {
+    ///
+    /// 직렬화된 바이트 길이
+    ///
+    const LENGTH: usize;
+
+    ///
+    /// 버퍼 앞부분의 LENGTH 바이트를 숫자로 변환한다
+    ///
+    fn from_network_bytes(bytes: &[u8]) -> Self;
+
+    ///
+    /// 숫자를 네트워크 바이트 순서로 변환한다
+    ///
+    fn to_network_bytes(self) -> Vec<u8>;
+}
+
+macro_rules! impl_network_byte_order {
+    ($($t:ty),*) => {
+        $(
+            impl NetworkByteOrder for $t {
+                const LENGTH: usize = std::mem::size_of::<$t>();
+
+                fn from_network_bytes(bytes: &[u8]) -> Self {
+                    let mut result = [0_u8; std::mem::size_of::<$t>()];
+                    result.copy_from_slice(&bytes[..Self::LENGTH]);
+
+                    <$t>::from_be_bytes(result)
+                }
+
+                fn to_network_bytes(self) -> Vec<u8> {
+                    self.to_be_bytes().to_vec()
+                }
+            }
+        )*
+    };
+}
+
+impl_network_byte_order!(u8, i8, i16, u16, i32, u32, i64, u64);
+
+///
+/// 문자열 인코딩 이름으로 인코딩을 찾는다
+///
+/// WHATWG 인코딩 이름 외에 CP949, MS949 는 EUC-KR 로 처리한다.
+///
+pub fn encoding_for_label(label: &str) -> Option<&'static Encoding> {
+    match label.trim().to_ascii_lowercase().as_str() {
+        "cp949" | "ms949" => Some(EUC_KR),
+        label => Encoding::for_label(label.as_bytes()),
+    }
+}
+
+///
+/// Cisco CTI 프로토콜 메시지 프레이밍 코덱
+///
+/// MHDR 의 길이 필드를 기준으로 메시지를 분리하며, 부분 수신된 메시지는
+/// 나머지 데이터가 수신될 때까지 버퍼에 보관한다.
+/// 헤더 길이가 비정상인 경우 수신 버퍼를 비워 스트림을 재동기화하고 오류를 반환한다.
+///
+/// PG 에서 전달되는 문자열이 레거시 코드페이지(EUC-KR/CP949)인 경우가 있어
+/// 메시지 문자열은 코덱에 지정된 인코딩으로 변환한다. (기본값: UTF-8)
+///
+#[derive(Debug, Clone, Copy)]
+pub struct CtiCodec {
+    string_encoding: &'static Encoding,
+}
+
+impl Default for CtiCodec {
+    fn default() -> Self {
+        Self::new(UTF_8)
+    }
+}
+
+impl CtiCodec {
+    pub fn new(string_encoding: &'static Encoding) -> Self {
+        Self { string_encoding }
+    }
+
+    pub fn get_string_encoding(&self) -> &'static Encoding {
+        self.string_encoding
+    }
+
+    ///
+    /// 수신한 메시지 프레임을 코덱의 문자열 인코딩으로 역직렬화한다
+    ///
+    pub fn deserialize<M: CtiMessage>(&self, data: &mut Vec<u8>) -> Result<M, DecodeError> {
+        self.with_string_encoding(|| M::try_deserialize(data))
+    }
+
+    ///
+    /// f 를 실행하는 동안 코덱의 문자열 인코딩으로 메시지 문자열을 변환한다
+    ///
+    pub fn with_string_encoding<R>(&self, f: impl FnOnce() -> R) -> R {
+        with_string_encoding(self.string_encoding, f)
+    }
+}
+
+impl Decoder for CtiCodec {
+    type Item = Result<(MessageType, Vec<u8>), DecodeError>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        // 메시지 헤더가 모두 수신되지 않은 경우
+        if src.len() < MHDR_LENGTH {
+            return Ok(None);
+        }
+
+        // 헤더는 고정 길이 배열로 복사해 메시지마다 할당하지 않는다
+        let mut header = [0_u8; MHDR_LENGTH];
+        header.copy_from_slice(&src[..MHDR_LENGTH]);
+        let (_, mhdr) = MHDR::deserialize(&mut header);
+        let length = mhdr.length as usize;
+
+        // 최대 길이를 넘는 헤더는 손상된 데이터로 보고 버퍼를 비운다
+        if length > MAX_MESSAGE_LENGTH {
+            src.clear();
+            return Ok(Some(Err(DecodeError::MessageTooLong {
+                length,
+                max_length: MAX_MESSAGE_LENGTH,
+            })));
+        }
+
+        let frame_length = MHDR_LENGTH + length;
+
+        // 메시지 본문이 모두 수신되지 않은 경우 남은 길이만큼 버퍼를 예약한다
+        if src.len() < frame_length {
+            src.reserve(frame_length - src.len());
+            return Ok(None);
+        }
+
+        let data = src.split_to(frame_length).to_vec();
+
+        Ok(Some(Ok((mhdr.message_type, data))))
+    }
+}
+
+impl<T> Encoder<T> for CtiCodec
+where
+    T: Serializable,
+{
+    type Error = io::Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        dst.extend_from_slice(&self.with_string_encoding(|| item.serialize()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, CtiMessage, DecodeError, Deserializable, FloatingField, RepeatedGroups,
+    TagValue, MHDR,
+};
+
+#[allow(unused)]
+#[derive(Debug, Serialize)]
+///
+/// Cisco CTI 프로토콜 CONFIG_AGENT_EVENT 메시지
+///
+pub struct ConfigAgentEvent {
+    pub mhdr: MHDR,
+    pub num_records: u16,
+    pub records: Vec<ConfigAgentEventRecord>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
+}
+
+#[allow(unused)]
+#[derive(Debug, Default, Serialize)]
+///
+/// CONFIG_AGENT_EVENT의 상담직원 레코드 구조체
+///
+/// 레코드는 RECORD_TYPE_TAG 로 시작한다.
+///
+pub struct ConfigAgentEventRecord {
+    pub record_type: Option<FloatingField<u16>>,
+    pub agent_config_key: Option<FloatingField<u32>>,
+    pub login_id: Option<FloatingField<String>>,
+    pub first_name: Option<FloatingField<String>>,
+    pub last_name: Option<FloatingField<String>>,
+    pub login_name: Option<FloatingField<String>>,
+}
+
+impl ConfigAgentEventRecord {
+    ///
+    /// 이름과 성을 합친 상담직원 표시 이름
+    ///
+    pub fn display_name(&self) -> String {
+        let first_name = self.first_name.as_ref().map_or("", |field| &field.data);
+        let last_name = self.last_name.as_ref().map_or("", |field| &field.data);
+
+        format!("{} {}", first_name, last_name).trim().to_string()
+    }
+}
+
+impl CtiMessage for ConfigAgentEvent {
+    const FIXED_PART_LENGTH: usize = 10;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let (mut buffer, mhdr) = MHDR::deserialize(buffer);
+        let (mut buffer, num_records) = u16::deserialize(&mut buffer);
+
+        let mut records = RepeatedGroups::<ConfigAgentEventRecord>::new(TagValue::RECORD_TYPE_TAG);
+        let mut unknown_fields = vec![];
+
+        loop {
+            let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
+            match floating_field {
+                Some(field) if field.length == 0 => {
+                    buffer = field.data;
+                    continue;
+                }
+                Some(field) => match field.tag {
+                    TagValue::RECORD_TYPE_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
+                        records.group(field.tag).record_type = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::AGENT_CONFIG_KEY_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        records.group(field.tag).agent_config_key = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::LOGIN_ID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        records.group(field.tag).login_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::FIRST_NAME_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        records.group(field.tag).first_name = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::LAST_NAME_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        records.group(field.tag).last_name = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::LOGIN_NAME_TAG_V11 => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        records.group(field.tag).login_name = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    _ => {
+                        buffer = preserve_unknown_field(&mut unknown_fields, &field);
+                    }
+                },
+                None => {
+                    break;
+                }
+            }
+        }
+
+        Ok(Self {
+            mhdr,
+            num_records,
+            records: records.into_vec(),
+            unknown_fields,
+        })
+    }
+}
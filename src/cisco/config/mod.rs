@@ -0,0 +1 @@
+pub mod config_agent_event;
@@ -1,13 +1,18 @@
-use crate::cisco::{Deserializable, FloatingField, TagValue, MHDR};
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, CtiMessage, DecodeError, Deserializable, FloatingField, InvokeId,
+    MessageType, RepeatedGroups, Serializable, SkillGroupAssignment, TagValue, MHDR,
+};
 
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 ///
 /// Cisco CTI 프로토콜 QUERY_AGENT_STATE_CONF 메시지
 ///
 pub struct QueryAgentStateConf {
     pub mhdr: MHDR,
-    pub invoke_id: u32,
+    pub invoke_id: InvokeId,
     pub agent_state: u16,
     pub num_skill_groups: u16,
     pub mrd_id: i32,
@@ -20,18 +25,19 @@ pub struct QueryAgentStateConf {
     pub agent_id: Option<FloatingField<String>>,
     pub agent_extension: Option<FloatingField<String>>,
     pub agent_instrument: Option<FloatingField<String>>,
-    pub skill_group_number: Option<FloatingField<u32>>,
-    pub skill_group_id: Option<FloatingField<u32>>,
-    pub skill_group_priority: Option<FloatingField<u16>>,
-    pub skill_group_state: Option<FloatingField<u16>>,
+    // 가변 필드로 전달된 스킬그룹 목록 (num_skill_groups 개)
+    pub skill_groups: Vec<SkillGroupAssignment>,
     pub internal_agent_state: Option<FloatingField<u16>>,
     pub max_beyond_task_limit: Option<FloatingField<u32>>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
 }
 
-impl Deserializable for QueryAgentStateConf {
-    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+impl CtiMessage for QueryAgentStateConf {
+    const FIXED_PART_LENGTH: usize = 42;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
         let (mut buffer, mhdr) = MHDR::deserialize(buffer);
-        let (mut buffer, invoke_id) = u32::deserialize(&mut buffer);
+        let (mut buffer, invoke_id) = InvokeId::deserialize(&mut buffer);
         let (mut buffer, agent_state) = u16::deserialize(&mut buffer);
         let (mut buffer, num_skill_groups) = u16::deserialize(&mut buffer);
         let (mut buffer, mrd_id) = i32::deserialize(&mut buffer);
@@ -44,12 +50,11 @@ impl Deserializable for QueryAgentStateConf {
         let mut agent_id = None;
         let mut agent_extension = None;
         let mut agent_instrument = None;
-        let mut skill_group_number = None;
-        let mut skill_group_id = None;
-        let mut skill_group_priority = None;
-        let mut skill_group_state = None;
+        let mut skill_groups =
+            RepeatedGroups::<SkillGroupAssignment>::new(TagValue::SKILL_GROUP_NUMBER_TAG);
         let mut internal_agent_state = None;
         let mut max_beyond_task_limit = None;
+        let mut unknown_fields = vec![];
 
         loop {
             let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
@@ -59,9 +64,9 @@ impl Deserializable for QueryAgentStateConf {
                     buffer = field.data;
                     continue;
                 }
-                Some(mut field) => match field.tag {
+                Some(field) => match field.tag {
                     TagValue::AGENT_ID_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_id = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -70,7 +75,7 @@ impl Deserializable for QueryAgentStateConf {
                         buffer = sub_buffer;
                     }
                     TagValue::AGENT_EXTENSION_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_extension = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -79,7 +84,7 @@ impl Deserializable for QueryAgentStateConf {
                         buffer = sub_buffer;
                     }
                     TagValue::AGENT_INSTRUMENT_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_instrument = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -88,43 +93,27 @@ impl Deserializable for QueryAgentStateConf {
                         buffer = sub_buffer;
                     }
                     TagValue::SKILL_GROUP_NUMBER_TAG => {
-                        let (sub_buffer, sub_result) = u32::deserialize(&mut field.data);
-                        skill_group_number = Some(FloatingField {
-                            tag: field.tag,
-                            length: field.length,
-                            data: sub_result,
-                        });
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        skill_groups.group(field.tag).skill_group_number = sub_result;
                         buffer = sub_buffer;
                     }
                     TagValue::SKILL_GROUP_ID_TAG => {
-                        let (sub_buffer, sub_result) = u32::deserialize(&mut field.data);
-                        skill_group_id = Some(FloatingField {
-                            tag: field.tag,
-                            length: field.length,
-                            data: sub_result,
-                        });
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        skill_groups.group(field.tag).skill_group_id = sub_result;
                         buffer = sub_buffer;
                     }
                     TagValue::SKILL_GROUP_PRIORITY_TAG => {
-                        let (sub_buffer, sub_result) = u16::deserialize(&mut field.data);
-                        skill_group_priority = Some(FloatingField {
-                            tag: field.tag,
-                            length: field.length,
-                            data: sub_result,
-                        });
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
+                        skill_groups.group(field.tag).skill_group_priority = sub_result;
                         buffer = sub_buffer;
                     }
                     TagValue::SKILL_GROUP_STATE_TAG => {
-                        let (sub_buffer, sub_result) = u16::deserialize(&mut field.data);
-                        skill_group_state = Some(FloatingField {
-                            tag: field.tag,
-                            length: field.length,
-                            data: sub_result,
-                        });
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
+                        skill_groups.group(field.tag).skill_group_state = sub_result;
                         buffer = sub_buffer;
                     }
                     TagValue::INTERNAL_AGENT_STATE_TAG => {
-                        let (sub_buffer, sub_result) = u16::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
                         internal_agent_state = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -133,7 +122,7 @@ impl Deserializable for QueryAgentStateConf {
                         buffer = sub_buffer;
                     }
                     TagValue::MAX_BEYOND_TASK_LIMIT_TAG => {
-                        let (sub_buffer, sub_result) = u32::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
                         max_beyond_task_limit = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -141,7 +130,9 @@ impl Deserializable for QueryAgentStateConf {
                         });
                         buffer = sub_buffer;
                     }
-                    _ => {}
+                    _ => {
+                        buffer = preserve_unknown_field(&mut unknown_fields, &field);
+                    }
                 },
                 None => {
                     break;
@@ -149,30 +140,61 @@ impl Deserializable for QueryAgentStateConf {
             };
         }
 
-        (
-            buffer,
-            Self {
-                mhdr,
-                invoke_id,
-                agent_state,
-                num_skill_groups,
-                mrd_id,
-                num_task,
-                agent_mode,
-                max_task_limit,
-                icm_agent_id,
-                agent_availability_status,
-                department_id,
-                agent_id,
-                agent_extension,
-                agent_instrument,
-                skill_group_number,
-                skill_group_id,
-                skill_group_priority,
-                skill_group_state,
-                internal_agent_state,
-                max_beyond_task_limit,
-            },
-        )
+        Ok(Self {
+            mhdr,
+            invoke_id,
+            agent_state,
+            num_skill_groups,
+            mrd_id,
+            num_task,
+            agent_mode,
+            max_task_limit,
+            icm_agent_id,
+            agent_availability_status,
+            department_id,
+            agent_id,
+            agent_extension,
+            agent_instrument,
+            skill_groups: skill_groups.into_vec(),
+            internal_agent_state,
+            max_beyond_task_limit,
+            unknown_fields,
+        })
+    }
+}
+
+impl Serializable for QueryAgentStateConf {
+    fn serialize(self) -> Vec<u8> {
+        let mut buffer = self.invoke_id.serialize();
+        buffer.append(&mut self.agent_state.serialize());
+        buffer.append(&mut self.num_skill_groups.serialize());
+        buffer.append(&mut self.mrd_id.serialize());
+        buffer.append(&mut self.num_task.serialize());
+        buffer.append(&mut self.agent_mode.serialize());
+        buffer.append(&mut self.max_task_limit.serialize());
+        buffer.append(&mut self.icm_agent_id.serialize());
+        buffer.append(&mut self.agent_availability_status.serialize());
+        buffer.append(&mut self.department_id.serialize());
+        buffer.append(&mut self.agent_id.serialize());
+        buffer.append(&mut self.agent_extension.serialize());
+        buffer.append(&mut self.agent_instrument.serialize());
+        for skill_group in self.skill_groups {
+            buffer.append(&mut skill_group.serialize());
+        }
+        buffer.append(&mut self.internal_agent_state.serialize());
+        buffer.append(&mut self.max_beyond_task_limit.serialize());
+        for field in self.unknown_fields {
+            buffer.append(&mut field.serialize());
+        }
+
+        let mhdr = MHDR {
+            length: buffer.len() as u32,
+            message_type: MessageType::QUERY_AGENT_STATE_CONF,
+        };
+
+        let mut result = mhdr.serialize();
+        result.append(&mut buffer);
+
+        result
     }
 }
@@ -1,14 +1,36 @@
-use crate::cisco::{FloatingField, Serializable, MHDR};
+use serde::Serialize;
+
+use crate::cisco::{
+    codec::MHDR_LENGTH,
+    error::{required, validate_length},
+    BuildError, CtiMessage, DecodeError, Deserializable, FloatingField, InvokeId, MessageType,
+    PeripheralId, Serializable, TagValue, MHDR,
+};
+
+///
+/// AgentExtension 최대 길이
+///
+const MAX_AGENT_EXTENSION_LENGTH: usize = 16;
+
+///
+/// AgentID 최대 길이
+///
+const MAX_AGENT_ID_LENGTH: usize = 12;
+
+///
+/// AgentInstrument 최대 길이
+///
+const MAX_AGENT_INSTRUMENT_LENGTH: usize = 64;
 
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize)]
 ///
 /// Cisco CTI 프로토콜 QUERY_AGENT_STATE_REQ 메시지
 ///
 pub struct QueryAgentStateReq {
     pub mhdr: MHDR,
-    pub invoke_id: u32,
-    pub peripheral_id: u32,
+    pub invoke_id: InvokeId,
+    pub peripheral_id: PeripheralId,
     pub mrd_id: i32,
     pub icm_agent_id: i32,
     pub agent_extension: Option<FloatingField<String>>,
@@ -16,6 +38,15 @@ pub struct QueryAgentStateReq {
     pub agent_instrument: Option<FloatingField<String>>,
 }
 
+impl QueryAgentStateReq {
+    ///
+    /// QUERY_AGENT_STATE_REQ 빌더를 생성
+    ///
+    pub fn builder() -> QueryAgentStateReqBuilder {
+        QueryAgentStateReqBuilder::default()
+    }
+}
+
 impl Serializable for QueryAgentStateReq {
     fn serialize(self) -> Vec<u8> {
         let mut buffer = vec![0_u8; 0];
@@ -38,3 +69,183 @@ impl Serializable for QueryAgentStateReq {
         result
     }
 }
+
+impl CtiMessage for QueryAgentStateReq {
+    const FIXED_PART_LENGTH: usize = 24;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let (mut buffer, mhdr) = MHDR::deserialize(buffer);
+        let (mut buffer, invoke_id) = InvokeId::deserialize(&mut buffer);
+        let (mut buffer, peripheral_id) = PeripheralId::deserialize(&mut buffer);
+        let (mut buffer, mrd_id) = i32::deserialize(&mut buffer);
+        let (mut buffer, icm_agent_id) = i32::deserialize(&mut buffer);
+        let mut agent_extension = None;
+        let mut agent_id = None;
+        let mut agent_instrument = None;
+
+        loop {
+            let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
+
+            match floating_field {
+                Some(field) if field.length == 0 => buffer = field.data,
+                Some(field) => match field.tag {
+                    TagValue::AGENT_EXTENSION_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        agent_extension = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::AGENT_ID_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        agent_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    TagValue::AGENT_INSTRUMENT_TAG => {
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        agent_instrument = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
+                        });
+                        buffer = sub_buffer;
+                    }
+                    _ => buffer = field.data[field.length as usize..].to_vec(),
+                },
+                None => break,
+            }
+        }
+
+        Ok(Self {
+            mhdr,
+            invoke_id,
+            peripheral_id,
+            mrd_id,
+            icm_agent_id,
+            agent_extension,
+            agent_id,
+            agent_instrument,
+        })
+    }
+}
+
+///
+/// QUERY_AGENT_STATE_REQ 메시지 빌더
+///
+/// InvokeID, PeripheralID 와 AgentExtension, AgentID, AgentInstrument 중 하나는 필수 값이다.
+///
+#[derive(Debug, Default)]
+pub struct QueryAgentStateReqBuilder {
+    invoke_id: Option<InvokeId>,
+    peripheral_id: Option<PeripheralId>,
+    mrd_id: i32,
+    icm_agent_id: i32,
+    agent_extension: Option<String>,
+    agent_id: Option<String>,
+    agent_instrument: Option<String>,
+}
+
+impl QueryAgentStateReqBuilder {
+    pub fn invoke_id(mut self, invoke_id: InvokeId) -> Self {
+        self.invoke_id = Some(invoke_id);
+        self
+    }
+
+    pub fn peripheral_id(mut self, peripheral_id: PeripheralId) -> Self {
+        self.peripheral_id = Some(peripheral_id);
+        self
+    }
+
+    pub fn mrd_id(mut self, mrd_id: i32) -> Self {
+        self.mrd_id = mrd_id;
+        self
+    }
+
+    pub fn icm_agent_id(mut self, icm_agent_id: i32) -> Self {
+        self.icm_agent_id = icm_agent_id;
+        self
+    }
+
+    pub fn agent_extension(mut self, agent_extension: impl Into<String>) -> Self {
+        self.agent_extension = Some(agent_extension.into());
+        self
+    }
+
+    pub fn agent_id(mut self, agent_id: impl Into<String>) -> Self {
+        self.agent_id = Some(agent_id.into());
+        self
+    }
+
+    pub fn agent_instrument(mut self, agent_instrument: impl Into<String>) -> Self {
+        self.agent_instrument = Some(agent_instrument.into());
+        self
+    }
+
+    ///
+    /// 필수 필드를 검증하고 QUERY_AGENT_STATE_REQ 메시지를 생성한다
+    ///
+    pub fn build(self) -> Result<QueryAgentStateReq, BuildError> {
+        let message_type = MessageType::QUERY_AGENT_STATE_REQ;
+        let invoke_id = required(self.invoke_id, message_type.clone(), "invoke_id")?;
+        let peripheral_id = required(self.peripheral_id, message_type.clone(), "peripheral_id")?;
+
+        // 조회할 상담직원을 식별할 수 있는 필드가 하나는 있어야 한다
+        if self.agent_extension.is_none()
+            && self.agent_id.is_none()
+            && self.agent_instrument.is_none()
+        {
+            return Err(BuildError::MissingField {
+                message_type,
+                field: "agent_extension | agent_id | agent_instrument",
+            });
+        }
+
+        if let Some(agent_extension) = &self.agent_extension {
+            validate_length(
+                "agent_extension",
+                agent_extension,
+                MAX_AGENT_EXTENSION_LENGTH,
+            )?;
+        }
+        if let Some(agent_id) = &self.agent_id {
+            validate_length("agent_id", agent_id, MAX_AGENT_ID_LENGTH)?;
+        }
+        if let Some(agent_instrument) = &self.agent_instrument {
+            validate_length(
+                "agent_instrument",
+                agent_instrument,
+                MAX_AGENT_INSTRUMENT_LENGTH,
+            )?;
+        }
+
+        let mut query_agent_state_req = QueryAgentStateReq {
+            mhdr: MHDR {
+                length: 0,
+                message_type,
+            },
+            invoke_id,
+            peripheral_id,
+            mrd_id: self.mrd_id,
+            icm_agent_id: self.icm_agent_id,
+            agent_extension: self
+                .agent_extension
+                .map(|data| FloatingField::new(TagValue::AGENT_EXTENSION_TAG, data)),
+            agent_id: self
+                .agent_id
+                .map(|data| FloatingField::new(TagValue::AGENT_ID_TAG, data)),
+            agent_instrument: self
+                .agent_instrument
+                .map(|data| FloatingField::new(TagValue::AGENT_INSTRUMENT_TAG, data)),
+        };
+        query_agent_state_req.mhdr.length =
+            (query_agent_state_req.clone().serialize().len() - MHDR_LENGTH) as u32;
+
+        Ok(query_agent_state_req)
+    }
+}
@@ -1,7 +1,43 @@
-use std::fmt::Debug;
+use std::{cell::Cell, fmt::Debug};
 
+use encoding_rs::{Encoding, UTF_8};
 use log::trace;
 
+use super::{
+    codec::{NetworkByteOrder, MHDR_LENGTH},
+    floating_field::FloatingField,
+    DecodeError,
+};
+
+thread_local! {
+    static STRING_ENCODING: Cell<&'static Encoding> = const { Cell::new(UTF_8) };
+}
+
+///
+/// 문자열 직렬화/역직렬화에 사용하는 인코딩을 반환한다
+///
+/// CtiCodec 이 메시지를 처리하는 동안에는 코덱에 지정된 인코딩을, 그 밖에는 UTF-8 을 사용한다.
+///
+pub fn string_encoding() -> &'static Encoding {
+    STRING_ENCODING.get()
+}
+
+///
+/// f 를 실행하는 동안 문자열 인코딩을 지정한다 (실행 후 이전 인코딩으로 되돌린다)
+///
+pub fn with_string_encoding<R>(string_encoding: &'static Encoding, f: impl FnOnce() -> R) -> R {
+    struct RestoreEncoding(&'static Encoding);
+
+    impl Drop for RestoreEncoding {
+        fn drop(&mut self) {
+            STRING_ENCODING.set(self.0);
+        }
+    }
+
+    let _restore_encoding = RestoreEncoding(STRING_ENCODING.replace(string_encoding));
+    f()
+}
+
 ///
 /// 역직렬화 트레잇
 ///
@@ -14,73 +50,136 @@ pub trait Deserializable {
 
 impl Deserializable for bool {
     fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
-        let result = (buffer.as_mut()[0] | buffer.as_mut()[1]) > 0;
+        let (buffer, result) = u16::deserialize(buffer);
 
-        (buffer.as_mut()[2..].to_vec(), result)
+        (buffer, result > 0)
     }
 }
 
-impl Deserializable for u8 {
+///
+/// 숫자 타입은 네트워크 바이트 순서로 역직렬화한다
+///
+macro_rules! impl_deserializable_number {
+    ($($t:ty),*) => {
+        $(
+            impl Deserializable for $t {
+                fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+                    let buffer = buffer.as_mut();
+                    let result = <$t>::from_network_bytes(buffer);
+
+                    (buffer[<$t>::LENGTH..].to_vec(), result)
+                }
+            }
+        )*
+    };
+}
+
+impl_deserializable_number!(u8, i8, i16, u16, i32, u32, i64, u64);
+
+impl Deserializable for f32 {
     fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
-        let result = buffer.as_mut()[0];
+        let (buffer, result) = u32::deserialize(buffer);
 
-        (buffer.as_mut()[1..].to_vec(), result)
+        (buffer, f32::from_bits(result))
     }
 }
 
-impl Deserializable for i16 {
-    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
-        let mut result = 0_i16;
+///
+/// 가변 필드 값 역직렬화 트레잇
+///
+/// 가변 필드는 선언된 길이(length)만큼의 데이터만 값으로 해석한다.
+/// 고정 길이 숫자 필드의 선언된 길이가 타입 길이와 다른 경우 잘못된 메시지로 보고 오류를 반환한다.
+///
+pub trait FloatingFieldValue: Sized {
+    ///
+    /// 가변 필드 데이터(선언된 길이만큼)를 값으로 변환한다
+    ///
+    fn from_field_data(tag: u16, data: &[u8]) -> Result<Self, DecodeError>;
+}
 
-        for i in (0..2).rev() {
-            result |= (buffer.as_mut()[1 - i] as i16) << (8 * i);
-        }
+macro_rules! impl_floating_field_value_number {
+    ($($t:ty),*) => {
+        $(
+            impl FloatingFieldValue for $t {
+                fn from_field_data(tag: u16, data: &[u8]) -> Result<Self, DecodeError> {
+                    if data.len() != <$t>::LENGTH {
+                        return Err(DecodeError::FloatingFieldLength {
+                            tag,
+                            length: data.len(),
+                            expected: <$t>::LENGTH,
+                        });
+                    }
 
-        (buffer.as_mut()[2..].to_vec(), result)
-    }
+                    Ok(<$t>::from_network_bytes(data))
+                }
+            }
+        )*
+    };
 }
 
-impl Deserializable for u16 {
-    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
-        let mut result = 0_u16;
+impl_floating_field_value_number!(u8, i8, i16, u16, i32, u32, i64, u64);
 
-        for i in (0..2).rev() {
-            result |= (buffer.as_mut()[1 - i] as u16) << (8 * i);
-        }
+impl FloatingFieldValue for String {
+    fn from_field_data(_tag: u16, data: &[u8]) -> Result<Self, DecodeError> {
+        // NUL 종료 문자가 없는 경우 선언된 길이 전체를 문자열로 처리한다
+        let index = data.iter().position(|&b| b == 0).unwrap_or(data.len());
 
-        (buffer.as_mut()[2..].to_vec(), result)
+        // 디코딩할 수 없는 문자는 대체 문자로 변환한다
+        let (result, _) = string_encoding().decode_without_bom_handling(&data[0..index]);
+
+        Ok(result.into_owned())
     }
 }
 
-impl Deserializable for i32 {
+impl Deserializable for String {
     fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
-        let mut result = 0_i32;
+        let buffer = buffer.as_mut();
 
-        for i in (0..4).rev() {
-            result |= (buffer.as_mut()[3 - i] as i32) << (8 * i);
-        }
+        // NUL 종료 문자가 없는 경우 남은 버퍼 전체를 문자열로 처리한다
+        let (index, next) = match buffer.iter().position(|&b| b == 0) {
+            Some(index) => (index, index + 1),
+            None => (buffer.len(), buffer.len()),
+        };
+
+        // 디코딩할 수 없는 문자는 대체 문자로 변환한다
+        let (result, _) = string_encoding().decode_without_bom_handling(&buffer[0..index]);
+
+        (buffer[next..].to_vec(), result.into_owned())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+///
+/// 고정 길이 문자열 (N 바이트, 남는 자리는 NUL 로 채운다)
+///
+/// 고정 필드의 문자열은 NUL 종료 문자 위치와 관계없이 항상 N 바이트를 차지한다.
+///
+pub struct FixedString<const N: usize>(pub String);
 
-        (buffer.as_mut()[4..].to_vec(), result)
+impl<const N: usize> From<FixedString<N>> for String {
+    fn from(value: FixedString<N>) -> Self {
+        value.0
     }
 }
 
-impl Deserializable for u32 {
+impl<const N: usize> Deserializable for FixedString<N> {
     fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
-        let mut result = 0_u32;
+        let (buffer, result) = <[u8; N]>::deserialize(buffer);
 
-        for i in (0..4).rev() {
-            result |= (buffer.as_mut()[3 - i] as u32) << (8 * i);
-        }
-        (buffer.as_mut()[4..].to_vec(), result)
+        // 첫 NUL 이후의 채움 바이트는 버린다
+        let index = result.iter().position(|&b| b == 0).unwrap_or(N);
+        let (result, _) = string_encoding().decode_without_bom_handling(&result[0..index]);
+
+        (buffer, Self(result.into_owned()))
     }
 }
 
-impl Deserializable for String {
+impl<const N: usize> Deserializable for [u8; N] {
     fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
-        let index = buffer.as_mut().iter().position(|&b| b == 0).unwrap();
-        let result = String::from_utf8(buffer.as_mut()[0..index].to_vec()).unwrap();
+        let mut result = [0_u8; N];
+        result.copy_from_slice(&buffer.as_mut()[0..N]);
 
-        (buffer.as_mut()[index + 1..].to_vec(), result)
+        (buffer.as_mut()[N..].to_vec(), result)
     }
 }
 
@@ -95,7 +194,7 @@ where
     T: Deserializable + Debug,
 {
     fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
-        if buffer.as_mut().len() > 0 {
+        if !buffer.as_mut().is_empty() {
             let (buffer, result) = T::deserialize(buffer);
             trace!("floating_field buffer: {:?}", result);
             (buffer, Some(result))
@@ -104,3 +203,108 @@ where
         }
     }
 }
+
+///
+/// 처리되지 않은 가변 필드를 원본 데이터 그대로 보존하고 잔여 버퍼를 반환한다
+///
+/// 메시지 구조체가 알지 못하는 태그의 가변 필드도 재직렬화, 로깅 시 유실되지 않도록
+/// 각 메시지의 `unknown_fields` 에 (tag, length, 원본 데이터) 그대로 쌓아둔다.
+///
+pub fn preserve_unknown_field(
+    unknown_fields: &mut Vec<FloatingField<Vec<u8>>>,
+    field: &FloatingField<Vec<u8>>,
+) -> Vec<u8> {
+    let length = field.length as usize;
+
+    unknown_fields.push(FloatingField {
+        tag: field.tag,
+        length: field.length,
+        data: field.data[..length].to_vec(),
+    });
+
+    field.data[length..].to_vec()
+}
+
+///
+/// 길이 검증 후 역직렬화하는 CTI 메시지 트레잇
+///
+pub trait CtiMessage: Sized {
+    ///
+    /// 메시지 헤더를 포함한 고정 필드 길이
+    ///
+    const FIXED_PART_LENGTH: usize;
+
+    ///
+    /// 길이 검증이 끝난 버퍼를 역직렬화한다
+    ///
+    /// 가변 필드 값의 길이가 타입과 맞지 않으면 오류를 반환한다.
+    ///
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError>;
+
+    ///
+    /// 메시지 헤더와 가변 필드 길이를 검증한 뒤 역직렬화한다
+    ///
+    fn try_deserialize(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let _entered =
+            tracing::info_span!("cti.deserialize", message = std::any::type_name::<Self>())
+                .entered();
+
+        validate_message(buffer, Self::FIXED_PART_LENGTH)?;
+
+        Self::decode(buffer)
+    }
+}
+
+///
+/// 메시지 헤더 길이와 가변 필드 길이가 버퍼 범위 내에 있는지 검증한다
+///
+pub fn validate_message(buffer: &[u8], fixed_part_length: usize) -> Result<(), DecodeError> {
+    if buffer.len() < MHDR_LENGTH {
+        return Err(DecodeError::UnexpectedEof {
+            expected: MHDR_LENGTH,
+            remaining: buffer.len(),
+        });
+    }
+
+    let (_, length) = u32::deserialize(&mut buffer[0..4].to_vec());
+    if buffer.len() != MHDR_LENGTH + length as usize {
+        return Err(DecodeError::LengthMismatch {
+            length: length as usize,
+            received: buffer.len() - MHDR_LENGTH,
+        });
+    }
+
+    if buffer.len() < fixed_part_length {
+        return Err(DecodeError::UnexpectedEof {
+            expected: fixed_part_length,
+            remaining: buffer.len(),
+        });
+    }
+
+    // 가변 필드 (tag, length, data) 를 순회하며 길이를 검증한다
+    let mut index = fixed_part_length;
+    while index < buffer.len() {
+        if buffer.len() - index < 4 {
+            return Err(DecodeError::UnexpectedEof {
+                expected: 4,
+                remaining: buffer.len() - index,
+            });
+        }
+
+        let (_, tag) = u16::deserialize(&mut buffer[index..index + 2].to_vec());
+        let (_, length) = u16::deserialize(&mut buffer[index + 2..index + 4].to_vec());
+        index += 4;
+
+        if buffer.len() - index < length as usize {
+            return Err(DecodeError::FloatingFieldOverflow {
+                tag,
+                length: length as usize,
+                remaining: buffer.len() - index,
+            });
+        }
+
+        index += length as usize;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use super::{DecodeError, Deserializable, FloatingFieldValue, Serializable};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Cisco CTI 프로토콜 호 방향
+///
+pub enum Direction {
+    NONE,
+    IN,
+    OUT,
+    OTHER_IN,
+    OTHER_OUT,
+    OUTBOUND_RESERVE,
+    OUTBOUND_PREVIEW,
+    OUTBOUND_PREDICTIVE,
+    // 정의되지 않은 호 방향은 원래 값을 보존한다
+    UNKNOWN(u32),
+}
+
+impl From<u32> for Direction {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Direction::NONE,
+            1 => Direction::IN,
+            2 => Direction::OUT,
+            3 => Direction::OTHER_IN,
+            4 => Direction::OTHER_OUT,
+            5 => Direction::OUTBOUND_RESERVE,
+            6 => Direction::OUTBOUND_PREVIEW,
+            7 => Direction::OUTBOUND_PREDICTIVE,
+            n => Direction::UNKNOWN(n),
+        }
+    }
+}
+
+impl From<Direction> for u32 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::NONE => 0,
+            Direction::IN => 1,
+            Direction::OUT => 2,
+            Direction::OTHER_IN => 3,
+            Direction::OTHER_OUT => 4,
+            Direction::OUTBOUND_RESERVE => 5,
+            Direction::OUTBOUND_PREVIEW => 6,
+            Direction::OUTBOUND_PREDICTIVE => 7,
+            Direction::UNKNOWN(n) => n,
+        }
+    }
+}
+
+impl Serializable for Direction {
+    fn serialize(self) -> Vec<u8> {
+        u32::from(self).serialize()
+    }
+}
+
+impl Deserializable for Direction {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (buffer, result) = <u32 as Deserializable>::deserialize(buffer);
+        (buffer, result.into())
+    }
+}
+
+impl FloatingFieldValue for Direction {
+    fn from_field_data(tag: u16, data: &[u8]) -> Result<Self, DecodeError> {
+        Ok(u32::from_field_data(tag, data)?.into())
+    }
+}
@@ -0,0 +1,152 @@
+use std::{error::Error, fmt::Display};
+
+use super::MessageType;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Cisco CTI 프로토콜 메시지 역직렬화 오류
+///
+pub enum DecodeError {
+    MessageTooLong {
+        length: usize,
+        max_length: usize,
+    },
+    LengthMismatch {
+        length: usize,
+        received: usize,
+    },
+    UnexpectedEof {
+        expected: usize,
+        remaining: usize,
+    },
+    FloatingFieldOverflow {
+        tag: u16,
+        length: usize,
+        remaining: usize,
+    },
+    FloatingFieldLength {
+        tag: u16,
+        length: usize,
+        expected: usize,
+    },
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::MessageTooLong { length, max_length } => write!(
+                f,
+                "Message length exceeds maximum. length: {}, max_length: {}",
+                length, max_length
+            ),
+            DecodeError::LengthMismatch { length, received } => write!(
+                f,
+                "Message length mismatch. length: {}, received: {}",
+                length, received
+            ),
+            DecodeError::UnexpectedEof {
+                expected,
+                remaining,
+            } => write!(
+                f,
+                "Unexpected end of message. expected: {}, remaining: {}",
+                expected, remaining
+            ),
+            DecodeError::FloatingFieldOverflow {
+                tag,
+                length,
+                remaining,
+            } => write!(
+                f,
+                "Floating field exceeds message. tag: {}, length: {}, remaining: {}",
+                tag, length, remaining
+            ),
+            DecodeError::FloatingFieldLength {
+                tag,
+                length,
+                expected,
+            } => write!(
+                f,
+                "Floating field length mismatch. tag: {}, length: {}, expected: {}",
+                tag, length, expected
+            ),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Cisco CTI 프로토콜 요청 메시지 생성 오류
+///
+pub enum BuildError {
+    MissingField {
+        message_type: MessageType,
+        field: &'static str,
+    },
+    FieldTooLong {
+        field: &'static str,
+        length: usize,
+        max_length: usize,
+    },
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::MissingField {
+                message_type,
+                field,
+            } => write!(
+                f,
+                "Mandatory field is missing. message_type: {:?}, field: {}",
+                message_type, field
+            ),
+            BuildError::FieldTooLong {
+                field,
+                length,
+                max_length,
+            } => write!(
+                f,
+                "Field length exceeds maximum. field: {}, length: {}, max_length: {}",
+                field, length, max_length
+            ),
+        }
+    }
+}
+
+impl Error for BuildError {}
+
+///
+/// 필수 필드 값이 없으면 오류를 반환한다
+///
+pub(crate) fn required<T>(
+    value: Option<T>,
+    message_type: MessageType,
+    field: &'static str,
+) -> Result<T, BuildError> {
+    value.ok_or(BuildError::MissingField {
+        message_type,
+        field,
+    })
+}
+
+///
+/// 문자열 필드 최대 길이를 검증한다
+///
+pub(crate) fn validate_length(
+    field: &'static str,
+    value: &str,
+    max_length: usize,
+) -> Result<(), BuildError> {
+    if value.len() > max_length {
+        return Err(BuildError::FieldTooLong {
+            field,
+            length: value.len(),
+            max_length,
+        });
+    }
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use super::{Deserializable, Serializable, TagValue};
+use serde::Serialize;
+
+use super::{DecodeError, Deserializable, FloatingFieldValue, Serializable, TagValue};
 
 #[allow(unused)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 ///
 /// Cisco CTI 프로토콜 가변 필드
 ///
@@ -11,6 +13,32 @@ pub struct FloatingField<T> {
     pub data: T,
 }
 
+impl<T> FloatingField<T>
+where
+    T: Serializable + Clone,
+{
+    ///
+    /// 직렬화된 데이터 길이로 length 를 계산해 가변 필드를 생성한다
+    ///
+    pub fn new(tag: TagValue, data: T) -> Self {
+        let length = data.clone().serialize().len() as u16;
+
+        Self { tag, length, data }
+    }
+}
+
+impl FloatingField<Vec<u8>> {
+    ///
+    /// 선언된 길이만큼의 데이터를 값으로 변환하고, 다음 가변 필드부터의 잔여 버퍼와 함께 반환한다
+    ///
+    pub fn decode<T: FloatingFieldValue>(&self) -> Result<(Vec<u8>, T), DecodeError> {
+        let length = self.length as usize;
+        let result = T::from_field_data(self.tag.into(), &self.data[..length])?;
+
+        Ok((self.data[length..].to_vec(), result))
+    }
+}
+
 impl<T> Serializable for FloatingField<T>
 where
     T: Serializable,
@@ -0,0 +1,150 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
+
+use serde::Serialize;
+
+use super::{DecodeError, Deserializable, FloatingFieldValue, Serializable};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+///
+/// Cisco CTI 프로토콜 요청-응답 매칭용 InvokeID
+///
+pub struct InvokeId(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+///
+/// Cisco CTI 프로토콜 모니터 ID
+///
+pub struct MonitorId(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+///
+/// Cisco CTI 프로토콜 주변장치(PG) ID
+///
+pub struct PeripheralId(pub u32);
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, serde::Deserialize,
+)]
+///
+/// Cisco CTI 프로토콜 호 식별자 (ConnectionCallID)
+///
+pub struct ConnectionCallId(pub u32);
+
+impl fmt::Display for InvokeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for MonitorId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for PeripheralId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for ConnectionCallId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serializable for InvokeId {
+    fn serialize(self) -> Vec<u8> {
+        self.0.serialize()
+    }
+}
+
+impl Serializable for MonitorId {
+    fn serialize(self) -> Vec<u8> {
+        self.0.serialize()
+    }
+}
+
+impl Serializable for PeripheralId {
+    fn serialize(self) -> Vec<u8> {
+        self.0.serialize()
+    }
+}
+
+impl Serializable for ConnectionCallId {
+    fn serialize(self) -> Vec<u8> {
+        self.0.serialize()
+    }
+}
+
+impl Deserializable for InvokeId {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (buffer, result) = u32::deserialize(buffer);
+
+        (buffer, Self(result))
+    }
+}
+
+impl Deserializable for MonitorId {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (buffer, result) = u32::deserialize(buffer);
+
+        (buffer, Self(result))
+    }
+}
+
+impl Deserializable for PeripheralId {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (buffer, result) = u32::deserialize(buffer);
+
+        (buffer, Self(result))
+    }
+}
+
+impl FloatingFieldValue for PeripheralId {
+    fn from_field_data(tag: u16, data: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self(u32::from_field_data(tag, data)?))
+    }
+}
+
+impl Deserializable for ConnectionCallId {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (buffer, result) = u32::deserialize(buffer);
+
+        (buffer, Self(result))
+    }
+}
+
+///
+/// InvokeID 생성기
+///
+/// 복제본끼리 카운터를 공유하므로 CTI 클라이언트가 재접속(이중화 전환)해도
+/// InvokeID 가 중복되지 않는다.
+///
+#[derive(Debug, Clone, Default)]
+pub struct InvokeIdGenerator {
+    counter: Arc<AtomicU32>,
+}
+
+impl InvokeIdGenerator {
+    ///
+    /// 새로운 InvokeID 생성기를 생성
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// InvokeID 값을 증가하고 증가한 값을 반환한다
+    ///
+    pub fn next_id(&self) -> InvokeId {
+        InvokeId(self.counter.fetch_add(1, Ordering::Relaxed).wrapping_add(1))
+    }
+}
@@ -1,7 +1,10 @@
+use serde::Serialize;
+
 use super::{Deserializable, Serializable};
 
 #[allow(unused, non_camel_case_types)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[repr(u32)]
 ///
 /// Cisco CTI 프로토콜 메시지 타입
 ///
@@ -288,11 +291,47 @@ pub enum MessageType {
     ACTIVE_MAINTENANCE_EVENT_MSG = 280,
     STOPPING_REQUESTS_TO_THIS_SIDE_END = 281,
     CONFIG_AGENT_SERVICE_EVENT = 282,
+    // 정의되지 않은 메시지 타입은 원래 값을 보존한다
+    UNKNOWN(u32),
+}
+
+impl MessageType {
+    ///
+    /// 호 이벤트 메시지 여부를 반환한다
+    ///
+    pub fn is_call_event(&self) -> bool {
+        matches!(
+            self,
+            MessageType::BEGIN_CALL_EVENT
+                | MessageType::END_CALL_EVENT
+                | MessageType::CALL_DATA_UPDATE_EVENT
+                | MessageType::CALL_DELIVERED_EVENT
+                | MessageType::CALL_ESTABLISHED_EVENT
+                | MessageType::CALL_HELD_EVENT
+                | MessageType::CALL_RETRIEVED_EVENT
+                | MessageType::CALL_CLEARED_EVENT
+                | MessageType::CALL_CONNECTION_CLEARED_EVENT
+                | MessageType::CALL_ORIGINATED_EVENT
+                | MessageType::CALL_FAILED_EVENT
+                | MessageType::CALL_CONFERENCED_EVENT
+                | MessageType::CALL_TRANSFERRED_EVENT
+                | MessageType::CALL_DIVERTED_EVENT
+                | MessageType::CALL_SERVICE_INITIATED_EVENT
+                | MessageType::CALL_QUEUED_EVENT
+                | MessageType::CALL_DEQUEUED_EVENT
+                | MessageType::CALL_TRANSLATION_ROUTE_EVENT
+                | MessageType::CALL_REACHED_NETWORK_EVENT
+                | MessageType::AGENT_PRE_CALL_EVENT
+                | MessageType::AGENT_PRE_CALL_ABORT_EVENT
+                | MessageType::RTP_STARTED_EVENT
+                | MessageType::RTP_STOPPED_EVENT
+        )
+    }
 }
 
-impl Into<MessageType> for u32 {
-    fn into(self) -> MessageType {
-        match self {
+impl From<u32> for MessageType {
+    fn from(value: u32) -> Self {
+        match value {
             1 => MessageType::FAILURE_CONF,
             2 => MessageType::FAILURE_EVENT,
             3 => MessageType::OPEN_REQ,
@@ -575,14 +614,304 @@ impl Into<MessageType> for u32 {
             280 => MessageType::ACTIVE_MAINTENANCE_EVENT_MSG,
             281 => MessageType::STOPPING_REQUESTS_TO_THIS_SIDE_END,
             282 => MessageType::CONFIG_AGENT_SERVICE_EVENT,
-            n => panic!("Invalid operation. (message_type: {})", n),
+            n => MessageType::UNKNOWN(n),
+        }
+    }
+}
+
+impl From<MessageType> for u32 {
+    fn from(value: MessageType) -> Self {
+        match value {
+            MessageType::FAILURE_CONF => 1,
+            MessageType::FAILURE_EVENT => 2,
+            MessageType::OPEN_REQ => 3,
+            MessageType::OPEN_CONF => 4,
+            MessageType::HEARTBEAT_REQ => 5,
+            MessageType::HEARTBEAT_CONF => 6,
+            MessageType::CLOSE_REQ => 7,
+            MessageType::CLOSE_CONF => 8,
+            MessageType::CALL_DELIVERED_EVENT => 9,
+            MessageType::CALL_ESTABLISHED_EVENT => 10,
+            MessageType::CALL_HELD_EVENT => 11,
+            MessageType::CALL_RETRIEVED_EVENT => 12,
+            MessageType::CALL_CLEARED_EVENT => 13,
+            MessageType::CALL_CONNECTION_CLEARED_EVENT => 14,
+            MessageType::CALL_ORIGINATED_EVENT => 15,
+            MessageType::CALL_FAILED_EVENT => 16,
+            MessageType::CALL_CONFERENCED_EVENT => 17,
+            MessageType::CALL_TRANSFERRED_EVENT => 18,
+            MessageType::CALL_DIVERTED_EVENT => 19,
+            MessageType::CALL_SERVICE_INITIATED_EVENT => 20,
+            MessageType::CALL_QUEUED_EVENT => 21,
+            MessageType::CALL_TRANSLATION_ROUTE_EVENT => 22,
+            MessageType::BEGIN_CALL_EVENT => 23,
+            MessageType::END_CALL_EVENT => 24,
+            MessageType::CALL_DATA_UPDATE_EVENT => 25,
+            MessageType::SET_CALL_DATA_REQ => 26,
+            MessageType::SET_CALL_DATA_CONF => 27,
+            MessageType::RELEASE_CALL_REQ => 28,
+            MessageType::RELEASE_CALL_CONF => 29,
+            MessageType::AGENT_STATE_EVENT => 30,
+            MessageType::SYSTEM_EVENT => 31,
+            MessageType::CLIENT_EVENT_REPORT_REQ => 32,
+            MessageType::CLIENT_EVENT_REPORT_CONF => 33,
+            MessageType::CALL_REACHED_NETWORK_EVENT => 34,
+            MessageType::CONTROL_FAILURE_CONF => 35,
+            MessageType::QUERY_AGENT_STATE_REQ => 36,
+            MessageType::QUERY_AGENT_STATE_CONF => 37,
+            MessageType::SET_AGENT_STATE_REQ => 38,
+            MessageType::SET_AGENT_STATE_CONF => 39,
+            MessageType::ALTERNATE_CALL_REQ => 40,
+            MessageType::ALTERNATE_CALL_CONF => 41,
+            MessageType::ANSWER_CALL_REQ => 42,
+            MessageType::ANSWER_CALL_CONF => 43,
+            MessageType::CLEAR_CALL_REQ => 44,
+            MessageType::CLEAR_CALL_CONF => 45,
+            MessageType::CLEAR_CONNECTION_REQ => 46,
+            MessageType::CLEAR_CONNECTION_CONF => 47,
+            MessageType::CONFERENCE_CALL_REQ => 48,
+            MessageType::CONFERENCE_CALL_CONF => 49,
+            MessageType::CONSULTATION_CALL_REQ => 50,
+            MessageType::CONSULTATION_CALL_CONF => 51,
+            MessageType::DEFLECT_CALL_REQ => 52,
+            MessageType::DEFLECT_CALL_CONF => 53,
+            MessageType::HOLD_CALL_REQ => 54,
+            MessageType::HOLD_CALL_CONF => 55,
+            MessageType::MAKE_CALL_REQ => 56,
+            MessageType::MAKE_CALL_CONF => 57,
+            MessageType::MAKE_PREDICTIVE_CALL_REQ => 58,
+            MessageType::MAKE_PREDICTIVE_CALL_CONF => 59,
+            MessageType::RECONNECT_CALL_REQ => 60,
+            MessageType::RECONNECT_CALL_CONF => 61,
+            MessageType::RETRIEVE_CALL_REQ => 62,
+            MessageType::RETRIEVE_CALL_CONF => 63,
+            MessageType::TRANSFER_CALL_REQ => 64,
+            MessageType::TRANSFER_CALL_CONF => 65,
+            MessageType::RESERVED66 => 66,
+            MessageType::RESERVED67 => 67,
+            MessageType::RESERVED68 => 68,
+            MessageType::RESERVED69 => 69,
+            MessageType::RESERVED70 => 70,
+            MessageType::RESERVED71 => 71,
+            MessageType::RESERVED72 => 72,
+            MessageType::RESERVED73 => 73,
+            MessageType::RESERVED74 => 74,
+            MessageType::RESERVED75 => 75,
+            MessageType::RESERVED76 => 76,
+            MessageType::RESERVED77 => 77,
+            MessageType::QUERY_DEVICE_INFO_REQ => 78,
+            MessageType::QUERY_DEVICE_INFO_CONF => 79,
+            MessageType::RESERVED80 => 80,
+            MessageType::RESERVED81 => 81,
+            MessageType::SNAPSHOT_CALL_REQ => 82,
+            MessageType::SNAPSHOT_CALL_CONF => 83,
+            MessageType::SNAPSHOT_DEVICE_REQ => 84,
+            MessageType::SNAPSHOT_DEVICE_CONF => 85,
+            MessageType::CALL_DEQUEUED_EVENT => 86,
+            MessageType::RESERVED87 => 87,
+            MessageType::RESERVED88 => 88,
+            MessageType::RESERVED89 => 89,
+            MessageType::RESERVED90 => 90,
+            MessageType::SEND_DTMF_SIGNAL_REQ => 91,
+            MessageType::SEND_DTMF_SIGNAL_CONF => 92,
+            MessageType::MONITOR_START_REQ => 93,
+            MessageType::MONITOR_START_CONF => 94,
+            MessageType::MONITOR_STOP_REQ => 95,
+            MessageType::MONITOR_STOP_CONF => 96,
+            MessageType::CHANGE_MONITOR_MASK_REQ => 97,
+            MessageType::CHANGE_MONITOR_MASK_CONF => 98,
+            MessageType::CLIENT_SESSION_OPENED_EVENT => 99,
+            MessageType::CLIENT_SESSION_CLOSED_EVENT => 100,
+            MessageType::SESSION_MONITOR_START_REQ => 101,
+            MessageType::SESSION_MONITOR_START_CONF => 102,
+            MessageType::SESSION_MONITOR_STOP_REQ => 103,
+            MessageType::SESSION_MONITOR_STOP_CONF => 104,
+            MessageType::AGENT_PRE_CALL_EVENT => 105,
+            MessageType::AGENT_PRE_CALL_ABORT_EVENT => 106,
+            MessageType::USER_MESSAGE_REQ => 107,
+            MessageType::USER_MESSAGE_CONF => 108,
+            MessageType::USER_MESSAGE_EVENT => 109,
+            MessageType::REGISTER_VARIABLES_REQ => 110,
+            MessageType::REGISTER_VARIABLES_CONF => 111,
+            MessageType::QUERY_AGENT_STATISTICS_REQ => 112,
+            MessageType::QUERY_AGENT_STATISTICS_CONF => 113,
+            MessageType::QUERY_SKILL_GROUP_STATISTICS_REQ => 114,
+            MessageType::QUERY_SKILL_GROUP_STATISTICS_CONF => 115,
+            MessageType::RTP_STARTED_EVENT => 116,
+            MessageType::RTP_STOPPED_EVENT => 117,
+            MessageType::SUPERVISOR_ASSIST_REQ => 118,
+            MessageType::SUPERVISOR_ASSIST_CONF => 119,
+            MessageType::SUPERVISOR_ASSIST_EVENT => 120,
+            MessageType::EMERGENCY_CALL_REQ => 121,
+            MessageType::EMERGENCY_CALL_CONF => 122,
+            MessageType::EMERGENCY_CALL_EVENT => 123,
+            MessageType::SUPERVISE_CALL_REQ => 124,
+            MessageType::SUPERVISE_CALL_CONF => 125,
+            MessageType::AGENT_TEAM_CONFIG_REQ => 126,
+            MessageType::AGENT_TEAM_CONFIG_CONF => 127,
+            MessageType::AGENT_TEAM_CONFIG_EVENT => 128,
+            MessageType::SET_APP_DATA_REQ => 129,
+            MessageType::SET_APP_DATA_CONF => 130,
+            MessageType::AGENT_DESK_SETTINGS_REQ => 131,
+            MessageType::AGENT_DESK_SETTINGS_CONF => 132,
+            MessageType::LIST_AGENT_TEAM_REQ => 133,
+            MessageType::LIST_AGENT_TEAM_CONF => 134,
+            MessageType::MONITOR_AGENT_TEAM_START_REQ => 135,
+            MessageType::MONITOR_AGENT_TEAM_START_CONF => 136,
+            MessageType::MONITOR_AGENT_TEAM_STOP_REQ => 137,
+            MessageType::MONITOR_AGENT_TEAM_STOP_CONF => 138,
+            MessageType::BAD_CALL_REQ => 139,
+            MessageType::BAD_CALL_CONF => 140,
+            MessageType::SET_DEVICE_ATTRIBUTES_REQ => 141,
+            MessageType::SET_DEVICE_ATTRIBUTES_CONF => 142,
+            MessageType::REGISTER_SERVICE_REQ => 143,
+            MessageType::REGISTER_SERVICE_CONF => 144,
+            MessageType::UNREGISTER_SERVICE_REQ => 145,
+            MessageType::UNREGISTER_SERVICE_CONF => 146,
+            MessageType::START_RECORDING_REQ => 147,
+            MessageType::START_RECORDING_CONF => 148,
+            MessageType::STOP_RECORDING_REQ => 149,
+            MessageType::STOP_RECORDING_CONF => 150,
+            MessageType::MEDIA_LOGIN_REQ => 151,
+            MessageType::MEDIA_LOGIN_RESP => 152,
+            MessageType::MEDIA_LOGOUT_IND => 153,
+            MessageType::MAKE_AGENT_ROUTABLE_IND => 154,
+            MessageType::MAKE_AGENT_NOT_ROUTABLE_REQ => 155,
+            MessageType::MAKE_AGENT_NOT_ROUTABLE_RESP => 156,
+            MessageType::MAKE_AGENT_READY_IND => 157,
+            MessageType::MAKE_AGENT_NOT_READY_REQ => 158,
+            MessageType::MAKE_AGENT_NOT_READY_RESP => 159,
+            MessageType::OFFER_TASK_IND => 160,
+            MessageType::OFFER_APPLICATION_TASK_REQ => 161,
+            MessageType::OFFER_APPLICATION_TASK_RESP => 162,
+            MessageType::START_TASK_IND => 163,
+            MessageType::START_APPLICATION_TASK_REQ => 164,
+            MessageType::START_APPLICATION_TASK_RESP => 165,
+            MessageType::PAUSE_TASK_IND => 166,
+            MessageType::RESUME_TASK_IND => 167,
+            MessageType::WRAPUP_TASK_IND => 168,
+            MessageType::END_TASK_IND => 169,
+            MessageType::AGENT_MADE_NOT_ROUTABLE_EVENT => 170,
+            MessageType::AGENT_INTERRUPT_ADVISORY_EVENT => 171,
+            MessageType::AGENT_INTERRUPT_ACCEPT_IND => 172,
+            MessageType::AGENT_INTERRUPT_UNACCEPT_IND => 173,
+            MessageType::AGENT_INTERRUPT_DONE_ADVISORY_EVENT => 174,
+            MessageType::AGENT_INTERRUPT_DONE_ACCEPTED_IND => 175,
+            MessageType::CHANGE_MAX_TASK_LIMIT_REQ => 176,
+            MessageType::CHANGE_MAX_TASK_LIMIT_RESP => 177,
+            MessageType::OVERRIDE_LIMIT_REQ => 178,
+            MessageType::OVERRIDE_LMIIT_RESP => 179,
+            MessageType::UPDATE_TASK_CONTEXT_IND => 180,
+            MessageType::BEGIN_AGENT_INIT_IND => 181,
+            MessageType::AGENT_INIT_REQ => 182,
+            MessageType::AGENT_INIT_RESP => 183,
+            MessageType::END_AGENT_INIT_IND => 184,
+            MessageType::TASK_INIT_IND => 185,
+            MessageType::AGENT_INIT_READY_EVENT => 186,
+            MessageType::GET_PRECALL_MESSAGE_REQ => 187,
+            MessageType::GET_PRECALL_MESSAGE_RESP => 188,
+            MessageType::AGENT_LEGACY_PRE_CALL_EVENT => 189,
+            MessageType::FAILURE_RESP => 190,
+            MessageType::BEGIN_TASK_EVENT => 191,
+            MessageType::QUEUED_TASK_EVENT => 192,
+            MessageType::DEQUEUED_TASK_EVENT => 193,
+            MessageType::OFFER_TASK_EVENT => 194,
+            MessageType::START_TASK_EVENT => 195,
+            MessageType::PAUSE_TASK_EVENT => 196,
+            MessageType::RESUME_TASK_EVENT => 197,
+            MessageType::WRAPUP_TASK_EVENT => 198,
+            MessageType::END_TASK_EVENT => 199,
+            MessageType::TASK_DATA_UPDATE_EVENT => 200,
+            MessageType::TASK_MONITOR_START_REQ => 201,
+            MessageType::TASK_MONITOR_START_CONF => 202,
+            MessageType::TASK_MONITOR_STOP_REQ => 203,
+            MessageType::TASK_MONITOR_STOP_CONF => 204,
+            MessageType::CHANGE_TASK_MONITOR_MASK_REQ => 205,
+            MessageType::CHANGE_TASK_MONITOR_MASK_CONF => 206,
+            MessageType::MAX_TASK_LIFETIME_EXCEEDED_EVENT => 207,
+            MessageType::SET_APP_PATH_DATA_IND => 208,
+            MessageType::TASK_INIT_REQ => 209,
+            MessageType::TASK_INIT_RESP => 210,
+            MessageType::ROUTE_REGISTER_EVENT => 211,
+            MessageType::ROUTE_REGISTER_REPLY_EVENT => 212,
+            MessageType::ROUTE_REQUEST_EVENT => 213,
+            MessageType::ROUTE_SELECT_EVENT => 214,
+            MessageType::ROUTE_END_EVENT => 215,
+            MessageType::RESERVED216 => 216,
+            MessageType::RESERVED217 => 217,
+            MessageType::RESERVED218 => 218,
+            MessageType::RESERVED219 => 219,
+            MessageType::RESERVED220 => 220,
+            MessageType::RESERVED221 => 221,
+            MessageType::RESERVED222 => 222,
+            MessageType::RESERVED223 => 223,
+            MessageType::RESERVED224 => 224,
+            MessageType::RESERVED225 => 225,
+            MessageType::RESERVED226 => 226,
+            MessageType::RESERVED227 => 227,
+            MessageType::RESERVED228 => 228,
+            MessageType::RESERVED229 => 229,
+            MessageType::CONFIG_REQUEST_KEY_EVENT => 230,
+            MessageType::CONFIG_KEY_EVENT => 231,
+            MessageType::CONFIG_REQUEST_EVENT => 232,
+            MessageType::CONFIG_BEGIN_EVENT => 233,
+            MessageType::CONFIG_END_EVENT => 234,
+            MessageType::CONFIG_SERVICE_EVENT => 235,
+            MessageType::CONFIG_SKILL_GROUP_EVENT => 236,
+            MessageType::CONFIG_AGENT_EVENT => 237,
+            MessageType::CONFIG_DEVICE_EVENT => 238,
+            MessageType::RESERVED239 => 239,
+            MessageType::RESERVED240 => 240,
+            MessageType::RESERVED241 => 241,
+            MessageType::TEAM_CONFIG_REQ => 242,
+            MessageType::TEAM_CONFIG_EVENT => 243,
+            MessageType::TEAM_CONFIG_CONF => 244,
+            MessageType::CONFIG_CALL_TYPE_EVENT => 245,
+            MessageType::RESERVED246 => 246,
+            MessageType::RESERVED247 => 247,
+            MessageType::CALL_AGENT_GREETING => 248,
+            MessageType::AGENT_GREETING_CONTROL_REQ => 249,
+            MessageType::AGENT_GREETING_CONTROL_RESP => 250,
+            MessageType::RESERVED251 => 251,
+            MessageType::RESERVED252 => 252,
+            MessageType::RESERVED253 => 253,
+            MessageType::CONFIG_MRD_EVENT => 254,
+            MessageType::GET_AGENT_TASKS_REQ => 255,
+            MessageType::AGENT_TASKS_RESP => 256,
+            MessageType::SNAPSHOT_TASK_REQ => 257,
+            MessageType::SNAPSHOT_TASK_RESP => 258,
+            MessageType::RESERVED259 => 259,
+            MessageType::CONFIG_PERIPHERAL_EVENT => 260,
+            MessageType::CONFIG_AGENT_DESK_SETTINGS_EVENT => 261,
+            MessageType::AGENT_TASKS_EVENT => 262,
+            MessageType::SNAPSHOT_TASKS_EVENT => 263,
+            MessageType::AGENT_TASKS_REQUEST_EVENT => 264,
+            MessageType::AGENT_TASKS_END_EVENT => 265,
+            MessageType::DESKTOP_CONNECTED_IND => 266,
+            MessageType::START_NETWORK_RECORDING_REQ => 267,
+            MessageType::START_NETWORK_RECORDING_CONF => 268,
+            MessageType::STOP_NETWORK_RECORDING_REQ => 269,
+            MessageType::STOP_NETWORK_RECORDING_CONF => 270,
+            MessageType::NETWORK_RECORDING_STARTED_EVENT => 271,
+            MessageType::NETWORK_RECORDING_ENDED_EVENT => 272,
+            MessageType::NETWORK_RECORDING_FAILED_EVENT => 273,
+            MessageType::NETWORK_RECORDING_TARGET_INFO_EVENT => 274,
+            MessageType::RESERVED275 => 275,
+            MessageType::RESERVED276 => 276,
+            MessageType::STANDBY_ACTIVE_EVENT_MSG => 277,
+            MessageType::ACTIVE_MAINTENANCE_REQ_MSG => 278,
+            MessageType::ACTIVE_MAINTENANCE_RESP_MSG => 279,
+            MessageType::ACTIVE_MAINTENANCE_EVENT_MSG => 280,
+            MessageType::STOPPING_REQUESTS_TO_THIS_SIDE_END => 281,
+            MessageType::CONFIG_AGENT_SERVICE_EVENT => 282,
+            MessageType::UNKNOWN(n) => n,
         }
     }
 }
 
 impl Serializable for MessageType {
     fn serialize(self) -> Vec<u8> {
-        (self as u32).serialize()
+        u32::from(self).serialize()
     }
 }
 
@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use super::{Deserializable, MessageType, Serializable};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize)]
 ///
 /// Cisco CTI 프로토콜 메시지 헤더
 ///
@@ -1,17 +1,38 @@
+pub mod call_type;
 pub mod client_event;
+pub mod codec;
+pub mod config;
 pub mod control;
 pub mod deserializable;
+pub mod direction;
+pub mod error;
 pub mod floating_field;
+pub mod identifier;
 pub mod message_type;
 pub mod mhdr;
+pub mod peripheral_type;
+pub mod pretty;
+pub mod repeated_group;
 pub mod serializable;
 pub mod session;
+pub mod skill_group_assignment;
 pub mod supervisor;
 pub mod tag_values;
+pub mod timestamp;
 
-pub use deserializable::Deserializable;
+pub use call_type::CallType;
+pub use deserializable::{
+    preserve_unknown_field, CtiMessage, Deserializable, FixedString, FloatingFieldValue,
+};
+pub use direction::Direction;
+pub use error::{BuildError, DecodeError};
 pub use floating_field::FloatingField;
+pub use identifier::{ConnectionCallId, InvokeId, InvokeIdGenerator, MonitorId, PeripheralId};
 pub use message_type::MessageType;
 pub use mhdr::MHDR;
+pub use peripheral_type::PeripheralType;
+pub use repeated_group::RepeatedGroups;
 pub use serializable::Serializable;
-pub use tag_values::TagValue;
+pub use skill_group_assignment::SkillGroupAssignment;
+pub use tag_values::{TagDataType, TagValue};
+pub use timestamp::CtiTimestamp;
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Deserializable, Serializable};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Cisco CTI 프로토콜 주변장치(PG) 유형
+///
+pub enum PeripheralType {
+    ASPECT,
+    MERIDIAN,
+    G2,
+    DEFINITY_ECS_NON_EAS,
+    DEFINITY_ECS_EAS,
+    GALAXY,
+    SPECTRUM,
+    VRU,
+    VRU_POLLING,
+    DMS_100,
+    SIEMENS_9005,
+    SIEMENS_9006,
+    ALCATEL,
+    NEC_NEAX_2X00,
+    ACP_1000,
+    SYMPOSIUM,
+    ENTERPRISE_AGENT,
+    MD110,
+    MEDIA_ROUTING,
+    GENERIC,
+    ACMI_CRS,
+    ACMI_IPCC,
+    SIMPLIFIED_IPCC,
+    ARS,
+    ACMI_ERS,
+    ACMI_EXPERT_ADVISOR,
+    NONE,
+    // 정의되지 않은 주변장치 유형은 원래 값을 보존한다
+    UNKNOWN(u16),
+}
+
+impl From<u16> for PeripheralType {
+    fn from(value: u16) -> Self {
+        match value {
+            1 => PeripheralType::ASPECT,
+            2 => PeripheralType::MERIDIAN,
+            3 => PeripheralType::G2,
+            4 => PeripheralType::DEFINITY_ECS_NON_EAS,
+            5 => PeripheralType::DEFINITY_ECS_EAS,
+            6 => PeripheralType::GALAXY,
+            7 => PeripheralType::SPECTRUM,
+            8 => PeripheralType::VRU,
+            9 => PeripheralType::VRU_POLLING,
+            10 => PeripheralType::DMS_100,
+            11 => PeripheralType::SIEMENS_9005,
+            12 => PeripheralType::SIEMENS_9006,
+            13 => PeripheralType::ALCATEL,
+            14 => PeripheralType::NEC_NEAX_2X00,
+            15 => PeripheralType::ACP_1000,
+            16 => PeripheralType::SYMPOSIUM,
+            17 => PeripheralType::ENTERPRISE_AGENT,
+            18 => PeripheralType::MD110,
+            19 => PeripheralType::MEDIA_ROUTING,
+            20 => PeripheralType::GENERIC,
+            21 => PeripheralType::ACMI_CRS,
+            22 => PeripheralType::ACMI_IPCC,
+            23 => PeripheralType::SIMPLIFIED_IPCC,
+            24 => PeripheralType::ARS,
+            25 => PeripheralType::ACMI_ERS,
+            26 => PeripheralType::ACMI_EXPERT_ADVISOR,
+            0xFFFF => PeripheralType::NONE,
+            n => PeripheralType::UNKNOWN(n),
+        }
+    }
+}
+
+impl From<PeripheralType> for u16 {
+    fn from(value: PeripheralType) -> Self {
+        match value {
+            PeripheralType::ASPECT => 1,
+            PeripheralType::MERIDIAN => 2,
+            PeripheralType::G2 => 3,
+            PeripheralType::DEFINITY_ECS_NON_EAS => 4,
+            PeripheralType::DEFINITY_ECS_EAS => 5,
+            PeripheralType::GALAXY => 6,
+            PeripheralType::SPECTRUM => 7,
+            PeripheralType::VRU => 8,
+            PeripheralType::VRU_POLLING => 9,
+            PeripheralType::DMS_100 => 10,
+            PeripheralType::SIEMENS_9005 => 11,
+            PeripheralType::SIEMENS_9006 => 12,
+            PeripheralType::ALCATEL => 13,
+            PeripheralType::NEC_NEAX_2X00 => 14,
+            PeripheralType::ACP_1000 => 15,
+            PeripheralType::SYMPOSIUM => 16,
+            PeripheralType::ENTERPRISE_AGENT => 17,
+            PeripheralType::MD110 => 18,
+            PeripheralType::MEDIA_ROUTING => 19,
+            PeripheralType::GENERIC => 20,
+            PeripheralType::ACMI_CRS => 21,
+            PeripheralType::ACMI_IPCC => 22,
+            PeripheralType::SIMPLIFIED_IPCC => 23,
+            PeripheralType::ARS => 24,
+            PeripheralType::ACMI_ERS => 25,
+            PeripheralType::ACMI_EXPERT_ADVISOR => 26,
+            PeripheralType::NONE => 0xFFFF,
+            PeripheralType::UNKNOWN(n) => n,
+        }
+    }
+}
+
+impl Serializable for PeripheralType {
+    fn serialize(self) -> Vec<u8> {
+        u16::from(self).serialize()
+    }
+}
+
+impl Deserializable for PeripheralType {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (buffer, result) = <u16 as Deserializable>::deserialize(buffer);
+        (buffer, result.into())
+    }
+}
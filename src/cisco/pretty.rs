@@ -0,0 +1,325 @@
+use std::fmt::Write;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{
+    client_event::{
+        agent_state_event::AgentStateEvent, begin_call_event::BeginCallEvent,
+        call_cleared_event::CallClearedEvent, call_established_event::CallEstablishedEvent,
+        call_queued_event::CallQueuedEvent,
+    },
+    codec::{NetworkByteOrder, MHDR_LENGTH},
+    config::config_agent_event::ConfigAgentEvent,
+    control::{
+        query_agent_state_conf::QueryAgentStateConf, query_agent_state_req::QueryAgentStateReq,
+    },
+    session::{
+        change_monitor_mask_conf::ChangeMonitorMaskConf,
+        change_monitor_mask_req::ChangeMonitorMaskReq, OpenConf,
+    },
+    supervisor::{
+        agent_team_config_event::AgentTeamConfigEvent, emergency_call_event::EmergencyCallEvent,
+    },
+    CtiMessage, CtiTimestamp, Deserializable, MessageType, TagDataType, TagValue,
+};
+
+///
+/// 상담직원 상태 값을 가지는 필드 이름
+///
+const AGENT_STATE_FIELDS: [&str; 3] = ["agent_state", "next_agent_state", "skill_group_state"];
+
+///
+/// 상담직원 상태 이름을 반환한다
+///
+pub fn agent_state_name(agent_state: u16) -> &'static str {
+    match agent_state {
+        0 => "LOGIN",
+        1 => "LOGOUT",
+        2 => "NOT_READY",
+        3 => "AVAILABLE",
+        4 => "TALKING",
+        5 => "WORK_NOT_READY",
+        6 => "WORK_READY",
+        7 => "BUSY_OTHER",
+        8 => "RESERVED",
+        9 => "UNKNOWN",
+        10 => "HOLD",
+        11 => "ACTIVE",
+        12 => "PAUSED",
+        13 => "INTERRUPTED",
+        14 => "NOT_ACTIVE",
+        _ => "?",
+    }
+}
+
+///
+/// 오프셋, 16진수, ASCII 형식의 hex dump 문자열을 반환한다
+///
+pub fn hex_dump(data: &[u8]) -> String {
+    let mut result = String::new();
+
+    for (index, chunk) in data.chunks(16).enumerate() {
+        let hex = chunk
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let ascii = chunk
+            .iter()
+            .map(|&b| match b {
+                0x20..=0x7E => b as char,
+                _ => '.',
+            })
+            .collect::<String>();
+
+        let _ = writeln!(result, "{:04x}  {:<47}  {}", index * 16, hex, ascii);
+    }
+
+    result
+}
+
+///
+/// CTI 메시지를 사람이 읽을 수 있는 형식으로 변환한다
+///
+/// 역직렬화할 수 있는 메시지는 필드별로, 그 외의 메시지는 hex dump 로 출력한다.
+///
+pub fn format_message(message_type: &MessageType, data: &[u8]) -> String {
+    let mut result = format!("{:?} (length: {})\n", message_type, data.len());
+
+    match decode_message(message_type, data) {
+        Some(Ok(Value::Object(fields))) => {
+            for (name, value) in fields.iter().filter(|(name, _)| *name != "mhdr") {
+                write_field(&mut result, 1, name, value);
+            }
+        }
+        Some(Ok(_)) => {}
+        Some(Err(e)) => {
+            let _ = writeln!(result, "  decode error: {}", e);
+            result.push_str(&indent(&hex_dump(data), 1));
+        }
+        None => result.push_str(&indent(&hex_dump(data), 1)),
+    }
+
+    result
+}
+
+///
+/// CTI 메시지를 JSON 값으로 역직렬화한다 (MHDR 포함)
+///
+/// 역직렬화를 지원하지 않는 메시지 유형은 None 을 반환한다.
+///
+pub fn decode_message(message_type: &MessageType, data: &[u8]) -> Option<Result<Value, String>> {
+    match message_type {
+        MessageType::OPEN_CONF => Some(decode::<OpenConf>(data)),
+        MessageType::CHANGE_MONITOR_MASK_REQ => Some(decode::<ChangeMonitorMaskReq>(data)),
+        MessageType::CHANGE_MONITOR_MASK_CONF => Some(decode::<ChangeMonitorMaskConf>(data)),
+        MessageType::QUERY_AGENT_STATE_REQ => Some(decode::<QueryAgentStateReq>(data)),
+        MessageType::QUERY_AGENT_STATE_CONF => Some(decode::<QueryAgentStateConf>(data)),
+        MessageType::AGENT_STATE_EVENT => Some(decode::<AgentStateEvent>(data)),
+        MessageType::AGENT_TEAM_CONFIG_EVENT => Some(decode::<AgentTeamConfigEvent>(data)),
+        MessageType::CONFIG_AGENT_EVENT => Some(decode::<ConfigAgentEvent>(data)),
+        MessageType::BEGIN_CALL_EVENT => Some(decode::<BeginCallEvent>(data)),
+        MessageType::CALL_QUEUED_EVENT => Some(decode::<CallQueuedEvent>(data)),
+        MessageType::CALL_ESTABLISHED_EVENT => Some(decode::<CallEstablishedEvent>(data)),
+        MessageType::CALL_CLEARED_EVENT => Some(decode::<CallClearedEvent>(data)),
+        MessageType::EMERGENCY_CALL_EVENT | MessageType::SUPERVISOR_ASSIST_EVENT => {
+            Some(decode::<EmergencyCallEvent>(data))
+        }
+        _ => None,
+    }
+}
+
+///
+/// 연속된 CTI 메시지 바이트를 MHDR 길이 기준으로 나누어 차례로 변환한다
+///
+/// 메시지 길이보다 짧게 잘린 데이터나 남은 바이트는 hex dump 로 출력한다.
+///
+pub fn format_messages(data: &[u8]) -> String {
+    let mut result = String::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        let remaining = &data[offset..];
+        if remaining.len() < MHDR_LENGTH {
+            let _ = writeln!(result, "trailing bytes (length: {})", remaining.len());
+            result.push_str(&indent(&hex_dump(remaining), 1));
+            break;
+        }
+
+        let length = u32::from_network_bytes(&remaining[0..4]) as usize;
+        let message_type = MessageType::from(u32::from_network_bytes(&remaining[4..8]));
+        if remaining.len() < MHDR_LENGTH + length {
+            let _ = writeln!(
+                result,
+                "{:?} truncated (expected: {}, received: {})",
+                message_type,
+                MHDR_LENGTH + length,
+                remaining.len()
+            );
+            result.push_str(&indent(&hex_dump(remaining), 1));
+            break;
+        }
+
+        result.push_str(&format_message(
+            &message_type,
+            &remaining[..MHDR_LENGTH + length],
+        ));
+        offset += MHDR_LENGTH + length;
+    }
+
+    result
+}
+
+///
+/// 16진수 문자열을 바이트로 변환한다
+///
+/// `#` 뒤의 주석과 공백, `0x` 접두어는 무시하며, 16진수가 아니거나 자릿수가 홀수이면 None 을 반환한다.
+///
+pub fn parse_hex(text: &str) -> Option<Vec<u8>> {
+    let hex = text
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or(""))
+        .flat_map(|line| line.split_whitespace())
+        .map(|word| word.trim_start_matches("0x").trim_start_matches("0X"))
+        .collect::<String>();
+
+    if hex.len() % 2 != 0 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+///
+/// 메시지를 역직렬화하여 JSON 값으로 변환한다
+///
+fn decode<T>(data: &[u8]) -> Result<Value, String>
+where
+    T: CtiMessage + Serialize,
+{
+    let message = T::try_deserialize(&mut data.to_vec()).map_err(|e| e.to_string())?;
+
+    serde_json::to_value(message).map_err(|e| e.to_string())
+}
+
+///
+/// 필드 한 개를 출력한다
+///
+fn write_field(result: &mut String, depth: usize, name: &str, value: &Value) {
+    let padding = "  ".repeat(depth);
+
+    match value {
+        // 가변 필드는 태그 이름과 함께 출력한다
+        Value::Object(field) if field.contains_key("tag") && field.contains_key("data") => {
+            let tag = field["tag"].as_str().unwrap_or("?");
+            let _ = writeln!(
+                result,
+                "{}{} [{}]: {}",
+                padding,
+                name,
+                tag,
+                format_value(name, &field["data"])
+            );
+        }
+        // 처리되지 않은 가변 필드는 hex dump 로 출력한다
+        Value::Array(fields) if name == "unknown_fields" => {
+            for field in fields {
+                let tag = field["tag"].as_str().unwrap_or("?");
+                let data = field["data"]
+                    .as_array()
+                    .map(|data| {
+                        data.iter()
+                            .filter_map(|b| b.as_u64().map(|b| b as u8))
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+
+                // 태그의 데이터 유형을 알면 값으로, 모르면 hex dump 로 출력한다
+                match decode_field(tag, &data) {
+                    Some(value) => {
+                        let _ = writeln!(result, "{}unknown [{}]: {}", padding, tag, value);
+                    }
+                    None => {
+                        let _ = writeln!(
+                            result,
+                            "{}unknown [{}]: length: {}",
+                            padding,
+                            tag,
+                            data.len()
+                        );
+                        result.push_str(&indent(&hex_dump(&data), depth + 1));
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            let _ = writeln!(result, "{}{}: ({})", padding, name, items.len());
+            for (index, item) in items.iter().enumerate() {
+                match item {
+                    Value::Object(fields) => {
+                        let _ = writeln!(result, "{}  [{}]", padding, index);
+                        for (name, value) in fields {
+                            write_field(result, depth + 2, name, value);
+                        }
+                    }
+                    item => write_field(result, depth + 1, &format!("[{}]", index), item),
+                }
+            }
+        }
+        value => {
+            let _ = writeln!(result, "{}{}: {}", padding, name, format_value(name, value));
+        }
+    }
+}
+
+///
+/// 값을 출력하며, 상담직원 상태 필드에는 상태 이름을 덧붙인다
+///
+fn format_value(name: &str, value: &Value) -> String {
+    match value.as_u64() {
+        Some(state) if AGENT_STATE_FIELDS.contains(&name) => {
+            format!("{} ({})", state, agent_state_name(state as u16))
+        }
+        _ => value.to_string(),
+    }
+}
+
+///
+/// 태그의 데이터 유형으로 처리되지 않은 가변 필드 값을 해석한다
+///
+fn decode_field(tag: &str, data: &[u8]) -> Option<String> {
+    let data_type = TagValue::from_name(tag)?.data_type();
+    let mut data = data.to_vec();
+
+    match (data_type, data.len()) {
+        (TagDataType::STRING, _) => Some(format!("{:?}", String::deserialize(&mut data).1)),
+        (TagDataType::UCHAR, 1) => Some(data[0].to_string()),
+        (TagDataType::BOOL, 2) => Some((u16::deserialize(&mut data).1 != 0).to_string()),
+        (TagDataType::USHORT, 2) => Some(u16::deserialize(&mut data).1.to_string()),
+        (TagDataType::SHORT, 2) => Some(i16::deserialize(&mut data).1.to_string()),
+        (TagDataType::UINT, 4) => Some(u32::deserialize(&mut data).1.to_string()),
+        (TagDataType::INT, 4) => Some(i32::deserialize(&mut data).1.to_string()),
+        (TagDataType::TIME, 4) => Some(
+            CtiTimestamp::deserialize(&mut data)
+                .1
+                .to_date_time()
+                .to_rfc3339(),
+        ),
+        _ => None,
+    }
+}
+
+///
+/// 여러 줄 문자열에 들여쓰기를 추가한다
+///
+fn indent(text: &str, depth: usize) -> String {
+    let padding = "  ".repeat(depth);
+
+    text.lines()
+        .map(|line| format!("{}{}\n", padding, line))
+        .collect()
+}
@@ -0,0 +1,46 @@
+use super::TagValue;
+
+#[derive(Debug)]
+///
+/// 반복되는 가변 필드 묶음 목록 (팀 구성 상담직원, 스킬그룹 등)
+///
+/// start_tag 가 나오면 새 묶음을 시작하고, 그 밖의 태그는 마지막 묶음에 반영한다.
+/// 묶음 안의 태그는 순서와 관계없이 반영하며, 빠진 태그는 기본값으로 남는다.
+/// 시작 태그 없이 나온 태그는 빈 묶음을 새로 만들어 반영한다.
+///
+pub struct RepeatedGroups<T> {
+    start_tag: TagValue,
+    groups: Vec<T>,
+}
+
+impl<T: Default> RepeatedGroups<T> {
+    pub fn new(start_tag: TagValue) -> Self {
+        Self {
+            start_tag,
+            groups: vec![],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    ///
+    /// 태그 값을 반영할 묶음을 반환한다
+    ///
+    pub fn group(&mut self, tag: TagValue) -> &mut T {
+        if tag == self.start_tag || self.groups.is_empty() {
+            self.groups.push(T::default());
+        }
+
+        self.groups.last_mut().unwrap()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.groups
+    }
+}
@@ -1,3 +1,8 @@
+use super::{
+    codec::NetworkByteOrder,
+    deserializable::{string_encoding, FixedString},
+};
+
 ///
 /// 직렬화 트레잇
 ///
@@ -8,74 +13,72 @@ pub trait Serializable {
     fn serialize(self) -> Vec<u8>;
 }
 
-impl Serializable for u8 {
+impl Serializable for bool {
     fn serialize(self) -> Vec<u8> {
-        vec![self]
+        (self as u16).serialize()
     }
 }
 
-impl Serializable for i16 {
-    fn serialize(self) -> Vec<u8> {
-        let mut result = vec![0_u8; 0];
+///
+/// 숫자 타입은 네트워크 바이트 순서로 직렬화한다
+///
+macro_rules! impl_serializable_number {
+    ($($t:ty),*) => {
+        $(
+            impl Serializable for $t {
+                fn serialize(self) -> Vec<u8> {
+                    self.to_network_bytes()
+                }
+            }
+        )*
+    };
+}
 
-        for i in 0..2 {
-            result.append(&mut ((self >> i * 8) as u8 & 0xFF).serialize());
-        }
+impl_serializable_number!(u8, i8, i16, u16, i32, u32, i64, u64);
 
-        result.reverse();
-        result
+impl Serializable for f32 {
+    fn serialize(self) -> Vec<u8> {
+        self.to_bits().serialize()
     }
 }
 
-impl Serializable for u16 {
+impl Serializable for String {
     fn serialize(self) -> Vec<u8> {
         let mut result = vec![0_u8; 0];
 
-        for i in 0..2 {
-            result.append(&mut ((self >> i * 8) as u8 & 0xFF).serialize());
+        // CTI 서버 문자열 인코딩으로 변환한다
+        let (bytes, _, _) = string_encoding().encode(&self);
+        for b in bytes.iter() {
+            result.push(*b);
         }
+        result.push(0);
 
-        result.reverse();
         result
     }
 }
 
-impl Serializable for i32 {
+impl<const N: usize> Serializable for FixedString<N> {
     fn serialize(self) -> Vec<u8> {
-        let mut result = vec![0_u8; 0];
+        let mut result = vec![0_u8; N];
 
-        for i in 0..4 {
-            result.append(&mut ((self >> i * 8) as u8 & 0xFF).serialize());
-        }
+        // N 바이트를 넘는 부분은 자르고, 남는 자리는 NUL 로 채운다
+        let (bytes, _, _) = string_encoding().encode(&self.0);
+        let length = bytes.len().min(N);
+        result[0..length].copy_from_slice(&bytes[0..length]);
 
-        result.reverse();
         result
     }
 }
 
-impl Serializable for u32 {
+impl<const N: usize> Serializable for [u8; N] {
     fn serialize(self) -> Vec<u8> {
-        let mut result = vec![0_u8; 0];
-
-        for i in 0..4 {
-            result.append(&mut ((self >> i * 8) as u8 & 0xFF).serialize());
-        }
-
-        result.reverse();
-        result
+        self.to_vec()
     }
 }
 
-impl Serializable for String {
+impl Serializable for Vec<u8> {
     fn serialize(self) -> Vec<u8> {
-        let mut result = vec![0_u8; 0];
-
-        for b in self.as_bytes() {
-            result.push(*b);
-        }
-        result.push(0);
-
-        result
+        self
     }
 }
 
@@ -0,0 +1,47 @@
+use serde::Serialize;
+
+use crate::cisco::{
+    CtiMessage, DecodeError, Deserializable, InvokeId, MessageType, Serializable, MHDR,
+};
+
+#[allow(unused)]
+#[derive(Debug, Serialize)]
+///
+/// Cisco CTI 프로토콜 CHANGE_MONITOR_MASK_CONF 메시지
+///
+pub struct ChangeMonitorMaskConf {
+    pub mhdr: MHDR,
+    pub invoke_id: InvokeId,
+}
+
+impl Serializable for ChangeMonitorMaskConf {
+    fn serialize(self) -> Vec<u8> {
+        let mut result = MHDR {
+            length: 4,
+            message_type: MessageType::CHANGE_MONITOR_MASK_CONF,
+        }
+        .serialize();
+        result.append(&mut self.invoke_id.serialize());
+
+        result
+    }
+}
+
+impl CtiMessage for ChangeMonitorMaskConf {
+    const FIXED_PART_LENGTH: usize = 12;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let (_, result) = Self::deserialize(buffer);
+
+        Ok(result)
+    }
+}
+
+impl Deserializable for ChangeMonitorMaskConf {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (mut buffer, mhdr) = MHDR::deserialize(buffer);
+        let (buffer, invoke_id) = InvokeId::deserialize(&mut buffer);
+
+        (buffer, Self { mhdr, invoke_id })
+    }
+}
@@ -0,0 +1,78 @@
+use serde::Serialize;
+
+use crate::cisco::{
+    session::CallMessageMask, CtiMessage, DecodeError, Deserializable, InvokeId, MessageType,
+    MonitorId, Serializable, MHDR,
+};
+
+#[allow(unused)]
+#[derive(Debug, Clone, Serialize)]
+///
+/// Cisco CTI 프로토콜 CHANGE_MONITOR_MASK_REQ 메시지
+///
+/// OPEN_CONF 로 받은 MonitorID 의 호 이벤트 마스크를 바꾼다.
+///
+pub struct ChangeMonitorMaskReq {
+    pub mhdr: MHDR,
+    pub invoke_id: InvokeId,
+    pub monitor_id: MonitorId,
+    pub call_msg_mask: u32,
+}
+
+impl ChangeMonitorMaskReq {
+    pub fn new(invoke_id: InvokeId, monitor_id: MonitorId, call_msg_mask: CallMessageMask) -> Self {
+        Self {
+            mhdr: MHDR {
+                length: 12,
+                message_type: MessageType::CHANGE_MONITOR_MASK_REQ,
+            },
+            invoke_id,
+            monitor_id,
+            call_msg_mask: call_msg_mask.bits(),
+        }
+    }
+}
+
+impl Serializable for ChangeMonitorMaskReq {
+    fn serialize(self) -> Vec<u8> {
+        let mut result = MHDR {
+            length: 12,
+            message_type: MessageType::CHANGE_MONITOR_MASK_REQ,
+        }
+        .serialize();
+        result.append(&mut self.invoke_id.serialize());
+        result.append(&mut self.monitor_id.serialize());
+        result.append(&mut self.call_msg_mask.serialize());
+
+        result
+    }
+}
+
+impl CtiMessage for ChangeMonitorMaskReq {
+    const FIXED_PART_LENGTH: usize = 20;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let (_, result) = Self::deserialize(buffer);
+
+        Ok(result)
+    }
+}
+
+impl Deserializable for ChangeMonitorMaskReq {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (mut buffer, mhdr) = MHDR::deserialize(buffer);
+        let (mut buffer, invoke_id) = InvokeId::deserialize(&mut buffer);
+        let (mut buffer, monitor_id) = MonitorId::deserialize(&mut buffer);
+        let (buffer, call_msg_mask) = u32::deserialize(&mut buffer);
+
+        (
+            buffer,
+            Self {
+                mhdr,
+                invoke_id,
+                monitor_id,
+                call_msg_mask,
+            },
+        )
+    }
+}
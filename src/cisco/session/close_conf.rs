@@ -0,0 +1,21 @@
+use crate::cisco::{InvokeId, Serializable, MHDR};
+
+#[allow(unused)]
+#[derive(Debug)]
+pub struct CloseConf {
+    pub mhdr: MHDR,
+    pub invoke_id: InvokeId,
+}
+
+impl Serializable for CloseConf {
+    fn serialize(self) -> Vec<u8> {
+        let mut result = MHDR {
+            length: 4,
+            message_type: crate::cisco::MessageType::CLOSE_CONF,
+        }
+        .serialize();
+        result.append(&mut self.invoke_id.serialize());
+
+        result
+    }
+}
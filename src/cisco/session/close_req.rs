@@ -0,0 +1,28 @@
+use crate::cisco::{InvokeId, Serializable, MHDR};
+
+///
+/// 세션 종료 상태 코드 (E_CTI_NO_ERROR)
+///
+pub const CLOSE_STATUS_NO_ERROR: u32 = 0;
+
+#[allow(unused)]
+#[derive(Debug)]
+pub struct CloseReq {
+    pub mhdr: MHDR,
+    pub invoke_id: InvokeId,
+    pub status: u32,
+}
+
+impl Serializable for CloseReq {
+    fn serialize(self) -> Vec<u8> {
+        let mut result = MHDR {
+            length: 8,
+            message_type: crate::cisco::MessageType::CLOSE_REQ,
+        }
+        .serialize();
+        result.append(&mut self.invoke_id.serialize());
+        result.append(&mut self.status.serialize());
+
+        result
+    }
+}
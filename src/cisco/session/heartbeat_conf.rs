@@ -0,0 +1,21 @@
+use crate::cisco::{InvokeId, Serializable, MHDR};
+
+#[allow(unused)]
+#[derive(Debug)]
+pub struct HeartBeatConf {
+    pub mhdr: MHDR,
+    pub invoke_id: InvokeId,
+}
+
+impl Serializable for HeartBeatConf {
+    fn serialize(self) -> Vec<u8> {
+        let mut result = MHDR {
+            length: 4,
+            message_type: crate::cisco::MessageType::HEARTBEAT_CONF,
+        }
+        .serialize();
+        result.append(&mut self.invoke_id.serialize());
+
+        result
+    }
+}
@@ -1,10 +1,10 @@
-use crate::cisco::{Serializable, MHDR};
+use crate::cisco::{InvokeId, Serializable, MHDR};
 
 #[allow(unused)]
 #[derive(Debug)]
 pub struct HeartBeatReq {
     pub mhdr: MHDR,
-    pub invoke_id: u32,
+    pub invoke_id: InvokeId,
 }
 
 impl Serializable for HeartBeatReq {
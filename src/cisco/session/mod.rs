@@ -1,6 +1,13 @@
+pub mod change_monitor_mask_conf;
+pub mod change_monitor_mask_req;
+pub mod close_conf;
+pub mod close_req;
+pub mod heartbeat_conf;
 pub mod heartbeat_req;
 pub mod open_conf;
 pub mod open_req;
+pub mod service_mask;
 
 pub use open_conf::OpenConf;
 pub use open_req::OpenReq;
+pub use service_mask::{AgentStateMask, CallMessageMask, ServicesRequested};
@@ -1,40 +1,48 @@
-use crate::cisco::{Deserializable, FloatingField, TagValue, MHDR};
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, CtiMessage, CtiTimestamp, DecodeError, Deserializable, FloatingField,
+    InvokeId, MessageType, MonitorId, PeripheralId, PeripheralType, Serializable, TagValue, MHDR,
+};
 
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 ///
 /// Cisco CTI 프로토콜 OPEN_CONF 메시지
 ///
 pub struct OpenConf {
-    mhdr: MHDR,
-    invoke_id: u32,
-    service_granted: u32,
-    monitor_id: u32,
-    pg_status: u32,
-    icm_central_controller_time: u32,
-    peripheral_online: bool,
-    peripheral_type: u16,
-    agent_state: u16,
-    department_id: i32,
-    session_type: u16,
-    agent_extension: Option<FloatingField<String>>,
-    agent_id: Option<FloatingField<String>>,
-    agent_instrument: Option<FloatingField<String>>,
-    num_peripherals: Option<FloatingField<u16>>,
-    flt_peripheral_id: Option<FloatingField<u32>>,
-    multiline_agent_control: Option<FloatingField<u16>>,
+    pub mhdr: MHDR,
+    pub invoke_id: InvokeId,
+    pub service_granted: u32,
+    pub monitor_id: MonitorId,
+    pub pg_status: u32,
+    pub icm_central_controller_time: CtiTimestamp,
+    pub peripheral_online: bool,
+    pub peripheral_type: PeripheralType,
+    pub agent_state: u16,
+    pub department_id: i32,
+    pub session_type: u16,
+    pub agent_extension: Option<FloatingField<String>>,
+    pub agent_id: Option<FloatingField<String>>,
+    pub agent_instrument: Option<FloatingField<String>>,
+    pub num_peripherals: Option<FloatingField<u16>>,
+    pub flt_peripheral_id: Option<FloatingField<PeripheralId>>,
+    pub multiline_agent_control: Option<FloatingField<u16>>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
 }
 
-impl Deserializable for OpenConf {
-    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+impl CtiMessage for OpenConf {
+    const FIXED_PART_LENGTH: usize = 40;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
         let (mut buffer, mhdr) = MHDR::deserialize(buffer);
-        let (mut buffer, invoke_id) = u32::deserialize(&mut buffer);
+        let (mut buffer, invoke_id) = InvokeId::deserialize(&mut buffer);
         let (mut buffer, service_granted) = u32::deserialize(&mut buffer);
-        let (mut buffer, monitor_id) = u32::deserialize(&mut buffer);
+        let (mut buffer, monitor_id) = MonitorId::deserialize(&mut buffer);
         let (mut buffer, pg_status) = u32::deserialize(&mut buffer);
-        let (mut buffer, icm_central_controller_time) = u32::deserialize(&mut buffer);
+        let (mut buffer, icm_central_controller_time) = CtiTimestamp::deserialize(&mut buffer);
         let (mut buffer, peripheral_online) = bool::deserialize(&mut buffer);
-        let (mut buffer, peripheral_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, peripheral_type) = PeripheralType::deserialize(&mut buffer);
         let (mut buffer, agent_state) = u16::deserialize(&mut buffer);
         let (mut buffer, department_id) = i32::deserialize(&mut buffer);
         let (mut buffer, session_type) = u16::deserialize(&mut buffer);
@@ -43,8 +51,9 @@ impl Deserializable for OpenConf {
         let mut agent_id: Option<FloatingField<String>> = None;
         let mut agent_instrument: Option<FloatingField<String>> = None;
         let mut num_peripherals: Option<FloatingField<u16>> = None;
-        let mut flt_peripheral_id: Option<FloatingField<u32>> = None;
+        let mut flt_peripheral_id: Option<FloatingField<PeripheralId>> = None;
         let mut multiline_agent_control: Option<FloatingField<u16>> = None;
+        let mut unknown_fields = vec![];
 
         loop {
             let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
@@ -53,9 +62,9 @@ impl Deserializable for OpenConf {
                     buffer = field.data;
                     continue;
                 }
-                Some(mut field) => match field.tag {
+                Some(field) => match field.tag {
                     TagValue::AGENT_EXTENSION_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_extension = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -64,7 +73,7 @@ impl Deserializable for OpenConf {
                         buffer = sub_buffer;
                     }
                     TagValue::AGENT_ID_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_id = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -73,7 +82,7 @@ impl Deserializable for OpenConf {
                         buffer = sub_buffer;
                     }
                     TagValue::AGENT_INSTRUMENT_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_instrument = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -82,7 +91,7 @@ impl Deserializable for OpenConf {
                         buffer = sub_buffer;
                     }
                     TagValue::NUM_PERIPHERALS_TAG => {
-                        let (sub_buffer, sub_result) = u16::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
                         num_peripherals = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -91,7 +100,7 @@ impl Deserializable for OpenConf {
                         buffer = sub_buffer;
                     }
                     TagValue::PERIPHERAL_ID_TAG_V11 => {
-                        let (sub_buffer, sub_result) = u32::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<PeripheralId>()?;
                         flt_peripheral_id = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -100,7 +109,7 @@ impl Deserializable for OpenConf {
                         buffer = sub_buffer;
                     }
                     TagValue::MULTI_LINE_AGENT_CONTROL_TAG => {
-                        let (sub_buffer, sub_result) = u16::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
                         multiline_agent_control = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -108,33 +117,67 @@ impl Deserializable for OpenConf {
                         });
                         buffer = sub_buffer;
                     }
-                    _ => {}
+                    _ => {
+                        buffer = preserve_unknown_field(&mut unknown_fields, &field);
+                    }
                 },
                 None => break,
             }
         }
 
-        (
-            buffer,
-            Self {
-                mhdr,
-                invoke_id,
-                service_granted,
-                monitor_id,
-                pg_status,
-                icm_central_controller_time,
-                peripheral_online,
-                peripheral_type,
-                agent_state,
-                department_id,
-                session_type,
-                agent_extension,
-                agent_id,
-                agent_instrument,
-                num_peripherals,
-                flt_peripheral_id,
-                multiline_agent_control,
-            },
-        )
+        Ok(Self {
+            mhdr,
+            invoke_id,
+            service_granted,
+            monitor_id,
+            pg_status,
+            icm_central_controller_time,
+            peripheral_online,
+            peripheral_type,
+            agent_state,
+            department_id,
+            session_type,
+            agent_extension,
+            agent_id,
+            agent_instrument,
+            num_peripherals,
+            flt_peripheral_id,
+            multiline_agent_control,
+            unknown_fields,
+        })
+    }
+}
+
+impl Serializable for OpenConf {
+    fn serialize(self) -> Vec<u8> {
+        let mut buffer = self.invoke_id.serialize();
+        buffer.append(&mut self.service_granted.serialize());
+        buffer.append(&mut self.monitor_id.serialize());
+        buffer.append(&mut self.pg_status.serialize());
+        buffer.append(&mut self.icm_central_controller_time.serialize());
+        buffer.append(&mut self.peripheral_online.serialize());
+        buffer.append(&mut self.peripheral_type.serialize());
+        buffer.append(&mut self.agent_state.serialize());
+        buffer.append(&mut self.department_id.serialize());
+        buffer.append(&mut self.session_type.serialize());
+        buffer.append(&mut self.agent_extension.serialize());
+        buffer.append(&mut self.agent_id.serialize());
+        buffer.append(&mut self.agent_instrument.serialize());
+        buffer.append(&mut self.num_peripherals.serialize());
+        buffer.append(&mut self.flt_peripheral_id.serialize());
+        buffer.append(&mut self.multiline_agent_control.serialize());
+        for field in self.unknown_fields {
+            buffer.append(&mut field.serialize());
+        }
+
+        let mhdr = MHDR {
+            length: buffer.len() as u32,
+            message_type: MessageType::OPEN_CONF,
+        };
+
+        let mut result = mhdr.serialize();
+        result.append(&mut buffer);
+
+        result
     }
 }
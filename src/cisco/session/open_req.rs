@@ -1,19 +1,42 @@
-use crate::cisco::{FloatingField, MessageType, Serializable, MHDR};
+use std::fmt::{self, Debug};
+
+use crate::cisco::{
+    codec::MHDR_LENGTH,
+    error::{required, validate_length},
+    BuildError, FloatingField, InvokeId, MessageType, PeripheralId, Serializable, TagValue, MHDR,
+};
+
+use super::{AgentStateMask, CallMessageMask, ServicesRequested};
+
+///
+/// OPEN_REQ 기본 프로토콜 버전
+///
+const DEFAULT_VERSION_NUMBER: u32 = 24;
+
+///
+/// OPEN_REQ 기본 유휴 타임아웃 (초)
+///
+const DEFAULT_IDLE_TIMEOUT: u32 = 100;
+
+///
+/// ClientID, ClientPassword, ClientSignature 최대 길이
+///
+const MAX_CLIENT_FIELD_LENGTH: usize = 64;
 
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Clone)]
 ///
 /// Cisco CTI 프로토콜 OPEN_REQ 메시지
-/// 
+///
 pub struct OpenReq {
     pub mhdr: MHDR,
-    pub invoke_id: u32,
+    pub invoke_id: InvokeId,
     pub version_number: u32,
     pub idle_timeout: u32,
-    pub peripheral_id: u32,
-    pub services_requested: u32,
-    pub call_msg_mask: u32,
-    pub agent_state_mask: u32,
+    pub peripheral_id: PeripheralId,
+    pub services_requested: ServicesRequested,
+    pub call_msg_mask: CallMessageMask,
+    pub agent_state_mask: AgentStateMask,
     pub config_msg_mask: u32,
     pub reserved1: u32,
     pub reserved2: u32,
@@ -28,15 +51,24 @@ pub struct OpenReq {
     pub unique_instance_id: Option<FloatingField<i32>>,
 }
 
+impl OpenReq {
+    ///
+    /// OPEN_REQ 빌더를 생성
+    ///
+    pub fn builder() -> OpenReqBuilder {
+        OpenReqBuilder::default()
+    }
+}
+
 impl Serializable for OpenReq {
     fn serialize(self) -> Vec<u8> {
         let mut buffer = self.invoke_id.serialize();
         buffer.append(&mut self.version_number.serialize());
         buffer.append(&mut self.idle_timeout.serialize());
         buffer.append(&mut self.peripheral_id.serialize());
-        buffer.append(&mut self.services_requested.serialize());
-        buffer.append(&mut self.call_msg_mask.serialize());
-        buffer.append(&mut self.agent_state_mask.serialize());
+        buffer.append(&mut self.services_requested.bits().serialize());
+        buffer.append(&mut self.call_msg_mask.bits().serialize());
+        buffer.append(&mut self.agent_state_mask.bits().serialize());
         buffer.append(&mut self.config_msg_mask.serialize());
         buffer.append(&mut self.reserved1.serialize());
         buffer.append(&mut self.reserved2.serialize());
@@ -61,3 +93,213 @@ impl Serializable for OpenReq {
         result
     }
 }
+
+impl Debug for OpenReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 비밀번호는 로그에 남지 않도록 가린다
+        let client_password = self.client_password.as_ref().map(|field| FloatingField {
+            tag: field.tag,
+            length: field.length,
+            data: "********",
+        });
+
+        f.debug_struct("OpenReq")
+            .field("mhdr", &self.mhdr)
+            .field("invoke_id", &self.invoke_id)
+            .field("version_number", &self.version_number)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("peripheral_id", &self.peripheral_id)
+            .field("services_requested", &self.services_requested)
+            .field("call_msg_mask", &self.call_msg_mask)
+            .field("agent_state_mask", &self.agent_state_mask)
+            .field("config_msg_mask", &self.config_msg_mask)
+            .field("reserved1", &self.reserved1)
+            .field("reserved2", &self.reserved2)
+            .field("reserved3", &self.reserved3)
+            .field("client_id", &self.client_id)
+            .field("client_password", &client_password)
+            .field("client_signature", &self.client_signature)
+            .field("agent_extension", &self.agent_extension)
+            .field("agent_id", &self.agent_id)
+            .field("agent_instrument", &self.agent_instrument)
+            .field("application_path_id", &self.application_path_id)
+            .field("unique_instance_id", &self.unique_instance_id)
+            .finish()
+    }
+}
+
+///
+/// OPEN_REQ 메시지 빌더
+///
+/// 예약 필드는 0 으로 채우고 MHDR 길이는 build 시점에 계산한다.
+/// InvokeID, PeripheralID, ClientID, ClientPassword 는 필수 값이다.
+///
+#[derive(Default)]
+pub struct OpenReqBuilder {
+    invoke_id: Option<InvokeId>,
+    version_number: Option<u32>,
+    idle_timeout: Option<u32>,
+    peripheral_id: Option<PeripheralId>,
+    services_requested: ServicesRequested,
+    call_msg_mask: CallMessageMask,
+    agent_state_mask: AgentStateMask,
+    config_msg_mask: u32,
+    client_id: Option<String>,
+    client_password: Option<String>,
+    client_signature: Option<String>,
+    agent_extension: Option<String>,
+    agent_id: Option<String>,
+    agent_instrument: Option<String>,
+    application_path_id: Option<i32>,
+    unique_instance_id: Option<i32>,
+}
+
+impl OpenReqBuilder {
+    pub fn invoke_id(mut self, invoke_id: InvokeId) -> Self {
+        self.invoke_id = Some(invoke_id);
+        self
+    }
+
+    pub fn version(mut self, version_number: u32) -> Self {
+        self.version_number = Some(version_number);
+        self
+    }
+
+    pub fn idle_timeout(mut self, idle_timeout: u32) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    pub fn peripheral_id(mut self, peripheral_id: PeripheralId) -> Self {
+        self.peripheral_id = Some(peripheral_id);
+        self
+    }
+
+    pub fn services_requested(mut self, services_requested: ServicesRequested) -> Self {
+        self.services_requested = services_requested;
+        self
+    }
+
+    pub fn call_msg_mask(mut self, call_msg_mask: CallMessageMask) -> Self {
+        self.call_msg_mask = call_msg_mask;
+        self
+    }
+
+    pub fn agent_state_mask(mut self, agent_state_mask: AgentStateMask) -> Self {
+        self.agent_state_mask = agent_state_mask;
+        self
+    }
+
+    pub fn config_msg_mask(mut self, config_msg_mask: u32) -> Self {
+        self.config_msg_mask = config_msg_mask;
+        self
+    }
+
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    pub fn client_password(mut self, client_password: impl Into<String>) -> Self {
+        self.client_password = Some(client_password.into());
+        self
+    }
+
+    pub fn client_signature(mut self, client_signature: Option<String>) -> Self {
+        self.client_signature = client_signature;
+        self
+    }
+
+    pub fn agent_extension(mut self, agent_extension: impl Into<String>) -> Self {
+        self.agent_extension = Some(agent_extension.into());
+        self
+    }
+
+    pub fn agent_id(mut self, agent_id: impl Into<String>) -> Self {
+        self.agent_id = Some(agent_id.into());
+        self
+    }
+
+    pub fn agent_instrument(mut self, agent_instrument: impl Into<String>) -> Self {
+        self.agent_instrument = Some(agent_instrument.into());
+        self
+    }
+
+    pub fn application_path_id(mut self, application_path_id: i32) -> Self {
+        self.application_path_id = Some(application_path_id);
+        self
+    }
+
+    pub fn unique_instance_id(mut self, unique_instance_id: i32) -> Self {
+        self.unique_instance_id = Some(unique_instance_id);
+        self
+    }
+
+    ///
+    /// 필수 필드를 검증하고 OPEN_REQ 메시지를 생성한다
+    ///
+    pub fn build(self) -> Result<OpenReq, BuildError> {
+        let invoke_id = required(self.invoke_id, MessageType::OPEN_REQ, "invoke_id")?;
+        let peripheral_id = required(self.peripheral_id, MessageType::OPEN_REQ, "peripheral_id")?;
+        let client_id = required(self.client_id, MessageType::OPEN_REQ, "client_id")?;
+        let client_password = required(
+            self.client_password,
+            MessageType::OPEN_REQ,
+            "client_password",
+        )?;
+
+        validate_length("client_id", &client_id, MAX_CLIENT_FIELD_LENGTH)?;
+        validate_length("client_password", &client_password, MAX_CLIENT_FIELD_LENGTH)?;
+        if let Some(client_signature) = &self.client_signature {
+            validate_length(
+                "client_signature",
+                client_signature,
+                MAX_CLIENT_FIELD_LENGTH,
+            )?;
+        }
+
+        let mut open_req = OpenReq {
+            mhdr: MHDR {
+                length: 0,
+                message_type: MessageType::OPEN_REQ,
+            },
+            invoke_id,
+            version_number: self.version_number.unwrap_or(DEFAULT_VERSION_NUMBER),
+            idle_timeout: self.idle_timeout.unwrap_or(DEFAULT_IDLE_TIMEOUT),
+            peripheral_id,
+            services_requested: self.services_requested,
+            call_msg_mask: self.call_msg_mask,
+            agent_state_mask: self.agent_state_mask,
+            config_msg_mask: self.config_msg_mask,
+            reserved1: 0,
+            reserved2: 0,
+            reserved3: 0,
+            client_id: Some(FloatingField::new(TagValue::CLIENT_ID_TAG, client_id)),
+            client_password: Some(FloatingField::new(
+                TagValue::CLIENT_PASSWORD_TAG,
+                client_password,
+            )),
+            client_signature: self
+                .client_signature
+                .map(|data| FloatingField::new(TagValue::CLIENT_SIGNATURE_TAG, data)),
+            agent_extension: self
+                .agent_extension
+                .map(|data| FloatingField::new(TagValue::AGENT_EXTENSION_TAG, data)),
+            agent_id: self
+                .agent_id
+                .map(|data| FloatingField::new(TagValue::AGENT_ID_TAG, data)),
+            agent_instrument: self
+                .agent_instrument
+                .map(|data| FloatingField::new(TagValue::AGENT_INSTRUMENT_TAG, data)),
+            application_path_id: self
+                .application_path_id
+                .map(|data| FloatingField::new(TagValue::APP_PATH_ID_TAG, data)),
+            unique_instance_id: self
+                .unique_instance_id
+                .map(|data| FloatingField::new(TagValue::UNIQUE_INSTANCE_ID_TAG, data)),
+        };
+        open_req.mhdr.length = (open_req.clone().serialize().len() - MHDR_LENGTH) as u32;
+
+        Ok(open_req)
+    }
+}
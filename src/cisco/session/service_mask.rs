@@ -0,0 +1,133 @@
+use std::str::FromStr;
+
+use bitflags::{bitflags, parser::ParseError};
+
+bitflags! {
+    ///
+    /// OPEN_REQ 요청 서비스 마스크
+    ///
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ServicesRequested: u32 {
+        const CLIENT_EVENTS = 0x0000_0001;
+        const CALL_DATA_UPDATE = 0x0000_0002;
+        const CLIENT_CONTROL = 0x0000_0004;
+        const CONNECTION_MONITOR = 0x0000_0008;
+        const ALL_EVENTS = 0x0000_0010;
+        const PERIPHERAL_MONITOR = 0x0000_0020;
+        const CLIENT_MONITOR = 0x0000_0040;
+        const SUPERVISOR = 0x0000_0080;
+        const SERVER = 0x0000_0100;
+        const AGENT_REPORTING = 0x0000_0400;
+        const ALL_TASK_EVENTS = 0x0000_0800;
+        const TASK_MONITOR = 0x0000_1000;
+        const AGENT_STATE_CONTROL_ONLY = 0x0000_2000;
+        const DEBUG = 0x8000_0000;
+    }
+}
+
+bitflags! {
+    ///
+    /// OPEN_REQ 호 이벤트 메시지 마스크
+    ///
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct CallMessageMask: u32 {
+        const CALL_DELIVERED = 0x0000_0001;
+        const CALL_QUEUED = 0x0000_0002;
+        const CALL_ESTABLISHED = 0x0000_0004;
+        const CALL_HELD = 0x0000_0008;
+        const CALL_RETRIEVED = 0x0000_0010;
+        const CALL_CLEARED = 0x0000_0020;
+        const CALL_CONNECTION_CLEARED = 0x0000_0040;
+        const CALL_ORIGINATED = 0x0000_0080;
+        const CALL_CONFERENCED = 0x0000_0100;
+        const CALL_TRANSFERRED = 0x0000_0200;
+        const CALL_DIVERTED = 0x0000_0400;
+        const CALL_SERVICE_INITIATED = 0x0000_0800;
+        const CALL_TRANSLATION_ROUTE = 0x0000_1000;
+        const BEGIN_CALL = 0x0000_2000;
+        const END_CALL = 0x0000_4000;
+        const CALL_DATA_UPDATE = 0x0000_8000;
+        const CALL_FAILED = 0x0001_0000;
+        const CALL_REACHED_NETWORK = 0x0002_0000;
+        const CALL_DEQUEUED = 0x0004_0000;
+        const AGENT_PRE_CALL = 0x0008_0000;
+        const AGENT_PRE_CALL_ABORT = 0x0010_0000;
+        const RTP_STARTED = 0x0020_0000;
+        const RTP_STOPPED = 0x0040_0000;
+        const AGENT_TEAM_CONFIG = 0x0080_0000;
+        const AGENT_LEGACY_PRE_CALL = 0x0100_0000;
+        const CALL_ATTRIBUTE_CHANGE = 0x0200_0000;
+        const CALL_TERMINATION = 0x0400_0000;
+        const CALL_AGENT_GREETING = 0x0800_0000;
+
+        // 정의되지 않은 비트도 CTI 서버로 그대로 전달한다
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    ///
+    /// OPEN_REQ 상담직원 상태 마스크
+    ///
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct AgentStateMask: u32 {
+        const LOGIN = 0x0000_0001;
+        const LOGOUT = 0x0000_0002;
+        const NOT_READY = 0x0000_0004;
+        const AVAILABLE = 0x0000_0008;
+        const TALKING = 0x0000_0010;
+        const WORK_NOT_READY = 0x0000_0020;
+        const WORK_READY = 0x0000_0040;
+        const BUSY_OTHER = 0x0000_0080;
+        const RESERVED = 0x0000_0100;
+        const UNKNOWN = 0x0000_0200;
+        const HOLD = 0x0000_0400;
+        const ACTIVE = 0x0000_0800;
+        const PAUSED = 0x0000_1000;
+        const INTERRUPTED = 0x0000_2000;
+        const NOT_ACTIVE = 0x0000_4000;
+    }
+}
+
+impl Default for ServicesRequested {
+    fn default() -> Self {
+        Self::CLIENT_CONTROL | Self::ALL_EVENTS | Self::SUPERVISOR | Self::DEBUG
+    }
+}
+
+impl Default for CallMessageMask {
+    fn default() -> Self {
+        Self::from_bits_retain(u32::MAX)
+    }
+}
+
+impl Default for AgentStateMask {
+    fn default() -> Self {
+        Self::from_bits_retain(0x0000_3FFF)
+    }
+}
+
+// "CLIENT_CONTROL|ALL_EVENTS|0x10" 형식의 설정 값을 마스크로 변환한다
+impl FromStr for ServicesRequested {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        bitflags::parser::from_str(s)
+    }
+}
+
+impl FromStr for CallMessageMask {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        bitflags::parser::from_str(s)
+    }
+}
+
+impl FromStr for AgentStateMask {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        bitflags::parser::from_str(s)
+    }
+}
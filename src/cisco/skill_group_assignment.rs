@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use super::{FloatingField, Serializable, TagValue};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// 상담직원이 속한 스킬그룹 (가변 필드 SKILL_GROUP_NUMBER, ID, PRIORITY, STATE 묶음)
+///
+pub struct SkillGroupAssignment {
+    pub skill_group_number: u32,
+    pub skill_group_id: u32,
+    pub skill_group_priority: u16,
+    pub skill_group_state: u16,
+}
+
+impl Serializable for SkillGroupAssignment {
+    fn serialize(self) -> Vec<u8> {
+        let mut buffer =
+            FloatingField::new(TagValue::SKILL_GROUP_NUMBER_TAG, self.skill_group_number)
+                .serialize();
+        buffer.append(
+            &mut FloatingField::new(TagValue::SKILL_GROUP_ID_TAG, self.skill_group_id).serialize(),
+        );
+        buffer.append(
+            &mut FloatingField::new(
+                TagValue::SKILL_GROUP_PRIORITY_TAG,
+                self.skill_group_priority,
+            )
+            .serialize(),
+        );
+        buffer.append(
+            &mut FloatingField::new(TagValue::SKILL_GROUP_STATE_TAG, self.skill_group_state)
+                .serialize(),
+        );
+
+        buffer
+    }
+}
@@ -1,26 +1,34 @@
-use crate::cisco::{Deserializable, FloatingField, TagValue, MHDR};
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, CtiMessage, DecodeError, Deserializable, FloatingField, MessageType,
+    PeripheralId, RepeatedGroups, Serializable, TagValue, MHDR,
+};
 
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 ///
 /// Cisco CTI 프로토콜 AGENT_TEAM_CONFIG_EVENT 메시지
-/// 
+///
 pub struct AgentTeamConfigEvent {
     pub mhdr: MHDR,
-    pub peripheral_id: u32,
+    pub peripheral_id: PeripheralId,
     pub team_id: u32,
     pub number_of_agents: u16,
     pub config_operation: u16,
     pub department_id: i32,
     pub agent_team_name: Option<FloatingField<String>>,
     pub agents: Vec<AgentTeamConfigEventAgent>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
 }
 
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize)]
 ///
 /// AGENT_TEAM_CONFIG_EVENT의 Agent 구조체
-/// 
+///
+/// 상담직원은 ATC_AGENT_ID_TAG 로 시작하며, 그 밖의 태그는 빠지거나 순서가 바뀔 수 있다.
+///
 pub struct AgentTeamConfigEventAgent {
     pub agent_id: Option<FloatingField<String>>,
     pub agent_flags: Option<FloatingField<u16>>,
@@ -28,18 +36,21 @@ pub struct AgentTeamConfigEventAgent {
     pub state_duration: Option<FloatingField<u32>>,
 }
 
-impl Deserializable for AgentTeamConfigEvent {
-    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+impl CtiMessage for AgentTeamConfigEvent {
+    const FIXED_PART_LENGTH: usize = 24;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
         let (mut buffer, mhdr) = MHDR::deserialize(buffer);
-        let (mut buffer, peripheral_id) = u32::deserialize(&mut buffer);
+        let (mut buffer, peripheral_id) = PeripheralId::deserialize(&mut buffer);
         let (mut buffer, team_id) = u32::deserialize(&mut buffer);
         let (mut buffer, number_of_agents) = u16::deserialize(&mut buffer);
         let (mut buffer, config_operation) = u16::deserialize(&mut buffer);
         let (mut buffer, department_id) = i32::deserialize(&mut buffer);
 
         let mut agent_team_name = None;
-        let mut agents = vec![];
-        let mut agent_index = 0;
+        let mut agents =
+            RepeatedGroups::<AgentTeamConfigEventAgent>::new(TagValue::ATC_AGENT_ID_TAG);
+        let mut unknown_fields = vec![];
 
         loop {
             let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
@@ -48,9 +59,9 @@ impl Deserializable for AgentTeamConfigEvent {
                     buffer = field.data;
                     continue;
                 }
-                Some(mut field) => match field.tag {
+                Some(field) => match field.tag {
                     TagValue::AGENT_TEAM_NAME_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
                         agent_team_name = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
@@ -59,22 +70,17 @@ impl Deserializable for AgentTeamConfigEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::ATC_AGENT_ID_TAG => {
-                        let (sub_buffer, sub_result) = String::deserialize(&mut field.data);
-                        agents.push(AgentTeamConfigEventAgent {
-                            agent_id: Some(FloatingField {
-                                tag: field.tag,
-                                length: field.length,
-                                data: sub_result,
-                            }),
-                            agent_flags: None,
-                            agent_state: None,
-                            state_duration: None,
+                        let (sub_buffer, sub_result) = field.decode::<String>()?;
+                        agents.group(field.tag).agent_id = Some(FloatingField {
+                            tag: field.tag,
+                            length: field.length,
+                            data: sub_result,
                         });
                         buffer = sub_buffer;
                     }
                     TagValue::AGENT_FLAGS_TAG => {
-                        let (sub_buffer, sub_result) = u16::deserialize(&mut field.data);
-                        agents[agent_index].agent_flags = Some(FloatingField {
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
+                        agents.group(field.tag).agent_flags = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
                             data: sub_result,
@@ -82,8 +88,8 @@ impl Deserializable for AgentTeamConfigEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::ATC_AGENT_STATE_TAG => {
-                        let (sub_buffer, sub_result) = u16::deserialize(&mut field.data);
-                        agents[agent_index].agent_state = Some(FloatingField {
+                        let (sub_buffer, sub_result) = field.decode::<u16>()?;
+                        agents.group(field.tag).agent_state = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
                             data: sub_result,
@@ -91,16 +97,17 @@ impl Deserializable for AgentTeamConfigEvent {
                         buffer = sub_buffer;
                     }
                     TagValue::ATC_AGENT_STATE_DURATION_TAG => {
-                        let (sub_buffer, sub_result) = u32::deserialize(&mut field.data);
-                        agents[agent_index].state_duration = Some(FloatingField {
+                        let (sub_buffer, sub_result) = field.decode::<u32>()?;
+                        agents.group(field.tag).state_duration = Some(FloatingField {
                             tag: field.tag,
                             length: field.length,
                             data: sub_result,
                         });
-                        agent_index = agent_index + 1;
                         buffer = sub_buffer;
                     }
-                    _ => {}
+                    _ => {
+                        buffer = preserve_unknown_field(&mut unknown_fields, &field);
+                    }
                 },
                 None => {
                     break;
@@ -108,18 +115,46 @@ impl Deserializable for AgentTeamConfigEvent {
             }
         }
 
-        (
-            buffer,
-            Self {
-                mhdr,
-                peripheral_id,
-                team_id,
-                number_of_agents,
-                config_operation,
-                department_id,
-                agent_team_name,
-                agents,
-            },
-        )
+        Ok(Self {
+            mhdr,
+            peripheral_id,
+            team_id,
+            number_of_agents,
+            config_operation,
+            department_id,
+            agent_team_name,
+            agents: agents.into_vec(),
+            unknown_fields,
+        })
+    }
+}
+
+impl Serializable for AgentTeamConfigEvent {
+    fn serialize(self) -> Vec<u8> {
+        let mut buffer = self.peripheral_id.serialize();
+        buffer.append(&mut self.team_id.serialize());
+        buffer.append(&mut self.number_of_agents.serialize());
+        buffer.append(&mut self.config_operation.serialize());
+        buffer.append(&mut self.department_id.serialize());
+        buffer.append(&mut self.agent_team_name.serialize());
+        for agent in self.agents {
+            buffer.append(&mut agent.agent_id.serialize());
+            buffer.append(&mut agent.agent_flags.serialize());
+            buffer.append(&mut agent.agent_state.serialize());
+            buffer.append(&mut agent.state_duration.serialize());
+        }
+        for field in self.unknown_fields {
+            buffer.append(&mut field.serialize());
+        }
+
+        let mhdr = MHDR {
+            length: buffer.len() as u32,
+            message_type: MessageType::AGENT_TEAM_CONFIG_EVENT,
+        };
+
+        let mut result = mhdr.serialize();
+        result.append(&mut buffer);
+
+        result
     }
 }
@@ -0,0 +1,103 @@
+use serde::Serialize;
+
+use crate::cisco::{
+    preserve_unknown_field, ConnectionCallId, CtiMessage, DecodeError, Deserializable,
+    FloatingField, PeripheralId, TagValue, MHDR,
+};
+
+#[allow(unused)]
+#[derive(Debug, Serialize)]
+///
+/// Cisco CTI 프로토콜 EMERGENCY_CALL_EVENT 메시지
+///
+/// 상담직원이 긴급 호출, 지원 요청을 하면 SUPERVISOR 서비스 클라이언트에 전송되며,
+/// SUPERVISOR_ASSIST_EVENT 도 같은 형식을 사용한다.
+///
+pub struct EmergencyCallEvent {
+    pub mhdr: MHDR,
+    pub peripheral_id: PeripheralId,
+    pub connection_call_id: ConnectionCallId,
+    pub connection_device_id_type: u16,
+    pub session_id: u32,
+    pub connection_device_id: Option<FloatingField<String>>,
+    pub client_id: Option<FloatingField<String>>,
+    pub client_address: Option<FloatingField<String>>,
+    pub agent_extension: Option<FloatingField<String>>,
+    pub agent_id: Option<FloatingField<String>>,
+    pub agent_instrument: Option<FloatingField<String>>,
+    pub unknown_fields: Vec<FloatingField<Vec<u8>>>,
+}
+
+///
+/// Cisco CTI 프로토콜 SUPERVISOR_ASSIST_EVENT 메시지 (EMERGENCY_CALL_EVENT 와 같은 형식)
+///
+pub type SupervisorAssistEvent = EmergencyCallEvent;
+
+impl CtiMessage for EmergencyCallEvent {
+    const FIXED_PART_LENGTH: usize = 22;
+
+    fn decode(buffer: &mut Vec<u8>) -> Result<Self, DecodeError> {
+        let (mut buffer, mhdr) = MHDR::deserialize(buffer);
+        let (mut buffer, peripheral_id) = PeripheralId::deserialize(&mut buffer);
+        let (mut buffer, connection_call_id) = ConnectionCallId::deserialize(&mut buffer);
+        let (mut buffer, connection_device_id_type) = u16::deserialize(&mut buffer);
+        let (mut buffer, session_id) = u32::deserialize(&mut buffer);
+        let mut connection_device_id = None;
+        let mut client_id = None;
+        let mut client_address = None;
+        let mut agent_extension = None;
+        let mut agent_id = None;
+        let mut agent_instrument = None;
+        let mut unknown_fields = vec![];
+
+        loop {
+            let (_, floating_field) = Option::<FloatingField<Vec<u8>>>::deserialize(&mut buffer);
+
+            match floating_field {
+                Some(field) if field.length == 0 => buffer = field.data,
+                Some(field) => {
+                    let target = match field.tag {
+                        TagValue::CONNECTION_DEVID_TAG => Some(&mut connection_device_id),
+                        TagValue::CLIENT_ID_TAG => Some(&mut client_id),
+                        TagValue::CLIENT_ADDRESS_TAG => Some(&mut client_address),
+                        TagValue::AGENT_EXTENSION_TAG => Some(&mut agent_extension),
+                        TagValue::AGENT_ID_TAG => Some(&mut agent_id),
+                        TagValue::AGENT_INSTRUMENT_TAG => Some(&mut agent_instrument),
+                        _ => None,
+                    };
+
+                    match target {
+                        Some(target) => {
+                            let (sub_buffer, sub_result) = field.decode::<String>()?;
+                            *target = Some(FloatingField {
+                                tag: field.tag,
+                                length: field.length,
+                                data: sub_result,
+                            });
+                            buffer = sub_buffer;
+                        }
+                        None => {
+                            buffer = preserve_unknown_field(&mut unknown_fields, &field);
+                        }
+                    }
+                }
+                None => break,
+            };
+        }
+
+        Ok(Self {
+            mhdr,
+            peripheral_id,
+            connection_call_id,
+            connection_device_id_type,
+            session_id,
+            connection_device_id,
+            client_id,
+            client_address,
+            agent_extension,
+            agent_id,
+            agent_instrument,
+            unknown_fields,
+        })
+    }
+}
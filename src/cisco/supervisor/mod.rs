@@ -1 +1,2 @@
-pub mod agent_team_config_event;
\ No newline at end of file
+pub mod agent_team_config_event;
+pub mod emergency_call_event;
\ No newline at end of file
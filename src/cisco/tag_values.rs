@@ -1,10 +1,15 @@
+use serde::{Serialize, Serializer};
+
 use super::{Deserializable, Serializable};
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Clone)]
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 ///
 /// Cisco CTI 프로토콜 태그 값
 ///
+/// 문서에 정의되지 않은 태그 번호는 UNKNOWN 으로 원래 값을 보존한다.
+///
 pub enum TagValue {
     CLIENT_ID_TAG = 1,
     CLIENT_PASSWORD_TAG = 2,
@@ -12,7 +17,7 @@ pub enum TagValue {
     AGENT_EXTENSION_TAG = 4,
     AGENT_ID_TAG = 5,
     AGENT_INSTRUMENT_TAG = 6,
-    TEXT_TAG = 07,
+    TEXT_TAG = 7,
     ANI_TAG = 8,
     UUI_TAG = 9,
     DNIS_TAG = 10,
@@ -79,7 +84,6 @@ pub enum TagValue {
     LINE_TYPE_TAG = 71,
     ROUTER_CALL_KEY_DAY_TAG = 72,
     ROUTER_CALL_KEY_CALLID_TAG = 73,
-    ROUTER_CALL_KEY_SEQUENCE_NUM_TAG = 110,
     RESERVED74 = 74,
     CALL_STATE_TAG = 75,
     MONITORED_DEVID_TAG = 76,
@@ -104,9 +108,11 @@ pub enum TagValue {
     CUSTOMER_PHONE_NUMBER_TAG = 95,
     CUSTOMER_ACCOUNT_NUMBER_TAG = 96,
     APP_PATH_ID_TAG = 97,
+    UNIQUE_INSTANCE_ID_TAG = 98,
     SCRIPT_SELECTOR_TAG = 99,
     APPLICATION_STRING1_TAG = 100,
     APPLICATION_STRING2_TAG = 101,
+    ROUTER_CALL_KEY_SEQUENCE_NUM_TAG = 110,
     TRUNK_NUMBER_TAG = 121,
     TRUNK_GROUP_NUMBER_TAG = 122,
     EXT_AGENT_STATE_TAG = 123,
@@ -289,18 +295,946 @@ pub enum TagValue {
     CCAI_CONFIG_ID = 311,
     NUM_POSITIVE_ANSWERS_SUGGESTIONS = 312,
     NUM_NEGATIVE_ANSWERS_SUGGESTIONS = 313,
+    UNKNOWN(u16),
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// 가변 필드 데이터 유형
+///
+pub enum TagDataType {
+    STRING,
+    BOOL,
+    UCHAR,
+    USHORT,
+    SHORT,
+    UINT,
+    INT,
+    TIME,
+    // 구조체, 이진 데이터 등 값 형식을 특정할 수 없는 필드
+    UNSPEC,
 }
 
-impl Into<TagValue> for u16 {
-    fn into(self) -> TagValue {
+impl TagValue {
+    ///
+    /// 태그 이름을 반환한다
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            TagValue::CLIENT_ID_TAG => "CLIENT_ID_TAG",
+            TagValue::CLIENT_PASSWORD_TAG => "CLIENT_PASSWORD_TAG",
+            TagValue::CLIENT_SIGNATURE_TAG => "CLIENT_SIGNATURE_TAG",
+            TagValue::AGENT_EXTENSION_TAG => "AGENT_EXTENSION_TAG",
+            TagValue::AGENT_ID_TAG => "AGENT_ID_TAG",
+            TagValue::AGENT_INSTRUMENT_TAG => "AGENT_INSTRUMENT_TAG",
+            TagValue::TEXT_TAG => "TEXT_TAG",
+            TagValue::ANI_TAG => "ANI_TAG",
+            TagValue::UUI_TAG => "UUI_TAG",
+            TagValue::DNIS_TAG => "DNIS_TAG",
+            TagValue::DIALED_NUMBER_TAG => "DIALED_NUMBER_TAG",
+            TagValue::CED_TAG => "CED_TAG",
+            TagValue::CALL_VAR_1_TAG => "CALL_VAR_1_TAG",
+            TagValue::CALL_VAR_2_TAG => "CALL_VAR_2_TAG",
+            TagValue::CALL_VAR_3_TAG => "CALL_VAR_3_TAG",
+            TagValue::CALL_VAR_4_TAG => "CALL_VAR_4_TAG",
+            TagValue::CALL_VAR_5_TAG => "CALL_VAR_5_TAG",
+            TagValue::CALL_VAR_6_TAG => "CALL_VAR_6_TAG",
+            TagValue::CALL_VAR_7_TAG => "CALL_VAR_7_TAG",
+            TagValue::CALL_VAR_8_TAG => "CALL_VAR_8_TAG",
+            TagValue::CALL_VAR_9_TAG => "CALL_VAR_9_TAG",
+            TagValue::CALL_VAR_10_TAG => "CALL_VAR_10_TAG",
+            TagValue::CTI_CLIENT_SIGNATURE_TAG => "CTI_CLIENT_SIGNATURE_TAG",
+            TagValue::CTI_CLIENT_TIMESTAMP_TAG => "CTI_CLIENT_TIMESTAMP_TAG",
+            TagValue::CONNECTION_DEVID_TAG => "CONNECTION_DEVID_TAG",
+            TagValue::ALERTING_DEVID_TAG => "ALERTING_DEVID_TAG",
+            TagValue::CALLING_DEVID_TAG => "CALLING_DEVID_TAG",
+            TagValue::CALLED_DEVID_TAG => "CALLED_DEVID_TAG",
+            TagValue::LAST_REDIRECT_DEVID_TAG => "LAST_REDIRECT_DEVID_TAG",
+            TagValue::ANSWERING_DEVID_TAG => "ANSWERING_DEVID_TAG",
+            TagValue::HOLDING_DEVID_TAG => "HOLDING_DEVID_TAG",
+            TagValue::RETREIVING_DEVID_TAG => "RETREIVING_DEVID_TAG",
+            TagValue::RELEASING_DEVID_TAG => "RELEASING_DEVID_TAG",
+            TagValue::FAILING_DEVID_TAG => "FAILING_DEVID_TAG",
+            TagValue::PRIMARY_DEVID_TAG => "PRIMARY_DEVID_TAG",
+            TagValue::SECONDARY_DEVID_TAG => "SECONDARY_DEVID_TAG",
+            TagValue::CONTROLLER_DEVID_TAG => "CONTROLLER_DEVID_TAG",
+            TagValue::ADD_PARTY_DEVID_TAG => "ADD_PARTY_DEVID_TAG",
+            TagValue::PARTY_CALLID_TAG => "PARTY_CALLID_TAG",
+            TagValue::PARTY_DEVID_TYPE_TAG => "PARTY_DEVID_TYPE_TAG",
+            TagValue::PARTY_DEVID_TAG => "PARTY_DEVID_TAG",
+            TagValue::TRANSFERRING_DEVID_TAG => "TRANSFERRING_DEVID_TAG",
+            TagValue::TRANSFERRED_DEVID_TAG => "TRANSFERRED_DEVID_TAG",
+            TagValue::DIVERTING_DEVID_TAG => "DIVERTING_DEVID_TAG",
+            TagValue::QUEUE_DEVID_TAG => "QUEUE_DEVID_TAG",
+            TagValue::CALL_WRAPUP_DATA_TAG => "CALL_WRAPUP_DATA_TAG",
+            TagValue::NEW_CONNECTION_DEVID_TAG => "NEW_CONNECTION_DEVID_TAG",
+            TagValue::TRUNK_USED_DEVID_TAG => "TRUNK_USED_DEVID_TAG",
+            TagValue::AGENT_PASSWORD_TAG => "AGENT_PASSWORD_TAG",
+            TagValue::ACTIVE_CONN_DEVID_TAG => "ACTIVE_CONN_DEVID_TAG",
+            TagValue::FACILITY_CODE_TAG => "FACILITY_CODE_TAG",
+            TagValue::OTHER_CONN_DEVID_TAG => "OTHER_CONN_DEVID_TAG",
+            TagValue::HELD_CONN_DEVID_TAG => "HELD_CONN_DEVID_TAG",
+            TagValue::RESERVED54 => "RESERVED54",
+            TagValue::RESERVED55 => "RESERVED55",
+            TagValue::CALL_CONN_CALLID_TAG => "CALL_CONN_CALLID_TAG",
+            TagValue::CALL_CONN_DEVID_TYPE_TAG => "CALL_CONN_DEVID_TYPE_TAG",
+            TagValue::CALL_CONN_DEVID_TAG => "CALL_CONN_DEVID_TAG",
+            TagValue::CALL_DEVID_TYPE_TAG => "CALL_DEVID_TYPE_TAG",
+            TagValue::CALL_DEVID_TAG => "CALL_DEVID_TAG",
+            TagValue::CALL_DEV_CONN_STATE_TAG => "CALL_DEV_CONN_STATE_TAG",
+            TagValue::SKILL_GROUP_NUMBER_TAG => "SKILL_GROUP_NUMBER_TAG",
+            TagValue::SKILL_GROUP_ID_TAG => "SKILL_GROUP_ID_TAG",
+            TagValue::SKILL_GROUP_PRIORITY_TAG => "SKILL_GROUP_PRIORITY_TAG",
+            TagValue::SKILL_GROUP_STATE_TAG => "SKILL_GROUP_STATE_TAG",
+            TagValue::OBJECT_NAME_TAG => "OBJECT_NAME_TAG",
+            TagValue::DTMF_STRING_TAG => "DTMF_STRING_TAG",
+            TagValue::POSITION_ID_TAG => "POSITION_ID_TAG",
+            TagValue::SUPERVISOR_ID_TAG => "SUPERVISOR_ID_TAG",
+            TagValue::LINE_HANDLE_TAG => "LINE_HANDLE_TAG",
+            TagValue::LINE_TYPE_TAG => "LINE_TYPE_TAG",
+            TagValue::ROUTER_CALL_KEY_DAY_TAG => "ROUTER_CALL_KEY_DAY_TAG",
+            TagValue::ROUTER_CALL_KEY_CALLID_TAG => "ROUTER_CALL_KEY_CALLID_TAG",
+            TagValue::RESERVED74 => "RESERVED74",
+            TagValue::CALL_STATE_TAG => "CALL_STATE_TAG",
+            TagValue::MONITORED_DEVID_TAG => "MONITORED_DEVID_TAG",
+            TagValue::AUTHORIZATION_CODE_TAG => "AUTHORIZATION_CODE_TAG",
+            TagValue::ACCOUNT_CODE_TAG => "ACCOUNT_CODE_TAG",
+            TagValue::ORIGINATING_DEVID_TAG => "ORIGINATING_DEVID_TAG",
+            TagValue::ORIGINATING_LINE_ID_TAG => "ORIGINATING_LINE_ID_TAG",
+            TagValue::CLIENT_ADDRESS_TAG => "CLIENT_ADDRESS_TAG",
+            TagValue::NAMED_VARIABLE_TAG => "NAMED_VARIABLE_TAG",
+            TagValue::NAMED_ARRAY_TAG => "NAMED_ARRAY_TAG",
+            TagValue::CALL_CONTROL_TABLE_TAG => "CALL_CONTROL_TABLE_TAG",
+            TagValue::SUPERVISOR_INSTRUMENT_TAG => "SUPERVISOR_INSTRUMENT_TAG",
+            TagValue::ATC_AGENT_ID_TAG => "ATC_AGENT_ID_TAG",
+            TagValue::AGENT_FLAGS_TAG => "AGENT_FLAGS_TAG",
+            TagValue::ATC_AGENT_STATE_TAG => "ATC_AGENT_STATE_TAG",
+            TagValue::ATC_AGENT_STATE_DURATION_TAG => "ATC_AGENT_STATE_DURATION_TAG",
+            TagValue::AGENT_CONNECTION_DEVID_TAG => "AGENT_CONNECTION_DEVID_TAG",
+            TagValue::SUPERVISOR_CONNECTION_DEVID_TAG => "SUPERVISOR_CONNECTION_DEVID_TAG",
+            TagValue::LIST_TEAM_ID_TAG => "LIST_TEAM_ID_TAG",
+            TagValue::DEFAULT_DEVICE_PORT_ADDRESS_TAG => "DEFAULT_DEVICE_PORT_ADDRESS_TAG",
+            TagValue::SERVICE_NAME_TAG => "SERVICE_NAME_TAG",
+            TagValue::CUSTOMER_PHONE_NUMBER_TAG => "CUSTOMER_PHONE_NUMBER_TAG",
+            TagValue::CUSTOMER_ACCOUNT_NUMBER_TAG => "CUSTOMER_ACCOUNT_NUMBER_TAG",
+            TagValue::APP_PATH_ID_TAG => "APP_PATH_ID_TAG",
+            TagValue::UNIQUE_INSTANCE_ID_TAG => "UNIQUE_INSTANCE_ID_TAG",
+            TagValue::SCRIPT_SELECTOR_TAG => "SCRIPT_SELECTOR_TAG",
+            TagValue::APPLICATION_STRING1_TAG => "APPLICATION_STRING1_TAG",
+            TagValue::APPLICATION_STRING2_TAG => "APPLICATION_STRING2_TAG",
+            TagValue::ROUTER_CALL_KEY_SEQUENCE_NUM_TAG => "ROUTER_CALL_KEY_SEQUENCE_NUM_TAG",
+            TagValue::TRUNK_NUMBER_TAG => "TRUNK_NUMBER_TAG",
+            TagValue::TRUNK_GROUP_NUMBER_TAG => "TRUNK_GROUP_NUMBER_TAG",
+            TagValue::EXT_AGENT_STATE_TAG => "EXT_AGENT_STATE_TAG",
+            TagValue::DEQUEUE_TYPE_TAG => "DEQUEUE_TYPE_TAG",
+            TagValue::SENDING_ADDRESS_TAG => "SENDING_ADDRESS_TAG",
+            TagValue::SENDING_PORT_TAG => "SENDING_PORT_TAG",
+            TagValue::UNUSED127 => "UNUSED127",
+            TagValue::UNUSED128 => "UNUSED128",
+            TagValue::MAX_QUEUED_TAG => "MAX_QUEUED_TAG",
+            TagValue::QUEUE_ID_TAG => "QUEUE_ID_TAG",
+            TagValue::CUSTOMER_ID_TAG => "CUSTOMER_ID_TAG",
+            TagValue::SERVICE_SKILL_TARGET_ID_TAG => "SERVICE_SKILL_TARGET_ID_TAG",
+            TagValue::PERIPHERAL_NAME_TAG => "PERIPHERAL_NAME_TAG",
+            TagValue::DESCRIPTION_TAG => "DESCRIPTION_TAG",
+            TagValue::SERVICE_MEMBER_ID_TAG => "SERVICE_MEMBER_ID_TAG",
+            TagValue::SERVICE_MEMBER_PRIORITY_TAG => "SERVICE_MEMBER_PRIORITY_TAG",
+            TagValue::FIRST_NAME_TAG => "FIRST_NAME_TAG",
+            TagValue::LAST_NAME_TAG => "LAST_NAME_TAG",
+            TagValue::SKILL_GROUP_TAG => "SKILL_GROUP_TAG",
+            TagValue::AGENT_SKILL_TARGET_ID_TAG => "AGENT_SKILL_TARGET_ID_TAG",
+            TagValue::SERVICE_TAG => "SERVICE_TAG",
+            TagValue::RESERVED143 => "RESERVED143",
+            TagValue::RESERVED144 => "RESERVED144",
+            TagValue::RESERVED145 => "RESERVED145",
+            TagValue::RESERVED146 => "RESERVED146",
+            TagValue::RESERVED147 => "RESERVED147",
+            TagValue::RESERVED148 => "RESERVED148",
+            TagValue::RESERVED149 => "RESERVED149",
+            TagValue::DURATION_TAG => "DURATION_TAG",
+            TagValue::RESERVED151 => "RESERVED151",
+            TagValue::RESERVED152 => "RESERVED152",
+            TagValue::RESERVED153 => "RESERVED153",
+            TagValue::RESERVED154 => "RESERVED154",
+            TagValue::RESERVED155 => "RESERVED155",
+            TagValue::RESERVED156 => "RESERVED156",
+            TagValue::RESERVED157 => "RESERVED157",
+            TagValue::RESERVED158 => "RESERVED158",
+            TagValue::RESERVED159 => "RESERVED159",
+            TagValue::RESERVED160 => "RESERVED160",
+            TagValue::RESERVED161 => "RESERVED161",
+            TagValue::RESERVED162 => "RESERVED162",
+            TagValue::RESERVED163 => "RESERVED163",
+            TagValue::RESERVED164 => "RESERVED164",
+            TagValue::RESERVED165 => "RESERVED165",
+            TagValue::RESERVED166 => "RESERVED166",
+            TagValue::RESERVED167 => "RESERVED167",
+            TagValue::RESERVED168 => "RESERVED168",
+            TagValue::RESERVED169 => "RESERVED169",
+            TagValue::RESERVED170 => "RESERVED170",
+            TagValue::RESERVED171 => "RESERVED171",
+            TagValue::RESERVED172 => "RESERVED172",
+            TagValue::EXTENSION_TAG => "EXTENSION_TAG",
+            TagValue::SERVICE_LEVEL_THRESHOLD_TAG => "SERVICE_LEVEL_THRESHOLD_TAG",
+            TagValue::SERVICE_LEVEL_TYPE_TAG => "SERVICE_LEVEL_TYPE_TAG",
+            TagValue::CONFIG_PARAM_TAG => "CONFIG_PARAM_TAG",
+            TagValue::SERVICE_CONFIG_KEY_TAG => "SERVICE_CONFIG_KEY_TAG",
+            TagValue::SKILL_GROUP_CONFIG_KEY_TAG => "SKILL_GROUP_CONFIG_KEY_TAG",
+            TagValue::AGENT_CONFIG_KEY_TAG => "AGENT_CONFIG_KEY_TAG",
+            TagValue::DEVICE_CONFIG_KEY_TAG => "DEVICE_CONFIG_KEY_TAG",
+            TagValue::UNUSED181 => "UNUSED181",
+            TagValue::UNUSED182 => "UNUSED182",
+            TagValue::RECORD_TYPE_TAG => "RECORD_TYPE_TAG",
+            TagValue::PERIPHERAL_NUMBER_TAG => "PERIPHERAL_NUMBER_TAG",
+            TagValue::AGENT_SKILL_TARGET_ID_TAG2 => "AGENT_SKILL_TARGET_ID_TAG2",
+            TagValue::NUM_SERVICE_MEMBERS_TAG => "NUM_SERVICE_MEMBERS_TAG",
+            TagValue::SERVICE_MEMBER_TAG => "SERVICE_MEMBER_TAG",
+            TagValue::SERVICE_PRIORITY_TAG => "SERVICE_PRIORITY_TAG",
+            TagValue::AGENT_TYPE_TAG => "AGENT_TYPE_TAG",
+            TagValue::LOGIN_ID_TAG => "LOGIN_ID_TAG",
+            TagValue::NUM_SKILLS_TAG => "NUM_SKILLS_TAG",
+            TagValue::SKILL_GROUP_SKILL_TARGET_ID_TAG => "SKILL_GROUP_SKILL_TARGET_ID_TAG",
+            TagValue::SERVICE_ID_TAG => "SERVICE_ID_TAG",
+            TagValue::AGENT_ID_LONG_TAG => "AGENT_ID_LONG_TAG",
+            TagValue::DEVICE_TYPE_TAG => "DEVICE_TYPE_TAG",
+            TagValue::UNUSED196 => "UNUSED196",
+            TagValue::UNUSED197 => "UNUSED197",
+            TagValue::ENABLE_TAG => "ENABLE_TAG",
+            TagValue::DEVICEID_TAG => "DEVICEID_TAG",
+            TagValue::TIMEOUT_TAG => "TIMEOUT_TAG",
+            TagValue::CURRENT_ROUTE_TAG => "CURRENT_ROUTE_TAG",
+            TagValue::SECONDARY_CONNECTION_CALL_ID => "SECONDARY_CONNECTION_CALL_ID",
+            TagValue::PRIORITY_QUEUE_NUMBER_TAG => "PRIORITY_QUEUE_NUMBER_TAG",
+            TagValue::TEAM_NAME_TAG => "TEAM_NAME_TAG",
+            TagValue::MEMBER_TYPE_TAG => "MEMBER_TYPE_TAG",
+            TagValue::EVENT_DEVICE_ID_TAG => "EVENT_DEVICE_ID_TAG",
+            TagValue::LOGIN_NAME_TAG_V11 => "LOGIN_NAME_TAG_V11",
+            TagValue::PERIPHERAL_ID_TAG_V11 => "PERIPHERAL_ID_TAG_V11",
+            TagValue::CALL_TYPE_KEY_CONFIG_TAG_V11 => "CALL_TYPE_KEY_CONFIG_TAG_V11",
+            TagValue::CALL_TYPE_ID_TAG_V11 => "CALL_TYPE_ID_TAG_V11",
+            TagValue::CUSTOMER_DEFINITION_ID_TAG_V11 => "CUSTOMER_DEFINITION_ID_TAG_V11",
+            TagValue::ENTERPRISE_NAME_TAG_V11 => "ENTERPRISE_NAME_TAG_V11",
+            TagValue::OLD_PERIPHERAL_NUMBER_TAG => "OLD_PERIPHERAL_NUMBER_TAG",
+            TagValue::CUR_LOGIN_ID => "CUR_LOGIN_ID",
+            TagValue::ANI_II_TAG => "ANI_II_TAG",
+            TagValue::MR_DOMAIN_ID_TAG => "MR_DOMAIN_ID_TAG",
+            TagValue::CTIOS_CIL_CLIENT_ID_TAG => "CTIOS_CIL_CLIENT_ID_TAG",
+            TagValue::SILENT_MONITOR_STATUS_TAG => "SILENT_MONITOR_STATUS_TAG",
+            TagValue::REQUESTING_DEVICE_ID_TAG => "REQUESTING_DEVICE_ID_TAG",
+            TagValue::REQUESTING_DEVICE_ID_TYPE_TAG => "REQUESTING_DEVICE_ID_TYPE_TAG",
+            TagValue::PRE_CALL_INVOKE_ID_TAG => "PRE_CALL_INVOKE_ID_TAG",
+            TagValue::ENTERPRISE_QUEUE_TIME => "ENTERPRISE_QUEUE_TIME",
+            TagValue::CALL_REFERENCE_ID_TAG => "CALL_REFERENCE_ID_TAG",
+            TagValue::MULTI_LINE_AGENT_CONTROL_TAG => "MULTI_LINE_AGENT_CONTROL_TAG",
+            TagValue::NETWORK_CONTROLLED_TAG => "NETWORK_CONTROLLED_TAG",
+            TagValue::UNUSED226 => "UNUSED226",
+            TagValue::UNUSED227 => "UNUSED227",
+            TagValue::NUM_PERIPHERALS_TAG => "NUM_PERIPHERALS_TAG",
+            TagValue::COC_CONNECTION_CALL_ID_TAG => "COC_CONNECTION_CALL_ID_TAG",
+            TagValue::COC_CONNECTION_DEVICE_ID_TYPE_TAG => "COC_CONNECTION_DEVICE_ID_TYPE_TAG",
+            TagValue::COC_CONNECTION_DEVICE_ID_TAG => "COC_CONNECTION_DEVICE_ID_TAG",
+            TagValue::CALL_ORIGINATED_FROM_TAG => "CALL_ORIGINATED_FROM_TAG",
+            TagValue::SET_APPDATA_CALLID_TAG => "SET_APPDATA_CALLID_TAG",
+            TagValue::CLIENT_SHARE_KEY_TAG => "CLIENT_SHARE_KEY_TAG",
+            TagValue::AGENT_TEAM_NAME_TAG => "AGENT_TEAM_NAME_TAG",
+            TagValue::DIRECTION_TAG => "DIRECTION_TAG",
+            TagValue::OPTIONS_TAG => "OPTIONS_TAG",
+            TagValue::FLT_MRD_ID_TAG => "FLT_MRD_ID_TAG",
+            TagValue::MEDIA_CLASS_ID_TAG => "MEDIA_CLASS_ID_TAG",
+            TagValue::TASK_LIFE_TAG => "TASK_LIFE_TAG",
+            TagValue::TASK_START_TIMEOUT_TAG => "TASK_START_TIMEOUT_TAG",
+            TagValue::MAX_TASK_DURATION_TAG => "MAX_TASK_DURATION_TAG",
+            TagValue::INTERRUPTIBLE_TAG => "INTERRUPTIBLE_TAG",
+            TagValue::MAX_CALLS_IN_QUEUE_TAG => "MAX_CALLS_IN_QUEUE_TAG",
+            TagValue::MAX_CALLS_IN_QUEUE_PER_CALL_TYPE_TAG => {
+                "MAX_CALLS_IN_QUEUE_PER_CALL_TYPE_TAG"
+            }
+            TagValue::MAX_TIME_IN_QUEUE_TAG => "MAX_TIME_IN_QUEUE_TAG",
+            TagValue::INTERNAL_AGENT_STATE_TAG => "INTERNAL_AGENT_STATE_TAG",
+            TagValue::UNUSED256 => "UNUSED256",
+            TagValue::SSO_ENABLED_TAG => "SSO_ENABLED_TAG",
+            TagValue::FLT_TASK_ID_TAG => "FLT_TASK_ID_TAG",
+            TagValue::FLT_ICM_DISP_TAG => "FLT_ICM_DISP_TAG",
+            TagValue::FLT_APP_DISP_TAG => "FLT_APP_DISP_TAG",
+            TagValue::NUM_MRDS_TAG => "NUM_MRDS_TAG",
+            TagValue::FLT_AGENT_MRD_ID_TAG => "FLT_AGENT_MRD_ID_TAG",
+            TagValue::FLT_AGENT_MRD_STATE_TAG => "FLT_AGENT_MRD_STATE_TAG",
+            TagValue::FLT_PRECISION_QUEUE_ID_TAG => "FLT_PRECISION_QUEUE_ID_TAG",
+            TagValue::FLT_PRECISION_QUEUE_NAME_TAG => "FLT_PRECISION_QUEUE_NAME_TAG",
+            TagValue::MAX_BEYOND_TASK_LIMIT_TAG => "MAX_BEYOND_TASK_LIMIT_TAG",
+            TagValue::AGENT_DESK_SETTINGS_ID_TAG => "AGENT_DESK_SETTINGS_ID_TAG",
+            TagValue::XFER_IN_WHILE_LOGGED_OUT_TAG => "XFER_IN_WHILE_LOGGED_OUT_TAG",
+            TagValue::PERIPHERAL_CONFIG_KEY_TAG => "PERIPHERAL_CONFIG_KEY_TAG",
+            TagValue::AGENT_DESK_SETTINGS_CONFIG_KEY_TAG => "AGENT_DESK_SETTINGS_CONFIG_KEY_TAG",
+            TagValue::CONFIG_PERIPHERAL_ID_TAG => "CONFIG_PERIPHERAL_ID_TAG",
+            TagValue::DEFAULT_AGENT_DESK_SETTINGS_ID_TAG => "DEFAULT_AGENT_DESK_SETTINGS_ID_TAG",
+            TagValue::FLT_DESK_SETTINGS_MASK_TAG => "FLT_DESK_SETTINGS_MASK_TAG",
+            TagValue::FLT_WRAP_UP_DATA_INCOMING_MODE_TAG => "FLT_WRAP_UP_DATA_INCOMING_MODE_TAG",
+            TagValue::FLT_WRAP_UP_DATA_OUTGOING_MODE_TAG => "FLT_WRAP_UP_DATA_OUTGOING_MODE_TAG",
+            TagValue::FLT_LOGOUT_NON_ACTIVITY_TIME_TAG => "FLT_LOGOUT_NON_ACTIVITY_TIME_TAG",
+            TagValue::FLT_QUALITY_RECORDING_RATE_TAG => "FLT_QUALITY_RECORDING_RATE_TAG",
+            TagValue::FLT_RING_NO_ANSWER_TIME_TAG => "FLT_RING_NO_ANSWER_TIME_TAG",
+            TagValue::FLT_SILENT_MONITOR_WARNING_MESSAGE_TAG => {
+                "FLT_SILENT_MONITOR_WARNING_MESSAGE_TAG"
+            }
+            TagValue::FLT_SILENT_MONITOR_AUDIBLE_INDICATION_TAG => {
+                "FLT_SILENT_MONITOR_AUDIBLE_INDICATION_TAG"
+            }
+            TagValue::FLT_SUPERVISOR_ASSIST_CALL_METHOD_TAG => {
+                "FLT_SUPERVISOR_ASSIST_CALL_METHOD_TAG"
+            }
+            TagValue::FLT_EMERGENCY_CALL_METHOD_TAG => "FLT_EMERGENCY_CALL_METHOD_TAG",
+            TagValue::FLT_AUTO_RECORD_ON_EMERGENCY_TAG => "FLT_AUTO_RECORD_ON_EMERGENCY_TAG",
+            TagValue::FLT_RECORDING_MODE_TAG => "FLT_RECORDING_MODE_TAG",
+            TagValue::FLT_WORK_MODE_TIMER_TAG => "FLT_WORK_MODE_TIMER_TAG",
+            TagValue::FLT_RING_NO_ANSWER_DN_ID_TAG => "FLT_RING_NO_ANSWER_DN_ID_TAG",
+            TagValue::FLT_DEFAULT_DEVICE_PORT_ADDRESS_TAG => "FLT_DEFAULT_DEVICE_PORT_ADDRESS_TAG",
+            TagValue::DESKTOP_CONNECTED_FLAG_TAG => "DESKTOP_CONNECTED_FLAG_TAG",
+            TagValue::PLAY_TONE_DIRECTION_TAG => "PLAY_TONE_DIRECTION_TAG",
+            TagValue::INVOCATION_TYPE_TAG => "INVOCATION_TYPE_TAG",
+            TagValue::RECORDER_ADDRESS_TAG => "RECORDER_ADDRESS_TAG",
+            TagValue::TERMINAL_NAME_TAG => "TERMINAL_NAME_TAG",
+            TagValue::MEDIA_FORKING_DEVICE_NAME_TAG => "MEDIA_FORKING_DEVICE_NAME_TAG",
+            TagValue::PROTOCOL_REFERENCE_GUID_TAG => "PROTOCOL_REFERENCE_GUID_TAG",
+            TagValue::MEDIA_FORKING_CLUSTER_ID_TAG => "MEDIA_FORKING_CLUSTER_ID_TAG",
+            TagValue::RECORDER_URI_TAG => "RECORDER_URI_TAG",
+            TagValue::RECORDER_ERROR_MSG_TAG => "RECORDER_ERROR_MSG_TAG",
+            TagValue::RECORDER_TYPE_TAG => "RECORDER_TYPE_TAG",
+            TagValue::RECORDER_STATUS_TAG => "RECORDER_STATUS_TAG",
+            TagValue::RECORDING_DEVICE_ID_TAG => "RECORDING_DEVICE_ID_TAG",
+            TagValue::FLT_TERM_TYPE => "FLT_TERM_TYPE",
+            TagValue::FLT_TERM_DEVICE_NAME => "FLT_TERM_DEVICE_NAME",
+            TagValue::FLT_TERM_TYPE_NAME => "FLT_TERM_TYPE_NAME",
+            TagValue::FLT_NUM_INSTRUMENTS => "FLT_NUM_INSTRUMENTS",
+            TagValue::ACD_SHARED_LINE_USAGE => "ACD_SHARED_LINE_USAGE",
+            TagValue::PLAY_ZIP_TONE => "PLAY_ZIP_TONE",
+            TagValue::FLT_ENABLED_SERVICES => "FLT_ENABLED_SERVICES",
+            TagValue::NUM_OF_ENABLED_SERVICES => "NUM_OF_ENABLED_SERVICES",
+            TagValue::CCAI_CONFIG_ID => "CCAI_CONFIG_ID",
+            TagValue::NUM_POSITIVE_ANSWERS_SUGGESTIONS => "NUM_POSITIVE_ANSWERS_SUGGESTIONS",
+            TagValue::NUM_NEGATIVE_ANSWERS_SUGGESTIONS => "NUM_NEGATIVE_ANSWERS_SUGGESTIONS",
+            TagValue::UNKNOWN(_) => "UNKNOWN",
+        }
+    }
+
+    ///
+    /// 태그 이름으로 태그 값을 찾는다
+    ///
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "CLIENT_ID_TAG" => Some(TagValue::CLIENT_ID_TAG),
+            "CLIENT_PASSWORD_TAG" => Some(TagValue::CLIENT_PASSWORD_TAG),
+            "CLIENT_SIGNATURE_TAG" => Some(TagValue::CLIENT_SIGNATURE_TAG),
+            "AGENT_EXTENSION_TAG" => Some(TagValue::AGENT_EXTENSION_TAG),
+            "AGENT_ID_TAG" => Some(TagValue::AGENT_ID_TAG),
+            "AGENT_INSTRUMENT_TAG" => Some(TagValue::AGENT_INSTRUMENT_TAG),
+            "TEXT_TAG" => Some(TagValue::TEXT_TAG),
+            "ANI_TAG" => Some(TagValue::ANI_TAG),
+            "UUI_TAG" => Some(TagValue::UUI_TAG),
+            "DNIS_TAG" => Some(TagValue::DNIS_TAG),
+            "DIALED_NUMBER_TAG" => Some(TagValue::DIALED_NUMBER_TAG),
+            "CED_TAG" => Some(TagValue::CED_TAG),
+            "CALL_VAR_1_TAG" => Some(TagValue::CALL_VAR_1_TAG),
+            "CALL_VAR_2_TAG" => Some(TagValue::CALL_VAR_2_TAG),
+            "CALL_VAR_3_TAG" => Some(TagValue::CALL_VAR_3_TAG),
+            "CALL_VAR_4_TAG" => Some(TagValue::CALL_VAR_4_TAG),
+            "CALL_VAR_5_TAG" => Some(TagValue::CALL_VAR_5_TAG),
+            "CALL_VAR_6_TAG" => Some(TagValue::CALL_VAR_6_TAG),
+            "CALL_VAR_7_TAG" => Some(TagValue::CALL_VAR_7_TAG),
+            "CALL_VAR_8_TAG" => Some(TagValue::CALL_VAR_8_TAG),
+            "CALL_VAR_9_TAG" => Some(TagValue::CALL_VAR_9_TAG),
+            "CALL_VAR_10_TAG" => Some(TagValue::CALL_VAR_10_TAG),
+            "CTI_CLIENT_SIGNATURE_TAG" => Some(TagValue::CTI_CLIENT_SIGNATURE_TAG),
+            "CTI_CLIENT_TIMESTAMP_TAG" => Some(TagValue::CTI_CLIENT_TIMESTAMP_TAG),
+            "CONNECTION_DEVID_TAG" => Some(TagValue::CONNECTION_DEVID_TAG),
+            "ALERTING_DEVID_TAG" => Some(TagValue::ALERTING_DEVID_TAG),
+            "CALLING_DEVID_TAG" => Some(TagValue::CALLING_DEVID_TAG),
+            "CALLED_DEVID_TAG" => Some(TagValue::CALLED_DEVID_TAG),
+            "LAST_REDIRECT_DEVID_TAG" => Some(TagValue::LAST_REDIRECT_DEVID_TAG),
+            "ANSWERING_DEVID_TAG" => Some(TagValue::ANSWERING_DEVID_TAG),
+            "HOLDING_DEVID_TAG" => Some(TagValue::HOLDING_DEVID_TAG),
+            "RETREIVING_DEVID_TAG" => Some(TagValue::RETREIVING_DEVID_TAG),
+            "RELEASING_DEVID_TAG" => Some(TagValue::RELEASING_DEVID_TAG),
+            "FAILING_DEVID_TAG" => Some(TagValue::FAILING_DEVID_TAG),
+            "PRIMARY_DEVID_TAG" => Some(TagValue::PRIMARY_DEVID_TAG),
+            "SECONDARY_DEVID_TAG" => Some(TagValue::SECONDARY_DEVID_TAG),
+            "CONTROLLER_DEVID_TAG" => Some(TagValue::CONTROLLER_DEVID_TAG),
+            "ADD_PARTY_DEVID_TAG" => Some(TagValue::ADD_PARTY_DEVID_TAG),
+            "PARTY_CALLID_TAG" => Some(TagValue::PARTY_CALLID_TAG),
+            "PARTY_DEVID_TYPE_TAG" => Some(TagValue::PARTY_DEVID_TYPE_TAG),
+            "PARTY_DEVID_TAG" => Some(TagValue::PARTY_DEVID_TAG),
+            "TRANSFERRING_DEVID_TAG" => Some(TagValue::TRANSFERRING_DEVID_TAG),
+            "TRANSFERRED_DEVID_TAG" => Some(TagValue::TRANSFERRED_DEVID_TAG),
+            "DIVERTING_DEVID_TAG" => Some(TagValue::DIVERTING_DEVID_TAG),
+            "QUEUE_DEVID_TAG" => Some(TagValue::QUEUE_DEVID_TAG),
+            "CALL_WRAPUP_DATA_TAG" => Some(TagValue::CALL_WRAPUP_DATA_TAG),
+            "NEW_CONNECTION_DEVID_TAG" => Some(TagValue::NEW_CONNECTION_DEVID_TAG),
+            "TRUNK_USED_DEVID_TAG" => Some(TagValue::TRUNK_USED_DEVID_TAG),
+            "AGENT_PASSWORD_TAG" => Some(TagValue::AGENT_PASSWORD_TAG),
+            "ACTIVE_CONN_DEVID_TAG" => Some(TagValue::ACTIVE_CONN_DEVID_TAG),
+            "FACILITY_CODE_TAG" => Some(TagValue::FACILITY_CODE_TAG),
+            "OTHER_CONN_DEVID_TAG" => Some(TagValue::OTHER_CONN_DEVID_TAG),
+            "HELD_CONN_DEVID_TAG" => Some(TagValue::HELD_CONN_DEVID_TAG),
+            "RESERVED54" => Some(TagValue::RESERVED54),
+            "RESERVED55" => Some(TagValue::RESERVED55),
+            "CALL_CONN_CALLID_TAG" => Some(TagValue::CALL_CONN_CALLID_TAG),
+            "CALL_CONN_DEVID_TYPE_TAG" => Some(TagValue::CALL_CONN_DEVID_TYPE_TAG),
+            "CALL_CONN_DEVID_TAG" => Some(TagValue::CALL_CONN_DEVID_TAG),
+            "CALL_DEVID_TYPE_TAG" => Some(TagValue::CALL_DEVID_TYPE_TAG),
+            "CALL_DEVID_TAG" => Some(TagValue::CALL_DEVID_TAG),
+            "CALL_DEV_CONN_STATE_TAG" => Some(TagValue::CALL_DEV_CONN_STATE_TAG),
+            "SKILL_GROUP_NUMBER_TAG" => Some(TagValue::SKILL_GROUP_NUMBER_TAG),
+            "SKILL_GROUP_ID_TAG" => Some(TagValue::SKILL_GROUP_ID_TAG),
+            "SKILL_GROUP_PRIORITY_TAG" => Some(TagValue::SKILL_GROUP_PRIORITY_TAG),
+            "SKILL_GROUP_STATE_TAG" => Some(TagValue::SKILL_GROUP_STATE_TAG),
+            "OBJECT_NAME_TAG" => Some(TagValue::OBJECT_NAME_TAG),
+            "DTMF_STRING_TAG" => Some(TagValue::DTMF_STRING_TAG),
+            "POSITION_ID_TAG" => Some(TagValue::POSITION_ID_TAG),
+            "SUPERVISOR_ID_TAG" => Some(TagValue::SUPERVISOR_ID_TAG),
+            "LINE_HANDLE_TAG" => Some(TagValue::LINE_HANDLE_TAG),
+            "LINE_TYPE_TAG" => Some(TagValue::LINE_TYPE_TAG),
+            "ROUTER_CALL_KEY_DAY_TAG" => Some(TagValue::ROUTER_CALL_KEY_DAY_TAG),
+            "ROUTER_CALL_KEY_CALLID_TAG" => Some(TagValue::ROUTER_CALL_KEY_CALLID_TAG),
+            "RESERVED74" => Some(TagValue::RESERVED74),
+            "CALL_STATE_TAG" => Some(TagValue::CALL_STATE_TAG),
+            "MONITORED_DEVID_TAG" => Some(TagValue::MONITORED_DEVID_TAG),
+            "AUTHORIZATION_CODE_TAG" => Some(TagValue::AUTHORIZATION_CODE_TAG),
+            "ACCOUNT_CODE_TAG" => Some(TagValue::ACCOUNT_CODE_TAG),
+            "ORIGINATING_DEVID_TAG" => Some(TagValue::ORIGINATING_DEVID_TAG),
+            "ORIGINATING_LINE_ID_TAG" => Some(TagValue::ORIGINATING_LINE_ID_TAG),
+            "CLIENT_ADDRESS_TAG" => Some(TagValue::CLIENT_ADDRESS_TAG),
+            "NAMED_VARIABLE_TAG" => Some(TagValue::NAMED_VARIABLE_TAG),
+            "NAMED_ARRAY_TAG" => Some(TagValue::NAMED_ARRAY_TAG),
+            "CALL_CONTROL_TABLE_TAG" => Some(TagValue::CALL_CONTROL_TABLE_TAG),
+            "SUPERVISOR_INSTRUMENT_TAG" => Some(TagValue::SUPERVISOR_INSTRUMENT_TAG),
+            "ATC_AGENT_ID_TAG" => Some(TagValue::ATC_AGENT_ID_TAG),
+            "AGENT_FLAGS_TAG" => Some(TagValue::AGENT_FLAGS_TAG),
+            "ATC_AGENT_STATE_TAG" => Some(TagValue::ATC_AGENT_STATE_TAG),
+            "ATC_AGENT_STATE_DURATION_TAG" => Some(TagValue::ATC_AGENT_STATE_DURATION_TAG),
+            "AGENT_CONNECTION_DEVID_TAG" => Some(TagValue::AGENT_CONNECTION_DEVID_TAG),
+            "SUPERVISOR_CONNECTION_DEVID_TAG" => Some(TagValue::SUPERVISOR_CONNECTION_DEVID_TAG),
+            "LIST_TEAM_ID_TAG" => Some(TagValue::LIST_TEAM_ID_TAG),
+            "DEFAULT_DEVICE_PORT_ADDRESS_TAG" => Some(TagValue::DEFAULT_DEVICE_PORT_ADDRESS_TAG),
+            "SERVICE_NAME_TAG" => Some(TagValue::SERVICE_NAME_TAG),
+            "CUSTOMER_PHONE_NUMBER_TAG" => Some(TagValue::CUSTOMER_PHONE_NUMBER_TAG),
+            "CUSTOMER_ACCOUNT_NUMBER_TAG" => Some(TagValue::CUSTOMER_ACCOUNT_NUMBER_TAG),
+            "APP_PATH_ID_TAG" => Some(TagValue::APP_PATH_ID_TAG),
+            "UNIQUE_INSTANCE_ID_TAG" => Some(TagValue::UNIQUE_INSTANCE_ID_TAG),
+            "SCRIPT_SELECTOR_TAG" => Some(TagValue::SCRIPT_SELECTOR_TAG),
+            "APPLICATION_STRING1_TAG" => Some(TagValue::APPLICATION_STRING1_TAG),
+            "APPLICATION_STRING2_TAG" => Some(TagValue::APPLICATION_STRING2_TAG),
+            "ROUTER_CALL_KEY_SEQUENCE_NUM_TAG" => Some(TagValue::ROUTER_CALL_KEY_SEQUENCE_NUM_TAG),
+            "TRUNK_NUMBER_TAG" => Some(TagValue::TRUNK_NUMBER_TAG),
+            "TRUNK_GROUP_NUMBER_TAG" => Some(TagValue::TRUNK_GROUP_NUMBER_TAG),
+            "EXT_AGENT_STATE_TAG" => Some(TagValue::EXT_AGENT_STATE_TAG),
+            "DEQUEUE_TYPE_TAG" => Some(TagValue::DEQUEUE_TYPE_TAG),
+            "SENDING_ADDRESS_TAG" => Some(TagValue::SENDING_ADDRESS_TAG),
+            "SENDING_PORT_TAG" => Some(TagValue::SENDING_PORT_TAG),
+            "UNUSED127" => Some(TagValue::UNUSED127),
+            "UNUSED128" => Some(TagValue::UNUSED128),
+            "MAX_QUEUED_TAG" => Some(TagValue::MAX_QUEUED_TAG),
+            "QUEUE_ID_TAG" => Some(TagValue::QUEUE_ID_TAG),
+            "CUSTOMER_ID_TAG" => Some(TagValue::CUSTOMER_ID_TAG),
+            "SERVICE_SKILL_TARGET_ID_TAG" => Some(TagValue::SERVICE_SKILL_TARGET_ID_TAG),
+            "PERIPHERAL_NAME_TAG" => Some(TagValue::PERIPHERAL_NAME_TAG),
+            "DESCRIPTION_TAG" => Some(TagValue::DESCRIPTION_TAG),
+            "SERVICE_MEMBER_ID_TAG" => Some(TagValue::SERVICE_MEMBER_ID_TAG),
+            "SERVICE_MEMBER_PRIORITY_TAG" => Some(TagValue::SERVICE_MEMBER_PRIORITY_TAG),
+            "FIRST_NAME_TAG" => Some(TagValue::FIRST_NAME_TAG),
+            "LAST_NAME_TAG" => Some(TagValue::LAST_NAME_TAG),
+            "SKILL_GROUP_TAG" => Some(TagValue::SKILL_GROUP_TAG),
+            "AGENT_SKILL_TARGET_ID_TAG" => Some(TagValue::AGENT_SKILL_TARGET_ID_TAG),
+            "SERVICE_TAG" => Some(TagValue::SERVICE_TAG),
+            "RESERVED143" => Some(TagValue::RESERVED143),
+            "RESERVED144" => Some(TagValue::RESERVED144),
+            "RESERVED145" => Some(TagValue::RESERVED145),
+            "RESERVED146" => Some(TagValue::RESERVED146),
+            "RESERVED147" => Some(TagValue::RESERVED147),
+            "RESERVED148" => Some(TagValue::RESERVED148),
+            "RESERVED149" => Some(TagValue::RESERVED149),
+            "DURATION_TAG" => Some(TagValue::DURATION_TAG),
+            "RESERVED151" => Some(TagValue::RESERVED151),
+            "RESERVED152" => Some(TagValue::RESERVED152),
+            "RESERVED153" => Some(TagValue::RESERVED153),
+            "RESERVED154" => Some(TagValue::RESERVED154),
+            "RESERVED155" => Some(TagValue::RESERVED155),
+            "RESERVED156" => Some(TagValue::RESERVED156),
+            "RESERVED157" => Some(TagValue::RESERVED157),
+            "RESERVED158" => Some(TagValue::RESERVED158),
+            "RESERVED159" => Some(TagValue::RESERVED159),
+            "RESERVED160" => Some(TagValue::RESERVED160),
+            "RESERVED161" => Some(TagValue::RESERVED161),
+            "RESERVED162" => Some(TagValue::RESERVED162),
+            "RESERVED163" => Some(TagValue::RESERVED163),
+            "RESERVED164" => Some(TagValue::RESERVED164),
+            "RESERVED165" => Some(TagValue::RESERVED165),
+            "RESERVED166" => Some(TagValue::RESERVED166),
+            "RESERVED167" => Some(TagValue::RESERVED167),
+            "RESERVED168" => Some(TagValue::RESERVED168),
+            "RESERVED169" => Some(TagValue::RESERVED169),
+            "RESERVED170" => Some(TagValue::RESERVED170),
+            "RESERVED171" => Some(TagValue::RESERVED171),
+            "RESERVED172" => Some(TagValue::RESERVED172),
+            "EXTENSION_TAG" => Some(TagValue::EXTENSION_TAG),
+            "SERVICE_LEVEL_THRESHOLD_TAG" => Some(TagValue::SERVICE_LEVEL_THRESHOLD_TAG),
+            "SERVICE_LEVEL_TYPE_TAG" => Some(TagValue::SERVICE_LEVEL_TYPE_TAG),
+            "CONFIG_PARAM_TAG" => Some(TagValue::CONFIG_PARAM_TAG),
+            "SERVICE_CONFIG_KEY_TAG" => Some(TagValue::SERVICE_CONFIG_KEY_TAG),
+            "SKILL_GROUP_CONFIG_KEY_TAG" => Some(TagValue::SKILL_GROUP_CONFIG_KEY_TAG),
+            "AGENT_CONFIG_KEY_TAG" => Some(TagValue::AGENT_CONFIG_KEY_TAG),
+            "DEVICE_CONFIG_KEY_TAG" => Some(TagValue::DEVICE_CONFIG_KEY_TAG),
+            "UNUSED181" => Some(TagValue::UNUSED181),
+            "UNUSED182" => Some(TagValue::UNUSED182),
+            "RECORD_TYPE_TAG" => Some(TagValue::RECORD_TYPE_TAG),
+            "PERIPHERAL_NUMBER_TAG" => Some(TagValue::PERIPHERAL_NUMBER_TAG),
+            "AGENT_SKILL_TARGET_ID_TAG2" => Some(TagValue::AGENT_SKILL_TARGET_ID_TAG2),
+            "NUM_SERVICE_MEMBERS_TAG" => Some(TagValue::NUM_SERVICE_MEMBERS_TAG),
+            "SERVICE_MEMBER_TAG" => Some(TagValue::SERVICE_MEMBER_TAG),
+            "SERVICE_PRIORITY_TAG" => Some(TagValue::SERVICE_PRIORITY_TAG),
+            "AGENT_TYPE_TAG" => Some(TagValue::AGENT_TYPE_TAG),
+            "LOGIN_ID_TAG" => Some(TagValue::LOGIN_ID_TAG),
+            "NUM_SKILLS_TAG" => Some(TagValue::NUM_SKILLS_TAG),
+            "SKILL_GROUP_SKILL_TARGET_ID_TAG" => Some(TagValue::SKILL_GROUP_SKILL_TARGET_ID_TAG),
+            "SERVICE_ID_TAG" => Some(TagValue::SERVICE_ID_TAG),
+            "AGENT_ID_LONG_TAG" => Some(TagValue::AGENT_ID_LONG_TAG),
+            "DEVICE_TYPE_TAG" => Some(TagValue::DEVICE_TYPE_TAG),
+            "UNUSED196" => Some(TagValue::UNUSED196),
+            "UNUSED197" => Some(TagValue::UNUSED197),
+            "ENABLE_TAG" => Some(TagValue::ENABLE_TAG),
+            "DEVICEID_TAG" => Some(TagValue::DEVICEID_TAG),
+            "TIMEOUT_TAG" => Some(TagValue::TIMEOUT_TAG),
+            "CURRENT_ROUTE_TAG" => Some(TagValue::CURRENT_ROUTE_TAG),
+            "SECONDARY_CONNECTION_CALL_ID" => Some(TagValue::SECONDARY_CONNECTION_CALL_ID),
+            "PRIORITY_QUEUE_NUMBER_TAG" => Some(TagValue::PRIORITY_QUEUE_NUMBER_TAG),
+            "TEAM_NAME_TAG" => Some(TagValue::TEAM_NAME_TAG),
+            "MEMBER_TYPE_TAG" => Some(TagValue::MEMBER_TYPE_TAG),
+            "EVENT_DEVICE_ID_TAG" => Some(TagValue::EVENT_DEVICE_ID_TAG),
+            "LOGIN_NAME_TAG_V11" => Some(TagValue::LOGIN_NAME_TAG_V11),
+            "PERIPHERAL_ID_TAG_V11" => Some(TagValue::PERIPHERAL_ID_TAG_V11),
+            "CALL_TYPE_KEY_CONFIG_TAG_V11" => Some(TagValue::CALL_TYPE_KEY_CONFIG_TAG_V11),
+            "CALL_TYPE_ID_TAG_V11" => Some(TagValue::CALL_TYPE_ID_TAG_V11),
+            "CUSTOMER_DEFINITION_ID_TAG_V11" => Some(TagValue::CUSTOMER_DEFINITION_ID_TAG_V11),
+            "ENTERPRISE_NAME_TAG_V11" => Some(TagValue::ENTERPRISE_NAME_TAG_V11),
+            "OLD_PERIPHERAL_NUMBER_TAG" => Some(TagValue::OLD_PERIPHERAL_NUMBER_TAG),
+            "CUR_LOGIN_ID" => Some(TagValue::CUR_LOGIN_ID),
+            "ANI_II_TAG" => Some(TagValue::ANI_II_TAG),
+            "MR_DOMAIN_ID_TAG" => Some(TagValue::MR_DOMAIN_ID_TAG),
+            "CTIOS_CIL_CLIENT_ID_TAG" => Some(TagValue::CTIOS_CIL_CLIENT_ID_TAG),
+            "SILENT_MONITOR_STATUS_TAG" => Some(TagValue::SILENT_MONITOR_STATUS_TAG),
+            "REQUESTING_DEVICE_ID_TAG" => Some(TagValue::REQUESTING_DEVICE_ID_TAG),
+            "REQUESTING_DEVICE_ID_TYPE_TAG" => Some(TagValue::REQUESTING_DEVICE_ID_TYPE_TAG),
+            "PRE_CALL_INVOKE_ID_TAG" => Some(TagValue::PRE_CALL_INVOKE_ID_TAG),
+            "ENTERPRISE_QUEUE_TIME" => Some(TagValue::ENTERPRISE_QUEUE_TIME),
+            "CALL_REFERENCE_ID_TAG" => Some(TagValue::CALL_REFERENCE_ID_TAG),
+            "MULTI_LINE_AGENT_CONTROL_TAG" => Some(TagValue::MULTI_LINE_AGENT_CONTROL_TAG),
+            "NETWORK_CONTROLLED_TAG" => Some(TagValue::NETWORK_CONTROLLED_TAG),
+            "UNUSED226" => Some(TagValue::UNUSED226),
+            "UNUSED227" => Some(TagValue::UNUSED227),
+            "NUM_PERIPHERALS_TAG" => Some(TagValue::NUM_PERIPHERALS_TAG),
+            "COC_CONNECTION_CALL_ID_TAG" => Some(TagValue::COC_CONNECTION_CALL_ID_TAG),
+            "COC_CONNECTION_DEVICE_ID_TYPE_TAG" => {
+                Some(TagValue::COC_CONNECTION_DEVICE_ID_TYPE_TAG)
+            }
+            "COC_CONNECTION_DEVICE_ID_TAG" => Some(TagValue::COC_CONNECTION_DEVICE_ID_TAG),
+            "CALL_ORIGINATED_FROM_TAG" => Some(TagValue::CALL_ORIGINATED_FROM_TAG),
+            "SET_APPDATA_CALLID_TAG" => Some(TagValue::SET_APPDATA_CALLID_TAG),
+            "CLIENT_SHARE_KEY_TAG" => Some(TagValue::CLIENT_SHARE_KEY_TAG),
+            "AGENT_TEAM_NAME_TAG" => Some(TagValue::AGENT_TEAM_NAME_TAG),
+            "DIRECTION_TAG" => Some(TagValue::DIRECTION_TAG),
+            "OPTIONS_TAG" => Some(TagValue::OPTIONS_TAG),
+            "FLT_MRD_ID_TAG" => Some(TagValue::FLT_MRD_ID_TAG),
+            "MEDIA_CLASS_ID_TAG" => Some(TagValue::MEDIA_CLASS_ID_TAG),
+            "TASK_LIFE_TAG" => Some(TagValue::TASK_LIFE_TAG),
+            "TASK_START_TIMEOUT_TAG" => Some(TagValue::TASK_START_TIMEOUT_TAG),
+            "MAX_TASK_DURATION_TAG" => Some(TagValue::MAX_TASK_DURATION_TAG),
+            "INTERRUPTIBLE_TAG" => Some(TagValue::INTERRUPTIBLE_TAG),
+            "MAX_CALLS_IN_QUEUE_TAG" => Some(TagValue::MAX_CALLS_IN_QUEUE_TAG),
+            "MAX_CALLS_IN_QUEUE_PER_CALL_TYPE_TAG" => {
+                Some(TagValue::MAX_CALLS_IN_QUEUE_PER_CALL_TYPE_TAG)
+            }
+            "MAX_TIME_IN_QUEUE_TAG" => Some(TagValue::MAX_TIME_IN_QUEUE_TAG),
+            "INTERNAL_AGENT_STATE_TAG" => Some(TagValue::INTERNAL_AGENT_STATE_TAG),
+            "UNUSED256" => Some(TagValue::UNUSED256),
+            "SSO_ENABLED_TAG" => Some(TagValue::SSO_ENABLED_TAG),
+            "FLT_TASK_ID_TAG" => Some(TagValue::FLT_TASK_ID_TAG),
+            "FLT_ICM_DISP_TAG" => Some(TagValue::FLT_ICM_DISP_TAG),
+            "FLT_APP_DISP_TAG" => Some(TagValue::FLT_APP_DISP_TAG),
+            "NUM_MRDS_TAG" => Some(TagValue::NUM_MRDS_TAG),
+            "FLT_AGENT_MRD_ID_TAG" => Some(TagValue::FLT_AGENT_MRD_ID_TAG),
+            "FLT_AGENT_MRD_STATE_TAG" => Some(TagValue::FLT_AGENT_MRD_STATE_TAG),
+            "FLT_PRECISION_QUEUE_ID_TAG" => Some(TagValue::FLT_PRECISION_QUEUE_ID_TAG),
+            "FLT_PRECISION_QUEUE_NAME_TAG" => Some(TagValue::FLT_PRECISION_QUEUE_NAME_TAG),
+            "MAX_BEYOND_TASK_LIMIT_TAG" => Some(TagValue::MAX_BEYOND_TASK_LIMIT_TAG),
+            "AGENT_DESK_SETTINGS_ID_TAG" => Some(TagValue::AGENT_DESK_SETTINGS_ID_TAG),
+            "XFER_IN_WHILE_LOGGED_OUT_TAG" => Some(TagValue::XFER_IN_WHILE_LOGGED_OUT_TAG),
+            "PERIPHERAL_CONFIG_KEY_TAG" => Some(TagValue::PERIPHERAL_CONFIG_KEY_TAG),
+            "AGENT_DESK_SETTINGS_CONFIG_KEY_TAG" => {
+                Some(TagValue::AGENT_DESK_SETTINGS_CONFIG_KEY_TAG)
+            }
+            "CONFIG_PERIPHERAL_ID_TAG" => Some(TagValue::CONFIG_PERIPHERAL_ID_TAG),
+            "DEFAULT_AGENT_DESK_SETTINGS_ID_TAG" => {
+                Some(TagValue::DEFAULT_AGENT_DESK_SETTINGS_ID_TAG)
+            }
+            "FLT_DESK_SETTINGS_MASK_TAG" => Some(TagValue::FLT_DESK_SETTINGS_MASK_TAG),
+            "FLT_WRAP_UP_DATA_INCOMING_MODE_TAG" => {
+                Some(TagValue::FLT_WRAP_UP_DATA_INCOMING_MODE_TAG)
+            }
+            "FLT_WRAP_UP_DATA_OUTGOING_MODE_TAG" => {
+                Some(TagValue::FLT_WRAP_UP_DATA_OUTGOING_MODE_TAG)
+            }
+            "FLT_LOGOUT_NON_ACTIVITY_TIME_TAG" => Some(TagValue::FLT_LOGOUT_NON_ACTIVITY_TIME_TAG),
+            "FLT_QUALITY_RECORDING_RATE_TAG" => Some(TagValue::FLT_QUALITY_RECORDING_RATE_TAG),
+            "FLT_RING_NO_ANSWER_TIME_TAG" => Some(TagValue::FLT_RING_NO_ANSWER_TIME_TAG),
+            "FLT_SILENT_MONITOR_WARNING_MESSAGE_TAG" => {
+                Some(TagValue::FLT_SILENT_MONITOR_WARNING_MESSAGE_TAG)
+            }
+            "FLT_SILENT_MONITOR_AUDIBLE_INDICATION_TAG" => {
+                Some(TagValue::FLT_SILENT_MONITOR_AUDIBLE_INDICATION_TAG)
+            }
+            "FLT_SUPERVISOR_ASSIST_CALL_METHOD_TAG" => {
+                Some(TagValue::FLT_SUPERVISOR_ASSIST_CALL_METHOD_TAG)
+            }
+            "FLT_EMERGENCY_CALL_METHOD_TAG" => Some(TagValue::FLT_EMERGENCY_CALL_METHOD_TAG),
+            "FLT_AUTO_RECORD_ON_EMERGENCY_TAG" => Some(TagValue::FLT_AUTO_RECORD_ON_EMERGENCY_TAG),
+            "FLT_RECORDING_MODE_TAG" => Some(TagValue::FLT_RECORDING_MODE_TAG),
+            "FLT_WORK_MODE_TIMER_TAG" => Some(TagValue::FLT_WORK_MODE_TIMER_TAG),
+            "FLT_RING_NO_ANSWER_DN_ID_TAG" => Some(TagValue::FLT_RING_NO_ANSWER_DN_ID_TAG),
+            "FLT_DEFAULT_DEVICE_PORT_ADDRESS_TAG" => {
+                Some(TagValue::FLT_DEFAULT_DEVICE_PORT_ADDRESS_TAG)
+            }
+            "DESKTOP_CONNECTED_FLAG_TAG" => Some(TagValue::DESKTOP_CONNECTED_FLAG_TAG),
+            "PLAY_TONE_DIRECTION_TAG" => Some(TagValue::PLAY_TONE_DIRECTION_TAG),
+            "INVOCATION_TYPE_TAG" => Some(TagValue::INVOCATION_TYPE_TAG),
+            "RECORDER_ADDRESS_TAG" => Some(TagValue::RECORDER_ADDRESS_TAG),
+            "TERMINAL_NAME_TAG" => Some(TagValue::TERMINAL_NAME_TAG),
+            "MEDIA_FORKING_DEVICE_NAME_TAG" => Some(TagValue::MEDIA_FORKING_DEVICE_NAME_TAG),
+            "PROTOCOL_REFERENCE_GUID_TAG" => Some(TagValue::PROTOCOL_REFERENCE_GUID_TAG),
+            "MEDIA_FORKING_CLUSTER_ID_TAG" => Some(TagValue::MEDIA_FORKING_CLUSTER_ID_TAG),
+            "RECORDER_URI_TAG" => Some(TagValue::RECORDER_URI_TAG),
+            "RECORDER_ERROR_MSG_TAG" => Some(TagValue::RECORDER_ERROR_MSG_TAG),
+            "RECORDER_TYPE_TAG" => Some(TagValue::RECORDER_TYPE_TAG),
+            "RECORDER_STATUS_TAG" => Some(TagValue::RECORDER_STATUS_TAG),
+            "RECORDING_DEVICE_ID_TAG" => Some(TagValue::RECORDING_DEVICE_ID_TAG),
+            "FLT_TERM_TYPE" => Some(TagValue::FLT_TERM_TYPE),
+            "FLT_TERM_DEVICE_NAME" => Some(TagValue::FLT_TERM_DEVICE_NAME),
+            "FLT_TERM_TYPE_NAME" => Some(TagValue::FLT_TERM_TYPE_NAME),
+            "FLT_NUM_INSTRUMENTS" => Some(TagValue::FLT_NUM_INSTRUMENTS),
+            "ACD_SHARED_LINE_USAGE" => Some(TagValue::ACD_SHARED_LINE_USAGE),
+            "PLAY_ZIP_TONE" => Some(TagValue::PLAY_ZIP_TONE),
+            "FLT_ENABLED_SERVICES" => Some(TagValue::FLT_ENABLED_SERVICES),
+            "NUM_OF_ENABLED_SERVICES" => Some(TagValue::NUM_OF_ENABLED_SERVICES),
+            "CCAI_CONFIG_ID" => Some(TagValue::CCAI_CONFIG_ID),
+            "NUM_POSITIVE_ANSWERS_SUGGESTIONS" => Some(TagValue::NUM_POSITIVE_ANSWERS_SUGGESTIONS),
+            "NUM_NEGATIVE_ANSWERS_SUGGESTIONS" => Some(TagValue::NUM_NEGATIVE_ANSWERS_SUGGESTIONS),
+            _ => None,
+        }
+    }
+
+    ///
+    /// 태그의 데이터 유형을 반환한다
+    ///
+    pub fn data_type(&self) -> TagDataType {
         match self {
+            TagValue::CLIENT_ID_TAG
+            | TagValue::CLIENT_PASSWORD_TAG
+            | TagValue::CLIENT_SIGNATURE_TAG
+            | TagValue::AGENT_EXTENSION_TAG
+            | TagValue::AGENT_ID_TAG
+            | TagValue::AGENT_INSTRUMENT_TAG
+            | TagValue::TEXT_TAG
+            | TagValue::ANI_TAG
+            | TagValue::DNIS_TAG
+            | TagValue::DIALED_NUMBER_TAG
+            | TagValue::CED_TAG
+            | TagValue::CALL_VAR_1_TAG
+            | TagValue::CALL_VAR_2_TAG
+            | TagValue::CALL_VAR_3_TAG
+            | TagValue::CALL_VAR_4_TAG
+            | TagValue::CALL_VAR_5_TAG
+            | TagValue::CALL_VAR_6_TAG
+            | TagValue::CALL_VAR_7_TAG
+            | TagValue::CALL_VAR_8_TAG
+            | TagValue::CALL_VAR_9_TAG
+            | TagValue::CALL_VAR_10_TAG
+            | TagValue::CTI_CLIENT_SIGNATURE_TAG
+            | TagValue::CONNECTION_DEVID_TAG
+            | TagValue::ALERTING_DEVID_TAG
+            | TagValue::CALLING_DEVID_TAG
+            | TagValue::CALLED_DEVID_TAG
+            | TagValue::LAST_REDIRECT_DEVID_TAG
+            | TagValue::ANSWERING_DEVID_TAG
+            | TagValue::HOLDING_DEVID_TAG
+            | TagValue::RETREIVING_DEVID_TAG
+            | TagValue::RELEASING_DEVID_TAG
+            | TagValue::FAILING_DEVID_TAG
+            | TagValue::PRIMARY_DEVID_TAG
+            | TagValue::SECONDARY_DEVID_TAG
+            | TagValue::CONTROLLER_DEVID_TAG
+            | TagValue::ADD_PARTY_DEVID_TAG
+            | TagValue::PARTY_DEVID_TAG
+            | TagValue::TRANSFERRING_DEVID_TAG
+            | TagValue::TRANSFERRED_DEVID_TAG
+            | TagValue::DIVERTING_DEVID_TAG
+            | TagValue::QUEUE_DEVID_TAG
+            | TagValue::CALL_WRAPUP_DATA_TAG
+            | TagValue::NEW_CONNECTION_DEVID_TAG
+            | TagValue::TRUNK_USED_DEVID_TAG
+            | TagValue::AGENT_PASSWORD_TAG
+            | TagValue::ACTIVE_CONN_DEVID_TAG
+            | TagValue::FACILITY_CODE_TAG
+            | TagValue::OTHER_CONN_DEVID_TAG
+            | TagValue::HELD_CONN_DEVID_TAG
+            | TagValue::CALL_CONN_DEVID_TAG
+            | TagValue::CALL_DEVID_TAG
+            | TagValue::OBJECT_NAME_TAG
+            | TagValue::DTMF_STRING_TAG
+            | TagValue::POSITION_ID_TAG
+            | TagValue::SUPERVISOR_ID_TAG
+            | TagValue::MONITORED_DEVID_TAG
+            | TagValue::AUTHORIZATION_CODE_TAG
+            | TagValue::ACCOUNT_CODE_TAG
+            | TagValue::ORIGINATING_DEVID_TAG
+            | TagValue::ORIGINATING_LINE_ID_TAG
+            | TagValue::CLIENT_ADDRESS_TAG
+            | TagValue::SUPERVISOR_INSTRUMENT_TAG
+            | TagValue::ATC_AGENT_ID_TAG
+            | TagValue::AGENT_CONNECTION_DEVID_TAG
+            | TagValue::SUPERVISOR_CONNECTION_DEVID_TAG
+            | TagValue::DEFAULT_DEVICE_PORT_ADDRESS_TAG
+            | TagValue::SERVICE_NAME_TAG
+            | TagValue::CUSTOMER_PHONE_NUMBER_TAG
+            | TagValue::CUSTOMER_ACCOUNT_NUMBER_TAG
+            | TagValue::SCRIPT_SELECTOR_TAG
+            | TagValue::APPLICATION_STRING1_TAG
+            | TagValue::APPLICATION_STRING2_TAG
+            | TagValue::TRUNK_NUMBER_TAG
+            | TagValue::TRUNK_GROUP_NUMBER_TAG
+            | TagValue::SENDING_ADDRESS_TAG
+            | TagValue::PERIPHERAL_NAME_TAG
+            | TagValue::DESCRIPTION_TAG
+            | TagValue::FIRST_NAME_TAG
+            | TagValue::LAST_NAME_TAG
+            | TagValue::EXTENSION_TAG
+            | TagValue::LOGIN_ID_TAG
+            | TagValue::AGENT_ID_LONG_TAG
+            | TagValue::DEVICEID_TAG
+            | TagValue::TEAM_NAME_TAG
+            | TagValue::EVENT_DEVICE_ID_TAG
+            | TagValue::LOGIN_NAME_TAG_V11
+            | TagValue::ENTERPRISE_NAME_TAG_V11
+            | TagValue::CUR_LOGIN_ID
+            | TagValue::ANI_II_TAG
+            | TagValue::CTIOS_CIL_CLIENT_ID_TAG
+            | TagValue::REQUESTING_DEVICE_ID_TAG
+            | TagValue::COC_CONNECTION_DEVICE_ID_TAG
+            | TagValue::AGENT_TEAM_NAME_TAG
+            | TagValue::FLT_PRECISION_QUEUE_NAME_TAG
+            | TagValue::FLT_SILENT_MONITOR_WARNING_MESSAGE_TAG
+            | TagValue::FLT_DEFAULT_DEVICE_PORT_ADDRESS_TAG
+            | TagValue::RECORDER_ADDRESS_TAG
+            | TagValue::TERMINAL_NAME_TAG
+            | TagValue::MEDIA_FORKING_DEVICE_NAME_TAG
+            | TagValue::PROTOCOL_REFERENCE_GUID_TAG
+            | TagValue::RECORDER_URI_TAG
+            | TagValue::RECORDER_ERROR_MSG_TAG
+            | TagValue::RECORDING_DEVICE_ID_TAG
+            | TagValue::FLT_TERM_DEVICE_NAME
+            | TagValue::FLT_TERM_TYPE_NAME
+            | TagValue::CCAI_CONFIG_ID => TagDataType::STRING,
+            TagValue::ENABLE_TAG
+            | TagValue::NETWORK_CONTROLLED_TAG
+            | TagValue::INTERRUPTIBLE_TAG
+            | TagValue::SSO_ENABLED_TAG
+            | TagValue::XFER_IN_WHILE_LOGGED_OUT_TAG
+            | TagValue::DESKTOP_CONNECTED_FLAG_TAG
+            | TagValue::PLAY_ZIP_TONE => TagDataType::BOOL,
+            TagValue::PARTY_DEVID_TYPE_TAG
+            | TagValue::CALL_CONN_DEVID_TYPE_TAG
+            | TagValue::CALL_DEVID_TYPE_TAG
+            | TagValue::CALL_DEV_CONN_STATE_TAG
+            | TagValue::SKILL_GROUP_PRIORITY_TAG
+            | TagValue::SKILL_GROUP_STATE_TAG
+            | TagValue::LINE_HANDLE_TAG
+            | TagValue::LINE_TYPE_TAG
+            | TagValue::CALL_STATE_TAG
+            | TagValue::AGENT_FLAGS_TAG
+            | TagValue::ATC_AGENT_STATE_TAG
+            | TagValue::EXT_AGENT_STATE_TAG
+            | TagValue::DEQUEUE_TYPE_TAG
+            | TagValue::SERVICE_MEMBER_PRIORITY_TAG
+            | TagValue::SKILL_GROUP_TAG
+            | TagValue::SERVICE_LEVEL_TYPE_TAG
+            | TagValue::RECORD_TYPE_TAG
+            | TagValue::NUM_SERVICE_MEMBERS_TAG
+            | TagValue::SERVICE_PRIORITY_TAG
+            | TagValue::AGENT_TYPE_TAG
+            | TagValue::NUM_SKILLS_TAG
+            | TagValue::DEVICE_TYPE_TAG
+            | TagValue::MEMBER_TYPE_TAG
+            | TagValue::SILENT_MONITOR_STATUS_TAG
+            | TagValue::REQUESTING_DEVICE_ID_TYPE_TAG
+            | TagValue::MULTI_LINE_AGENT_CONTROL_TAG
+            | TagValue::NUM_PERIPHERALS_TAG
+            | TagValue::COC_CONNECTION_DEVICE_ID_TYPE_TAG
+            | TagValue::CALL_ORIGINATED_FROM_TAG
+            | TagValue::INTERNAL_AGENT_STATE_TAG
+            | TagValue::NUM_MRDS_TAG
+            | TagValue::FLT_AGENT_MRD_STATE_TAG
+            | TagValue::PLAY_TONE_DIRECTION_TAG
+            | TagValue::INVOCATION_TYPE_TAG
+            | TagValue::RECORDER_TYPE_TAG
+            | TagValue::RECORDER_STATUS_TAG
+            | TagValue::FLT_TERM_TYPE
+            | TagValue::FLT_NUM_INSTRUMENTS
+            | TagValue::ACD_SHARED_LINE_USAGE
+            | TagValue::NUM_OF_ENABLED_SERVICES
+            | TagValue::NUM_POSITIVE_ANSWERS_SUGGESTIONS
+            | TagValue::NUM_NEGATIVE_ANSWERS_SUGGESTIONS => TagDataType::USHORT,
+            TagValue::PARTY_CALLID_TAG
+            | TagValue::CALL_CONN_CALLID_TAG
+            | TagValue::SKILL_GROUP_ID_TAG
+            | TagValue::ROUTER_CALL_KEY_DAY_TAG
+            | TagValue::ROUTER_CALL_KEY_CALLID_TAG
+            | TagValue::ATC_AGENT_STATE_DURATION_TAG
+            | TagValue::LIST_TEAM_ID_TAG
+            | TagValue::ROUTER_CALL_KEY_SEQUENCE_NUM_TAG
+            | TagValue::SENDING_PORT_TAG
+            | TagValue::MAX_QUEUED_TAG
+            | TagValue::QUEUE_ID_TAG
+            | TagValue::CUSTOMER_ID_TAG
+            | TagValue::SERVICE_SKILL_TARGET_ID_TAG
+            | TagValue::SERVICE_MEMBER_ID_TAG
+            | TagValue::AGENT_SKILL_TARGET_ID_TAG
+            | TagValue::SERVICE_TAG
+            | TagValue::DURATION_TAG
+            | TagValue::SERVICE_LEVEL_THRESHOLD_TAG
+            | TagValue::SERVICE_CONFIG_KEY_TAG
+            | TagValue::SKILL_GROUP_CONFIG_KEY_TAG
+            | TagValue::AGENT_CONFIG_KEY_TAG
+            | TagValue::DEVICE_CONFIG_KEY_TAG
+            | TagValue::PERIPHERAL_NUMBER_TAG
+            | TagValue::AGENT_SKILL_TARGET_ID_TAG2
+            | TagValue::SKILL_GROUP_SKILL_TARGET_ID_TAG
+            | TagValue::SERVICE_ID_TAG
+            | TagValue::TIMEOUT_TAG
+            | TagValue::CURRENT_ROUTE_TAG
+            | TagValue::SECONDARY_CONNECTION_CALL_ID
+            | TagValue::PRIORITY_QUEUE_NUMBER_TAG
+            | TagValue::PERIPHERAL_ID_TAG_V11
+            | TagValue::CALL_TYPE_KEY_CONFIG_TAG_V11
+            | TagValue::CALL_TYPE_ID_TAG_V11
+            | TagValue::CUSTOMER_DEFINITION_ID_TAG_V11
+            | TagValue::OLD_PERIPHERAL_NUMBER_TAG
+            | TagValue::MR_DOMAIN_ID_TAG
+            | TagValue::PRE_CALL_INVOKE_ID_TAG
+            | TagValue::ENTERPRISE_QUEUE_TIME
+            | TagValue::COC_CONNECTION_CALL_ID_TAG
+            | TagValue::SET_APPDATA_CALLID_TAG
+            | TagValue::CLIENT_SHARE_KEY_TAG
+            | TagValue::DIRECTION_TAG
+            | TagValue::OPTIONS_TAG
+            | TagValue::FLT_MRD_ID_TAG
+            | TagValue::MEDIA_CLASS_ID_TAG
+            | TagValue::TASK_LIFE_TAG
+            | TagValue::TASK_START_TIMEOUT_TAG
+            | TagValue::MAX_TASK_DURATION_TAG
+            | TagValue::MAX_CALLS_IN_QUEUE_TAG
+            | TagValue::MAX_CALLS_IN_QUEUE_PER_CALL_TYPE_TAG
+            | TagValue::MAX_TIME_IN_QUEUE_TAG
+            | TagValue::FLT_ICM_DISP_TAG
+            | TagValue::FLT_APP_DISP_TAG
+            | TagValue::FLT_AGENT_MRD_ID_TAG
+            | TagValue::FLT_PRECISION_QUEUE_ID_TAG
+            | TagValue::MAX_BEYOND_TASK_LIMIT_TAG
+            | TagValue::AGENT_DESK_SETTINGS_ID_TAG
+            | TagValue::PERIPHERAL_CONFIG_KEY_TAG
+            | TagValue::AGENT_DESK_SETTINGS_CONFIG_KEY_TAG
+            | TagValue::CONFIG_PERIPHERAL_ID_TAG
+            | TagValue::DEFAULT_AGENT_DESK_SETTINGS_ID_TAG
+            | TagValue::FLT_DESK_SETTINGS_MASK_TAG
+            | TagValue::FLT_WRAP_UP_DATA_INCOMING_MODE_TAG
+            | TagValue::FLT_WRAP_UP_DATA_OUTGOING_MODE_TAG
+            | TagValue::FLT_LOGOUT_NON_ACTIVITY_TIME_TAG
+            | TagValue::FLT_QUALITY_RECORDING_RATE_TAG
+            | TagValue::FLT_RING_NO_ANSWER_TIME_TAG
+            | TagValue::FLT_SILENT_MONITOR_AUDIBLE_INDICATION_TAG
+            | TagValue::FLT_SUPERVISOR_ASSIST_CALL_METHOD_TAG
+            | TagValue::FLT_EMERGENCY_CALL_METHOD_TAG
+            | TagValue::FLT_AUTO_RECORD_ON_EMERGENCY_TAG
+            | TagValue::FLT_RECORDING_MODE_TAG
+            | TagValue::FLT_WORK_MODE_TIMER_TAG
+            | TagValue::FLT_RING_NO_ANSWER_DN_ID_TAG
+            | TagValue::MEDIA_FORKING_CLUSTER_ID_TAG
+            | TagValue::FLT_ENABLED_SERVICES => TagDataType::UINT,
+            TagValue::SKILL_GROUP_NUMBER_TAG
+            | TagValue::APP_PATH_ID_TAG
+            | TagValue::UNIQUE_INSTANCE_ID_TAG => TagDataType::INT,
+            TagValue::CTI_CLIENT_TIMESTAMP_TAG => TagDataType::TIME,
+            TagValue::UUI_TAG
+            | TagValue::RESERVED54
+            | TagValue::RESERVED55
+            | TagValue::RESERVED74
+            | TagValue::NAMED_VARIABLE_TAG
+            | TagValue::NAMED_ARRAY_TAG
+            | TagValue::CALL_CONTROL_TABLE_TAG
+            | TagValue::UNUSED127
+            | TagValue::UNUSED128
+            | TagValue::RESERVED143
+            | TagValue::RESERVED144
+            | TagValue::RESERVED145
+            | TagValue::RESERVED146
+            | TagValue::RESERVED147
+            | TagValue::RESERVED148
+            | TagValue::RESERVED149
+            | TagValue::RESERVED151
+            | TagValue::RESERVED152
+            | TagValue::RESERVED153
+            | TagValue::RESERVED154
+            | TagValue::RESERVED155
+            | TagValue::RESERVED156
+            | TagValue::RESERVED157
+            | TagValue::RESERVED158
+            | TagValue::RESERVED159
+            | TagValue::RESERVED160
+            | TagValue::RESERVED161
+            | TagValue::RESERVED162
+            | TagValue::RESERVED163
+            | TagValue::RESERVED164
+            | TagValue::RESERVED165
+            | TagValue::RESERVED166
+            | TagValue::RESERVED167
+            | TagValue::RESERVED168
+            | TagValue::RESERVED169
+            | TagValue::RESERVED170
+            | TagValue::RESERVED171
+            | TagValue::RESERVED172
+            | TagValue::CONFIG_PARAM_TAG
+            | TagValue::UNUSED181
+            | TagValue::UNUSED182
+            | TagValue::SERVICE_MEMBER_TAG
+            | TagValue::UNUSED196
+            | TagValue::UNUSED197
+            | TagValue::CALL_REFERENCE_ID_TAG
+            | TagValue::UNUSED226
+            | TagValue::UNUSED227
+            | TagValue::UNUSED256
+            | TagValue::FLT_TASK_ID_TAG
+            | TagValue::UNKNOWN(_) => TagDataType::UNSPEC,
+        }
+    }
+}
+
+impl From<u16> for TagValue {
+    fn from(value: u16) -> Self {
+        match value {
             1 => TagValue::CLIENT_ID_TAG,
             2 => TagValue::CLIENT_PASSWORD_TAG,
             3 => TagValue::CLIENT_SIGNATURE_TAG,
             4 => TagValue::AGENT_EXTENSION_TAG,
             5 => TagValue::AGENT_ID_TAG,
             6 => TagValue::AGENT_INSTRUMENT_TAG,
-            07 => TagValue::TEXT_TAG,
+            7 => TagValue::TEXT_TAG,
             8 => TagValue::ANI_TAG,
             9 => TagValue::UUI_TAG,
             10 => TagValue::DNIS_TAG,
@@ -367,7 +1301,6 @@ impl Into<TagValue> for u16 {
             71 => TagValue::LINE_TYPE_TAG,
             72 => TagValue::ROUTER_CALL_KEY_DAY_TAG,
             73 => TagValue::ROUTER_CALL_KEY_CALLID_TAG,
-            110 => TagValue::ROUTER_CALL_KEY_SEQUENCE_NUM_TAG,
             74 => TagValue::RESERVED74,
             75 => TagValue::CALL_STATE_TAG,
             76 => TagValue::MONITORED_DEVID_TAG,
@@ -392,9 +1325,11 @@ impl Into<TagValue> for u16 {
             95 => TagValue::CUSTOMER_PHONE_NUMBER_TAG,
             96 => TagValue::CUSTOMER_ACCOUNT_NUMBER_TAG,
             97 => TagValue::APP_PATH_ID_TAG,
+            98 => TagValue::UNIQUE_INSTANCE_ID_TAG,
             99 => TagValue::SCRIPT_SELECTOR_TAG,
             100 => TagValue::APPLICATION_STRING1_TAG,
             101 => TagValue::APPLICATION_STRING2_TAG,
+            110 => TagValue::ROUTER_CALL_KEY_SEQUENCE_NUM_TAG,
             121 => TagValue::TRUNK_NUMBER_TAG,
             122 => TagValue::TRUNK_GROUP_NUMBER_TAG,
             123 => TagValue::EXT_AGENT_STATE_TAG,
@@ -458,7 +1393,7 @@ impl Into<TagValue> for u16 {
             182 => TagValue::UNUSED182,
             183 => TagValue::RECORD_TYPE_TAG,
             184 => TagValue::PERIPHERAL_NUMBER_TAG,
-            185 => TagValue::AGENT_SKILL_TARGET_ID_TAG2, // ???
+            185 => TagValue::AGENT_SKILL_TARGET_ID_TAG2,
             186 => TagValue::NUM_SERVICE_MEMBERS_TAG,
             187 => TagValue::SERVICE_MEMBER_TAG,
             188 => TagValue::SERVICE_PRIORITY_TAG,
@@ -577,20 +1512,324 @@ impl Into<TagValue> for u16 {
             311 => TagValue::CCAI_CONFIG_ID,
             312 => TagValue::NUM_POSITIVE_ANSWERS_SUGGESTIONS,
             313 => TagValue::NUM_NEGATIVE_ANSWERS_SUGGESTIONS,
-            n => panic!("Invalid tag value. tag_value: {}", n),
+            n => TagValue::UNKNOWN(n),
+        }
+    }
+}
+
+impl From<TagValue> for u16 {
+    fn from(value: TagValue) -> Self {
+        match value {
+            TagValue::CLIENT_ID_TAG => 1,
+            TagValue::CLIENT_PASSWORD_TAG => 2,
+            TagValue::CLIENT_SIGNATURE_TAG => 3,
+            TagValue::AGENT_EXTENSION_TAG => 4,
+            TagValue::AGENT_ID_TAG => 5,
+            TagValue::AGENT_INSTRUMENT_TAG => 6,
+            TagValue::TEXT_TAG => 7,
+            TagValue::ANI_TAG => 8,
+            TagValue::UUI_TAG => 9,
+            TagValue::DNIS_TAG => 10,
+            TagValue::DIALED_NUMBER_TAG => 11,
+            TagValue::CED_TAG => 12,
+            TagValue::CALL_VAR_1_TAG => 13,
+            TagValue::CALL_VAR_2_TAG => 14,
+            TagValue::CALL_VAR_3_TAG => 15,
+            TagValue::CALL_VAR_4_TAG => 16,
+            TagValue::CALL_VAR_5_TAG => 17,
+            TagValue::CALL_VAR_6_TAG => 18,
+            TagValue::CALL_VAR_7_TAG => 19,
+            TagValue::CALL_VAR_8_TAG => 20,
+            TagValue::CALL_VAR_9_TAG => 21,
+            TagValue::CALL_VAR_10_TAG => 22,
+            TagValue::CTI_CLIENT_SIGNATURE_TAG => 23,
+            TagValue::CTI_CLIENT_TIMESTAMP_TAG => 24,
+            TagValue::CONNECTION_DEVID_TAG => 25,
+            TagValue::ALERTING_DEVID_TAG => 26,
+            TagValue::CALLING_DEVID_TAG => 27,
+            TagValue::CALLED_DEVID_TAG => 28,
+            TagValue::LAST_REDIRECT_DEVID_TAG => 29,
+            TagValue::ANSWERING_DEVID_TAG => 30,
+            TagValue::HOLDING_DEVID_TAG => 31,
+            TagValue::RETREIVING_DEVID_TAG => 32,
+            TagValue::RELEASING_DEVID_TAG => 33,
+            TagValue::FAILING_DEVID_TAG => 34,
+            TagValue::PRIMARY_DEVID_TAG => 35,
+            TagValue::SECONDARY_DEVID_TAG => 36,
+            TagValue::CONTROLLER_DEVID_TAG => 37,
+            TagValue::ADD_PARTY_DEVID_TAG => 38,
+            TagValue::PARTY_CALLID_TAG => 39,
+            TagValue::PARTY_DEVID_TYPE_TAG => 40,
+            TagValue::PARTY_DEVID_TAG => 41,
+            TagValue::TRANSFERRING_DEVID_TAG => 42,
+            TagValue::TRANSFERRED_DEVID_TAG => 43,
+            TagValue::DIVERTING_DEVID_TAG => 44,
+            TagValue::QUEUE_DEVID_TAG => 45,
+            TagValue::CALL_WRAPUP_DATA_TAG => 46,
+            TagValue::NEW_CONNECTION_DEVID_TAG => 47,
+            TagValue::TRUNK_USED_DEVID_TAG => 48,
+            TagValue::AGENT_PASSWORD_TAG => 49,
+            TagValue::ACTIVE_CONN_DEVID_TAG => 50,
+            TagValue::FACILITY_CODE_TAG => 51,
+            TagValue::OTHER_CONN_DEVID_TAG => 52,
+            TagValue::HELD_CONN_DEVID_TAG => 53,
+            TagValue::RESERVED54 => 54,
+            TagValue::RESERVED55 => 55,
+            TagValue::CALL_CONN_CALLID_TAG => 56,
+            TagValue::CALL_CONN_DEVID_TYPE_TAG => 57,
+            TagValue::CALL_CONN_DEVID_TAG => 58,
+            TagValue::CALL_DEVID_TYPE_TAG => 59,
+            TagValue::CALL_DEVID_TAG => 60,
+            TagValue::CALL_DEV_CONN_STATE_TAG => 61,
+            TagValue::SKILL_GROUP_NUMBER_TAG => 62,
+            TagValue::SKILL_GROUP_ID_TAG => 63,
+            TagValue::SKILL_GROUP_PRIORITY_TAG => 64,
+            TagValue::SKILL_GROUP_STATE_TAG => 65,
+            TagValue::OBJECT_NAME_TAG => 66,
+            TagValue::DTMF_STRING_TAG => 67,
+            TagValue::POSITION_ID_TAG => 68,
+            TagValue::SUPERVISOR_ID_TAG => 69,
+            TagValue::LINE_HANDLE_TAG => 70,
+            TagValue::LINE_TYPE_TAG => 71,
+            TagValue::ROUTER_CALL_KEY_DAY_TAG => 72,
+            TagValue::ROUTER_CALL_KEY_CALLID_TAG => 73,
+            TagValue::RESERVED74 => 74,
+            TagValue::CALL_STATE_TAG => 75,
+            TagValue::MONITORED_DEVID_TAG => 76,
+            TagValue::AUTHORIZATION_CODE_TAG => 77,
+            TagValue::ACCOUNT_CODE_TAG => 78,
+            TagValue::ORIGINATING_DEVID_TAG => 79,
+            TagValue::ORIGINATING_LINE_ID_TAG => 80,
+            TagValue::CLIENT_ADDRESS_TAG => 81,
+            TagValue::NAMED_VARIABLE_TAG => 82,
+            TagValue::NAMED_ARRAY_TAG => 83,
+            TagValue::CALL_CONTROL_TABLE_TAG => 84,
+            TagValue::SUPERVISOR_INSTRUMENT_TAG => 85,
+            TagValue::ATC_AGENT_ID_TAG => 86,
+            TagValue::AGENT_FLAGS_TAG => 87,
+            TagValue::ATC_AGENT_STATE_TAG => 88,
+            TagValue::ATC_AGENT_STATE_DURATION_TAG => 89,
+            TagValue::AGENT_CONNECTION_DEVID_TAG => 90,
+            TagValue::SUPERVISOR_CONNECTION_DEVID_TAG => 91,
+            TagValue::LIST_TEAM_ID_TAG => 92,
+            TagValue::DEFAULT_DEVICE_PORT_ADDRESS_TAG => 93,
+            TagValue::SERVICE_NAME_TAG => 94,
+            TagValue::CUSTOMER_PHONE_NUMBER_TAG => 95,
+            TagValue::CUSTOMER_ACCOUNT_NUMBER_TAG => 96,
+            TagValue::APP_PATH_ID_TAG => 97,
+            TagValue::UNIQUE_INSTANCE_ID_TAG => 98,
+            TagValue::SCRIPT_SELECTOR_TAG => 99,
+            TagValue::APPLICATION_STRING1_TAG => 100,
+            TagValue::APPLICATION_STRING2_TAG => 101,
+            TagValue::ROUTER_CALL_KEY_SEQUENCE_NUM_TAG => 110,
+            TagValue::TRUNK_NUMBER_TAG => 121,
+            TagValue::TRUNK_GROUP_NUMBER_TAG => 122,
+            TagValue::EXT_AGENT_STATE_TAG => 123,
+            TagValue::DEQUEUE_TYPE_TAG => 124,
+            TagValue::SENDING_ADDRESS_TAG => 125,
+            TagValue::SENDING_PORT_TAG => 126,
+            TagValue::UNUSED127 => 127,
+            TagValue::UNUSED128 => 128,
+            TagValue::MAX_QUEUED_TAG => 129,
+            TagValue::QUEUE_ID_TAG => 130,
+            TagValue::CUSTOMER_ID_TAG => 131,
+            TagValue::SERVICE_SKILL_TARGET_ID_TAG => 132,
+            TagValue::PERIPHERAL_NAME_TAG => 133,
+            TagValue::DESCRIPTION_TAG => 134,
+            TagValue::SERVICE_MEMBER_ID_TAG => 135,
+            TagValue::SERVICE_MEMBER_PRIORITY_TAG => 136,
+            TagValue::FIRST_NAME_TAG => 137,
+            TagValue::LAST_NAME_TAG => 138,
+            TagValue::SKILL_GROUP_TAG => 139,
+            TagValue::AGENT_SKILL_TARGET_ID_TAG => 141,
+            TagValue::SERVICE_TAG => 142,
+            TagValue::RESERVED143 => 143,
+            TagValue::RESERVED144 => 144,
+            TagValue::RESERVED145 => 145,
+            TagValue::RESERVED146 => 146,
+            TagValue::RESERVED147 => 147,
+            TagValue::RESERVED148 => 148,
+            TagValue::RESERVED149 => 149,
+            TagValue::DURATION_TAG => 150,
+            TagValue::RESERVED151 => 151,
+            TagValue::RESERVED152 => 152,
+            TagValue::RESERVED153 => 153,
+            TagValue::RESERVED154 => 154,
+            TagValue::RESERVED155 => 155,
+            TagValue::RESERVED156 => 156,
+            TagValue::RESERVED157 => 157,
+            TagValue::RESERVED158 => 158,
+            TagValue::RESERVED159 => 159,
+            TagValue::RESERVED160 => 160,
+            TagValue::RESERVED161 => 161,
+            TagValue::RESERVED162 => 162,
+            TagValue::RESERVED163 => 163,
+            TagValue::RESERVED164 => 164,
+            TagValue::RESERVED165 => 165,
+            TagValue::RESERVED166 => 166,
+            TagValue::RESERVED167 => 167,
+            TagValue::RESERVED168 => 168,
+            TagValue::RESERVED169 => 169,
+            TagValue::RESERVED170 => 170,
+            TagValue::RESERVED171 => 171,
+            TagValue::RESERVED172 => 172,
+            TagValue::EXTENSION_TAG => 173,
+            TagValue::SERVICE_LEVEL_THRESHOLD_TAG => 174,
+            TagValue::SERVICE_LEVEL_TYPE_TAG => 175,
+            TagValue::CONFIG_PARAM_TAG => 176,
+            TagValue::SERVICE_CONFIG_KEY_TAG => 177,
+            TagValue::SKILL_GROUP_CONFIG_KEY_TAG => 178,
+            TagValue::AGENT_CONFIG_KEY_TAG => 179,
+            TagValue::DEVICE_CONFIG_KEY_TAG => 180,
+            TagValue::UNUSED181 => 181,
+            TagValue::UNUSED182 => 182,
+            TagValue::RECORD_TYPE_TAG => 183,
+            TagValue::PERIPHERAL_NUMBER_TAG => 184,
+            TagValue::AGENT_SKILL_TARGET_ID_TAG2 => 185,
+            TagValue::NUM_SERVICE_MEMBERS_TAG => 186,
+            TagValue::SERVICE_MEMBER_TAG => 187,
+            TagValue::SERVICE_PRIORITY_TAG => 188,
+            TagValue::AGENT_TYPE_TAG => 189,
+            TagValue::LOGIN_ID_TAG => 190,
+            TagValue::NUM_SKILLS_TAG => 191,
+            TagValue::SKILL_GROUP_SKILL_TARGET_ID_TAG => 192,
+            TagValue::SERVICE_ID_TAG => 193,
+            TagValue::AGENT_ID_LONG_TAG => 194,
+            TagValue::DEVICE_TYPE_TAG => 195,
+            TagValue::UNUSED196 => 196,
+            TagValue::UNUSED197 => 197,
+            TagValue::ENABLE_TAG => 198,
+            TagValue::DEVICEID_TAG => 199,
+            TagValue::TIMEOUT_TAG => 200,
+            TagValue::CURRENT_ROUTE_TAG => 201,
+            TagValue::SECONDARY_CONNECTION_CALL_ID => 202,
+            TagValue::PRIORITY_QUEUE_NUMBER_TAG => 203,
+            TagValue::TEAM_NAME_TAG => 204,
+            TagValue::MEMBER_TYPE_TAG => 205,
+            TagValue::EVENT_DEVICE_ID_TAG => 206,
+            TagValue::LOGIN_NAME_TAG_V11 => 207,
+            TagValue::PERIPHERAL_ID_TAG_V11 => 208,
+            TagValue::CALL_TYPE_KEY_CONFIG_TAG_V11 => 209,
+            TagValue::CALL_TYPE_ID_TAG_V11 => 210,
+            TagValue::CUSTOMER_DEFINITION_ID_TAG_V11 => 211,
+            TagValue::ENTERPRISE_NAME_TAG_V11 => 212,
+            TagValue::OLD_PERIPHERAL_NUMBER_TAG => 213,
+            TagValue::CUR_LOGIN_ID => 214,
+            TagValue::ANI_II_TAG => 215,
+            TagValue::MR_DOMAIN_ID_TAG => 216,
+            TagValue::CTIOS_CIL_CLIENT_ID_TAG => 217,
+            TagValue::SILENT_MONITOR_STATUS_TAG => 218,
+            TagValue::REQUESTING_DEVICE_ID_TAG => 219,
+            TagValue::REQUESTING_DEVICE_ID_TYPE_TAG => 220,
+            TagValue::PRE_CALL_INVOKE_ID_TAG => 221,
+            TagValue::ENTERPRISE_QUEUE_TIME => 222,
+            TagValue::CALL_REFERENCE_ID_TAG => 223,
+            TagValue::MULTI_LINE_AGENT_CONTROL_TAG => 224,
+            TagValue::NETWORK_CONTROLLED_TAG => 225,
+            TagValue::UNUSED226 => 226,
+            TagValue::UNUSED227 => 227,
+            TagValue::NUM_PERIPHERALS_TAG => 228,
+            TagValue::COC_CONNECTION_CALL_ID_TAG => 229,
+            TagValue::COC_CONNECTION_DEVICE_ID_TYPE_TAG => 230,
+            TagValue::COC_CONNECTION_DEVICE_ID_TAG => 231,
+            TagValue::CALL_ORIGINATED_FROM_TAG => 232,
+            TagValue::SET_APPDATA_CALLID_TAG => 233,
+            TagValue::CLIENT_SHARE_KEY_TAG => 234,
+            TagValue::AGENT_TEAM_NAME_TAG => 243,
+            TagValue::DIRECTION_TAG => 244,
+            TagValue::OPTIONS_TAG => 245,
+            TagValue::FLT_MRD_ID_TAG => 246,
+            TagValue::MEDIA_CLASS_ID_TAG => 247,
+            TagValue::TASK_LIFE_TAG => 248,
+            TagValue::TASK_START_TIMEOUT_TAG => 249,
+            TagValue::MAX_TASK_DURATION_TAG => 250,
+            TagValue::INTERRUPTIBLE_TAG => 251,
+            TagValue::MAX_CALLS_IN_QUEUE_TAG => 252,
+            TagValue::MAX_CALLS_IN_QUEUE_PER_CALL_TYPE_TAG => 253,
+            TagValue::MAX_TIME_IN_QUEUE_TAG => 254,
+            TagValue::INTERNAL_AGENT_STATE_TAG => 255,
+            TagValue::UNUSED256 => 256,
+            TagValue::SSO_ENABLED_TAG => 257,
+            TagValue::FLT_TASK_ID_TAG => 258,
+            TagValue::FLT_ICM_DISP_TAG => 259,
+            TagValue::FLT_APP_DISP_TAG => 260,
+            TagValue::NUM_MRDS_TAG => 261,
+            TagValue::FLT_AGENT_MRD_ID_TAG => 262,
+            TagValue::FLT_AGENT_MRD_STATE_TAG => 263,
+            TagValue::FLT_PRECISION_QUEUE_ID_TAG => 264,
+            TagValue::FLT_PRECISION_QUEUE_NAME_TAG => 265,
+            TagValue::MAX_BEYOND_TASK_LIMIT_TAG => 266,
+            TagValue::AGENT_DESK_SETTINGS_ID_TAG => 267,
+            TagValue::XFER_IN_WHILE_LOGGED_OUT_TAG => 268,
+            TagValue::PERIPHERAL_CONFIG_KEY_TAG => 269,
+            TagValue::AGENT_DESK_SETTINGS_CONFIG_KEY_TAG => 270,
+            TagValue::CONFIG_PERIPHERAL_ID_TAG => 271,
+            TagValue::DEFAULT_AGENT_DESK_SETTINGS_ID_TAG => 272,
+            TagValue::FLT_DESK_SETTINGS_MASK_TAG => 273,
+            TagValue::FLT_WRAP_UP_DATA_INCOMING_MODE_TAG => 274,
+            TagValue::FLT_WRAP_UP_DATA_OUTGOING_MODE_TAG => 275,
+            TagValue::FLT_LOGOUT_NON_ACTIVITY_TIME_TAG => 276,
+            TagValue::FLT_QUALITY_RECORDING_RATE_TAG => 277,
+            TagValue::FLT_RING_NO_ANSWER_TIME_TAG => 278,
+            TagValue::FLT_SILENT_MONITOR_WARNING_MESSAGE_TAG => 279,
+            TagValue::FLT_SILENT_MONITOR_AUDIBLE_INDICATION_TAG => 280,
+            TagValue::FLT_SUPERVISOR_ASSIST_CALL_METHOD_TAG => 281,
+            TagValue::FLT_EMERGENCY_CALL_METHOD_TAG => 282,
+            TagValue::FLT_AUTO_RECORD_ON_EMERGENCY_TAG => 283,
+            TagValue::FLT_RECORDING_MODE_TAG => 284,
+            TagValue::FLT_WORK_MODE_TIMER_TAG => 285,
+            TagValue::FLT_RING_NO_ANSWER_DN_ID_TAG => 286,
+            TagValue::FLT_DEFAULT_DEVICE_PORT_ADDRESS_TAG => 287,
+            TagValue::DESKTOP_CONNECTED_FLAG_TAG => 288,
+            TagValue::PLAY_TONE_DIRECTION_TAG => 289,
+            TagValue::INVOCATION_TYPE_TAG => 290,
+            TagValue::RECORDER_ADDRESS_TAG => 291,
+            TagValue::TERMINAL_NAME_TAG => 292,
+            TagValue::MEDIA_FORKING_DEVICE_NAME_TAG => 293,
+            TagValue::PROTOCOL_REFERENCE_GUID_TAG => 294,
+            TagValue::MEDIA_FORKING_CLUSTER_ID_TAG => 295,
+            TagValue::RECORDER_URI_TAG => 296,
+            TagValue::RECORDER_ERROR_MSG_TAG => 297,
+            TagValue::RECORDER_TYPE_TAG => 298,
+            TagValue::RECORDER_STATUS_TAG => 299,
+            TagValue::RECORDING_DEVICE_ID_TAG => 300,
+            TagValue::FLT_TERM_TYPE => 302,
+            TagValue::FLT_TERM_DEVICE_NAME => 303,
+            TagValue::FLT_TERM_TYPE_NAME => 304,
+            TagValue::FLT_NUM_INSTRUMENTS => 305,
+            TagValue::ACD_SHARED_LINE_USAGE => 306,
+            TagValue::PLAY_ZIP_TONE => 307,
+            TagValue::FLT_ENABLED_SERVICES => 309,
+            TagValue::NUM_OF_ENABLED_SERVICES => 310,
+            TagValue::CCAI_CONFIG_ID => 311,
+            TagValue::NUM_POSITIVE_ANSWERS_SUGGESTIONS => 312,
+            TagValue::NUM_NEGATIVE_ANSWERS_SUGGESTIONS => 313,
+            TagValue::UNKNOWN(n) => n,
+        }
+    }
+}
+
+impl Serialize for TagValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TagValue::UNKNOWN(n) => serializer.serialize_str(&format!("UNKNOWN({})", n)),
+            tag => serializer.serialize_str(tag.name()),
         }
     }
 }
 
 impl Serializable for TagValue {
     fn serialize(self) -> Vec<u8> {
-        (self as u16).serialize()
+        u16::from(self).serialize()
     }
 }
 
 impl Deserializable for TagValue {
     fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
-        let (buffer, result) = u16::deserialize(buffer).into();
+        let (buffer, result) = u16::deserialize(buffer);
 
         (buffer, result.into())
     }
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::{Deserializable, Serializable};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+///
+/// Cisco CTI 프로토콜 시각 (UNIX epoch 기준 초)
+///
+pub struct CtiTimestamp(pub u32);
+
+impl CtiTimestamp {
+    ///
+    /// UTC 시각으로 변환한다
+    ///
+    pub fn to_date_time(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.0 as i64, 0).unwrap_or_default()
+    }
+}
+
+impl From<CtiTimestamp> for DateTime<Utc> {
+    fn from(value: CtiTimestamp) -> Self {
+        value.to_date_time()
+    }
+}
+
+impl Serializable for CtiTimestamp {
+    fn serialize(self) -> Vec<u8> {
+        self.0.serialize()
+    }
+}
+
+impl Deserializable for CtiTimestamp {
+    fn deserialize<Buffer: AsMut<[u8]>>(buffer: &mut Buffer) -> (Vec<u8>, Self) {
+        let (buffer, result) = u32::deserialize(buffer);
+
+        (buffer, Self(result))
+    }
+}
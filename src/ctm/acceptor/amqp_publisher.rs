@@ -0,0 +1,364 @@
+use std::{error::Error, time::Duration};
+
+use async_trait::async_trait;
+use lapin::{
+    options::{BasicPublishOptions, ConfirmSelectOptions, ExchangeDeclareOptions},
+    types::FieldTable,
+    BasicProperties, Channel, Confirmation, Connection, ConnectionProperties, ExchangeKind,
+};
+use serde::Serialize;
+use tokio::sync::{broadcast, mpsc};
+
+use crate::{
+    ctm::{agent_delta::AgentInfoMirror, config::AmqpConfig},
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+
+use super::{connect_internal_client, recv_broker_event, Acceptor};
+
+// 영속 메시지 delivery mode
+const AMQP_DELIVERY_MODE_PERSISTENT: u8 = 2;
+
+// publisher confirm 을 함께 기다리는 발행 메시지 최대 개수
+const AMQP_MAX_CONFIRM_BATCH: usize = 256;
+
+///
+/// AMQP 발행기
+///
+/// 브로커 이벤트를 토픽 exchange 에 JSON 으로 발행한다.
+/// 느린 서버가 브로커 이벤트 수신을 막지 않도록 발행은 별도 작업에서 처리하며, 메시지를 여러 개 발행한 뒤
+/// publisher confirm 을 한꺼번에 기다린다. 연결이 끊어진 동안의 메시지는 발행 대기 큐에 쌓아두고,
+/// 큐가 가득 차면 버린 뒤 버린 개수를 로그로 남긴다.
+///
+/// - `agent.{cti_instance}.{team_id}.{agent_id}`
+/// - `agent_removed.{cti_instance}.{team_id}.{agent_id}`
+/// - `call.{cti_instance}.{skill_group_id}`
+/// - `skill_group.{cti_instance}.{skill_group_id}`
+/// - `team_snapshot`, `wallboard_summary`
+///
+pub struct AmqpPublisher {
+    uri: String,
+    exchange: String,
+    reconnect_interval: Duration,
+    max_pending_messages: usize,
+}
+
+impl AmqpPublisher {
+    ///
+    /// AmqpPublisher 생성
+    ///
+    pub async fn new(config: &AmqpConfig) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            uri: config.uri.clone(),
+            exchange: config.exchange.clone(),
+            reconnect_interval: config.reconnect_interval,
+            max_pending_messages: config.max_pending_messages,
+        })
+    }
+
+    ///
+    /// 라우팅 키 생성
+    ///
+    /// 토픽 단어 구분자(.)와 와일드카드(*, #)는 밑줄로 바꾼다.
+    ///
+    pub fn routing_key(words: &[&str]) -> String {
+        words
+            .iter()
+            .map(|word| word.replace(['.', '*', '#'], "_"))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    ///
+    /// 브로커 이벤트를 (라우팅 키, 데이터) 목록으로 변환한다
+    ///
+    pub fn messages(
+        agent_info_mirror: &mut AgentInfoMirror,
+        broker_event: &BrokerEvent,
+    ) -> Result<Vec<(String, Vec<u8>)>, serde_json::Error> {
+        // 제거 이벤트의 팀은 반영 전 상태에서 찾는다
+        let removed_team_id = match broker_event {
+            BrokerEvent::BroadCastAgentRemoved { agent_removal, .. } => agent_info_mirror
+                .get(
+                    agent_removal.get_cti_instance(),
+                    agent_removal.get_agent_id(),
+                )
+                .map(|agent_info| agent_info.get_team_id()),
+            _ => None,
+        };
+
+        let mut messages = vec![];
+        for agent_info in agent_info_mirror.apply(broker_event) {
+            messages.push(Self::message(
+                &[
+                    "agent",
+                    agent_info.get_cti_instance(),
+                    &agent_info.get_team_id().to_string(),
+                    agent_info.get_agent_id(),
+                ],
+                &agent_info,
+            )?);
+        }
+
+        match broker_event {
+            BrokerEvent::BroadCastAgentRemoved { agent_removal, .. } => {
+                messages.push(Self::message(
+                    &[
+                        "agent_removed",
+                        agent_removal.get_cti_instance(),
+                        &removed_team_id.unwrap_or_default().to_string(),
+                        agent_removal.get_agent_id(),
+                    ],
+                    agent_removal,
+                )?);
+            }
+            BrokerEvent::BroadCastCallState { call_info, .. } => {
+                messages.push(Self::message(
+                    &[
+                        "call",
+                        call_info.get_cti_instance(),
+                        &call_info.get_skill_group_id().to_string(),
+                    ],
+                    call_info,
+                )?);
+            }
+            BrokerEvent::BroadCastSkillGroupState {
+                skill_group_info, ..
+            } => {
+                messages.push(Self::message(
+                    &[
+                        "skill_group",
+                        skill_group_info.get_cti_instance(),
+                        &skill_group_info.get_skill_group_id().to_string(),
+                    ],
+                    skill_group_info,
+                )?);
+            }
+            BrokerEvent::BroadCastTeamSnapshot { team_infos, .. } => {
+                messages.push(Self::message(&["team_snapshot"], team_infos)?);
+            }
+            BrokerEvent::BroadCastWallboardSummary {
+                wallboard_summary, ..
+            } => {
+                messages.push(Self::message(&["wallboard_summary"], wallboard_summary)?);
+            }
+            _ => {}
+        }
+
+        Ok(messages)
+    }
+
+    fn message<T: Serialize>(
+        words: &[&str],
+        payload: &T,
+    ) -> Result<(String, Vec<u8>), serde_json::Error> {
+        Ok((Self::routing_key(words), serde_json::to_vec(payload)?))
+    }
+}
+
+#[async_trait]
+impl Acceptor for AmqpPublisher {
+    fn get_name(&self) -> &str {
+        "amqp"
+    }
+
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!(
+            "AMQP publisher starts publishing. exchange: {}",
+            self.exchange
+        );
+
+        // 느린 서버가 브로커 이벤트 수신을 막지 않도록 발행 작업을 분리한다
+        let (message_tx, message_rx) =
+            mpsc::channel::<(String, Vec<u8>)>(self.max_pending_messages);
+        let sender = AmqpSender {
+            uri: self.uri.clone(),
+            exchange: self.exchange.clone(),
+            reconnect_interval: self.reconnect_interval,
+        };
+        let sender_handle = tokio::spawn(sender.run(message_rx));
+
+        // 변경분 반영을 위해 내부 클라이언트로 접속하여 전체 상태를 받는다
+        let id = connect_internal_client(&client_event_channel_tx).await?;
+
+        let mut agent_info_mirror = AgentInfoMirror::new();
+        let mut dropped = 0_u64;
+        while let Some(broker_event) = recv_broker_event(&mut broker_event_channel_rx, id).await {
+            let messages = match Self::messages(&mut agent_info_mirror, &broker_event) {
+                Ok(messages) => messages,
+                Err(e) => {
+                    log::warn!("Unable to serialize AMQP message. {}", e);
+                    continue;
+                }
+            };
+
+            // 발행 대기 큐가 가득 찬 동안 버린 메시지 개수를 세고, 큐가 비워지면 로그로 남긴다
+            for message in messages {
+                match message_tx.try_send(message) {
+                    Ok(()) if dropped > 0 => {
+                        log::warn!(
+                            "Dropped AMQP messages while publish queue was full. dropped: {}",
+                            dropped
+                        );
+                        dropped = 0;
+                    }
+                    Ok(()) => {}
+                    Err(_) => {
+                        if dropped == 0 {
+                            log::warn!(
+                                "AMQP publish queue is full. max_pending_messages: {}",
+                                self.max_pending_messages
+                            );
+                        }
+                        dropped += 1;
+                    }
+                }
+            }
+        }
+
+        if dropped > 0 {
+            log::warn!(
+                "Dropped AMQP messages while publish queue was full. dropped: {}",
+                dropped
+            );
+        }
+
+        // 종료 시 대기 중인 메시지를 모두 발행할 때까지 기다린다
+        drop(message_tx);
+        let _ = sender_handle.await;
+
+        Ok(())
+    }
+}
+
+///
+/// 발행 대기 큐의 메시지를 AMQP 서버에 발행하는 작업
+///
+struct AmqpSender {
+    uri: String,
+    exchange: String,
+    reconnect_interval: Duration,
+}
+
+impl AmqpSender {
+    async fn run(self, mut message_rx: mpsc::Receiver<(String, Vec<u8>)>) {
+        let mut connection: Option<(Connection, Channel)> = None;
+        let mut messages = Vec::with_capacity(AMQP_MAX_CONFIRM_BATCH);
+        while message_rx
+            .recv_many(&mut messages, AMQP_MAX_CONFIRM_BATCH)
+            .await
+            > 0
+        {
+            // 연결이 끊어진 경우 재접속 주기마다 다시 접속하며, 그동안 도착한 메시지는 큐에 쌓인다
+            while connection.is_none() {
+                match self.connect().await {
+                    Ok(connected) => {
+                        log::info!("AMQP server connected. exchange: {}", self.exchange);
+                        connection = Some(connected);
+                    }
+                    Err(e) => {
+                        log::warn!("Unable to connect to AMQP server. {}", e);
+
+                        // 종료 중에는 재접속을 기다리지 않고 남은 메시지를 버린다
+                        if message_rx.is_closed() {
+                            log::warn!(
+                                "Dropped AMQP messages on shutdown. dropped: {}",
+                                messages.len() + message_rx.len()
+                            );
+                            return;
+                        }
+                        tokio::time::sleep(self.reconnect_interval).await;
+                    }
+                }
+            }
+            let Some((_, ref channel)) = connection else {
+                continue;
+            };
+
+            if let Err(e) = self.publish(channel, &messages).await {
+                log::warn!(
+                    "Unable to publish AMQP messages. dropped: {}, error: {}",
+                    messages.len(),
+                    e
+                );
+                connection = None;
+            }
+            messages.clear();
+        }
+
+        // 종료 시 연결을 정상 종료한다
+        if let Some((connection, _)) = connection {
+            if let Err(e) = connection.close(200, "Shutdown".into()).await {
+                log::warn!("Unable to close AMQP connection. {}", e);
+            }
+        }
+    }
+
+    ///
+    /// AMQP 서버 접속
+    ///
+    /// 토픽 exchange 를 선언하고 publisher confirm 을 활성화한 채널을 반환한다.
+    ///
+    async fn connect(&self) -> Result<(Connection, Channel), lapin::Error> {
+        let connection = Connection::connect(&self.uri, ConnectionProperties::default()).await?;
+        let channel = connection.create_channel().await?;
+
+        channel
+            .exchange_declare(
+                self.exchange.as_str().into(),
+                ExchangeKind::Topic,
+                ExchangeDeclareOptions {
+                    durable: true,
+                    ..Default::default()
+                },
+                FieldTable::default(),
+            )
+            .await?;
+        channel
+            .confirm_select(ConfirmSelectOptions::default())
+            .await?;
+
+        Ok((connection, channel))
+    }
+
+    ///
+    /// 메시지를 모두 발행한 뒤 서버 확인을 한꺼번에 기다린다
+    ///
+    async fn publish(
+        &self,
+        channel: &Channel,
+        messages: &[(String, Vec<u8>)],
+    ) -> Result<(), lapin::Error> {
+        let mut publisher_confirms = Vec::with_capacity(messages.len());
+        for (routing_key, payload) in messages {
+            let publisher_confirm = channel
+                .basic_publish(
+                    self.exchange.as_str().into(),
+                    routing_key.as_str().into(),
+                    BasicPublishOptions::default(),
+                    payload,
+                    BasicProperties::default()
+                        .with_content_type("application/json".into())
+                        .with_delivery_mode(AMQP_DELIVERY_MODE_PERSISTENT),
+                )
+                .await?;
+            publisher_confirms.push((routing_key, publisher_confirm));
+        }
+
+        for (routing_key, publisher_confirm) in publisher_confirms {
+            if let Confirmation::Nack(_) = publisher_confirm.await? {
+                log::warn!(
+                    "AMQP server rejected message. exchange: {}, routing_key: {}",
+                    self.exchange,
+                    routing_key
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
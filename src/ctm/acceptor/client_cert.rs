@@ -0,0 +1,124 @@
+use std::{error::Error, sync::Arc};
+
+use rustls::{
+    pki_types::{pem::PemObject, CertificateDer},
+    server::{danger::ClientCertVerifier, WebPkiClientVerifier},
+    RootCertStore,
+};
+
+// commonName 속성 OID (2.5.4.3)
+const OID_COMMON_NAME: [u8; 3] = [0x55, 0x04, 0x03];
+
+// DER 태그
+const DER_TAG_VERSION: u8 = 0xa0;
+const DER_TAG_UTF8_STRING: u8 = 0x0c;
+const DER_TAG_PRINTABLE_STRING: u8 = 0x13;
+const DER_TAG_T61_STRING: u8 = 0x14;
+const DER_TAG_IA5_STRING: u8 = 0x16;
+const DER_TAG_BMP_STRING: u8 = 0x1e;
+
+///
+/// 클라이언트 인증서 검증기 생성
+///
+/// CA 인증서 파일(PEM)로 서명된 클라이언트 인증서만 허용하며, 인증서가 없는 클라이언트는 거부한다.
+///
+pub fn client_cert_verifier(ca_file: &str) -> Result<Arc<dyn ClientCertVerifier>, Box<dyn Error>> {
+    let mut root_cert_store = RootCertStore::empty();
+    for cert in CertificateDer::pem_file_iter(ca_file)? {
+        root_cert_store.add(cert?)?;
+    }
+
+    Ok(WebPkiClientVerifier::builder(Arc::new(root_cert_store)).build()?)
+}
+
+///
+/// 클라이언트 인증서의 Subject CN 반환
+///
+/// DER 인코딩된 X.509 인증서에서 Subject 의 마지막 commonName 값을 읽는다.
+///
+pub fn common_name(cert: &[u8]) -> Option<String> {
+    let (_, certificate, _) = read_tlv(cert)?;
+    let (_, tbs_certificate, _) = read_tlv(certificate)?;
+
+    // version 은 생략될 수 있다
+    let mut rest = match read_tlv(tbs_certificate)? {
+        (DER_TAG_VERSION, _, rest) => rest,
+        _ => tbs_certificate,
+    };
+    // serialNumber, signature, issuer, validity 는 건너뛴다
+    for _ in 0..4 {
+        rest = read_tlv(rest)?.2;
+    }
+    let (_, subject, _) = read_tlv(rest)?;
+
+    // Subject 는 RelativeDistinguishedName(SET of AttributeTypeAndValue) 의 SEQUENCE
+    let mut common_name = None;
+    let mut rdns = subject;
+    while !rdns.is_empty() {
+        let (_, rdn, next) = read_tlv(rdns)?;
+        rdns = next;
+
+        let mut attributes = rdn;
+        while !attributes.is_empty() {
+            let (_, attribute, next) = read_tlv(attributes)?;
+            attributes = next;
+
+            let (_, oid, value) = read_tlv(attribute)?;
+            let (tag, value, _) = read_tlv(value)?;
+            if oid == OID_COMMON_NAME {
+                common_name = decode_string(tag, value);
+            }
+        }
+    }
+
+    common_name
+}
+
+///
+/// DER TLV 를 읽어 (태그, 값, 나머지 데이터) 반환
+///
+fn read_tlv(data: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = data.split_first()?;
+    let (&length, mut rest) = rest.split_first()?;
+
+    let length = if length & 0x80 == 0 {
+        length as usize
+    } else {
+        // 긴 길이 형식 (최대 4 바이트)
+        let size = (length & 0x7f) as usize;
+        if size == 0 || size > 4 || rest.len() < size {
+            return None;
+        }
+        let (length, next) = rest.split_at(size);
+        rest = next;
+        length
+            .iter()
+            .fold(0_usize, |acc, byte| (acc << 8) | *byte as usize)
+    };
+    if rest.len() < length {
+        return None;
+    }
+
+    let (value, rest) = rest.split_at(length);
+    Some((tag, value, rest))
+}
+
+///
+/// DER 문자열 디코딩
+///
+fn decode_string(tag: u8, value: &[u8]) -> Option<String> {
+    match tag {
+        DER_TAG_UTF8_STRING
+        | DER_TAG_PRINTABLE_STRING
+        | DER_TAG_T61_STRING
+        | DER_TAG_IA5_STRING => String::from_utf8(value.to_vec()).ok(),
+        DER_TAG_BMP_STRING => String::from_utf16(
+            &value
+                .chunks_exact(2)
+                .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]))
+                .collect::<Vec<_>>(),
+        )
+        .ok(),
+        _ => None,
+    }
+}
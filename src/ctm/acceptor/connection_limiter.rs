@@ -0,0 +1,138 @@
+use std::{
+    fmt::Display,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Instant,
+};
+
+use crate::ctm::config::ConnectionLimitConfig;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// 접속 거절 사유
+///
+pub enum ConnectionRejection {
+    TOO_MANY_CLIENTS,
+    RATE_LIMITED,
+}
+
+impl Display for ConnectionRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl ConnectionRejection {
+    ///
+    /// HTTP 503 거절 응답
+    ///
+    pub fn to_http_response(&self) -> String {
+        let body = format!(r#"{{"error":"{}"}}"#, self);
+        format!(
+            "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 1\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    }
+}
+
+///
+/// 접속 허가
+///
+/// 클라이언트 연결이 끝날 때까지 보관하며, 드롭되면 동시 접속 수에서 제외된다.
+///
+pub struct ConnectionPermit {
+    active_clients: Arc<AtomicUsize>,
+}
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        self.active_clients.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+///
+/// Acceptor 별 접속 제한
+///
+/// 최대 동시 접속 수와 토큰 버킷 방식의 접속 속도(초당 허용 수, 순간 허용량)를 제한한다.
+/// max_clients, accept_rate 가 0 이면 해당 제한을 사용하지 않는다.
+///
+pub struct ConnectionLimiter {
+    max_clients: usize,
+    accept_rate: f64,
+    accept_burst: f64,
+    active_clients: Arc<AtomicUsize>,
+    // (남은 토큰, 마지막 충전 시각)
+    token_bucket: Mutex<(f64, Instant)>,
+}
+
+impl ConnectionLimiter {
+    pub fn new(max_clients: usize, accept_rate: f64, accept_burst: u32) -> Self {
+        let accept_rate = accept_rate.max(0.0);
+        // 순간 허용량은 최소 1 이상이어야 한다
+        let accept_burst = (accept_burst as f64).max(1.0);
+
+        Self {
+            max_clients,
+            accept_rate,
+            accept_burst,
+            active_clients: Arc::new(AtomicUsize::new(0)),
+            token_bucket: Mutex::new((accept_burst, Instant::now())),
+        }
+    }
+
+    ///
+    /// 접속 제한 설정으로 생성 ({prefix}_MAX_CLIENTS, {prefix}_ACCEPT_RATE, {prefix}_ACCEPT_BURST)
+    ///
+    pub fn from_config(config: &ConnectionLimitConfig) -> Self {
+        Self::new(config.max_clients, config.accept_rate, config.accept_burst)
+    }
+
+    ///
+    /// 현재 동시 접속 수
+    ///
+    pub fn get_active_clients(&self) -> usize {
+        self.active_clients.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// 접속 허가 요청
+    ///
+    /// 동시 접속 수를 먼저 확인하므로, 최대 접속 수 초과로 거절된 접속은 토큰을 소모하지 않는다.
+    ///
+    pub fn try_acquire(&self) -> Result<ConnectionPermit, ConnectionRejection> {
+        let reserved = self.active_clients.fetch_update(
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+            |active_clients| match self.max_clients == 0 || active_clients < self.max_clients {
+                true => Some(active_clients + 1),
+                false => None,
+            },
+        );
+        if reserved.is_err() {
+            return Err(ConnectionRejection::TOO_MANY_CLIENTS);
+        }
+        let permit = ConnectionPermit {
+            active_clients: self.active_clients.clone(),
+        };
+
+        if self.accept_rate > 0.0 {
+            let mut token_bucket = self.token_bucket.lock().unwrap();
+            let now = Instant::now();
+            let (tokens, refilled_at) = *token_bucket;
+            let tokens = (tokens
+                + now.duration_since(refilled_at).as_secs_f64() * self.accept_rate)
+                .min(self.accept_burst);
+            if tokens < 1.0 {
+                *token_bucket = (tokens, now);
+                return Err(ConnectionRejection::RATE_LIMITED);
+            }
+            *token_bucket = (tokens - 1.0, now);
+        }
+
+        Ok(permit)
+    }
+}
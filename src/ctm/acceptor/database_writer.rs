@@ -0,0 +1,313 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use sqlx::{any::AnyPoolOptions, AnyPool};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::{
+    ctm::{
+        agent_delta::AgentInfoMirror, agent_info::AgentInfo, call_info::CallInfo,
+        config::DatabaseConfig,
+    },
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+
+use super::{connect_internal_client, recv_broker_event, Acceptor};
+
+const CREATE_AGENT_STATE_HISTORY: &str = "CREATE TABLE IF NOT EXISTS agent_state_history (
+    recorded_at BIGINT NOT NULL,
+    cti_instance VARCHAR(64) NOT NULL,
+    agent_id VARCHAR(64) NOT NULL,
+    team_id BIGINT NOT NULL,
+    agent_state INTEGER NOT NULL,
+    reason_code INTEGER NOT NULL,
+    state_start_time BIGINT NOT NULL
+)";
+
+const CREATE_CALL_EVENT_HISTORY: &str = "CREATE TABLE IF NOT EXISTS call_event_history (
+    recorded_at BIGINT NOT NULL,
+    cti_instance VARCHAR(64) NOT NULL,
+    connection_call_id BIGINT NOT NULL,
+    call_state VARCHAR(16) NOT NULL,
+    agent_id VARCHAR(64) NOT NULL,
+    skill_group_id BIGINT NOT NULL
+)";
+
+const INSERT_AGENT_STATE_HISTORY: &str = "INSERT INTO agent_state_history \
+    (recorded_at, cti_instance, agent_id, team_id, agent_state, reason_code, state_start_time) \
+    VALUES ($1, $2, $3, $4, $5, $6, $7)";
+
+const INSERT_CALL_EVENT_HISTORY: &str = "INSERT INTO call_event_history \
+    (recorded_at, cti_instance, connection_call_id, call_state, agent_id, skill_group_id) \
+    VALUES ($1, $2, $3, $4, $5, $6)";
+
+#[derive(Debug, Clone)]
+///
+/// 이력 테이블에 기록할 레코드
+///
+/// recorded_at 은 UNIX 시간(밀리초)이다.
+///
+pub enum HistoryRecord {
+    AgentState {
+        recorded_at: u64,
+        agent_info: AgentInfo,
+    },
+    CallEvent {
+        recorded_at: u64,
+        call_info: CallInfo,
+    },
+}
+
+///
+/// 브로커 이벤트에서 이력 레코드를 추출한다
+///
+/// 상담직원은 상태 또는 사유 코드가 바뀐 경우만 기록한다.
+///
+pub struct HistoryRecorder {
+    agent_info_mirror: AgentInfoMirror,
+    // (cti_instance, agent_id) 별 마지막 (상태, 사유 코드)
+    last_states: HashMap<(String, String), (u16, u16)>,
+}
+
+impl Default for HistoryRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HistoryRecorder {
+    pub fn new() -> Self {
+        Self {
+            agent_info_mirror: AgentInfoMirror::new(),
+            last_states: HashMap::new(),
+        }
+    }
+
+    pub fn records(&mut self, broker_event: &BrokerEvent) -> Vec<HistoryRecord> {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        let mut records = vec![];
+        for agent_info in self.agent_info_mirror.apply(broker_event) {
+            let state = (agent_info.get_agent_state(), agent_info.get_reason_code());
+            let key = (
+                agent_info.get_cti_instance().to_string(),
+                agent_info.get_agent_id().to_string(),
+            );
+            if self.last_states.insert(key, state) != Some(state) {
+                records.push(HistoryRecord::AgentState {
+                    recorded_at,
+                    agent_info,
+                });
+            }
+        }
+
+        match broker_event {
+            BrokerEvent::BroadCastAgentRemoved { agent_removal, .. } => {
+                self.last_states.remove(&(
+                    agent_removal.get_cti_instance().to_string(),
+                    agent_removal.get_agent_id().to_string(),
+                ));
+            }
+            BrokerEvent::BroadCastCallState { call_info, .. } => {
+                records.push(HistoryRecord::CallEvent {
+                    recorded_at,
+                    call_info: call_info.clone(),
+                });
+            }
+            _ => {}
+        }
+
+        records
+    }
+}
+
+///
+/// 데이터베이스 기록기
+///
+/// 상담직원 상태 변경과 호 이벤트를 `agent_state_history`, `call_event_history` 테이블에 일괄 기록한다.
+/// 데이터베이스에 접속할 수 없는 동안에는 최대 버퍼 크기까지 레코드를 보관했다가 재접속 후 기록한다.
+///
+pub struct DatabaseWriter {
+    pool: AnyPool,
+    batch_size: usize,
+    batch_interval: Duration,
+    max_buffer_size: usize,
+}
+
+impl DatabaseWriter {
+    ///
+    /// DatabaseWriter 생성
+    ///
+    /// 실제 접속은 첫 기록 시점에 이루어진다.
+    ///
+    pub async fn new(config: &DatabaseConfig) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            pool: Self::connect(&config.url, config.max_connections)?,
+            batch_size: config.batch_size,
+            batch_interval: config.batch_interval,
+            max_buffer_size: config.max_buffer_size,
+        })
+    }
+
+    ///
+    /// 커넥션 풀 생성 (postgres://, sqlite:// URL 지원)
+    ///
+    pub fn connect(url: &str, max_connections: u32) -> Result<AnyPool, sqlx::Error> {
+        sqlx::any::install_default_drivers();
+
+        AnyPoolOptions::new()
+            .max_connections(max_connections.max(1))
+            .acquire_timeout(Duration::from_secs(5))
+            .connect_lazy(url)
+    }
+
+    ///
+    /// 이력 테이블 생성
+    ///
+    pub async fn create_tables(pool: &AnyPool) -> Result<(), sqlx::Error> {
+        sqlx::query(CREATE_AGENT_STATE_HISTORY)
+            .execute(pool)
+            .await?;
+        sqlx::query(CREATE_CALL_EVENT_HISTORY).execute(pool).await?;
+
+        Ok(())
+    }
+
+    ///
+    /// 레코드를 하나의 트랜잭션으로 기록한다
+    ///
+    pub async fn insert_records(
+        pool: &AnyPool,
+        records: &[HistoryRecord],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        for record in records {
+            match record {
+                HistoryRecord::AgentState {
+                    recorded_at,
+                    agent_info,
+                } => {
+                    sqlx::query(INSERT_AGENT_STATE_HISTORY)
+                        .bind(*recorded_at as i64)
+                        .bind(agent_info.get_cti_instance())
+                        .bind(agent_info.get_agent_id())
+                        .bind(agent_info.get_team_id() as i64)
+                        .bind(agent_info.get_agent_state() as i32)
+                        .bind(agent_info.get_reason_code() as i32)
+                        .bind(agent_info.get_state_duration() as i64)
+                        .execute(&mut *transaction)
+                        .await?;
+                }
+                HistoryRecord::CallEvent {
+                    recorded_at,
+                    call_info,
+                } => {
+                    sqlx::query(INSERT_CALL_EVENT_HISTORY)
+                        .bind(*recorded_at as i64)
+                        .bind(call_info.get_cti_instance())
+                        .bind(call_info.get_connection_call_id().0 as i64)
+                        .bind(format!("{:?}", call_info.get_call_state()))
+                        .bind(call_info.get_agent_id())
+                        .bind(call_info.get_skill_group_id() as i64)
+                        .execute(&mut *transaction)
+                        .await?;
+                }
+            }
+        }
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    ///
+    /// 버퍼의 레코드를 배치 크기 단위로 기록한다
+    ///
+    /// 기록에 실패하면 남은 레코드를 보관하고 false 를 반환한다.
+    ///
+    async fn flush(&self, buffer: &mut VecDeque<HistoryRecord>, tables_created: &mut bool) -> bool {
+        if !*tables_created {
+            if let Err(e) = Self::create_tables(&self.pool).await {
+                log::warn!("Unable to create history tables. {}", e);
+                return false;
+            }
+            *tables_created = true;
+        }
+
+        while !buffer.is_empty() {
+            let length = buffer.len().min(self.batch_size);
+            let batch = buffer.range(0..length).cloned().collect::<Vec<_>>();
+            if let Err(e) = Self::insert_records(&self.pool, &batch).await {
+                log::warn!(
+                    "Unable to write history records. buffered: {}, error: {}",
+                    buffer.len(),
+                    e
+                );
+                return false;
+            }
+            buffer.drain(0..length);
+        }
+
+        true
+    }
+}
+
+#[async_trait]
+impl Acceptor for DatabaseWriter {
+    fn get_name(&self) -> &str {
+        "database"
+    }
+
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!("Database writer starts writing");
+
+        // 상태 변경 비교를 위해 내부 클라이언트로 접속하여 전체 상태를 받는다
+        let id = connect_internal_client(&client_event_channel_tx).await?;
+
+        let mut history_recorder = HistoryRecorder::new();
+        let mut buffer = VecDeque::new();
+        let mut tables_created = false;
+        let mut flush_failed = false;
+        let mut flush_interval = tokio::time::interval(self.batch_interval);
+        loop {
+            let (closed, flush) = tokio::select! {
+                broker_event = recv_broker_event(&mut broker_event_channel_rx, id) => match broker_event {
+                    Some(broker_event) => {
+                        buffer.extend(history_recorder.records(&broker_event));
+                        // 기록에 실패한 뒤에는 다음 주기까지 기다린다
+                        (false, !flush_failed && buffer.len() >= self.batch_size)
+                    }
+                    None => (true, true),
+                },
+                _ = flush_interval.tick() => (false, true),
+            };
+
+            if flush && !buffer.is_empty() {
+                flush_failed = !self.flush(&mut buffer, &mut tables_created).await;
+            }
+
+            // 접속 불가 상태가 길어지면 오래된 레코드부터 버린다
+            if buffer.len() > self.max_buffer_size {
+                let dropped = buffer.len() - self.max_buffer_size;
+                buffer.drain(0..dropped);
+                log::warn!("Dropped buffered history records. dropped: {}", dropped);
+            }
+
+            if closed {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,327 @@
+use std::{error::Error, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use rustls::ServerConfig;
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpListener,
+    sync::{broadcast, mpsc},
+};
+use tokio_rustls::TlsAcceptor;
+
+use crate::{
+    ctm::{
+        client_auth::ClientAuthenticator,
+        config::GraphQLAcceptorConfig,
+        graphql::{build_schema, ClientDepartment, CtmSchema, GraphQLState},
+        net_addr::bind_tcp_listener,
+    },
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+
+use super::{
+    connect_internal_client,
+    connection_limiter::{ConnectionLimiter, ConnectionPermit, ConnectionRejection},
+    recv_broker_event,
+    server_cert::ServerCertResolver,
+    wait_shutdown, write_response, Acceptor,
+};
+
+// 요청 최대 크기 (바이트)
+const GRAPHQL_MAX_REQUEST_SIZE: usize = 1_048_576;
+
+///
+/// GraphQL Acceptor
+///
+/// `POST {path}` 로 GraphQL 요청을 받으며, `Accept: text/event-stream` 요청은 구독 결과를 SSE 로 전송한다.
+///
+pub struct GraphQLAcceptor {
+    graphql_listener: TcpListener,
+    tls_acceptor: Option<TlsAcceptor>,
+    server_cert_resolver: Option<Arc<ServerCertResolver>>,
+    client_authenticator: Arc<ClientAuthenticator>,
+    connection_limiter: ConnectionLimiter,
+    path: String,
+    cert_reload_interval: Option<Duration>,
+}
+
+impl GraphQLAcceptor {
+    ///
+    /// GraphQLAcceptor 생성
+    ///
+    pub async fn new(
+        config: &GraphQLAcceptorConfig,
+        client_authenticator: Arc<ClientAuthenticator>,
+    ) -> Result<Self, Box<dyn Error>> {
+        // GraphQL 서버 초기화
+        let graphql_listener = bind_tcp_listener(&config.bind_address, config.port, true).await?;
+
+        // TLS acceptor 생성
+        let mut tls_acceptor = None;
+        let mut server_cert_resolver = None;
+        if config.secure {
+            let builder = ServerConfig::builder();
+            let resolver = Arc::new(ServerCertResolver::new(
+                builder.crypto_provider().clone(),
+                &config.cert_file,
+                &config.key_file,
+                &[],
+            )?);
+            let tls_config = builder
+                .with_no_client_auth()
+                .with_cert_resolver(resolver.clone());
+
+            tls_acceptor = Some(TlsAcceptor::from(Arc::new(tls_config)));
+            server_cert_resolver = Some(resolver);
+        }
+
+        Ok(Self {
+            graphql_listener,
+            tls_acceptor,
+            server_cert_resolver,
+            client_authenticator,
+            connection_limiter: ConnectionLimiter::from_config(&config.connection_limit),
+            path: config.path.clone(),
+            cert_reload_interval: config.cert_reload_interval,
+        })
+    }
+}
+
+#[async_trait]
+impl Acceptor for GraphQLAcceptor {
+    fn get_name(&self) -> &str {
+        "graphql"
+    }
+
+    ///
+    /// 클라이언트 수신
+    ///
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!("GraphQL server starts accepting");
+        if let Some(server_cert_resolver) = self.server_cert_resolver.as_ref() {
+            server_cert_resolver.watch(self.cert_reload_interval);
+        }
+
+        // GraphQL 서버도 내부 클라이언트로 접속하여 전체 상태를 받는다
+        let id = connect_internal_client(&client_event_channel_tx).await?;
+
+        // 브로커 이벤트를 GraphQL 상태 저장소에 반영
+        let mut shutdown_rx = broker_event_channel_rx.resubscribe();
+        let state = Arc::new(GraphQLState::new());
+        let schema = build_schema(state.clone());
+        tokio::spawn(async move {
+            while let Some(broker_event) = recv_broker_event(&mut broker_event_channel_rx, id).await
+            {
+                state.apply(&broker_event);
+            }
+        });
+
+        loop {
+            let accepted = tokio::select! {
+                accepted = self.graphql_listener.accept() => accepted,
+                _ = wait_shutdown(&mut shutdown_rx) => break,
+            };
+            match accepted {
+                Ok((native_stream, client_addr)) => {
+                    log::debug!("GraphQL client connected. client_addr: {:?}", client_addr);
+
+                    // 구독(SSE) 연결도 동시 접속 수에 포함된다
+                    let connection_permit = self.connection_limiter.try_acquire();
+
+                    let tls_acceptor = self.tls_acceptor.clone();
+                    let client_authenticator = self.client_authenticator.clone();
+                    let schema = schema.clone();
+                    let path = self.path.clone();
+                    tokio::spawn(async move {
+                        // TLS 적용 여부에 따라 클라이언트 소켓 스트림을 구분
+                        let result = match tls_acceptor {
+                            Some(tls) => match tls.accept(native_stream).await {
+                                Ok(stream) => {
+                                    handle_request(
+                                        stream,
+                                        connection_permit,
+                                        &schema,
+                                        &client_authenticator,
+                                        &path,
+                                    )
+                                    .await
+                                }
+                                Err(e) => Err(e.into()),
+                            },
+                            None => {
+                                handle_request(
+                                    native_stream,
+                                    connection_permit,
+                                    &schema,
+                                    &client_authenticator,
+                                    &path,
+                                )
+                                .await
+                            }
+                        };
+
+                        if let Err(e) = result {
+                            log::warn!(
+                                "Unable to handle GraphQL request. client_addr: {:?}, error: {}",
+                                client_addr,
+                                e
+                            );
+                        }
+                    });
+                }
+                Err(e) => {
+                    log::error!("Unable to accept GraphQL client connection. {:?}", e);
+                    return Ok(());
+                }
+            }
+        }
+
+        log::info!("GraphQL server stops accepting");
+
+        Ok(())
+    }
+}
+
+///
+/// HTTP 요청 하나를 처리하고 연결을 닫는다
+///
+/// 접속 제한을 넘은 요청은 헤더를 읽은 뒤 503 으로 응답한다.
+///
+async fn handle_request<S: AsyncRead + AsyncWrite + Unpin>(
+    mut stream: S,
+    connection_permit: Result<ConnectionPermit, ConnectionRejection>,
+    schema: &CtmSchema,
+    client_authenticator: &ClientAuthenticator,
+    path: &str,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    // 요청 헤더 수신
+    let mut buffer = Vec::new();
+    let mut chunk = vec![0_u8; 4_096];
+    let header_length = loop {
+        if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break position + 4;
+        }
+        if buffer.len() > GRAPHQL_MAX_REQUEST_SIZE {
+            return write_response(&mut stream, "413 Payload Too Large", "text/plain", b"").await;
+        }
+
+        match stream.read(&mut chunk).await? {
+            0 => return Ok(()),
+            n => buffer.extend_from_slice(&chunk[0..n]),
+        }
+    };
+    let request_header = String::from_utf8_lossy(&buffer[0..header_length]).to_string();
+    log::debug!("GraphQL client request header: {}", request_header);
+
+    let _connection_permit = match connection_permit {
+        Ok(connection_permit) => connection_permit,
+        Err(rejection) => {
+            log::warn!("GraphQL client rejected. reason: {}", rejection);
+            stream
+                .write_all(rejection.to_http_response().as_bytes())
+                .await?;
+            stream.shutdown().await?;
+            return Ok(());
+        }
+    };
+
+    let mut request_line = request_header
+        .lines()
+        .next()
+        .unwrap_or("")
+        .split_whitespace();
+    let method = request_line.next().unwrap_or("");
+    let request_path = request_line
+        .next()
+        .unwrap_or("")
+        .split('?')
+        .next()
+        .unwrap_or("");
+    if request_path != path {
+        return write_response(&mut stream, "404 Not Found", "text/plain", b"").await;
+    }
+
+    // 인증 토큰은 Authorization 헤더로 받는다
+    let token = header_value(&request_header, "Authorization")
+        .and_then(|authorization| authorization.strip_prefix("Bearer "))
+        .map(str::trim);
+    if client_authenticator.authenticate(token).is_none() {
+        log::warn!("GraphQL client authentication failed");
+        return write_response(&mut stream, "401 Unauthorized", "text/plain", b"").await;
+    }
+
+    if method != "POST" {
+        return write_response(&mut stream, "405 Method Not Allowed", "text/plain", b"").await;
+    }
+
+    // 요청 본문 수신
+    let content_length = header_value(&request_header, "Content-Length")
+        .and_then(|content_length| content_length.parse::<usize>().ok())
+        .unwrap_or(0);
+    if content_length > GRAPHQL_MAX_REQUEST_SIZE {
+        return write_response(&mut stream, "413 Payload Too Large", "text/plain", b"").await;
+    }
+    while buffer.len() < header_length + content_length {
+        match stream.read(&mut chunk).await? {
+            0 => return Ok(()),
+            n => buffer.extend_from_slice(&chunk[0..n]),
+        }
+    }
+    let body = &buffer[header_length..header_length + content_length];
+
+    let mut request = match serde_json::from_slice::<async_graphql::Request>(body) {
+        Ok(request) => request,
+        Err(e) => {
+            log::debug!("GraphQL client sent invalid request. error: {}", e);
+            return write_response(&mut stream, "400 Bad Request", "text/plain", b"").await;
+        }
+    };
+
+    // 부서가 지정된 인증 키는 해당 부서 상담직원만 조회한다
+    if let Some(department_id) = client_authenticator.get_department_id(token) {
+        request = request.data(ClientDepartment(department_id));
+    }
+
+    // 구독 요청은 SSE 로 응답을 계속 전송한다
+    let event_stream = header_value(&request_header, "Accept")
+        .is_some_and(|accept| accept.contains("text/event-stream"));
+    if !event_stream {
+        let response = schema.execute(request).await;
+        let body = serde_json::to_vec(&response)?;
+        return write_response(&mut stream, "200 OK", "application/json", &body).await;
+    }
+
+    stream
+        .write_all(
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+        )
+        .await?;
+    let mut responses = schema.execute_stream(request);
+    while let Some(response) = responses.next().await {
+        let data = serde_json::to_string(&response)?;
+        stream
+            .write_all(format!("event: next\ndata: {}\n\n", data).as_bytes())
+            .await?;
+    }
+    stream.write_all(b"event: complete\ndata:\n\n").await?;
+    stream.shutdown().await?;
+
+    Ok(())
+}
+
+///
+/// HTTP 헤더 값 반환 (헤더 이름은 대소문자를 구분하지 않는다)
+///
+fn header_value<'a>(request_header: &'a str, name: &str) -> Option<&'a str> {
+    request_header.lines().skip(1).find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then_some(value.trim())
+    })
+}
@@ -0,0 +1,165 @@
+use std::{error::Error, sync::Arc};
+
+use async_trait::async_trait;
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite},
+    net::TcpListener,
+    sync::{broadcast, mpsc},
+    time::{timeout, Duration},
+};
+
+use crate::{
+    ctm::{
+        channel_metrics::ChannelMetrics, config::HealthConfig, health::HealthState,
+        net_addr::bind_tcp_listener,
+    },
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+
+use super::{wait_shutdown, write_response, Acceptor};
+
+// 요청 헤더 최대 크기 (바이트)
+const HEALTH_MAX_REQUEST_SIZE: usize = 8_192;
+
+///
+/// 상태 확인 HTTP 서버
+///
+/// - `GET /health/live`: 프로세스가 응답하면 항상 200
+/// - `GET /health/ready`: 준비 상태이면 200, 아니면 503 과 함께 CTI 세션 및 Acceptor 상태(JSON)를 반환
+/// - `GET /metrics`: 브로커 채널 지표(Prometheus 텍스트 형식)
+///
+pub struct HealthServer {
+    health_listener: TcpListener,
+    health_state: Arc<HealthState>,
+    channel_metrics: Arc<ChannelMetrics>,
+}
+
+impl HealthServer {
+    ///
+    /// HealthServer 생성
+    ///
+    pub async fn new(
+        config: &HealthConfig,
+        health_state: Arc<HealthState>,
+        channel_metrics: Arc<ChannelMetrics>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let health_listener = bind_tcp_listener(&config.bind_address, config.port, true).await?;
+
+        Ok(Self {
+            health_listener,
+            health_state,
+            channel_metrics,
+        })
+    }
+}
+
+#[async_trait]
+impl Acceptor for HealthServer {
+    fn get_name(&self) -> &str {
+        "health"
+    }
+
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        _client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!("Health server starts accepting");
+
+        loop {
+            let (stream, client_addr) = tokio::select! {
+                accepted = self.health_listener.accept() => accepted?,
+                _ = wait_shutdown(&mut broker_event_channel_rx) => break,
+            };
+
+            let health_state = self.health_state.clone();
+            let channel_metrics = self.channel_metrics.clone();
+            tokio::spawn(async move {
+                match timeout(
+                    Duration::from_secs(5),
+                    handle_request(stream, &health_state, &channel_metrics),
+                )
+                .await
+                {
+                    Ok(Ok(())) => {}
+                    Ok(Err(e)) => log::debug!(
+                        "Unable to handle health request. client_addr: {:?}, error: {}",
+                        client_addr,
+                        e
+                    ),
+                    Err(_) => {
+                        log::debug!("Health request timed out. client_addr: {:?}", client_addr)
+                    }
+                }
+            });
+        }
+
+        log::info!("Health server stops accepting");
+
+        Ok(())
+    }
+}
+
+///
+/// HTTP 요청 하나를 처리하고 연결을 닫는다
+///
+async fn handle_request<S: AsyncRead + AsyncWrite + Unpin>(
+    mut stream: S,
+    health_state: &HealthState,
+    channel_metrics: &ChannelMetrics,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    // 요청 줄만 확인한다
+    let mut buffer = Vec::new();
+    let mut chunk = [0_u8; 1_024];
+    while !buffer.windows(2).any(|window| window == b"\r\n") {
+        if buffer.len() > HEALTH_MAX_REQUEST_SIZE {
+            return write_response(&mut stream, "413 Payload Too Large", "text/plain", b"").await;
+        }
+        match stream.read(&mut chunk).await? {
+            0 => return Ok(()),
+            n => buffer.extend_from_slice(&chunk[0..n]),
+        }
+    }
+
+    let request = String::from_utf8_lossy(&buffer).to_string();
+    let mut request_line = request.lines().next().unwrap_or("").split_whitespace();
+    let method = request_line.next().unwrap_or("");
+    let path = request_line
+        .next()
+        .unwrap_or("")
+        .split('?')
+        .next()
+        .unwrap_or("");
+
+    match (method, path) {
+        ("GET", "/health/live") => {
+            write_response(
+                &mut stream,
+                "200 OK",
+                "application/json",
+                br#"{"status":"ok"}"#,
+            )
+            .await
+        }
+        ("GET", "/health/ready") => {
+            let report = health_state.report();
+            let body = serde_json::to_vec(&report)?;
+            let status = match report.is_ready() {
+                true => "200 OK",
+                false => "503 Service Unavailable",
+            };
+            write_response(&mut stream, status, "application/json", &body).await
+        }
+        ("GET", "/metrics") => {
+            write_response(
+                &mut stream,
+                "200 OK",
+                "text/plain; version=0.0.4",
+                channel_metrics.to_prometheus().as_bytes(),
+            )
+            .await
+        }
+        ("GET", _) => write_response(&mut stream, "404 Not Found", "text/plain", b"").await,
+        _ => write_response(&mut stream, "405 Method Not Allowed", "text/plain", b"").await,
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt::Display;
+
+///
+/// HTTP 요청 헤더 최대 크기 (바이트)
+///
+pub const HTTP_MAX_HEADER_SIZE: usize = 16_384;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// HTTP 요청 파싱 오류
+///
+pub enum HttpRequestError {
+    // 요청 줄 또는 헤더 형식 오류
+    MALFORMED,
+    // 헤더가 최대 크기를 넘음
+    HEADER_TOO_LARGE,
+}
+
+impl Display for HttpRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for HttpRequestError {}
+
+impl HttpRequestError {
+    ///
+    /// 오류에 해당하는 HTTP 응답 상태
+    ///
+    pub fn get_status(&self) -> &'static str {
+        match self {
+            HttpRequestError::MALFORMED => "400 Bad Request",
+            HttpRequestError::HEADER_TOO_LARGE => "431 Request Header Fields Too Large",
+        }
+    }
+}
+
+///
+/// HTTP 요청 헤더
+///
+/// 헤더 이름은 대소문자를 구분하지 않으며, 같은 이름의 헤더가 여러 개이면 첫 번째 값을 사용한다.
+///
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    method: String,
+    path: String,
+    query: String,
+    version: String,
+    headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    ///
+    /// 수신 버퍼에서 헤더 끝(빈 줄)까지의 길이를 반환한다
+    ///
+    /// 헤더가 아직 끝나지 않았으면 None, 최대 크기를 넘으면 HEADER_TOO_LARGE 오류를 반환한다.
+    ///
+    pub fn header_length(buffer: &[u8]) -> Result<Option<usize>, HttpRequestError> {
+        match buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            Some(position) if position + 4 <= HTTP_MAX_HEADER_SIZE => Ok(Some(position + 4)),
+            Some(_) => Err(HttpRequestError::HEADER_TOO_LARGE),
+            None if buffer.len() >= HTTP_MAX_HEADER_SIZE => Err(HttpRequestError::HEADER_TOO_LARGE),
+            None => Ok(None),
+        }
+    }
+
+    ///
+    /// 요청 줄과 헤더를 파싱한다
+    ///
+    pub fn parse(header: &[u8]) -> Result<Self, HttpRequestError> {
+        let header = std::str::from_utf8(header).map_err(|_| HttpRequestError::MALFORMED)?;
+        let mut lines = header.split("\r\n");
+
+        // 요청 줄: METHOD SP request-target SP HTTP-version
+        let mut request_line = lines.next().ok_or(HttpRequestError::MALFORMED)?.split(' ');
+        let (Some(method), Some(target), Some(version), None) = (
+            request_line.next(),
+            request_line.next(),
+            request_line.next(),
+            request_line.next(),
+        ) else {
+            return Err(HttpRequestError::MALFORMED);
+        };
+        if method.is_empty() || !target.starts_with('/') || !version.starts_with("HTTP/") {
+            return Err(HttpRequestError::MALFORMED);
+        }
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+        let mut headers = vec![];
+        for line in lines.take_while(|line| !line.is_empty()) {
+            let (name, value) = line.split_once(':').ok_or(HttpRequestError::MALFORMED)?;
+            // 헤더 이름과 콜론 사이 공백은 허용하지 않는다 (RFC 9112)
+            if name.is_empty() || name.ends_with(char::is_whitespace) {
+                return Err(HttpRequestError::MALFORMED);
+            }
+            headers.push((name.to_string(), value.trim().to_string()));
+        }
+
+        Ok(Self {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: query.to_string(),
+            version: version.to_string(),
+            headers,
+        })
+    }
+
+    pub fn get_method(&self) -> &str {
+        &self.method
+    }
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn get_query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn get_version(&self) -> &str {
+        &self.version
+    }
+
+    ///
+    /// 헤더 값 반환
+    ///
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    ///
+    /// 쉼표로 구분된 헤더 값에 토큰이 있는지 확인한다 (대소문자 구분 없음)
+    ///
+    pub fn has_header_token(&self, name: &str, token: &str) -> bool {
+        self.headers
+            .iter()
+            .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+            .flat_map(|(_, value)| value.split(','))
+            .any(|value| value.trim().eq_ignore_ascii_case(token))
+    }
+
+    ///
+    /// 쿼리 파라미터 값 반환 (퍼센트 인코딩을 해제한다)
+    ///
+    pub fn get_query_param(&self, name: &str) -> Option<String> {
+        self.query
+            .split('&')
+            .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| percent_decode(value))
+    }
+}
+
+///
+/// 본문이 없는 HTTP 응답 (연결을 닫는다)
+///
+/// headers 는 `이름: 값\r\n` 형식의 추가 헤더다.
+///
+pub fn empty_response(status: &str, headers: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\n{}Content-Length: 0\r\nConnection: close\r\n\r\n",
+        status, headers
+    )
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = match bytes[index] {
+            b'%' if index + 2 < bytes.len() => std::str::from_utf8(&bytes[index + 1..index + 3])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None,
+        };
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).to_string()
+}
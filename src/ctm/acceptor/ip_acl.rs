@@ -0,0 +1,111 @@
+use std::{error::Error, net::IpAddr};
+
+use crate::ctm::net_addr::strip_brackets;
+
+///
+/// 허용 대역 (주소/접두사 길이)
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IpNetwork {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpNetwork {
+    ///
+    /// `172.30.1.0/24`, `fd00::/8`, `[fd00::]/8` 또는 단일 주소를 파싱한다
+    ///
+    fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let (addr, prefix_len) = match text.split_once('/') {
+            Some((addr, prefix_len)) => (
+                strip_brackets(addr).parse::<IpAddr>()?,
+                Some(prefix_len.parse::<u8>()?),
+            ),
+            None => (strip_brackets(text).parse::<IpAddr>()?, None),
+        };
+        let max_prefix_len = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix_len = prefix_len.unwrap_or(max_prefix_len);
+        if prefix_len > max_prefix_len {
+            return Err(format!("invalid prefix length '{}'", prefix_len).into());
+        }
+
+        Ok(Self { addr, prefix_len })
+    }
+
+    fn contains(&self, addr: IpAddr) -> bool {
+        // IPv4-mapped IPv6 주소(::ffff:a.b.c.d)는 IPv4 대역으로 비교한다
+        let addr = match addr {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(addr),
+            IpAddr::V4(_) => addr,
+        };
+
+        match (self.addr, addr) {
+            (IpAddr::V4(network), IpAddr::V4(addr)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u32::from(network) & mask == u32::from(addr) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(addr)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u128::from(network) & mask == u128::from(addr) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+///
+/// 접속 허용 IP 목록
+///
+/// `{PREFIX}_ALLOWED_IPS` 에 쉼표로 구분된 주소 또는 CIDR 대역(IPv4, IPv6)을 지정한다.
+/// 목록이 비어 있으면 모든 주소의 접속을 허용한다.
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IpAcl {
+    networks: Vec<IpNetwork>,
+    deny_all: bool,
+}
+
+impl IpAcl {
+    ///
+    /// 쉼표로 구분된 허용 목록으로 생성한다
+    ///
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let networks = text
+            .split(',')
+            .map(str::trim)
+            .filter(|network| !network.is_empty())
+            .map(|network| {
+                IpNetwork::parse(network)
+                    .map_err(|e| format!("invalid network '{}' ({})", network, e).into())
+            })
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+        Ok(Self {
+            networks,
+            deny_all: false,
+        })
+    }
+
+    ///
+    /// 접속 허용 여부
+    ///
+    pub fn allows(&self, addr: IpAddr) -> bool {
+        !self.deny_all
+            && (self.networks.is_empty()
+                || self.networks.iter().any(|network| network.contains(addr)))
+    }
+
+    ///
+    /// 허용 대역 수 (0 이면 제한 없음)
+    ///
+    pub fn get_network_count(&self) -> usize {
+        self.networks.len()
+    }
+}
@@ -1,11 +1,200 @@
-use std::error::Error;
+use std::{error::Error, io, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
+use tokio::{
+    io::{AsyncWrite, AsyncWriteExt},
+    sync::{broadcast, mpsc},
+    time::timeout,
+};
+use uuid::Uuid;
 
+use crate::{
+    ctm::{client_auth::ClientPermission, config::Config},
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+
+pub mod amqp_publisher;
+pub mod client_cert;
+pub mod connection_limiter;
+pub mod database_writer;
+pub mod graphql_acceptor;
+pub mod health_server;
+pub mod http_request;
+pub mod ip_acl;
+pub mod mqtt_publisher;
+pub mod payload_format;
+pub mod redis_publisher;
+pub mod server_cert;
 pub mod tcp_acceptor;
+pub mod udp_multicast_publisher;
+pub mod webhook_publisher;
 pub mod websocket_acceptor;
+pub mod websocket_frame;
 
+///
+/// 클라이언트 접속을 수신해 브로커 이벤트를 전달하는 출력 전송 계층
+///
+/// CTM 실행 시 브로커 이벤트 수신 채널과 클라이언트 이벤트 송신 채널이 전달된다.
+///
 #[async_trait]
-pub trait Acceptor: Send {
-    async fn accept(&self) -> Result<(), Box<dyn Error + Send + Sync>>;
+pub trait Acceptor: Send + Sync {
+    ///
+    /// 상태 보고에 사용하는 Acceptor 이름
+    ///
+    fn get_name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    async fn accept(
+        &self,
+        broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+///
+/// 내부 클라이언트로 접속한다
+///
+/// 접속 시 전송되는 전체 상태를 받기 위해 사용하며, 내부 클라이언트 ID 를 반환한다.
+///
+pub(crate) async fn connect_internal_client(
+    client_event_channel_tx: &mpsc::Sender<ClientEvent>,
+) -> Result<Uuid, Box<dyn Error + Send + Sync>> {
+    let id = Uuid::now_v7();
+    client_event_channel_tx
+        .send(ClientEvent::Connect {
+            id,
+            permission: ClientPermission::READ_ONLY,
+            department_id: None,
+            common_name: None,
+            addr: None,
+            direct_event_tx: None,
+            resume: None,
+        })
+        .await?;
+
+    Ok(id)
+}
+
+///
+/// 전체 클라이언트 또는 내부 클라이언트 대상 브로커 이벤트를 수신한다
+///
+/// 처리가 늦어 누락된 이벤트는 건너뛰며, 채널이 닫히거나 서버가 종료되면 None 을 반환한다.
+///
+pub(crate) async fn recv_broker_event(
+    broker_event_channel_rx: &mut broadcast::Receiver<BrokerEvent>,
+    client_id: Uuid,
+) -> Option<BrokerEvent> {
+    loop {
+        match broker_event_channel_rx.recv().await {
+            Ok(BrokerEvent::Shutdown) => return None,
+            Ok(broker_event) => {
+                if broker_event
+                    .get_client_id()
+                    .is_some_and(|target_client_id| target_client_id != client_id)
+                {
+                    continue;
+                }
+                return Some(broker_event);
+            }
+            Err(broadcast::error::RecvError::Lagged(n)) => {
+                log::warn!("Broker event receiver lagged. skipped: {}", n);
+            }
+            Err(broadcast::error::RecvError::Closed) => return None,
+        }
+    }
+}
+
+///
+/// 서버 종료 이벤트를 기다린다
+///
+/// 신규 접속 수신 루프에서 사용하며, 채널이 닫힌 경우에도 반환한다.
+///
+pub(crate) async fn wait_shutdown(broker_event_channel_rx: &mut broadcast::Receiver<BrokerEvent>) {
+    loop {
+        match broker_event_channel_rx.recv().await {
+            Ok(BrokerEvent::Shutdown) | Err(broadcast::error::RecvError::Closed) => return,
+            Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+        }
+    }
+}
+
+///
+/// 신규 접속 수신 루프 제어 신호
+///
+pub(crate) enum AcceptorSignal {
+    // 다시 읽은 설정
+    Reload(Arc<Config>),
+    Shutdown,
+}
+
+///
+/// 설정 재적용 또는 서버 종료 이벤트를 기다린다
+///
+/// 채널이 닫힌 경우에는 종료 신호를 반환한다.
+///
+pub(crate) async fn wait_acceptor_signal(
+    broker_event_channel_rx: &mut broadcast::Receiver<BrokerEvent>,
+) -> AcceptorSignal {
+    loop {
+        match broker_event_channel_rx.recv().await {
+            Ok(BrokerEvent::ReloadConfig { config }) => return AcceptorSignal::Reload(config),
+            Ok(BrokerEvent::Shutdown) | Err(broadcast::error::RecvError::Closed) => {
+                return AcceptorSignal::Shutdown
+            }
+            Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+        }
+    }
+}
+
+///
+/// 제한 시간 안에 버퍼 전체를 전송한다
+///
+/// 메시지가 중간에 잘리지 않도록 모두 쓸 때까지 기다린 뒤 flush 하며,
+/// 클라이언트가 받지 않아 제한 시간을 넘으면 TimedOut 오류를 반환한다.
+///
+pub async fn write_all_timeout<S: AsyncWrite + Unpin>(
+    stream: &mut S,
+    buffer: &[u8],
+    write_timeout: Duration,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let written = timeout(write_timeout, async {
+        stream.write_all(buffer).await?;
+        stream.flush().await
+    })
+    .await;
+
+    match written {
+        Ok(result) => Ok(result?),
+        Err(_) => Err(Box::new(io::Error::new(
+            io::ErrorKind::TimedOut,
+            "write timed out",
+        ))),
+    }
+}
+
+///
+/// HTTP 응답을 전송하고 연결을 닫는다
+///
+pub(crate) async fn write_response<S: AsyncWrite + Unpin>(
+    stream: &mut S,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    stream
+        .write_all(
+            format!(
+                "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                content_type,
+                body.len()
+            )
+            .as_bytes(),
+        )
+        .await?;
+    stream.write_all(body).await?;
+    stream.shutdown().await?;
+
+    Ok(())
 }
@@ -0,0 +1,202 @@
+use std::{error::Error, time::Duration};
+
+use async_trait::async_trait;
+use rumqttc::{AsyncClient, MqttOptions, QoS};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::{
+    ctm::{agent_delta::AgentInfoMirror, config::MqttConfig},
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+
+use super::{connect_internal_client, recv_broker_event, Acceptor};
+
+///
+/// MQTT 발행기
+///
+/// 상담직원, 스킬그룹 상태와 전광판 요약을 토픽별 retained 메시지(JSON)로 발행한다.
+///
+/// - `{prefix}/agent/{cti_instance}/{agent_id}`
+/// - `{prefix}/skill_group/{cti_instance}/{skill_group_id}`
+/// - `{prefix}/wallboard_summary`
+///
+pub struct MqttPublisher {
+    mqtt_options: MqttOptions,
+    topic_prefix: String,
+    qos: QoS,
+}
+
+impl MqttPublisher {
+    ///
+    /// MqttPublisher 생성
+    ///
+    pub async fn new(config: &MqttConfig) -> Result<Self, Box<dyn Error>> {
+        let mut mqtt_options =
+            MqttOptions::new(config.client_id.clone(), config.host.clone(), config.port);
+        mqtt_options.set_keep_alive(config.keep_alive);
+        if !config.username.is_empty() {
+            mqtt_options.set_credentials(config.username.clone(), config.password.clone());
+        }
+
+        Ok(Self {
+            mqtt_options,
+            topic_prefix: config.topic_prefix.clone(),
+            qos: Self::parse_qos(config.qos),
+        })
+    }
+
+    ///
+    /// QoS 변환 (0, 1, 2 외의 값은 1 로 처리한다)
+    ///
+    pub fn parse_qos(qos: u8) -> QoS {
+        match qos {
+            0 => QoS::AtMostOnce,
+            2 => QoS::ExactlyOnce,
+            _ => QoS::AtLeastOnce,
+        }
+    }
+
+    ///
+    /// 상담직원 상태 토픽
+    ///
+    pub fn agent_topic(topic_prefix: &str, cti_instance: &str, agent_id: &str) -> String {
+        format!("{}/agent/{}/{}", topic_prefix, cti_instance, agent_id)
+    }
+
+    ///
+    /// 스킬그룹 상태 토픽
+    ///
+    pub fn skill_group_topic(
+        topic_prefix: &str,
+        cti_instance: &str,
+        skill_group_id: u32,
+    ) -> String {
+        format!(
+            "{}/skill_group/{}/{}",
+            topic_prefix, cti_instance, skill_group_id
+        )
+    }
+
+    ///
+    /// 브로커 이벤트 처리
+    ///
+    async fn handle(
+        &self,
+        mqtt_client: &AsyncClient,
+        agent_info_mirror: &mut AgentInfoMirror,
+        broker_event: BrokerEvent,
+    ) -> Result<(), Box<dyn Error>> {
+        for agent_info in agent_info_mirror.apply(&broker_event) {
+            mqtt_client
+                .publish(
+                    Self::agent_topic(
+                        &self.topic_prefix,
+                        agent_info.get_cti_instance(),
+                        agent_info.get_agent_id(),
+                    ),
+                    self.qos,
+                    true,
+                    serde_json::to_vec(&agent_info)?,
+                )
+                .await?;
+        }
+
+        match broker_event {
+            // 빈 retained 메시지로 토픽에 남은 상태를 지운다
+            BrokerEvent::BroadCastAgentRemoved { agent_removal, .. } => {
+                mqtt_client
+                    .publish(
+                        Self::agent_topic(
+                            &self.topic_prefix,
+                            agent_removal.get_cti_instance(),
+                            agent_removal.get_agent_id(),
+                        ),
+                        self.qos,
+                        true,
+                        vec![],
+                    )
+                    .await?;
+            }
+            BrokerEvent::BroadCastSkillGroupState {
+                skill_group_info, ..
+            } => {
+                mqtt_client
+                    .publish(
+                        Self::skill_group_topic(
+                            &self.topic_prefix,
+                            skill_group_info.get_cti_instance(),
+                            skill_group_info.get_skill_group_id(),
+                        ),
+                        self.qos,
+                        true,
+                        serde_json::to_vec(&skill_group_info)?,
+                    )
+                    .await?;
+            }
+            BrokerEvent::BroadCastWallboardSummary {
+                wallboard_summary, ..
+            } => {
+                mqtt_client
+                    .publish(
+                        format!("{}/wallboard_summary", self.topic_prefix),
+                        self.qos,
+                        true,
+                        serde_json::to_vec(&wallboard_summary)?,
+                    )
+                    .await?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Acceptor for MqttPublisher {
+    fn get_name(&self) -> &str {
+        "mqtt"
+    }
+
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!(
+            "MQTT publisher starts publishing. broker: {:?}",
+            self.mqtt_options.broker_address()
+        );
+
+        // 이벤트 루프를 계속 폴링해야 메시지가 전송되며, 접속 오류 시 다음 폴링에서 재접속한다
+        let (mqtt_client, mut event_loop) = AsyncClient::new(self.mqtt_options.clone(), 1_024);
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = event_loop.poll().await {
+                    log::warn!("MQTT connection error. {}", e);
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+            }
+        });
+
+        // retained 메시지 갱신을 위해 내부 클라이언트로 접속하여 전체 상태를 받는다
+        let id = connect_internal_client(&client_event_channel_tx).await?;
+
+        let mut agent_info_mirror = AgentInfoMirror::new();
+        while let Some(broker_event) = recv_broker_event(&mut broker_event_channel_rx, id).await {
+            if let Err(e) = self
+                .handle(&mqtt_client, &mut agent_info_mirror, broker_event)
+                .await
+            {
+                log::warn!("Unable to publish to MQTT broker. {}", e);
+            }
+        }
+
+        // 대기 중인 발행 요청 뒤에 연결 종료를 요청한다
+        if let Err(e) = mqtt_client.disconnect().await {
+            log::warn!("Unable to disconnect from MQTT broker. {}", e);
+        }
+
+        Ok(())
+    }
+}
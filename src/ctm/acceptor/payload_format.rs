@@ -0,0 +1,239 @@
+use std::{
+    error::Error,
+    fmt::Debug,
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///
+/// 클라이언트 전송 데이터 형식
+///
+pub enum PayloadFormat {
+    // MessagePack 이진 데이터 (기본값)
+    #[default]
+    MSGPACK,
+    // JSON 텍스트 데이터
+    JSON,
+}
+
+///
+/// 클라이언트 데이터 형식 협상 메시지
+///
+/// `{"format": "json"}` 형식의 MessagePack 또는 JSON 으로 수신한다.
+///
+#[derive(Debug, Deserialize)]
+struct FormatHandshake {
+    format: String,
+}
+
+impl PayloadFormat {
+    ///
+    /// 형식 이름(msgpack, json)을 변환한다
+    ///
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "msgpack" | "messagepack" => Some(PayloadFormat::MSGPACK),
+            "json" => Some(PayloadFormat::JSON),
+            _ => None,
+        }
+    }
+
+    ///
+    /// 클라이언트가 전송한 데이터가 형식 협상 메시지이면 요청한 형식을 반환한다
+    ///
+    pub fn parse_handshake(data: &[u8]) -> Option<Self> {
+        let handshake = match rmp_serde::from_slice::<FormatHandshake>(data) {
+            Ok(handshake) => handshake,
+            Err(_) => serde_json::from_slice::<FormatHandshake>(data).ok()?,
+        };
+
+        Self::parse(&handshake.format)
+    }
+
+    ///
+    /// 데이터를 형식에 맞게 직렬화한다
+    ///
+    pub fn serialize<T: Serialize>(
+        &self,
+        payload: &T,
+    ) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        match self {
+            PayloadFormat::MSGPACK => {
+                let mut buffer = Vec::new();
+                payload.serialize(&mut rmp_serde::Serializer::new(&mut buffer))?;
+                Ok(buffer)
+            }
+            PayloadFormat::JSON => Ok(serde_json::to_vec(payload)?),
+        }
+    }
+}
+
+///
+/// 현재 클라이언트 프로토콜 버전
+///
+pub const CLIENT_PROTOCOL_VERSION: u32 = 1;
+
+///
+/// 버전이 포함된 클라이언트 전송 데이터
+///
+#[derive(Debug, Serialize)]
+struct VersionedPayload<'a, T: Serialize> {
+    version: u32,
+    payload: &'a T,
+}
+
+///
+/// 클라이언트 프로토콜 (데이터 형식과 프로토콜 버전)
+///
+/// 웹 소켓 서브 프로토콜 `ctm.v{버전}.{형식}`(예: `ctm.v1.msgpack`, `ctm.v1.json`)으로 협상하면
+/// 모든 전송 데이터를 `{"version": 버전, "payload": 데이터}` 형식으로 감싸 보낸다.
+/// 버전 없이 형식 이름(msgpack, json)만 협상한 기존 클라이언트에는 데이터를 그대로 보낸다.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClientProtocol {
+    format: PayloadFormat,
+    version: Option<u32>,
+}
+
+impl ClientProtocol {
+    pub fn new(format: PayloadFormat, version: Option<u32>) -> Self {
+        Self { format, version }
+    }
+
+    pub fn get_format(&self) -> PayloadFormat {
+        self.format
+    }
+
+    pub fn get_version(&self) -> Option<u32> {
+        self.version
+    }
+
+    ///
+    /// 서브 프로토콜 이름을 변환한다
+    ///
+    /// 지원하지 않는 버전이나 형식이면 None 을 반환한다.
+    ///
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        let Some(versioned) = name.strip_prefix("ctm.v") else {
+            return PayloadFormat::parse(&name).map(|format| Self::new(format, None));
+        };
+
+        let (version, format) = versioned.split_once('.')?;
+        let version = version.parse::<u32>().ok()?;
+        if version == 0 || version > CLIENT_PROTOCOL_VERSION {
+            return None;
+        }
+
+        Some(Self::new(PayloadFormat::parse(format)?, Some(version)))
+    }
+
+    ///
+    /// 클라이언트가 제시한 서브 프로토콜 목록(쉼표 구분)에서 지원하는 첫 번째 프로토콜을 선택한다
+    ///
+    /// 선택한 서브 프로토콜 이름과 프로토콜을 반환한다.
+    ///
+    pub fn negotiate(protocols: &str) -> Option<(String, Self)> {
+        protocols.split(',').find_map(|protocol| {
+            Self::parse(protocol)
+                .map(|client_protocol| (protocol.trim().to_string(), client_protocol))
+        })
+    }
+
+    ///
+    /// 데이터를 프로토콜에 맞게 직렬화한다
+    ///
+    pub fn serialize<T: Serialize>(
+        &self,
+        payload: &T,
+    ) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        match self.version {
+            Some(version) => self
+                .format
+                .serialize(&VersionedPayload { version, payload }),
+            None => self.format.serialize(payload),
+        }
+    }
+}
+
+///
+/// 클라이언트 프로토콜별 직렬화 결과
+///
+type SerializedPayload = (ClientProtocol, Arc<Vec<u8>>);
+
+///
+/// 여러 클라이언트에 전송하는 공유 데이터
+///
+/// 복제해도 데이터를 복사하지 않으며, 클라이언트 프로토콜별 직렬화 결과를 보관해
+/// 데이터를 받는 클라이언트 수와 관계없이 프로토콜별로 한 번만 직렬화한다.
+///
+pub struct SharedPayload<T> {
+    payload: Arc<T>,
+    serialized: Arc<Mutex<Vec<SerializedPayload>>>,
+}
+
+impl<T> Clone for SharedPayload<T> {
+    fn clone(&self) -> Self {
+        Self {
+            payload: self.payload.clone(),
+            serialized: self.serialized.clone(),
+        }
+    }
+}
+
+impl<T: Debug> Debug for SharedPayload<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.payload.fmt(f)
+    }
+}
+
+impl<T> Deref for SharedPayload<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.payload
+    }
+}
+
+impl<T> From<T> for SharedPayload<T> {
+    fn from(payload: T) -> Self {
+        Self::new(payload)
+    }
+}
+
+impl<T> SharedPayload<T> {
+    pub fn new(payload: T) -> Self {
+        Self {
+            payload: Arc::new(payload),
+            serialized: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+impl<T: Serialize> SharedPayload<T> {
+    ///
+    /// 클라이언트 프로토콜로 직렬화한 데이터를 반환한다
+    ///
+    /// 같은 프로토콜로 이미 직렬화했으면 보관 중인 결과를 반환한다.
+    ///
+    pub fn serialize(
+        &self,
+        client_protocol: ClientProtocol,
+    ) -> Result<Arc<Vec<u8>>, Box<dyn Error + Send + Sync>> {
+        let mut serialized = self.serialized.lock().unwrap();
+        if let Some((_, buffer)) = serialized
+            .iter()
+            .find(|(protocol, _)| *protocol == client_protocol)
+        {
+            return Ok(buffer.clone());
+        }
+
+        let buffer = Arc::new(client_protocol.serialize(self.payload.as_ref())?);
+        serialized.push((client_protocol, buffer.clone()));
+        Ok(buffer)
+    }
+}
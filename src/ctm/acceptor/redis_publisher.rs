@@ -0,0 +1,190 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::{broadcast, mpsc};
+
+use crate::{
+    ctm::{agent_delta::AgentInfoMirror, agent_info::AgentInfo, config::RedisConfig},
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+
+use super::{connect_internal_client, recv_broker_event, Acceptor};
+
+///
+/// Redis 발행기
+///
+/// 브로커 이벤트를 pub/sub 채널(`{prefix}agent_state` 등)에 JSON 으로 발행하고,
+/// 상담직원 현재 상태를 `{prefix}agent:{cti_instance}:{agent_id}` 해시에 유지한다.
+///
+pub struct RedisPublisher {
+    connection_manager: ConnectionManager,
+    key_prefix: String,
+}
+
+impl RedisPublisher {
+    ///
+    /// RedisPublisher 생성
+    ///
+    /// 연결이 끊어지면 다음 명령 실행 시 자동으로 재접속한다.
+    ///
+    pub async fn new(config: &RedisConfig) -> Result<Self, Box<dyn Error>> {
+        let client = redis::Client::open(config.url.as_str())?;
+        let connection_manager = client.get_connection_manager().await?;
+
+        Ok(Self {
+            connection_manager,
+            key_prefix: config.key_prefix.clone(),
+        })
+    }
+
+    ///
+    /// 상담직원 상태 해시 키
+    ///
+    pub fn agent_key(key_prefix: &str, cti_instance: &str, agent_id: &str) -> String {
+        format!("{}agent:{}:{}", key_prefix, cti_instance, agent_id)
+    }
+
+    ///
+    /// 상담직원 상태를 해시 필드 목록으로 변환한다
+    ///
+    /// 문자열이 아닌 값은 JSON 으로 저장한다.
+    ///
+    pub fn agent_fields(agent_info: &AgentInfo) -> Vec<(String, String)> {
+        match serde_json::to_value(agent_info) {
+            Ok(Value::Object(fields)) => fields
+                .into_iter()
+                .map(|(field, value)| match value {
+                    Value::String(value) => (field, value),
+                    value => (field, value.to_string()),
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    ///
+    /// 상담직원 상태 해시를 갱신하고 변경을 발행한다
+    ///
+    async fn publish_agent_info(&self, agent_info: &AgentInfo) -> Result<(), Box<dyn Error>> {
+        let key = Self::agent_key(
+            &self.key_prefix,
+            agent_info.get_cti_instance(),
+            agent_info.get_agent_id(),
+        );
+
+        redis::pipe()
+            .atomic()
+            .hset_multiple(key, &Self::agent_fields(agent_info))
+            .ignore()
+            .publish(
+                format!("{}agent_state", self.key_prefix),
+                serde_json::to_string(agent_info)?,
+            )
+            .ignore()
+            .query_async::<()>(&mut self.connection_manager.clone())
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// 브로커 이벤트를 채널에 발행한다
+    ///
+    async fn publish<T: Serialize>(
+        &self,
+        channel: &str,
+        payload: &T,
+    ) -> Result<(), Box<dyn Error>> {
+        redis::cmd("PUBLISH")
+            .arg(format!("{}{}", self.key_prefix, channel))
+            .arg(serde_json::to_string(payload)?)
+            .query_async::<()>(&mut self.connection_manager.clone())
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// 브로커 이벤트 처리
+    ///
+    async fn handle(
+        &self,
+        agent_info_mirror: &mut AgentInfoMirror,
+        broker_event: BrokerEvent,
+    ) -> Result<(), Box<dyn Error>> {
+        for agent_info in agent_info_mirror.apply(&broker_event) {
+            self.publish_agent_info(&agent_info).await?;
+        }
+
+        match broker_event {
+            BrokerEvent::BroadCastAgentRemoved { agent_removal, .. } => {
+                redis::pipe()
+                    .atomic()
+                    .del(Self::agent_key(
+                        &self.key_prefix,
+                        agent_removal.get_cti_instance(),
+                        agent_removal.get_agent_id(),
+                    ))
+                    .ignore()
+                    .publish(
+                        format!("{}agent_removed", self.key_prefix),
+                        serde_json::to_string(&agent_removal)?,
+                    )
+                    .ignore()
+                    .query_async::<()>(&mut self.connection_manager.clone())
+                    .await?;
+            }
+            BrokerEvent::BroadCastAgentStatistics {
+                agent_statistics, ..
+            } => self.publish("agent_statistics", &agent_statistics).await?,
+            BrokerEvent::BroadCastCallState { call_info, .. } => {
+                self.publish("call_state", &call_info).await?
+            }
+            BrokerEvent::BroadCastSkillGroupState {
+                skill_group_info, ..
+            } => self.publish("skill_group_state", &skill_group_info).await?,
+            BrokerEvent::BroadCastTeamSnapshot { team_infos, .. } => {
+                self.publish("team_snapshot", &team_infos).await?
+            }
+            BrokerEvent::BroadCastWallboardSummary {
+                wallboard_summary, ..
+            } => {
+                self.publish("wallboard_summary", &wallboard_summary)
+                    .await?
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Acceptor for RedisPublisher {
+    fn get_name(&self) -> &str {
+        "redis"
+    }
+
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!("Redis publisher starts publishing");
+
+        // 해시 갱신을 위해 내부 클라이언트로 접속하여 전체 상태를 받는다
+        let id = connect_internal_client(&client_event_channel_tx).await?;
+
+        let mut agent_info_mirror = AgentInfoMirror::new();
+        while let Some(broker_event) = recv_broker_event(&mut broker_event_channel_rx, id).await {
+            if let Err(e) = self.handle(&mut agent_info_mirror, broker_event).await {
+                log::warn!("Unable to publish to redis. {}", e);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    error::Error,
+    fmt::Debug,
+    fs,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use rustls::{
+    crypto::CryptoProvider,
+    pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
+    server::{ClientHello, ResolvesServerCert},
+    sign::CertifiedKey,
+};
+
+///
+/// SNI 인증서 설정 (호스트 이름, 인증서 파일, 키 파일)
+///
+pub type SniCert = (String, String, String);
+
+///
+/// 인증서/키 파일 쌍과 마지막으로 읽은 인증서
+///
+struct ServerCert {
+    // SNI 호스트 이름 (None: 기본 인증서)
+    server_name: Option<String>,
+    cert_file: String,
+    key_file: String,
+    modified: Mutex<Option<SystemTime>>,
+    certified_key: RwLock<Arc<CertifiedKey>>,
+}
+
+impl ServerCert {
+    fn load(
+        server_name: Option<String>,
+        cert_file: &str,
+        key_file: &str,
+        provider: &CryptoProvider,
+    ) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            server_name: server_name.map(|server_name| server_name.to_lowercase()),
+            cert_file: cert_file.to_string(),
+            key_file: key_file.to_string(),
+            modified: Mutex::new(modified(cert_file, key_file)),
+            certified_key: RwLock::new(Arc::new(certified_key(cert_file, key_file, provider)?)),
+        })
+    }
+
+    ///
+    /// 호스트 이름 일치 여부 (`*.example.com` 형식은 한 단계 하위 도메인과 일치)
+    ///
+    fn matches(&self, server_name: &str) -> bool {
+        match self.server_name.as_deref() {
+            Some(name) => match name.strip_prefix("*.") {
+                Some(domain) => server_name
+                    .split_once('.')
+                    .is_some_and(|(_, rest)| rest.eq_ignore_ascii_case(domain)),
+                None => name.eq_ignore_ascii_case(server_name),
+            },
+            None => false,
+        }
+    }
+}
+
+///
+/// 서버 인증서 선택기
+///
+/// TLS 접속 시 SNI 호스트 이름에 맞는 인증서를, 일치하는 인증서가 없으면 기본 인증서를 사용한다.
+/// reload 호출 시 파일 수정 시각이 바뀐 인증서만 다시 읽으며, 읽기에 실패하면 기존 인증서를 계속 사용한다.
+///
+pub struct ServerCertResolver {
+    provider: Arc<CryptoProvider>,
+    server_certs: Vec<ServerCert>,
+}
+
+impl Debug for ServerCertResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerCertResolver")
+            .field(
+                "server_names",
+                &self
+                    .server_certs
+                    .iter()
+                    .map(|server_cert| server_cert.server_name.as_deref())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl ServerCertResolver {
+    ///
+    /// ServerCertResolver 생성
+    ///
+    pub fn new(
+        provider: Arc<CryptoProvider>,
+        cert_file: &str,
+        key_file: &str,
+        sni_certs: &[SniCert],
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut server_certs = vec![ServerCert::load(None, cert_file, key_file, &provider)?];
+        for (server_name, cert_file, key_file) in sni_certs {
+            server_certs.push(ServerCert::load(
+                Some(server_name.clone()),
+                cert_file,
+                key_file,
+                &provider,
+            )?);
+        }
+
+        Ok(Self {
+            provider,
+            server_certs,
+        })
+    }
+
+    ///
+    /// SNI 인증서 설정 파싱
+    ///
+    /// `호스트 이름=인증서 파일,키 파일` 항목을 세미콜론으로 구분한다.
+    /// 예) `a.example.com=./res/ssl/a.crt,./res/ssl/a.key;*.example.com=./res/ssl/b.crt,./res/ssl/b.key`
+    ///
+    pub fn parse_sni_certs(value: &str) -> Result<Vec<SniCert>, Box<dyn Error>> {
+        value
+            .split(';')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (server_name, files) = entry
+                    .split_once('=')
+                    .ok_or(format!("Invalid SNI certificate entry. {}", entry))?;
+                let (cert_file, key_file) = files
+                    .split_once(',')
+                    .ok_or(format!("Invalid SNI certificate entry. {}", entry))?;
+
+                Ok((
+                    server_name.trim().to_string(),
+                    cert_file.trim().to_string(),
+                    key_file.trim().to_string(),
+                ))
+            })
+            .collect()
+    }
+
+    ///
+    /// 변경된 인증서를 다시 읽는다
+    ///
+    /// 다시 읽은 인증서 개수를 반환한다.
+    ///
+    pub fn reload(&self) -> usize {
+        let mut reloaded = 0;
+        for server_cert in self.server_certs.iter() {
+            let modified = modified(&server_cert.cert_file, &server_cert.key_file);
+            let mut last_modified = server_cert.modified.lock().unwrap();
+            if modified.is_none() || modified == *last_modified {
+                continue;
+            }
+
+            match certified_key(
+                &server_cert.cert_file,
+                &server_cert.key_file,
+                &self.provider,
+            ) {
+                Ok(certified_key) => {
+                    *server_cert.certified_key.write().unwrap() = Arc::new(certified_key);
+                    *last_modified = modified;
+                    reloaded += 1;
+                    log::info!(
+                        "Reloaded TLS certificate. server_name: {:?}, cert_file: {}",
+                        server_cert.server_name,
+                        server_cert.cert_file
+                    );
+                }
+                // 인증서와 키를 따로 교체하는 중일 수 있으므로 다음 주기에 다시 시도한다
+                Err(e) => log::error!(
+                    "Unable to reload TLS certificate. server_name: {:?}, cert_file: {}, error: {}",
+                    server_cert.server_name,
+                    server_cert.cert_file,
+                    e
+                ),
+            }
+        }
+
+        reloaded
+    }
+
+    ///
+    /// 주기적으로 인증서 파일 변경을 확인한다 (TLS_CERT_RELOAD_INTERVAL, None: 사용하지 않음)
+    ///
+    pub fn watch(self: &Arc<Self>, reload_interval: Option<Duration>) {
+        let Some(reload_interval) = reload_interval else {
+            return;
+        };
+
+        let server_cert_resolver = self.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(reload_interval).await;
+                server_cert_resolver.reload();
+            }
+        });
+    }
+}
+
+impl ResolvesServerCert for ServerCertResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let server_cert = client_hello
+            .server_name()
+            .and_then(|server_name| {
+                self.server_certs
+                    .iter()
+                    .find(|server_cert| server_cert.matches(server_name))
+            })
+            .or(self.server_certs.first())?;
+
+        Some(server_cert.certified_key.read().unwrap().clone())
+    }
+}
+
+///
+/// 인증서, 키 파일 중 늦은 수정 시각
+///
+fn modified(cert_file: &str, key_file: &str) -> Option<SystemTime> {
+    let cert_modified = fs::metadata(cert_file).and_then(|metadata| metadata.modified());
+    let key_modified = fs::metadata(key_file).and_then(|metadata| metadata.modified());
+
+    match (cert_modified, key_modified) {
+        (Ok(cert_modified), Ok(key_modified)) => Some(cert_modified.max(key_modified)),
+        _ => None,
+    }
+}
+
+fn certified_key(
+    cert_file: &str,
+    key_file: &str,
+    provider: &CryptoProvider,
+) -> Result<CertifiedKey, Box<dyn Error>> {
+    let cert = CertificateDer::pem_file_iter(cert_file)?.collect::<Result<Vec<_>, _>>()?;
+    let key = PrivateKeyDer::from_pem_file(key_file)?;
+
+    Ok(CertifiedKey::from_der(cert, key, provider)?)
+}
@@ -1,23 +1,45 @@
-use std::{error::Error, net::SocketAddr, sync::Arc, time::Duration};
+use std::{
+    error::Error,
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 use async_trait::async_trait;
-use rustls::{
-    pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
-    ServerConfig,
-};
+use rustls::ServerConfig;
 use serde::Serialize;
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
     net::{TcpListener, TcpStream},
     sync::{broadcast, mpsc},
-    time::timeout,
+    time::{sleep, sleep_until, timeout, Instant},
 };
 use tokio_rustls::{server::TlsStream, TlsAcceptor};
+use tracing::Instrument;
 use uuid::Uuid;
 
-use crate::event::{broker_event::BrokerEvent, client_event::ClientEvent};
+use crate::{
+    ctm::{
+        buffer_pool::send_buffer_pool,
+        client_auth::ClientAuthenticator,
+        client_heartbeat::{ClientHeartbeat, ClientHeartbeatType},
+        config::{Config, TcpAcceptorConfig},
+        logging::CONNECTION_SPAN_TARGET,
+        net_addr::bind_tcp_listener,
+        session_resume::SessionResume,
+        shutdown::ShutdownNotice,
+    },
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
 
-use super::Acceptor;
+use super::{
+    client_cert::{client_cert_verifier, common_name},
+    connection_limiter::ConnectionLimiter,
+    ip_acl::IpAcl,
+    payload_format::{ClientProtocol, PayloadFormat, SharedPayload},
+    server_cert::ServerCertResolver,
+    wait_acceptor_signal, write_all_timeout, Acceptor, AcceptorSignal,
+};
 
 ///
 /// TCP Acceptor
@@ -25,8 +47,19 @@ use super::Acceptor;
 pub struct TCPAcceptor {
     tcp_listener: TcpListener,
     tls_acceptor: Option<TlsAcceptor>,
-    broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
-    client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    server_cert_resolver: Option<Arc<ServerCertResolver>>,
+    client_authenticator: Arc<ClientAuthenticator>,
+    connection_limiter: ConnectionLimiter,
+    // 설정 재적용 시 같은 리스너의 설정을 찾는 리스너 이름
+    listener: Option<String>,
+    // 접속 허용 IP 목록 (설정 재적용 시 다시 읽는다)
+    ip_acl: RwLock<IpAcl>,
+    cert_reload_interval: Option<Duration>,
+    write_timeout: Duration,
+    handshake_timeout: Duration,
+    // 연결 확인 PING 전송 주기 (None: 전송하지 않음), PONG 대기 시간
+    ping_interval: Option<Duration>,
+    pong_timeout: Duration,
 }
 
 impl TCPAcceptor {
@@ -34,59 +67,141 @@ impl TCPAcceptor {
     /// TCPAcceptor 생성
     ///
     pub async fn new(
-        broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
-        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+        config: &TcpAcceptorConfig,
+        client_authenticator: Arc<ClientAuthenticator>,
     ) -> Result<Self, Box<dyn Error>> {
-        let ssl_enabled = dotenv::var("TCP_ACCEPTOR_SECURE")
-            .unwrap_or("false".to_string())
-            .parse::<bool>()
-            .unwrap_or(false);
-
         // TCP 소켓 서버 초기화
-        let tcp_listener = TcpListener::bind(format!(
-            "0.0.0.0:{}",
-            dotenv::var("TCP_ACCEPTOR_PORT").unwrap_or("5110".to_string())
-        ))
-        .await?;
+        let tcp_listener =
+            bind_tcp_listener(&config.bind_address, config.port, config.dual_stack).await?;
 
         // TLS acceptor 생성
+        // 인증서는 ServerCertResolver 가 제공하므로 파일이 교체되면 재시작 없이 반영된다
         let mut tls_acceptor = None;
-        if ssl_enabled {
-            let cert = dotenv::var("TCP_ACCEPTOR_SECURE_CERT_FILE")
-                .unwrap_or("./res/ssl/server.crt".to_string());
-            let key = dotenv::var("TCP_ACCEPTOR_SECURE_KEY_FILE")
-                .unwrap_or("./res/ssl/server.key".to_string());
-
-            let cert = CertificateDer::pem_file_iter(cert)?.collect::<Result<Vec<_>, _>>()?;
-            let key = PrivateKeyDer::from_pem_file(key)?;
+        let mut server_cert_resolver = None;
+        if config.secure {
+            let builder = ServerConfig::builder();
+            let resolver = Arc::new(ServerCertResolver::new(
+                builder.crypto_provider().clone(),
+                &config.cert_file,
+                &config.key_file,
+                &[],
+            )?);
 
-            let tls_config = ServerConfig::builder()
-                .with_no_client_auth()
-                .with_single_cert(cert, key)?;
+            // 클라이언트 인증서 검증 (mutual TLS) 사용 여부
+            let tls_config = match config.client_auth {
+                true => {
+                    builder.with_client_cert_verifier(client_cert_verifier(&config.client_ca_file)?)
+                }
+                false => builder.with_no_client_auth(),
+            }
+            .with_cert_resolver(resolver.clone());
 
             tls_acceptor = Some(TlsAcceptor::from(Arc::new(tls_config)));
+            server_cert_resolver = Some(resolver);
         }
 
         Ok(Self {
             tcp_listener,
             tls_acceptor,
-            broker_event_channel_rx,
-            client_event_channel_tx,
+            server_cert_resolver,
+            client_authenticator,
+            connection_limiter: ConnectionLimiter::from_config(&config.connection_limit),
+            listener: config.listener.clone(),
+            ip_acl: RwLock::new(config.allowed_ips.clone()),
+            cert_reload_interval: config.cert_reload_interval,
+            // 클라이언트 전송 제한 시간, 넘으면 연결을 끊는다
+            write_timeout: config.write_timeout,
+            handshake_timeout: config.handshake_timeout,
+            ping_interval: config.ping_interval,
+            pong_timeout: config.pong_timeout,
         })
     }
+
+    ///
+    /// 다시 읽은 설정의 접속 허용 IP 목록을 적용하고 TLS 인증서를 다시 읽는다
+    ///
+    /// 이미 접속한 클라이언트의 연결은 유지된다.
+    ///
+    fn reload(&self, config: &Config) {
+        if let Some(tcp_acceptor) = config
+            .tcp_acceptors
+            .iter()
+            .find(|tcp_acceptor| tcp_acceptor.listener == self.listener)
+        {
+            log::info!(
+                "TCP server reloaded IP ACL. networks: {}",
+                tcp_acceptor.allowed_ips.get_network_count()
+            );
+            *self.ip_acl.write().unwrap() = tcp_acceptor.allowed_ips.clone();
+        }
+
+        if let Some(server_cert_resolver) = self.server_cert_resolver.as_ref() {
+            server_cert_resolver.reload();
+        }
+    }
 }
 
 #[async_trait]
 impl Acceptor for TCPAcceptor {
+    fn get_name(&self) -> &str {
+        "tcp"
+    }
+
     ///
     /// 클라이언트 수신
     ///
-    async fn accept(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
-        log::info!("TCP server starts accepting");
+    async fn accept(
+        &self,
+        broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!(
+            "TCP server starts accepting. local_addr: {:?}",
+            self.tcp_listener.local_addr()
+        );
+        if let Some(server_cert_resolver) = self.server_cert_resolver.as_ref() {
+            server_cert_resolver.watch(self.cert_reload_interval);
+        }
 
+        let mut shutdown_rx = broker_event_channel_rx.resubscribe();
         loop {
-            match self.tcp_listener.accept().await {
-                Ok((native_stream, client_addr)) => {
+            let accepted = tokio::select! {
+                accepted = self.tcp_listener.accept() => accepted,
+                acceptor_signal = wait_acceptor_signal(&mut shutdown_rx) => match acceptor_signal {
+                    AcceptorSignal::Reload(config) => {
+                        self.reload(&config);
+                        continue;
+                    }
+                    AcceptorSignal::Shutdown => break,
+                },
+            };
+            match accepted {
+                Ok((mut native_stream, client_addr)) => {
+                    // 허용되지 않은 주소는 TLS 협상 없이 연결을 종료한다
+                    if !self.ip_acl.read().unwrap().allows(client_addr.ip()) {
+                        log::warn!(
+                            "TCP client rejected by IP ACL. client_addr: {:?}",
+                            client_addr
+                        );
+                        let _ = native_stream.shutdown().await;
+                        continue;
+                    }
+
+                    // 접속 제한을 넘으면 TLS 협상 없이 연결을 종료한다
+                    let connection_permit = match self.connection_limiter.try_acquire() {
+                        Ok(connection_permit) => connection_permit,
+                        Err(rejection) => {
+                            log::warn!(
+                                "TCP client rejected. client_addr: {:?}, reason: {}, active_clients: {}",
+                                client_addr,
+                                rejection,
+                                self.connection_limiter.get_active_clients()
+                            );
+                            let _ = native_stream.shutdown().await;
+                            continue;
+                        }
+                    };
+
                     let uuid = Uuid::now_v7();
                     log::info!(
                         "TCP client connected. client_addr: {:?}, id: {}",
@@ -98,37 +213,73 @@ impl Acceptor for TCPAcceptor {
                     let mut client_stream = match self.tls_acceptor {
                         Some(ref tls) => ClientStream::Secure {
                             stream: match tls.accept(native_stream).await {
-                                Ok(stream) => stream,
-                                Err(_) => continue,
+                                Ok(stream) => Box::new(stream),
+                                Err(e) => {
+                                    log::warn!(
+                                        "TCP client TLS handshake failed. client_addr: {:?}, error: {}",
+                                        client_addr,
+                                        e
+                                    );
+                                    continue;
+                                }
                             },
                             id: uuid,
-                            addr: client_addr.clone(),
+                            addr: client_addr,
+                            write_timeout: self.write_timeout,
                         },
                         None => ClientStream::Plain {
                             stream: native_stream,
                             id: uuid,
-                            addr: client_addr.clone(),
+                            addr: client_addr,
+                            write_timeout: self.write_timeout,
                         },
                     };
 
                     // 접속된 클라이언트 핸들링
-                    let broker_event_channel_rx = self.broker_event_channel_rx.resubscribe();
-                    let client_event_channel_tx = self.client_event_channel_tx.clone();
-                    tokio::spawn(async move {
-                        client_stream
-                            .handle(broker_event_channel_rx, client_event_channel_tx)
-                            .await
-                            .unwrap();
-                        log::info!("TCP client disconnected. client_addr: {:?}", client_addr);
-                    });
+                    let broker_event_channel_rx = broker_event_channel_rx.resubscribe();
+                    let client_event_channel_tx = client_event_channel_tx.clone();
+                    let client_authenticator = self.client_authenticator.clone();
+                    let handshake_timeout = self.handshake_timeout;
+                    let (ping_interval, pong_timeout) = (self.ping_interval, self.pong_timeout);
+                    let connection_span = tracing::info_span!(
+                        target: CONNECTION_SPAN_TARGET,
+                        "client",
+                        acceptor = "tcp",
+                        client_id = %uuid,
+                        client_addr = %client_addr,
+                    );
+                    tokio::spawn(
+                        async move {
+                            let _connection_permit = connection_permit;
+                            client_stream
+                                .handle(
+                                    broker_event_channel_rx,
+                                    client_event_channel_tx,
+                                    client_authenticator,
+                                    handshake_timeout,
+                                    ping_interval,
+                                    pong_timeout,
+                                )
+                                .await
+                                .unwrap();
+                            log::info!("TCP client disconnected. client_addr: {:?}", client_addr);
+                        }
+                        .instrument(connection_span),
+                    );
                 }
                 Err(e) => {
                     log::error!("Unable to accept TCP client connection. {:?}", e);
-                    break;
+                    return Ok(());
                 }
             }
         }
 
+        log::info!("TCP server stops accepting");
+        // 접속 중인 클라이언트가 종료 안내를 받고 연결을 끊을 때까지 기다린다
+        while self.connection_limiter.get_active_clients() > 0 {
+            sleep(Duration::from_millis(10)).await;
+        }
+
         Ok(())
     }
 }
@@ -141,11 +292,13 @@ enum ClientStream {
         stream: TcpStream,
         id: Uuid,
         addr: SocketAddr,
+        write_timeout: Duration,
     },
     Secure {
-        stream: TlsStream<TcpStream>,
+        stream: Box<TlsStream<TcpStream>>,
         id: Uuid,
         addr: SocketAddr,
+        write_timeout: Duration,
     },
 }
 
@@ -159,15 +312,34 @@ impl ClientStream {
                 stream: _,
                 id,
                 addr: _,
+                write_timeout: _,
             } => id,
             ClientStream::Secure {
                 stream: _,
                 id,
                 addr: _,
+                write_timeout: _,
             } => id,
         }
     }
 
+    ///
+    /// 클라이언트 인증서의 CN 반환
+    ///
+    /// TLS 를 사용하지 않거나 클라이언트 인증서가 없으면 None 을 반환한다.
+    ///
+    fn get_common_name(&self) -> Option<String> {
+        match self {
+            ClientStream::Plain { .. } => None,
+            ClientStream::Secure { stream, .. } => stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certs| certs.first())
+                .and_then(|cert| common_name(cert)),
+        }
+    }
+
     ///
     /// 주소 반환
     ///
@@ -177,11 +349,13 @@ impl ClientStream {
                 stream: _,
                 id: _,
                 addr,
+                write_timeout: _,
             } => addr,
             ClientStream::Secure {
                 stream: _,
                 id: _,
                 addr,
+                write_timeout: _,
             } => addr,
         }
     }
@@ -189,19 +363,355 @@ impl ClientStream {
     ///
     /// 데이터 전송
     ///
+    /// 버퍼 전체를 전송하며, 전송 제한 시간을 넘으면 오류를 반환한다.
+    ///
     async fn write(&mut self, buffer: &[u8]) -> Result<usize, Box<dyn Error + Send + Sync>> {
         match self {
             ClientStream::Plain {
-                ref mut stream,
+                stream,
                 id: _,
                 addr: _,
-            } => Ok(stream.write(buffer).await?),
+                write_timeout,
+            } => write_all_timeout(stream, buffer, *write_timeout).await?,
             ClientStream::Secure {
-                ref mut stream,
+                stream,
                 id: _,
                 addr: _,
-            } => Ok(stream.write(buffer).await?),
+                write_timeout,
+            } => write_all_timeout(stream, buffer, *write_timeout).await?,
         }
+
+        Ok(buffer.len())
+    }
+
+    ///
+    /// 브로커 이벤트 데이터를 클라이언트 형식으로 전송
+    ///
+    /// JSON 형식은 메시지 구분을 위해 줄바꿈 문자를 덧붙인다.
+    ///
+    async fn write_payload<T: Serialize>(
+        &mut self,
+        payload_format: PayloadFormat,
+        payload: &T,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let buffer = payload_format.serialize(payload)?;
+        self.write_serialized(payload_format, &buffer).await
+    }
+
+    ///
+    /// 여러 클라이언트가 공유하는 데이터 전송 (형식별로 한 번만 직렬화한다)
+    ///
+    async fn write_shared_payload<T: Serialize>(
+        &mut self,
+        payload_format: PayloadFormat,
+        payload: &SharedPayload<T>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let buffer = payload.serialize(ClientProtocol::new(payload_format, None))?;
+        self.write_serialized(payload_format, &buffer).await
+    }
+
+    ///
+    /// 직렬화한 데이터 전송
+    ///
+    /// JSON 형식은 메시지 구분을 위해 줄바꿈 문자를 덧붙인다.
+    ///
+    async fn write_serialized(
+        &mut self,
+        payload_format: PayloadFormat,
+        buffer: &[u8],
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        // 줄바꿈을 덧붙일 때는 풀에서 빌린 전송 버퍼를 사용한다
+        let mut send_buffer = None;
+        let buffer = match payload_format {
+            PayloadFormat::JSON => {
+                let send_buffer = send_buffer.insert(send_buffer_pool().get());
+                send_buffer.extend_from_slice(buffer);
+                send_buffer.extend_from_slice(b"\n");
+                &send_buffer[..]
+            }
+            PayloadFormat::MSGPACK => buffer,
+        };
+
+        let span = tracing::info_span!(
+            "client.write",
+            acceptor = "tcp",
+            client_id = %self.get_id(),
+            size = buffer.len(),
+        );
+        self.write(buffer).instrument(span).await
+    }
+
+    ///
+    /// 브로커 이벤트 전송
+    ///
+    /// 다른 클라이언트 대상 이벤트는 건너뛰며, 연결을 종료해야 하면 false 를 반환한다.
+    ///
+    async fn write_broker_event(
+        &mut self,
+        payload_format: PayloadFormat,
+        broker_event: BrokerEvent,
+    ) -> bool {
+        let written = match broker_event {
+            BrokerEvent::BroadCastAgentState {
+                agent_info,
+                client_id,
+            } => {
+                // id 값이 있을땐 매칭되지 않을 경우 처리하지 않음
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_shared_payload(payload_format, &agent_info).await
+            }
+            BrokerEvent::BroadCastAgentRemoved {
+                client_id,
+                agent_removal,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &agent_removal).await
+            }
+            BrokerEvent::BroadCastAgentStateHistory {
+                client_id,
+                agent_state_history,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &agent_state_history)
+                    .await
+            }
+            BrokerEvent::BroadCastAgentDelta {
+                client_id,
+                agent_info_delta,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &agent_info_delta).await
+            }
+            BrokerEvent::BroadCastAgentSnapshot {
+                client_id,
+                agent_snapshot,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &agent_snapshot).await
+            }
+            BrokerEvent::BroadCastAgentStatistics {
+                client_id,
+                agent_statistics,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &agent_statistics).await
+            }
+            BrokerEvent::BroadCastCallState {
+                client_id,
+                call_info,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &call_info).await
+            }
+            BrokerEvent::BroadCastSkillGroupState {
+                client_id,
+                skill_group_info,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &skill_group_info).await
+            }
+            BrokerEvent::BroadCastTeamSnapshot {
+                client_id,
+                team_infos,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &team_infos).await
+            }
+            BrokerEvent::BroadCastWallboardSummary {
+                client_id,
+                wallboard_summary,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &wallboard_summary).await
+            }
+            BrokerEvent::BroadCastAgentQueryResult {
+                client_id,
+                agent_query_result,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &agent_query_result)
+                    .await
+            }
+            BrokerEvent::BroadCastClientList {
+                client_id,
+                client_infos,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &client_infos).await
+            }
+            BrokerEvent::BroadCastCtiSyncState {
+                client_id,
+                cti_sync_state,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &cti_sync_state).await
+            }
+            BrokerEvent::BroadCastCtiLinkStatus {
+                client_id,
+                cti_link_status,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &cti_link_status).await
+            }
+            BrokerEvent::BroadCastHaStatus {
+                client_id,
+                ha_status,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &ha_status).await
+            }
+            BrokerEvent::BroadCastWelcome { client_id, welcome } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &welcome).await
+            }
+            BrokerEvent::BroadCastFirehoseMessage {
+                client_id,
+                firehose_message,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_shared_payload(payload_format, &firehose_message)
+                    .await
+            }
+            BrokerEvent::BroadCastStateRefreshResult {
+                client_id,
+                state_refresh_result,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &state_refresh_result)
+                    .await
+            }
+            BrokerEvent::BroadCastStateSnapshot {
+                client_id,
+                snapshot,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(payload_format, &snapshot).await
+            }
+            BrokerEvent::BroadCastSupervisorAlert {
+                client_id,
+                supervisor_alert,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_shared_payload(payload_format, &supervisor_alert)
+                    .await
+            }
+            // 관리 명령에 의한 연결 종료
+            BrokerEvent::DisconnectClient { client_id } => {
+                if client_id != *self.get_id() {
+                    return true;
+                }
+
+                log::info!(
+                    "TCP client disconnected by admin. client_id: {}, client_addr: {}",
+                    self.get_id(),
+                    self.get_addr()
+                );
+                return false;
+            }
+            // 서버 종료 안내 후 연결 종료
+            BrokerEvent::Shutdown => {
+                let _ = self
+                    .write_payload(payload_format, &ShutdownNotice::new())
+                    .await;
+                log::info!(
+                    "TCP client disconnected by shutdown. client_id: {}, client_addr: {}",
+                    self.get_id(),
+                    self.get_addr()
+                );
+                return false;
+            }
+            _ => return true,
+        };
+
+        // 전송하지 못한 클라이언트는 이후 메시지가 어긋나지 않도록 연결을 끊는다
+        if let Err(e) = written {
+            log::warn!(
+                "TCP client write failed. client_id: {}, client_addr: {}, error: {}",
+                self.get_id(),
+                self.get_addr(),
+                e
+            );
+            return false;
+        }
+
+        true
+    }
+
+    ///
+    /// 브로커 이벤트를 순서대로 전송
+    ///
+    async fn write_broker_events(
+        &mut self,
+        payload_format: PayloadFormat,
+        broker_events: Vec<BrokerEvent>,
+    ) -> bool {
+        for broker_event in broker_events {
+            if !self.write_broker_event(payload_format, broker_event).await {
+                return false;
+            }
+        }
+
+        true
     }
 
     ///
@@ -213,11 +723,13 @@ impl ClientStream {
                 stream,
                 id: _,
                 addr: _,
+                write_timeout: _,
             } => Ok(stream.read(buffer).await?),
             ClientStream::Secure {
                 stream,
                 id: _,
                 addr: _,
+                write_timeout: _,
             } => Ok(stream.read(buffer).await?),
         }
     }
@@ -225,81 +737,253 @@ impl ClientStream {
     ///
     /// 클라이언트 핸들링
     ///
+    /// 연결 확인 주기를 지정하면 그동안 수신이 없는 클라이언트에 PING 메시지를 보내고,
+    /// PONG 대기 시간 내에도 수신이 없으면 끊어진 연결로 보고 종료한다.
+    ///
     pub async fn handle(
         &mut self,
         mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
         client_event_channel_tx: mpsc::Sender<ClientEvent>,
+        client_authenticator: Arc<ClientAuthenticator>,
+        handshake_timeout: Duration,
+        ping_interval: Option<Duration>,
+        pong_timeout: Duration,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
         let mut buffer = vec![0_u8; 4_096];
 
+        // 접속 후 첫 메시지로 데이터 형식을 협상한다 (0: 협상하지 않음)
+        // 인증을 사용하면 첫 메시지는 토큰을 담은 로그인 메시지여야 한다
+        // 재접속 클라이언트는 첫 메시지에 이어 받을 일련번호를 함께 보낼 수 있다
+        let handshake_timeout = match handshake_timeout.is_zero() {
+            true if client_authenticator.is_enabled() => Duration::from_millis(500),
+            _ => handshake_timeout,
+        };
+        let mut payload_format = PayloadFormat::default();
+        let mut first_message = None;
+        let mut token = None;
+        let mut resume = None;
+        if !handshake_timeout.is_zero() {
+            match timeout(handshake_timeout, self.read(&mut buffer)).await {
+                Ok(Ok(0)) => return Ok(()),
+                Ok(Ok(n)) => {
+                    if client_authenticator.is_enabled() {
+                        token = ClientAuthenticator::parse_login(&buffer[0..n]);
+                    }
+                    resume = SessionResume::parse(&buffer[0..n]);
+
+                    match PayloadFormat::parse_handshake(&buffer[0..n]) {
+                        Some(format) => payload_format = format,
+                        // 로그인, 이어 받기 메시지는 요청으로 처리하지 않는다
+                        None if client_authenticator.is_enabled() || resume.is_some() => {}
+                        // 협상 메시지가 아니면 일반 요청으로 처리한다
+                        None => first_message = Some(buffer[0..n].to_vec()),
+                    }
+                }
+                Ok(Err(e)) => return Err(e),
+                Err(_) => {}
+            }
+        }
+        log::debug!(
+            "TCP client payload format. client_id: {}, payload_format: {:?}",
+            self.get_id(),
+            payload_format
+        );
+
+        // 인증되지 않은 클라이언트는 이벤트를 받지 못하고 연결이 종료된다
+        let permission = match client_authenticator.authenticate(token.as_deref()) {
+            Some(permission) => permission,
+            None => {
+                log::warn!(
+                    "TCP client authentication failed. client_id: {}, client_addr: {:?}",
+                    self.get_id(),
+                    self.get_addr()
+                );
+                return Ok(());
+            }
+        };
+        log::debug!(
+            "TCP client authenticated. client_id: {}, permission: {:?}",
+            self.get_id(),
+            permission
+        );
+        let department_id = client_authenticator.get_department_id(token.as_deref());
+
+        // 클라이언트 인증서를 사용한 경우 CN 으로 클라이언트를 식별한다
+        let common_name = self.get_common_name();
+        if let Some(ref common_name) = common_name {
+            log::info!(
+                "TCP client certificate verified. client_id: {}, common_name: {}",
+                self.get_id(),
+                common_name
+            );
+        }
+
         // 클라이언트 소켓 접속 이벤트 전송
+        let (direct_event_tx, mut direct_event_rx) = mpsc::unbounded_channel::<Vec<BrokerEvent>>();
         client_event_channel_tx
             .send(ClientEvent::Connect {
-                id: self.get_id().clone(),
+                id: *self.get_id(),
+                permission,
+                department_id,
+                common_name,
+                addr: Some(*self.get_addr()),
+                direct_event_tx: Some(direct_event_tx),
+                resume,
             })
             .await
             .unwrap();
+        if let Some(data) = first_message {
+            client_event_channel_tx
+                .send(ClientEvent::Receive {
+                    id: *self.get_id(),
+                    data,
+                })
+                .await
+                .unwrap();
+        }
 
+        let mut last_received = Instant::now();
+        let mut ping_sent: Option<Instant> = None;
+
+        // 소켓 데이터, 연결 확인 시각, 브로커 이벤트 중 먼저 도착한 것을 처리한다
         loop {
-            // 소켓 데이터 수신
-            match timeout(Duration::from_millis(10), self.read(&mut buffer)).await {
-                Ok(Ok(n)) if n == 0 => {
-                    break;
-                }
-                Ok(Ok(n)) => {
-                    log::debug!(
-                        "Client send. client_id: {}, client_addr: {}, buffer: {:?}",
-                        self.get_id(),
-                        self.get_addr(),
-                        &buffer[0..n]
-                    );
-                }
-                Ok(Err(e)) => {
-                    log::error!(
-                        "TCP Client error. {:?}, client_addr: {}",
-                        e,
-                        self.get_addr()
-                    );
-                    break;
-                }
-                Err(_) => {}
-            }
+            let heartbeat_deadline = match ping_sent {
+                Some(ping_sent) => ping_sent + pong_timeout,
+                None => last_received + ping_interval.unwrap_or_default(),
+            };
 
-            // 브로킹 이벤트 수신
-            match timeout(Duration::from_millis(10), broker_event_channel_rx.recv()).await {
-                Ok(Ok(event)) => match event {
-                    BrokerEvent::BroadCastAgentState {
-                        agent_info,
-                        client_id,
-                    } => {
-                        match client_id {
-                            // id 값이 있을땐 매칭되지 않을 경우 처리하지 않음
-                            Some(id) => {
-                                if &id != self.get_id() {
-                                    continue;
+            tokio::select! {
+                // 소켓 데이터 수신
+                received = self.read(&mut buffer) => match received {
+                    Ok(0) => {
+                        break;
+                    }
+                    Ok(n) => {
+                        log::debug!(
+                            "Client send. client_id: {}, client_addr: {}, buffer: {:?}",
+                            self.get_id(),
+                            self.get_addr(),
+                            &buffer[0..n]
+                        );
+
+                        // 어떤 데이터든 수신하면 연결이 살아 있는 것으로 본다
+                        last_received = Instant::now();
+                        ping_sent = None;
+
+                        // 연결 확인 메시지는 요청으로 처리하지 않는다
+                        match ClientHeartbeat::parse(&buffer[0..n]) {
+                            Some(ClientHeartbeatType::PING) => {
+                                let pong = ClientHeartbeat::new(ClientHeartbeatType::PONG);
+                                if let Err(e) = self.write_payload(payload_format, &pong).await {
+                                    log::warn!(
+                                        "TCP client write failed. client_id: {}, client_addr: {}, error: {}",
+                                        self.get_id(),
+                                        self.get_addr(),
+                                        e
+                                    );
+                                    break;
                                 }
+                                continue;
                             }
+                            Some(ClientHeartbeatType::PONG) => continue,
                             None => {}
                         }
 
-                        let mut buffer = Vec::new();
-                        agent_info
-                            .serialize(&mut rmp_serde::Serializer::new(&mut buffer))
+                        client_event_channel_tx
+                            .send(ClientEvent::Receive {
+                                id: *self.get_id(),
+                                data: buffer[0..n].to_vec(),
+                            })
+                            .await
                             .unwrap();
-
-                        self.write(&buffer).await.unwrap();
                     }
-                    _ => {}
+                    Err(e) => {
+                        log::error!(
+                            "TCP Client error. {:?}, client_addr: {}",
+                            e,
+                            self.get_addr()
+                        );
+                        break;
+                    }
                 },
-                Ok(Err(e)) => {
-                    log::error!("Unable to read broker message. {:?}", e);
-                    break;
+                // 일정 시간 수신이 없으면 PING 을 보내고, PONG 대기 시간 내에 수신이 없으면 연결을 끊는다
+                _ = sleep_until(heartbeat_deadline), if ping_interval.is_some() => {
+                    if ping_sent.is_some() {
+                        log::warn!(
+                            "TCP client did not respond to ping. client_id: {}, client_addr: {}",
+                            self.get_id(),
+                            self.get_addr()
+                        );
+                        break;
+                    }
+
+                    let ping = ClientHeartbeat::new(ClientHeartbeatType::PING);
+                    if let Err(e) = self.write_payload(payload_format, &ping).await {
+                        log::warn!(
+                            "TCP client write failed. client_id: {}, client_addr: {}, error: {}",
+                            self.get_id(),
+                            self.get_addr(),
+                            e
+                        );
+                        break;
+                    }
+                    ping_sent = Some(Instant::now());
                 }
-                Err(_) => {}
+                // 클라이언트 전용 이벤트 수신 (접속 시 전체 상태)
+                Some(broker_events) = direct_event_rx.recv() => {
+                    if !self.write_broker_events(payload_format, broker_events).await {
+                        break;
+                    }
+                }
+                // 브로킹 이벤트 수신
+                event = broker_event_channel_rx.recv() => match event {
+                    Ok(broker_event) => {
+                        // 전용 큐에 먼저 들어온 전체 상태를 이후 변경분보다 먼저 전송한다
+                        let mut broker_events = vec![];
+                        while let Ok(direct_events) = direct_event_rx.try_recv() {
+                            broker_events.extend(direct_events);
+                        }
+                        broker_events.push(broker_event);
+
+                        if !self.write_broker_events(payload_format, broker_events).await {
+                            break;
+                        }
+                    }
+                    // 놓친 이벤트는 전체 상태를 다시 받아 보완한다
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        log::warn!(
+                            "TCP client lagged behind broker events. client_id: {}, skipped: {}",
+                            self.get_id(),
+                            skipped
+                        );
+                        // 종료 중에는 CTM 이 이미 멈춰 클라이언트 이벤트를 받지 못한다
+                        if let Err(e) = client_event_channel_tx
+                            .send(ClientEvent::Lagged {
+                                id: *self.get_id(),
+                                skipped,
+                            })
+                            .await
+                        {
+                            log::error!("Unable to send client event. {:?}", e);
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        log::error!("Unable to read broker message. channel closed");
+                        break;
+                    }
+                },
             }
         }
 
-        #[allow(unreachable_code)]
+        // 클라이언트 소켓 종료 이벤트 전송
+        if let Err(e) = client_event_channel_tx
+            .send(ClientEvent::Disconnect { id: *self.get_id() })
+            .await
+        {
+            log::error!("Unable to send client event. {:?}", e);
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,93 @@
+use std::{error::Error, net::SocketAddr};
+
+use async_trait::async_trait;
+use tokio::{
+    net::UdpSocket,
+    sync::{broadcast, mpsc},
+};
+
+use crate::{
+    ctm::{
+        agent_delta::AgentInfoMirror, agent_info::AgentInfo,
+        agent_state_datagram::AgentStateDatagram, config::UdpMulticastConfig,
+    },
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+
+use super::{connect_internal_client, recv_broker_event, Acceptor};
+
+///
+/// UDP 멀티캐스트 발행기
+///
+/// 상담직원 상태가 바뀔 때마다 고정 형식 데이터그램을 멀티캐스트 그룹으로 전송하며, 수신 확인은 하지 않는다.
+///
+pub struct UdpMulticastPublisher {
+    udp_socket: UdpSocket,
+    multicast_addr: SocketAddr,
+}
+
+impl UdpMulticastPublisher {
+    ///
+    /// UdpMulticastPublisher 생성
+    ///
+    pub async fn new(config: &UdpMulticastConfig) -> Result<Self, Box<dyn Error>> {
+        // 송신 전용 소켓 초기화
+        let udp_socket = UdpSocket::bind((config.interface, 0)).await?;
+        udp_socket.set_multicast_ttl_v4(config.ttl)?;
+
+        Ok(Self {
+            udp_socket,
+            multicast_addr: SocketAddr::from((config.group, config.port)),
+        })
+    }
+
+    ///
+    /// 상담직원 상태 데이터그램 전송
+    ///
+    async fn publish(&self, agent_info: &AgentInfo) {
+        let datagram = AgentStateDatagram::from(agent_info).encode();
+
+        if let Err(e) = self
+            .udp_socket
+            .send_to(&datagram, self.multicast_addr)
+            .await
+        {
+            log::warn!(
+                "Unable to publish agent state datagram. multicast_addr: {}, agent_id: {}, error: {}",
+                self.multicast_addr,
+                agent_info.get_agent_id(),
+                e
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl Acceptor for UdpMulticastPublisher {
+    fn get_name(&self) -> &str {
+        "udp_multicast"
+    }
+
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!(
+            "UDP multicast publisher starts publishing. multicast_addr: {}",
+            self.multicast_addr
+        );
+
+        // 변경분 반영을 위해 내부 클라이언트로 접속하여 전체 상태를 받는다
+        let id = connect_internal_client(&client_event_channel_tx).await?;
+
+        let mut agent_info_mirror = AgentInfoMirror::new();
+        while let Some(broker_event) = recv_broker_event(&mut broker_event_channel_rx, id).await {
+            for agent_info in agent_info_mirror.apply(&broker_event) {
+                self.publish(&agent_info).await;
+            }
+        }
+
+        Ok(())
+    }
+}
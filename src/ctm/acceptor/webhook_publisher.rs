@@ -0,0 +1,446 @@
+use std::{
+    error::Error,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use rustls::{pki_types::ServerName, ClientConfig, RootCertStore};
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::Sha256;
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+    sync::{broadcast, mpsc},
+    time::timeout,
+};
+use tokio_rustls::TlsConnector;
+
+use crate::{
+    ctm::{agent_delta::AgentInfoMirror, config::WebhookConfig},
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+
+use super::{connect_internal_client, recv_broker_event, Acceptor};
+
+// URL 별 전송 대기 배치 최대 개수
+const WEBHOOK_MAX_PENDING_BATCHES: usize = 64;
+
+// 재시도 대기 시간 상한
+const WEBHOOK_MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// 웹훅 대상 주소
+///
+pub struct WebhookEndpoint {
+    secure: bool,
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl WebhookEndpoint {
+    ///
+    /// `http://host[:port]/path` 또는 `https://host[:port]/path` 형식의 URL 을 해석한다
+    ///
+    pub fn parse(url: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let (secure, rest) = if let Some(rest) = url.strip_prefix("https://") {
+            (true, rest)
+        } else if let Some(rest) = url.strip_prefix("http://") {
+            (false, rest)
+        } else {
+            return Err(format!("Unsupported webhook url scheme. url: {}", url).into());
+        };
+
+        let (authority, path) = match rest.find('/') {
+            Some(position) => rest.split_at(position),
+            None => (rest, "/"),
+        };
+
+        // IPv6 주소는 대괄호로 감싼다
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) if !port.ends_with(']') => (host, Some(port.parse::<u16>()?)),
+            _ => (authority, None),
+        };
+        if host.is_empty() {
+            return Err(format!("Invalid webhook url. url: {}", url).into());
+        }
+
+        Ok(Self {
+            secure,
+            host: host.to_string(),
+            port: port.unwrap_or(if secure { 443 } else { 80 }),
+            path: path.to_string(),
+        })
+    }
+
+    pub fn is_secure(&self) -> bool {
+        self.secure
+    }
+
+    pub fn get_host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn get_port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl std::fmt::Display for WebhookEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}://{}:{}{}",
+            if self.secure { "https" } else { "http" },
+            self.host,
+            self.port,
+            self.path
+        )
+    }
+}
+
+///
+/// 웹훅 발행기
+///
+/// 상태 변경을 모아 `{"events": [...]}` 형식의 JSON 배치로 설정된 URL 마다 POST 한다.
+/// 전송에 실패하면 지수 백오프로 재시도하며, 비밀 키가 설정된 경우 본문에 HMAC-SHA256 서명을 붙인다.
+///
+pub struct WebhookPublisher {
+    endpoints: Vec<WebhookEndpoint>,
+    secret: String,
+    batch_size: usize,
+    batch_interval: Duration,
+    max_retries: u32,
+    retry_delay: Duration,
+    request_timeout: Duration,
+    tls_connector: TlsConnector,
+}
+
+impl WebhookPublisher {
+    ///
+    /// WebhookPublisher 생성
+    ///
+    pub async fn new(config: &WebhookConfig) -> Result<Self, Box<dyn Error>> {
+        if config.endpoints.is_empty() {
+            return Err("No webhook url configured".into());
+        }
+
+        // HTTPS 대상 검증에는 운영체제 인증서 저장소를 사용한다
+        let mut root_cert_store = RootCertStore::empty();
+        let native_certs = rustls_native_certs::load_native_certs();
+        for e in native_certs.errors {
+            log::warn!("Unable to load native certificate. {}", e);
+        }
+        root_cert_store.add_parsable_certificates(native_certs.certs);
+        let tls_config = ClientConfig::builder()
+            .with_root_certificates(root_cert_store)
+            .with_no_client_auth();
+
+        Ok(Self {
+            endpoints: config.endpoints.clone(),
+            secret: config.secret.clone(),
+            batch_size: config.batch_size,
+            batch_interval: config.batch_interval,
+            max_retries: config.max_retries,
+            retry_delay: config.retry_delay,
+            request_timeout: config.request_timeout,
+            tls_connector: TlsConnector::from(Arc::new(tls_config)),
+        })
+    }
+
+    ///
+    /// 브로커 이벤트를 웹훅 이벤트 목록으로 변환한다
+    ///
+    pub fn events(
+        agent_info_mirror: &mut AgentInfoMirror,
+        broker_event: &BrokerEvent,
+    ) -> Result<Vec<Value>, serde_json::Error> {
+        let mut events = vec![];
+        for agent_info in agent_info_mirror.apply(broker_event) {
+            events.push(Self::event("agent_state", &agent_info)?);
+        }
+
+        match broker_event {
+            BrokerEvent::BroadCastAgentRemoved { agent_removal, .. } => {
+                events.push(Self::event("agent_removed", agent_removal)?);
+            }
+            BrokerEvent::BroadCastCallState { call_info, .. } => {
+                events.push(Self::event("call_state", call_info)?);
+            }
+            BrokerEvent::BroadCastSkillGroupState {
+                skill_group_info, ..
+            } => {
+                events.push(Self::event("skill_group_state", skill_group_info)?);
+            }
+            BrokerEvent::BroadCastWallboardSummary {
+                wallboard_summary, ..
+            } => {
+                events.push(Self::event("wallboard_summary", wallboard_summary)?);
+            }
+            _ => {}
+        }
+
+        Ok(events)
+    }
+
+    fn event<T: Serialize>(event: &str, data: &T) -> Result<Value, serde_json::Error> {
+        Ok(json!({
+            "event": event,
+            "timestamp": SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+            "data": serde_json::to_value(data)?,
+        }))
+    }
+
+    ///
+    /// 본문 서명 (HMAC-SHA256, 16진수)
+    ///
+    /// 서명 대상은 `{timestamp}.{body}` 이며, 수신측은 `X-CTM-Timestamp` 헤더 값으로 같은 문자열을 만들어 검증한다.
+    ///
+    pub fn sign(secret: &str, timestamp: u64, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("HMAC accepts keys of any size");
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(body);
+
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    ///
+    /// 재시도 대기 시간
+    ///
+    /// 재시도마다 두 배씩 늘어나며 최대 60초를 넘지 않는다.
+    ///
+    pub fn retry_delay(base: Duration, attempt: u32) -> Duration {
+        base.saturating_mul(2_u32.saturating_pow(attempt))
+            .min(WEBHOOK_MAX_RETRY_DELAY)
+    }
+}
+
+#[async_trait]
+impl Acceptor for WebhookPublisher {
+    fn get_name(&self) -> &str {
+        "webhook"
+    }
+
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!(
+            "Webhook publisher starts publishing. endpoints: {:?}",
+            self.endpoints
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+
+        // 느린 대상이 다른 대상의 전송을 막지 않도록 URL 마다 전송 작업을 분리한다
+        let mut batch_txs = vec![];
+        let mut sender_handles = vec![];
+        for endpoint in self.endpoints.iter() {
+            let (batch_tx, batch_rx) = mpsc::channel::<Arc<Vec<u8>>>(WEBHOOK_MAX_PENDING_BATCHES);
+            batch_txs.push((endpoint.to_string(), batch_tx));
+
+            let sender = WebhookSender {
+                endpoint: endpoint.clone(),
+                secret: self.secret.clone(),
+                max_retries: self.max_retries,
+                retry_delay: self.retry_delay,
+                request_timeout: self.request_timeout,
+                tls_connector: self.tls_connector.clone(),
+            };
+            sender_handles.push(tokio::spawn(sender.run(batch_rx)));
+        }
+
+        // 변경분 반영을 위해 내부 클라이언트로 접속하여 전체 상태를 받는다
+        let id = connect_internal_client(&client_event_channel_tx).await?;
+
+        let mut agent_info_mirror = AgentInfoMirror::new();
+        let mut events = vec![];
+        let mut flush_interval = tokio::time::interval(self.batch_interval);
+        loop {
+            let (closed, flush) = tokio::select! {
+                broker_event = recv_broker_event(&mut broker_event_channel_rx, id) => match broker_event {
+                    Some(broker_event) => {
+                        match Self::events(&mut agent_info_mirror, &broker_event) {
+                            Ok(converted) => events.extend(converted),
+                            Err(e) => log::warn!("Unable to serialize webhook event. {}", e),
+                        }
+                        (false, false)
+                    }
+                    None => (true, true),
+                },
+                _ = flush_interval.tick() => (false, true),
+            };
+
+            // 배치 크기를 채웠거나 전송 주기가 되었거나 종료 시 남은 이벤트를 전송한다
+            while !events.is_empty() && (flush || events.len() >= self.batch_size) {
+                let batch = events
+                    .drain(0..events.len().min(self.batch_size))
+                    .collect::<Vec<_>>();
+                let body = match serde_json::to_vec(&json!({ "events": batch })) {
+                    Ok(body) => Arc::new(body),
+                    Err(e) => {
+                        log::warn!("Unable to serialize webhook batch. {}", e);
+                        continue;
+                    }
+                };
+
+                for (endpoint, batch_tx) in batch_txs.iter() {
+                    if batch_tx.try_send(body.clone()).is_err() {
+                        log::warn!("Dropped webhook batch. endpoint: {}", endpoint);
+                    }
+                }
+            }
+
+            if closed {
+                break;
+            }
+        }
+
+        // 종료 시 대기 중인 배치를 모두 전송할 때까지 기다린다
+        drop(batch_txs);
+        for sender_handle in sender_handles {
+            let _ = sender_handle.await;
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// URL 하나에 배치를 순서대로 전송하는 작업
+///
+struct WebhookSender {
+    endpoint: WebhookEndpoint,
+    secret: String,
+    max_retries: u32,
+    retry_delay: Duration,
+    request_timeout: Duration,
+    tls_connector: TlsConnector,
+}
+
+impl WebhookSender {
+    async fn run(self, mut batch_rx: mpsc::Receiver<Arc<Vec<u8>>>) {
+        while let Some(body) = batch_rx.recv().await {
+            let mut attempt = 0;
+            loop {
+                match timeout(self.request_timeout, self.post(&body)).await {
+                    Ok(Ok(())) => break,
+                    Ok(Err(e)) => log::warn!(
+                        "Unable to deliver webhook batch. endpoint: {}, attempt: {}, error: {}",
+                        self.endpoint,
+                        attempt + 1,
+                        e
+                    ),
+                    Err(_) => log::warn!(
+                        "Webhook request timed out. endpoint: {}, attempt: {}",
+                        self.endpoint,
+                        attempt + 1
+                    ),
+                }
+
+                if attempt >= self.max_retries {
+                    log::error!(
+                        "Gave up delivering webhook batch. endpoint: {}, size: {}",
+                        self.endpoint,
+                        body.len()
+                    );
+                    break;
+                }
+                tokio::time::sleep(WebhookPublisher::retry_delay(self.retry_delay, attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
+
+    ///
+    /// 배치 전송
+    ///
+    async fn post(&self, body: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let native_stream =
+            TcpStream::connect((self.endpoint.get_host(), self.endpoint.get_port())).await?;
+
+        if self.endpoint.is_secure() {
+            let server_name = ServerName::try_from(
+                self.endpoint
+                    .get_host()
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .to_string(),
+            )?;
+            let stream = self
+                .tls_connector
+                .connect(server_name, native_stream)
+                .await?;
+            self.request(stream, body).await
+        } else {
+            self.request(native_stream, body).await
+        }
+    }
+
+    async fn request<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        mut stream: S,
+        body: &[u8],
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut request_header = format!(
+            "POST {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: ctmonitor_rs\r\nContent-Type: application/json\r\nContent-Length: {}\r\nX-CTM-Timestamp: {}\r\nConnection: close\r\n",
+            self.endpoint.get_path(),
+            self.endpoint.get_host(),
+            body.len(),
+            timestamp
+        );
+        if !self.secret.is_empty() {
+            request_header.push_str(&format!(
+                "X-CTM-Signature: sha256={}\r\n",
+                WebhookPublisher::sign(&self.secret, timestamp, body)
+            ));
+        }
+        request_header.push_str("\r\n");
+
+        stream.write_all(request_header.as_bytes()).await?;
+        stream.write_all(body).await?;
+        stream.flush().await?;
+
+        // 상태 줄만 확인한다
+        let mut buffer = Vec::new();
+        let mut chunk = [0_u8; 1_024];
+        let status_line = loop {
+            if let Some(position) = buffer.windows(2).position(|window| window == b"\r\n") {
+                break String::from_utf8_lossy(&buffer[0..position]).to_string();
+            }
+            match stream.read(&mut chunk).await? {
+                0 => return Err("Connection closed before response".into()),
+                n => buffer.extend_from_slice(&chunk[0..n]),
+            }
+        };
+
+        match status_line.split_whitespace().nth(1) {
+            Some(status) if status.starts_with('2') => Ok(()),
+            _ => Err(format!("Unexpected webhook response. {}", status_line).into()),
+        }
+    }
+}
@@ -1,99 +1,193 @@
-use std::{error::Error, net::SocketAddr, sync::Arc, time::Duration};
+use std::{
+    error::Error,
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
 use base64::{prelude::BASE64_STANDARD, Engine};
-use rustls::{
-    pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
-    ServerConfig,
-};
+use rustls::ServerConfig;
 use serde::Serialize;
 use sha1::{Digest, Sha1};
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
     net::{TcpListener, TcpStream},
     sync::{broadcast, mpsc},
-    time::timeout,
+    time::{interval, sleep, timeout, MissedTickBehavior},
 };
 use tokio_rustls::{server::TlsStream, TlsAcceptor};
+use tracing::Instrument;
 use uuid::Uuid;
 
-use crate::event::{broker_event::BrokerEvent, client_event::ClientEvent};
+use crate::{
+    ctm::{
+        buffer_pool::send_buffer_pool,
+        client_auth::{ClientAuthenticator, ClientPermission},
+        config::{Config, WebsocketAcceptorConfig},
+        logging::CONNECTION_SPAN_TARGET,
+        net_addr::bind_tcp_listener,
+        session_resume::SessionResume,
+        shutdown::ShutdownNotice,
+    },
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
 
-use super::Acceptor;
+use super::{
+    client_cert::{client_cert_verifier, common_name},
+    connection_limiter::ConnectionLimiter,
+    http_request::{empty_response, HttpRequest, HttpRequestError},
+    ip_acl::IpAcl,
+    payload_format::{ClientProtocol, PayloadFormat, SharedPayload},
+    server_cert::ServerCertResolver,
+    wait_acceptor_signal,
+    websocket_frame::{
+        encode_frame_header, WebsocketFrame, WebsocketFrameReader, WebsocketMessage,
+        WEBSOCKET_CLOSE_GOING_AWAY, WEBSOCKET_CLOSE_NORMAL, WEBSOCKET_CLOSE_POLICY_VIOLATION,
+        WEBSOCKET_OP_CODE_BINARY_FRAME, WEBSOCKET_OP_CODE_PING_FRAME, WEBSOCKET_OP_CODE_PONG_FRAME,
+        WEBSOCKET_OP_CODE_TEXT_FRAME,
+    },
+    write_all_timeout, Acceptor, AcceptorSignal,
+};
 
 const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11"; // RFC 6455
-const WEBSOCKET_FIN_TRUE: u8 = 0x80;
-#[allow(unused)]
-const WEBSOCKET_FIN_FALSE: u8 = 0x00;
-#[allow(unused)]
-const WEBSOCKET_OP_CODE_CONTINUATION_FRAME: u8 = 0x00;
-const WEBSOCKET_OP_CODE_TEXT_FRAME: u8 = 0x01;
-const WEBSOCKET_OP_CODE_BINARY_FRAME: u8 = 0x02;
-#[allow(unused)]
-const WEBSOCKET_OP_CODE_CLOSE_FRAME: u8 = 0x08;
-#[allow(unused)]
-const WEBSOCKET_OP_CODE_PING_FRAME: u8 = 0x09;
-#[allow(unused)]
-const WEBSOCKET_OP_CODE_PONG_FRAME: u8 = 0x0A;
+const WEBSOCKET_VERSION: &str = "13";
+// 접속 후 HTTP 요청 헤더를 모두 받을 때까지 기다리는 시간
+const WEBSOCKET_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct WebsocketAcceptor {
     websocket_listener: TcpListener,
     tls_acceptor: Option<TlsAcceptor>,
-    broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
-    client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    server_cert_resolver: Option<Arc<ServerCertResolver>>,
+    client_authenticator: Arc<ClientAuthenticator>,
+    connection_limiter: ConnectionLimiter,
+    // 접속 허용 IP 목록 (설정 재적용 시 다시 읽는다)
+    ip_acl: RwLock<IpAcl>,
+    // 업그레이드를 허용하는 Origin 목록 (비어 있으면 모두 허용)
+    allowed_origins: Arc<Vec<String>>,
+    config: Arc<WebsocketAcceptorConfig>,
 }
 
 impl WebsocketAcceptor {
     pub async fn new(
-        broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
-        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+        config: &WebsocketAcceptorConfig,
+        client_authenticator: Arc<ClientAuthenticator>,
     ) -> Result<Self, Box<dyn Error>> {
-        let ssl_enabled = dotenv::var("WEBSOCKET_ACCEPTOR_SECURE")
-            .unwrap_or("false".to_string())
-            .parse::<bool>()
-            .unwrap_or(false);
-
         // 웹 소켓 서버 초기화
-        let websocket_listener = TcpListener::bind(format!(
-            "0.0.0.0:{}",
-            dotenv::var("WEBSOCKET_ACCEPTOR_PORT").unwrap_or("8085".to_string())
-        ))
-        .await?;
+        let websocket_listener =
+            bind_tcp_listener(&config.bind_address, config.port, config.dual_stack).await?;
 
         // TLS acceptor 생성
+        // 인증서는 ServerCertResolver 가 제공하므로 파일이 교체되면 재시작 없이 반영된다
         let mut tls_acceptor = None;
-        if ssl_enabled {
-            let cert = dotenv::var("WEBSOCKET_ACCEPTOR_SECURE_CERT_FILE")
-                .unwrap_or("./res/ssl/server.crt".to_string());
-            let key = dotenv::var("WEBSOCKET_ACCEPTOR_SECURE_KEY_FILE")
-                .unwrap_or("./res/ssl/server.key".to_string());
-            let cert = CertificateDer::pem_file_iter(cert)?.collect::<Result<Vec<_>, _>>()?;
-            let key = PrivateKeyDer::from_pem_file(key)?;
-
-            let tls_config = ServerConfig::builder()
-                .with_no_client_auth()
-                .with_single_cert(cert, key)?;
+        let mut server_cert_resolver = None;
+        if config.secure {
+            // SNI 호스트 이름별 인증서
+            let sni_certs = ServerCertResolver::parse_sni_certs(&config.sni_certs)?;
+
+            let builder = ServerConfig::builder();
+            let resolver = Arc::new(ServerCertResolver::new(
+                builder.crypto_provider().clone(),
+                &config.cert_file,
+                &config.key_file,
+                &sni_certs,
+            )?);
+
+            // 클라이언트 인증서 검증 (mutual TLS) 사용 여부
+            let tls_config = match config.client_auth {
+                true => {
+                    builder.with_client_cert_verifier(client_cert_verifier(&config.client_ca_file)?)
+                }
+                false => builder.with_no_client_auth(),
+            }
+            .with_cert_resolver(resolver.clone());
 
             tls_acceptor = Some(TlsAcceptor::from(Arc::new(tls_config)));
+            server_cert_resolver = Some(resolver);
         }
 
         Ok(Self {
             websocket_listener,
             tls_acceptor,
-            broker_event_channel_rx,
-            client_event_channel_tx,
+            server_cert_resolver,
+            client_authenticator,
+            connection_limiter: ConnectionLimiter::from_config(&config.connection_limit),
+            ip_acl: RwLock::new(config.allowed_ips.clone()),
+            allowed_origins: Arc::new(parse_allowed_origins(&config.allowed_origins)),
+            config: Arc::new(config.clone()),
         })
     }
+
+    ///
+    /// 다시 읽은 설정의 접속 허용 IP 목록을 적용하고 TLS 인증서를 다시 읽는다
+    ///
+    /// 이미 접속한 클라이언트의 연결은 유지된다.
+    ///
+    fn reload(&self, config: &Config) {
+        if let Some(websocket_acceptor) = config
+            .websocket_acceptors
+            .iter()
+            .find(|websocket_acceptor| websocket_acceptor.listener == self.config.listener)
+        {
+            log::info!(
+                "Websocket server reloaded IP ACL. networks: {}",
+                websocket_acceptor.allowed_ips.get_network_count()
+            );
+            *self.ip_acl.write().unwrap() = websocket_acceptor.allowed_ips.clone();
+        }
+
+        if let Some(server_cert_resolver) = self.server_cert_resolver.as_ref() {
+            server_cert_resolver.reload();
+        }
+    }
 }
 
 #[async_trait]
 impl Acceptor for WebsocketAcceptor {
-    async fn accept(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
-        log::info!("Websocket server starts accepting");
+    fn get_name(&self) -> &str {
+        "websocket"
+    }
+
+    async fn accept(
+        &self,
+        broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        log::info!(
+            "Websocket server starts accepting. local_addr: {:?}",
+            self.websocket_listener.local_addr()
+        );
+        if let Some(server_cert_resolver) = self.server_cert_resolver.as_ref() {
+            server_cert_resolver.watch(self.config.cert_reload_interval);
+        }
 
+        let mut shutdown_rx = broker_event_channel_rx.resubscribe();
         loop {
-            match self.websocket_listener.accept().await {
-                Ok((native_stream, client_addr)) => {
+            let accepted = tokio::select! {
+                accepted = self.websocket_listener.accept() => accepted,
+                acceptor_signal = wait_acceptor_signal(&mut shutdown_rx) => match acceptor_signal {
+                    AcceptorSignal::Reload(config) => {
+                        self.reload(&config);
+                        continue;
+                    }
+                    AcceptorSignal::Shutdown => break,
+                },
+            };
+            match accepted {
+                Ok((mut native_stream, client_addr)) => {
+                    // 허용되지 않은 주소는 TLS 협상 없이 연결을 종료한다
+                    if !self.ip_acl.read().unwrap().allows(client_addr.ip()) {
+                        log::warn!(
+                            "Websocket client rejected by IP ACL. client_addr: {:?}",
+                            client_addr
+                        );
+                        let _ = native_stream.shutdown().await;
+                        continue;
+                    }
+
+                    // 접속 제한을 넘은 요청은 HTTP 요청을 받은 뒤 503 으로 응답한다
+                    let connection_permit = self.connection_limiter.try_acquire();
+
                     let uuid = Uuid::now_v7();
                     log::info!(
                         "Websocket client connected. client_addr: {:?}, id: {}",
@@ -105,82 +199,139 @@ impl Acceptor for WebsocketAcceptor {
                     let mut client_stream = match self.tls_acceptor {
                         Some(ref tls) => ClientStream::Secure {
                             stream: match tls.accept(native_stream).await {
-                                Ok(stream) => stream,
-                                Err(_) => continue,
+                                Ok(stream) => Box::new(stream),
+                                Err(e) => {
+                                    log::warn!(
+                                        "Websocket client TLS handshake failed. client_addr: {:?}, error: {}",
+                                        client_addr,
+                                        e
+                                    );
+                                    continue;
+                                }
                             },
                             id: uuid,
-                            addr: client_addr.clone(),
+                            addr: client_addr,
+                            write_timeout: self.config.write_timeout,
                         },
                         None => ClientStream::Plain {
                             stream: native_stream,
                             id: uuid,
-                            addr: client_addr.clone(),
+                            addr: client_addr,
+                            write_timeout: self.config.write_timeout,
                         },
                     };
 
                     // 접속된 클라이언트 핸들링
-                    let broker_event_channel_rx = self.broker_event_channel_rx.resubscribe();
-                    let client_event_channel_tx = self.client_event_channel_tx.clone();
+                    let broker_event_channel_rx = broker_event_channel_rx.resubscribe();
+                    let client_event_channel_tx = client_event_channel_tx.clone();
+                    let client_authenticator = self.client_authenticator.clone();
+                    let allowed_origins = self.allowed_origins.clone();
+                    let config = self.config.clone();
+                    let connection_span = tracing::info_span!(
+                        target: CONNECTION_SPAN_TARGET,
+                        "client",
+                        acceptor = "websocket",
+                        client_id = %uuid,
+                        client_addr = %client_addr,
+                    );
                     tokio::spawn(async move {
-                        // HTTP 요청 수신
-                        let mut buffer = vec![0_u8; 2_048];
-                        let length = client_stream.read(&mut buffer).await.unwrap();
-
-                        let path = dotenv::var("WEBSOCKET_ACCEPTOR_PATH")
-                            .unwrap_or("/ctmonitor".to_string());
-
-                        // 요청 헤더 데이터 검증
-                        let request_header =
-                            String::from_utf8((&buffer[0..length]).to_vec()).unwrap();
-                        log::debug!("Websocket client request header: {}", request_header);
-
-                        // 헤더 경로가 잘못된 경우 허용하지 않는다
-                        let header_regex =
-                            regex::Regex::new(format!(r"^GET {} ", path).as_str()).unwrap();
-                        match header_regex.captures(&request_header) {
-                            Some(_) => {}
-                            None => {
-                                log::debug!("Websocket client requested invalid path");
-                                client_stream
-                                    .write(r"HTTP/1.1 400 Bad Request".as_bytes())
-                                    .await
-                                    .unwrap();
+                        // HTTP 요청 헤더 수신
+                        let request = match timeout(
+                            WEBSOCKET_HANDSHAKE_TIMEOUT,
+                            client_stream.read_request(),
+                        )
+                        .await
+                        {
+                            Ok(Ok(Some(request))) => request,
+                            Ok(Ok(None)) => return,
+                            Ok(Err(e)) => {
+                                log::debug!(
+                                    "Websocket client sent invalid request. client_addr: {:?}, error: {}",
+                                    client_addr,
+                                    e
+                                );
+                                let status = match e.downcast_ref::<HttpRequestError>() {
+                                    Some(e) => e.get_status(),
+                                    None => return,
+                                };
+                                let _ = client_stream
+                                    .write(empty_response(status, "").as_bytes())
+                                    .await;
                                 return;
                             }
-                        }
+                            Err(_) => {
+                                log::debug!(
+                                    "Websocket client handshake timed out. client_addr: {:?}",
+                                    client_addr
+                                );
+                                let _ = client_stream
+                                    .write(empty_response("408 Request Timeout", "").as_bytes())
+                                    .await;
+                                return;
+                            }
+                        };
+                        log::debug!("Websocket client request: {:?}", request);
 
-                        let header_regex = regex::Regex::new(r"^Upgrade|Sec-WebSocket").unwrap();
-                        // 업그레이드, 웹소켓 메시지가 없는 경우 허용하지 않는다
-                        match header_regex.captures(&request_header) {
-                            Some(_) => {}
-                            None => {
+                        let _connection_permit = match connection_permit {
+                            Ok(connection_permit) => connection_permit,
+                            Err(rejection) => {
+                                log::warn!(
+                                    "Websocket client rejected. client_addr: {:?}, reason: {}",
+                                    client_addr,
+                                    rejection
+                                );
+                                let _ = client_stream
+                                    .write(rejection.to_http_response().as_bytes())
+                                    .await;
+                                return;
+                            }
+                        };
+
+                        // 업그레이드 요청 검증 (RFC 6455 4.2.1)
+                        let websocket_key = match validate_upgrade_request(
+                            &request,
+                            &config.path,
+                            &allowed_origins,
+                        ) {
+                            Ok(websocket_key) => websocket_key,
+                            Err((status, headers)) => {
                                 log::debug!(
-                                    "Websocket client requested without websocket default headers"
+                                    "Websocket client upgrade rejected. client_addr: {:?}, status: {}",
+                                    client_addr,
+                                    status
                                 );
-                                client_stream
-                                    .write(r"HTTP/1.1 400 Bad Request".as_bytes())
-                                    .await
-                                    .unwrap();
+                                let _ = client_stream
+                                    .write(empty_response(status, headers).as_bytes())
+                                    .await;
                                 return;
                             }
                         };
 
-                        // 웹 소켓 키를 사용해 Accept 키를 만든다
-                        let header_regex =
-                            regex::Regex::new(r"Sec-WebSocket-Key:\s?([0-9a-zA-Z+=/]*)").unwrap();
-                        let websocket_key = match header_regex.captures(&request_header) {
-                            Some(captures) => captures.get(1).unwrap().as_str(),
+                        // 인증 토큰은 Authorization 헤더, token 쿼리 파라미터 순으로 찾는다
+                        let token = request
+                            .get_header("Authorization")
+                            .and_then(|authorization| {
+                                authorization
+                                    .split_once(' ')
+                                    .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("Bearer"))
+                                    .map(|(_, token)| token.trim().to_string())
+                            })
+                            .or_else(|| request.get_query_param("token"));
+                        let department_id = client_authenticator.get_department_id(token.as_deref());
+                        let permission = match client_authenticator.authenticate(token.as_deref()) {
+                            Some(permission) => permission,
                             None => {
-                                log::debug!(
-                                    "Websocket client request without Sec-WebSocket-Key header"
+                                log::warn!(
+                                    "Websocket client authentication failed. client_addr: {:?}",
+                                    client_addr
                                 );
-                                client_stream
-                                    .write(r"HTTP/1.1 400 Bad Request".as_bytes())
-                                    .await
-                                    .unwrap();
+                                let _ = client_stream
+                                    .write(empty_response("401 Unauthorized", "").as_bytes())
+                                    .await;
                                 return;
                             }
                         };
+
                         log::debug!("Websocket client request to accept. client_addr: {:?}, websocket_key: '{}'", client_addr, websocket_key);
 
                         // 웹소켓 Upgrade 응답 메시지 전송
@@ -191,39 +342,170 @@ impl Acceptor for WebsocketAcceptor {
 
                         log::debug!("Websocket client accept key: {}", websocket_accept);
 
+                        // 데이터 형식은 서브 프로토콜(ctm.v1.msgpack, ctm.v1.json, msgpack, json), format 쿼리 파라미터 순으로 결정한다
+                        let websocket_protocol = request
+                            .get_header("Sec-WebSocket-Protocol")
+                            .and_then(ClientProtocol::negotiate);
+                        let client_protocol = match websocket_protocol {
+                            Some((_, client_protocol)) => client_protocol,
+                            None => ClientProtocol::new(
+                                request
+                                    .get_query_param("format")
+                                    .and_then(|format| PayloadFormat::parse(&format))
+                                    .unwrap_or_default(),
+                                None,
+                            ),
+                        };
+                        log::debug!(
+                            "Websocket client protocol. client_addr: {:?}, client_protocol: {:?}",
+                            client_addr,
+                            client_protocol
+                        );
+
+                        // 재접속 클라이언트는 이어 받을 일련번호를 쿼리 파라미터로 보낼 수 있다
+                        let resume = SessionResume::from_query(
+                            request.get_query_param("resume_epoch"),
+                            request.get_query_param("resume_sequence"),
+                        );
+
+                        // 선택한 서브 프로토콜은 응답 헤더로 알려야 한다
+                        let protocol_header = match websocket_protocol {
+                            Some((protocol, _)) => {
+                                format!("Sec-WebSocket-Protocol: {}\r\n", protocol)
+                            }
+                            None => "".to_string(),
+                        };
+
                         // 웹소켓 101 Switching Protocols 전송
-                        client_stream
+                        if client_stream
                             .write(
                                 format!(
-                                    "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-Websocket-Accept: {}\r\n\r\n",
-                                    websocket_accept
+                                    "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-Websocket-Accept: {}\r\n{}\r\n",
+                                    websocket_accept,
+                                    protocol_header
                                 )
                                 .as_bytes(),
                             )
                             .await
-                            .unwrap();
+                            .is_err()
+                        {
+                            return;
+                        }
 
                         client_stream
-                            .handle(broker_event_channel_rx, client_event_channel_tx)
+                            .handle(
+                                broker_event_channel_rx,
+                                client_event_channel_tx,
+                                client_protocol,
+                                permission,
+                                department_id,
+                                resume,
+                                &config,
+                            )
                             .await
                             .unwrap();
                         log::info!(
                             "Websocket client disconnected. client_addr: {:?}",
                             client_addr
                         );
-                    });
+                    }
+                    .instrument(connection_span));
                 }
                 Err(e) => {
                     log::error!("Unable to accept Websocket client connection. {:?}", e);
-                    break;
+                    return Ok(());
                 }
             }
         }
 
+        log::info!("Websocket server stops accepting");
+        // 접속 중인 클라이언트가 종료 안내를 받고 연결을 끊을 때까지 기다린다
+        while self.connection_limiter.get_active_clients() > 0 {
+            sleep(Duration::from_millis(10)).await;
+        }
+
         Ok(())
     }
 }
 
+///
+/// 허용 Origin 목록 파싱 (쉼표 구분)
+///
+/// 예) `https://dashboard.example.com,http://localhost:3000`
+///
+pub fn parse_allowed_origins(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|origin| origin.trim().trim_end_matches('/').to_lowercase())
+        .filter(|origin| !origin.is_empty())
+        .collect()
+}
+
+///
+/// Origin 허용 여부
+///
+/// 목록이 비어 있거나 `*` 가 있으면 모두 허용한다.
+/// 브라우저가 아닌 클라이언트는 Origin 헤더를 보내지 않으므로 Origin 이 없는 요청은 허용한다.
+///
+pub fn is_allowed_origin(allowed_origins: &[String], origin: Option<&str>) -> bool {
+    let Some(origin) = origin else {
+        return true;
+    };
+    let origin = origin.trim().trim_end_matches('/');
+
+    allowed_origins.is_empty()
+        || allowed_origins.iter().any(|allowed_origin| {
+            allowed_origin == "*" || allowed_origin.eq_ignore_ascii_case(origin)
+        })
+}
+
+///
+/// 웹 소켓 업그레이드 요청 검증
+///
+/// 검증에 성공하면 Sec-WebSocket-Key 를, 실패하면 응답 상태와 추가 헤더를 반환한다.
+///
+fn validate_upgrade_request<'a>(
+    request: &'a HttpRequest,
+    path: &str,
+    allowed_origins: &[String],
+) -> Result<&'a str, (&'static str, &'static str)> {
+    if request.get_method() != "GET" {
+        return Err(("405 Method Not Allowed", "Allow: GET\r\n"));
+    }
+    if request.get_version() != "HTTP/1.1" {
+        return Err(("505 HTTP Version Not Supported", ""));
+    }
+    if request.get_path() != path {
+        return Err(("404 Not Found", ""));
+    }
+    if !is_allowed_origin(allowed_origins, request.get_header("Origin")) {
+        return Err(("403 Forbidden", ""));
+    }
+    if !request.has_header_token("Upgrade", "websocket")
+        || !request.has_header_token("Connection", "Upgrade")
+    {
+        return Err((
+            "426 Upgrade Required",
+            "Upgrade: websocket\r\nConnection: Upgrade\r\n",
+        ));
+    }
+    if request.get_header("Sec-WebSocket-Version") != Some(WEBSOCKET_VERSION) {
+        return Err(("426 Upgrade Required", "Sec-WebSocket-Version: 13\r\n"));
+    }
+
+    // 키는 16 바이트 임의 값을 base64 로 인코딩한 값이어야 한다
+    match request.get_header("Sec-WebSocket-Key") {
+        Some(websocket_key)
+            if BASE64_STANDARD
+                .decode(websocket_key)
+                .is_ok_and(|key| key.len() == 16) =>
+        {
+            Ok(websocket_key)
+        }
+        _ => Err(("400 Bad Request", "")),
+    }
+}
+
 ///
 /// 클라이언트 웹 소켓 스트림
 ///
@@ -232,11 +514,13 @@ enum ClientStream {
         stream: TcpStream,
         id: Uuid,
         addr: SocketAddr,
+        write_timeout: Duration,
     },
     Secure {
-        stream: TlsStream<TcpStream>,
+        stream: Box<TlsStream<TcpStream>>,
         id: Uuid,
         addr: SocketAddr,
+        write_timeout: Duration,
     },
 }
 
@@ -250,15 +534,34 @@ impl ClientStream {
                 stream: _,
                 id,
                 addr: _,
+                write_timeout: _,
             } => id,
             ClientStream::Secure {
                 stream: _,
                 id,
                 addr: _,
+                write_timeout: _,
             } => id,
         }
     }
 
+    ///
+    /// 클라이언트 인증서의 CN 반환
+    ///
+    /// TLS 를 사용하지 않거나 클라이언트 인증서가 없으면 None 을 반환한다.
+    ///
+    fn get_common_name(&self) -> Option<String> {
+        match self {
+            ClientStream::Plain { .. } => None,
+            ClientStream::Secure { stream, .. } => stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certs| certs.first())
+                .and_then(|cert| common_name(cert)),
+        }
+    }
+
     ///
     /// 주소 반환
     ///
@@ -268,11 +571,13 @@ impl ClientStream {
                 stream: _,
                 id: _,
                 addr,
+                write_timeout: _,
             } => addr,
             ClientStream::Secure {
                 stream: _,
                 id: _,
                 addr,
+                write_timeout: _,
             } => addr,
         }
     }
@@ -280,99 +585,393 @@ impl ClientStream {
     ///
     /// 패킷 데이터 전송
     ///
+    /// 버퍼 전체를 전송하며, 전송 제한 시간을 넘으면 오류를 반환한다.
+    ///
     async fn write(&mut self, buffer: &[u8]) -> Result<usize, Box<dyn Error + Send + Sync>> {
         match self {
             ClientStream::Plain {
                 stream,
                 id: _,
                 addr: _,
-            } => Ok(stream.write(&buffer).await?),
+                write_timeout,
+            } => write_all_timeout(stream, buffer, *write_timeout).await?,
             ClientStream::Secure {
                 stream,
                 id: _,
                 addr: _,
-            } => Ok(stream.write(&buffer).await?),
+                write_timeout,
+            } => write_all_timeout(stream, buffer, *write_timeout).await?,
         }
+
+        Ok(buffer.len())
+    }
+
+    ///
+    /// 웹 소켓 프레임 전송
+    ///
+    async fn write_frame(
+        &mut self,
+        frame: &WebsocketFrame,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let mut send_buffer = send_buffer_pool().get();
+        frame.encode_into(&mut *send_buffer);
+
+        self.write(&send_buffer).await
+    }
+
+    ///
+    /// 데이터 프레임 전송
+    ///
+    /// 페이로드를 프레임 헤더와 함께 풀에서 빌린 전송 버퍼에 바로 써서 보낸다.
+    ///
+    async fn write_data_frame(
+        &mut self,
+        op_code: u8,
+        payload: &[u8],
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let mut send_buffer = send_buffer_pool().get();
+        encode_frame_header(true, op_code, payload.len(), &mut *send_buffer);
+        send_buffer.extend_from_slice(payload);
+
+        self.write(&send_buffer).await
+    }
+
+    ///
+    /// 브로커 이벤트 데이터를 클라이언트 프로토콜로 전송
+    ///
+    /// JSON 형식은 텍스트 프레임, MessagePack 형식은 이진 프레임으로 전송한다.
+    ///
+    async fn write_payload<T: Serialize>(
+        &mut self,
+        client_protocol: ClientProtocol,
+        payload: &T,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let buffer = client_protocol.serialize(payload)?;
+        self.write_serialized(client_protocol, &buffer).await
+    }
+
+    ///
+    /// 여러 클라이언트가 공유하는 데이터 전송 (프로토콜별로 한 번만 직렬화한다)
+    ///
+    async fn write_shared_payload<T: Serialize>(
+        &mut self,
+        client_protocol: ClientProtocol,
+        payload: &SharedPayload<T>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let buffer = payload.serialize(client_protocol)?;
+        self.write_serialized(client_protocol, &buffer).await
     }
 
     ///
-    /// 이진 데이터 전송
+    /// 직렬화한 데이터 전송
     ///
-    async fn write_binary(&mut self, buffer: &[u8]) -> Result<usize, Box<dyn Error + Send + Sync>> {
-        let length = buffer.len();
-        let mut send_buffer = Vec::new();
+    async fn write_serialized(
+        &mut self,
+        client_protocol: ClientProtocol,
+        buffer: &[u8],
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let span = tracing::info_span!(
+            "client.write",
+            acceptor = "websocket",
+            client_id = %self.get_id(),
+            size = buffer.len(),
+        );
+        // JSON 직렬화 결과는 UTF-8 이므로 그대로 텍스트 프레임으로 보낸다
+        let op_code = match client_protocol.get_format() {
+            PayloadFormat::MSGPACK => WEBSOCKET_OP_CODE_BINARY_FRAME,
+            PayloadFormat::JSON => WEBSOCKET_OP_CODE_TEXT_FRAME,
+        };
+        self.write_data_frame(op_code, buffer)
+            .instrument(span)
+            .await
+    }
 
-        // 웹 소켓 프레임 헤더 추가
-        send_buffer.push(WEBSOCKET_FIN_TRUE | WEBSOCKET_OP_CODE_BINARY_FRAME);
+    ///
+    /// 브로커 이벤트 전송
+    ///
+    /// 다른 클라이언트 대상 이벤트는 건너뛰며, 연결을 종료해야 하면 false 를 반환한다.
+    ///
+    async fn write_broker_event(
+        &mut self,
+        client_protocol: ClientProtocol,
+        broker_event: BrokerEvent,
+    ) -> bool {
+        let written = match broker_event {
+            BrokerEvent::BroadCastAgentState {
+                client_id,
+                agent_info,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
 
-        // 웹 소켓 길이 패킷 추가
-        match length {
-            0_usize..126_usize => {
-                send_buffer.push(length as u8);
+                self.write_shared_payload(client_protocol, &agent_info)
+                    .await
             }
-            126_usize..65535_usize => {
-                send_buffer.push(126_u8);
-                send_buffer.push(((length & 0xFF00) >> 8) as u8);
-                send_buffer.push((length & 0x00FF) as u8);
+            BrokerEvent::BroadCastAgentRemoved {
+                client_id,
+                agent_removal,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &agent_removal).await
             }
-            65535_usize.. => {
-                send_buffer.push(127_u8);
-                send_buffer.push(((length & 0xFF00_0000_0000_0000) >> 56) as u8);
-                send_buffer.push(((length & 0x00FF_0000_0000_0000) >> 48) as u8);
-                send_buffer.push(((length & 0x0000_FF00_0000_0000) >> 40) as u8);
-                send_buffer.push(((length & 0x0000_00FF_0000_0000) >> 32) as u8);
-                send_buffer.push(((length & 0x0000_0000_FF00_0000) >> 24) as u8);
-                send_buffer.push(((length & 0x0000_0000_00FF_0000) >> 16) as u8);
-                send_buffer.push(((length & 0x0000_0000_0000_FF00) >> 8) as u8);
-                send_buffer.push((length & 0x0000_0000_0000_00FF) as u8);
+            BrokerEvent::BroadCastAgentStateHistory {
+                client_id,
+                agent_state_history,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &agent_state_history)
+                    .await
             }
-        };
+            BrokerEvent::BroadCastAgentDelta {
+                client_id,
+                agent_info_delta,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
 
-        // 웹 소켓 데이터 추가
-        send_buffer.append(&mut buffer.to_vec());
+                self.write_payload(client_protocol, &agent_info_delta).await
+            }
+            BrokerEvent::BroadCastAgentSnapshot {
+                client_id,
+                agent_snapshot,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
 
-        match self {
-            ClientStream::Plain {
-                stream,
-                id: _,
-                addr: _,
-            } => Ok(stream.write(&send_buffer).await?),
-            ClientStream::Secure {
-                stream,
-                id: _,
-                addr: _,
-            } => Ok(stream.write(&send_buffer).await?),
+                self.write_payload(client_protocol, &agent_snapshot).await
+            }
+            BrokerEvent::BroadCastAgentStatistics {
+                client_id,
+                agent_statistics,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &agent_statistics).await
+            }
+            BrokerEvent::BroadCastCallState {
+                client_id,
+                call_info,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &call_info).await
+            }
+            BrokerEvent::BroadCastSkillGroupState {
+                client_id,
+                skill_group_info,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &skill_group_info).await
+            }
+            BrokerEvent::BroadCastTeamSnapshot {
+                client_id,
+                team_infos,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &team_infos).await
+            }
+            BrokerEvent::BroadCastWallboardSummary {
+                client_id,
+                wallboard_summary,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &wallboard_summary)
+                    .await
+            }
+            BrokerEvent::BroadCastAgentQueryResult {
+                client_id,
+                agent_query_result,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &agent_query_result)
+                    .await
+            }
+            BrokerEvent::BroadCastClientList {
+                client_id,
+                client_infos,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &client_infos).await
+            }
+            BrokerEvent::BroadCastCtiSyncState {
+                client_id,
+                cti_sync_state,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &cti_sync_state).await
+            }
+            BrokerEvent::BroadCastCtiLinkStatus {
+                client_id,
+                cti_link_status,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &cti_link_status).await
+            }
+            BrokerEvent::BroadCastHaStatus {
+                client_id,
+                ha_status,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &ha_status).await
+            }
+            BrokerEvent::BroadCastWelcome { client_id, welcome } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &welcome).await
+            }
+            BrokerEvent::BroadCastFirehoseMessage {
+                client_id,
+                firehose_message,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_shared_payload(client_protocol, &firehose_message)
+                    .await
+            }
+            BrokerEvent::BroadCastStateRefreshResult {
+                client_id,
+                state_refresh_result,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &state_refresh_result)
+                    .await
+            }
+            BrokerEvent::BroadCastStateSnapshot {
+                client_id,
+                snapshot,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_payload(client_protocol, &snapshot).await
+            }
+            BrokerEvent::BroadCastSupervisorAlert {
+                client_id,
+                supervisor_alert,
+            } => {
+                if client_id.is_some_and(|id| id != *self.get_id()) {
+                    return true;
+                }
+
+                self.write_shared_payload(client_protocol, &supervisor_alert)
+                    .await
+            }
+            // 관리 명령에 의한 연결 종료
+            BrokerEvent::DisconnectClient { client_id } => {
+                if client_id != *self.get_id() {
+                    return true;
+                }
+
+                log::info!(
+                    "Websocket client disconnected by admin. client_id: {}, client_addr: {}",
+                    self.get_id(),
+                    self.get_addr()
+                );
+                let _ = self.write_close(WEBSOCKET_CLOSE_POLICY_VIOLATION).await;
+                let _ = self.close().await;
+                return false;
+            }
+            // 서버 종료 안내 후 연결 종료
+            BrokerEvent::Shutdown => {
+                let _ = self
+                    .write_payload(client_protocol, &ShutdownNotice::new())
+                    .await;
+                let _ = self.write_close(WEBSOCKET_CLOSE_GOING_AWAY).await;
+                let _ = self.close().await;
+                log::info!(
+                    "Websocket client disconnected by shutdown. client_id: {}, client_addr: {}",
+                    self.get_id(),
+                    self.get_addr()
+                );
+                return false;
+            }
+            _ => return true,
+        };
+
+        // 전송하지 못한 클라이언트는 이후 메시지가 어긋나지 않도록 연결을 끊는다
+        if let Err(e) = written {
+            log::warn!(
+                "Websocket client write failed. client_id: {}, client_addr: {}, error: {}",
+                self.get_id(),
+                self.get_addr(),
+                e
+            );
+            return false;
         }
+
+        true
     }
 
     ///
-    /// 종료 프레임 전송
+    /// 브로커 이벤트를 순서대로 전송
     ///
-    async fn write_close(&mut self, reason: u16) -> Result<usize, Box<dyn Error + Send + Sync>> {
-        let length = 2;
-        let mut send_buffer = Vec::new();
-
-        // 웹 소켓 프레임 헤더 추가
-        send_buffer.push(WEBSOCKET_FIN_TRUE | WEBSOCKET_OP_CODE_CLOSE_FRAME);
-        // 웹 소켓 길이 패킷 추가
-        send_buffer.push(length as u8);
+    async fn write_broker_events(
+        &mut self,
+        client_protocol: ClientProtocol,
+        broker_events: Vec<BrokerEvent>,
+    ) -> bool {
+        for broker_event in broker_events {
+            if !self.write_broker_event(client_protocol, broker_event).await {
+                return false;
+            }
+        }
 
-        // CLOSE 이유 패킷 추가
-        send_buffer.push(((reason & 0xFF00) >> 8) as u8);
-        send_buffer.push((reason & 0xFF) as u8);
+        true
+    }
 
-        match self {
-            ClientStream::Plain {
-                stream,
-                id: _,
-                addr: _,
-            } => Ok(stream.write(&send_buffer).await.unwrap()),
-            ClientStream::Secure {
-                stream,
-                id: _,
-                addr: _,
-            } => Ok(stream.write(&send_buffer).await.unwrap()),
-        }
+    ///
+    /// 종료 프레임 전송
+    ///
+    async fn write_close(
+        &mut self,
+        status_code: u16,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        self.write_frame(&WebsocketFrame::close(status_code)).await
     }
 
     ///
@@ -384,63 +983,35 @@ impl ClientStream {
                 stream,
                 id: _,
                 addr: _,
+                write_timeout: _,
             } => Ok(stream.shutdown().await?),
             ClientStream::Secure {
                 stream,
                 id: _,
                 addr: _,
+                write_timeout: _,
             } => Ok(stream.shutdown().await?),
         }
     }
 
     ///
-    /// 텍스트 데이터 전송
+    /// HTTP 요청 헤더 수신
     ///
-    #[allow(unused)]
-    async fn write_text(&mut self, message: String) -> Result<usize, Box<dyn Error + Send + Sync>> {
-        let length = message.len();
-        let mut send_buffer = Vec::new();
-
-        // 웹 소켓 프레임 헤더 추가
-        send_buffer.push(WEBSOCKET_FIN_TRUE | WEBSOCKET_OP_CODE_TEXT_FRAME);
-
-        // 웹 소켓 길이 패킷 추가
-        match length {
-            0_usize..126_usize => {
-                send_buffer.push(length as u8);
-            }
-            126_usize..65535_usize => {
-                send_buffer.push(126_u8);
-                send_buffer.push(((length & 0xFF00) >> 8) as u8);
-                send_buffer.push((length & 0x00FF) as u8);
-            }
-            65535_usize.. => {
-                send_buffer.push(127_u8);
-                send_buffer.push(((length & 0xFF00_0000_0000_0000) >> 56) as u8);
-                send_buffer.push(((length & 0x00FF_0000_0000_0000) >> 48) as u8);
-                send_buffer.push(((length & 0x0000_FF00_0000_0000) >> 40) as u8);
-                send_buffer.push(((length & 0x0000_00FF_0000_0000) >> 32) as u8);
-                send_buffer.push(((length & 0x0000_0000_FF00_0000) >> 24) as u8);
-                send_buffer.push(((length & 0x0000_0000_00FF_0000) >> 16) as u8);
-                send_buffer.push(((length & 0x0000_0000_0000_FF00) >> 8) as u8);
-                send_buffer.push((length & 0x0000_0000_0000_00FF) as u8);
+    /// 빈 줄(CRLFCRLF)까지 나누어 받은 데이터를 모아 파싱한다. 헤더를 모두 받기 전에 연결이 끊기면 None 을 반환한다.
+    ///
+    async fn read_request(&mut self) -> Result<Option<HttpRequest>, Box<dyn Error + Send + Sync>> {
+        let mut request_buffer = vec![];
+        let mut buffer = vec![0_u8; 2_048];
+        loop {
+            let length = self.read(&mut buffer).await?;
+            if length == 0 {
+                return Ok(None);
             }
-        };
+            request_buffer.extend_from_slice(&buffer[0..length]);
 
-        // 웹 소켓 데이터 추가
-        send_buffer.append(&mut message.as_bytes().to_vec());
-
-        match self {
-            ClientStream::Plain {
-                stream,
-                id: _,
-                addr: _,
-            } => Ok(stream.write(&send_buffer).await?),
-            ClientStream::Secure {
-                stream,
-                id: _,
-                addr: _,
-            } => Ok(stream.write(&send_buffer).await?),
+            if let Some(header_length) = HttpRequest::header_length(&request_buffer)? {
+                return Ok(Some(HttpRequest::parse(&request_buffer[0..header_length])?));
+            }
         }
     }
 
@@ -453,94 +1024,270 @@ impl ClientStream {
                 stream,
                 id: _,
                 addr: _,
+                write_timeout: _,
             } => Ok(stream.read(buffer).await?),
             ClientStream::Secure {
                 stream,
                 id: _,
                 addr: _,
+                write_timeout: _,
             } => Ok(stream.read(buffer).await?),
         }
     }
 
+    ///
+    /// 수신 버퍼에서 완성된 웹 소켓 메시지를 모두 처리한다
+    ///
+    /// 텍스트, 이진 메시지는 클라이언트 이벤트로 전달하고 PING 에는 PONG 으로 응답한다.
+    /// CLOSE 메시지를 받거나 프로토콜 오류가 발생하면 종료 프레임을 보내고 커넥션을 닫은 뒤 false 를 반환한다.
+    ///
+    async fn handle_messages(
+        &mut self,
+        frame_reader: &mut WebsocketFrameReader,
+        client_event_channel_tx: &mpsc::Sender<ClientEvent>,
+    ) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        loop {
+            let status_code = match frame_reader.read_message() {
+                Ok(Some(WebsocketMessage::Data {
+                    op_code: _,
+                    payload,
+                })) => {
+                    client_event_channel_tx
+                        .send(ClientEvent::Receive {
+                            id: *self.get_id(),
+                            data: payload,
+                        })
+                        .await
+                        .unwrap();
+                    continue;
+                }
+                Ok(Some(WebsocketMessage::Ping { payload })) => {
+                    self.write_frame(&WebsocketFrame::new(
+                        true,
+                        WEBSOCKET_OP_CODE_PONG_FRAME,
+                        payload,
+                    ))
+                    .await?;
+                    continue;
+                }
+                Ok(Some(WebsocketMessage::Pong { payload: _ })) => continue,
+                Ok(Some(WebsocketMessage::Close {
+                    status_code,
+                    reason,
+                })) => {
+                    log::debug!(
+                        "Websocket client sent close. client_addr: {}, status_code: {:?}, reason: {}",
+                        self.get_addr(),
+                        status_code,
+                        reason
+                    );
+                    status_code.unwrap_or(WEBSOCKET_CLOSE_NORMAL)
+                }
+                Ok(None) => return Ok(true),
+                Err(e) => {
+                    log::warn!(
+                        "Websocket client protocol error. {}, client_addr: {}",
+                        e,
+                        self.get_addr()
+                    );
+                    e.get_status_code()
+                }
+            };
+
+            // 종료 프레임을 응답하고 커넥션을 닫는다
+            self.write_close(status_code).await?;
+            self.close().await?;
+            return Ok(false);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub async fn handle(
         &mut self,
         mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
         client_event_channel_tx: mpsc::Sender<ClientEvent>,
+        client_protocol: ClientProtocol,
+        permission: ClientPermission,
+        department_id: Option<i32>,
+        resume: Option<SessionResume>,
+        config: &WebsocketAcceptorConfig,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
         let mut buffer = vec![0_u8; 4_096];
 
+        // 조립 가능한 최대 메시지 크기 (바이트)
+        let mut frame_reader = WebsocketFrameReader::new(config.max_message_size);
+
+        // 연결 확인 PING 전송 주기 (None: 전송하지 않음), PONG 대기 시간
+        let ping_interval = config.ping_interval;
+        let pong_timeout = config.pong_timeout;
+        let mut last_received = Instant::now();
+        let mut ping_sent: Option<Instant> = None;
+
+        // 클라이언트 인증서를 사용한 경우 CN 으로 클라이언트를 식별한다
+        let common_name = self.get_common_name();
+        if let Some(ref common_name) = common_name {
+            log::info!(
+                "Websocket client certificate verified. client_id: {}, common_name: {}",
+                self.get_id(),
+                common_name
+            );
+        }
+
         // 클라이언트 소켓 접속 이벤트 전송
+        let (direct_event_tx, mut direct_event_rx) = mpsc::unbounded_channel::<Vec<BrokerEvent>>();
         client_event_channel_tx
             .send(ClientEvent::Connect {
-                id: self.get_id().clone(),
+                id: *self.get_id(),
+                permission,
+                department_id,
+                common_name,
+                addr: Some(*self.get_addr()),
+                direct_event_tx: Some(direct_event_tx),
+                resume,
             })
             .await
             .unwrap();
 
-        loop {
-            // 웹 소켓 데이터 수신
-            match timeout(Duration::from_millis(10), self.read(&mut buffer)).await {
-                Ok(Ok(n)) if n == 0 => {
-                    break;
-                }
-                Ok(Ok(n)) => {
-                    log::debug!(
-                        "Client send. client_id: {}, client_addr: {}, buffer: {:?}",
-                        self.get_id(),
-                        self.get_addr(),
-                        &buffer[0..n]
-                    );
+        let mut ping_timer = interval(Duration::from_secs(1));
+        ping_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
-                    // CLOSE 프레임 수신하면 커넥션 닫아버림
-                    if &buffer[0] & WEBSOCKET_OP_CODE_CLOSE_FRAME != 0_u8 {
-                        self.write_close(1_000_u16).await?;
-                        self.close().await?;
+        // 소켓 데이터, PING 주기, 브로커 이벤트 중 먼저 도착한 것을 처리한다
+        loop {
+            tokio::select! {
+                // 웹 소켓 데이터 수신
+                received = self.read(&mut buffer) => match received {
+                    Ok(0) => {
+                        break;
                     }
-                }
-                Ok(Err(e)) => {
-                    log::error!(
-                        "Websocket client error. {:?}, client_addr: {}",
-                        e,
-                        self.get_addr()
-                    );
-                    break;
-                }
-                Err(_) => {}
-            }
-
-            // 브로킹 이벤트 수신
-            match timeout(Duration::from_millis(10), broker_event_channel_rx.recv()).await {
-                Ok(Ok(event)) => match event {
-                    BrokerEvent::BroadCastAgentState {
-                        client_id,
-                        agent_info,
-                    } => {
-                        match client_id {
-                            Some(id) => {
-                                if &id != self.get_id() {
-                                    continue;
-                                }
-                            }
-                            None => {}
-                        };
+                    Ok(n) => {
+                        log::debug!(
+                            "Client send. client_id: {}, client_addr: {}, buffer: {:?}",
+                            self.get_id(),
+                            self.get_addr(),
+                            &buffer[0..n]
+                        );
 
-                        let mut buffer = Vec::new();
-                        agent_info
-                            .serialize(&mut rmp_serde::Serializer::new(&mut buffer))
-                            .unwrap();
+                        last_received = Instant::now();
+                        ping_sent = None;
 
-                        self.write_binary(&buffer).await.unwrap();
+                        frame_reader.extend(&buffer[0..n]);
+                        match self
+                            .handle_messages(&mut frame_reader, &client_event_channel_tx)
+                            .await
+                        {
+                            Ok(true) => {}
+                            Ok(false) => break,
+                            Err(e) => {
+                                log::warn!(
+                                    "Websocket client write failed. client_id: {}, client_addr: {}, error: {}",
+                                    self.get_id(),
+                                    self.get_addr(),
+                                    e
+                                );
+                                break;
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        log::error!(
+                            "Websocket client error. {:?}, client_addr: {}",
+                            e,
+                            self.get_addr()
+                        );
+                        break;
                     }
-                    _ => {}
                 },
-                Ok(Err(e)) => {
-                    log::error!("Unable to read broker message. {:?}", e);
-                    break;
+                // 일정 시간 수신이 없으면 PING 을 보내고, PONG 대기 시간 내에 응답이 없으면 연결을 끊는다
+                _ = ping_timer.tick(), if ping_interval.is_some() => match ping_sent {
+                    Some(ping_sent) if ping_sent.elapsed() >= pong_timeout => {
+                        log::warn!(
+                            "Websocket client did not respond to ping. client_id: {}, client_addr: {}",
+                            self.get_id(),
+                            self.get_addr()
+                        );
+                        let _ = self.write_close(WEBSOCKET_CLOSE_GOING_AWAY).await;
+                        let _ = self.close().await;
+                        break;
+                    }
+                    Some(_) => {}
+                    None if ping_interval
+                        .is_some_and(|ping_interval| last_received.elapsed() >= ping_interval) =>
+                    {
+                        if let Err(e) = self
+                            .write_frame(&WebsocketFrame::new(
+                                true,
+                                WEBSOCKET_OP_CODE_PING_FRAME,
+                                Vec::new(),
+                            ))
+                            .await
+                        {
+                            log::warn!(
+                                "Websocket client write failed. client_id: {}, client_addr: {}, error: {}",
+                                self.get_id(),
+                                self.get_addr(),
+                                e
+                            );
+                            break;
+                        }
+                        ping_sent = Some(Instant::now());
+                    }
+                    None => {}
+                },
+                // 클라이언트 전용 이벤트 수신 (접속 시 전체 상태)
+                Some(broker_events) = direct_event_rx.recv() => {
+                    if !self.write_broker_events(client_protocol, broker_events).await {
+                        break;
+                    }
                 }
-                Err(_) => {}
+                // 브로킹 이벤트 수신
+                event = broker_event_channel_rx.recv() => match event {
+                    Ok(broker_event) => {
+                        // 전용 큐에 먼저 들어온 전체 상태를 이후 변경분보다 먼저 전송한다
+                        let mut broker_events = vec![];
+                        while let Ok(direct_events) = direct_event_rx.try_recv() {
+                            broker_events.extend(direct_events);
+                        }
+                        broker_events.push(broker_event);
+
+                        if !self.write_broker_events(client_protocol, broker_events).await {
+                            break;
+                        }
+                    }
+                    // 놓친 이벤트는 전체 상태를 다시 받아 보완한다
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        log::warn!(
+                            "Websocket client lagged behind broker events. client_id: {}, skipped: {}",
+                            self.get_id(),
+                            skipped
+                        );
+                        // 종료 중에는 CTM 이 이미 멈춰 클라이언트 이벤트를 받지 못한다
+                        if let Err(e) = client_event_channel_tx
+                            .send(ClientEvent::Lagged {
+                                id: *self.get_id(),
+                                skipped,
+                            })
+                            .await
+                        {
+                            log::error!("Unable to send client event. {:?}", e);
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        log::error!("Unable to read broker message. channel closed");
+                        break;
+                    }
+                },
             }
         }
 
+        // 클라이언트 소켓 종료 이벤트 전송
+        if let Err(e) = client_event_channel_tx
+            .send(ClientEvent::Disconnect { id: *self.get_id() })
+            .await
+        {
+            log::error!("Unable to send client event. {:?}", e);
+        }
+
         Ok(())
     }
 }
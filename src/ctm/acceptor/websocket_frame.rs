@@ -0,0 +1,483 @@
+use std::{error::Error, fmt::Display};
+
+use bytes::BufMut;
+
+pub const WEBSOCKET_OP_CODE_CONTINUATION_FRAME: u8 = 0x00;
+pub const WEBSOCKET_OP_CODE_TEXT_FRAME: u8 = 0x01;
+pub const WEBSOCKET_OP_CODE_BINARY_FRAME: u8 = 0x02;
+pub const WEBSOCKET_OP_CODE_CLOSE_FRAME: u8 = 0x08;
+pub const WEBSOCKET_OP_CODE_PING_FRAME: u8 = 0x09;
+pub const WEBSOCKET_OP_CODE_PONG_FRAME: u8 = 0x0A;
+
+pub const WEBSOCKET_CLOSE_NORMAL: u16 = 1_000;
+pub const WEBSOCKET_CLOSE_GOING_AWAY: u16 = 1_001;
+pub const WEBSOCKET_CLOSE_PROTOCOL_ERROR: u16 = 1_002;
+pub const WEBSOCKET_CLOSE_INVALID_PAYLOAD: u16 = 1_007;
+pub const WEBSOCKET_CLOSE_POLICY_VIOLATION: u16 = 1_008;
+pub const WEBSOCKET_CLOSE_MESSAGE_TOO_BIG: u16 = 1_009;
+
+const WEBSOCKET_FIN: u8 = 0x80;
+const WEBSOCKET_RSV: u8 = 0x70;
+const WEBSOCKET_MASK: u8 = 0x80;
+const WEBSOCKET_MAX_CONTROL_PAYLOAD_LENGTH: usize = 125;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// 웹 소켓 프레임 처리 오류
+///
+pub enum WebsocketFrameError {
+    ReservedBits { op_code: u8 },
+    UnknownOpCode { op_code: u8 },
+    UnmaskedFrame { op_code: u8 },
+    InvalidControlFrame { op_code: u8, length: usize },
+    UnexpectedContinuation,
+    UnfinishedFragment { op_code: u8 },
+    MessageTooBig { length: usize, max_length: usize },
+    InvalidCloseStatus { status_code: Option<u16> },
+    InvalidUtf8 { op_code: u8 },
+}
+
+impl WebsocketFrameError {
+    ///
+    /// 오류 발생 시 클라이언트로 전송할 CLOSE 상태 코드
+    ///
+    pub fn get_status_code(&self) -> u16 {
+        match self {
+            WebsocketFrameError::MessageTooBig { .. } => WEBSOCKET_CLOSE_MESSAGE_TOO_BIG,
+            WebsocketFrameError::InvalidUtf8 { .. } => WEBSOCKET_CLOSE_INVALID_PAYLOAD,
+            _ => WEBSOCKET_CLOSE_PROTOCOL_ERROR,
+        }
+    }
+}
+
+impl Display for WebsocketFrameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebsocketFrameError::ReservedBits { op_code } => {
+                write!(f, "Reserved bits are set. op_code: {}", op_code)
+            }
+            WebsocketFrameError::UnknownOpCode { op_code } => {
+                write!(f, "Unknown op code. op_code: {}", op_code)
+            }
+            WebsocketFrameError::UnmaskedFrame { op_code } => {
+                write!(f, "Client frame is not masked. op_code: {}", op_code)
+            }
+            WebsocketFrameError::InvalidControlFrame { op_code, length } => write!(
+                f,
+                "Control frame is fragmented or too long. op_code: {}, length: {}",
+                op_code, length
+            ),
+            WebsocketFrameError::UnexpectedContinuation => {
+                write!(f, "Continuation frame without fragmented message")
+            }
+            WebsocketFrameError::UnfinishedFragment { op_code } => write!(
+                f,
+                "New data frame before fragmented message finished. op_code: {}",
+                op_code
+            ),
+            WebsocketFrameError::MessageTooBig { length, max_length } => write!(
+                f,
+                "Message length exceeds maximum. length: {}, max_length: {}",
+                length, max_length
+            ),
+            WebsocketFrameError::InvalidCloseStatus { status_code } => {
+                write!(f, "Invalid close status. status_code: {:?}", status_code)
+            }
+            WebsocketFrameError::InvalidUtf8 { op_code } => {
+                write!(f, "Payload is not valid UTF-8. op_code: {}", op_code)
+            }
+        }
+    }
+}
+
+impl Error for WebsocketFrameError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// RFC 6455 웹 소켓 프레임
+///
+/// 페이로드는 마스킹이 해제된 상태로 보관한다.
+///
+pub struct WebsocketFrame {
+    fin: bool,
+    op_code: u8,
+    payload: Vec<u8>,
+}
+
+impl WebsocketFrame {
+    pub fn new(fin: bool, op_code: u8, payload: impl Into<Vec<u8>>) -> Self {
+        Self {
+            fin,
+            op_code,
+            payload: payload.into(),
+        }
+    }
+
+    ///
+    /// CLOSE 프레임 생성
+    ///
+    pub fn close(status_code: u16) -> Self {
+        Self::new(
+            true,
+            WEBSOCKET_OP_CODE_CLOSE_FRAME,
+            status_code.to_be_bytes().to_vec(),
+        )
+    }
+
+    pub fn get_fin(&self) -> bool {
+        self.fin
+    }
+
+    pub fn get_op_code(&self) -> u8 {
+        self.op_code
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    ///
+    /// 제어 프레임(CLOSE, PING, PONG) 여부
+    ///
+    pub fn is_control(&self) -> bool {
+        self.op_code & 0x08 != 0
+    }
+
+    ///
+    /// 클라이언트가 전송한 프레임 하나를 역직렬화한다
+    ///
+    /// 프레임이 아직 모두 수신되지 않았으면 None 을, 완성된 경우 프레임과 사용한 바이트 수를 반환한다.
+    /// 클라이언트 프레임은 반드시 마스킹되어 있어야 한다.
+    ///
+    pub fn decode(
+        buffer: &[u8],
+        max_payload_length: usize,
+    ) -> Result<Option<(Self, usize)>, WebsocketFrameError> {
+        Self::decode_frame(buffer, max_payload_length, true)
+    }
+
+    ///
+    /// 서버가 전송한 프레임 하나를 역직렬화한다
+    ///
+    /// 부하 테스트 등 클라이언트로 동작할 때 사용하며, 서버 프레임은 마스킹되지 않는다.
+    ///
+    pub fn decode_server(
+        buffer: &[u8],
+        max_payload_length: usize,
+    ) -> Result<Option<(Self, usize)>, WebsocketFrameError> {
+        Self::decode_frame(buffer, max_payload_length, false)
+    }
+
+    fn decode_frame(
+        buffer: &[u8],
+        max_payload_length: usize,
+        masked: bool,
+    ) -> Result<Option<(Self, usize)>, WebsocketFrameError> {
+        let (first, second) = match (buffer.first(), buffer.get(1)) {
+            (Some(first), Some(second)) => (*first, *second),
+            _ => return Ok(None),
+        };
+
+        let fin = first & WEBSOCKET_FIN != 0;
+        let op_code = first & 0x0F;
+
+        if first & WEBSOCKET_RSV != 0 {
+            return Err(WebsocketFrameError::ReservedBits { op_code });
+        }
+        match op_code {
+            WEBSOCKET_OP_CODE_CONTINUATION_FRAME
+            | WEBSOCKET_OP_CODE_TEXT_FRAME
+            | WEBSOCKET_OP_CODE_BINARY_FRAME
+            | WEBSOCKET_OP_CODE_CLOSE_FRAME
+            | WEBSOCKET_OP_CODE_PING_FRAME
+            | WEBSOCKET_OP_CODE_PONG_FRAME => {}
+            _ => return Err(WebsocketFrameError::UnknownOpCode { op_code }),
+        }
+        if masked && second & WEBSOCKET_MASK == 0 {
+            return Err(WebsocketFrameError::UnmaskedFrame { op_code });
+        }
+
+        // 페이로드 길이 (7비트, 16비트, 64비트)
+        let (length, offset) = match second & 0x7F {
+            126 => match buffer.get(2..4) {
+                Some(bytes) => (u16::from_be_bytes([bytes[0], bytes[1]]) as u64, 4),
+                None => return Ok(None),
+            },
+            127 => match buffer.get(2..10) {
+                Some(bytes) => (u64::from_be_bytes(bytes.try_into().unwrap()), 10),
+                None => return Ok(None),
+            },
+            length => (length as u64, 2),
+        };
+
+        if op_code & 0x08 != 0 && (!fin || length > WEBSOCKET_MAX_CONTROL_PAYLOAD_LENGTH as u64) {
+            return Err(WebsocketFrameError::InvalidControlFrame {
+                op_code,
+                length: length as usize,
+            });
+        }
+        if length > max_payload_length as u64 {
+            return Err(WebsocketFrameError::MessageTooBig {
+                length: length.min(usize::MAX as u64) as usize,
+                max_length: max_payload_length,
+            });
+        }
+        let length = length as usize;
+
+        if !masked {
+            return Ok(buffer.get(offset..offset + length).map(|payload| {
+                (
+                    Self {
+                        fin,
+                        op_code,
+                        payload: payload.to_vec(),
+                    },
+                    offset + length,
+                )
+            }));
+        }
+
+        let mask = match buffer.get(offset..offset + 4) {
+            Some(mask) => mask,
+            None => return Ok(None),
+        };
+        let payload = match buffer.get(offset + 4..offset + 4 + length) {
+            Some(payload) => payload
+                .iter()
+                .enumerate()
+                .map(|(i, byte)| byte ^ mask[i % 4])
+                .collect(),
+            None => return Ok(None),
+        };
+
+        Ok(Some((
+            Self {
+                fin,
+                op_code,
+                payload,
+            },
+            offset + 4 + length,
+        )))
+    }
+
+    ///
+    /// 서버 프레임으로 직렬화한다
+    ///
+    /// 서버가 전송하는 프레임은 마스킹하지 않는다.
+    ///
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(self.payload.len() + 10);
+        self.encode_into(&mut buffer);
+
+        buffer
+    }
+
+    ///
+    /// 클라이언트 프레임으로 직렬화한다
+    ///
+    /// 클라이언트가 전송하는 프레임은 반드시 마스킹해야 한다.
+    ///
+    pub fn encode_masked(&self, mask: [u8; 4]) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(self.payload.len() + 14);
+        encode_frame_header(self.fin, self.op_code, self.payload.len(), &mut buffer);
+        buffer[1] |= WEBSOCKET_MASK;
+        buffer.put_slice(&mask);
+        buffer.extend(
+            self.payload
+                .iter()
+                .enumerate()
+                .map(|(i, byte)| byte ^ mask[i % 4]),
+        );
+
+        buffer
+    }
+
+    ///
+    /// 서버 프레임으로 직렬화해 버퍼 뒤에 덧붙인다
+    ///
+    pub fn encode_into<B: BufMut>(&self, buffer: &mut B) {
+        encode_frame_header(self.fin, self.op_code, self.payload.len(), buffer);
+        buffer.put_slice(&self.payload);
+    }
+}
+
+///
+/// 서버 프레임 헤더를 버퍼 뒤에 덧붙인다
+///
+/// 페이로드를 WebsocketFrame 으로 옮기지 않고 전송 버퍼에 바로 이어 쓸 때 사용한다.
+///
+pub fn encode_frame_header<B: BufMut>(fin: bool, op_code: u8, length: usize, buffer: &mut B) {
+    // 웹 소켓 프레임 헤더 추가
+    buffer.put_u8(if fin { WEBSOCKET_FIN } else { 0 } | op_code);
+
+    // 웹 소켓 길이 패킷 추가
+    match length {
+        0..=125 => buffer.put_u8(length as u8),
+        126..=65_535 => {
+            buffer.put_u8(126);
+            buffer.put_u16(length as u16);
+        }
+        _ => {
+            buffer.put_u8(127);
+            buffer.put_u64(length as u64);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// 조립이 완료된 웹 소켓 메시지
+///
+pub enum WebsocketMessage {
+    Data {
+        op_code: u8,
+        payload: Vec<u8>,
+    },
+    Ping {
+        payload: Vec<u8>,
+    },
+    Pong {
+        payload: Vec<u8>,
+    },
+    Close {
+        status_code: Option<u16>,
+        reason: String,
+    },
+}
+
+///
+/// 클라이언트 수신 데이터에서 웹 소켓 메시지를 읽는 리더
+///
+/// 여러 번에 나누어 수신된 프레임을 버퍼링하고, 분할(CONTINUATION) 프레임을 하나의 메시지로 조립한다.
+/// 분할 메시지 사이에 수신된 제어 프레임은 즉시 반환한다.
+///
+#[derive(Debug)]
+pub struct WebsocketFrameReader {
+    buffer: Vec<u8>,
+    fragment_op_code: Option<u8>,
+    fragment_payload: Vec<u8>,
+    max_message_size: usize,
+}
+
+impl WebsocketFrameReader {
+    pub fn new(max_message_size: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            fragment_op_code: None,
+            fragment_payload: Vec::new(),
+            max_message_size,
+        }
+    }
+
+    ///
+    /// 수신 데이터 추가
+    ///
+    pub fn extend(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    ///
+    /// 완성된 다음 메시지를 반환한다
+    ///
+    /// 메시지를 만들 데이터가 부족하면 None 을 반환한다.
+    ///
+    pub fn read_message(&mut self) -> Result<Option<WebsocketMessage>, WebsocketFrameError> {
+        loop {
+            let max_payload_length = self.max_message_size - self.fragment_payload.len();
+            let frame = match WebsocketFrame::decode(&self.buffer, max_payload_length)? {
+                Some((frame, length)) => {
+                    self.buffer.drain(..length);
+                    frame
+                }
+                None => return Ok(None),
+            };
+
+            match frame.op_code {
+                WEBSOCKET_OP_CODE_PING_FRAME => {
+                    return Ok(Some(WebsocketMessage::Ping {
+                        payload: frame.payload,
+                    }))
+                }
+                WEBSOCKET_OP_CODE_PONG_FRAME => {
+                    return Ok(Some(WebsocketMessage::Pong {
+                        payload: frame.payload,
+                    }))
+                }
+                WEBSOCKET_OP_CODE_CLOSE_FRAME => {
+                    return Self::decode_close(&frame.payload).map(Some)
+                }
+                WEBSOCKET_OP_CODE_CONTINUATION_FRAME => {
+                    let op_code = self
+                        .fragment_op_code
+                        .ok_or(WebsocketFrameError::UnexpectedContinuation)?;
+                    self.fragment_payload.extend_from_slice(&frame.payload);
+
+                    if frame.fin {
+                        self.fragment_op_code = None;
+                        let payload = std::mem::take(&mut self.fragment_payload);
+                        return Self::decode_data(op_code, payload).map(Some);
+                    }
+                }
+                op_code => {
+                    if self.fragment_op_code.is_some() {
+                        return Err(WebsocketFrameError::UnfinishedFragment { op_code });
+                    }
+
+                    if frame.fin {
+                        return Self::decode_data(op_code, frame.payload).map(Some);
+                    }
+                    self.fragment_op_code = Some(op_code);
+                    self.fragment_payload = frame.payload;
+                }
+            }
+        }
+    }
+
+    ///
+    /// 텍스트 메시지는 UTF-8 여부를 검증한다
+    ///
+    fn decode_data(op_code: u8, payload: Vec<u8>) -> Result<WebsocketMessage, WebsocketFrameError> {
+        if op_code == WEBSOCKET_OP_CODE_TEXT_FRAME && std::str::from_utf8(&payload).is_err() {
+            return Err(WebsocketFrameError::InvalidUtf8 { op_code });
+        }
+
+        Ok(WebsocketMessage::Data { op_code, payload })
+    }
+
+    ///
+    /// CLOSE 프레임의 상태 코드와 사유를 읽는다
+    ///
+    fn decode_close(payload: &[u8]) -> Result<WebsocketMessage, WebsocketFrameError> {
+        let status_code = match payload.len() {
+            0 => {
+                return Ok(WebsocketMessage::Close {
+                    status_code: None,
+                    reason: "".to_string(),
+                })
+            }
+            1 => return Err(WebsocketFrameError::InvalidCloseStatus { status_code: None }),
+            _ => u16::from_be_bytes([payload[0], payload[1]]),
+        };
+
+        // 1004~1006, 1015 는 전송할 수 없는 예약 코드이며 1016~2999 는 정의되지 않은 코드이다
+        match status_code {
+            1_000..=1_003 | 1_007..=1_014 | 3_000..=4_999 => {}
+            _ => {
+                return Err(WebsocketFrameError::InvalidCloseStatus {
+                    status_code: Some(status_code),
+                })
+            }
+        }
+
+        let reason = String::from_utf8(payload[2..].to_vec()).map_err(|_| {
+            WebsocketFrameError::InvalidUtf8 {
+                op_code: WEBSOCKET_OP_CODE_CLOSE_FRAME,
+            }
+        })?;
+
+        Ok(WebsocketMessage::Close {
+            status_code: Some(status_code),
+            reason,
+        })
+    }
+}
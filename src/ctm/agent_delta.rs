@@ -0,0 +1,428 @@
+use std::collections::{HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::event::broker_event::BrokerEvent;
+
+use super::{agent_info::AgentInfo, agent_removal::AgentRemoval, locale::LocaleDictionary};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// 상담직원 상태 변경분
+///
+/// 이전에 전송한 상태와 달라진 필드만 담으며, 클라이언트는 sequence 로 누락 여부를 확인한다.
+///
+pub struct AgentInfoDelta {
+    sequence: u64,
+    cti_instance: String,
+    agent_id: String,
+    changes: Map<String, Value>,
+}
+
+impl AgentInfoDelta {
+    pub fn get_sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_changes(&self) -> &Map<String, Value> {
+        &self.changes
+    }
+
+    ///
+    /// 이전 상태에 변경분을 반영한 상담직원 상태를 반환한다
+    ///
+    /// 이전 상태가 없으면 변경분에 모든 필드가 있어야 한다.
+    ///
+    pub fn apply(&self, agent_info: Option<&AgentInfo>) -> Result<AgentInfo, serde_json::Error> {
+        let mut fields = agent_info.map(to_fields).unwrap_or_default();
+        fields.extend(self.changes.clone());
+
+        serde_json::from_value(Value::Object(fields))
+    }
+
+    ///
+    /// 클라이언트 언어에 맞게 변경된 상태, 사유코드의 표시 이름을 바꾼다
+    ///
+    pub fn localize(&mut self, locale_dictionary: &LocaleDictionary, locale: &str) {
+        let code = |field: &str| {
+            self.changes
+                .get(field)
+                .and_then(Value::as_u64)
+                .and_then(|code| u16::try_from(code).ok())
+        };
+        let agent_state_label = code("agent_state")
+            .and_then(|agent_state| locale_dictionary.agent_state_label(locale, agent_state));
+        let reason_code_label = code("reason_code")
+            .and_then(|reason_code| locale_dictionary.reason_code_label(locale, reason_code));
+
+        if let Some(label) = agent_state_label {
+            self.changes
+                .insert("agent_state_label".to_string(), Value::from(label));
+        }
+        if let Some(label) = reason_code_label {
+            self.changes
+                .insert("reason_code_label".to_string(), Value::from(label));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 상담직원 전체 상태
+///
+/// 접속 또는 재동기화 요청 시 전송하며, sequence 까지의 변경분이 모두 반영되어 있다.
+///
+pub struct AgentSnapshot {
+    sequence: u64,
+    agent_infos: Vec<AgentInfo>,
+}
+
+impl AgentSnapshot {
+    pub fn get_sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn get_agent_infos(&self) -> &Vec<AgentInfo> {
+        &self.agent_infos
+    }
+
+    ///
+    /// 조건에 맞는 상담직원만 남긴다
+    ///
+    pub fn retain(&mut self, f: impl FnMut(&AgentInfo) -> bool) {
+        self.agent_infos.retain(f);
+    }
+
+    ///
+    /// 클라이언트 언어에 맞게 상태, 사유코드 표시 이름을 바꾼다
+    ///
+    pub fn localize(&mut self, locale_dictionary: &LocaleDictionary, locale: &str) {
+        self.agent_infos
+            .iter_mut()
+            .for_each(|agent_info| agent_info.localize(locale_dictionary, locale));
+    }
+}
+
+#[derive(Debug, Clone)]
+///
+/// 재접속 클라이언트에 다시 보낼 상담직원 변경 기록
+///
+pub enum AgentDeltaRecord {
+    // 상태 변경분
+    Delta(AgentInfoDelta),
+    // 상담직원 제거
+    Removal(AgentRemoval),
+}
+
+///
+/// 보관 중인 변경 기록
+///
+/// sequence 는 변경분의 일련번호 또는 제거 시점의 마지막 일련번호이며, 구독 조건 확인을 위해
+/// 기록 시점의 팀, 부서 ID 를 함께 보관한다.
+///
+#[derive(Debug)]
+struct AgentDeltaHistoryEntry {
+    sequence: u64,
+    team_id: Option<u32>,
+    department_id: Option<i32>,
+    record: AgentDeltaRecord,
+}
+
+#[derive(Debug)]
+///
+/// 재접속 클라이언트에 다시 보낼 변경 기록과 기록 시점의 팀, 부서 ID
+///
+pub struct AgentDeltaReplayEntry<'a> {
+    pub team_id: Option<u32>,
+    pub department_id: Option<i32>,
+    pub record: &'a AgentDeltaRecord,
+}
+
+///
+/// 상담직원 상태 변경분 계산기
+///
+/// 마지막으로 전송한 상담직원 상태를 보관하고, 변경분마다 일련번호를 1씩 증가시킨다.
+/// 재접속한 클라이언트가 놓친 변경분만 다시 받을 수 있도록 최근 변경 기록을 정해진 개수만큼 보관한다.
+///
+#[derive(Debug)]
+pub struct AgentDeltaTracker {
+    // 일련번호 구간 식별자 (서버를 재시작하면 일련번호가 다시 시작되므로 재접속 시 함께 확인한다)
+    epoch: String,
+    sequence: u64,
+    agent_infos: HashMap<(String, String), AgentInfo>,
+    history: VecDeque<AgentDeltaHistoryEntry>,
+    history_size: usize,
+    // 변경 기록으로 이어 받을 수 있는 최소 일련번호
+    min_resume_sequence: u64,
+}
+
+impl Default for AgentDeltaTracker {
+    fn default() -> Self {
+        Self::with_history_size(0)
+    }
+}
+
+impl AgentDeltaTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// 최근 변경 기록을 history_size 개까지 보관하는 계산기를 생성한다 (0: 보관하지 않음)
+    ///
+    pub fn with_history_size(history_size: usize) -> Self {
+        Self {
+            epoch: Uuid::now_v7().to_string(),
+            sequence: 0,
+            agent_infos: HashMap::new(),
+            history: VecDeque::with_capacity(history_size.min(1_024)),
+            history_size,
+            min_resume_sequence: 0,
+        }
+    }
+
+    pub fn get_epoch(&self) -> &str {
+        &self.epoch
+    }
+
+    pub fn get_sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    ///
+    /// 마지막으로 전송한 상태와 비교하여 변경분을 계산한다
+    ///
+    /// 처음 보는 상담직원은 모든 필드를, 변경된 필드가 없으면 None 을 반환한다.
+    ///
+    pub fn track(&mut self, agent_info: &AgentInfo) -> Option<AgentInfoDelta> {
+        let key = (
+            agent_info.get_cti_instance().to_string(),
+            agent_info.get_agent_id().to_string(),
+        );
+
+        let new_fields = to_fields(agent_info);
+        let old_fields = self
+            .agent_infos
+            .get(&key)
+            .map(to_fields)
+            .unwrap_or_default();
+        let changes = new_fields
+            .into_iter()
+            .filter(|(field, value)| old_fields.get(field) != Some(value))
+            .collect::<Map<_, _>>();
+
+        if changes.is_empty() {
+            return None;
+        }
+
+        self.sequence += 1;
+        self.agent_infos.insert(key, agent_info.clone());
+
+        let agent_info_delta = AgentInfoDelta {
+            sequence: self.sequence,
+            cti_instance: agent_info.get_cti_instance().to_string(),
+            agent_id: agent_info.get_agent_id().to_string(),
+            changes,
+        };
+        self.record(
+            Some(agent_info),
+            AgentDeltaRecord::Delta(agent_info_delta.clone()),
+        );
+
+        Some(agent_info_delta)
+    }
+
+    ///
+    /// 제거된 상담직원의 마지막 상태를 삭제하여 반환한다
+    ///
+    /// 제거 사유가 없어 재접속 클라이언트에 다시 보낼 수 없으므로, 이전 일련번호로는 더 이상
+    /// 이어 받을 수 없다.
+    ///
+    pub fn remove(&mut self, cti_instance: &str, agent_id: &str) -> Option<AgentInfo> {
+        let agent_info = self
+            .agent_infos
+            .remove(&(cti_instance.to_string(), agent_id.to_string()));
+        if agent_info.is_some() {
+            self.history.clear();
+            self.min_resume_sequence = self.sequence + 1;
+        }
+
+        agent_info
+    }
+
+    ///
+    /// 제거된 상담직원의 마지막 상태를 삭제하여 반환하고, 제거를 변경 기록에 남긴다
+    ///
+    pub fn remove_agent(&mut self, agent_removal: &AgentRemoval) -> Option<AgentInfo> {
+        let agent_info = self.agent_infos.remove(&(
+            agent_removal.get_cti_instance().to_string(),
+            agent_removal.get_agent_id().to_string(),
+        ));
+        self.record(
+            agent_info.as_ref(),
+            AgentDeltaRecord::Removal(agent_removal.clone()),
+        );
+
+        agent_info
+    }
+
+    ///
+    /// sequence 이후의 변경 기록을 반환한다
+    ///
+    /// 구간 식별자가 다르거나, 보관 개수를 넘어 이미 지운 기록이 필요하면 None 을 반환한다.
+    /// 기록과 함께 기록 시점의 팀, 부서 ID 를 반환한다.
+    ///
+    pub fn replay(&self, epoch: &str, sequence: u64) -> Option<Vec<AgentDeltaReplayEntry<'_>>> {
+        if self.history_size == 0
+            || epoch != self.epoch
+            || sequence < self.min_resume_sequence
+            || sequence > self.sequence
+        {
+            return None;
+        }
+
+        // 마지막으로 받은 변경분 직후의 제거는 클라이언트가 받았는지 알 수 없으므로 다시 보낸다
+        Some(
+            self.history
+                .iter()
+                .filter(|entry| match entry.record {
+                    AgentDeltaRecord::Delta(_) => entry.sequence > sequence,
+                    AgentDeltaRecord::Removal(_) => entry.sequence >= sequence,
+                })
+                .map(|entry| AgentDeltaReplayEntry {
+                    team_id: entry.team_id,
+                    department_id: entry.department_id,
+                    record: &entry.record,
+                })
+                .collect(),
+        )
+    }
+
+    ///
+    /// 변경 기록을 보관하고, 보관 개수를 넘으면 오래된 기록부터 지운다
+    ///
+    fn record(&mut self, agent_info: Option<&AgentInfo>, record: AgentDeltaRecord) {
+        if self.history_size == 0 {
+            return;
+        }
+
+        self.history.push_back(AgentDeltaHistoryEntry {
+            sequence: self.sequence,
+            team_id: agent_info.map(AgentInfo::get_team_id),
+            department_id: agent_info.map(AgentInfo::get_department_id),
+            record,
+        });
+        while self.history.len() > self.history_size {
+            if let Some(entry) = self.history.pop_front() {
+                let min_resume_sequence = match entry.record {
+                    AgentDeltaRecord::Delta(_) => entry.sequence,
+                    AgentDeltaRecord::Removal(_) => entry.sequence + 1,
+                };
+                self.min_resume_sequence = self.min_resume_sequence.max(min_resume_sequence);
+            }
+        }
+    }
+
+    ///
+    /// 현재 일련번호까지 반영된 전체 상태를 반환한다
+    ///
+    pub fn snapshot(&self) -> AgentSnapshot {
+        let mut agent_infos = self.agent_infos.values().cloned().collect::<Vec<_>>();
+        agent_infos.sort_by(|a, b| {
+            (a.get_cti_instance(), a.get_agent_id()).cmp(&(b.get_cti_instance(), b.get_agent_id()))
+        });
+
+        AgentSnapshot {
+            sequence: self.sequence,
+            agent_infos,
+        }
+    }
+}
+
+///
+/// 상담직원 상태 수신기
+///
+/// 브로커 이벤트의 상태, 전체 상태, 변경분을 받아 상담직원 전체 상태를 유지한다.
+///
+#[derive(Debug, Default)]
+pub struct AgentInfoMirror {
+    agent_infos: HashMap<(String, String), AgentInfo>,
+}
+
+impl AgentInfoMirror {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, cti_instance: &str, agent_id: &str) -> Option<&AgentInfo> {
+        self.agent_infos
+            .get(&(cti_instance.to_string(), agent_id.to_string()))
+    }
+
+    ///
+    /// 브로커 이벤트를 반영하고 상태가 바뀐 상담직원을 반환한다
+    ///
+    /// 제거 이벤트는 보관 중인 상태만 삭제한다.
+    ///
+    pub fn apply(&mut self, broker_event: &BrokerEvent) -> Vec<AgentInfo> {
+        let agent_infos = match broker_event {
+            BrokerEvent::BroadCastAgentState { agent_info, .. } => vec![(**agent_info).clone()],
+            BrokerEvent::BroadCastAgentSnapshot { agent_snapshot, .. } => {
+                agent_snapshot.get_agent_infos().clone()
+            }
+            BrokerEvent::BroadCastAgentDelta {
+                agent_info_delta, ..
+            } => match agent_info_delta.apply(self.get(
+                agent_info_delta.get_cti_instance(),
+                agent_info_delta.get_agent_id(),
+            )) {
+                Ok(agent_info) => vec![agent_info],
+                Err(e) => {
+                    log::warn!(
+                        "Unable to apply agent delta. agent_id: {}, error: {}",
+                        agent_info_delta.get_agent_id(),
+                        e
+                    );
+                    vec![]
+                }
+            },
+            BrokerEvent::BroadCastAgentRemoved { agent_removal, .. } => {
+                self.agent_infos.remove(&(
+                    agent_removal.get_cti_instance().to_string(),
+                    agent_removal.get_agent_id().to_string(),
+                ));
+                vec![]
+            }
+            _ => vec![],
+        };
+
+        agent_infos.iter().for_each(|agent_info| {
+            self.agent_infos.insert(
+                (
+                    agent_info.get_cti_instance().to_string(),
+                    agent_info.get_agent_id().to_string(),
+                ),
+                agent_info.clone(),
+            );
+        });
+
+        agent_infos
+    }
+}
+
+fn to_fields(agent_info: &AgentInfo) -> Map<String, Value> {
+    match serde_json::to_value(agent_info) {
+        Ok(Value::Object(fields)) => fields,
+        _ => Map::new(),
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use super::agent_info::AgentInfo;
+
+///
+/// 내선(장치) 번호로 상담직원을 찾는 역색인
+///
+/// 호 이벤트는 상담직원 ID 없이 내선이나 장치 번호만 전달하는 경우가 있어
+/// 상담직원 상태를 갱신할 때마다 내선, 장치 번호와 상담직원 ID 를 함께 보관한다.
+/// 같은 번호를 다른 상담직원이 사용하면 마지막으로 갱신한 상담직원으로 바꾼다.
+///
+#[derive(Debug, Default)]
+pub struct AgentDeviceIndex {
+    device_agent_map: HashMap<String, String>,
+    agent_device_map: HashMap<String, Vec<String>>,
+}
+
+impl AgentDeviceIndex {
+    pub fn len(&self) -> usize {
+        self.device_agent_map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.device_agent_map.is_empty()
+    }
+
+    ///
+    /// 상담직원의 내선, 장치 번호를 다시 색인한다 (빈 번호는 색인하지 않는다)
+    ///
+    pub fn update(&mut self, agent_info: &AgentInfo) {
+        let agent_id = agent_info.get_agent_id();
+        self.remove(agent_id);
+
+        let mut devices: Vec<String> = vec![];
+        for device in [
+            agent_info.get_agent_extension(),
+            agent_info.get_agent_instrument(),
+        ] {
+            if device.is_empty() || devices.iter().any(|d| d == device) {
+                continue;
+            }
+
+            // 다른 상담직원이 사용하던 번호는 그 상담직원의 색인에서 뺀다
+            if let Some(previous_agent_id) = self
+                .device_agent_map
+                .insert(device.to_string(), agent_id.to_string())
+            {
+                if let Some(previous_devices) = self.agent_device_map.get_mut(&previous_agent_id) {
+                    previous_devices.retain(|d| d != device);
+                }
+            }
+            devices.push(device.to_string());
+        }
+
+        if !devices.is_empty() {
+            self.agent_device_map.insert(agent_id.to_string(), devices);
+        }
+    }
+
+    ///
+    /// 상담직원의 색인을 제거한다
+    ///
+    pub fn remove(&mut self, agent_id: &str) {
+        if let Some(devices) = self.agent_device_map.remove(agent_id) {
+            devices.iter().for_each(|device| {
+                if self
+                    .device_agent_map
+                    .get(device)
+                    .is_some_and(|indexed_agent_id| indexed_agent_id == agent_id)
+                {
+                    self.device_agent_map.remove(device);
+                }
+            });
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.device_agent_map.clear();
+        self.agent_device_map.clear();
+    }
+
+    ///
+    /// 내선 또는 장치 번호를 사용하는 상담직원 ID
+    ///
+    pub fn get_agent_id(&self, device: &str) -> Option<&str> {
+        self.device_agent_map.get(device).map(String::as_str)
+    }
+}
@@ -1,47 +1,145 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::SystemTime;
 
+use async_graphql::SimpleObject;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::cisco::{pretty::agent_state_name, Direction, SkillGroupAssignment};
+
+use super::{
+    field_masker::FieldMasker, locale::LocaleDictionary, media_state::MediaState, pg_clock::PgClock,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
 pub struct AgentInfo {
+    cti_instance: String,
     icm_agent_id: i32,
     agent_id: String,
+    agent_name: String,
+    team_id: u32,
+    team_name: String,
+    department_id: i32,
     agent_state: u16,
+    #[serde(default)]
+    agent_state_label: String,
     state_duration: u64,
     reason_code: u16,
+    reason_code_label: String,
     skill_group_id: u16,
-    direction: u32,
+    // 상담직원이 속한 스킬그룹 목록
+    #[serde(default)]
+    skill_groups: Vec<SkillGroupAssignment>,
+    direction: Direction,
     agent_extension: String,
+    #[serde(default)]
+    agent_instrument: String,
+    // 음성 외 MRD(채팅, 이메일 등)별 상태
+    #[serde(default)]
+    media_states: Vec<MediaState>,
 }
 
 impl AgentInfo {
     pub fn new(agent_id: impl Into<String>) -> Self {
         Self {
+            cti_instance: "".to_string(),
             icm_agent_id: 0,
             agent_id: agent_id.into(),
+            agent_name: "".to_string(),
+            team_id: 0,
+            team_name: "".to_string(),
+            department_id: 0,
             agent_state: 0,
+            agent_state_label: agent_state_name(0).to_string(),
             state_duration: 0,
             reason_code: 0,
+            reason_code_label: "".to_string(),
             skill_group_id: 0,
-            direction: 0,
+            skill_groups: vec![],
+            direction: Direction::NONE,
             agent_extension: "".to_string(),
+            agent_instrument: "".to_string(),
+            media_states: vec![],
         }
     }
 
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn set_cti_instance(&mut self, cti_instance: impl Into<String>) {
+        self.cti_instance = cti_instance.into();
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_agent_extension(&self) -> &str {
+        &self.agent_extension
+    }
+
+    pub fn get_agent_instrument(&self) -> &str {
+        &self.agent_instrument
+    }
+
+    pub fn get_agent_state(&self) -> u16 {
+        self.agent_state
+    }
+
+    ///
+    /// 현재 상태 시작 시각 (UNIX 시간, 초)
+    ///
+    pub fn get_state_duration(&self) -> u64 {
+        self.state_duration
+    }
+
+    pub fn get_team_id(&self) -> u32 {
+        self.team_id
+    }
+
+    pub fn get_reason_code(&self) -> u16 {
+        self.reason_code
+    }
+
+    pub fn get_agent_name(&self) -> &str {
+        &self.agent_name
+    }
+
+    pub fn get_team_name(&self) -> &str {
+        &self.team_name
+    }
+
+    pub fn set_agent_name(&mut self, agent_name: impl Into<String>) {
+        self.agent_name = agent_name.into();
+    }
+
+    pub fn set_team(&mut self, team_id: u32, team_name: impl Into<String>) {
+        self.team_id = team_id;
+        self.team_name = team_name.into();
+    }
+
+    pub fn get_department_id(&self) -> i32 {
+        self.department_id
+    }
+
+    pub fn set_department_id(&mut self, department_id: i32) {
+        self.department_id = department_id;
+    }
+
     pub fn set_icm_agent_id(&mut self, icm_agent_id: i32) {
         self.icm_agent_id = icm_agent_id;
     }
 
+    pub fn get_agent_state_label(&self) -> &str {
+        &self.agent_state_label
+    }
+
     pub fn set_agent_state(&mut self, agent_state: u16) {
         self.agent_state = agent_state;
+        self.agent_state_label = agent_state_name(agent_state).to_string();
     }
 
-    pub fn set_state_duration(&mut self, state_duration: u32) {
-        self.state_duration = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-            - state_duration as u64;
+    pub fn set_state_duration(&mut self, state_duration: u32, pg_clock: &PgClock) {
+        self.state_duration = pg_clock.state_start_time(state_duration, SystemTime::now());
     }
 
     pub fn set_reason_code(&mut self, reason_code: u16) {
@@ -56,30 +154,92 @@ impl AgentInfo {
         }
     }
 
+    pub fn get_reason_code_label(&self) -> &str {
+        &self.reason_code_label
+    }
+
+    pub fn set_reason_code_label(&mut self, reason_code_label: impl Into<String>) {
+        self.reason_code_label = reason_code_label.into();
+    }
+
     pub fn set_skill_group_id(&mut self, skill_group_id: u16) {
         // 통화, 보류 상태일때만 할당
         match self.agent_state {
-                4 | 10 => {
-                    self.skill_group_id = skill_group_id;
-                }
+            4 | 10 => {
+                self.skill_group_id = skill_group_id;
+            }
             _ => {
                 self.skill_group_id = 0;
             }
         }
     }
 
-    pub fn set_direction(&mut self, direction: u32) {
+    pub fn get_skill_groups(&self) -> &[SkillGroupAssignment] {
+        &self.skill_groups
+    }
+
+    ///
+    /// 스킬그룹 목록을 바꾼다 (목록이 없는 이벤트는 유지하고, 로그아웃하면 비운다)
+    ///
+    pub fn set_skill_groups(&mut self, skill_groups: Vec<SkillGroupAssignment>) {
+        if self.agent_state == 1 {
+            self.skill_groups.clear();
+        } else if !skill_groups.is_empty() {
+            self.skill_groups = skill_groups;
+        }
+    }
+
+    pub fn set_direction(&mut self, direction: Direction) {
         match self.agent_state {
             4 | 7 | 8 | 10 => {
                 // 통화, 예약, 보류 상태일때만 할당
                 self.direction = direction;
             }
             _ => {
-                self.direction = 0;
+                self.direction = Direction::NONE;
             }
         }
     }
 
+    pub fn get_media_states(&self) -> &[MediaState] {
+        &self.media_states
+    }
+
+    ///
+    /// 음성 외 MRD 상태를 갱신한다 (로그아웃한 MRD 는 제외한다)
+    ///
+    pub fn set_media_state(&mut self, media_state: MediaState) {
+        self.media_states
+            .retain(|current| current.get_mrd_id() != media_state.get_mrd_id());
+        if media_state.get_agent_state() != 1 {
+            self.media_states.push(media_state);
+            self.media_states.sort_by_key(MediaState::get_mrd_id);
+        }
+    }
+
+    ///
+    /// 클라이언트 전송 전 개인정보 필드를 마스킹한다
+    ///
+    pub fn mask_fields(&mut self, field_masker: &FieldMasker) {
+        self.agent_extension = field_masker.mask(&self.agent_extension);
+        self.agent_instrument = field_masker.mask(&self.agent_instrument);
+    }
+
+    ///
+    /// 클라이언트 언어에 맞게 상태, 사유코드 표시 이름을 바꾼다
+    ///
+    pub fn localize(&mut self, locale_dictionary: &LocaleDictionary, locale: &str) {
+        if let Some(label) = locale_dictionary.agent_state_label(locale, self.agent_state) {
+            self.agent_state_label = label.to_string();
+        }
+        if let Some(label) = locale_dictionary.reason_code_label(locale, self.reason_code) {
+            self.reason_code_label = label.to_string();
+        }
+        self.media_states
+            .iter_mut()
+            .for_each(|media_state| media_state.localize(locale_dictionary, locale));
+    }
+
     pub fn set_agent_extension(&mut self, agent_extension: impl Into<String>) {
         match self.agent_state {
             1 | 9 => {
@@ -91,4 +251,16 @@ impl AgentInfo {
             }
         }
     }
+
+    pub fn set_agent_instrument(&mut self, agent_instrument: impl Into<String>) {
+        match self.agent_state {
+            1 | 9 => {
+                // 로그아웃, 알수없음 상태일때는 할당받지 않는다
+                self.agent_instrument = "".to_string();
+            }
+            _ => {
+                self.agent_instrument = agent_instrument.into();
+            }
+        }
+    }
 }
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use super::agent_info::AgentInfo;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 클라이언트 상담직원 조회 응답
+///
+/// 요청 시 전달한 request_id 를 그대로 돌려주며, 응답 대기 시간이 지나면 agent_info 없이 전송한다.
+///
+pub struct AgentQueryResult {
+    request_id: String,
+    cti_instance: String,
+    agent_id: String,
+    agent_info: Option<AgentInfo>,
+}
+
+impl AgentQueryResult {
+    pub fn new(
+        request_id: impl Into<String>,
+        cti_instance: impl Into<String>,
+        agent_id: impl Into<String>,
+        agent_info: Option<AgentInfo>,
+    ) -> Self {
+        Self {
+            request_id: request_id.into(),
+            cti_instance: cti_instance.into(),
+            agent_id: agent_id.into(),
+            agent_info,
+        }
+    }
+
+    pub fn get_request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_agent_info(&self) -> Option<&AgentInfo> {
+        self.agent_info.as_ref()
+    }
+}
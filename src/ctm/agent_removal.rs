@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// 상담직원 제거 사유
+///
+pub enum AgentRemovalReason {
+    // 로그아웃 후 일정 시간 경과
+    LOGOUT_TIMEOUT,
+    // 팀 구성에서 삭제
+    TEAM_CONFIG_DELETED,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 상담직원 제거 이벤트
+///
+pub struct AgentRemoval {
+    cti_instance: String,
+    agent_id: String,
+    reason: AgentRemovalReason,
+}
+
+impl AgentRemoval {
+    pub fn new(
+        cti_instance: impl Into<String>,
+        agent_id: impl Into<String>,
+        reason: AgentRemovalReason,
+    ) -> Self {
+        Self {
+            cti_instance: cti_instance.into(),
+            agent_id: agent_id.into(),
+            reason,
+        }
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_reason(&self) -> AgentRemovalReason {
+        self.reason
+    }
+}
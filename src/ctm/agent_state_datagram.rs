@@ -0,0 +1,134 @@
+use super::agent_info::AgentInfo;
+
+// 데이터그램 형식 버전
+pub const AGENT_STATE_DATAGRAM_VERSION: u8 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// 상담직원 상태 데이터그램
+///
+/// UDP 멀티캐스트로 전송하는 고정 형식 메시지이며, 정수는 빅 엔디언으로 인코딩한다.
+///
+/// | 필드 | 크기 |
+/// |---|---|
+/// | version | u8 |
+/// | agent_state | u16 |
+/// | reason_code | u16 |
+/// | team_id | u32 |
+/// | state_start_time (UNIX 시간, 초) | u32 |
+/// | agent_id 길이, agent_id | u8, UTF-8 |
+/// | cti_instance 길이, cti_instance | u8, UTF-8 |
+///
+pub struct AgentStateDatagram {
+    cti_instance: String,
+    agent_id: String,
+    agent_state: u16,
+    reason_code: u16,
+    team_id: u32,
+    state_start_time: u32,
+}
+
+impl From<&AgentInfo> for AgentStateDatagram {
+    fn from(agent_info: &AgentInfo) -> Self {
+        Self {
+            cti_instance: agent_info.get_cti_instance().to_string(),
+            agent_id: agent_info.get_agent_id().to_string(),
+            agent_state: agent_info.get_agent_state(),
+            reason_code: agent_info.get_reason_code(),
+            team_id: agent_info.get_team_id(),
+            state_start_time: u32::try_from(agent_info.get_state_duration()).unwrap_or(u32::MAX),
+        }
+    }
+}
+
+impl AgentStateDatagram {
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_agent_state(&self) -> u16 {
+        self.agent_state
+    }
+
+    pub fn get_reason_code(&self) -> u16 {
+        self.reason_code
+    }
+
+    pub fn get_team_id(&self) -> u32 {
+        self.team_id
+    }
+
+    pub fn get_state_start_time(&self) -> u32 {
+        self.state_start_time
+    }
+
+    ///
+    /// 데이터그램 인코딩
+    ///
+    /// 255 바이트를 넘는 문자열은 잘라서 전송한다.
+    ///
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(15 + self.agent_id.len() + self.cti_instance.len());
+        buffer.push(AGENT_STATE_DATAGRAM_VERSION);
+        buffer.extend_from_slice(&self.agent_state.to_be_bytes());
+        buffer.extend_from_slice(&self.reason_code.to_be_bytes());
+        buffer.extend_from_slice(&self.team_id.to_be_bytes());
+        buffer.extend_from_slice(&self.state_start_time.to_be_bytes());
+        encode_string(&mut buffer, &self.agent_id);
+        encode_string(&mut buffer, &self.cti_instance);
+
+        buffer
+    }
+
+    ///
+    /// 데이터그램 디코딩
+    ///
+    /// 버전이 다르거나 길이가 맞지 않으면 None 을 반환한다.
+    ///
+    pub fn decode(buffer: &[u8]) -> Option<Self> {
+        let (&version, buffer) = buffer.split_first()?;
+        if version != AGENT_STATE_DATAGRAM_VERSION || buffer.len() < 12 {
+            return None;
+        }
+
+        let (agent_id, rest) = decode_string(&buffer[12..])?;
+        let (cti_instance, rest) = decode_string(rest)?;
+        if !rest.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            cti_instance,
+            agent_id,
+            agent_state: u16::from_be_bytes([buffer[0], buffer[1]]),
+            reason_code: u16::from_be_bytes([buffer[2], buffer[3]]),
+            team_id: u32::from_be_bytes([buffer[4], buffer[5], buffer[6], buffer[7]]),
+            state_start_time: u32::from_be_bytes([buffer[8], buffer[9], buffer[10], buffer[11]]),
+        })
+    }
+}
+
+fn encode_string(buffer: &mut Vec<u8>, value: &str) {
+    // UTF-8 문자 경계에서 자른다
+    let mut length = value.len().min(u8::MAX as usize);
+    while !value.is_char_boundary(length) {
+        length -= 1;
+    }
+
+    buffer.push(length as u8);
+    buffer.extend_from_slice(&value.as_bytes()[0..length]);
+}
+
+fn decode_string(buffer: &[u8]) -> Option<(String, &[u8])> {
+    let (&length, buffer) = buffer.split_first()?;
+    if buffer.len() < length as usize {
+        return None;
+    }
+
+    let (value, rest) = buffer.split_at(length as usize);
+    Some((String::from_utf8(value.to_vec()).ok()?, rest))
+}
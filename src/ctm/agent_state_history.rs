@@ -0,0 +1,96 @@
+use std::{
+    collections::VecDeque,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentStateTransition {
+    timestamp: u64,
+    old_state: u16,
+    new_state: u16,
+    reason_code: u16,
+}
+
+impl AgentStateTransition {
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn get_old_state(&self) -> u16 {
+        self.old_state
+    }
+
+    pub fn get_new_state(&self) -> u16 {
+        self.new_state
+    }
+
+    pub fn get_reason_code(&self) -> u16 {
+        self.reason_code
+    }
+}
+
+///
+/// 상담직원 상태 변경 이력
+///
+/// 최근 capacity 개의 상태 변경만 보관하며, 가득 차면 가장 오래된 이력부터 버린다.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentStateHistory {
+    cti_instance: String,
+    agent_id: String,
+    #[serde(skip)]
+    capacity: usize,
+    transitions: VecDeque<AgentStateTransition>,
+}
+
+impl AgentStateHistory {
+    pub fn new(agent_id: impl Into<String>, capacity: usize) -> Self {
+        Self {
+            cti_instance: "".to_string(),
+            agent_id: agent_id.into(),
+            capacity,
+            transitions: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn set_cti_instance(&mut self, cti_instance: impl Into<String>) {
+        self.cti_instance = cti_instance.into();
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_transitions(&self) -> &VecDeque<AgentStateTransition> {
+        &self.transitions
+    }
+
+    ///
+    /// 상태 변경 이력 추가
+    ///
+    pub fn push(&mut self, old_state: u16, new_state: u16, reason_code: u16) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.transitions.len() == self.capacity {
+            self.transitions.pop_front();
+        }
+
+        self.transitions.push_back(AgentStateTransition {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            old_state,
+            new_state,
+            reason_code,
+        });
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Serialize, Serializer};
+
+///
+/// 상담직원 일별 통계
+///
+/// 상태별 누적 시간(초), 통화 건수, 이석 사유코드별 이석 횟수를 집계하며
+/// 날짜가 바뀌면 자정 기준으로 초기화한다.
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentStatistics {
+    cti_instance: String,
+    agent_id: String,
+    #[serde(serialize_with = "serialize_date")]
+    date: NaiveDate,
+    state_durations: BTreeMap<u16, u64>,
+    call_count: u32,
+    not_ready_counts: BTreeMap<u16, u32>,
+    #[serde(skip)]
+    current_state: Option<u16>,
+    #[serde(skip)]
+    state_started_at: Option<NaiveDateTime>,
+}
+
+impl AgentStatistics {
+    pub fn new(agent_id: impl Into<String>, date: NaiveDate) -> Self {
+        Self {
+            cti_instance: "".to_string(),
+            agent_id: agent_id.into(),
+            date,
+            state_durations: BTreeMap::new(),
+            call_count: 0,
+            not_ready_counts: BTreeMap::new(),
+            current_state: None,
+            state_started_at: None,
+        }
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn set_cti_instance(&mut self, cti_instance: impl Into<String>) {
+        self.cti_instance = cti_instance.into();
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn get_state_duration(&self, agent_state: u16) -> u64 {
+        self.state_durations
+            .get(&agent_state)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn get_call_count(&self) -> u32 {
+        self.call_count
+    }
+
+    pub fn get_not_ready_count(&self, reason_code: u16) -> u32 {
+        self.not_ready_counts
+            .get(&reason_code)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    ///
+    /// 상태 변경 반영
+    ///
+    pub fn update_state(&mut self, agent_state: u16, reason_code: u16, now: NaiveDateTime) {
+        self.roll_over(now);
+        self.accumulate(now);
+
+        // 보류 해제(HOLD → TALKING)는 새로운 통화로 집계하지 않는다
+        if agent_state == 4 && !matches!(self.current_state, Some(4) | Some(10)) {
+            self.call_count += 1;
+        }
+
+        if agent_state == 2 && self.current_state != Some(2) {
+            *self.not_ready_counts.entry(reason_code).or_default() += 1;
+        }
+
+        self.current_state = Some(agent_state);
+        self.state_started_at = Some(now);
+    }
+
+    ///
+    /// 날짜가 바뀌었으면 전날 통계를 자정까지 마감하고 초기화한다
+    ///
+    /// 초기화된 경우 true 를 반환한다.
+    ///
+    pub fn roll_over(&mut self, now: NaiveDateTime) -> bool {
+        if now.date() <= self.date {
+            return false;
+        }
+
+        if let Some(next_midnight) = self
+            .date
+            .succ_opt()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+        {
+            self.accumulate(next_midnight);
+        }
+
+        self.date = now.date();
+        self.state_durations.clear();
+        self.call_count = 0;
+        self.not_ready_counts.clear();
+        if self.current_state.is_some() {
+            self.state_started_at = now.date().and_hms_opt(0, 0, 0);
+        }
+
+        true
+    }
+
+    ///
+    /// 현재 상태의 경과 시간까지 반영한 통계를 반환한다
+    ///
+    pub fn snapshot(&self, now: NaiveDateTime) -> Self {
+        let mut snapshot = self.clone();
+        snapshot.roll_over(now);
+        snapshot.accumulate(now);
+
+        snapshot
+    }
+
+    ///
+    /// 현재 상태의 시간을 until 까지 누적한다
+    ///
+    fn accumulate(&mut self, until: NaiveDateTime) {
+        if let (Some(agent_state), Some(state_started_at)) =
+            (self.current_state, self.state_started_at)
+        {
+            let seconds = (until - state_started_at).num_seconds().max(0) as u64;
+            *self.state_durations.entry(agent_state).or_default() += seconds;
+            self.state_started_at = Some(until.max(state_started_at));
+        }
+    }
+}
+
+fn serialize_date<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&date.format("%Y-%m-%d").to_string())
+}
@@ -0,0 +1,507 @@
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    error::Error,
+    fmt::Display,
+    hash::{BuildHasher, Hasher},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use serde_json::Value;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    sync::broadcast,
+    time::{sleep, timeout_at, Instant},
+};
+
+use super::{
+    acceptor::websocket_frame::{
+        WebsocketFrame, WEBSOCKET_OP_CODE_CLOSE_FRAME, WEBSOCKET_OP_CODE_PING_FRAME,
+        WEBSOCKET_OP_CODE_PONG_FRAME, WEBSOCKET_OP_CODE_TEXT_FRAME,
+    },
+    simulator::{CtiSimulator, ScriptedEvent, SimulatorScript},
+};
+
+///
+/// TCP 형식 협상 메시지와 구독 요청이 한 번에 읽히지 않도록 기다리는 시간
+///
+const BENCH_HANDSHAKE_DELAY: Duration = Duration::from_millis(100);
+
+///
+/// 수신 메시지 최대 크기
+///
+const BENCH_MAX_MESSAGE_SIZE: usize = 16 * 1_024 * 1_024;
+
+///
+/// 상담직원, 이석 사유코드 쌍 (이벤트 식별자)
+///
+type BenchEventKey = (String, u16);
+
+type BenchError = Box<dyn Error + Send + Sync>;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// 부하 테스트 클라이언트 프로토콜
+///
+pub enum BenchProtocol {
+    TCP,
+    WEBSOCKET,
+}
+
+impl BenchProtocol {
+    ///
+    /// 프로토콜 이름(tcp, websocket, ws)을 변환한다
+    ///
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "tcp" => Some(BenchProtocol::TCP),
+            "websocket" | "ws" => Some(BenchProtocol::WEBSOCKET),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+///
+/// 부하 테스트 설정
+///
+/// duration 동안 발생한 이벤트를 측정 대상으로 하며, 이후 drain_timeout 동안 늦게 도착한 이벤트를 더 기다린다.
+/// team_ids 가 비어 있으면 전체 구독이며, 구독 조건에 맞지 않는 이벤트는 누락으로 집계된다.
+///
+pub struct BenchConfig {
+    pub protocol: BenchProtocol,
+    pub address: String,
+    pub websocket_path: String,
+    pub connections: usize,
+    pub duration: Duration,
+    pub drain_timeout: Duration,
+    pub team_ids: Vec<u32>,
+}
+
+///
+/// 부하 테스트용 시뮬레이터 시나리오를 생성한다
+///
+/// 상담직원을 차례로 AVAILABLE, NOT_READY 로 바꾸며, 이석 사유코드에 일련번호(1 ~ 65535)를 실어
+/// 클라이언트가 받은 상담직원 이벤트를 시뮬레이터가 보낸 이벤트와 짝지을 수 있게 한다.
+///
+pub fn bench_script(agent_count: u32, event_interval: Duration) -> SimulatorScript {
+    let mut script = SimulatorScript::generated(agent_count);
+    if agent_count == 0 {
+        return script;
+    }
+
+    script.events = (0..u16::MAX as u32)
+        .map(|index| ScriptedEvent {
+            delay: event_interval.as_millis() as u64,
+            agent_id: (1001 + index % agent_count).to_string(),
+            agent_state: match (index / agent_count) % 2 {
+                0 => 3,
+                _ => 2,
+            },
+            reason_code: (index + 1) as u16,
+        })
+        .collect();
+
+    script
+}
+
+///
+/// 클라이언트가 받은 JSON 메시지에서 상담직원 이벤트 식별자를 읽는다
+///
+/// 버전 포함 형식(`{"version": 1, "payload": {...}}`)도 처리하며, 상담직원 이벤트가 아니면 None 을 반환한다.
+///
+pub fn parse_agent_event(payload: &[u8]) -> Option<(String, u16)> {
+    let value = serde_json::from_slice::<Value>(payload).ok()?;
+    let value = match value.get("version") {
+        Some(_) => value.get("payload")?,
+        None => &value,
+    };
+
+    Some((
+        value.get("agent_id")?.as_str()?.to_string(),
+        value.get("reason_code")?.as_u64()? as u16,
+    ))
+}
+
+#[derive(Debug, Clone, Default)]
+///
+/// 전송 지연 통계
+///
+pub struct LatencyStats {
+    samples: Vec<Duration>,
+}
+
+impl LatencyStats {
+    pub fn record(&mut self, latency: Duration) {
+        self.samples.push(latency);
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn min(&self) -> Option<Duration> {
+        self.samples.iter().min().copied()
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        self.samples.iter().max().copied()
+    }
+
+    pub fn mean(&self) -> Option<Duration> {
+        match self.samples.is_empty() {
+            true => None,
+            false => Some(self.samples.iter().sum::<Duration>() / self.samples.len() as u32),
+        }
+    }
+
+    ///
+    /// 백분위 값 (percentile: 0 ~ 100)
+    ///
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let mut samples = self.samples.clone();
+        samples.sort();
+        let index = ((samples.len() - 1) as f64 * percentile.clamp(0.0, 100.0) / 100.0).round();
+
+        Some(samples[index as usize])
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+///
+/// 부하 테스트 결과
+///
+pub struct BenchReport {
+    pub connections: usize,
+    pub connected: usize,
+    pub sent_events: usize,
+    pub expected_events: usize,
+    pub received_events: usize,
+    pub dropped_events: usize,
+    pub latency: LatencyStats,
+}
+
+impl BenchReport {
+    ///
+    /// 접속한 클라이언트가 받아야 할 이벤트 중 받지 못한 비율
+    ///
+    pub fn drop_rate(&self) -> f64 {
+        match self.expected_events {
+            0 => 0.0,
+            expected_events => self.dropped_events as f64 / expected_events as f64,
+        }
+    }
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let millis = |latency: Option<Duration>| {
+            latency
+                .map(|latency| format!("{:.3}ms", latency.as_secs_f64() * 1_000.0))
+                .unwrap_or("-".to_string())
+        };
+
+        writeln!(f, "connections: {}/{}", self.connected, self.connections)?;
+        writeln!(
+            f,
+            "events: sent {}, expected {}, received {}, dropped {} ({:.3}%)",
+            self.sent_events,
+            self.expected_events,
+            self.received_events,
+            self.dropped_events,
+            self.drop_rate() * 100.0
+        )?;
+        write!(
+            f,
+            "latency: min {}, avg {}, p50 {}, p95 {}, p99 {}, max {}",
+            millis(self.latency.min()),
+            millis(self.latency.mean()),
+            millis(self.latency.percentile(50.0)),
+            millis(self.latency.percentile(95.0)),
+            millis(self.latency.percentile(99.0)),
+            millis(self.latency.max())
+        )
+    }
+}
+
+///
+/// 부하 테스트를 실행한다
+///
+/// CTM 은 시뮬레이터에 접속해 있어야 하며, 시뮬레이터가 상태를 바꾼 시각부터
+/// 각 클라이언트가 해당 상담직원 이벤트를 받은 시각까지를 지연으로 측정한다.
+///
+pub async fn run_bench(config: BenchConfig, simulator: &CtiSimulator) -> BenchReport {
+    // 시뮬레이터 이벤트 발생 시각 기록
+    let sent_event_map = Arc::new(Mutex::new(HashMap::<BenchEventKey, Instant>::new()));
+    let mut simulated_event_rx = simulator.subscribe_events();
+    let recorder = {
+        let sent_event_map = sent_event_map.clone();
+        tokio::spawn(async move {
+            loop {
+                match simulated_event_rx.recv().await {
+                    Ok(simulated_event) => {
+                        sent_event_map.lock().unwrap().insert(
+                            (simulated_event.agent_id, simulated_event.reason_code),
+                            simulated_event.changed_at,
+                        );
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        log::warn!("Bench recorder lagged. skipped: {}", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return,
+                }
+            }
+        })
+    };
+
+    // 모든 클라이언트가 접속한 뒤부터 측정한다
+    let clients =
+        futures::future::join_all((0..config.connections).map(|_| BenchClient::connect(&config)))
+            .await;
+    let measure_start = Instant::now();
+    let measure_end = measure_start + config.duration;
+    let read_end = measure_end + config.drain_timeout;
+
+    let mut handles = vec![];
+    for client in clients {
+        match client {
+            Ok(client) => handles.push(tokio::spawn(client.receive_until(read_end))),
+            Err(e) => log::warn!("Bench client connection failed. {}", e),
+        }
+    }
+    log::info!(
+        "Bench clients connected. connected: {}, connections: {}",
+        handles.len(),
+        config.connections
+    );
+
+    let mut received_event_maps = vec![];
+    for handle in handles {
+        if let Ok(received_event_map) = handle.await {
+            received_event_maps.push(received_event_map);
+        }
+    }
+    recorder.abort();
+
+    // 측정 구간에 발생한 이벤트를 클라이언트별로 대조한다
+    let sent_event_map = sent_event_map.lock().unwrap();
+    let measured_events = sent_event_map
+        .iter()
+        .filter(|(_, sent_at)| **sent_at >= measure_start && **sent_at <= measure_end)
+        .collect::<Vec<_>>();
+
+    let mut report = BenchReport {
+        connections: config.connections,
+        connected: received_event_maps.len(),
+        sent_events: measured_events.len(),
+        expected_events: measured_events.len() * received_event_maps.len(),
+        ..Default::default()
+    };
+    for received_event_map in &received_event_maps {
+        for (key, sent_at) in &measured_events {
+            match received_event_map.get(*key) {
+                Some(received_at) => {
+                    report.received_events += 1;
+                    report
+                        .latency
+                        .record(received_at.saturating_duration_since(**sent_at));
+                }
+                None => report.dropped_events += 1,
+            }
+        }
+    }
+
+    report
+}
+
+///
+/// 부하 테스트 클라이언트 연결
+///
+enum BenchClient {
+    Tcp { stream: TcpStream, buffer: Vec<u8> },
+    Websocket { stream: TcpStream, buffer: Vec<u8> },
+}
+
+impl BenchClient {
+    ///
+    /// 접속 후 JSON 형식을 협상하고 구독을 요청한다
+    ///
+    async fn connect(config: &BenchConfig) -> Result<Self, BenchError> {
+        let mut stream = TcpStream::connect(&config.address).await?;
+        stream.set_nodelay(true)?;
+
+        let subscribe = serde_json::json!({
+            "command": "SUBSCRIBE",
+            "team_ids": config.team_ids,
+        })
+        .to_string();
+
+        match config.protocol {
+            BenchProtocol::TCP => {
+                stream.write_all(br#"{"format": "json"}"#).await?;
+                sleep(BENCH_HANDSHAKE_DELAY).await;
+                stream.write_all(subscribe.as_bytes()).await?;
+
+                Ok(BenchClient::Tcp {
+                    stream,
+                    buffer: vec![],
+                })
+            }
+            BenchProtocol::WEBSOCKET => {
+                let websocket_key = BASE64_STANDARD
+                    .encode([random_u64().to_be_bytes(), random_u64().to_be_bytes()].concat());
+                stream
+                    .write_all(
+                        format!(
+                            "GET {}?format=json HTTP/1.1\r\nHost: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {}\r\nSec-WebSocket-Version: 13\r\n\r\n",
+                            config.websocket_path, config.address, websocket_key
+                        )
+                        .as_bytes(),
+                    )
+                    .await?;
+
+                // 업그레이드 응답 헤더를 읽고, 뒤에 이어 온 데이터는 프레임 버퍼에 남긴다
+                let mut buffer = vec![];
+                let header_length = loop {
+                    if let Some(index) = buffer.windows(4).position(|bytes| bytes == b"\r\n\r\n") {
+                        break index + 4;
+                    }
+                    if read_into(&mut stream, &mut buffer).await? == 0 {
+                        return Err("Connection closed during websocket upgrade".into());
+                    }
+                };
+                let status_line = String::from_utf8_lossy(&buffer[..header_length])
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string();
+                if status_line.split_whitespace().nth(1) != Some("101") {
+                    return Err(format!("Websocket upgrade rejected. {}", status_line).into());
+                }
+                buffer.drain(..header_length);
+
+                let subscribe =
+                    WebsocketFrame::new(true, WEBSOCKET_OP_CODE_TEXT_FRAME, subscribe.as_bytes());
+                stream
+                    .write_all(&subscribe.encode_masked(random_mask()))
+                    .await?;
+
+                Ok(BenchClient::Websocket { stream, buffer })
+            }
+        }
+    }
+
+    ///
+    /// 종료 시각까지 메시지를 받아 상담직원 이벤트별 최초 수신 시각을 반환한다
+    ///
+    async fn receive_until(mut self, read_end: Instant) -> HashMap<BenchEventKey, Instant> {
+        let mut received_event_map = HashMap::new();
+
+        loop {
+            let messages = match timeout_at(read_end, self.read_messages()).await {
+                Ok(Ok(messages)) => messages,
+                Ok(Err(e)) => {
+                    log::warn!("Bench client stopped receiving. {}", e);
+                    break;
+                }
+                Err(_) => break,
+            };
+
+            let received_at = Instant::now();
+            for message in messages {
+                if let Some(key) = parse_agent_event(&message) {
+                    received_event_map.entry(key).or_insert(received_at);
+                }
+            }
+        }
+
+        received_event_map
+    }
+
+    ///
+    /// 완성된 메시지를 하나 이상 읽는다
+    ///
+    async fn read_messages(&mut self) -> Result<Vec<Vec<u8>>, BenchError> {
+        loop {
+            let messages = match self {
+                // JSON 형식은 줄바꿈으로 메시지를 구분한다
+                BenchClient::Tcp { buffer, .. } => {
+                    let mut messages = vec![];
+                    while let Some(index) = buffer.iter().position(|b| *b == b'\n') {
+                        let mut message = buffer.drain(..=index).collect::<Vec<_>>();
+                        message.pop();
+                        messages.push(message);
+                    }
+                    messages
+                }
+                BenchClient::Websocket { stream, buffer } => {
+                    let mut messages = vec![];
+                    while let Some((frame, length)) =
+                        WebsocketFrame::decode_server(buffer, BENCH_MAX_MESSAGE_SIZE)?
+                    {
+                        buffer.drain(..length);
+                        match frame.get_op_code() {
+                            WEBSOCKET_OP_CODE_TEXT_FRAME => {
+                                messages.push(frame.get_payload().to_vec())
+                            }
+                            WEBSOCKET_OP_CODE_PING_FRAME => {
+                                let pong = WebsocketFrame::new(
+                                    true,
+                                    WEBSOCKET_OP_CODE_PONG_FRAME,
+                                    frame.get_payload(),
+                                );
+                                stream.write_all(&pong.encode_masked(random_mask())).await?;
+                            }
+                            WEBSOCKET_OP_CODE_CLOSE_FRAME => {
+                                return Err("Websocket closed by server".into())
+                            }
+                            _ => {}
+                        }
+                    }
+                    messages
+                }
+            };
+            if !messages.is_empty() {
+                return Ok(messages);
+            }
+
+            let (stream, buffer) = match self {
+                BenchClient::Tcp { stream, buffer } => (stream, buffer),
+                BenchClient::Websocket { stream, buffer } => (stream, buffer),
+            };
+            if read_into(stream, buffer).await? == 0 {
+                return Err("Connection closed by server".into());
+            }
+        }
+    }
+}
+
+///
+/// 소켓에서 읽은 데이터를 버퍼 뒤에 덧붙인다
+///
+async fn read_into(stream: &mut TcpStream, buffer: &mut Vec<u8>) -> Result<usize, BenchError> {
+    let mut chunk = [0_u8; 8_192];
+    let n = stream.read(&mut chunk).await?;
+    buffer.extend_from_slice(&chunk[..n]);
+
+    Ok(n)
+}
+
+fn random_u64() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+fn random_mask() -> [u8; 4] {
+    (random_u64() as u32).to_be_bytes()
+}
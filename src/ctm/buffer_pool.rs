@@ -0,0 +1,130 @@
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use bytes::BytesMut;
+
+///
+/// 풀에서 새로 만드는 버퍼의 기본 용량 (바이트)
+///
+pub const BUFFER_POOL_BUFFER_CAPACITY: usize = 4_096;
+
+///
+/// 풀에 되돌려 보관하는 버퍼의 최대 용량 (바이트)
+///
+/// 큰 전체 상태를 보내느라 커진 버퍼는 보관하지 않고 해제해 메모리를 오래 잡지 않게 한다.
+///
+pub const BUFFER_POOL_MAX_RETAINED_CAPACITY: usize = 65_536;
+
+///
+/// 풀에 보관하는 최대 버퍼 수
+///
+pub const BUFFER_POOL_MAX_BUFFERS: usize = 256;
+
+static SEND_BUFFER_POOL: OnceLock<BufferPool> = OnceLock::new();
+
+///
+/// 클라이언트 전송 버퍼 풀
+///
+/// 모든 Acceptor 가 함께 사용한다.
+///
+pub fn send_buffer_pool() -> &'static BufferPool {
+    SEND_BUFFER_POOL.get_or_init(|| {
+        BufferPool::new(
+            BUFFER_POOL_BUFFER_CAPACITY,
+            BUFFER_POOL_MAX_RETAINED_CAPACITY,
+            BUFFER_POOL_MAX_BUFFERS,
+        )
+    })
+}
+
+///
+/// 재사용 버퍼 풀
+///
+/// 메시지마다 버퍼를 할당하지 않도록 다 쓴 버퍼를 비워 보관했다가 다시 빌려준다.
+/// 빌린 버퍼는 드롭되면 풀로 돌아간다.
+///
+#[derive(Debug, Clone)]
+pub struct BufferPool {
+    buffers: Arc<Mutex<Vec<BytesMut>>>,
+    buffer_capacity: usize,
+    max_retained_capacity: usize,
+    max_buffers: usize,
+}
+
+impl BufferPool {
+    pub fn new(buffer_capacity: usize, max_retained_capacity: usize, max_buffers: usize) -> Self {
+        Self {
+            buffers: Arc::new(Mutex::new(Vec::with_capacity(max_buffers))),
+            buffer_capacity,
+            max_retained_capacity: max_retained_capacity.max(buffer_capacity),
+            max_buffers,
+        }
+    }
+
+    ///
+    /// 빈 버퍼를 빌린다
+    ///
+    pub fn get(&self) -> PooledBuffer {
+        let buffer = self
+            .buffers
+            .lock()
+            .unwrap()
+            .pop()
+            .unwrap_or_else(|| BytesMut::with_capacity(self.buffer_capacity));
+
+        PooledBuffer {
+            buffer,
+            pool: self.clone(),
+        }
+    }
+
+    ///
+    /// 풀에 보관 중인 버퍼 수
+    ///
+    pub fn get_pooled_count(&self) -> usize {
+        self.buffers.lock().unwrap().len()
+    }
+
+    fn put(&self, mut buffer: BytesMut) {
+        if buffer.capacity() > self.max_retained_capacity {
+            return;
+        }
+
+        buffer.clear();
+        let mut buffers = self.buffers.lock().unwrap();
+        if buffers.len() < self.max_buffers {
+            buffers.push(buffer);
+        }
+    }
+}
+
+///
+/// 풀에서 빌린 버퍼
+///
+#[derive(Debug)]
+pub struct PooledBuffer {
+    buffer: BytesMut,
+    pool: BufferPool,
+}
+
+impl Deref for PooledBuffer {
+    type Target = BytesMut;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl DerefMut for PooledBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+impl Drop for PooledBuffer {
+    fn drop(&mut self) {
+        self.pool.put(std::mem::take(&mut self.buffer));
+    }
+}
@@ -0,0 +1,78 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use crate::cisco::ConnectionCallId;
+
+use super::call_info::CallInfo;
+
+///
+/// 호 상태 일괄 전송 버퍼
+///
+/// All Events 세션은 주변장치 전체 상담직원의 호 이벤트를 받으므로 이벤트마다 전송하지 않고
+/// interval 동안 모아서 보낸다. 같은 호의 상태가 여러 번 바뀌면 마지막 상태만 보내며,
+/// 모은 호가 max_size 에 이르면 interval 전이라도 보낸다.
+///
+#[derive(Debug)]
+pub struct CallBroadcastBatch {
+    interval: Duration,
+    max_size: usize,
+    last_flush: Instant,
+    // 처음 바뀐 순서대로 보낸다
+    call_infos: Vec<CallInfo>,
+    call_index_map: HashMap<ConnectionCallId, usize>,
+}
+
+impl CallBroadcastBatch {
+    pub fn new(interval: Duration, max_size: usize, now: Instant) -> Self {
+        Self {
+            interval,
+            max_size: max_size.max(1),
+            last_flush: now,
+            call_infos: vec![],
+            call_index_map: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.call_infos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.call_infos.is_empty()
+    }
+
+    ///
+    /// 바뀐 호 상태를 추가한다 (이미 있는 호는 마지막 상태로 바꾼다)
+    ///
+    pub fn push(&mut self, call_info: CallInfo) {
+        match self.call_index_map.get(&call_info.get_connection_call_id()) {
+            Some(index) => self.call_infos[*index] = call_info,
+            None => {
+                self.call_index_map
+                    .insert(call_info.get_connection_call_id(), self.call_infos.len());
+                self.call_infos.push(call_info);
+            }
+        }
+    }
+
+    ///
+    /// 전송할 때가 되었는지 확인한다
+    ///
+    pub fn is_due(&self, now: Instant) -> bool {
+        !self.is_empty()
+            && (self.call_infos.len() >= self.max_size
+                || now.saturating_duration_since(self.last_flush) >= self.interval)
+    }
+
+    ///
+    /// 모은 호 상태를 꺼낸다
+    ///
+    pub fn take(&mut self, now: Instant) -> Vec<CallInfo> {
+        self.last_flush = now;
+        self.call_index_map.clear();
+
+        std::mem::take(&mut self.call_infos)
+    }
+}
@@ -0,0 +1,137 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+
+use crate::cisco::{CallType, ConnectionCallId};
+
+use super::field_masker::FieldMasker;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// 호 상태
+///
+pub enum CallState {
+    // BEGIN_CALL_EVENT 수신
+    BEGIN,
+    // CALL_QUEUED_EVENT 수신
+    QUEUED,
+    // CALL_ESTABLISHED_EVENT 수신
+    ESTABLISHED,
+    // CALL_CLEARED_EVENT 수신
+    CLEARED,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct CallInfo {
+    cti_instance: String,
+    connection_call_id: ConnectionCallId,
+    connection_device_id: String,
+    call_type: CallType,
+    call_state: CallState,
+    ani: String,
+    dnis: String,
+    dialed_number: String,
+    agent_id: String,
+    agent_extension: String,
+    skill_group_id: u32,
+    begin_time: u64,
+    established_time: u64,
+}
+
+impl CallInfo {
+    pub fn new(connection_call_id: ConnectionCallId) -> Self {
+        Self {
+            cti_instance: "".to_string(),
+            connection_call_id,
+            connection_device_id: "".to_string(),
+            call_type: CallType::UNKNOWN(0),
+            call_state: CallState::BEGIN,
+            ani: "".to_string(),
+            dnis: "".to_string(),
+            dialed_number: "".to_string(),
+            agent_id: "".to_string(),
+            agent_extension: "".to_string(),
+            skill_group_id: 0,
+            begin_time: now(),
+            established_time: 0,
+        }
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn set_cti_instance(&mut self, cti_instance: impl Into<String>) {
+        self.cti_instance = cti_instance.into();
+    }
+
+    pub fn get_connection_call_id(&self) -> ConnectionCallId {
+        self.connection_call_id
+    }
+
+    pub fn get_call_state(&self) -> CallState {
+        self.call_state
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_skill_group_id(&self) -> u32 {
+        self.skill_group_id
+    }
+
+    pub fn set_connection_device_id(&mut self, connection_device_id: impl Into<String>) {
+        self.connection_device_id = connection_device_id.into();
+    }
+
+    pub fn set_call_type(&mut self, call_type: CallType) {
+        self.call_type = call_type;
+    }
+
+    pub fn set_call_state(&mut self, call_state: CallState) {
+        if call_state == CallState::ESTABLISHED && self.call_state != CallState::ESTABLISHED {
+            // 최초 연결 시각만 기록한다 (보류 해제 등으로 재수신될 수 있음)
+            self.established_time = now();
+        }
+        self.call_state = call_state;
+    }
+
+    pub fn set_ani(&mut self, ani: impl Into<String>) {
+        self.ani = ani.into();
+    }
+
+    pub fn set_dnis(&mut self, dnis: impl Into<String>) {
+        self.dnis = dnis.into();
+    }
+
+    pub fn set_dialed_number(&mut self, dialed_number: impl Into<String>) {
+        self.dialed_number = dialed_number.into();
+    }
+
+    pub fn set_agent(&mut self, agent_id: impl Into<String>, agent_extension: impl Into<String>) {
+        self.agent_id = agent_id.into();
+        self.agent_extension = agent_extension.into();
+    }
+
+    ///
+    /// 클라이언트 전송 전 개인정보 필드를 마스킹한다
+    ///
+    pub fn mask_fields(&mut self, field_masker: &FieldMasker) {
+        self.ani = field_masker.mask(&self.ani);
+        self.agent_extension = field_masker.mask(&self.agent_extension);
+    }
+
+    pub fn set_skill_group_id(&mut self, skill_group_id: u32) {
+        self.skill_group_id = skill_group_id;
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
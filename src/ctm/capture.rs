@@ -0,0 +1,317 @@
+use std::{
+    error::Error,
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use chrono::Local;
+use futures::StreamExt;
+use tokio::{
+    io::{AsyncRead, AsyncWrite, AsyncWriteExt, ReadBuf},
+    sync::mpsc,
+};
+use tokio_util::codec::FramedRead;
+
+use crate::{cisco::codec::CtiCodec, event::cti_event::CTIEvent};
+
+///
+/// 캡처 파일 식별자
+///
+pub const CAPTURE_MAGIC: [u8; 8] = *b"CTMCAP01";
+
+///
+/// 재생 시 한 번에 기록하는 파이프 버퍼 크기
+///
+const CAPTURE_REPLAY_BUFFER_SIZE: usize = 65_536;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// 캡처 레코드
+///
+/// CTI 서버 소켓에서 한 번에 읽은 원본 바이트를 수신 시각(마이크로초)과 함께 보관한다.
+/// 메시지 경계와 무관하게 읽은 그대로 기록하므로 프레이밍 오류도 재현할 수 있다.
+///
+pub struct CaptureRecord {
+    pub timestamp: i64,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// 캡처 파일 내용
+///
+pub struct Capture {
+    pub cti_instance: String,
+    pub cti_server_host: String,
+    pub records: Vec<CaptureRecord>,
+}
+
+///
+/// CTI 원본 트래픽 캡처 기록기
+///
+/// 파일 헤더(식별자, 인스턴스 이름, 서버 주소) 뒤에 레코드를
+/// `timestamp(i64) + length(u32) + data` 형식(빅 엔디언)으로 이어 붙인다.
+///
+pub struct CaptureWriter {
+    path: PathBuf,
+    writer: BufWriter<File>,
+}
+
+impl CaptureWriter {
+    ///
+    /// 세션 캡처 기록기를 생성한다
+    ///
+    /// 파일을 열 수 없으면 오류를 남기고 캡처하지 않는다.
+    ///
+    pub fn open(directory: &str, cti_instance: &str, cti_server_host: &str) -> Option<Self> {
+        match Self::create(directory, cti_instance, cti_server_host) {
+            Ok(capture_writer) => {
+                log::info!(
+                    "Opened CTI capture file. path: {}",
+                    capture_writer.path.display()
+                );
+                Some(capture_writer)
+            }
+            Err(e) => {
+                log::error!("Unable to open CTI capture file. {}", e);
+                None
+            }
+        }
+    }
+
+    ///
+    /// 디렉토리에 새 캡처 파일을 생성한다
+    ///
+    pub fn create(
+        directory: impl AsRef<Path>,
+        cti_instance: &str,
+        cti_server_host: &str,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(&directory)?;
+        let path = directory.as_ref().join(format!(
+            "ctm_capture_{}_{}.cap",
+            cti_instance,
+            Local::now().format("%Y%m%d%H%M%S%3f")
+        ));
+
+        let mut writer = BufWriter::new(File::create(&path)?);
+        writer.write_all(&CAPTURE_MAGIC)?;
+        write_string(&mut writer, cti_instance)?;
+        write_string(&mut writer, cti_server_host)?;
+        writer.flush()?;
+
+        Ok(Self { path, writer })
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    ///
+    /// 수신한 원본 바이트를 현재 시각으로 기록한다
+    ///
+    pub fn write(&mut self, data: &[u8]) -> io::Result<()> {
+        self.write_record(Local::now().timestamp_micros(), data)
+    }
+
+    ///
+    /// 수신한 원본 바이트를 지정한 시각으로 기록한다
+    ///
+    pub fn write_record(&mut self, timestamp: i64, data: &[u8]) -> io::Result<()> {
+        self.writer.write_all(&timestamp.to_be_bytes())?;
+        self.writer.write_all(&(data.len() as u32).to_be_bytes())?;
+        self.writer.write_all(data)?;
+        self.writer.flush()
+    }
+}
+
+///
+/// 읽은 데이터를 캡처 파일에 기록하는 스트림
+///
+/// 캡처 기록기가 없으면 원본 스트림과 동일하게 동작하며, 기록에 실패하면 캡처만 중단한다.
+///
+pub struct CaptureStream<S> {
+    inner: S,
+    capture_writer: Option<CaptureWriter>,
+}
+
+impl<S> CaptureStream<S> {
+    pub fn new(inner: S, capture_writer: Option<CaptureWriter>) -> Self {
+        Self {
+            inner,
+            capture_writer,
+        }
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for CaptureStream<S> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let filled = buf.filled().len();
+        let result = Pin::new(&mut self.inner).poll_read(cx, buf);
+
+        if let (Poll::Ready(Ok(())), Some(capture_writer)) = (&result, self.capture_writer.as_mut())
+        {
+            let data = &buf.filled()[filled..];
+            if !data.is_empty() {
+                if let Err(e) = capture_writer.write(data) {
+                    log::error!(
+                        "Stopped CTI capture. path: {}, error: {}",
+                        capture_writer.path.display(),
+                        e
+                    );
+                    self.capture_writer = None;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for CaptureStream<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+///
+/// 캡처 파일을 모두 읽는다
+///
+pub fn read_capture(path: impl AsRef<Path>) -> Result<Capture, Box<dyn Error>> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    let mut magic = [0_u8; 8];
+    reader.read_exact(&mut magic)?;
+    if magic != CAPTURE_MAGIC {
+        return Err("Invalid CTI capture file".into());
+    }
+    let cti_instance = read_string(&mut reader)?;
+    let cti_server_host = read_string(&mut reader)?;
+
+    let mut records = vec![];
+    loop {
+        let mut timestamp = [0_u8; 8];
+        match reader.read_exact(&mut timestamp) {
+            Ok(_) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e.into()),
+        }
+        let mut length = [0_u8; 4];
+        reader.read_exact(&mut length)?;
+        let mut data = vec![0_u8; u32::from_be_bytes(length) as usize];
+        reader.read_exact(&mut data)?;
+
+        records.push(CaptureRecord {
+            timestamp: i64::from_be_bytes(timestamp),
+            data,
+        });
+    }
+
+    Ok(Capture {
+        cti_instance,
+        cti_server_host,
+        records,
+    })
+}
+
+///
+/// 캡처한 원본 바이트를 CTI 메시지 프레이밍 코덱으로 다시 디코딩해 CTI 이벤트 채널로 재생한다
+///
+/// 레코드 사이의 시간 간격을 replay_speed 배속으로 유지하며, 0 이면 지연 없이 재생한다.
+/// CTIClient 와 같은 코덱을 거치므로 손상된 메시지는 같은 방식으로 버려진다.
+///
+pub async fn replay_capture(
+    capture: Capture,
+    replay_speed: f64,
+    cti_event_channel_tx: mpsc::Sender<CTIEvent>,
+) {
+    let (mut writer, reader) = tokio::io::duplex(CAPTURE_REPLAY_BUFFER_SIZE);
+
+    let records = capture.records;
+    tokio::spawn(async move {
+        let mut last_timestamp: Option<i64> = None;
+        for record in records {
+            if let Some(last_timestamp) = last_timestamp {
+                let gap = (record.timestamp - last_timestamp).max(0) as f64;
+                if replay_speed > 0.0 {
+                    tokio::time::sleep(Duration::from_micros((gap / replay_speed) as u64)).await;
+                }
+            }
+            last_timestamp = Some(record.timestamp);
+
+            if writer.write_all(&record.data).await.is_err() {
+                return;
+            }
+        }
+    });
+
+    let mut client_stream = FramedRead::new(reader, CtiCodec::default());
+    while let Some(message) = client_stream.next().await {
+        match message {
+            Ok(Ok((message_type, data))) => {
+                if let Err(e) = cti_event_channel_tx
+                    .send(CTIEvent::Recevied {
+                        span: tracing::info_span!(
+                            "capture.replay",
+                            cti_instance = %capture.cti_instance,
+                            message_type = ?message_type,
+                        ),
+                        cti_instance: capture.cti_instance.clone(),
+                        cti_server_host: capture.cti_server_host.clone(),
+                        message_type,
+                        data,
+                    })
+                    .await
+                {
+                    log::error!("Unable to replay capture record. {}", e);
+                    return;
+                }
+            }
+            Ok(Err(e)) => log::error!("Discarded invalid CTI message while replaying. {}", e),
+            Err(e) => {
+                log::error!("Unable to replay capture. {}", e);
+                return;
+            }
+        }
+    }
+
+    log::info!("Finished replaying capture.");
+}
+
+fn write_string(writer: &mut impl Write, value: &str) -> io::Result<()> {
+    writer.write_all(&(value.len() as u16).to_be_bytes())?;
+    writer.write_all(value.as_bytes())
+}
+
+fn read_string(reader: &mut impl Read) -> Result<String, Box<dyn Error>> {
+    let mut length = [0_u8; 2];
+    reader.read_exact(&mut length)?;
+    let mut value = vec![0_u8; u16::from_be_bytes(length) as usize];
+    reader.read_exact(&mut value)?;
+
+    Ok(String::from_utf8(value)?)
+}
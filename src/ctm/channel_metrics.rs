@@ -0,0 +1,97 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+///
+/// 브로커 채널 지표
+///
+/// 브로커 채널 용량과, 처리가 늦어 이벤트를 놓친 클라이언트 수신기(lagged) 및 전체 상태 재전송(resync) 횟수를 기록한다.
+///
+#[derive(Debug, Default)]
+pub struct ChannelMetrics {
+    broker_event_capacity: AtomicUsize,
+    lagged_total: AtomicU64,
+    lagged_events_total: AtomicU64,
+    resync_total: AtomicU64,
+}
+
+impl ChannelMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_broker_event_capacity(&self) -> usize {
+        self.broker_event_capacity.load(Ordering::Relaxed)
+    }
+
+    pub fn set_broker_event_capacity(&self, broker_event_capacity: usize) {
+        self.broker_event_capacity
+            .store(broker_event_capacity, Ordering::Relaxed);
+    }
+
+    pub fn get_lagged_total(&self) -> u64 {
+        self.lagged_total.load(Ordering::Relaxed)
+    }
+
+    pub fn get_lagged_events_total(&self) -> u64 {
+        self.lagged_events_total.load(Ordering::Relaxed)
+    }
+
+    pub fn get_resync_total(&self) -> u64 {
+        self.resync_total.load(Ordering::Relaxed)
+    }
+
+    ///
+    /// 수신기 지연 기록
+    ///
+    pub fn record_lagged(&self, skipped: u64) {
+        self.lagged_total.fetch_add(1, Ordering::Relaxed);
+        self.lagged_events_total
+            .fetch_add(skipped, Ordering::Relaxed);
+    }
+
+    ///
+    /// 전체 상태 재전송 기록
+    ///
+    pub fn record_resync(&self) {
+        self.resync_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///
+    /// Prometheus 텍스트 형식으로 변환
+    ///
+    pub fn to_prometheus(&self) -> String {
+        [
+            (
+                "ctm_broker_channel_capacity",
+                "gauge",
+                "Broker event channel capacity",
+                self.get_broker_event_capacity() as u64,
+            ),
+            (
+                "ctm_broker_channel_lagged_total",
+                "counter",
+                "Number of times a client receiver lagged behind the broker event channel",
+                self.get_lagged_total(),
+            ),
+            (
+                "ctm_broker_channel_lagged_events_total",
+                "counter",
+                "Number of broker events skipped by lagging client receivers",
+                self.get_lagged_events_total(),
+            ),
+            (
+                "ctm_client_resync_total",
+                "counter",
+                "Number of full state resyncs sent to lagging clients",
+                self.get_resync_total(),
+            ),
+        ]
+        .iter()
+        .map(|(name, metric_type, help, value)| {
+            format!(
+                "# HELP {} {}\n# TYPE {} {}\n{} {}\n",
+                name, help, name, metric_type, name, value
+            )
+        })
+        .collect()
+    }
+}
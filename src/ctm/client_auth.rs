@@ -0,0 +1,196 @@
+use std::{collections::HashMap, error::Error, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use super::config::ClientAuthConfig;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+///
+/// 클라이언트 권한
+///
+/// 상위 권한은 하위 권한의 명령을 모두 허용한다.
+///
+pub enum ClientPermission {
+    // 이벤트 수신, 조회 명령만 허용
+    READ_ONLY,
+    // CTI 서버에 요청을 보내는 제어 명령까지 허용
+    CONTROL,
+    // 클라이언트 목록 조회, 강제 종료 등 관리 명령까지 허용 (인증 사용 시에만 부여)
+    ADMIN,
+}
+
+///
+/// 인증 키 파일 항목
+///
+/// 권한만 지정하거나(`"READ_ONLY"`), 권한과 부서를 함께 지정한다(`{"permission": "READ_ONLY", "department_id": 1}`).
+///
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ClientAuthKey {
+    Permission(ClientPermission),
+    Department {
+        permission: ClientPermission,
+        department_id: i32,
+    },
+}
+
+///
+/// 클라이언트 로그인 메시지
+///
+/// `{"token": "..."}` 형식의 MessagePack 또는 JSON 으로 수신한다.
+///
+#[derive(Debug, Deserialize)]
+struct ClientLogin {
+    token: String,
+}
+
+///
+/// 클라이언트 인증 키 저장소
+///
+/// CLIENT_AUTH_FILE 에 지정된 JSON 파일(`{"key": "READ_ONLY", "key2": "CONTROL", "key3": "ADMIN"}`)을 읽어 생성한다.
+/// 인증을 사용하지 않으면 모든 클라이언트에 제어 권한을 부여한다.
+///
+/// 부서를 지정한 키로 접속한 클라이언트는 해당 부서 상담직원의 이벤트만 받는다.
+/// department_required 이면 부서를 지정하지 않은 키는 관리 권한 키만 허용한다.
+///
+#[derive(Debug, Clone, Default)]
+pub struct ClientAuthenticator {
+    enabled: bool,
+    api_keys: HashMap<String, ClientPermission>,
+    departments: HashMap<String, i32>,
+    department_required: bool,
+}
+
+impl ClientAuthenticator {
+    pub fn new(enabled: bool, api_keys: HashMap<String, ClientPermission>) -> Self {
+        Self {
+            enabled,
+            api_keys,
+            departments: HashMap::new(),
+            department_required: false,
+        }
+    }
+
+    ///
+    /// 키별 부서를 설정한다
+    ///
+    pub fn with_departments(mut self, departments: HashMap<String, i32>) -> Self {
+        self.departments = departments;
+        self
+    }
+
+    ///
+    /// 부서 지정 필수 여부를 설정한다
+    ///
+    pub fn with_department_required(mut self, department_required: bool) -> Self {
+        self.department_required = department_required;
+        self
+    }
+
+    ///
+    /// 설정으로 인증 키 저장소를 생성한다
+    ///
+    /// 인증을 사용하는데 키 파일을 읽을 수 없으면 모든 클라이언트 접속을 거부한다.
+    /// CLIENT_AUTH_DEPARTMENT_REQUIRED 를 사용하면 부서를 지정하지 않은 키로는 관리 권한만 접속할 수 있다.
+    ///
+    pub fn load(config: &ClientAuthConfig) -> Self {
+        if !config.enabled {
+            return Self::default();
+        }
+
+        let path = &config.file;
+        match Self::from_file(path) {
+            Ok(authenticator) => authenticator.with_department_required(config.department_required),
+            Err(e) => {
+                log::error!(
+                    "Unable to load client auth keys. path: {}, error: {}",
+                    path,
+                    e
+                );
+                Self::new(true, HashMap::new())
+            }
+        }
+    }
+
+    ///
+    /// JSON 파일로 인증 키 저장소를 생성한다
+    ///
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)?;
+        let client_auth_keys = serde_json::from_str::<HashMap<String, ClientAuthKey>>(&text)?;
+
+        let mut api_keys = HashMap::new();
+        let mut departments = HashMap::new();
+        for (key, client_auth_key) in client_auth_keys {
+            match client_auth_key {
+                ClientAuthKey::Permission(permission) => {
+                    api_keys.insert(key, permission);
+                }
+                ClientAuthKey::Department {
+                    permission,
+                    department_id,
+                } => {
+                    api_keys.insert(key.clone(), permission);
+                    departments.insert(key, department_id);
+                }
+            }
+        }
+
+        Ok(Self::new(true, api_keys).with_departments(departments))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    ///
+    /// 토큰을 검증하여 클라이언트 권한을 반환한다
+    ///
+    /// 인증을 사용하지 않으면 토큰과 관계없이 제어 권한을 반환한다.
+    /// 부서 지정이 필수이면 부서가 없는 관리 권한 미만의 키는 거부한다.
+    ///
+    pub fn authenticate(&self, token: Option<&str>) -> Option<ClientPermission> {
+        if !self.enabled {
+            return Some(ClientPermission::CONTROL);
+        }
+
+        let token = token?;
+        let permission = self.api_keys.get(token).copied()?;
+        if self.department_required
+            && permission < ClientPermission::ADMIN
+            && !self.departments.contains_key(token)
+        {
+            log::warn!(
+                "Client auth key has no department. permission: {:?}",
+                permission
+            );
+            return None;
+        }
+
+        Some(permission)
+    }
+
+    ///
+    /// 토큰에 지정된 부서 ID (부서를 지정하지 않았거나 인증을 사용하지 않으면 None)
+    ///
+    pub fn get_department_id(&self, token: Option<&str>) -> Option<i32> {
+        if !self.enabled {
+            return None;
+        }
+
+        token.and_then(|token| self.departments.get(token).copied())
+    }
+
+    ///
+    /// 클라이언트가 전송한 로그인 메시지에서 토큰을 읽는다
+    ///
+    pub fn parse_login(data: &[u8]) -> Option<String> {
+        let login = match rmp_serde::from_slice::<ClientLogin>(data) {
+            Ok(login) => login,
+            Err(_) => serde_json::from_slice::<ClientLogin>(data).ok()?,
+        };
+
+        Some(login.token)
+    }
+}
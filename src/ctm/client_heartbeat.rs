@@ -0,0 +1,68 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// 연결 확인 메시지 종류
+///
+pub enum ClientHeartbeatType {
+    // 연결 확인 요청
+    PING,
+    // 연결 확인 응답
+    PONG,
+}
+
+///
+/// 클라이언트가 보낸 연결 확인 메시지
+///
+#[derive(Debug, Deserialize)]
+struct ClientHeartbeatCommand {
+    command: ClientHeartbeatType,
+}
+
+#[derive(Debug, Clone, Serialize)]
+///
+/// 연결 확인 메시지 (웹 소켓 제어 프레임이 없는 TCP 클라이언트용)
+///
+/// 서버는 수신이 없는 클라이언트에 `{"event": "PING"}` 을 보내고 클라이언트는 `{"command": "PONG"}` 으로,
+/// 클라이언트가 `{"command": "PING"}` 을 보내면 서버는 `{"event": "PONG"}` 으로 응답한다.
+/// timestamp 는 UNIX 시간(밀리초)이다.
+///
+pub struct ClientHeartbeat {
+    event: ClientHeartbeatType,
+    timestamp: u64,
+}
+
+impl ClientHeartbeat {
+    pub fn new(event: ClientHeartbeatType) -> Self {
+        Self {
+            event,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    ///
+    /// 클라이언트가 보낸 데이터가 연결 확인 메시지이면 종류를 반환한다
+    ///
+    pub fn parse(data: &[u8]) -> Option<ClientHeartbeatType> {
+        let command = match rmp_serde::from_slice::<ClientHeartbeatCommand>(data) {
+            Ok(command) => command,
+            Err(_) => serde_json::from_slice::<ClientHeartbeatCommand>(data).ok()?,
+        };
+
+        Some(command.command)
+    }
+
+    pub fn get_event(&self) -> ClientHeartbeatType {
+        self.event
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
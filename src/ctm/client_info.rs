@@ -0,0 +1,73 @@
+use std::{
+    net::SocketAddr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::{client_auth::ClientPermission, client_subscription::ClientSubscription};
+
+#[derive(Debug, Clone, Serialize)]
+///
+/// 접속 중인 클라이언트 정보 (관리 명령 LIST_CLIENTS 응답)
+///
+/// addr 은 내부 클라이언트(발행기 등)인 경우 None 이며, connected_at 은 UNIX 시간(초)이다.
+///
+pub struct ClientInfo {
+    id: String,
+    addr: Option<String>,
+    permission: ClientPermission,
+    common_name: Option<String>,
+    connected_at: u64,
+    subscription: ClientSubscription,
+}
+
+impl ClientInfo {
+    pub fn new(
+        id: Uuid,
+        addr: Option<SocketAddr>,
+        permission: ClientPermission,
+        common_name: Option<String>,
+    ) -> Self {
+        Self {
+            id: id.to_string(),
+            addr: addr.map(|addr| addr.to_string()),
+            permission,
+            common_name,
+            connected_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            subscription: ClientSubscription::default(),
+        }
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_addr(&self) -> Option<&str> {
+        self.addr.as_deref()
+    }
+
+    pub fn get_permission(&self) -> ClientPermission {
+        self.permission
+    }
+
+    pub fn get_common_name(&self) -> Option<&str> {
+        self.common_name.as_deref()
+    }
+
+    pub fn get_connected_at(&self) -> u64 {
+        self.connected_at
+    }
+
+    pub fn get_subscription(&self) -> &ClientSubscription {
+        &self.subscription
+    }
+
+    pub fn set_subscription(&mut self, subscription: ClientSubscription) {
+        self.subscription = subscription;
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::call_info::CallInfo;
+
+///
+/// 클라이언트 구독 조건
+///
+/// 팀, 상담직원, 스킬그룹 조건 중 하나라도 맞으면 전송하며, 조건이 없으면 모든 이벤트를 전송한다.
+/// 조건이 있으면 걸러진 상담직원 변경분은 전송되지 않으므로 변경분 일련번호가 연속되지 않을 수 있다.
+///
+/// department_id 는 접속 시 인증 키로 정해지며, 구독 조건과 별개로 다른 부서 상담직원의
+/// 상태, 변경분, 제거, 통계, 호 이벤트를 막는다.
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ClientSubscription {
+    team_ids: HashSet<u32>,
+    agent_ids: HashSet<String>,
+    skill_group_ids: HashSet<u32>,
+    // 상담직원 이벤트를 받을 부서 (None: 모든 부서)
+    department_id: Option<i32>,
+    // 상태, 사유코드 표시 이름 언어 (None: 기본 표시 이름)
+    locale: Option<String>,
+}
+
+impl ClientSubscription {
+    ///
+    /// 구독 조건과 부서 지정이 없어 모든 이벤트를 수신하는지 여부
+    ///
+    pub fn is_all(&self) -> bool {
+        self.department_id.is_none() && self.is_unconditional()
+    }
+
+    ///
+    /// 팀, 상담직원, 스킬그룹 구독 조건이 없는지 여부
+    ///
+    fn is_unconditional(&self) -> bool {
+        self.team_ids.is_empty() && self.agent_ids.is_empty() && self.skill_group_ids.is_empty()
+    }
+
+    ///
+    /// 구독 조건 추가
+    ///
+    pub fn subscribe(&mut self, team_ids: &[u32], agent_ids: &[String], skill_group_ids: &[u32]) {
+        self.team_ids.extend(team_ids);
+        self.agent_ids.extend(agent_ids.iter().cloned());
+        self.skill_group_ids.extend(skill_group_ids);
+    }
+
+    ///
+    /// 구독 조건 제거
+    ///
+    /// 제거할 조건을 지정하지 않으면 모든 조건을 제거하여 전체 구독 상태로 되돌린다.
+    /// 표시 이름 언어는 유지한다.
+    ///
+    pub fn unsubscribe(&mut self, team_ids: &[u32], agent_ids: &[String], skill_group_ids: &[u32]) {
+        if team_ids.is_empty() && agent_ids.is_empty() && skill_group_ids.is_empty() {
+            self.team_ids.clear();
+            self.agent_ids.clear();
+            self.skill_group_ids.clear();
+            return;
+        }
+
+        self.team_ids.retain(|team_id| !team_ids.contains(team_id));
+        self.agent_ids
+            .retain(|agent_id| !agent_ids.contains(agent_id));
+        self.skill_group_ids
+            .retain(|skill_group_id| !skill_group_ids.contains(skill_group_id));
+    }
+
+    pub fn get_team_ids(&self) -> &HashSet<u32> {
+        &self.team_ids
+    }
+
+    pub fn get_agent_ids(&self) -> &HashSet<String> {
+        &self.agent_ids
+    }
+
+    pub fn get_skill_group_ids(&self) -> &HashSet<u32> {
+        &self.skill_group_ids
+    }
+
+    pub fn get_department_id(&self) -> Option<i32> {
+        self.department_id
+    }
+
+    ///
+    /// 상담직원 이벤트를 받을 부서 지정 (구독 조건 제거와 관계없이 유지한다)
+    ///
+    pub fn set_department_id(&mut self, department_id: Option<i32>) {
+        self.department_id = department_id;
+    }
+
+    pub fn get_locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    ///
+    /// 표시 이름 언어 지정 (빈 문자열이면 기본 표시 이름으로 되돌린다)
+    ///
+    pub fn set_locale(&mut self, locale: Option<String>) {
+        self.locale = locale
+            .map(|locale| locale.trim().to_string())
+            .filter(|locale| !locale.is_empty());
+    }
+
+    ///
+    /// 상담직원 이벤트 구독 여부
+    ///
+    /// 팀을 알 수 없는 경우 상담직원 ID 로만 판단한다.
+    ///
+    pub fn matches_agent(&self, agent_id: &str, team_id: Option<u32>) -> bool {
+        self.is_unconditional()
+            || self.agent_ids.contains(agent_id)
+            || team_id.is_some_and(|team_id| self.team_ids.contains(&team_id))
+    }
+
+    ///
+    /// 상담직원 부서가 클라이언트 부서와 같은지 확인한다
+    ///
+    /// 부서를 지정한 클라이언트는 부서를 알 수 없는 상담직원의 이벤트도 받지 않는다.
+    ///
+    pub fn matches_department(&self, department_id: Option<i32>) -> bool {
+        self.department_id
+            .is_none_or(|client_department_id| department_id == Some(client_department_id))
+    }
+
+    ///
+    /// 호 이벤트 구독 여부
+    ///
+    pub fn matches_call(&self, call_info: &CallInfo) -> bool {
+        self.is_unconditional()
+            || self.agent_ids.contains(call_info.get_agent_id())
+            || self
+                .skill_group_ids
+                .contains(&call_info.get_skill_group_id())
+    }
+
+    ///
+    /// 스킬그룹 집계 이벤트 구독 여부
+    ///
+    pub fn matches_skill_group(&self, skill_group_id: u32) -> bool {
+        self.is_unconditional() || self.skill_group_ids.contains(&skill_group_id)
+    }
+}
@@ -0,0 +1,156 @@
+use std::error::Error;
+
+use serde::Deserialize;
+
+use super::{client_auth::ClientPermission, cti_client::DEFAULT_CTI_INSTANCE};
+
+///
+/// 클라이언트 요청 메시지
+///
+/// `{"command": "AGENT_STATE_HISTORY", "agent_id": "1001"}` 형식의 MessagePack 또는 JSON 으로 수신한다.
+/// cti_instance 를 생략하면 기본 CTI 서버 인스턴스를 조회한다.
+///
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "command")]
+pub enum ClientCommand {
+    // 상담직원 상태 변경 이력 조회
+    AGENT_STATE_HISTORY {
+        #[serde(default = "default_cti_instance")]
+        cti_instance: String,
+        agent_id: String,
+    },
+    // 상담직원 전체 상태 재전송 (변경분 일련번호 누락 시)
+    AGENT_SNAPSHOT,
+    // 상담직원 일별 통계 조회
+    AGENT_STATISTICS {
+        #[serde(default = "default_cti_instance")]
+        cti_instance: String,
+        agent_id: String,
+    },
+    // 상담직원 상태 조회 (CTI 서버에 QUERY_AGENT_STATE_REQ 요청)
+    QUERY_AGENT {
+        request_id: String,
+        #[serde(default = "default_cti_instance")]
+        cti_instance: String,
+        #[serde(default)]
+        peripheral_id: Option<u32>,
+        agent_id: String,
+    },
+    // 내선 또는 장치 번호로 상담직원 조회 (CTM 이 보관 중인 상태)
+    FIND_AGENT {
+        request_id: String,
+        #[serde(default = "default_cti_instance")]
+        cti_instance: String,
+        extension: String,
+    },
+    // CTI 서버에 상담직원 상태 다시 조회 (agent_id, team_id 생략 시 전체 상담직원 / 빈도 제한)
+    REFRESH_STATE {
+        #[serde(default = "default_cti_instance")]
+        cti_instance: String,
+        #[serde(default)]
+        agent_id: Option<String>,
+        #[serde(default)]
+        team_id: Option<u32>,
+    },
+    // 구독 조건 추가 (팀, 상담직원, 스킬그룹), 표시 이름 언어 지정
+    SUBSCRIBE {
+        #[serde(default)]
+        team_ids: Vec<u32>,
+        #[serde(default)]
+        agent_ids: Vec<String>,
+        #[serde(default)]
+        skill_group_ids: Vec<u32>,
+        #[serde(default)]
+        locale: Option<String>,
+    },
+    // 구독 조건 제거 (조건 생략 시 전체 구독으로 복귀)
+    UNSUBSCRIBE {
+        #[serde(default)]
+        team_ids: Vec<u32>,
+        #[serde(default)]
+        agent_ids: Vec<String>,
+        #[serde(default)]
+        skill_group_ids: Vec<u32>,
+    },
+    // 접속 중인 클라이언트 목록 조회 (관리 명령)
+    LIST_CLIENTS,
+    // 클라이언트 강제 종료 (관리 명령)
+    DISCONNECT_CLIENT {
+        client_id: String,
+    },
+    // 모든 클라이언트에 전체 상태 재전송 (관리 명령)
+    REBROADCAST,
+    // 구독 조건과 관계없이 전체 상담직원 상태 조회 (관리 명령)
+    DUMP_AGENTS,
+    // 상담직원/호/팀/스킬그룹 상태 전체를 스냅샷으로 조회 (관리 명령, --import-state 로 불러온다)
+    EXPORT_STATE,
+    // 일부 설정 재적용 (관리 명령, SIGHUP 과 동일)
+    RELOAD_CONFIG,
+    // CTI 세션 모니터의 호 이벤트 마스크 변경 (관리 명령, CHANGE_MONITOR_MASK_REQ 요청)
+    CHANGE_MONITOR_MASK {
+        #[serde(default = "default_cti_instance")]
+        cti_instance: String,
+        call_msg_mask: String,
+    },
+    // 복호화한 CTI 메시지 전체 수신 시작 (관리 명령)
+    SUBSCRIBE_FIREHOSE,
+    // 복호화한 CTI 메시지 전체 수신 중단
+    UNSUBSCRIBE_FIREHOSE,
+}
+
+impl ClientCommand {
+    ///
+    /// 클라이언트가 전송한 데이터를 요청 메시지로 변환한다
+    ///
+    pub fn parse(data: &[u8]) -> Result<Self, Box<dyn Error>> {
+        match rmp_serde::from_slice::<Self>(data) {
+            Ok(command) => Ok(command),
+            Err(_) => Ok(serde_json::from_slice::<Self>(data)?),
+        }
+    }
+
+    ///
+    /// CTI 서버에 요청을 보내는 제어 명령 여부
+    ///
+    pub fn is_control(&self) -> bool {
+        matches!(
+            self,
+            ClientCommand::QUERY_AGENT { .. } | ClientCommand::REFRESH_STATE { .. }
+        )
+    }
+
+    ///
+    /// 관리 명령 여부
+    ///
+    pub fn is_admin(&self) -> bool {
+        matches!(
+            self,
+            ClientCommand::LIST_CLIENTS
+                | ClientCommand::DISCONNECT_CLIENT { .. }
+                | ClientCommand::REBROADCAST
+                | ClientCommand::DUMP_AGENTS
+                | ClientCommand::EXPORT_STATE
+                | ClientCommand::RELOAD_CONFIG
+                | ClientCommand::CHANGE_MONITOR_MASK { .. }
+                | ClientCommand::SUBSCRIBE_FIREHOSE
+        )
+    }
+
+    ///
+    /// 명령 실행에 필요한 최소 권한
+    ///
+    pub fn required_permission(&self) -> ClientPermission {
+        if self.is_admin() {
+            ClientPermission::ADMIN
+        } else if self.is_control() {
+            ClientPermission::CONTROL
+        } else {
+            ClientPermission::READ_ONLY
+        }
+    }
+}
+
+fn default_cti_instance() -> String {
+    DEFAULT_CTI_INSTANCE.to_string()
+}
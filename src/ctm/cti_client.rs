@@ -4,32 +4,59 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
+use futures::{SinkExt, StreamExt};
 use tokio::{
-    io::{AsyncReadExt, AsyncWriteExt},
     net::TcpStream,
-    sync::{broadcast, mpsc},
-    time::{sleep, timeout},
+    sync::{
+        broadcast::{self, error::RecvError},
+        mpsc,
+    },
+    time::{interval, sleep, timeout},
 };
+use tokio_util::codec::Framed;
+use tracing::Instrument;
 
 use crate::{
     cisco::{
+        codec::{CtiCodec, MHDR_LENGTH},
         control::query_agent_state_req::QueryAgentStateReq,
-        session::{heartbeat_req::HeartBeatReq, OpenReq},
-        Deserializable, FloatingField, MessageType, Serializable, TagValue, MHDR,
+        pretty,
+        session::{
+            change_monitor_mask_req::ChangeMonitorMaskReq,
+            close_req::{CloseReq, CLOSE_STATUS_NO_ERROR},
+            heartbeat_req::HeartBeatReq,
+            OpenReq,
+        },
+        Deserializable, InvokeId, InvokeIdGenerator, MessageType, MHDR,
     },
     event::{broker_event::BrokerEvent, cti_event::CTIEvent},
 };
 
+use super::{
+    capture::{CaptureStream, CaptureWriter},
+    config::CtiClientConfig,
+    logging::CONNECTION_SPAN_TARGET,
+    net_addr::strip_brackets,
+    pending_request::{PendingRequestTimeout, PendingRequests},
+};
+
+///
+/// 기본 CTI 서버 인스턴스 이름
+///
+pub const DEFAULT_CTI_INSTANCE: &str = "default";
+
 ///
 /// CTI 클라이언트 구조체
 ///
 pub struct CTIClient {
+    cti_instance: String,
     is_active: bool,
     is_running: Arc<AtomicBool>,
-    invoke_id: u32,
+    invoke_id_generator: InvokeIdGenerator,
+    config: CtiClientConfig,
     cti_event_channel_tx: mpsc::Sender<CTIEvent>,
     broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
 }
@@ -39,45 +66,55 @@ impl CTIClient {
     /// 새로운 CTI Client 구조체를 생성
     ///
     pub async fn new(
+        config: CtiClientConfig,
         is_active: bool,
+        invoke_id_generator: InvokeIdGenerator,
         cti_event_channel_tx: mpsc::Sender<CTIEvent>,
         broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
     ) -> Result<Self, Box<dyn Error>> {
-        let is_running = Arc::new(AtomicBool::new(false));
-        let invoke_id = 0;
         Ok(Self {
+            cti_instance: config.cti_instance.clone(),
             is_active,
-            is_running,
-            invoke_id,
+            is_running: Arc::new(AtomicBool::new(false)),
+            invoke_id_generator,
+            config,
             cti_event_channel_tx,
             broker_event_channel_rx,
         })
     }
 
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    ///
+    /// 세션 실행 여부
+    ///
+    /// CTI 서버에 접속하면 true, 연결 오류 또는 종료(CLOSE_REQ 전송) 후 false 가 된다.
+    ///
+    pub fn get_is_running(&self) -> Arc<AtomicBool> {
+        self.is_running.clone()
+    }
+
     ///
     /// CTI 서버에 접속
     ///
     pub async fn connect(mut self) -> () {
-        const ASYNC_POLL_TIMEOUT: u64 = 10;
         const HEART_BEAT_TIMEOUT: u64 = 10_000;
+        const CLOSE_CONF_TIMEOUT: u64 = 1_000;
         const CTI_SERVER_BUFFER_SIZE: usize = 65_536;
+        const PENDING_REQUEST_CHECK_INTERVAL: u64 = 100;
 
         let is_running = self.is_running.clone();
 
-        let cti_server_address = dotenv::var(match self.is_active {
-            true => "CTI_SERVER_SIDE_A_ADDRESS",
-            false => "CTI_SERVER_SIDE_B_ADDRESS",
-        })
-        .unwrap_or("localhost".to_string());
-        let cti_server_port = dotenv::var(match self.is_active {
-            true => "CTI_SERVER_SIDE_A_PORT",
-            false => "CTI_SERVER_SIDE_B_PORT",
-        })
-        .unwrap_or("42027".to_string());
+        let cti_server_address = match self.is_active {
+            true => strip_brackets(&self.config.side_a_address).to_string(),
+            false => strip_brackets(&self.config.side_b_address).to_string(),
+        };
 
-        let mut client_stream = match timeout(
+        let client_stream = match timeout(
             Duration::from_millis(3_000),
-            TcpStream::connect(format!("{}:{}", cti_server_address, cti_server_port)),
+            TcpStream::connect(self.config.get_server_address(self.is_active)),
         )
         .await
         {
@@ -89,6 +126,7 @@ impl CTIClient {
             Ok(Err(e)) => {
                 self.cti_event_channel_tx
                     .send(CTIEvent::Error {
+                        cti_instance: self.cti_instance.clone(),
                         cti_server_host: cti_server_address,
                         error_cause: e.to_string(),
                     })
@@ -99,6 +137,7 @@ impl CTIClient {
             Err(e) => {
                 self.cti_event_channel_tx
                     .send(CTIEvent::Error {
+                        cti_instance: self.cti_instance.clone(),
                         cti_server_host: cti_server_address,
                         error_cause: e.to_string(),
                     })
@@ -112,53 +151,101 @@ impl CTIClient {
 
         let is_running_heartbeat = is_running.clone();
         let cti_event_channel_tx_heartbeat = self.cti_event_channel_tx.clone();
+        let cti_instance_heartbeat = self.cti_instance.clone();
 
+        // 세션 동안 남기는 로그에 CTI 서버 인스턴스와 접속한 Side 를 붙인다
+        let session_span = tracing::info_span!(
+            target: CONNECTION_SPAN_TARGET,
+            "cti.session",
+            cti_instance = %self.cti_instance,
+            cti_side = if self.is_active { "A" } else { "B" },
+            cti_server_host = %cti_server_address,
+        );
         tokio::spawn(async move {
             // OPEN_REQ 메시지 전송
-            let open_req = OpenReq {
-                mhdr: MHDR {
-                    length: 0,
-                    message_type: MessageType::OPEN_REQ,
-                },
-                invoke_id: self.get_invoke_id(),
-                version_number: 24,
-                idle_timeout: 100,
-                peripheral_id: 5000,
-                services_requested: 0x8000_0000 | 0x0000_0004 | 0x0000_0010 | 0x0000_0080,
-                call_msg_mask: u32::max_value(),
-                agent_state_mask: 0x0000_3FFF,
-                config_msg_mask: 0,
-                reserved1: 0,
-                reserved2: 0,
-                reserved3: 0,
-                client_id: Some(FloatingField {
-                    tag: TagValue::CLIENT_ID_TAG,
-                    length: 0,
-                    data: "ctmonitor_rs".to_string(),
-                }),
-                client_password: Some(FloatingField {
-                    tag: TagValue::CLIENT_PASSWORD_TAG,
-                    length: 0,
-                    data: "SomePassword!!".to_string(),
-                }),
-                client_signature: None,
-                agent_extension: None,
-                agent_id: None,
-                agent_instrument: None,
-                application_path_id: None,
-                unique_instance_id: None,
+            let mut open_req_builder = OpenReq::builder()
+                .invoke_id(self.invoke_id_generator.next_id())
+                .version(24)
+                .idle_timeout(self.config.idle_timeout)
+                .peripheral_id(self.config.peripheral_id)
+                .services_requested(self.config.services_requested)
+                .call_msg_mask(self.config.call_msg_mask)
+                .agent_state_mask(self.config.agent_state_mask)
+                .client_id(self.config.client_id.clone())
+                .client_password(self.config.client_password.clone())
+                .client_signature(self.config.client_signature.clone());
+            // Client Events 세션은 상담직원 한 명을 대신하여 연다
+            if let Some(agent_id) = self.config.agent_id.clone() {
+                open_req_builder = open_req_builder.agent_id(agent_id);
+            }
+            if let Some(agent_extension) = self.config.agent_extension.clone() {
+                open_req_builder = open_req_builder.agent_extension(agent_extension);
+            }
+            if let Some(agent_instrument) = self.config.agent_instrument.clone() {
+                open_req_builder = open_req_builder.agent_instrument(agent_instrument);
+            }
+            if let Some(application_path_id) = self.config.application_path_id {
+                open_req_builder = open_req_builder.application_path_id(application_path_id);
+            }
+            let open_req = match open_req_builder.build() {
+                Ok(open_req) => open_req,
+                Err(e) => {
+                    is_running.store(false, Ordering::Release);
+                    self.cti_event_channel_tx
+                        .send(CTIEvent::Error {
+                            cti_instance: self.cti_instance.clone(),
+                            cti_server_host: cti_server_address,
+                            error_cause: e.to_string(),
+                        })
+                        .await
+                        .unwrap();
+                    return;
+                }
             };
-            match client_stream.write(&open_req.serialize()).await {
+            log::debug!("{:?}", open_req);
+
+            // 응답을 받지 못한 요청은 재시도하거나 시간 초과로 처리한다
+            // OPEN_CONF 를 받지 못하면 세션이 반쯤 열린 채로 남지 않도록 연결을 끊는다
+            let mut pending_requests = PendingRequests::<Option<QueryAgentStateReq>>::new();
+            pending_requests.insert(
+                open_req.invoke_id,
+                MessageType::OPEN_REQ,
+                None,
+                self.config.open_conf_timeout,
+                0,
+                Instant::now(),
+            );
+            let mut pending_request_interval =
+                interval(Duration::from_millis(PENDING_REQUEST_CHECK_INTERVAL));
+
+            // CTI_CAPTURE_DIRECTORY 가 설정되면 수신한 원본 바이트를 세션별 캡처 파일에 기록한다
+            let client_stream = CaptureStream::new(
+                client_stream,
+                self.config
+                    .capture_directory
+                    .as_ref()
+                    .and_then(|directory| {
+                        CaptureWriter::open(directory, &self.cti_instance, &cti_server_address)
+                    }),
+            );
+            let mut client_stream = Framed::with_capacity(
+                client_stream,
+                CtiCodec::new(self.config.string_encoding),
+                CTI_SERVER_BUFFER_SIZE,
+            );
+            match client_stream.send(open_req).await {
                 Ok(_) => {
                     log::info!(
-                        "Sent OPEN_REQ message. cti_server_host: {}",
-                        cti_server_address
+                        "Sent OPEN_REQ message. cti_server_host: {}, client_id: {}",
+                        cti_server_address,
+                        self.config.client_id
                     );
                 }
                 Err(e) => {
                     is_running.store(false, Ordering::Release);
                     self.cti_event_channel_tx
                         .send(CTIEvent::Error {
+                            cti_instance: self.cti_instance.clone(),
                             cti_server_host: cti_server_address,
                             error_cause: e.to_string(),
                         })
@@ -168,217 +255,411 @@ impl CTIClient {
                 }
             }
 
-            let (mut rx, mut tx) = client_stream.split();
-
             // CTI 서버 메시지 핸들링
-            let mut buffer = vec![0_u8; CTI_SERVER_BUFFER_SIZE];
-            let mut reserved_length = 0_usize;
-            let mut reserved_buffer = vec![0_u8; CTI_SERVER_BUFFER_SIZE];
+            // 서버 메시지와 브로커 이벤트 중 먼저 도착한 것을 처리한다
             loop {
-                match timeout(
-                    Duration::from_millis(ASYNC_POLL_TIMEOUT),
-                    rx.read(&mut buffer),
-                )
-                .await
-                {
-                    Ok(Ok(n)) if n == 0 => {
-                        is_running.store(false, Ordering::Release);
-                        self.cti_event_channel_tx
-                            .send(CTIEvent::Error {
-                                cti_server_host: cti_server_address.clone(),
-                                error_cause: "Disconnected from server".to_string(),
-                            })
-                            .await
-                            .unwrap();
-                        log::error!("Disconnected from server");
-                        return;
-                    }
-                    Ok(Ok(n)) => {
-                        // 수신받은 패킷 이전에 처리 예약된 패킷이 있는 경우 수신 패킷이전에 추가한다
-                        // Linux 에서 문제가 발생해서 추가함
-                        let mut received_packet = reserved_buffer[0..reserved_length].to_vec();
-                        let total_length = n + reserved_length;
-                        reserved_length = 0;
-
-                        // 수신된 버퍼를 추가한다
-                        received_packet.extend_from_slice(&buffer[0..n]);
-
-                        log::trace!(
-                            "Received CTI Packet. length: {}, packet: {:?}",
-                            total_length,
-                            &received_packet[0..total_length]
-                        );
-
-                        // CTI 서버로부터 패킷을 전송받은 경우
-                        let mut index = 0_usize;
-
-                        // 여러 메시지를 한 패킷에 받을 수 있어 분리해서 처리한다
-                        while index < total_length {
-                            log::trace!("Dividing packet index: {}, length: {}", index, total_length);
-                            // 메시지 헤더 조회
-                            let (_, mhdr) =
-                                MHDR::deserialize(&mut received_packet[index..index + 8].to_vec());
-
-                            // 수신된 패킷의 길이가 메시지 헤더에서 정의된 길이보다 짧은 경우
-                            if total_length < (8 + mhdr.length as usize) {
-                                // 예약된 버퍼에 수신된 패킷을 이동
-                                reserved_buffer[..n].copy_from_slice(&received_packet[..n]);
-                                reserved_length = total_length;
-
-                                log::trace!("Reserved buffer: {:?}", &reserved_buffer[0..reserved_length]);
+                tokio::select! {
+                    message = client_stream.next() => match message {
+                        None => {
+                            is_running.store(false, Ordering::Release);
+                            self.cti_event_channel_tx
+                                .send(CTIEvent::Error {
+                                    cti_instance: self.cti_instance.clone(),
+                                    cti_server_host: cti_server_address.clone(),
+                                    error_cause: "Disconnected from server".to_string(),
+                                })
+                                .await
+                                .unwrap();
+                            log::error!("Disconnected from server");
+                            return;
+                        }
+                        Some(Ok(Err(e))) => {
+                            // 손상된 메시지는 버리고 다음 메시지부터 다시 처리한다
+                            log::error!(
+                                "Discarded invalid CTI message. cti_server_host: {}, error: {}",
+                                cti_server_address,
+                                e
+                            );
+                        }
+                        Some(Ok(Ok((message_type, data)))) => {
+                            // 요청에 대한 응답이면 응답 대기 목록에서 제거한다
+                            if matches!(
+                                message_type,
+                                MessageType::OPEN_CONF
+                                    | MessageType::QUERY_AGENT_STATE_CONF
+                                    | MessageType::CHANGE_MONITOR_MASK_CONF
+                                    | MessageType::FAILURE_CONF
+                                    | MessageType::CONTROL_FAILURE_CONF
+                            ) && data.len() >= MHDR_LENGTH + 4
+                            {
+                                let mut invoke_id = [0_u8; 4];
+                                invoke_id.copy_from_slice(&data[MHDR_LENGTH..MHDR_LENGTH + 4]);
+                                let (_, invoke_id) = InvokeId::deserialize(&mut invoke_id);
+                                if pending_requests.complete(&invoke_id).is_some() {
+                                    log::debug!(
+                                        "Received CTI response. cti_server_host: {}, invoke_id: {}, message_type: {:?}",
+                                        cti_server_address,
+                                        invoke_id,
+                                        message_type
+                                    );
+                                }
+                            }
 
-                                break;
+                            if log::log_enabled!(log::Level::Trace) {
+                                log::trace!(
+                                    "Received CTI message. cti_server_host: {}\n{}",
+                                    cti_server_address,
+                                    client_stream.codec().with_string_encoding(|| {
+                                        pretty::format_message(&message_type, &data)
+                                    })
+                                );
                             }
 
+                            let span = tracing::info_span!(
+                                "cti.read",
+                                cti_instance = %self.cti_instance,
+                                cti_server_host = %cti_server_address,
+                                message_type = ?message_type,
+                                size = data.len(),
+                            );
                             self.cti_event_channel_tx
                                 .send(CTIEvent::Recevied {
+                                    cti_instance: self.cti_instance.clone(),
                                     cti_server_host: cti_server_address.clone(),
-                                    message_type: mhdr.message_type,
-                                    data: received_packet
-                                        [index..index + (mhdr.length + 8) as usize]
-                                        .to_vec(),
+                                    message_type,
+                                    data,
+                                    span,
                                 })
                                 .await
                                 .unwrap();
-
-                            // 현재 인덱스 증가
-                            index = index + 8 + mhdr.length as usize;
                         }
-                    }
-                    Ok(Err(e)) => {
-                        // CTI 이벤트 채널로 오류 이벤트를 발생시킨다
-                        is_running.store(false, Ordering::Release);
-                        self.cti_event_channel_tx
-                            .send(CTIEvent::Error {
-                                cti_server_host: cti_server_address.clone(),
-                                error_cause: e.to_string(),
-                            })
-                            .await
-                            .unwrap();
-                        log::error!("Read error. {:#?}", e);
-                        return;
-                    }
-                    Err(_) => {}
-                }
+                        Some(Err(e)) => {
+                            // CTI 이벤트 채널로 오류 이벤트를 발생시킨다
+                            is_running.store(false, Ordering::Release);
+                            self.cti_event_channel_tx
+                                .send(CTIEvent::Error {
+                                    cti_instance: self.cti_instance.clone(),
+                                    cti_server_host: cti_server_address.clone(),
+                                    error_cause: e.to_string(),
+                                })
+                                .await
+                                .unwrap();
+                            log::error!("Read error. {:#?}", e);
+                            return;
+                        }
+                    },
+                    // 브로커 이벤트 핸들링
+                    event = self.broker_event_channel_rx.recv() => match event {
+                        Ok(event) => match event {
+                            // HEART_BEAT_REQ 전송 요청 이벤트
+                            BrokerEvent::RequestHeartBeatReq { cti_instance }
+                                if cti_instance == self.cti_instance =>
+                            {
+                                log::debug!("Received request heartbeat req");
 
-                // 브로커 이벤트 핸들링
-                match timeout(
-                    Duration::from_millis(ASYNC_POLL_TIMEOUT),
-                    self.broker_event_channel_rx.recv(),
-                )
-                .await
-                {
-                    Ok(Ok(event)) => match event {
-                        // HEART_BEAT_REQ 전송 요청 이벤트
-                        BrokerEvent::RequestHeartBeatReq => {
-                            log::debug!("Received request heartbeat req");
+                                let heartbeat_req = HeartBeatReq {
+                                    mhdr: MHDR {
+                                        length: 4,
+                                        message_type: MessageType::HEARTBEAT_REQ,
+                                    },
+                                    invoke_id: self.invoke_id_generator.next_id(),
+                                };
 
-                            let heartbeat_req = HeartBeatReq {
-                                mhdr: MHDR {
-                                    length: 4,
-                                    message_type: MessageType::HEARTBEAT_REQ,
-                                },
-                                invoke_id: self.get_invoke_id(),
-                            };
+                                match timeout(
+                                    Duration::from_millis(100),
+                                    client_stream.send(heartbeat_req),
+                                )
+                                .await
+                                {
+                                    Ok(Ok(_)) => {}
+                                    Ok(Err(e)) => {
+                                        is_running.store(false, Ordering::Release);
+                                        self.cti_event_channel_tx
+                                            .send(CTIEvent::Error {
+                                                cti_instance: self.cti_instance.clone(),
+                                                cti_server_host: cti_server_address.clone(),
+                                                error_cause: e.to_string(),
+                                            })
+                                            .await
+                                            .unwrap();
+                                        log::error!("Send error. {:#?}", e);
+                                    }
+                                    Err(_) => {}
+                                }
+                            }
+                            // QUERY_AGENT_STATE_REQ 전송 요청 이벤트
+                            BrokerEvent::RequestAgentStateEvent {
+                                cti_instance,
+                                invoke_id,
+                                peripheral_id,
+                                agent_id,
+                            } if cti_instance == self.cti_instance => {
+                                let request_span = tracing::info_span!(
+                                    "cti.request",
+                                    invoke_id = %invoke_id,
+                                    message_type = ?MessageType::QUERY_AGENT_STATE_REQ,
+                                );
+                                request_span.in_scope(|| log::debug!(
+                                    "Received request agent state event: peripheral_id: {} agent_id: {}", peripheral_id,
+                                    agent_id
+                                ));
 
-                            match timeout(
-                                Duration::from_millis(100),
-                                tx.write(&heartbeat_req.serialize()),
-                            )
-                            .await
-                            {
-                                Ok(Ok(_)) => {}
-                                Ok(Err(e)) => {
-                                    is_running.store(false, Ordering::Release);
-                                    self.cti_event_channel_tx
-                                        .send(CTIEvent::Error {
-                                            cti_server_host: cti_server_address.clone(),
-                                            error_cause: e.to_string(),
-                                        })
-                                        .await
-                                        .unwrap();
-                                    log::error!("Send error. {:#?}", e);
+                                let query_agent_state_req = match QueryAgentStateReq::builder()
+                                    .invoke_id(invoke_id)
+                                    .peripheral_id(peripheral_id)
+                                    .mrd_id(self.config.voice_mrd_id)
+                                    .agent_id(agent_id)
+                                    .build()
+                                {
+                                    Ok(query_agent_state_req) => query_agent_state_req,
+                                    Err(e) => {
+                                        request_span.in_scope(|| {
+                                            log::error!("Invalid QUERY_AGENT_STATE_REQ. {}", e)
+                                        });
+                                        continue;
+                                    }
+                                };
+
+                                pending_requests.insert(
+                                    invoke_id,
+                                    MessageType::QUERY_AGENT_STATE_REQ,
+                                    Some(query_agent_state_req.clone()),
+                                    self.config.request_timeout,
+                                    self.config.request_retries,
+                                    Instant::now(),
+                                );
+
+                                match timeout(
+                                    Duration::from_millis(100),
+                                    client_stream.send(query_agent_state_req),
+                                )
+                                .instrument(request_span)
+                                .await
+                                {
+                                    Ok(Ok(_)) => {}
+                                    Ok(Err(e)) => {
+                                        is_running.store(false, Ordering::Release);
+                                        self.cti_event_channel_tx
+                                            .send(CTIEvent::Error {
+                                                cti_instance: self.cti_instance.clone(),
+                                                cti_server_host: cti_server_address.clone(),
+                                                error_cause: e.to_string(),
+                                            })
+                                            .await
+                                            .unwrap();
+                                        log::error!("Send error. {:#?}", e);
+                                    }
+                                    Err(_) => {}
                                 }
-                                Err(_) => {}
                             }
-                        }
-                        // QUERY_AGENT_STATE_REQ 전송 요청 이벤트
-                        BrokerEvent::RequestAgentStateEvent {
-                            peripheral_id,
-                            agent_id,
-                        } => {
-                            log::debug!(
-                                "Received request agent state event: peripheral_id: {} agent_id: {}", peripheral_id,
-                                agent_id
-                            );
+                            // CHANGE_MONITOR_MASK_REQ 전송 요청 이벤트
+                            BrokerEvent::RequestChangeMonitorMask {
+                                cti_instance,
+                                invoke_id,
+                                monitor_id,
+                                call_msg_mask,
+                            } if cti_instance == self.cti_instance => {
+                                let request_span = tracing::info_span!(
+                                    "cti.request",
+                                    invoke_id = %invoke_id,
+                                    message_type = ?MessageType::CHANGE_MONITOR_MASK_REQ,
+                                );
+                                request_span.in_scope(|| log::info!(
+                                    "Changing monitor mask. cti_server_host: {}, monitor_id: {}, call_msg_mask: {:?}",
+                                    cti_server_address,
+                                    monitor_id,
+                                    call_msg_mask
+                                ));
 
-                            let query_agent_state_req = QueryAgentStateReq {
-                                mhdr: MHDR {
-                                    length: 0,
-                                    message_type: MessageType::QUERY_AGENT_STATE_REQ,
-                                },
-                                invoke_id: self.get_invoke_id(),
-                                peripheral_id,
-                                mrd_id: 0,
-                                icm_agent_id: 0,
-                                agent_extension: None,
-                                agent_id: Some(FloatingField {
-                                    tag: TagValue::AGENT_ID_TAG,
-                                    length: agent_id.len() as u16,
-                                    data: agent_id,
-                                }),
-                                agent_instrument: None,
-                            };
+                                pending_requests.insert(
+                                    invoke_id,
+                                    MessageType::CHANGE_MONITOR_MASK_REQ,
+                                    None,
+                                    self.config.request_timeout,
+                                    0,
+                                    Instant::now(),
+                                );
 
-                            match timeout(
-                                Duration::from_millis(100),
-                                tx.write(&query_agent_state_req.serialize()),
-                            )
-                            .await
-                            {
-                                Ok(Ok(_)) => {}
-                                Ok(Err(e)) => {
+                                match timeout(
+                                    Duration::from_millis(100),
+                                    client_stream.send(ChangeMonitorMaskReq::new(
+                                        invoke_id,
+                                        monitor_id,
+                                        call_msg_mask,
+                                    )),
+                                )
+                                .instrument(request_span)
+                                .await
+                                {
+                                    Ok(Ok(_)) => {}
+                                    Ok(Err(e)) => {
+                                        is_running.store(false, Ordering::Release);
+                                        self.cti_event_channel_tx
+                                            .send(CTIEvent::Error {
+                                                cti_instance: self.cti_instance.clone(),
+                                                cti_server_host: cti_server_address.clone(),
+                                                error_cause: e.to_string(),
+                                            })
+                                            .await
+                                            .unwrap();
+                                        log::error!("Send error. {:#?}", e);
+                                    }
+                                    Err(_) => {}
+                                }
+                            }
+                            // 서버 종료 또는 HA 대기 노드로 전환 시 CLOSE_REQ 를 전송하고 CLOSE_CONF 를 기다린다
+                            BrokerEvent::Shutdown | BrokerEvent::CloseCtiSession => {
+                                let close_req = CloseReq {
+                                    mhdr: MHDR {
+                                        length: 8,
+                                        message_type: MessageType::CLOSE_REQ,
+                                    },
+                                    invoke_id: self.invoke_id_generator.next_id(),
+                                    status: CLOSE_STATUS_NO_ERROR,
+                                };
+
+                                match timeout(
+                                    Duration::from_millis(CLOSE_CONF_TIMEOUT),
+                                    client_stream.send(close_req),
+                                )
+                                .await
+                                {
+                                    Ok(Ok(_)) => {
+                                        log::info!(
+                                            "Sent CLOSE_REQ message. cti_server_host: {}",
+                                            cti_server_address
+                                        );
+                                        let close_conf = timeout(
+                                            Duration::from_millis(CLOSE_CONF_TIMEOUT),
+                                            async {
+                                                while let Some(Ok(Ok((message_type, _)))) =
+                                                    client_stream.next().await
+                                                {
+                                                    if message_type == MessageType::CLOSE_CONF {
+                                                        return true;
+                                                    }
+                                                }
+                                                false
+                                            },
+                                        )
+                                        .await;
+                                        if close_conf != Ok(true) {
+                                            log::warn!(
+                                                "Closed CTI session without CLOSE_CONF. cti_server_host: {}",
+                                                cti_server_address
+                                            );
+                                        }
+                                    }
+                                    Ok(Err(e)) => log::warn!(
+                                        "Unable to send CLOSE_REQ message. cti_server_host: {}, error: {}",
+                                        cti_server_address,
+                                        e
+                                    ),
+                                    Err(_) => log::warn!(
+                                        "Timed out sending CLOSE_REQ message. cti_server_host: {}",
+                                        cti_server_address
+                                    ),
+                                }
+
+                                is_running.store(false, Ordering::Release);
+                                return;
+                            }
+                            _ => {}
+                        },
+                        Err(RecvError::Lagged(skipped)) => {
+                            log::error!("Unabled to receive broking event. skipped: {}", skipped);
+                        }
+                        Err(RecvError::Closed) => {
+                            is_running.store(false, Ordering::Release);
+                            return;
+                        }
+                    },
+                    // 응답 대기 시간이 지난 요청 처리
+                    _ = pending_request_interval.tick() => {
+                        for pending_request_timeout in pending_requests.expire(Instant::now()) {
+                            match pending_request_timeout {
+                                PendingRequestTimeout::RETRY {
+                                    invoke_id,
+                                    message_type,
+                                    request: Some(request),
+                                } => {
+                                    let request_span = tracing::info_span!(
+                                        "cti.request",
+                                        invoke_id = %invoke_id,
+                                        message_type = ?message_type,
+                                    );
+                                    request_span.in_scope(|| log::warn!(
+                                        "Retrying CTI request. cti_server_host: {}, invoke_id: {}, message_type: {:?}",
+                                        cti_server_address,
+                                        invoke_id,
+                                        message_type
+                                    ));
+                                    match timeout(
+                                        Duration::from_millis(100),
+                                        client_stream.send(request),
+                                    )
+                                    .instrument(request_span)
+                                    .await
+                                    {
+                                        Ok(Ok(_)) => {}
+                                        Ok(Err(e)) => log::error!("Send error. {:#?}", e),
+                                        Err(_) => {}
+                                    }
+                                }
+                                PendingRequestTimeout::RETRY { request: None, .. } => {}
+                                PendingRequestTimeout::TIMED_OUT {
+                                    invoke_id,
+                                    message_type: MessageType::OPEN_REQ,
+                                    ..
+                                } => {
                                     is_running.store(false, Ordering::Release);
                                     self.cti_event_channel_tx
                                         .send(CTIEvent::Error {
+                                            cti_instance: self.cti_instance.clone(),
                                             cti_server_host: cti_server_address.clone(),
-                                            error_cause: e.to_string(),
+                                            error_cause: "OPEN_CONF timed out".to_string(),
                                         })
                                         .await
                                         .unwrap();
-                                    log::error!("Send error. {:#?}", e);
+                                    log::error!(
+                                        "OPEN_CONF timed out. cti_server_host: {}, invoke_id: {}",
+                                        cti_server_address,
+                                        invoke_id
+                                    );
+                                    return;
+                                }
+                                PendingRequestTimeout::TIMED_OUT {
+                                    invoke_id,
+                                    message_type,
+                                    ..
+                                } => {
+                                    log::warn!(
+                                        "CTI request timed out. cti_server_host: {}, invoke_id: {}, message_type: {:?}",
+                                        cti_server_address,
+                                        invoke_id,
+                                        message_type
+                                    );
                                 }
-                                Err(_) => {}
                             }
                         }
-                        _ => {}
-                    },
-                    Ok(Err(e)) => {
-                        log::error!("Unabled to receive broking event. {:?}", e);
                     }
-                    Err(_) => {}
                 }
             }
-        });
+        }
+        .instrument(session_span));
 
         // HEART_BEAT 전송
         tokio::spawn(async move {
             sleep(Duration::from_millis(HEART_BEAT_TIMEOUT)).await;
             while is_running_heartbeat.load(Ordering::Acquire) {
                 cti_event_channel_tx_heartbeat
-                    .send(CTIEvent::TimeToHeartBeat)
+                    .send(CTIEvent::TimeToHeartBeat {
+                        cti_instance: cti_instance_heartbeat.clone(),
+                    })
                     .await
                     .unwrap();
                 sleep(Duration::from_millis(HEART_BEAT_TIMEOUT)).await;
             }
         });
     }
-
-    ///
-    /// InvokeID 값을 증가하고 증가한 값을 반환한다
-    ///
-    fn get_invoke_id(&mut self) -> u32 {
-        self.invoke_id = self.invoke_id + 1;
-        self.invoke_id
-    }
 }
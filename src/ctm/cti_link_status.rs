@@ -0,0 +1,118 @@
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// CTI 서버 연결 상태 (주기 전송)
+///
+/// side 는 현재 접속 대상(A: 주, B: 예비)이며, heartbeat_rtt_ms 는 마지막 하트비트 요청부터 응답까지
+/// 걸린 시간(밀리초)이다. 연결이 끊겼거나 마지막 CTI 메시지를 받은 뒤 오래 지나면 degraded 이며,
+/// 하트비트 응답이나 CTI 메시지를 받은 적이 없으면 해당 값은 null 이다.
+///
+pub struct CtiLinkStatus {
+    cti_instance: String,
+    connected: bool,
+    side: String,
+    heartbeat_rtt_ms: Option<u64>,
+    reconnect_count: u64,
+    seconds_since_last_event: Option<u64>,
+    degraded: bool,
+}
+
+impl CtiLinkStatus {
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    pub fn get_side(&self) -> &str {
+        &self.side
+    }
+
+    pub fn get_heartbeat_rtt_ms(&self) -> Option<u64> {
+        self.heartbeat_rtt_ms
+    }
+
+    pub fn get_reconnect_count(&self) -> u64 {
+        self.reconnect_count
+    }
+
+    pub fn get_seconds_since_last_event(&self) -> Option<u64> {
+        self.seconds_since_last_event
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.degraded
+    }
+}
+
+#[derive(Debug, Default)]
+///
+/// CTI 서버 인스턴스별 연결 상태 측정
+///
+pub struct CtiLinkTracker {
+    heartbeat_requested_at: Option<Instant>,
+    heartbeat_rtt: Option<Duration>,
+    reconnect_count: u64,
+    last_event_at: Option<Instant>,
+}
+
+impl CtiLinkTracker {
+    pub fn on_heartbeat_requested(&mut self, now: Instant) {
+        self.heartbeat_requested_at = Some(now);
+    }
+
+    ///
+    /// HEARTBEAT_CONF 수신 (요청하지 않은 응답은 무시한다)
+    ///
+    pub fn on_heartbeat_conf(&mut self, now: Instant) {
+        if let Some(heartbeat_requested_at) = self.heartbeat_requested_at.take() {
+            self.heartbeat_rtt = Some(now.saturating_duration_since(heartbeat_requested_at));
+        }
+    }
+
+    pub fn on_reconnect(&mut self) {
+        self.reconnect_count = self.reconnect_count.saturating_add(1);
+        self.heartbeat_requested_at = None;
+    }
+
+    pub fn on_event(&mut self, now: Instant) {
+        self.last_event_at = Some(now);
+    }
+
+    ///
+    /// 현재 연결 상태 (stale_after 동안 CTI 메시지가 없으면 degraded)
+    ///
+    pub fn status(
+        &self,
+        cti_instance: impl Into<String>,
+        connected: bool,
+        is_active: bool,
+        stale_after: Duration,
+        now: Instant,
+    ) -> CtiLinkStatus {
+        let since_last_event = self
+            .last_event_at
+            .map(|last_event_at| now.saturating_duration_since(last_event_at));
+
+        CtiLinkStatus {
+            cti_instance: cti_instance.into(),
+            connected,
+            side: match is_active {
+                true => "A".to_string(),
+                false => "B".to_string(),
+            },
+            heartbeat_rtt_ms: self
+                .heartbeat_rtt
+                .map(|heartbeat_rtt| heartbeat_rtt.as_millis() as u64),
+            reconnect_count: self.reconnect_count,
+            seconds_since_last_event: since_last_event.map(|duration| duration.as_secs()),
+            degraded: !connected
+                || since_last_event.is_none_or(|since_last_event| since_last_event >= stale_after),
+        }
+    }
+}
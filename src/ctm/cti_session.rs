@@ -0,0 +1,212 @@
+use std::{
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+
+use crate::cisco::{
+    session::{CallMessageMask, OpenConf, ServicesRequested},
+    InvokeId, MonitorId, PeripheralType,
+};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+///
+/// CTI 세션 모드 (CTI_SERVER_SESSION_MODE)
+///
+pub enum SessionMode {
+    // CTI_SERVER_SERVICES_REQUESTED 를 그대로 요청한다
+    #[default]
+    CUSTOM,
+    // All Events 서비스로 주변장치 전체 상담직원의 호 이벤트를 받는다 (호 상태는 모아서 전송)
+    ALL_EVENTS,
+    // Client Events 서비스로 상담직원 한 명(ID, 내선 또는 장치)의 이벤트만 받는다
+    CLIENT_EVENTS,
+}
+
+impl SessionMode {
+    ///
+    /// OPEN_REQ 로 요청할 서비스 마스크
+    ///
+    /// All Events 와 Client Events 는 함께 요청할 수 없으므로 한쪽 모드는 다른 쪽 서비스를 뺀다.
+    ///
+    pub fn services_requested(&self, services_requested: ServicesRequested) -> ServicesRequested {
+        match self {
+            SessionMode::CUSTOM => services_requested,
+            SessionMode::ALL_EVENTS => {
+                services_requested.difference(ServicesRequested::CLIENT_EVENTS)
+                    | ServicesRequested::ALL_EVENTS
+            }
+            SessionMode::CLIENT_EVENTS => {
+                services_requested.difference(ServicesRequested::ALL_EVENTS)
+                    | ServicesRequested::CLIENT_EVENTS
+            }
+        }
+    }
+}
+
+impl FromStr for SessionMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "" | "custom" => Ok(SessionMode::CUSTOM),
+            "all_events" => Ok(SessionMode::ALL_EVENTS),
+            "client_events" | "agent" => Ok(SessionMode::CLIENT_EVENTS),
+            _ => Err(format!("Unsupported session mode '{}'", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+///
+/// CTI 서버 세션 정보
+///
+/// OPEN_CONF 로 받은 MonitorID 와 허용된 서비스를 보관하며, 이후 모니터 요청
+/// (CHANGE_MONITOR_MASK_REQ) 에 MonitorID 를 사용한다. 세션이 끊기면 버린다.
+/// Client Events 세션이면 OPEN_CONF 로 받은 상담직원 ID, 내선, 장치와 상태도 보관한다.
+/// opened_at 은 UNIX 시간(밀리초)이다.
+///
+pub struct CtiSession {
+    cti_instance: String,
+    monitor_id: u32,
+    services_granted: u32,
+    pg_status: u32,
+    peripheral_type: PeripheralType,
+    department_id: i32,
+    session_type: u16,
+    agent_id: Option<String>,
+    agent_extension: Option<String>,
+    agent_instrument: Option<String>,
+    agent_state: u16,
+    call_msg_mask: u32,
+    // 응답을 기다리는 호 이벤트 마스크 변경 요청
+    #[serde(skip)]
+    pending_call_msg_mask: Option<(InvokeId, u32)>,
+    opened_at: u64,
+}
+
+impl CtiSession {
+    pub fn new(
+        cti_instance: impl Into<String>,
+        open_conf: &OpenConf,
+        call_msg_mask: CallMessageMask,
+    ) -> Self {
+        Self {
+            cti_instance: cti_instance.into(),
+            monitor_id: open_conf.monitor_id.0,
+            services_granted: open_conf.service_granted,
+            pg_status: open_conf.pg_status,
+            peripheral_type: open_conf.peripheral_type,
+            department_id: open_conf.department_id,
+            session_type: open_conf.session_type,
+            agent_id: open_conf
+                .agent_id
+                .as_ref()
+                .map(|agent_id| agent_id.data.clone())
+                .filter(|agent_id| !agent_id.is_empty()),
+            agent_extension: open_conf
+                .agent_extension
+                .as_ref()
+                .map(|agent_extension| agent_extension.data.clone())
+                .filter(|agent_extension| !agent_extension.is_empty()),
+            agent_instrument: open_conf
+                .agent_instrument
+                .as_ref()
+                .map(|agent_instrument| agent_instrument.data.clone())
+                .filter(|agent_instrument| !agent_instrument.is_empty()),
+            agent_state: open_conf.agent_state,
+            call_msg_mask: call_msg_mask.bits(),
+            pending_call_msg_mask: None,
+            opened_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    ///
+    /// 세션의 이벤트 모니터 ID (모니터가 만들어지지 않았으면 None)
+    ///
+    pub fn get_monitor_id(&self) -> Option<MonitorId> {
+        match self.monitor_id {
+            0 => None,
+            monitor_id => Some(MonitorId(monitor_id)),
+        }
+    }
+
+    pub fn get_services_granted(&self) -> u32 {
+        self.services_granted
+    }
+
+    pub fn get_pg_status(&self) -> u32 {
+        self.pg_status
+    }
+
+    pub fn get_peripheral_type(&self) -> PeripheralType {
+        self.peripheral_type
+    }
+
+    pub fn get_department_id(&self) -> i32 {
+        self.department_id
+    }
+
+    pub fn get_session_type(&self) -> u16 {
+        self.session_type
+    }
+
+    ///
+    /// 세션을 연 상담직원 ID (Client Events 세션이 아니면 None)
+    ///
+    pub fn get_agent_id(&self) -> Option<&str> {
+        self.agent_id.as_deref()
+    }
+
+    pub fn get_agent_extension(&self) -> Option<&str> {
+        self.agent_extension.as_deref()
+    }
+
+    pub fn get_agent_instrument(&self) -> Option<&str> {
+        self.agent_instrument.as_deref()
+    }
+
+    pub fn get_agent_state(&self) -> u16 {
+        self.agent_state
+    }
+
+    pub fn get_call_msg_mask(&self) -> u32 {
+        self.call_msg_mask
+    }
+
+    pub fn get_opened_at(&self) -> u64 {
+        self.opened_at
+    }
+
+    ///
+    /// 호 이벤트 마스크 변경 요청을 기록한다
+    ///
+    pub fn request_call_msg_mask(&mut self, invoke_id: InvokeId, call_msg_mask: CallMessageMask) {
+        self.pending_call_msg_mask = Some((invoke_id, call_msg_mask.bits()));
+    }
+
+    ///
+    /// CHANGE_MONITOR_MASK_CONF 를 받으면 요청한 호 이벤트 마스크를 적용한다
+    ///
+    /// 기다리는 요청의 응답이 아니면 None 을 반환한다.
+    ///
+    pub fn confirm_call_msg_mask(&mut self, invoke_id: &InvokeId) -> Option<u32> {
+        match self.pending_call_msg_mask {
+            Some((pending_invoke_id, call_msg_mask)) if pending_invoke_id == *invoke_id => {
+                self.pending_call_msg_mask = None;
+                self.call_msg_mask = call_msg_mask;
+                Some(call_msg_mask)
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// CTI 서버 상태 재동기화 단계
+///
+pub enum CtiSyncStatus {
+    // 재접속 후 상담직원 상태를 다시 조회하는 중
+    RESYNCING,
+    // 조회 응답을 모두 받았거나 조회 제한 시간이 지남
+    RESYNCED,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// CTI 서버 상태 재동기화 이벤트
+///
+/// requested_agents 는 상태를 다시 조회한 상담직원 수이며,
+/// unanswered_agents 는 RESYNCED 시점까지 응답을 받지 못한 상담직원 수이다.
+///
+pub struct CtiSyncState {
+    cti_instance: String,
+    status: CtiSyncStatus,
+    requested_agents: usize,
+    unanswered_agents: usize,
+}
+
+impl CtiSyncState {
+    pub fn new(
+        cti_instance: impl Into<String>,
+        status: CtiSyncStatus,
+        requested_agents: usize,
+        unanswered_agents: usize,
+    ) -> Self {
+        Self {
+            cti_instance: cti_instance.into(),
+            status,
+            requested_agents,
+            unanswered_agents,
+        }
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn get_status(&self) -> CtiSyncStatus {
+        self.status
+    }
+
+    pub fn get_requested_agents(&self) -> usize {
+        self.requested_agents
+    }
+
+    pub fn get_unanswered_agents(&self) -> usize {
+        self.unanswered_agents
+    }
+}
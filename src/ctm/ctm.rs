@@ -1,359 +1,4437 @@
-use std::{collections::HashMap, error::Error, thread, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
+use chrono::Local;
 use tokio::{
     sync::{broadcast, mpsc},
-    time::timeout,
+    task::JoinHandle,
+    time::{interval, sleep, MissedTickBehavior},
 };
 use uuid::Uuid;
 
 use crate::{
     cisco::{
-        client_event::agent_state_event::AgentStateEvent,
-        control::query_agent_state_conf::QueryAgentStateConf, session::OpenConf,
-        supervisor::agent_team_config_event::AgentTeamConfigEvent, Deserializable, MessageType,
+        client_event::{
+            agent_state_event::AgentStateEvent, begin_call_event::BeginCallEvent,
+            call_cleared_event::CallClearedEvent, call_established_event::CallEstablishedEvent,
+            call_queued_event::CallQueuedEvent,
+        },
+        codec::CtiCodec,
+        config::config_agent_event::ConfigAgentEvent,
+        control::query_agent_state_conf::QueryAgentStateConf,
+        session::{change_monitor_mask_conf::ChangeMonitorMaskConf, CallMessageMask, OpenConf},
+        supervisor::{
+            agent_team_config_event::AgentTeamConfigEvent, emergency_call_event::EmergencyCallEvent,
+        },
+        ConnectionCallId, InvokeId, InvokeIdGenerator, MessageType, PeripheralId,
     },
     ctm::cti_client::CTIClient,
-    event::{broker_event::BrokerEvent, client_event::ClientEvent, cti_event::CTIEvent},
+    event::{
+        broker_event::BrokerEvent, client_event::ClientEvent, cti_event::CTIEvent,
+        system_event::SystemEvent,
+    },
 };
 
 use super::{
-    acceptor::{tcp_acceptor::TCPAcceptor, websocket_acceptor::WebsocketAcceptor, Acceptor},
+    acceptor::{
+        amqp_publisher::AmqpPublisher, database_writer::DatabaseWriter,
+        graphql_acceptor::GraphQLAcceptor, health_server::HealthServer,
+        mqtt_publisher::MqttPublisher, payload_format::SharedPayload,
+        redis_publisher::RedisPublisher, tcp_acceptor::TCPAcceptor,
+        udp_multicast_publisher::UdpMulticastPublisher, webhook_publisher::WebhookPublisher,
+        websocket_acceptor::WebsocketAcceptor, Acceptor,
+    },
+    agent_delta::{AgentDeltaRecord, AgentDeltaTracker},
+    agent_device_index::AgentDeviceIndex,
     agent_info::AgentInfo,
+    agent_query::AgentQueryResult,
+    agent_removal::{AgentRemoval, AgentRemovalReason},
+    agent_state_history::AgentStateHistory,
+    agent_statistics::AgentStatistics,
+    call_batch::CallBroadcastBatch,
+    call_info::{CallInfo, CallState},
+    capture::{read_capture, replay_capture},
+    channel_metrics::ChannelMetrics,
+    client_auth::ClientAuthenticator,
+    client_info::ClientInfo,
+    client_subscription::ClientSubscription,
+    command::ClientCommand,
+    config::{reload_config_file, Config},
+    cti_link_status::CtiLinkTracker,
+    cti_session::{CtiSession, SessionMode},
+    cti_sync_state::{CtiSyncState, CtiSyncStatus},
+    field_masker::FieldMasker,
+    firehose::FirehoseMessage,
+    ha::{HaEvent, HaNode},
+    ha_status::{HaRole, HaStatus},
+    health::HealthState,
+    journal::{read_journal, JournalRecord, JournalWriter},
+    locale::LocaleDictionary,
+    logging::reload_logging,
+    media_state::{MediaState, DEFAULT_VOICE_MRD_ID},
+    peer_sync::{PeerSyncClient, PeerSyncMessage, PeerSyncPublisher, PeerSyncServer},
+    reason_code::ReasonCodeDictionary,
+    rebroadcast::FullStateRebroadcast,
+    reconnect::{ReconnectPolicy, ReconnectState},
+    session_resume::SessionResume,
+    shutdown::{listen_reload_signal, ShutdownController},
+    skill_group_info::SkillGroupInfo,
+    snapshot::CtmSnapshot,
+    state_refresh::{StateRefreshLimiter, StateRefreshResult, StateRefreshScope},
+    subscriber::CtmSubscriber,
+    supervisor_alert::{SupervisorAlert, SupervisorAlertType},
+    systemd::SystemdNotifier,
+    team_info::TeamInfo,
+    wallboard_summary::WallboardSummary,
+    welcome::{CtiConnectionStatus, Welcome},
 };
 
-pub struct CTM {
+// 이벤트가 없을 때 주기 작업(집계 전송, 조회 시간 초과, 스냅샷 저장)을 확인하는 간격
+const HOUSEKEEPING_INTERVAL: Duration = Duration::from_millis(100);
+
+///
+/// CTI 서버 인스턴스별 상태
+///
+/// 여러 UCCE 를 동시에 감시할 때 상담직원/호/팀/스킬그룹 ID 가 겹치지 않도록 인스턴스별로 분리한다.
+///
+#[derive(Default)]
+struct CtiInstanceState {
     is_active: bool,
-    cti_client: CTIClient,
+    reconnect_policy: ReconnectPolicy,
+    reconnect_state: ReconnectState,
+    agent_info_map: HashMap<String, AgentInfo>,
+    // 내선, 장치 번호로 상담직원을 찾는 역색인
+    agent_device_index: AgentDeviceIndex,
+    call_info_map: HashMap<ConnectionCallId, CallInfo>,
+    // ALL_EVENTS 세션 모드에서 모아서 전송할 호 상태
+    call_broadcast_batch: Option<CallBroadcastBatch>,
+    skill_group_agent_map: HashMap<u32, HashMap<String, u16>>,
+    team_info_map: HashMap<u32, TeamInfo>,
+    agent_name_map: HashMap<String, String>,
+    agent_state_history_map: HashMap<String, AgentStateHistory>,
+    agent_statistics_map: HashMap<String, AgentStatistics>,
+    agent_logout_map: HashMap<String, Instant>,
+    pending_agent_query_map: HashMap<InvokeId, PendingAgentQuery>,
+    // OPEN_CONF 를 받은 적이 있으면 이후 OPEN_CONF 는 재접속이다
+    is_opened: bool,
+    // CTI 세션 접속 여부와 OPEN_CONF 로 받은 주변장치 온라인 여부
+    is_connected: bool,
+    peripheral_online: bool,
+    // 접속 중인 CTI 세션 정보 (OPEN_CONF 로 받은 MonitorID)
+    session: Option<CtiSession>,
+    // 재접속 후 상태 재동기화 중인 상담직원 조회 요청
+    resync_invoke_ids: HashSet<InvokeId>,
+    resync_requested: usize,
+    resync_started_at: Option<Instant>,
+    // 하트비트 응답 시간, 재접속 횟수, 마지막 CTI 메시지 수신 시각
+    cti_link: CtiLinkTracker,
+}
+
+///
+/// CTI 서버 응답을 기다리는 클라이언트 상담직원 조회 요청
+///
+struct PendingAgentQuery {
+    client_id: Uuid,
+    request_id: String,
+    agent_id: String,
+    requested_at: Instant,
+}
+
+pub struct CTM {
+    invoke_id_generator: InvokeIdGenerator,
+    cti_clients: Vec<CTIClient>,
     cti_event_channel_rx: mpsc::Receiver<CTIEvent>,
     cti_event_channel_tx: mpsc::Sender<CTIEvent>,
     broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
     broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
     client_event_channel_rx: mpsc::Receiver<ClientEvent>,
     client_event_channel_tx: mpsc::Sender<ClientEvent>,
-    agent_info_map: HashMap<String, AgentInfo>,
+    cti_instance_state_map: HashMap<String, CtiInstanceState>,
+    agent_delta_tracker: AgentDeltaTracker,
+    agent_delta_broadcast: bool,
+    agent_state_history_size: usize,
+    agent_logout_eviction: Option<Duration>,
+    agent_team_delete_eviction: bool,
+    reason_code_dictionary: ReasonCodeDictionary,
+    locale_dictionary: LocaleDictionary,
+    field_masker: FieldMasker,
+    skill_group_broadcast_interval: Duration,
+    last_skill_group_broadcast: Instant,
+    wallboard_broadcast_interval: Duration,
+    last_wallboard_broadcast: Instant,
+    last_cti_link_status_broadcast: Instant,
+    full_state_rebroadcast: Option<FullStateRebroadcast>,
+    snapshot_file: Option<String>,
+    snapshot_interval: Duration,
+    last_snapshot_save: Instant,
+    journal_writer: Option<JournalWriter>,
+    replay_file: Option<PathBuf>,
+    capture_replay_file: Option<PathBuf>,
+    client_subscription_map: HashMap<Uuid, ClientSubscription>,
+    // 신규 클라이언트의 기본 구독 조건
+    default_client_subscription: ClientSubscription,
+    client_info_map: HashMap<Uuid, ClientInfo>,
+    // 클라이언트 전용 이벤트 큐 (큐가 없는 클라이언트는 브로커 채널로 받는다)
+    direct_event_tx_map: HashMap<Uuid, mpsc::UnboundedSender<Vec<BrokerEvent>>>,
+    // 복호화한 CTI 메시지 전체를 받는 클라이언트
+    firehose_client_ids: HashSet<Uuid>,
+    channel_metrics: Arc<ChannelMetrics>,
+    agent_query_timeout: Duration,
+    state_refresh_limiter: StateRefreshLimiter,
+    acceptors: Vec<Box<dyn Acceptor>>,
+    subscribers: Vec<Arc<dyn CtmSubscriber>>,
+    cti_client_running_map: HashMap<String, Arc<AtomicBool>>,
+    shutdown_controller: ShutdownController,
+    shutdown_timeout: Duration,
+    // HA 를 사용하면 실행 시 역할 선출을 시작하는 노드
+    ha_node: Option<HaNode>,
+    ha_node_id: Option<String>,
+    ha_role: HaRole,
+    ha_peer_node_id: Option<String>,
+    ha_mirror_tx: Option<mpsc::Sender<CtmSnapshot>>,
+    ha_mirror_interval: Duration,
+    last_ha_mirror: Instant,
+    // HA 대기 노드로 전환되면 대기 중인 CTI 재접속을 취소한다
+    cti_session_controller: ShutdownController,
+    // 노드 간 상태 동기화 (실행 시 서버, 클라이언트를 시작한다)
+    peer_sync_server: Option<PeerSyncServer>,
+    peer_sync_publisher: Option<Arc<PeerSyncPublisher>>,
+    peer_sync_client: Option<PeerSyncClient>,
+    peer_sync_read_only: bool,
+    peer_sync_snapshot_interval: Duration,
+    last_peer_sync_snapshot: Instant,
+    systemd_notifier: SystemdNotifier,
+    config: Config,
 }
 
-impl CTM {
+///
+/// CTM 빌더
+///
+/// 기본 TCP/웹 소켓 Acceptor 외에 사용자 정의 Acceptor 를 등록할 수 있다.
+///
+#[derive(Default)]
+pub struct CTMBuilder {
+    acceptors: Vec<Box<dyn Acceptor>>,
+    subscribers: Vec<Arc<dyn CtmSubscriber>>,
+    replay_file: Option<PathBuf>,
+    capture_replay_file: Option<PathBuf>,
+    state_import_file: Option<PathBuf>,
+    channel_metrics: Arc<ChannelMetrics>,
+    shutdown_controller: Option<ShutdownController>,
+    config: Option<Config>,
+}
+
+impl CTMBuilder {
+    ///
+    /// 설정 등록
+    ///
+    /// 등록하지 않으면 빌드할 때 환경 변수와 설정 파일에서 읽는다.
+    ///
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    ///
+    /// Acceptor 등록
+    ///
+    pub fn with_acceptor(mut self, acceptor: Box<dyn Acceptor>) -> Self {
+        self.acceptors.push(acceptor);
+        self
+    }
+
+    ///
+    /// 이벤트 구독자 등록
+    ///
+    pub fn with_subscriber(mut self, subscriber: Arc<dyn CtmSubscriber>) -> Self {
+        self.subscribers.push(subscriber);
+        self
+    }
+
+    ///
+    /// 브로커 채널 지표 등록
+    ///
+    /// 상태 확인 서버 등 지표를 내보내는 곳과 같은 지표를 공유할 때 사용한다.
+    ///
+    pub fn with_channel_metrics(mut self, channel_metrics: Arc<ChannelMetrics>) -> Self {
+        self.channel_metrics = channel_metrics;
+        self
+    }
+
+    ///
+    /// 종료 제어기 등록
+    ///
+    /// Windows 서비스처럼 CTM 생성 전에 종료 요청을 받는 곳과 공유할 때 사용한다.
+    ///
+    pub fn with_shutdown_controller(mut self, shutdown_controller: ShutdownController) -> Self {
+        self.shutdown_controller = Some(shutdown_controller);
+        self
+    }
+
+    ///
+    /// CTI 서버에 접속하지 않고 저널 파일을 재생한다
+    ///
+    pub fn with_replay(mut self, replay_file: impl Into<PathBuf>) -> Self {
+        self.replay_file = Some(replay_file.into());
+        self
+    }
+
+    ///
+    /// CTI 서버에 접속하지 않고 원본 트래픽 캡처 파일을 재생한다
+    ///
+    pub fn with_capture_replay(mut self, capture_replay_file: impl Into<PathBuf>) -> Self {
+        self.capture_replay_file = Some(capture_replay_file.into());
+        self
+    }
+
+    ///
+    /// 저장된 상태 스냅샷 대신 JSON 상태 파일로 상태를 복원한다
+    ///
+    /// 관리 명령 EXPORT_STATE 로 받은 운영 상태를 개발 환경에서 재현할 때 사용한다.
+    ///
+    pub fn with_state_import(mut self, state_import_file: impl Into<PathBuf>) -> Self {
+        self.state_import_file = Some(state_import_file.into());
+        self
+    }
+
     ///
     /// 새로운 CTM 구조체 생성
     ///
-    pub async fn new() -> Result<Self, Box<dyn Error>> {
-        let is_active = true;
-        let (cti_event_channel_tx, cti_event_channel_rx) = mpsc::channel::<CTIEvent>(1_024);
+    pub async fn build(self) -> Result<CTM, Box<dyn Error>> {
+        let config = match self.config {
+            Some(config) => config,
+            None => Config::load()?,
+        };
+
+        // 채널 용량 (브로커 채널 용량을 넘게 밀린 클라이언트는 이벤트를 놓치고 전체 상태를 다시 받는다)
+        let broker_event_channel_capacity = config.ctm.broker_event_channel_capacity;
+        self.channel_metrics
+            .set_broker_event_capacity(broker_event_channel_capacity);
+
+        let (cti_event_channel_tx, cti_event_channel_rx) =
+            mpsc::channel::<CTIEvent>(config.ctm.cti_event_channel_capacity);
         let (broker_event_channel_tx, broker_event_channel_rx) =
-            broadcast::channel::<BrokerEvent>(1_024);
+            broadcast::channel::<BrokerEvent>(broker_event_channel_capacity);
         let (client_event_channel_tx, client_event_channel_rx) =
-            mpsc::channel::<ClientEvent>(4_096);
+            mpsc::channel::<ClientEvent>(config.ctm.client_event_channel_capacity);
 
-        let cti_client = CTIClient::new(
-            is_active,
-            cti_event_channel_tx.clone(),
-            broker_event_channel_rx.resubscribe(),
-        )
-        .await?;
+        // 이중화 전환으로 CTI 클라이언트를 다시 생성해도 InvokeID 를 이어서 사용한다
+        let invoke_id_generator = InvokeIdGenerator::new();
+
+        // CTI 서버 이중화 쌍마다 CTI 클라이언트를 생성한다
+        let mut cti_clients = vec![];
+        let mut cti_instance_state_map = HashMap::new();
+        for cti_client_config in config.cti_clients.iter() {
+            let cti_instance = cti_client_config.cti_instance.clone();
+            cti_clients.push(
+                CTIClient::new(
+                    cti_client_config.clone(),
+                    true,
+                    invoke_id_generator.clone(),
+                    cti_event_channel_tx.clone(),
+                    broker_event_channel_rx.resubscribe(),
+                )
+                .await?,
+            );
+            cti_instance_state_map.insert(
+                cti_instance.clone(),
+                CtiInstanceState {
+                    is_active: true,
+                    reconnect_policy: cti_client_config.reconnect_policy.clone(),
+                    call_broadcast_batch: CTM::call_broadcast_batch(&config, &cti_instance),
+                    ..Default::default()
+                },
+            );
+        }
+
+        // 상태 스냅샷 파일 (빈 값 또는 저널/캡처 재생 중: 저장하지 않음)
+        let is_replay = self.replay_file.is_some() || self.capture_replay_file.is_some();
+        let snapshot_file = config.ctm.snapshot_file.clone().filter(|_| !is_replay);
+
+        // 상태 스냅샷 저장 주기
+        let snapshot_interval = config.ctm.snapshot_interval;
+
+        // 불러올 상태 파일이 없으면 이전 실행에서 저장한 스냅샷으로 상태 복원
+        let snapshot = match (self.state_import_file.as_ref(), snapshot_file.as_ref()) {
+            (Some(state_import_file), _) => {
+                let snapshot = CtmSnapshot::from_json_file(state_import_file)?;
+                log::info!(
+                    "Imported state. state_import_file: {}, agents: {}, calls: {}, teams: {}",
+                    state_import_file.display(),
+                    snapshot.agent_infos.len(),
+                    snapshot.call_infos.len(),
+                    snapshot.team_infos.len()
+                );
+                snapshot
+            }
+            (None, Some(snapshot_file)) => match CtmSnapshot::from_file(snapshot_file) {
+                Ok(snapshot) => {
+                    log::info!(
+                        "Restored state snapshot. snapshot_file: {}, agents: {}, calls: {}, teams: {}",
+                        snapshot_file,
+                        snapshot.agent_infos.len(),
+                        snapshot.call_infos.len(),
+                        snapshot.team_infos.len()
+                    );
+                    snapshot
+                }
+                Err(e) => {
+                    log::warn!(
+                        "Unable to restore state snapshot. snapshot_file: {}, error: {}",
+                        snapshot_file,
+                        e
+                    );
+                    CtmSnapshot::default()
+                }
+            },
+            (None, None) => CtmSnapshot::default(),
+        };
+
+        CTM::apply_snapshot(&mut cti_instance_state_map, snapshot);
 
-        let agent_info_map = HashMap::new();
+        // 상담직원 상태 변경분 전송 여부 (false: 변경 시 전체 상태 전송)
+        let agent_delta_broadcast = config.ctm.agent_delta_broadcast;
 
-        Ok(Self {
-            is_active,
-            cti_client,
+        // 클라이언트 전송 전 내선번호, ANI 마스킹 설정
+        let field_masker = config.ctm.field_masker.clone();
+
+        // 복원한 상담직원 상태를 마스킹하여 마지막 전송 상태로 등록한다
+        // 재접속 클라이언트의 이어 받기를 위해 최근 변경 기록을 함께 보관한다
+        let mut agent_delta_tracker =
+            AgentDeltaTracker::with_history_size(config.ctm.agent_delta_history_size);
+        CTM::track_agent_infos(
+            &mut agent_delta_tracker,
+            &cti_instance_state_map,
+            &field_masker,
+        );
+
+        // 로그아웃한 상담직원 제거 대기 시간 (None: 제거하지 않음)
+        let agent_logout_eviction = config.ctm.agent_logout_eviction;
+
+        // 팀 구성에서 삭제된 상담직원 제거 여부
+        let agent_team_delete_eviction = config.ctm.agent_team_delete_eviction;
+
+        // 상담직원별 상태 변경 이력 보관 개수
+        let agent_state_history_size = config.ctm.agent_state_history_size;
+        let reason_code_dictionary = ReasonCodeDictionary::load(&config.ctm.reason_code_file);
+        let locale_dictionary = LocaleDictionary::load(&config.ctm.locale_file);
+
+        // 스킬그룹 집계, 상황판 요약 지표 전송 주기
+        let skill_group_broadcast_interval = config.ctm.skill_group_broadcast_interval;
+        let wallboard_broadcast_interval = config.ctm.wallboard_broadcast_interval;
+
+        // 전체 상태 주기 재전송 일정 (None: 재전송하지 않음)
+        let full_state_rebroadcast = config.ctm.full_state_broadcast_interval.map(|interval| {
+            FullStateRebroadcast::new(
+                interval,
+                config.ctm.full_state_broadcast_stagger,
+                Instant::now(),
+            )
+        });
+
+        // 클라이언트 상담직원 조회 응답 대기 시간
+        let agent_query_timeout = config.ctm.agent_query_timeout;
+
+        // 클라이언트 상태 재조회 요청 빈도 제한
+        let state_refresh_limiter = StateRefreshLimiter::new(config.ctm.state_refresh_min_interval);
+
+        // 종료 시 Acceptor, CTI 세션 종료를 기다리는 최대 시간
+        let shutdown_timeout = config.ctm.shutdown_timeout;
+
+        // 재생 중에는 저널을 다시 기록하지 않는다
+        let journal_writer = match is_replay {
+            true => None,
+            false => config
+                .ctm
+                .journal_directory
+                .as_ref()
+                .map(|journal_directory| {
+                    JournalWriter::new(journal_directory, config.ctm.journal_segment_size)
+                }),
+        };
+
+        // 활성/대기 HA 노드 (재생 중: 사용하지 않음)
+        let ha_node = match config.ha.enabled && !is_replay {
+            true => Some(HaNode::bind(config.ha.clone()).await?),
+            false => None,
+        };
+        let ha_node_id = ha_node
+            .as_ref()
+            .map(|ha_node| ha_node.get_node_id().to_string());
+        let ha_role = match ha_node.is_some() {
+            true => HaRole::STANDBY,
+            false => HaRole::ACTIVE,
+        };
+
+        // 노드 간 상태 동기화 (재생 중: 사용하지 않음)
+        let mut subscribers = self.subscribers;
+        let peer_sync_server = match config.peer_sync.enabled && !is_replay {
+            true => Some(PeerSyncServer::bind(&config.peer_sync).await?),
+            false => None,
+        };
+        let peer_sync_publisher = peer_sync_server.as_ref().map(PeerSyncServer::get_publisher);
+        if let Some(peer_sync_publisher) = peer_sync_publisher.as_ref() {
+            subscribers.push(peer_sync_publisher.clone());
+        }
+        let peer_sync_client = (!config.peer_sync.sources.is_empty() && !is_replay)
+            .then(|| PeerSyncClient::new(&config.peer_sync));
+        let peer_sync_read_only = config.peer_sync.read_only && !is_replay;
+
+        Ok(CTM {
+            invoke_id_generator,
+            cti_clients,
             cti_event_channel_rx,
             cti_event_channel_tx,
             broker_event_channel_rx,
             broker_event_channel_tx,
             client_event_channel_rx,
             client_event_channel_tx,
-            agent_info_map,
+            cti_instance_state_map,
+            agent_delta_tracker,
+            agent_delta_broadcast,
+            agent_state_history_size,
+            agent_logout_eviction,
+            agent_team_delete_eviction,
+            reason_code_dictionary,
+            locale_dictionary,
+            field_masker,
+            skill_group_broadcast_interval,
+            last_skill_group_broadcast: Instant::now(),
+            wallboard_broadcast_interval,
+            last_wallboard_broadcast: Instant::now(),
+            last_cti_link_status_broadcast: Instant::now(),
+            full_state_rebroadcast,
+            snapshot_file,
+            snapshot_interval,
+            last_snapshot_save: Instant::now(),
+            journal_writer,
+            replay_file: self.replay_file,
+            capture_replay_file: self.capture_replay_file,
+            client_subscription_map: HashMap::new(),
+            default_client_subscription: config.ctm.default_client_subscription.clone(),
+            client_info_map: HashMap::new(),
+            direct_event_tx_map: HashMap::new(),
+            firehose_client_ids: HashSet::new(),
+            channel_metrics: self.channel_metrics,
+            agent_query_timeout,
+            state_refresh_limiter,
+            acceptors: self.acceptors,
+            subscribers,
+            cti_client_running_map: HashMap::new(),
+            shutdown_controller: self.shutdown_controller.unwrap_or_default(),
+            shutdown_timeout,
+            ha_node,
+            ha_node_id,
+            ha_role,
+            ha_peer_node_id: None,
+            ha_mirror_tx: None,
+            ha_mirror_interval: config.ha.mirror_interval,
+            last_ha_mirror: Instant::now(),
+            cti_session_controller: ShutdownController::new(),
+            peer_sync_server,
+            peer_sync_publisher,
+            peer_sync_client,
+            peer_sync_read_only,
+            peer_sync_snapshot_interval: config.peer_sync.snapshot_interval,
+            last_peer_sync_snapshot: Instant::now(),
+            systemd_notifier: SystemdNotifier::new(),
+            config,
         })
     }
+}
 
+impl CTM {
     ///
-    /// CTM 서버 실행
+    /// CTM 빌더 생성
     ///
-    pub async fn start(mut self) -> Result<(), Box<dyn Error>> {
-        self.cti_client.connect().await;
+    pub fn builder() -> CTMBuilder {
+        CTMBuilder::default()
+    }
 
-        let mut acceptors: Vec<Box<dyn Acceptor>> = Vec::new();
+    ///
+    /// 환경 설정에 따라 기본 Acceptor 를 등록한 CTM 구조체 생성
+    ///
+    pub async fn new() -> Result<Self, Box<dyn Error>> {
+        Self::default_builder(Config::load()?).await.build().await
+    }
 
-        // TCP Acceptor 생성
-        if dotenv::var("TCP_ACCEPTOR_ENABLED")
-            .unwrap_or("false".to_string())
-            .parse::<bool>()
-            .unwrap_or(false)
-        {
-            let broker_event_channel_rx = self.broker_event_channel_rx.resubscribe();
-            let client_event_channel_tx = self.client_event_channel_tx.clone();
+    ///
+    /// 환경 설정에 따라 기본 Acceptor 를 등록하고 저널 파일을 재생하는 CTM 구조체 생성
+    ///
+    pub async fn new_replay(replay_file: impl Into<PathBuf>) -> Result<Self, Box<dyn Error>> {
+        Self::default_builder(Config::load()?)
+            .await
+            .with_replay(replay_file)
+            .build()
+            .await
+    }
+
+    ///
+    /// 환경 설정에 따라 기본 Acceptor 를 등록하고 원본 트래픽 캡처 파일을 재생하는 CTM 구조체 생성
+    ///
+    pub async fn new_capture_replay(
+        capture_replay_file: impl Into<PathBuf>,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::default_builder(Config::load()?)
+            .await
+            .with_capture_replay(capture_replay_file)
+            .build()
+            .await
+    }
 
-            match TCPAcceptor::new(broker_event_channel_rx, client_event_channel_tx).await {
-                Ok(acceptor) => acceptors.push(Box::new(acceptor)),
-                Err(_) => {}
+    ///
+    /// 환경 설정에 따라 기본 Acceptor 를 등록한 CTM 빌더 생성
+    ///
+    pub async fn default_builder(config: Config) -> CTMBuilder {
+        let channel_metrics = Arc::new(ChannelMetrics::new());
+        let mut builder = Self::builder().with_channel_metrics(channel_metrics.clone());
+
+        // 클라이언트 인증 키는 모든 Acceptor 가 함께 사용한다
+        let client_authenticator = Arc::new(ClientAuthenticator::load(&config.client_auth));
+
+        // TCP Acceptor 생성
+        // 리스너가 여러 개이면 리스너마다 Acceptor 를 하나씩 생성한다
+        for tcp_acceptor in config.tcp_acceptors.iter().filter(|config| config.enabled) {
+            match TCPAcceptor::new(tcp_acceptor, client_authenticator.clone()).await {
+                Ok(acceptor) => builder = builder.with_acceptor(Box::new(acceptor)),
+                Err(e) => log::error!(
+                    "Unable to create TCP acceptor. (listener: {}, address: {}:{}) {}",
+                    tcp_acceptor.listener.as_deref().unwrap_or("default"),
+                    tcp_acceptor.bind_address,
+                    tcp_acceptor.port,
+                    e
+                ),
             }
         }
 
         // 웹 소켓 Acceptor 생성
-        if dotenv::var("WEBSOCKET_ACCEPTOR_ENABLED")
-            .unwrap_or("false".to_string())
-            .parse::<bool>()
-            .unwrap_or(false)
+        for websocket_acceptor in config
+            .websocket_acceptors
+            .iter()
+            .filter(|config| config.enabled)
         {
-            let broker_event_channel_rx = self.broker_event_channel_rx.resubscribe();
-            let client_event_channel_tx = self.client_event_channel_tx.clone();
-
-            match WebsocketAcceptor::new(broker_event_channel_rx, client_event_channel_tx).await {
-                Ok(acceptor) => acceptors.push(Box::new(acceptor)),
-                Err(_) => {}
+            match WebsocketAcceptor::new(websocket_acceptor, client_authenticator.clone()).await {
+                Ok(acceptor) => builder = builder.with_acceptor(Box::new(acceptor)),
+                Err(e) => log::error!(
+                    "Unable to create websocket acceptor. (listener: {}, address: {}:{}) {}",
+                    websocket_acceptor.listener.as_deref().unwrap_or("default"),
+                    websocket_acceptor.bind_address,
+                    websocket_acceptor.port,
+                    e
+                ),
             }
         }
 
-        // Acceptor 실행
-        for acceptor in acceptors {
-            tokio::spawn(async move {
-                acceptor.accept().await.unwrap();
-            });
+        // GraphQL Acceptor 생성
+        if config.components.graphql_acceptor {
+            match GraphQLAcceptor::new(&config.graphql_acceptor, client_authenticator.clone()).await
+            {
+                Ok(acceptor) => builder = builder.with_acceptor(Box::new(acceptor)),
+                Err(e) => log::error!("Unable to create GraphQL acceptor. {}", e),
+            }
         }
 
-        loop {
-            // CTI 이벤트 채널 데이터 수신
-            match timeout(Duration::from_millis(10), self.cti_event_channel_rx.recv()).await {
-                Ok(Some(event)) => match event {
-                    // HeartBeat 요청 전송 시간 이벤트 수신
-                    CTIEvent::TimeToHeartBeat => {
-                        log::debug!("Received time to send heartbeat event.");
-                        self.broker_event_channel_tx
-                            .send(BrokerEvent::RequestHeartBeatReq)
-                            .unwrap();
-                    }
-                    // 오류 이벤트 수신
-                    CTIEvent::Error {
-                        cti_server_host,
-                        error_cause,
-                    } => {
-                        log::warn!(
-                            "Received CTI Server error. cti_server_host: {}, error_cause: {}",
-                            cti_server_host,
-                            error_cause
-                        );
-
-                        // CTI 서버가 이중화 넘어가는데 시간이 소요됨
-                        thread::sleep(Duration::from_millis(500));
-                        self.is_active = !self.is_active;
-                        self.cti_client = CTIClient::new(
-                            self.is_active,
-                            self.cti_event_channel_tx.clone(),
-                            self.broker_event_channel_rx.resubscribe(),
-                        )
-                        .await?;
-                        self.cti_client.connect().await;
-                    }
-                    // CTI 메시지 수신
-                    CTIEvent::Recevied {
-                        cti_server_host,
-                        message_type,
-                        mut data,
-                    } => {
-                        log::debug!(
-                            "Received CTI event. cti_server_host: {}, message_type: {:?}, data: {:?}",
-                            cti_server_host,
-                            message_type, data
-                        );
-                        // 메시지 역직렬화
-                        match message_type {
-                            // OPEN_CONF 메시지 수신
-                            MessageType::OPEN_CONF => {
-                                let (_, open_conf) = OpenConf::deserialize(&mut data);
-                                log::info!("{:?}", open_conf);
-                            }
-                            // AGENT_TEAM_CONFIG_EVENT 메시지 수신
-                            MessageType::AGENT_TEAM_CONFIG_EVENT => {
-                                let (_, agent_team_config_event) =
-                                    AgentTeamConfigEvent::deserialize(&mut data);
-                                log::info!("{:?}", agent_team_config_event);
-
-                                // ATCAgent의 상태를 CTI 서버에 요청한다
-                                agent_team_config_event.agents.iter().for_each(
-                                    |agent| match &agent.agent_id {
-                                        Some(agent_id) => {
-                                            self.broker_event_channel_tx
-                                                .send(BrokerEvent::RequestAgentStateEvent {
-                                                    peripheral_id: agent_team_config_event
-                                                        .peripheral_id,
-                                                    agent_id: agent_id.data.clone(),
-                                                })
-                                                .unwrap();
-
-                                            let agent_state =
-                                                agent.agent_state.clone().unwrap().data;
-                                            let state_duration =
-                                                agent.state_duration.clone().unwrap().data;
-
-                                            match self.agent_info_map.get_mut(&agent_id.data) {
-                                                Some(agent_info) => {
-                                                    agent_info.set_agent_state(agent_state);
-                                                    agent_info.set_state_duration(state_duration);
-
-                                                    // 상담직원 이벤트 전송
-                                                    Self::broadcast_agent_info(
-                                                        None,
-                                                        self.broker_event_channel_tx.clone(),
-                                                        agent_info.clone(),
-                                                    );
-                                                }
-                                                None => {
-                                                    let mut agent_info =
-                                                        AgentInfo::new(agent_id.clone().data);
-
-                                                    agent_info.set_agent_state(agent_state);
-                                                    agent_info.set_state_duration(state_duration);
-
-                                                    self.agent_info_map.insert(
-                                                        agent_id.data.clone(),
-                                                        agent_info.clone(),
-                                                    );
-
-                                                    // 상담직원 이벤트 전송
-                                                    Self::broadcast_agent_info(
-                                                        None,
-                                                        self.broker_event_channel_tx.clone(),
-                                                        agent_info,
-                                                    );
-                                                }
-                                            };
-                                        }
-                                        None => {}
-                                    },
-                                );
-                            }
-                            // QUERY_AGENT_STATE_CONF 메시지 수신
-                            MessageType::QUERY_AGENT_STATE_CONF => {
-                                let (_, query_agent_state_conf) =
-                                    QueryAgentStateConf::deserialize(&mut data);
-                                log::info!("{:?}", query_agent_state_conf);
-
-                                let agent_id = query_agent_state_conf.agent_id.unwrap().data;
-                                let agent_state = query_agent_state_conf.agent_state;
-                                let icm_agent_id = query_agent_state_conf.icm_agent_id;
-                                let skill_group_id =
-                                    query_agent_state_conf.skill_group_id.unwrap().data;
-                                let agent_extension =
-                                    query_agent_state_conf.agent_extension.unwrap().data;
-
-                                match self.agent_info_map.get_mut(&agent_id) {
-                                    Some(agent_info) => {
-                                        agent_info.set_agent_state(agent_state);
-                                        agent_info.set_skill_group_id(skill_group_id as u16);
-                                        agent_info.set_icm_agent_id(icm_agent_id);
-                                        agent_info.set_agent_extension(agent_extension);
+        // UDP 멀티캐스트 발행기 생성
+        if config.components.udp_multicast {
+            match UdpMulticastPublisher::new(&config.udp_multicast).await {
+                Ok(publisher) => builder = builder.with_acceptor(Box::new(publisher)),
+                Err(e) => log::error!("Unable to create UDP multicast publisher. {}", e),
+            }
+        }
 
-                                        // 상담직원 이벤트 전송
-                                        Self::broadcast_agent_info(
-                                            None,
-                                            self.broker_event_channel_tx.clone(),
-                                            agent_info.clone(),
-                                        );
-                                    }
-                                    None => {}
-                                };
-                            }
-                            // AGENT_STATE_EVENT 메시지 수신
-                            MessageType::AGENT_STATE_EVENT => {
-                                let (_, agent_state_event) =
-                                    AgentStateEvent::deserialize(&mut data);
+        // Redis 발행기 생성
+        if config.components.redis {
+            match RedisPublisher::new(&config.redis).await {
+                Ok(publisher) => builder = builder.with_acceptor(Box::new(publisher)),
+                Err(e) => log::error!("Unable to create redis publisher. {}", e),
+            }
+        }
 
-                                log::info!("{:?}", agent_state_event);
+        // MQTT 발행기 생성
+        if config.components.mqtt {
+            match MqttPublisher::new(&config.mqtt).await {
+                Ok(publisher) => builder = builder.with_acceptor(Box::new(publisher)),
+                Err(e) => log::error!("Unable to create MQTT publisher. {}", e),
+            }
+        }
 
-                                let agent_id = agent_state_event.agent_id.unwrap().data;
-                                let agent_state = agent_state_event.agent_state;
-                                let icm_agent_id = agent_state_event.icm_agent_id;
-                                let skill_group_id = agent_state_event.skill_group_id;
-                                let agent_extension =
-                                    agent_state_event.agent_extension.unwrap().data;
-                                let direction = agent_state_event.direction.unwrap().data;
-                                let reason_code = agent_state_event.event_reason_code;
-                                let state_duration = agent_state_event.state_duration;
+        // AMQP 발행기 생성
+        if config.components.amqp {
+            match AmqpPublisher::new(&config.amqp).await {
+                Ok(publisher) => builder = builder.with_acceptor(Box::new(publisher)),
+                Err(e) => log::error!("Unable to create AMQP publisher. {}", e),
+            }
+        }
 
-                                match self.agent_info_map.get_mut(&agent_id) {
-                                    Some(agent_info) => {
-                                        agent_info.set_agent_state(agent_state);
-                                        agent_info.set_skill_group_id(skill_group_id as u16);
-                                        agent_info.set_icm_agent_id(icm_agent_id);
-                                        agent_info.set_agent_extension(agent_extension);
-                                        agent_info.set_direction(direction);
-                                        agent_info.set_reason_code(reason_code);
-                                        agent_info.set_state_duration(state_duration);
+        // 웹훅 발행기 생성
+        if config.components.webhook {
+            match WebhookPublisher::new(&config.webhook).await {
+                Ok(publisher) => builder = builder.with_acceptor(Box::new(publisher)),
+                Err(e) => log::error!("Unable to create webhook publisher. {}", e),
+            }
+        }
 
-                                        // 상담직원 이벤트 전송
-                                        Self::broadcast_agent_info(
-                                            None,
-                                            self.broker_event_channel_tx.clone(),
-                                            agent_info.clone(),
-                                        );
-                                    }
-                                    None => {}
-                                }
-                            }
-                            // 처리되지 않은 메시지 수신
-                            message_type => {
-                                log::info!(
-                                    "Received CTI message. message_type: {:?}",
-                                    message_type
-                                );
-                            }
-                        }
-                    }
-                },
-                Ok(None) => {}
-                Err(_) => {}
-            };
+        // 데이터베이스 기록기 생성
+        if config.components.database {
+            match DatabaseWriter::new(&config.database).await {
+                Ok(writer) => builder = builder.with_acceptor(Box::new(writer)),
+                Err(e) => log::error!("Unable to create database writer. {}", e),
+            }
+        }
 
-            // 클라이언트 이벤트 채널 수신
-            match timeout(
-                Duration::from_millis(10),
-                self.client_event_channel_rx.recv(),
-            )
-            .await
-            {
-                Ok(Some(event)) => match event {
-                    ClientEvent::Connect { id } => {
-                        self.agent_info_map.iter().for_each(|(_, agent_info)| {
-                            Self::broadcast_agent_info(
-                                Some(id),
-                                self.broker_event_channel_tx.clone(),
-                                agent_info.clone(),
-                            );
-                        });
-                    }
-                    ClientEvent::Receive { data, id } => {
-                        log::debug!("Client sent. id: {}, data: {:?}", id, data);
-                    }
-                    ClientEvent::Disconnect { id: _ } => {}
-                },
-                Ok(None) => {}
-                Err(_) => {}
+        // 상태 확인 서버 생성
+        if config.components.health {
+            let health_state = Arc::new(HealthState::new(
+                config
+                    .cti_clients
+                    .iter()
+                    .map(|cti_client| cti_client.cti_instance.clone())
+                    .collect(),
+                config.ctm.health_heartbeat_timeout,
+            ));
+            match HealthServer::new(&config.health, health_state.clone(), channel_metrics).await {
+                Ok(server) => {
+                    builder = builder
+                        .with_subscriber(health_state)
+                        .with_acceptor(Box::new(server))
+                }
+                Err(e) => log::error!("Unable to create health server. {}", e),
             }
         }
 
-        #[allow(unreachable_code)]
-        Ok(())
+        builder.with_config(config)
     }
 
     ///
-    /// 상담직원 상태를 브로커 채널에 전송한다
+    /// CTI 서버 인스턴스의 Peripheral ID
     ///
-    fn broadcast_agent_info(
-        target_client_id: Option<Uuid>,
-        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
-        agent_info: AgentInfo,
-    ) {
-        let agent_info_clone = agent_info.clone();
-        broker_event_channel_tx
-            .send(BrokerEvent::BroadCastAgentState {
-                agent_info,
-                client_id: target_client_id,
+    fn get_peripheral_id(config: &Config, cti_instance: &str) -> PeripheralId {
+        config
+            .get_cti_client(cti_instance)
+            .map(|cti_client| cti_client.peripheral_id)
+            .unwrap_or(PeripheralId(5000))
+    }
+
+    ///
+    /// CTI 서버 인스턴스의 호 상태 일괄 전송 버퍼 (ALL_EVENTS 세션 모드만 사용)
+    ///
+    fn call_broadcast_batch(config: &Config, cti_instance: &str) -> Option<CallBroadcastBatch> {
+        config
+            .get_cti_client(cti_instance)
+            .filter(|cti_client| cti_client.session_mode == SessionMode::ALL_EVENTS)
+            .map(|_| {
+                CallBroadcastBatch::new(
+                    config.ctm.call_broadcast_batch_interval,
+                    config.ctm.call_broadcast_batch_size,
+                    Instant::now(),
+                )
             })
-            .unwrap();
-        log::debug!(
-            "Broadcasted agent info event. agent_info: {:?}",
-            agent_info_clone
+    }
+
+    ///
+    /// CTI 서버 재접속 후 상태 재동기화 시작
+    ///
+    /// 알고 있는 모든 상담직원의 상태를 다시 조회하고 클라이언트에 RESYNCING 을 알린다.
+    /// 조회 응답을 모두 받거나 조회 제한 시간이 지나면 finish_cti_resync 가 RESYNCED 를 알린다.
+    ///
+    fn start_cti_resync(
+        cti_instance: &str,
+        peripheral_id: PeripheralId,
+        state: &mut CtiInstanceState,
+        invoke_id_generator: &InvokeIdGenerator,
+        broker_event_channel_tx: &broadcast::Sender<BrokerEvent>,
+    ) {
+        state.resync_invoke_ids.clear();
+        state.resync_requested = state.agent_info_map.len();
+        state.resync_started_at = Some(Instant::now());
+        log::info!(
+            "Resyncing CTI instance state. cti_instance: {}, agents: {}",
+            cti_instance,
+            state.resync_requested
+        );
+        broker_event_channel_tx
+            .send(BrokerEvent::BroadCastCtiSyncState {
+                client_id: None,
+                cti_sync_state: CtiSyncState::new(
+                    cti_instance,
+                    CtiSyncStatus::RESYNCING,
+                    state.resync_requested,
+                    state.resync_requested,
+                ),
+            })
+            .unwrap();
+
+        for agent_id in state.agent_info_map.keys() {
+            let invoke_id = invoke_id_generator.next_id();
+            state.resync_invoke_ids.insert(invoke_id);
+            broker_event_channel_tx
+                .send(BrokerEvent::RequestAgentStateEvent {
+                    cti_instance: cti_instance.to_string(),
+                    invoke_id,
+                    peripheral_id,
+                    agent_id: agent_id.clone(),
+                })
+                .unwrap();
+        }
+
+        if state.resync_invoke_ids.is_empty() {
+            Self::finish_cti_resync(cti_instance, state, broker_event_channel_tx);
+        }
+    }
+
+    ///
+    /// CTI 서버에 상담직원 한 명, 팀 또는 전체 상담직원의 상태를 다시 조회한다
+    ///
+    /// 전체 상담직원은 재접속 후와 같은 재동기화로 조회한다. CTI 서버에 접속되어 있지 않거나
+    /// 빈도 제한에 걸리면 조회하지 않는다.
+    ///
+    fn refresh_state(
+        &mut self,
+        cti_instance: &str,
+        agent_id: Option<String>,
+        team_id: Option<u32>,
+    ) -> StateRefreshResult {
+        let (scope, target) = match (agent_id, team_id) {
+            (Some(agent_id), _) => (StateRefreshScope::AGENT, agent_id),
+            (None, Some(team_id)) => (StateRefreshScope::TEAM, team_id.to_string()),
+            (None, None) => (StateRefreshScope::ALL, "".to_string()),
+        };
+
+        let state = match self.cti_instance_state_map.get_mut(cti_instance) {
+            Some(state) if state.is_connected => state,
+            _ => {
+                return StateRefreshResult::new(
+                    cti_instance,
+                    scope,
+                    target,
+                    false,
+                    0,
+                    Duration::ZERO,
+                );
+            }
+        };
+        if let Err(retry_after) =
+            self.state_refresh_limiter
+                .try_acquire(cti_instance, scope, &target, Instant::now())
+        {
+            return StateRefreshResult::new(cti_instance, scope, target, false, 0, retry_after);
+        }
+
+        let peripheral_id = Self::get_peripheral_id(&self.config, cti_instance);
+        if scope == StateRefreshScope::ALL {
+            Self::start_cti_resync(
+                cti_instance,
+                peripheral_id,
+                state,
+                &self.invoke_id_generator,
+                &self.broker_event_channel_tx,
+            );
+            return StateRefreshResult::new(
+                cti_instance,
+                scope,
+                target,
+                true,
+                state.resync_requested,
+                Duration::ZERO,
+            );
+        }
+
+        // 알고 있는 상담직원만 조회한다 (조회 결과는 보관 중인 상담직원에만 반영된다)
+        let agent_ids = state
+            .agent_info_map
+            .values()
+            .filter(|agent_info| match scope {
+                StateRefreshScope::AGENT => agent_info.get_agent_id() == target,
+                _ => agent_info.get_team_id().to_string() == target,
+            })
+            .map(|agent_info| agent_info.get_agent_id().to_string())
+            .collect::<Vec<_>>();
+        for agent_id in agent_ids.iter() {
+            self.broker_event_channel_tx
+                .send(BrokerEvent::RequestAgentStateEvent {
+                    cti_instance: cti_instance.to_string(),
+                    invoke_id: self.invoke_id_generator.next_id(),
+                    peripheral_id,
+                    agent_id: agent_id.clone(),
+                })
+                .unwrap();
+        }
+
+        StateRefreshResult::new(
+            cti_instance,
+            scope,
+            target,
+            true,
+            agent_ids.len(),
+            Duration::ZERO,
+        )
+    }
+
+    ///
+    /// CTI 세션 모니터의 호 이벤트 마스크를 바꾼다
+    ///
+    /// OPEN_CONF 로 받은 MonitorID 로 요청하며, 세션이 없거나 모니터가 만들어지지 않았으면 요청하지 않는다.
+    ///
+    fn change_monitor_mask(&mut self, cti_instance: &str, call_msg_mask: CallMessageMask) {
+        let Some(session) = self
+            .cti_instance_state_map
+            .get_mut(cti_instance)
+            .and_then(|state| state.session.as_mut())
+        else {
+            log::warn!("CTI session is not opened. cti_instance: {}", cti_instance);
+            return;
+        };
+        let Some(monitor_id) = session.get_monitor_id() else {
+            log::warn!(
+                "CTI session has no event monitor. cti_instance: {}",
+                cti_instance
+            );
+            return;
+        };
+
+        let invoke_id = self.invoke_id_generator.next_id();
+        session.request_call_msg_mask(invoke_id, call_msg_mask);
+        self.broker_event_channel_tx
+            .send(BrokerEvent::RequestChangeMonitorMask {
+                cti_instance: cti_instance.to_string(),
+                invoke_id,
+                monitor_id,
+                call_msg_mask,
+            })
+            .unwrap();
+    }
+
+    ///
+    /// CTI 서버 상태 재동기화 완료
+    ///
+    fn finish_cti_resync(
+        cti_instance: &str,
+        state: &mut CtiInstanceState,
+        broker_event_channel_tx: &broadcast::Sender<BrokerEvent>,
+    ) {
+        if state.resync_started_at.take().is_none() {
+            return;
+        }
+
+        let unanswered_agents = state.resync_invoke_ids.len();
+        state.resync_invoke_ids.clear();
+        match unanswered_agents {
+            0 => log::info!(
+                "Resynced CTI instance state. cti_instance: {}, agents: {}",
+                cti_instance,
+                state.resync_requested
+            ),
+            _ => log::warn!(
+                "Resynced CTI instance state with unanswered agents. cti_instance: {}, agents: {}, unanswered: {}",
+                cti_instance,
+                state.resync_requested,
+                unanswered_agents
+            ),
+        }
+        broker_event_channel_tx
+            .send(BrokerEvent::BroadCastCtiSyncState {
+                client_id: None,
+                cti_sync_state: CtiSyncState::new(
+                    cti_instance,
+                    CtiSyncStatus::RESYNCED,
+                    state.resync_requested,
+                    unanswered_agents,
+                ),
+            })
+            .unwrap();
+    }
+
+    ///
+    /// 클라이언트에 팀, 상담직원, 호, 스킬그룹, 전광판 전체 상태를 전송한다
+    ///
+    fn broadcast_client_state(&self, id: Uuid) {
+        self.send_client_events(id, self.client_state_events(id, None));
+    }
+
+    ///
+    /// 접속한 클라이언트에 접속 안내와 전체 상태를 전송한다
+    ///
+    /// 접속 안내에는 서버, 프로토콜 버전과 CTI 서버 접속 상태, 이어서 전송할 상담직원 수가 포함된다.
+    /// 이어 받기를 요청한 클라이언트에는 가능하면 상담직원 전체 상태 대신 놓친 변경분을 전송한다.
+    ///
+    fn welcome_client(&self, id: Uuid, resume: Option<SessionResume>) {
+        let agent_replay_events = resume.and_then(|resume| {
+            let agent_replay_events = match self.agent_delta_broadcast {
+                true => Self::agent_replay_events(
+                    id,
+                    &self.client_subscription_map,
+                    &self.locale_dictionary,
+                    &self.agent_delta_tracker,
+                    &resume,
+                ),
+                false => None,
+            };
+            match agent_replay_events {
+                Some(ref agent_replay_events) => log::info!(
+                    "Client session resumed. id: {}, sequence: {}, replayed: {}",
+                    id,
+                    resume.get_sequence(),
+                    agent_replay_events.len()
+                ),
+                None => log::info!(
+                    "Unable to resume client session, sending full state. id: {}, epoch: {}, sequence: {}",
+                    id,
+                    resume.get_epoch(),
+                    resume.get_sequence()
+                ),
+            }
+            agent_replay_events
+        });
+        let resumed = agent_replay_events.is_some();
+        let client_state_events = self.client_state_events(id, agent_replay_events);
+        let agent_count = client_state_events
+            .iter()
+            .map(|broker_event| match broker_event {
+                BrokerEvent::BroadCastAgentState { .. } => 1,
+                BrokerEvent::BroadCastAgentSnapshot { agent_snapshot, .. } => {
+                    agent_snapshot.get_agent_infos().len()
+                }
+                _ => 0,
+            })
+            .sum();
+
+        let cti_connections = self
+            .config
+            .cti_clients
+            .iter()
+            .map(|cti_client_config| {
+                let state = self
+                    .cti_instance_state_map
+                    .get(&cti_client_config.cti_instance);
+                CtiConnectionStatus::new(
+                    cti_client_config.cti_instance.clone(),
+                    state.is_some_and(|state| state.is_connected),
+                    cti_client_config.peripheral_id.0,
+                    state.is_some_and(|state| state.peripheral_online),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut welcome = Welcome::new(id, cti_connections, agent_count);
+        welcome.set_sequence_epoch(self.agent_delta_tracker.get_epoch());
+        welcome.set_resumed(resumed);
+
+        let mut broker_events = vec![BrokerEvent::BroadCastWelcome {
+            client_id: Some(id),
+            welcome,
+        }];
+        broker_events.extend(client_state_events);
+        self.send_client_events(id, broker_events);
+    }
+
+    ///
+    /// 클라이언트에 보낼 팀, 상담직원, 호, 스킬그룹, 전광판 전체 상태 이벤트
+    ///
+    /// 놓친 변경분 이벤트가 있으면 상담직원 전체 상태 대신 전송한다.
+    ///
+    fn client_state_events(
+        &self,
+        id: Uuid,
+        agent_replay_events: Option<Vec<BrokerEvent>>,
+    ) -> Vec<BrokerEvent> {
+        let mut broker_events = vec![];
+        if let Some(ha_status) = self.ha_status() {
+            broker_events.push(BrokerEvent::BroadCastHaStatus {
+                client_id: Some(id),
+                ha_status,
+            });
+        }
+        let department_id = self
+            .client_subscription_map
+            .get(&id)
+            .and_then(ClientSubscription::get_department_id);
+        broker_events.push(BrokerEvent::BroadCastTeamSnapshot {
+            client_id: Some(id),
+            team_infos: Self::team_infos(&self.cti_instance_state_map, department_id),
+        });
+
+        broker_events.extend(agent_replay_events.unwrap_or_else(|| {
+            Self::agent_snapshot_events(
+                id,
+                &self.client_subscription_map,
+                &self.locale_dictionary,
+                &self.agent_delta_tracker,
+                self.agent_delta_broadcast,
+            )
+        }));
+
+        self.cti_instance_state_map
+            .iter()
+            .for_each(|(cti_instance, state)| {
+                state
+                    .call_info_map
+                    .values()
+                    .filter(|call_info| {
+                        self.client_subscription_map
+                            .get(&id)
+                            .is_none_or(|client_subscription| {
+                                client_subscription.matches_department(Self::call_department_id(
+                                    &state.agent_info_map,
+                                    call_info,
+                                )) && client_subscription.matches_call(call_info)
+                            })
+                    })
+                    .for_each(|call_info| {
+                        let mut call_info = call_info.clone();
+                        call_info.mask_fields(&self.field_masker);
+                        broker_events.push(BrokerEvent::BroadCastCallState {
+                            client_id: Some(id),
+                            call_info,
+                        });
+                    });
+
+                Self::aggregate_skill_group_infos(cti_instance, state, department_id)
+                    .into_iter()
+                    .for_each(|skill_group_info| {
+                        broker_events.push(BrokerEvent::BroadCastSkillGroupState {
+                            client_id: Some(id),
+                            skill_group_info,
+                        });
+                    });
+            });
+
+        broker_events.push(BrokerEvent::BroadCastWallboardSummary {
+            client_id: Some(id),
+            wallboard_summary: Self::wallboard_summary(&self.cti_instance_state_map, department_id),
+        });
+
+        broker_events
+    }
+
+    ///
+    /// 한 클라이언트 대상 이벤트를 전송한다
+    ///
+    /// 전용 이벤트 큐가 있는 클라이언트에는 이벤트를 묶어 큐로 보내, 다른 클라이언트가 이벤트 수만큼
+    /// 깨어나지 않게 한다. 큐가 없는 클라이언트(내부 클라이언트 등)에는 브로커 채널로 보낸다.
+    ///
+    fn send_client_events(&self, id: Uuid, broker_events: Vec<BrokerEvent>) {
+        log::debug!(
+            "Sending client events. id: {}, events: {}",
+            id,
+            broker_events.len()
+        );
+
+        if let Some(direct_event_tx) = self.direct_event_tx_map.get(&id) {
+            if direct_event_tx.send(broker_events).is_err() {
+                log::warn!("Client event queue is closed. id: {}", id);
+            }
+            return;
+        }
+
+        broker_events.into_iter().for_each(|broker_event| {
+            self.broker_event_channel_tx.send(broker_event).unwrap();
+        });
+    }
+
+    ///
+    /// 관리자 경보를 접속한 모든 클라이언트에 전송한다
+    ///
+    /// 구독 조건과 관계없이 전송하며, 전용 이벤트 큐가 있는 클라이언트에는 큐로 보내 처리가 늦어
+    /// 브로커 이벤트를 놓치더라도 경보는 받을 수 있게 한다.
+    ///
+    fn broadcast_supervisor_alert(&self, supervisor_alert: SupervisorAlert) {
+        log::info!(
+            "Broadcasted supervisor alert. supervisor_alert: {:?}",
+            supervisor_alert
+        );
+
+        let supervisor_alert = SharedPayload::new(supervisor_alert);
+        for id in self.client_info_map.keys() {
+            self.send_client_events(
+                *id,
+                vec![BrokerEvent::BroadCastSupervisorAlert {
+                    client_id: Some(*id),
+                    supervisor_alert: supervisor_alert.clone(),
+                }],
+            );
+        }
+    }
+
+    ///
+    /// 이벤트 구독자 등록
+    ///
+    pub fn subscribe(&mut self, subscriber: Arc<dyn CtmSubscriber>) {
+        self.subscribers.push(subscriber);
+    }
+
+    ///
+    /// 종료 제어기 반환
+    ///
+    /// 종료를 요청하면 start 가 종료 절차를 마친 뒤 반환된다.
+    ///
+    pub fn get_shutdown_controller(&self) -> ShutdownController {
+        self.shutdown_controller.clone()
+    }
+
+    ///
+    /// CTM 서버 실행
+    ///
+    pub async fn start(mut self) -> Result<(), Box<dyn Error>> {
+        let is_replay = self.replay_file.is_some() || self.capture_replay_file.is_some();
+
+        // HA 노드는 활성 노드로 선출된 뒤 CTI 서버에 접속한다
+        let (ha_event_tx, mut ha_event_rx) = mpsc::channel::<HaEvent>(16);
+        self.ha_mirror_tx = self
+            .ha_node
+            .take()
+            .map(|ha_node| ha_node.start(ha_event_tx, self.shutdown_controller.clone()));
+
+        // 노드 간 상태 동기화 (동기화 원본 노드가 없으면 수신 채널이 닫힌다)
+        let (peer_sync_tx, mut peer_sync_rx) = mpsc::channel::<PeerSyncMessage>(1_024);
+        if let Some(peer_sync_server) = self.peer_sync_server.take() {
+            peer_sync_server.start(self.shutdown_controller.clone());
+        }
+        if let Some(peer_sync_client) = self.peer_sync_client.take() {
+            peer_sync_client.start(peer_sync_tx, self.shutdown_controller.clone());
+        }
+
+        match (self.replay_file.take(), self.capture_replay_file.take()) {
+            (Some(replay_file), _) => {
+                let records = read_journal(&replay_file)?;
+                log::info!(
+                    "Replaying journal. replay_file: {}, records: {}",
+                    replay_file.display(),
+                    records.len()
+                );
+                tokio::spawn(Self::replay_journal(
+                    records,
+                    self.config.ctm.journal_replay_speed,
+                    self.cti_event_channel_tx.clone(),
+                ));
+            }
+            (None, Some(capture_replay_file)) => {
+                let capture = read_capture(&capture_replay_file)?;
+                let replay_speed = self.config.ctm.capture_replay_speed;
+                log::info!(
+                    "Replaying CTI capture. capture_replay_file: {}, records: {}, replay_speed: {}",
+                    capture_replay_file.display(),
+                    capture.records.len(),
+                    replay_speed
+                );
+                tokio::spawn(replay_capture(
+                    capture,
+                    replay_speed,
+                    self.cti_event_channel_tx.clone(),
+                ));
+            }
+            (None, None) if self.peer_sync_read_only => {
+                self.cti_clients.clear();
+                log::info!(
+                    "Running as read-only replica. sources: {:?}",
+                    self.config.peer_sync.sources
+                );
+            }
+            (None, None) if self.ha_mirror_tx.is_some() => {
+                self.cti_clients.clear();
+                log::info!(
+                    "Waiting for HA role election. node_id: {:?}",
+                    self.ha_node_id
+                );
+            }
+            (None, None) => {
+                for cti_client in std::mem::take(&mut self.cti_clients) {
+                    self.cti_client_running_map.insert(
+                        cti_client.get_cti_instance().to_string(),
+                        cti_client.get_is_running(),
+                    );
+                    cti_client.connect().await;
+                }
+            }
+        }
+
+        // Acceptor 실행
+        let mut acceptor_handles = vec![];
+        for acceptor in std::mem::take(&mut self.acceptors) {
+            let broker_event_channel_rx = self.broker_event_channel_rx.resubscribe();
+            let client_event_channel_tx = self.client_event_channel_tx.clone();
+            let subscribers = self.subscribers.clone();
+
+            acceptor_handles.push(tokio::spawn(async move {
+                let name = acceptor.get_name().to_string();
+                Self::notify_system_event(
+                    &subscribers,
+                    &SystemEvent::AcceptorStarted { name: name.clone() },
+                );
+
+                let result = acceptor
+                    .accept(broker_event_channel_rx, client_event_channel_tx)
+                    .await;
+                if let Err(e) = &result {
+                    log::error!("Acceptor stopped with error. name: {}, error: {}", name, e);
+                }
+                Self::notify_system_event(
+                    &subscribers,
+                    &SystemEvent::AcceptorStopped {
+                        name,
+                        error: result.err().map(|e| e.to_string()),
+                    },
+                );
+            }));
+        }
+
+        // SIGHUP 수신 시 설정 재적용
+        let mut reload_rx = listen_reload_signal();
+
+        // 재생 모드, HA 대기 노드, 읽기 전용 복제 노드는 CTI 세션이 없으므로 Acceptor 실행 즉시 준비 완료를 알린다
+        if is_replay {
+            self.systemd_notifier
+                .ready(&format!("Replaying. acceptors: {}", acceptor_handles.len()));
+        } else if self.peer_sync_read_only {
+            self.systemd_notifier.ready("Read-only replica started");
+        } else if self.ha_mirror_tx.is_some() {
+            self.systemd_notifier
+                .ready(&format!("HA node started. node_id: {:?}", self.ha_node_id));
+        }
+
+        let mut housekeeping_interval = interval(HOUSEKEEPING_INTERVAL);
+        housekeeping_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            if self.shutdown_controller.is_shutdown() {
+                break;
+            }
+
+            // CTI, 클라이언트 이벤트를 받는 즉시 처리하고, 이벤트가 없으면 주기 작업만 확인한다
+            tokio::select! {
+                // CTI 이벤트 채널 데이터 수신
+                Some(event) = self.cti_event_channel_rx.recv() => match event {
+                    // HeartBeat 요청 전송 시간 이벤트 수신
+                    CTIEvent::TimeToHeartBeat { cti_instance } => {
+                        log::debug!(
+                            "Received time to send heartbeat event. cti_instance: {}",
+                            cti_instance
+                        );
+                        if let Some(state) = self.cti_instance_state_map.get_mut(&cti_instance) {
+                            state.cti_link.on_heartbeat_requested(Instant::now());
+                        }
+                        self.broker_event_channel_tx
+                            .send(BrokerEvent::RequestHeartBeatReq { cti_instance })
+                            .unwrap();
+                    }
+                    // 오류 이벤트 수신
+                    CTIEvent::Error {
+                        cti_instance,
+                        cti_server_host,
+                        error_cause,
+                    } => {
+                        log::warn!(
+                            "Received CTI Server error. cti_instance: {}, cti_server_host: {}, error_cause: {}",
+                            cti_instance,
+                            cti_server_host,
+                            error_cause
+                        );
+                        Self::notify_system_event(
+                            &self.subscribers,
+                            &SystemEvent::CtiError {
+                                cti_instance: cti_instance.clone(),
+                                cti_server_host,
+                                error_cause,
+                            },
+                        );
+                        if let Some(state) = self.cti_instance_state_map.get_mut(&cti_instance) {
+                            state.is_connected = false;
+                            state.session = None;
+                        }
+
+                        // HA 대기 노드는 재접속하지 않는다
+                        if self.ha_role != HaRole::ACTIVE {
+                            continue;
+                        }
+
+                        // CTI 서버가 이중화 넘어가는데 시간이 소요되므로 대기 시간을 늘려가며 재접속하고,
+                        // 같은 쪽에서 연속으로 실패한 경우에만 반대쪽으로 전환한다
+                        let state = self
+                            .cti_instance_state_map
+                            .entry(cti_instance.clone())
+                            .or_default();
+                        let (delay, failover) =
+                            state.reconnect_state.on_failure(&state.reconnect_policy);
+                        if failover {
+                            state.is_active = !state.is_active;
+                        }
+                        state.cti_link.on_reconnect();
+                        let is_active = state.is_active;
+                        self.systemd_notifier.status(&format!(
+                            "Reconnecting to CTI Server. cti_instance: {}",
+                            cti_instance
+                        ));
+                        log::info!(
+                            "Reconnecting to CTI Server. cti_instance: {}, is_active: {}, attempt: {}, delay: {:?}",
+                            cti_instance,
+                            is_active,
+                            state.reconnect_state.get_attempt(),
+                            delay
+                        );
+                        if failover {
+                            Self::notify_system_event(
+                                &self.subscribers,
+                                &SystemEvent::Failover {
+                                    cti_instance: cti_instance.clone(),
+                                    is_active,
+                                },
+                            );
+                        }
+                        let Some(cti_client_config) =
+                            self.config.get_cti_client(&cti_instance).cloned()
+                        else {
+                            log::error!(
+                                "Unknown CTI instance to reconnect. cti_instance: {}",
+                                cti_instance
+                            );
+                            continue;
+                        };
+                        let cti_client = CTIClient::new(
+                            cti_client_config,
+                            is_active,
+                            self.invoke_id_generator.clone(),
+                            self.cti_event_channel_tx.clone(),
+                            self.broker_event_channel_rx.resubscribe(),
+                        )
+                        .await?;
+                        self.cti_client_running_map
+                            .insert(cti_instance, cti_client.get_is_running());
+
+                        // 대기 중에도 이벤트 처리는 계속하며, 종료가 요청되면 재접속하지 않는다
+                        let shutdown_controller = self.shutdown_controller.clone();
+                        let cti_session_controller = self.cti_session_controller.clone();
+                        tokio::spawn(async move {
+                            tokio::select! {
+                                _ = sleep(delay) => cti_client.connect().await,
+                                _ = shutdown_controller.wait() => {}
+                                _ = cti_session_controller.wait() => {}
+                            }
+                        });
+                    }
+                    // CTI 메시지 수신
+                    CTIEvent::Recevied {
+                        cti_instance,
+                        cti_server_host,
+                        message_type,
+                        mut data,
+                        span,
+                    } => {
+                        let _entered =
+                            tracing::info_span!(parent: &span, "ctm.handle", message_type = ?message_type)
+                                .entered();
+                        log::debug!(
+                            "Received CTI event. cti_instance: {}, cti_server_host: {}, message_type: {:?}, data: {:?}",
+                            cti_instance,
+                            cti_server_host,
+                            message_type, data
+                        );
+                        // CTI 인스턴스에 설정된 문자열 인코딩으로 복호화한다
+                        let cti_codec = self
+                            .config
+                            .get_cti_client(&cti_instance)
+                            .map(|cti_client| CtiCodec::new(cti_client.string_encoding))
+                            .unwrap_or_default();
+                        // 이벤트 저널 기록
+                        if let Some(journal_writer) = self.journal_writer.as_mut() {
+                            let record = JournalRecord::new(
+                                cti_instance.clone(),
+                                cti_server_host.clone(),
+                                message_type.clone(),
+                                data.clone(),
+                            );
+                            if let Err(e) = journal_writer.write(&record) {
+                                log::error!("Unable to write journal record. {}", e);
+                            }
+                        }
+                        // firehose 구독 클라이언트에 복호화한 메시지 전송 (한 번만 역직렬화한다)
+                        if !self.firehose_client_ids.is_empty() {
+                            let firehose_message = SharedPayload::new(
+                                cti_codec.with_string_encoding(|| {
+                                    FirehoseMessage::new(cti_instance.clone(), &message_type, &data)
+                                }),
+                            );
+                            for id in self.firehose_client_ids.iter() {
+                                self.send_client_events(
+                                    *id,
+                                    vec![BrokerEvent::BroadCastFirehoseMessage {
+                                        client_id: Some(*id),
+                                        firehose_message: firehose_message.clone(),
+                                    }],
+                                );
+                            }
+                        }
+                        let state = self
+                            .cti_instance_state_map
+                            .entry(cti_instance.clone())
+                            .or_default();
+                        state.cti_link.on_event(Instant::now());
+
+                        // 메시지 역직렬화
+                        match message_type {
+                            // OPEN_CONF 메시지 수신
+                            MessageType::OPEN_CONF => {
+                                let open_conf = match cti_codec.deserialize::<OpenConf>(&mut data) {
+                                    Ok(open_conf) => open_conf,
+                                    Err(e) => {
+                                        log::error!("Invalid OPEN_CONF. {}", e);
+                                        continue;
+                                    }
+                                };
+                                log::info!("{:?}", open_conf);
+                                state.reconnect_state.reset();
+                                state.is_connected = true;
+                                state.peripheral_online = open_conf.peripheral_online;
+                                let monitor_id = open_conf.monitor_id.0;
+                                state.session = Some(CtiSession::new(
+                                    &cti_instance,
+                                    &open_conf,
+                                    self.config
+                                        .get_cti_client(&cti_instance)
+                                        .map(|cti_client| cti_client.call_msg_mask)
+                                        .unwrap_or_default(),
+                                ));
+
+                                // PG 시계와 차이가 크면 상태 시작 시각이 어긋나므로 알린다
+                                if let Some(pg_clock) = self
+                                    .config
+                                    .get_cti_client(&cti_instance)
+                                    .map(|cti_client| cti_client.pg_clock)
+                                    .filter(|pg_clock| {
+                                        pg_clock.is_skewed(
+                                            open_conf.icm_central_controller_time.0,
+                                            SystemTime::now(),
+                                        )
+                                    })
+                                {
+                                    log::warn!(
+                                        "Clock skew detected. cti_instance: {}, skew: {}s, offset: {}s",
+                                        cti_instance,
+                                        pg_clock.skew(
+                                            open_conf.icm_central_controller_time.0,
+                                            SystemTime::now()
+                                        ),
+                                        pg_clock.get_offset()
+                                    );
+                                }
+
+                                // Client Events 세션은 팀 구성 이벤트를 받지 않으므로 세션을 연 상담직원을 직접 등록한다
+                                if let Some(session) = state.session.as_ref() {
+                                    if let Some(agent_id) = session.get_agent_id().filter(|agent_id| {
+                                        !state.agent_info_map.contains_key(*agent_id)
+                                    }) {
+                                        let mut agent_info = AgentInfo::new(agent_id);
+                                        agent_info.set_cti_instance(cti_instance.clone());
+                                        agent_info.set_agent_state(session.get_agent_state());
+                                        agent_info.set_department_id(open_conf.department_id);
+                                        if let Some(agent_extension) = session.get_agent_extension()
+                                        {
+                                            agent_info.set_agent_extension(agent_extension);
+                                        }
+                                        if let Some(agent_instrument) =
+                                            session.get_agent_instrument()
+                                        {
+                                            agent_info.set_agent_instrument(agent_instrument);
+                                        }
+                                        state.agent_device_index.update(&agent_info);
+                                        log::info!(
+                                            "Registered client events session agent. cti_instance: {}, agent_id: {}",
+                                            cti_instance,
+                                            agent_id
+                                        );
+
+                                        Self::publish_agent_info(
+                                            self.broker_event_channel_tx.clone(),
+                                            &self.client_subscription_map,
+                                            &self.field_masker,
+                                            &self.locale_dictionary,
+                                            &mut self.agent_delta_tracker,
+                                            self.agent_delta_broadcast,
+                                            agent_info.clone(),
+                                        );
+                                        Self::notify_agent_state(&self.subscribers, &agent_info);
+                                        state
+                                            .agent_info_map
+                                            .insert(agent_id.to_string(), agent_info);
+                                    }
+                                }
+
+                                // 재접속이면 연결이 끊긴 동안 바뀐 상담직원 상태를 다시 조회한다
+                                if state.is_opened {
+                                    Self::start_cti_resync(
+                                        &cti_instance,
+                                        Self::get_peripheral_id(&self.config, &cti_instance),
+                                        state,
+                                        &self.invoke_id_generator,
+                                        &self.broker_event_channel_tx,
+                                    );
+                                }
+                                state.is_opened = true;
+                                self.notify_cti_opened();
+                                Self::notify_system_event(
+                                    &self.subscribers,
+                                    &SystemEvent::CtiConnected {
+                                        cti_instance,
+                                        cti_server_host,
+                                        monitor_id,
+                                    },
+                                );
+                            }
+                            // CHANGE_MONITOR_MASK_CONF 메시지 수신
+                            MessageType::CHANGE_MONITOR_MASK_CONF => {
+                                let change_monitor_mask_conf =
+                                    match cti_codec.deserialize::<ChangeMonitorMaskConf>(&mut data) {
+                                        Ok(change_monitor_mask_conf) => change_monitor_mask_conf,
+                                        Err(e) => {
+                                            log::error!("Invalid CHANGE_MONITOR_MASK_CONF. {}", e);
+                                            continue;
+                                        }
+                                    };
+
+                                if let Some(call_msg_mask) =
+                                    state.session.as_mut().and_then(|session| {
+                                        session.confirm_call_msg_mask(
+                                            &change_monitor_mask_conf.invoke_id,
+                                        )
+                                    })
+                                {
+                                    log::info!(
+                                        "Changed monitor mask. cti_instance: {}, call_msg_mask: {:#010x}",
+                                        cti_instance,
+                                        call_msg_mask
+                                    );
+                                }
+                            }
+                            // AGENT_TEAM_CONFIG_EVENT 메시지 수신
+                            MessageType::AGENT_TEAM_CONFIG_EVENT => {
+                                let agent_team_config_event =
+                                    match cti_codec.deserialize::<AgentTeamConfigEvent>(&mut data) {
+                                        Ok(agent_team_config_event) => agent_team_config_event,
+                                        Err(e) => {
+                                            log::error!("Invalid AGENT_TEAM_CONFIG_EVENT. {}", e);
+                                            continue;
+                                        }
+                                    };
+                                log::info!("{:?}", agent_team_config_event);
+
+                                // 팀 구성원 갱신 (ConfigOperation 2: 삭제, 그 외: 추가/변경)
+                                let team_id = agent_team_config_event.team_id;
+                                let is_removal = agent_team_config_event.config_operation == 2;
+
+                                if is_removal && agent_team_config_event.agents.is_empty() {
+                                    state.team_info_map.remove(&team_id);
+                                } else {
+                                    let team_info =
+                                        state.team_info_map.entry(team_id).or_insert_with(|| {
+                                            let mut team_info = TeamInfo::new(team_id);
+                                            team_info.set_cti_instance(cti_instance.clone());
+                                            team_info
+                                        });
+
+                                    if let Some(agent_team_name) =
+                                        &agent_team_config_event.agent_team_name
+                                    {
+                                        team_info.set_team_name(agent_team_name.data.clone());
+                                    }
+                                    team_info.set_department_id(agent_team_config_event.department_id);
+
+                                    agent_team_config_event.agents.iter().for_each(|agent| {
+                                        if let Some(agent_id) = &agent.agent_id {
+                                            match is_removal {
+                                                true => team_info.remove_agent(&agent_id.data),
+                                                false => team_info.add_agent(agent_id.data.clone()),
+                                            }
+                                        }
+                                    });
+                                }
+
+                                // 팀 구성 이벤트 전송
+                                Self::broadcast_team_snapshot(
+                                    None,
+                                    self.broker_event_channel_tx.clone(),
+                                    &self.client_subscription_map,
+                                    &self.cti_instance_state_map,
+                                );
+                                let state = self
+                                    .cti_instance_state_map
+                                    .entry(cti_instance.clone())
+                                    .or_default();
+
+                                // 삭제된 상담직원은 제거하거나 팀 정보를 비우고, 상태를 요청하지 않는다
+                                if is_removal {
+                                    let removed_agent_ids = agent_team_config_event
+                                        .agents
+                                        .iter()
+                                        .filter_map(|agent| agent.agent_id.as_ref())
+                                        .map(|agent_id| agent_id.data.clone())
+                                        .collect::<Vec<_>>();
+
+                                    let target_agent_ids = state
+                                        .agent_info_map
+                                        .values()
+                                        .filter(|agent_info| {
+                                            agent_info.get_team_id() == team_id
+                                                && (removed_agent_ids.is_empty()
+                                                    || removed_agent_ids.iter().any(|agent_id| {
+                                                        agent_id == agent_info.get_agent_id()
+                                                    }))
+                                        })
+                                        .map(|agent_info| agent_info.get_agent_id().to_string())
+                                        .collect::<Vec<_>>();
+
+                                    for agent_id in target_agent_ids {
+                                        if self.agent_team_delete_eviction {
+                                            if Self::evict_agent(
+                                                &agent_id,
+                                                &mut state.agent_info_map,
+                                                &mut state.agent_device_index,
+                                                &mut state.skill_group_agent_map,
+                                                &mut state.agent_state_history_map,
+                                                &mut state.agent_statistics_map,
+                                                &mut state.agent_logout_map,
+                                            ) {
+                                                let agent_removal = AgentRemoval::new(
+                                                    cti_instance.clone(),
+                                                    agent_id,
+                                                    AgentRemovalReason::TEAM_CONFIG_DELETED,
+                                                );
+                                                Self::notify_agent_removed(&self.subscribers, &agent_removal);
+                                                Self::broadcast_agent_removal(
+                                                    None,
+                                                    self.broker_event_channel_tx.clone(),
+                                                    &self.client_subscription_map,
+                                                    &mut self.agent_delta_tracker,
+                                                    agent_removal,
+                                                );
+                                            }
+                                        } else if let Some(agent_info) =
+                                            state.agent_info_map.get_mut(&agent_id)
+                                        {
+                                            agent_info.set_team(0, "");
+
+                                            // 상담직원 이벤트 전송
+                                            Self::publish_agent_info(
+                                                self.broker_event_channel_tx.clone(),
+                                                &self.client_subscription_map,
+                                                &self.field_masker,
+                                                &self.locale_dictionary,
+                                                &mut self.agent_delta_tracker,
+                                                self.agent_delta_broadcast,
+                                                agent_info.clone(),
+                                            );
+                                            Self::notify_agent_state(&self.subscribers, agent_info);
+                                        }
+                                    }
+                                    continue;
+                                }
+
+                                let team_name = state
+                                    .team_info_map
+                                    .get(&team_id)
+                                    .map(|team_info| team_info.get_team_name().to_string())
+                                    .unwrap_or_default();
+                                let department_id = agent_team_config_event.department_id;
+                                let pg_clock = self
+                                    .config
+                                    .get_cti_client(&cti_instance)
+                                    .map(|cti_client| cti_client.pg_clock)
+                                    .unwrap_or_default();
+
+                                // ATCAgent의 상태를 CTI 서버에 요청한다
+                                agent_team_config_event.agents.iter().for_each(|agent| {
+                                    if let Some(agent_id) = &agent.agent_id {
+                                        self.broker_event_channel_tx
+                                            .send(BrokerEvent::RequestAgentStateEvent {
+                                                cti_instance: cti_instance.clone(),
+                                                invoke_id: self.invoke_id_generator.next_id(),
+                                                peripheral_id: agent_team_config_event
+                                                    .peripheral_id,
+                                                agent_id: agent_id.data.clone(),
+                                            })
+                                            .unwrap();
+
+                                        // 상태가 빠진 상담직원은 조회 응답을 받을 때까지 알수없음으로 둔다
+                                        let agent_state = agent
+                                            .agent_state
+                                            .as_ref()
+                                            .map_or(9, |agent_state| agent_state.data);
+                                        let state_duration = agent
+                                            .state_duration
+                                            .as_ref()
+                                            .map_or(0, |state_duration| state_duration.data);
+
+                                        match state.agent_info_map.get_mut(&agent_id.data) {
+                                            Some(agent_info) => {
+                                                agent_info.set_agent_state(agent_state);
+                                                agent_info
+                                                    .set_state_duration(state_duration, &pg_clock);
+                                                agent_info.set_team(team_id, team_name.clone());
+                                                agent_info.set_department_id(department_id);
+
+                                                // 상담직원 이벤트 전송
+                                                Self::publish_agent_info(
+                                                    self.broker_event_channel_tx.clone(),
+                                                    &self.client_subscription_map,
+                                                    &self.field_masker,
+                                                    &self.locale_dictionary,
+                                                    &mut self.agent_delta_tracker,
+                                                    self.agent_delta_broadcast,
+                                                    agent_info.clone(),
+                                                );
+
+                                                Self::notify_agent_state(
+                                                    &self.subscribers,
+                                                    agent_info,
+                                                );
+                                            }
+                                            None => {
+                                                let mut agent_info =
+                                                    AgentInfo::new(agent_id.clone().data);
+                                                agent_info.set_cti_instance(cti_instance.clone());
+
+                                                agent_info.set_agent_state(agent_state);
+                                                agent_info
+                                                    .set_state_duration(state_duration, &pg_clock);
+                                                agent_info.set_team(team_id, team_name.clone());
+                                                agent_info.set_department_id(department_id);
+                                                if let Some(agent_name) =
+                                                    state.agent_name_map.get(&agent_id.data)
+                                                {
+                                                    agent_info.set_agent_name(agent_name.clone());
+                                                }
+
+                                                state.agent_info_map.insert(
+                                                    agent_id.data.clone(),
+                                                    agent_info.clone(),
+                                                );
+
+                                                // 상담직원 이벤트 전송
+                                                Self::publish_agent_info(
+                                                    self.broker_event_channel_tx.clone(),
+                                                    &self.client_subscription_map,
+                                                    &self.field_masker,
+                                                    &self.locale_dictionary,
+                                                    &mut self.agent_delta_tracker,
+                                                    self.agent_delta_broadcast,
+                                                    agent_info.clone(),
+                                                );
+                                                Self::notify_agent_state(
+                                                    &self.subscribers,
+                                                    &agent_info,
+                                                );
+                                            }
+                                        };
+                                    }
+                                });
+                            }
+                            // CONFIG_AGENT_EVENT 메시지 수신
+                            MessageType::CONFIG_AGENT_EVENT => {
+                                let config_agent_event =
+                                    match cti_codec.deserialize::<ConfigAgentEvent>(&mut data) {
+                                        Ok(config_agent_event) => config_agent_event,
+                                        Err(e) => {
+                                            log::error!("Invalid CONFIG_AGENT_EVENT. {}", e);
+                                            continue;
+                                        }
+                                    };
+                                log::info!("{:?}", config_agent_event);
+
+                                // 상담직원 이름은 팀 구성 이벤트보다 먼저 수신될 수 있어 별도로 보관한다
+                                config_agent_event.records.iter().for_each(|record| {
+                                    if let Some(login_id) = &record.login_id {
+                                        let agent_name = record.display_name();
+                                        state
+                                            .agent_name_map
+                                            .insert(login_id.data.clone(), agent_name.clone());
+
+                                        if let Some(agent_info) =
+                                            state.agent_info_map.get_mut(&login_id.data)
+                                        {
+                                            agent_info.set_agent_name(agent_name);
+
+                                            // 상담직원 이벤트 전송
+                                            Self::publish_agent_info(
+                                                self.broker_event_channel_tx.clone(),
+                                                &self.client_subscription_map,
+                                                &self.field_masker,
+                                                &self.locale_dictionary,
+                                                &mut self.agent_delta_tracker,
+                                                self.agent_delta_broadcast,
+                                                agent_info.clone(),
+                                            );
+                                            Self::notify_agent_state(&self.subscribers, agent_info);
+                                        }
+                                    }
+                                });
+                            }
+                            // QUERY_AGENT_STATE_CONF 메시지 수신
+                            MessageType::QUERY_AGENT_STATE_CONF => {
+                                let query_agent_state_conf =
+                                    match cti_codec.deserialize::<QueryAgentStateConf>(&mut data) {
+                                        Ok(query_agent_state_conf) => query_agent_state_conf,
+                                        Err(e) => {
+                                            log::error!("Invalid QUERY_AGENT_STATE_CONF. {}", e);
+                                            continue;
+                                        }
+                                    };
+                                log::info!("{:?}", query_agent_state_conf);
+
+                                let agent_id = query_agent_state_conf.agent_id.unwrap().data;
+                                let agent_state = query_agent_state_conf.agent_state;
+                                let icm_agent_id = query_agent_state_conf.icm_agent_id;
+                                let department_id = query_agent_state_conf.department_id;
+                                let skill_groups = query_agent_state_conf.skill_groups;
+                                let skill_group_id = skill_groups
+                                    .first()
+                                    .map(|skill_group| skill_group.skill_group_id)
+                                    .unwrap_or_default();
+                                let agent_extension =
+                                    query_agent_state_conf.agent_extension.unwrap().data;
+                                let agent_instrument = query_agent_state_conf
+                                    .agent_instrument
+                                    .map(|agent_instrument| agent_instrument.data)
+                                    .unwrap_or_default();
+
+                                if let Some(agent_info) = state.agent_info_map.get_mut(&agent_id) {
+                                    agent_info.set_agent_state(agent_state);
+                                    agent_info.set_skill_group_id(skill_group_id as u16);
+                                    agent_info.set_skill_groups(skill_groups.clone());
+                                    agent_info.set_icm_agent_id(icm_agent_id);
+                                    agent_info.set_department_id(department_id);
+                                    agent_info.set_agent_extension(agent_extension);
+                                    agent_info.set_agent_instrument(agent_instrument);
+                                    state.agent_device_index.update(agent_info);
+
+                                    // 상담직원 이벤트 전송
+                                    Self::publish_agent_info(
+                                        self.broker_event_channel_tx.clone(),
+                                        &self.client_subscription_map,
+                                        &self.field_masker,
+                                        &self.locale_dictionary,
+                                        &mut self.agent_delta_tracker,
+                                        self.agent_delta_broadcast,
+                                        agent_info.clone(),
+                                    );
+
+                                    Self::notify_agent_state(&self.subscribers, agent_info);
+                                }
+
+                                // 재동기화 조회 응답을 모두 받으면 재동기화를 마친다
+                                if state
+                                    .resync_invoke_ids
+                                    .remove(&query_agent_state_conf.invoke_id)
+                                    && state.resync_invoke_ids.is_empty()
+                                {
+                                    Self::finish_cti_resync(
+                                        &cti_instance,
+                                        state,
+                                        &self.broker_event_channel_tx,
+                                    );
+                                }
+
+                                // 클라이언트 조회 요청에 대한 응답이면 요청한 클라이언트에게만 전송한다
+                                if let Some(pending_agent_query) = state
+                                    .pending_agent_query_map
+                                    .remove(&query_agent_state_conf.invoke_id)
+                                {
+                                    let mut agent_info = match state.agent_info_map.get(&agent_id) {
+                                        Some(agent_info) => agent_info.clone(),
+                                        None => {
+                                            let mut agent_info = AgentInfo::new(agent_id.clone());
+                                            agent_info.set_cti_instance(cti_instance.clone());
+                                            agent_info.set_agent_state(agent_state);
+                                            agent_info.set_skill_group_id(skill_group_id as u16);
+                                            agent_info.set_skill_groups(skill_groups);
+                                            agent_info.set_icm_agent_id(icm_agent_id);
+                                            agent_info.set_department_id(department_id);
+                                            agent_info
+                                        }
+                                    };
+                                    agent_info.mask_fields(&self.field_masker);
+                                    if let Some(locale) = self
+                                        .client_subscription_map
+                                        .get(&pending_agent_query.client_id)
+                                        .and_then(ClientSubscription::get_locale)
+                                    {
+                                        agent_info.localize(&self.locale_dictionary, locale);
+                                    }
+
+                                    // 다른 부서 상담직원은 상담직원 정보 없이 응답한다
+                                    let agent_info = Some(agent_info).filter(|agent_info| {
+                                        Self::matches_client_department(
+                                            &self.client_subscription_map,
+                                            pending_agent_query.client_id,
+                                            Some(agent_info),
+                                        )
+                                    });
+
+                                    self.broker_event_channel_tx
+                                        .send(BrokerEvent::BroadCastAgentQueryResult {
+                                            client_id: Some(pending_agent_query.client_id),
+                                            agent_query_result: AgentQueryResult::new(
+                                                pending_agent_query.request_id,
+                                                cti_instance.clone(),
+                                                agent_id,
+                                                agent_info,
+                                            ),
+                                        })
+                                        .unwrap();
+                                }
+                            }
+                            // AGENT_STATE_EVENT 메시지 수신
+                            MessageType::AGENT_STATE_EVENT => {
+                                let agent_state_event =
+                                    match cti_codec.deserialize::<AgentStateEvent>(&mut data) {
+                                        Ok(agent_state_event) => agent_state_event,
+                                        Err(e) => {
+                                            log::error!("Invalid AGENT_STATE_EVENT. {}", e);
+                                            continue;
+                                        }
+                                    };
+
+                                log::info!("{:?}", agent_state_event);
+
+                                let agent_id = agent_state_event.agent_id.unwrap().data;
+                                let agent_state = agent_state_event.agent_state;
+                                let icm_agent_id = agent_state_event.icm_agent_id;
+                                let skill_group_id = agent_state_event.skill_group_id;
+                                let skill_groups = agent_state_event.skill_groups;
+                                let agent_extension =
+                                    agent_state_event.agent_extension.unwrap().data;
+                                let agent_instrument = agent_state_event
+                                    .agent_instrument
+                                    .map(|agent_instrument| agent_instrument.data)
+                                    .unwrap_or_default();
+                                let direction = agent_state_event.direction.unwrap().data;
+                                let reason_code = agent_state_event.event_reason_code;
+                                let state_duration = agent_state_event.state_duration;
+                                let department_id = agent_state_event.department_id;
+
+                                let pg_clock = self
+                                    .config
+                                    .get_cti_client(&cti_instance)
+                                    .map(|cti_client| cti_client.pg_clock)
+                                    .unwrap_or_default();
+
+                                // 음성 외 MRD(채팅, 이메일 등) 상태는 MRD 별 상태로만 갱신한다 (0: MRD 를 알리지 않는 이전 버전)
+                                let voice_mrd_id = self
+                                    .config
+                                    .get_cti_client(&cti_instance)
+                                    .map(|cti_client| cti_client.voice_mrd_id)
+                                    .unwrap_or(DEFAULT_VOICE_MRD_ID);
+                                if agent_state_event.mrd_id != 0
+                                    && agent_state_event.mrd_id != voice_mrd_id
+                                {
+                                    if let Some(agent_info) = state.agent_info_map.get_mut(&agent_id)
+                                    {
+                                        let mut media_state =
+                                            MediaState::new(agent_state_event.mrd_id, agent_state);
+                                        media_state.set_state_duration(state_duration, &pg_clock);
+                                        media_state.set_reason_code(reason_code);
+                                        media_state.set_tasks(
+                                            agent_state_event.num_tasks,
+                                            agent_state_event.max_task_limit,
+                                        );
+                                        agent_info.set_media_state(media_state);
+
+                                        // 상담직원 이벤트 전송
+                                        Self::publish_agent_info(
+                                            self.broker_event_channel_tx.clone(),
+                                            &self.client_subscription_map,
+                                            &self.field_masker,
+                                            &self.locale_dictionary,
+                                            &mut self.agent_delta_tracker,
+                                            self.agent_delta_broadcast,
+                                            agent_info.clone(),
+                                        );
+                                        Self::notify_agent_state(&self.subscribers, agent_info);
+                                    }
+                                    continue;
+                                }
+
+                                // 스킬그룹별 상담직원 상태 갱신 (로그아웃 시 모든 스킬그룹에서 제외)
+                                if agent_state == 1 {
+                                    state.skill_group_agent_map.values_mut().for_each(
+                                        |agent_state_map| {
+                                            agent_state_map.remove(&agent_id);
+                                        },
+                                    );
+                                } else if agent_state_event.skill_group_id != 0 {
+                                    state
+                                        .skill_group_agent_map
+                                        .entry(agent_state_event.skill_group_id)
+                                        .or_default()
+                                        .insert(
+                                            agent_id.clone(),
+                                            agent_state_event.skill_group_state,
+                                        );
+                                }
+
+                                if let Some(agent_info) = state.agent_info_map.get_mut(&agent_id) {
+                                    // 상태 변경 이력 추가
+                                    if agent_info.get_agent_state() != agent_state {
+                                        state
+                                            .agent_state_history_map
+                                            .entry(agent_id.clone())
+                                            .or_insert_with(|| {
+                                                let mut agent_state_history =
+                                                    AgentStateHistory::new(
+                                                        agent_id.clone(),
+                                                        self.agent_state_history_size,
+                                                    );
+                                                agent_state_history
+                                                    .set_cti_instance(cti_instance.clone());
+                                                agent_state_history
+                                            })
+                                            .push(
+                                                agent_info.get_agent_state(),
+                                                agent_state,
+                                                reason_code,
+                                            );
+
+                                        // 일별 통계 갱신
+                                        let now = Local::now().naive_local();
+                                        let agent_statistics = state
+                                            .agent_statistics_map
+                                            .entry(agent_id.clone())
+                                            .or_insert_with(|| {
+                                                let mut agent_statistics = AgentStatistics::new(
+                                                    agent_id.clone(),
+                                                    now.date(),
+                                                );
+                                                agent_statistics
+                                                    .set_cti_instance(cti_instance.clone());
+                                                agent_statistics
+                                            });
+                                        agent_statistics.update_state(
+                                            agent_state,
+                                            reason_code,
+                                            now,
+                                        );
+
+                                        // 상담직원 통계 이벤트 전송
+                                        Self::broadcast_agent_statistics(
+                                            None,
+                                            self.broker_event_channel_tx.clone(),
+                                            &self.client_subscription_map,
+                                            Some(&*agent_info),
+                                            agent_statistics.snapshot(now),
+                                        );
+                                    }
+
+                                    agent_info.set_agent_state(agent_state);
+                                    agent_info.set_skill_group_id(skill_group_id as u16);
+                                    agent_info.set_icm_agent_id(icm_agent_id);
+                                    agent_info.set_agent_extension(agent_extension);
+                                    agent_info.set_direction(direction);
+                                    agent_info.set_reason_code(reason_code);
+                                    agent_info.set_reason_code_label(
+                                        self.reason_code_dictionary
+                                            .label(agent_info.get_reason_code())
+                                            .unwrap_or_default(),
+                                    );
+                                    agent_info.set_state_duration(state_duration, &pg_clock);
+                                    agent_info.set_department_id(department_id);
+                                    agent_info.set_agent_instrument(agent_instrument);
+                                    agent_info.set_skill_groups(skill_groups);
+                                    state.agent_device_index.update(agent_info);
+
+                                    // 상담직원 이벤트 전송
+                                    Self::publish_agent_info(
+                                        self.broker_event_channel_tx.clone(),
+                                        &self.client_subscription_map,
+                                        &self.field_masker,
+                                        &self.locale_dictionary,
+                                        &mut self.agent_delta_tracker,
+                                        self.agent_delta_broadcast,
+                                        agent_info.clone(),
+                                    );
+
+                                    Self::notify_agent_state(&self.subscribers, agent_info);
+                                }
+                            }
+                            // BEGIN_CALL_EVENT 메시지 수신
+                            MessageType::BEGIN_CALL_EVENT => {
+                                Self::notify_call_event(&self.subscribers, &message_type, &data);
+
+                                let begin_call_event =
+                                    match cti_codec.deserialize::<BeginCallEvent>(&mut data) {
+                                        Ok(begin_call_event) => begin_call_event,
+                                        Err(e) => {
+                                            log::error!("Invalid BEGIN_CALL_EVENT. {}", e);
+                                            continue;
+                                        }
+                                    };
+                                log::info!("{:?}", begin_call_event);
+
+                                let call_info = state
+                                    .call_info_map
+                                    .entry(begin_call_event.connection_call_id)
+                                    .or_insert_with(|| {
+                                        let mut call_info =
+                                            CallInfo::new(begin_call_event.connection_call_id);
+                                        call_info.set_cti_instance(cti_instance.clone());
+                                        call_info
+                                    });
+
+                                call_info.set_call_type(begin_call_event.call_type);
+                                if let Some(connection_device_id) =
+                                    begin_call_event.connection_device_id
+                                {
+                                    call_info.set_connection_device_id(connection_device_id.data);
+                                }
+                                if let Some(ani) = begin_call_event.ani {
+                                    call_info.set_ani(ani.data);
+                                }
+                                if let Some(dnis) = begin_call_event.dnis {
+                                    call_info.set_dnis(dnis.data);
+                                }
+                                if let Some(dialed_number) = begin_call_event.dialed_number {
+                                    call_info.set_dialed_number(dialed_number.data);
+                                }
+
+                                // 호 이벤트 전송
+                                Self::publish_call_info(
+                                    state.call_broadcast_batch.as_mut(),
+                                    self.broker_event_channel_tx.clone(),
+                                    &self.client_subscription_map,
+                                    &self.field_masker,
+                                    Self::call_department_id(&state.agent_info_map, call_info),
+                                    call_info.clone(),
+                                );
+                                Self::notify_call_state(&self.subscribers, call_info);
+                            }
+                            // CALL_QUEUED_EVENT 메시지 수신
+                            MessageType::CALL_QUEUED_EVENT => {
+                                Self::notify_call_event(&self.subscribers, &message_type, &data);
+
+                                let call_queued_event =
+                                    match cti_codec.deserialize::<CallQueuedEvent>(&mut data) {
+                                        Ok(call_queued_event) => call_queued_event,
+                                        Err(e) => {
+                                            log::error!("Invalid CALL_QUEUED_EVENT. {}", e);
+                                            continue;
+                                        }
+                                    };
+                                log::info!("{:?}", call_queued_event);
+
+                                let call_info = state
+                                    .call_info_map
+                                    .entry(call_queued_event.connection_call_id)
+                                    .or_insert_with(|| {
+                                        let mut call_info =
+                                            CallInfo::new(call_queued_event.connection_call_id);
+                                        call_info.set_cti_instance(cti_instance.clone());
+                                        call_info
+                                    });
+
+                                call_info.set_call_state(CallState::QUEUED);
+                                // 여러 스킬그룹에 대기하는 경우 첫번째 스킬그룹으로 집계한다
+                                if let Some(skill_group_id) =
+                                    call_queued_event.skill_group_ids.first()
+                                {
+                                    call_info.set_skill_group_id(skill_group_id.data);
+                                }
+
+                                // 호 이벤트 전송
+                                Self::publish_call_info(
+                                    state.call_broadcast_batch.as_mut(),
+                                    self.broker_event_channel_tx.clone(),
+                                    &self.client_subscription_map,
+                                    &self.field_masker,
+                                    Self::call_department_id(&state.agent_info_map, call_info),
+                                    call_info.clone(),
+                                );
+                                Self::notify_call_state(&self.subscribers, call_info);
+                            }
+                            // CALL_ESTABLISHED_EVENT 메시지 수신
+                            MessageType::CALL_ESTABLISHED_EVENT => {
+                                Self::notify_call_event(&self.subscribers, &message_type, &data);
+
+                                let call_established_event =
+                                    match cti_codec.deserialize::<CallEstablishedEvent>(&mut data) {
+                                        Ok(call_established_event) => call_established_event,
+                                        Err(e) => {
+                                            log::error!("Invalid CALL_ESTABLISHED_EVENT. {}", e);
+                                            continue;
+                                        }
+                                    };
+                                log::info!("{:?}", call_established_event);
+
+                                // CTM 기동 전에 시작된 호는 BEGIN_CALL_EVENT 없이 수신될 수 있다
+                                let call_info = state
+                                    .call_info_map
+                                    .entry(call_established_event.connection_call_id)
+                                    .or_insert_with(|| {
+                                        let mut call_info = CallInfo::new(
+                                            call_established_event.connection_call_id,
+                                        );
+                                        call_info.set_cti_instance(cti_instance.clone());
+                                        call_info
+                                    });
+
+                                call_info.set_call_state(CallState::ESTABLISHED);
+                                call_info.set_skill_group_id(call_established_event.skill_group_id);
+
+                                // 응답한 내선으로 상담직원을 찾아 연결한다
+                                if let Some(answering_device_id) =
+                                    call_established_event.answering_device_id
+                                {
+                                    if let Some(agent_info) = state
+                                        .agent_device_index
+                                        .get_agent_id(&answering_device_id.data)
+                                        .and_then(|agent_id| state.agent_info_map.get(agent_id))
+                                    {
+                                        call_info.set_agent(
+                                            agent_info.get_agent_id(),
+                                            answering_device_id.data,
+                                        );
+                                    }
+                                }
+
+                                // 호 이벤트 전송
+                                Self::publish_call_info(
+                                    state.call_broadcast_batch.as_mut(),
+                                    self.broker_event_channel_tx.clone(),
+                                    &self.client_subscription_map,
+                                    &self.field_masker,
+                                    Self::call_department_id(&state.agent_info_map, call_info),
+                                    call_info.clone(),
+                                );
+                                Self::notify_call_state(&self.subscribers, call_info);
+                            }
+                            // CALL_CLEARED_EVENT 메시지 수신
+                            MessageType::CALL_CLEARED_EVENT => {
+                                Self::notify_call_event(&self.subscribers, &message_type, &data);
+
+                                let call_cleared_event =
+                                    match cti_codec.deserialize::<CallClearedEvent>(&mut data) {
+                                        Ok(call_cleared_event) => call_cleared_event,
+                                        Err(e) => {
+                                            log::error!("Invalid CALL_CLEARED_EVENT. {}", e);
+                                            continue;
+                                        }
+                                    };
+                                log::info!("{:?}", call_cleared_event);
+
+                                // 종료된 호는 목록에서 제거하고 마지막 상태를 전송한다
+                                if let Some(mut call_info) = state
+                                    .call_info_map
+                                    .remove(&call_cleared_event.connection_call_id)
+                                {
+                                    call_info.set_call_state(CallState::CLEARED);
+
+                                    // 호 이벤트 전송
+                                    Self::publish_call_info(
+                                        state.call_broadcast_batch.as_mut(),
+                                        self.broker_event_channel_tx.clone(),
+                                        &self.client_subscription_map,
+                                        &self.field_masker,
+                                        Self::call_department_id(
+                                            &state.agent_info_map,
+                                            &call_info,
+                                        ),
+                                        call_info.clone(),
+                                    );
+                                    Self::notify_call_state(&self.subscribers, &call_info);
+                                }
+                            }
+                            // EMERGENCY_CALL_EVENT, SUPERVISOR_ASSIST_EVENT 메시지 수신
+                            MessageType::EMERGENCY_CALL_EVENT
+                            | MessageType::SUPERVISOR_ASSIST_EVENT => {
+                                let emergency_call_event =
+                                    match cti_codec.deserialize::<EmergencyCallEvent>(&mut data) {
+                                        Ok(emergency_call_event) => emergency_call_event,
+                                        Err(e) => {
+                                            log::error!("Invalid {:?}. {}", message_type, e);
+                                            continue;
+                                        }
+                                    };
+                                log::warn!("{:?}", emergency_call_event);
+
+                                let alert_type = match message_type {
+                                    MessageType::EMERGENCY_CALL_EVENT => {
+                                        SupervisorAlertType::EMERGENCY_CALL
+                                    }
+                                    _ => SupervisorAlertType::SUPERVISOR_ASSIST,
+                                };
+                                let mut supervisor_alert = SupervisorAlert::new(
+                                    alert_type,
+                                    cti_instance.clone(),
+                                    &emergency_call_event,
+                                );
+                                if let Some(agent_info) =
+                                    state.agent_info_map.get(supervisor_alert.get_agent_id())
+                                {
+                                    supervisor_alert.set_agent_info(agent_info);
+                                }
+                                supervisor_alert.mask_fields(&self.field_masker);
+
+                                self.broadcast_supervisor_alert(supervisor_alert);
+                            }
+                            // HEARTBEAT_CONF 메시지 수신
+                            MessageType::HEARTBEAT_CONF => {
+                                state.cti_link.on_heartbeat_conf(Instant::now());
+                                Self::notify_system_event(
+                                    &self.subscribers,
+                                    &SystemEvent::HeartBeatConf { cti_instance },
+                                );
+                            }
+                            // 호 이벤트 메시지 수신
+                            message_type if message_type.is_call_event() => {
+                                Self::notify_call_event(&self.subscribers, &message_type, &data);
+                            }
+                            // 처리되지 않은 메시지 수신
+                            message_type => {
+                                log::info!(
+                                    "Received CTI message. message_type: {:?}",
+                                    message_type
+                                );
+                            }
+                        }
+                    }
+                },
+                // 클라이언트 이벤트 채널 수신
+                Some(event) = self.client_event_channel_rx.recv() => match event {
+                    ClientEvent::Connect {
+                        id,
+                        permission,
+                        department_id,
+                        common_name,
+                        addr,
+                        direct_event_tx,
+                        resume,
+                    } => {
+                        log::info!(
+                            "Client connected. id: {}, addr: {:?}, permission: {:?}, department_id: {:?}, common_name: {:?}",
+                            id,
+                            addr,
+                            permission,
+                            department_id,
+                            common_name
+                        );
+                        self.client_info_map
+                            .insert(id, ClientInfo::new(id, addr, permission, common_name));
+                        let mut client_subscription = self.default_client_subscription.clone();
+                        client_subscription.set_department_id(department_id);
+                        self.client_subscription_map.insert(id, client_subscription);
+                        if let Some(direct_event_tx) = direct_event_tx {
+                            self.direct_event_tx_map.insert(id, direct_event_tx);
+                        }
+                        Self::notify_system_event(
+                            &self.subscribers,
+                            &SystemEvent::ClientConnected { id },
+                        );
+
+                        if let Some(full_state_rebroadcast) = self.full_state_rebroadcast.as_mut() {
+                            full_state_rebroadcast.add_client(id, Instant::now());
+                        }
+                        self.welcome_client(id, resume);
+                    }
+                    ClientEvent::Receive { data, id } => {
+                        log::debug!("Client sent. id: {}, data: {:?}", id, data);
+
+                        match ClientCommand::parse(&data) {
+                            // 권한이 부족한 클라이언트는 제어, 관리 명령을 사용할 수 없다
+                            Ok(command)
+                                if self.client_info_map.get(&id).is_none_or(|client_info| {
+                                    client_info.get_permission() < command.required_permission()
+                                }) =>
+                            {
+                                log::warn!(
+                                    "Client is not permitted to send command. id: {}, command: {:?}",
+                                    id,
+                                    command
+                                );
+                            }
+                            // 상담직원 상태 변경 이력 요청
+                            Ok(ClientCommand::AGENT_STATE_HISTORY {
+                                cti_instance,
+                                agent_id,
+                            }) => {
+                                // 다른 부서 상담직원은 빈 이력으로 응답한다
+                                let agent_state_history = self
+                                    .cti_instance_state_map
+                                    .get(&cti_instance)
+                                    .filter(|state| {
+                                        Self::matches_client_department(
+                                            &self.client_subscription_map,
+                                            id,
+                                            state.agent_info_map.get(&agent_id),
+                                        )
+                                    })
+                                    .and_then(|state| state.agent_state_history_map.get(&agent_id))
+                                    .cloned()
+                                    .unwrap_or_else(|| {
+                                        let mut agent_state_history = AgentStateHistory::new(
+                                            agent_id,
+                                            self.agent_state_history_size,
+                                        );
+                                        agent_state_history.set_cti_instance(cti_instance);
+                                        agent_state_history
+                                    });
+
+                                self.broker_event_channel_tx
+                                    .send(BrokerEvent::BroadCastAgentStateHistory {
+                                        client_id: Some(id),
+                                        agent_state_history,
+                                    })
+                                    .unwrap();
+                            }
+                            // 상담직원 전체 상태 재전송 요청
+                            Ok(ClientCommand::AGENT_SNAPSHOT) => {
+                                self.send_client_events(
+                                    id,
+                                    Self::agent_snapshot_events(
+                                        id,
+                                        &self.client_subscription_map,
+                                        &self.locale_dictionary,
+                                        &self.agent_delta_tracker,
+                                        self.agent_delta_broadcast,
+                                    ),
+                                );
+                            }
+                            // 상담직원 일별 통계 요청
+                            Ok(ClientCommand::AGENT_STATISTICS {
+                                cti_instance,
+                                agent_id,
+                            }) => {
+                                // 다른 부서 상담직원은 빈 통계로 응답한다
+                                let now = Local::now().naive_local();
+                                let agent_statistics = match self
+                                    .cti_instance_state_map
+                                    .get(&cti_instance)
+                                    .filter(|state| {
+                                        Self::matches_client_department(
+                                            &self.client_subscription_map,
+                                            id,
+                                            state.agent_info_map.get(&agent_id),
+                                        )
+                                    })
+                                    .and_then(|state| state.agent_statistics_map.get(&agent_id))
+                                {
+                                    Some(agent_statistics) => agent_statistics.snapshot(now),
+                                    None => {
+                                        let mut agent_statistics =
+                                            AgentStatistics::new(agent_id, now.date());
+                                        agent_statistics.set_cti_instance(cti_instance);
+                                        agent_statistics
+                                    }
+                                };
+
+                                Self::broadcast_agent_statistics(
+                                    Some(id),
+                                    self.broker_event_channel_tx.clone(),
+                                    &self.client_subscription_map,
+                                    None,
+                                    agent_statistics,
+                                );
+                            }
+                            // 상담직원 상태 조회 요청
+                            Ok(ClientCommand::QUERY_AGENT {
+                                request_id,
+                                cti_instance,
+                                peripheral_id,
+                                agent_id,
+                            }) => {
+                                let peripheral_id =
+                                    peripheral_id.map(PeripheralId).unwrap_or_else(|| {
+                                        Self::get_peripheral_id(&self.config, &cti_instance)
+                                    });
+                                let invoke_id = self.invoke_id_generator.next_id();
+                                log::info!(
+                                    "Client requested agent query. id: {}, request_id: {}, cti_instance: {}, agent_id: {}, invoke_id: {}",
+                                    id,
+                                    request_id,
+                                    cti_instance,
+                                    agent_id,
+                                    invoke_id
+                                );
+
+                                self.cti_instance_state_map
+                                    .entry(cti_instance.clone())
+                                    .or_default()
+                                    .pending_agent_query_map
+                                    .insert(
+                                        invoke_id,
+                                        PendingAgentQuery {
+                                            client_id: id,
+                                            request_id,
+                                            agent_id: agent_id.clone(),
+                                            requested_at: Instant::now(),
+                                        },
+                                    );
+                                self.broker_event_channel_tx
+                                    .send(BrokerEvent::RequestAgentStateEvent {
+                                        cti_instance,
+                                        invoke_id,
+                                        peripheral_id,
+                                        agent_id,
+                                    })
+                                    .unwrap();
+                            }
+                            // 내선(장치) 번호로 상담직원 조회 요청
+                            Ok(ClientCommand::FIND_AGENT {
+                                request_id,
+                                cti_instance,
+                                extension,
+                            }) => {
+                                let agent_info = self
+                                    .cti_instance_state_map
+                                    .get(&cti_instance)
+                                    .and_then(|state| {
+                                        state
+                                            .agent_device_index
+                                            .get_agent_id(&extension)
+                                            .and_then(|agent_id| state.agent_info_map.get(agent_id))
+                                    })
+                                    .filter(|agent_info| {
+                                        Self::matches_client_department(
+                                            &self.client_subscription_map,
+                                            id,
+                                            Some(*agent_info),
+                                        )
+                                    })
+                                    .cloned()
+                                    .map(|mut agent_info| {
+                                        agent_info.mask_fields(&self.field_masker);
+                                        if let Some(locale) = self
+                                            .client_subscription_map
+                                            .get(&id)
+                                            .and_then(ClientSubscription::get_locale)
+                                        {
+                                            agent_info.localize(&self.locale_dictionary, locale);
+                                        }
+                                        agent_info
+                                    });
+                                log::info!(
+                                    "Client requested agent lookup by extension. id: {}, request_id: {}, cti_instance: {}, extension: {}, found: {}",
+                                    id,
+                                    request_id,
+                                    cti_instance,
+                                    extension,
+                                    agent_info.is_some()
+                                );
+
+                                // 찾지 못하면 agent_id 를 비워서 보낸다
+                                let agent_id = agent_info
+                                    .as_ref()
+                                    .map(|agent_info| agent_info.get_agent_id().to_string())
+                                    .unwrap_or_default();
+                                self.broker_event_channel_tx
+                                    .send(BrokerEvent::BroadCastAgentQueryResult {
+                                        client_id: Some(id),
+                                        agent_query_result: AgentQueryResult::new(
+                                            request_id,
+                                            cti_instance,
+                                            agent_id,
+                                            agent_info,
+                                        ),
+                                    })
+                                    .unwrap();
+                            }
+                            // 상담직원 상태 재조회 요청
+                            Ok(ClientCommand::REFRESH_STATE {
+                                cti_instance,
+                                agent_id,
+                                team_id,
+                            }) => {
+                                let state_refresh_result =
+                                    self.refresh_state(&cti_instance, agent_id, team_id);
+                                log::info!(
+                                    "Client requested state refresh. id: {}, state_refresh_result: {:?}",
+                                    id,
+                                    state_refresh_result
+                                );
+
+                                self.send_client_events(
+                                    id,
+                                    vec![BrokerEvent::BroadCastStateRefreshResult {
+                                        client_id: Some(id),
+                                        state_refresh_result,
+                                    }],
+                                );
+                            }
+                            // 구독 조건 추가
+                            Ok(ClientCommand::SUBSCRIBE {
+                                team_ids,
+                                agent_ids,
+                                skill_group_ids,
+                                locale,
+                            }) => {
+                                let client_subscription =
+                                    self.client_subscription_map.entry(id).or_default();
+                                client_subscription.subscribe(
+                                    &team_ids,
+                                    &agent_ids,
+                                    &skill_group_ids,
+                                );
+                                if locale.is_some() {
+                                    client_subscription.set_locale(locale);
+                                }
+                                log::info!(
+                                    "Client subscribed. id: {}, client_subscription: {:?}",
+                                    id,
+                                    client_subscription
+                                );
+
+                                // 구독 조건에 맞는 상담직원 상태를 다시 전송한다
+                                self.send_client_events(
+                                    id,
+                                    Self::agent_snapshot_events(
+                                        id,
+                                        &self.client_subscription_map,
+                                        &self.locale_dictionary,
+                                        &self.agent_delta_tracker,
+                                        self.agent_delta_broadcast,
+                                    ),
+                                );
+                            }
+                            // 구독 조건 제거
+                            Ok(ClientCommand::UNSUBSCRIBE {
+                                team_ids,
+                                agent_ids,
+                                skill_group_ids,
+                            }) => {
+                                let client_subscription =
+                                    self.client_subscription_map.entry(id).or_default();
+                                client_subscription.unsubscribe(
+                                    &team_ids,
+                                    &agent_ids,
+                                    &skill_group_ids,
+                                );
+                                log::info!(
+                                    "Client unsubscribed. id: {}, client_subscription: {:?}",
+                                    id,
+                                    client_subscription
+                                );
+                            }
+                            // 접속 중인 클라이언트 목록 조회
+                            Ok(ClientCommand::LIST_CLIENTS) => {
+                                let client_infos = self
+                                    .client_info_map
+                                    .iter()
+                                    .map(|(client_id, client_info)| {
+                                        let mut client_info = client_info.clone();
+                                        if let Some(client_subscription) =
+                                            self.client_subscription_map.get(client_id)
+                                        {
+                                            client_info
+                                                .set_subscription(client_subscription.clone());
+                                        }
+                                        client_info
+                                    })
+                                    .collect::<Vec<_>>();
+
+                                self.broker_event_channel_tx
+                                    .send(BrokerEvent::BroadCastClientList {
+                                        client_id: Some(id),
+                                        client_infos,
+                                    })
+                                    .unwrap();
+                            }
+                            // 클라이언트 강제 종료
+                            Ok(ClientCommand::DISCONNECT_CLIENT { client_id }) => {
+                                match Uuid::parse_str(&client_id) {
+                                    Ok(client_id)
+                                        if self.client_info_map.contains_key(&client_id) =>
+                                    {
+                                        log::info!(
+                                            "Admin requested client disconnect. id: {}, client_id: {}",
+                                            id,
+                                            client_id
+                                        );
+                                        self.broker_event_channel_tx
+                                            .send(BrokerEvent::DisconnectClient { client_id })
+                                            .unwrap();
+                                    }
+                                    _ => log::warn!(
+                                        "Unknown client to disconnect. id: {}, client_id: {}",
+                                        id,
+                                        client_id
+                                    ),
+                                }
+                            }
+                            // 모든 클라이언트에 전체 상태 재전송
+                            Ok(ClientCommand::REBROADCAST) => {
+                                log::info!("Admin requested full rebroadcast. id: {}", id);
+                                self.client_info_map
+                                    .keys()
+                                    .for_each(|client_id| self.broadcast_client_state(*client_id));
+                            }
+                            // 구독 조건과 관계없이 전체 상담직원 상태 전송
+                            Ok(ClientCommand::DUMP_AGENTS) => {
+                                let mut agent_snapshot = self.agent_delta_tracker.snapshot();
+                                if let Some(locale) = self
+                                    .client_subscription_map
+                                    .get(&id)
+                                    .and_then(ClientSubscription::get_locale)
+                                {
+                                    agent_snapshot.localize(&self.locale_dictionary, locale);
+                                }
+                                self.send_client_events(
+                                    id,
+                                    vec![BrokerEvent::BroadCastAgentSnapshot {
+                                        client_id: Some(id),
+                                        agent_snapshot,
+                                    }],
+                                );
+                            }
+                            // 상담직원/호/팀/스킬그룹 상태 전체 전송
+                            Ok(ClientCommand::EXPORT_STATE) => {
+                                let mut snapshot = self.state_snapshot();
+                                snapshot.mask_fields(&self.field_masker);
+                                log::info!(
+                                    "Admin requested state export. id: {}, agents: {}, calls: {}, teams: {}",
+                                    id,
+                                    snapshot.agent_infos.len(),
+                                    snapshot.call_infos.len(),
+                                    snapshot.team_infos.len()
+                                );
+                                self.send_client_events(
+                                    id,
+                                    vec![BrokerEvent::BroadCastStateSnapshot {
+                                        client_id: Some(id),
+                                        snapshot,
+                                    }],
+                                );
+                            }
+                            // 설정 재적용
+                            Ok(ClientCommand::RELOAD_CONFIG) => {
+                                log::info!("Admin requested configuration reload. id: {}", id);
+                                self.reload_config();
+                            }
+                            // CTI 세션 모니터의 호 이벤트 마스크 변경
+                            Ok(ClientCommand::CHANGE_MONITOR_MASK {
+                                cti_instance,
+                                call_msg_mask,
+                            }) => match call_msg_mask.parse::<CallMessageMask>() {
+                                Ok(call_msg_mask) => {
+                                    log::info!(
+                                        "Admin requested monitor mask change. id: {}, cti_instance: {}",
+                                        id,
+                                        cti_instance
+                                    );
+                                    self.change_monitor_mask(&cti_instance, call_msg_mask);
+                                }
+                                Err(e) => log::warn!(
+                                    "Invalid call message mask. id: {}, call_msg_mask: {}, error: {}",
+                                    id,
+                                    call_msg_mask,
+                                    e
+                                ),
+                            },
+                            // 복호화한 CTI 메시지 전체 수신 시작
+                            Ok(ClientCommand::SUBSCRIBE_FIREHOSE) => {
+                                log::info!("Client subscribed to firehose. id: {}", id);
+                                self.firehose_client_ids.insert(id);
+                            }
+                            // 복호화한 CTI 메시지 전체 수신 중단
+                            Ok(ClientCommand::UNSUBSCRIBE_FIREHOSE) => {
+                                log::info!("Client unsubscribed from firehose. id: {}", id);
+                                self.firehose_client_ids.remove(&id);
+                            }
+                            Err(e) => {
+                                log::warn!("Invalid client command. id: {}, error: {}", id, e);
+                            }
+                        }
+                    }
+                    ClientEvent::Lagged { id, skipped } => {
+                        // 놓친 변경분 대신 전체 상태를 다시 전송한다
+                        log::warn!(
+                            "Client lagged behind broker events, resyncing. id: {}, skipped: {}",
+                            id,
+                            skipped
+                        );
+                        self.channel_metrics.record_lagged(skipped);
+                        self.broadcast_client_state(id);
+                        self.channel_metrics.record_resync();
+                    }
+                    ClientEvent::Disconnect { id } => {
+                        self.client_subscription_map.remove(&id);
+                        self.client_info_map.remove(&id);
+                        self.direct_event_tx_map.remove(&id);
+                        self.firehose_client_ids.remove(&id);
+                        if let Some(full_state_rebroadcast) = self.full_state_rebroadcast.as_mut() {
+                            full_state_rebroadcast.remove_client(&id);
+                        }
+                        Self::notify_system_event(
+                            &self.subscribers,
+                            &SystemEvent::ClientDisconnected { id },
+                        );
+                    }
+                },
+                Some(()) = reload_rx.recv() => self.reload_config(),
+                Some(ha_event) = ha_event_rx.recv() => self.handle_ha_event(ha_event).await?,
+                // 동기화 원본 노드의 상태 변경 수신
+                Some(message) = peer_sync_rx.recv() => self.handle_peer_sync(message),
+                _ = housekeeping_interval.tick() => {}
+                _ = self.shutdown_controller.wait() => break,
+            }
+
+            // 스킬그룹 집계 주기 전송
+            if self.last_skill_group_broadcast.elapsed() >= self.skill_group_broadcast_interval {
+                self.last_skill_group_broadcast = Instant::now();
+
+                for (cti_instance, state) in self.cti_instance_state_map.iter_mut() {
+                    // 로그아웃 후 대기 시간이 지난 상담직원 제거
+                    if let Some(agent_logout_eviction) = self.agent_logout_eviction {
+                        let agent_logout_map = &mut state.agent_logout_map;
+                        agent_logout_map.retain(|agent_id, _| {
+                            state
+                                .agent_info_map
+                                .get(agent_id)
+                                .is_some_and(|agent_info| agent_info.get_agent_state() == 1)
+                        });
+                        state
+                            .agent_info_map
+                            .values()
+                            .filter(|agent_info| agent_info.get_agent_state() == 1)
+                            .for_each(|agent_info| {
+                                agent_logout_map
+                                    .entry(agent_info.get_agent_id().to_string())
+                                    .or_insert_with(Instant::now);
+                            });
+
+                        let expired_agent_ids = agent_logout_map
+                            .iter()
+                            .filter(|(_, logout_time)| {
+                                logout_time.elapsed() >= agent_logout_eviction
+                            })
+                            .map(|(agent_id, _)| agent_id.clone())
+                            .collect::<Vec<_>>();
+
+                        for agent_id in expired_agent_ids {
+                            if Self::evict_agent(
+                                &agent_id,
+                                &mut state.agent_info_map,
+                                &mut state.agent_device_index,
+                                &mut state.skill_group_agent_map,
+                                &mut state.agent_state_history_map,
+                                &mut state.agent_statistics_map,
+                                &mut state.agent_logout_map,
+                            ) {
+                                let agent_removal = AgentRemoval::new(
+                                    cti_instance.clone(),
+                                    agent_id,
+                                    AgentRemovalReason::LOGOUT_TIMEOUT,
+                                );
+                                Self::notify_agent_removed(&self.subscribers, &agent_removal);
+                                Self::broadcast_agent_removal(
+                                    None,
+                                    self.broker_event_channel_tx.clone(),
+                                    &self.client_subscription_map,
+                                    &mut self.agent_delta_tracker,
+                                    agent_removal,
+                                );
+                            }
+                        }
+                    }
+
+                    // 자정이 지나면 상담직원 일별 통계를 초기화하여 전송한다
+                    let now = Local::now().naive_local();
+                    state
+                        .agent_statistics_map
+                        .values_mut()
+                        .filter_map(|agent_statistics| {
+                            agent_statistics
+                                .roll_over(now)
+                                .then(|| agent_statistics.snapshot(now))
+                        })
+                        .for_each(|agent_statistics| {
+                            Self::broadcast_agent_statistics(
+                                None,
+                                self.broker_event_channel_tx.clone(),
+                                &self.client_subscription_map,
+                                state.agent_info_map.get(agent_statistics.get_agent_id()),
+                                agent_statistics,
+                            );
+                        });
+
+                    Self::broadcast_skill_group_infos(
+                        None,
+                        self.broker_event_channel_tx.clone(),
+                        &self.client_subscription_map,
+                        cti_instance,
+                        state,
+                    );
+                }
+            }
+
+            // 응답 대기 시간이 지난 클라이언트 조회 요청은 상담직원 정보 없이 응답한다
+            for (cti_instance, state) in self.cti_instance_state_map.iter_mut() {
+                let agent_query_timeout = self.agent_query_timeout;
+                let broker_event_channel_tx = &self.broker_event_channel_tx;
+
+                // 재동기화 응답을 기다리는 시간도 조회 제한 시간을 따른다
+                if state.resync_started_at.is_some_and(|resync_started_at| {
+                    resync_started_at.elapsed() >= agent_query_timeout
+                }) {
+                    Self::finish_cti_resync(cti_instance, state, broker_event_channel_tx);
+                }
+
+                state
+                    .pending_agent_query_map
+                    .retain(|invoke_id, pending_agent_query| {
+                        if pending_agent_query.requested_at.elapsed() < agent_query_timeout {
+                            return true;
+                        }
+
+                        log::warn!(
+                            "Agent query timed out. cti_instance: {}, invoke_id: {}, agent_id: {}",
+                            cti_instance,
+                            invoke_id,
+                            pending_agent_query.agent_id
+                        );
+                        broker_event_channel_tx
+                            .send(BrokerEvent::BroadCastAgentQueryResult {
+                                client_id: Some(pending_agent_query.client_id),
+                                agent_query_result: AgentQueryResult::new(
+                                    pending_agent_query.request_id.clone(),
+                                    cti_instance.clone(),
+                                    pending_agent_query.agent_id.clone(),
+                                    None,
+                                ),
+                            })
+                            .unwrap();
+                        false
+                    });
+            }
+
+            // ALL_EVENTS 세션 모드의 호 상태 일괄 전송
+            let now = Instant::now();
+            for (cti_instance, state) in self.cti_instance_state_map.iter_mut() {
+                let Some(call_broadcast_batch) = state
+                    .call_broadcast_batch
+                    .as_mut()
+                    .filter(|call_broadcast_batch| call_broadcast_batch.is_due(now))
+                else {
+                    continue;
+                };
+
+                let call_infos = call_broadcast_batch.take(now);
+                log::debug!(
+                    "Broadcasting call state batch. cti_instance: {}, calls: {}",
+                    cti_instance,
+                    call_infos.len()
+                );
+                call_infos.into_iter().for_each(|call_info| {
+                    Self::broadcast_call_info(
+                        None,
+                        self.broker_event_channel_tx.clone(),
+                        &self.client_subscription_map,
+                        &self.field_masker,
+                        Self::call_department_id(&state.agent_info_map, &call_info),
+                        call_info,
+                    );
+                });
+            }
+
+            // 상황판 요약 지표 주기 전송
+            if self.last_wallboard_broadcast.elapsed() >= self.wallboard_broadcast_interval {
+                self.last_wallboard_broadcast = Instant::now();
+
+                Self::broadcast_wallboard_summary(
+                    None,
+                    self.broker_event_channel_tx.clone(),
+                    &self.client_subscription_map,
+                    &self.cti_instance_state_map,
+                );
+            }
+
+            // CTI 서버 연결 상태 주기 전송
+            if self
+                .config
+                .ctm
+                .cti_link_status_interval
+                .is_some_and(|cti_link_status_interval| {
+                    self.last_cti_link_status_broadcast.elapsed() >= cti_link_status_interval
+                })
+            {
+                self.last_cti_link_status_broadcast = Instant::now();
+                self.broadcast_cti_link_status();
+            }
+
+            // 전체 상태 주기 재전송 (변경분을 놓친 클라이언트도 결국 같은 상태가 되도록 한다)
+            if let Some(full_state_rebroadcast) = self.full_state_rebroadcast.as_mut() {
+                let due_client_ids = full_state_rebroadcast.due_clients(Instant::now());
+                if !due_client_ids.is_empty() {
+                    log::debug!(
+                        "Rebroadcasting full state. clients: {}",
+                        due_client_ids.len()
+                    );
+                }
+                due_client_ids
+                    .into_iter()
+                    .for_each(|id| self.broadcast_client_state(id));
+            }
+
+            // 상태 스냅샷 주기 저장
+            if self.snapshot_file.is_some()
+                && self.last_snapshot_save.elapsed() >= self.snapshot_interval
+            {
+                self.last_snapshot_save = Instant::now();
+                self.save_snapshot();
+            }
+
+            // HA 대기 노드에 상태 복제
+            if self.ha_role == HaRole::ACTIVE
+                && self.last_ha_mirror.elapsed() >= self.ha_mirror_interval
+            {
+                self.last_ha_mirror = Instant::now();
+                if let Some(ha_mirror_tx) = self.ha_mirror_tx.as_ref() {
+                    let _ = ha_mirror_tx.try_send(self.state_snapshot());
+                }
+            }
+
+            // 복제 노드에 전체 상태 주기 전송 (복제받는 노드는 받은 전체 상태를 그대로 전달한다)
+            if !self.is_peer_sync_follower()
+                && self.last_peer_sync_snapshot.elapsed() >= self.peer_sync_snapshot_interval
+            {
+                self.last_peer_sync_snapshot = Instant::now();
+                if let Some(peer_sync_publisher) = self.peer_sync_publisher.as_ref() {
+                    peer_sync_publisher.publish_snapshot(self.state_snapshot());
+                }
+            }
+
+            // 이벤트 루프가 멈추면 watchdog 신호가 끊겨 systemd 가 서비스를 재시작한다
+            self.systemd_notifier.watchdog();
+        }
+
+        self.shutdown(acceptor_handles).await;
+
+        Ok(())
+    }
+
+    ///
+    /// HA 노드 이벤트 처리
+    ///
+    /// 활성 노드로 전환되면 CTI 서버에 접속하고, 복제받은 상담직원 상태는 OPEN_CONF 후 재동기화한다.
+    /// 대기 노드로 전환되면 CTI 세션을 닫고 활성 노드의 상태를 복제받는다.
+    ///
+    async fn handle_ha_event(&mut self, ha_event: HaEvent) -> Result<(), Box<dyn Error>> {
+        match ha_event {
+            HaEvent::RoleChanged { role, peer_node_id } => {
+                self.ha_role = role;
+                self.ha_peer_node_id = peer_node_id;
+
+                match role {
+                    HaRole::ACTIVE => {
+                        self.cti_session_controller = ShutdownController::new();
+                        self.cti_instance_state_map
+                            .values_mut()
+                            .filter(|state| !state.agent_info_map.is_empty())
+                            .for_each(|state| state.is_opened = true);
+                        self.connect_cti_clients().await?;
+                    }
+                    HaRole::STANDBY => {
+                        self.cti_session_controller.shutdown();
+                        self.cti_instance_state_map.values_mut().for_each(|state| {
+                            state.is_connected = false;
+                            state.session = None;
+                        });
+                        let _ = self
+                            .broker_event_channel_tx
+                            .send(BrokerEvent::CloseCtiSession);
+                    }
+                }
+
+                self.systemd_notifier.status(&format!(
+                    "HA role: {:?}, node_id: {:?}",
+                    role, self.ha_node_id
+                ));
+                Self::notify_system_event(
+                    &self.subscribers,
+                    &SystemEvent::HaRoleChanged {
+                        node_id: self.ha_node_id.clone().unwrap_or_default(),
+                        role,
+                    },
+                );
+
+                // 전체 상태에 역할 안내가 포함되며, 대기 중 복제받은 상태로 클라이언트 상태를 맞춘다
+                match role {
+                    HaRole::ACTIVE => self
+                        .client_info_map
+                        .keys()
+                        .for_each(|id| self.broadcast_client_state(*id)),
+                    HaRole::STANDBY => {
+                        if let Some(ha_status) = self.ha_status() {
+                            let _ =
+                                self.broker_event_channel_tx
+                                    .send(BrokerEvent::BroadCastHaStatus {
+                                        client_id: None,
+                                        ha_status,
+                                    });
+                        }
+                    }
+                }
+            }
+            HaEvent::Mirror(snapshot) => {
+                if self.ha_role != HaRole::STANDBY {
+                    return Ok(());
+                }
+                log::debug!(
+                    "Received HA state mirror. agents: {}, calls: {}, teams: {}",
+                    snapshot.agent_infos.len(),
+                    snapshot.call_infos.len(),
+                    snapshot.team_infos.len()
+                );
+                self.replace_state(snapshot);
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// 동기화 원본 노드에서 받은 상태 변경 처리
+    ///
+    /// HA 대기 노드와 읽기 전용 복제 노드에서만 반영하며, 접속한 클라이언트와
+    /// 구독자(이 노드에 접속한 복제 노드 포함)에게 CTI 이벤트를 받은 것처럼 전달한다.
+    ///
+    fn handle_peer_sync(&mut self, message: PeerSyncMessage) {
+        if !self.is_peer_sync_follower() {
+            return;
+        }
+
+        match message {
+            PeerSyncMessage::Snapshot(snapshot) => {
+                log::debug!(
+                    "Received peer sync snapshot. agents: {}, calls: {}, teams: {}",
+                    snapshot.agent_infos.len(),
+                    snapshot.call_infos.len(),
+                    snapshot.team_infos.len()
+                );
+                if let Some(peer_sync_publisher) = self.peer_sync_publisher.as_ref() {
+                    peer_sync_publisher.publish_snapshot(snapshot.clone());
+                }
+                self.replace_state(snapshot);
+                self.client_info_map
+                    .keys()
+                    .for_each(|id| self.broadcast_client_state(*id));
+            }
+            PeerSyncMessage::AgentState(agent_info) => {
+                let state = self
+                    .cti_instance_state_map
+                    .entry(agent_info.get_cti_instance().to_string())
+                    .or_default();
+                state.agent_device_index.update(&agent_info);
+                state
+                    .agent_info_map
+                    .insert(agent_info.get_agent_id().to_string(), agent_info.clone());
+
+                Self::publish_agent_info(
+                    self.broker_event_channel_tx.clone(),
+                    &self.client_subscription_map,
+                    &self.field_masker,
+                    &self.locale_dictionary,
+                    &mut self.agent_delta_tracker,
+                    self.agent_delta_broadcast,
+                    agent_info.clone(),
+                );
+                Self::notify_agent_state(&self.subscribers, &agent_info);
+            }
+            PeerSyncMessage::AgentRemoved(agent_removal) => {
+                let state = self
+                    .cti_instance_state_map
+                    .entry(agent_removal.get_cti_instance().to_string())
+                    .or_default();
+                if Self::evict_agent(
+                    agent_removal.get_agent_id(),
+                    &mut state.agent_info_map,
+                    &mut state.agent_device_index,
+                    &mut state.skill_group_agent_map,
+                    &mut state.agent_state_history_map,
+                    &mut state.agent_statistics_map,
+                    &mut state.agent_logout_map,
+                ) {
+                    Self::notify_agent_removed(&self.subscribers, &agent_removal);
+                    Self::broadcast_agent_removal(
+                        None,
+                        self.broker_event_channel_tx.clone(),
+                        &self.client_subscription_map,
+                        &mut self.agent_delta_tracker,
+                        agent_removal,
+                    );
+                }
+            }
+            PeerSyncMessage::CallState(call_info) => {
+                let state = self
+                    .cti_instance_state_map
+                    .entry(call_info.get_cti_instance().to_string())
+                    .or_default();
+                match call_info.get_call_state() {
+                    CallState::CLEARED => {
+                        state
+                            .call_info_map
+                            .remove(&call_info.get_connection_call_id());
+                    }
+                    _ => {
+                        state
+                            .call_info_map
+                            .insert(call_info.get_connection_call_id(), call_info.clone());
+                    }
+                }
+
+                Self::broadcast_call_info(
+                    None,
+                    self.broker_event_channel_tx.clone(),
+                    &self.client_subscription_map,
+                    &self.field_masker,
+                    Self::call_department_id(&state.agent_info_map, &call_info),
+                    call_info.clone(),
+                );
+                Self::notify_call_state(&self.subscribers, &call_info);
+            }
+        }
+    }
+
+    ///
+    /// 다른 노드의 상태를 복제받는 중인지 여부 (HA 대기 노드, 읽기 전용 복제 노드)
+    ///
+    fn is_peer_sync_follower(&self) -> bool {
+        self.peer_sync_read_only || self.ha_role == HaRole::STANDBY
+    }
+
+    ///
+    /// 상담직원/호/팀/스킬그룹 상태를 복제받은 전체 상태로 교체한다
+    ///
+    /// 마지막 전송 상태도 함께 맞추므로 클라이언트에는 전송하지 않는다.
+    ///
+    fn replace_state(&mut self, snapshot: CtmSnapshot) {
+        self.cti_instance_state_map.values_mut().for_each(|state| {
+            state.agent_info_map.clear();
+            state.agent_device_index.clear();
+            state.call_info_map.clear();
+            state.team_info_map.clear();
+            state.skill_group_agent_map.clear();
+        });
+        Self::apply_snapshot(&mut self.cti_instance_state_map, snapshot);
+
+        // 복제 후 없어진 상담직원은 마지막 전송 상태에서도 제거한다
+        for agent_info in self.agent_delta_tracker.snapshot().get_agent_infos() {
+            let is_mirrored = self
+                .cti_instance_state_map
+                .get(agent_info.get_cti_instance())
+                .is_some_and(|state| state.agent_info_map.contains_key(agent_info.get_agent_id()));
+            if !is_mirrored {
+                self.agent_delta_tracker
+                    .remove(agent_info.get_cti_instance(), agent_info.get_agent_id());
+            }
+        }
+        Self::track_agent_infos(
+            &mut self.agent_delta_tracker,
+            &self.cti_instance_state_map,
+            &self.field_masker,
+        );
+    }
+
+    ///
+    /// 모든 CTI 서버 인스턴스에 접속한다 (HA 활성 노드로 전환)
+    ///
+    async fn connect_cti_clients(&mut self) -> Result<(), Box<dyn Error>> {
+        for cti_client_config in self.config.cti_clients.clone() {
+            let cti_instance = cti_client_config.cti_instance.clone();
+            let state = self
+                .cti_instance_state_map
+                .entry(cti_instance.clone())
+                .or_insert_with(|| CtiInstanceState {
+                    is_active: true,
+                    reconnect_policy: cti_client_config.reconnect_policy.clone(),
+                    call_broadcast_batch: Self::call_broadcast_batch(&self.config, &cti_instance),
+                    ..Default::default()
+                });
+            state.reconnect_state.reset();
+
+            let cti_client = CTIClient::new(
+                cti_client_config,
+                state.is_active,
+                self.invoke_id_generator.clone(),
+                self.cti_event_channel_tx.clone(),
+                self.broker_event_channel_rx.resubscribe(),
+            )
+            .await?;
+            self.cti_client_running_map
+                .insert(cti_instance, cti_client.get_is_running());
+            tokio::spawn(cti_client.connect());
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// HA 역할 안내 (HA 를 사용하지 않으면 None)
+    ///
+    fn ha_status(&self) -> Option<HaStatus> {
+        self.ha_node_id.as_ref().map(|node_id| {
+            HaStatus::new(node_id.clone(), self.ha_role, self.ha_peer_node_id.clone())
+        })
+    }
+
+    ///
+    /// 실행 중 바꿀 수 있는 설정을 다시 읽어 적용한다
+    ///
+    /// 로그 레벨, 이석 사유코드 사전, 신규 클라이언트 기본 구독 조건을 갱신하고
+    /// Acceptor 에 접속 허용 IP 목록과 TLS 인증서를 다시 읽도록 알린다.
+    /// 다시 읽은 설정에 잘못된 값이 있으면 아무것도 바꾸지 않는다.
+    /// CTI 세션과 접속 중인 클라이언트 연결은 유지된다.
+    ///
+    fn reload_config(&mut self) {
+        self.systemd_notifier.reloading();
+        if let Err(e) = reload_config_file() {
+            log::error!("Unable to reload configuration file. {}", e);
+        }
+        let config = match Config::load() {
+            Ok(config) => Arc::new(config),
+            Err(e) => {
+                log::error!("Unable to reload configuration. {}", e);
+                self.systemd_notifier.reloaded();
+                return;
+            }
+        };
+        if let Err(e) = reload_logging(&config.logging) {
+            log::error!("Unable to reload logging configuration. {}", e);
+        }
+
+        self.reason_code_dictionary = ReasonCodeDictionary::load(&config.ctm.reason_code_file);
+        self.locale_dictionary = LocaleDictionary::load(&config.ctm.locale_file);
+        self.default_client_subscription = config.ctm.default_client_subscription.clone();
+        let _ = self
+            .broker_event_channel_tx
+            .send(BrokerEvent::ReloadConfig { config });
+
+        log::info!(
+            "Reloaded configuration. default_client_subscription: {:?}",
+            self.default_client_subscription
+        );
+        self.systemd_notifier.reloaded();
+    }
+
+    ///
+    /// 설정된 모든 CTI 세션이 열리면 systemd 에 준비 완료를 알린다
+    ///
+    /// 이미 준비 완료를 알린 뒤에는 재접속 완료를 상태 문구로만 알린다.
+    ///
+    fn notify_cti_opened(&mut self) {
+        let opened = self
+            .config
+            .cti_clients
+            .iter()
+            .filter(|cti_client| {
+                self.cti_instance_state_map
+                    .get(&cti_client.cti_instance)
+                    .is_some_and(|state| state.is_opened)
+            })
+            .count();
+        if opened < self.config.cti_clients.len() {
+            return;
+        }
+
+        let status = format!("CTI sessions opened. cti_instances: {}", opened);
+        match self.systemd_notifier.is_ready() {
+            true => self.systemd_notifier.status(&status),
+            false => self.systemd_notifier.ready(&status),
+        }
+    }
+
+    ///
+    /// 종료 절차 실행
+    ///
+    /// 종료 이벤트를 전송한 뒤 Acceptor 와 CTI 세션이 끝나기를 SHUTDOWN_TIMEOUT 동안 기다리고,
+    /// 마지막 상태 스냅샷을 저장한다.
+    ///
+    async fn shutdown(&mut self, acceptor_handles: Vec<JoinHandle<()>>) {
+        self.systemd_notifier.stopping();
+        log::info!(
+            "Shutting down. clients: {}, acceptors: {}",
+            self.client_info_map.len(),
+            acceptor_handles.len()
+        );
+        let _ = self.broker_event_channel_tx.send(BrokerEvent::Shutdown);
+
+        let deadline = Instant::now() + self.shutdown_timeout;
+        loop {
+            // 채널이 가득 차 클라이언트, CTI 세션 종료가 막히지 않도록 남은 이벤트를 버린다
+            while self.client_event_channel_rx.try_recv().is_ok() {}
+            while self.cti_event_channel_rx.try_recv().is_ok() {}
+
+            let acceptors_stopped = acceptor_handles.iter().all(JoinHandle::is_finished);
+            let cti_sessions_closed = self
+                .cti_client_running_map
+                .values()
+                .all(|is_running| !is_running.load(Ordering::Acquire));
+            if acceptors_stopped && cti_sessions_closed {
+                break;
+            }
+            if Instant::now() >= deadline {
+                log::warn!(
+                    "Shutdown timed out. acceptors_stopped: {}, cti_sessions_closed: {}",
+                    acceptors_stopped,
+                    cti_sessions_closed
+                );
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        self.save_snapshot();
+        log::info!("Shutdown completed");
+    }
+
+    ///
+    /// 상태 스냅샷 저장
+    ///
+    fn save_snapshot(&self) {
+        let Some(snapshot_file) = self.snapshot_file.as_ref() else {
+            return;
+        };
+
+        match self.state_snapshot().save(snapshot_file) {
+            Ok(_) => {
+                log::debug!("Saved state snapshot. snapshot_file: {}", snapshot_file)
+            }
+            Err(e) => log::error!(
+                "Unable to save state snapshot. snapshot_file: {}, error: {}",
+                snapshot_file,
+                e
+            ),
+        }
+    }
+
+    ///
+    /// 현재 상담직원/호/팀 상태 스냅샷
+    ///
+    fn state_snapshot(&self) -> CtmSnapshot {
+        let states = self.cti_instance_state_map.values();
+        CtmSnapshot {
+            agent_infos: states
+                .clone()
+                .flat_map(|state| state.agent_info_map.values().cloned())
+                .collect(),
+            call_infos: states
+                .clone()
+                .flat_map(|state| state.call_info_map.values().cloned())
+                .collect(),
+            team_infos: states
+                .flat_map(|state| state.team_info_map.values().cloned())
+                .collect(),
+            skill_group_agent_map: self
+                .cti_instance_state_map
+                .iter()
+                .map(|(cti_instance, state)| {
+                    (cti_instance.clone(), state.skill_group_agent_map.clone())
+                })
+                .collect(),
+        }
+    }
+
+    ///
+    /// 스냅샷의 상담직원/호/팀 상태를 CTI 서버 인스턴스별 상태에 등록한다
+    ///
+    fn apply_snapshot(
+        cti_instance_state_map: &mut HashMap<String, CtiInstanceState>,
+        snapshot: CtmSnapshot,
+    ) {
+        snapshot.agent_infos.into_iter().for_each(|agent_info| {
+            let state = cti_instance_state_map
+                .entry(agent_info.get_cti_instance().to_string())
+                .or_default();
+            state.agent_device_index.update(&agent_info);
+            state
+                .agent_info_map
+                .insert(agent_info.get_agent_id().to_string(), agent_info);
+        });
+        snapshot.call_infos.into_iter().for_each(|call_info| {
+            cti_instance_state_map
+                .entry(call_info.get_cti_instance().to_string())
+                .or_default()
+                .call_info_map
+                .insert(call_info.get_connection_call_id(), call_info);
+        });
+        snapshot.team_infos.into_iter().for_each(|team_info| {
+            cti_instance_state_map
+                .entry(team_info.get_cti_instance().to_string())
+                .or_default()
+                .team_info_map
+                .insert(team_info.get_team_id(), team_info);
+        });
+        snapshot.skill_group_agent_map.into_iter().for_each(
+            |(cti_instance, skill_group_agent_map)| {
+                cti_instance_state_map
+                    .entry(cti_instance)
+                    .or_default()
+                    .skill_group_agent_map = skill_group_agent_map;
+            },
+        );
+    }
+
+    ///
+    /// 상담직원 상태를 마스킹하여 마지막 전송 상태로 등록한다
+    ///
+    fn track_agent_infos(
+        agent_delta_tracker: &mut AgentDeltaTracker,
+        cti_instance_state_map: &HashMap<String, CtiInstanceState>,
+        field_masker: &FieldMasker,
+    ) {
+        cti_instance_state_map
+            .values()
+            .flat_map(|state| state.agent_info_map.values())
+            .for_each(|agent_info| {
+                let mut agent_info = agent_info.clone();
+                agent_info.mask_fields(field_masker);
+                agent_delta_tracker.track(&agent_info);
+            });
+    }
+
+    ///
+    /// 저널 레코드를 CTI 이벤트 채널로 재생한다
+    ///
+    /// 레코드 사이의 시간 간격을 JOURNAL_REPLAY_SPEED 배속으로 유지하며, 0 이면 지연 없이 재생한다.
+    ///
+    async fn replay_journal(
+        records: Vec<JournalRecord>,
+        replay_speed: f64,
+        cti_event_channel_tx: mpsc::Sender<CTIEvent>,
+    ) {
+        let mut last_timestamp: Option<i64> = None;
+        for record in records {
+            if let Some(last_timestamp) = last_timestamp {
+                let gap = (record.timestamp - last_timestamp).max(0) as f64;
+                if replay_speed > 0.0 {
+                    tokio::time::sleep(Duration::from_millis((gap / replay_speed) as u64)).await;
+                }
+            }
+            last_timestamp = Some(record.timestamp);
+
+            let message_type = record.get_message_type();
+            if let Err(e) = cti_event_channel_tx
+                .send(CTIEvent::Recevied {
+                    span: tracing::info_span!(
+                        "journal.replay",
+                        cti_instance = %record.cti_instance,
+                        message_type = ?message_type,
+                    ),
+                    cti_instance: record.cti_instance,
+                    cti_server_host: record.cti_server_host,
+                    message_type,
+                    data: record.data,
+                })
+                .await
+            {
+                log::error!("Unable to replay journal record. {}", e);
+                return;
+            }
+        }
+
+        log::info!("Finished replaying journal.");
+    }
+
+    ///
+    /// 상담직원과 관련된 상태를 모두 제거한다
+    ///
+    /// 제거할 상담직원이 없으면 false 를 반환한다.
+    ///
+    fn evict_agent(
+        agent_id: &str,
+        agent_info_map: &mut HashMap<String, AgentInfo>,
+        agent_device_index: &mut AgentDeviceIndex,
+        skill_group_agent_map: &mut HashMap<u32, HashMap<String, u16>>,
+        agent_state_history_map: &mut HashMap<String, AgentStateHistory>,
+        agent_statistics_map: &mut HashMap<String, AgentStatistics>,
+        agent_logout_map: &mut HashMap<String, Instant>,
+    ) -> bool {
+        if agent_info_map.remove(agent_id).is_none() {
+            return false;
+        }
+
+        skill_group_agent_map
+            .values_mut()
+            .for_each(|agent_state_map| {
+                agent_state_map.remove(agent_id);
+            });
+        agent_device_index.remove(agent_id);
+        agent_state_history_map.remove(agent_id);
+        agent_statistics_map.remove(agent_id);
+        agent_logout_map.remove(agent_id);
+
+        true
+    }
+
+    ///
+    /// 상담직원 제거를 브로커 채널에 전송한다
+    ///
+    fn broadcast_agent_removal(
+        target_client_id: Option<Uuid>,
+        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        agent_delta_tracker: &mut AgentDeltaTracker,
+        agent_removal: AgentRemoval,
+    ) {
+        let removed_agent_info = agent_delta_tracker.remove_agent(&agent_removal);
+        let team_id = removed_agent_info.as_ref().map(AgentInfo::get_team_id);
+        let department_id = removed_agent_info
+            .as_ref()
+            .map(AgentInfo::get_department_id);
+
+        log::info!(
+            "Broadcasted agent removal event. agent_removal: {:?}",
+            agent_removal
+        );
+        Self::dispatch_broker_event(
+            target_client_id,
+            &broker_event_channel_tx,
+            client_subscription_map,
+            |client_subscription| {
+                client_subscription.matches_department(department_id)
+                    && client_subscription.matches_agent(agent_removal.get_agent_id(), team_id)
+            },
+            |client_id| BrokerEvent::BroadCastAgentRemoved {
+                agent_removal: agent_removal.clone(),
+                client_id,
+            },
+        );
+    }
+
+    ///
+    /// 상담직원 상태를 브로커 채널에 전송한다
+    ///
+    /// 표시 이름 언어를 지정한 클라이언트에는 언어별로 표시 이름을 바꾼 상태를 전송한다.
+    ///
+    fn broadcast_agent_info(
+        target_client_id: Option<Uuid>,
+        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        locale_dictionary: &LocaleDictionary,
+        agent_info: AgentInfo,
+    ) {
+        // 구독 조건별로 여러 이벤트를 보내도 데이터와 직렬화 결과는 언어별로 공유한다
+        let localized_agent_infos = client_subscription_map
+            .values()
+            .filter_map(ClientSubscription::get_locale)
+            .map(|locale| {
+                let mut agent_info = agent_info.clone();
+                agent_info.localize(locale_dictionary, locale);
+                (locale, SharedPayload::new(agent_info))
+            })
+            .collect::<HashMap<_, _>>();
+        let agent_info = SharedPayload::new(agent_info);
+        Self::dispatch_localized_broker_event(
+            target_client_id,
+            &broker_event_channel_tx,
+            client_subscription_map,
+            |client_subscription| {
+                client_subscription.matches_department(Some(agent_info.get_department_id()))
+                    && client_subscription
+                        .matches_agent(agent_info.get_agent_id(), Some(agent_info.get_team_id()))
+            },
+            |client_id, locale| BrokerEvent::BroadCastAgentState {
+                agent_info: locale
+                    .and_then(|locale| localized_agent_infos.get(locale))
+                    .unwrap_or(&agent_info)
+                    .clone(),
+                client_id,
+            },
+        );
+        log::debug!("Broadcasted agent info event. agent_info: {:?}", agent_info);
+    }
+
+    ///
+    /// 상담직원 상태 변경을 브로커 채널에 전송한다
+    ///
+    /// 마지막으로 전송한 상태와 같으면 전송하지 않는다. 변경분 전송을 사용하면 달라진 필드만
+    /// 일련번호와 함께 전송하고, 그렇지 않으면 전체 상태를 전송한다. 개인정보 필드는 마스킹 후
+    /// 비교, 전송한다.
+    ///
+    fn publish_agent_info(
+        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        field_masker: &FieldMasker,
+        locale_dictionary: &LocaleDictionary,
+        agent_delta_tracker: &mut AgentDeltaTracker,
+        agent_delta_broadcast: bool,
+        mut agent_info: AgentInfo,
+    ) {
+        agent_info.mask_fields(field_masker);
+
+        let agent_info_delta = match agent_delta_tracker.track(&agent_info) {
+            Some(agent_info_delta) => agent_info_delta,
+            None => {
+                log::debug!(
+                    "Suppressed unchanged agent info event. agent_id: {}",
+                    agent_info.get_agent_id()
+                );
+                return;
+            }
+        };
+
+        if !agent_delta_broadcast {
+            return Self::broadcast_agent_info(
+                None,
+                broker_event_channel_tx,
+                client_subscription_map,
+                locale_dictionary,
+                agent_info,
+            );
+        }
+
+        log::debug!(
+            "Broadcasted agent delta event. agent_info_delta: {:?}",
+            agent_info_delta
+        );
+        Self::dispatch_localized_broker_event(
+            None,
+            &broker_event_channel_tx,
+            client_subscription_map,
+            |client_subscription| {
+                client_subscription.matches_department(Some(agent_info.get_department_id()))
+                    && client_subscription
+                        .matches_agent(agent_info.get_agent_id(), Some(agent_info.get_team_id()))
+            },
+            |client_id, locale| {
+                let mut agent_info_delta = agent_info_delta.clone();
+                if let Some(locale) = locale {
+                    agent_info_delta.localize(locale_dictionary, locale);
+                }
+                BrokerEvent::BroadCastAgentDelta {
+                    agent_info_delta,
+                    client_id,
+                }
+            },
+        );
+    }
+
+    ///
+    /// 클라이언트에 보낼 상담직원 전체 상태 이벤트
+    ///
+    /// 구독 조건에 맞는 상담직원만 포함하며, 변경분 전송을 사용하면 일련번호가 있는 전체 상태 하나를,
+    /// 그렇지 않으면 상담직원별 상태를 반환한다. 표시 이름은 클라이언트 언어에 맞게 바꾼다.
+    ///
+    fn agent_snapshot_events(
+        id: Uuid,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        locale_dictionary: &LocaleDictionary,
+        agent_delta_tracker: &AgentDeltaTracker,
+        agent_delta_broadcast: bool,
+    ) -> Vec<BrokerEvent> {
+        let mut agent_snapshot = agent_delta_tracker.snapshot();
+        if let Some(client_subscription) = client_subscription_map.get(&id) {
+            agent_snapshot.retain(|agent_info| {
+                client_subscription.matches_department(Some(agent_info.get_department_id()))
+                    && client_subscription
+                        .matches_agent(agent_info.get_agent_id(), Some(agent_info.get_team_id()))
+            });
+            if let Some(locale) = client_subscription.get_locale() {
+                agent_snapshot.localize(locale_dictionary, locale);
+            }
+        }
+
+        if !agent_delta_broadcast {
+            return agent_snapshot
+                .get_agent_infos()
+                .iter()
+                .map(|agent_info| BrokerEvent::BroadCastAgentState {
+                    client_id: Some(id),
+                    agent_info: agent_info.clone().into(),
+                })
+                .collect();
+        }
+
+        log::debug!(
+            "Created agent snapshot event. id: {}, sequence: {}",
+            id,
+            agent_snapshot.get_sequence()
+        );
+        vec![BrokerEvent::BroadCastAgentSnapshot {
+            client_id: Some(id),
+            agent_snapshot,
+        }]
+    }
+
+    ///
+    /// 재접속 클라이언트가 놓친 상담직원 변경분, 제거 이벤트
+    ///
+    /// 구독 조건에 맞는 상담직원만 포함하고 표시 이름은 클라이언트 언어에 맞게 바꾼다.
+    /// 보관 중인 변경 기록으로 이어 받을 수 없으면 None 을 반환한다.
+    ///
+    fn agent_replay_events(
+        id: Uuid,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        locale_dictionary: &LocaleDictionary,
+        agent_delta_tracker: &AgentDeltaTracker,
+        resume: &SessionResume,
+    ) -> Option<Vec<BrokerEvent>> {
+        let client_subscription = client_subscription_map.get(&id);
+        let matches_agent = |agent_id: &str, team_id: Option<u32>, department_id: Option<i32>| {
+            client_subscription.is_none_or(|client_subscription| {
+                client_subscription.matches_department(department_id)
+                    && client_subscription.matches_agent(agent_id, team_id)
+            })
+        };
+        let locale = client_subscription.and_then(ClientSubscription::get_locale);
+
+        let agent_delta_records =
+            agent_delta_tracker.replay(resume.get_epoch(), resume.get_sequence())?;
+        Some(
+            agent_delta_records
+                .into_iter()
+                .filter_map(
+                    |agent_delta_replay_entry| match agent_delta_replay_entry.record {
+                        AgentDeltaRecord::Delta(agent_info_delta) => {
+                            if !matches_agent(
+                                agent_info_delta.get_agent_id(),
+                                agent_delta_replay_entry.team_id,
+                                agent_delta_replay_entry.department_id,
+                            ) {
+                                return None;
+                            }
+                            let mut agent_info_delta = agent_info_delta.clone();
+                            if let Some(locale) = locale {
+                                agent_info_delta.localize(locale_dictionary, locale);
+                            }
+                            Some(BrokerEvent::BroadCastAgentDelta {
+                                client_id: Some(id),
+                                agent_info_delta,
+                            })
+                        }
+                        AgentDeltaRecord::Removal(agent_removal) => matches_agent(
+                            agent_removal.get_agent_id(),
+                            agent_delta_replay_entry.team_id,
+                            agent_delta_replay_entry.department_id,
+                        )
+                        .then(|| BrokerEvent::BroadCastAgentRemoved {
+                            client_id: Some(id),
+                            agent_removal: agent_removal.clone(),
+                        }),
+                    },
+                )
+                .collect(),
+        )
+    }
+
+    ///
+    /// 호를 받은 상담직원의 부서 (상담직원을 알 수 없으면 None)
+    ///
+    fn call_department_id(
+        agent_info_map: &HashMap<String, AgentInfo>,
+        call_info: &CallInfo,
+    ) -> Option<i32> {
+        agent_info_map
+            .get(call_info.get_agent_id())
+            .map(AgentInfo::get_department_id)
+    }
+
+    ///
+    /// 호 상태를 브로커 채널에 전송한다
+    ///
+    /// 부서를 지정한 클라이언트에는 같은 부서 상담직원의 호만 전송한다.
+    ///
+    fn broadcast_call_info(
+        target_client_id: Option<Uuid>,
+        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        field_masker: &FieldMasker,
+        department_id: Option<i32>,
+        mut call_info: CallInfo,
+    ) {
+        call_info.mask_fields(field_masker);
+
+        Self::dispatch_broker_event(
+            target_client_id,
+            &broker_event_channel_tx,
+            client_subscription_map,
+            |client_subscription| {
+                client_subscription.matches_department(department_id)
+                    && client_subscription.matches_call(&call_info)
+            },
+            |client_id| BrokerEvent::BroadCastCallState {
+                call_info: call_info.clone(),
+                client_id,
+            },
+        );
+        log::debug!("Broadcasted call info event. call_info: {:?}", call_info);
+    }
+
+    ///
+    /// 바뀐 호 상태를 전송한다
+    ///
+    /// 일괄 전송 버퍼가 있으면(ALL_EVENTS 세션 모드) 버퍼에 모으고 주기 작업에서 보낸다.
+    ///
+    fn publish_call_info(
+        call_broadcast_batch: Option<&mut CallBroadcastBatch>,
+        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        field_masker: &FieldMasker,
+        department_id: Option<i32>,
+        call_info: CallInfo,
+    ) {
+        match call_broadcast_batch {
+            Some(call_broadcast_batch) => call_broadcast_batch.push(call_info),
+            None => Self::broadcast_call_info(
+                None,
+                broker_event_channel_tx,
+                client_subscription_map,
+                field_masker,
+                department_id,
+                call_info,
+            ),
+        }
+    }
+
+    ///
+    /// CTI 서버 인스턴스별 연결 상태를 전송한다
+    ///
+    /// 하트비트 제한 시간(HEALTH_HEARTBEAT_TIMEOUT) 동안 CTI 메시지가 없으면 degraded 로 알린다.
+    ///
+    fn broadcast_cti_link_status(&self) {
+        let now = Instant::now();
+        for cti_client_config in self.config.cti_clients.iter() {
+            let Some(state) = self
+                .cti_instance_state_map
+                .get(&cti_client_config.cti_instance)
+            else {
+                continue;
+            };
+
+            let cti_link_status = state.cti_link.status(
+                cti_client_config.cti_instance.clone(),
+                state.is_connected,
+                state.is_active,
+                self.config.ctm.health_heartbeat_timeout,
+                now,
+            );
+            if cti_link_status.is_degraded() {
+                log::debug!(
+                    "CTI link degraded. cti_instance: {}, connected: {}, seconds_since_last_event: {:?}",
+                    cti_link_status.get_cti_instance(),
+                    cti_link_status.is_connected(),
+                    cti_link_status.get_seconds_since_last_event()
+                );
+            }
+            let _ = self
+                .broker_event_channel_tx
+                .send(BrokerEvent::BroadCastCtiLinkStatus {
+                    client_id: None,
+                    cti_link_status,
+                });
+        }
+    }
+
+    ///
+    /// CTI 서버 인스턴스의 스킬그룹 집계를 부서별로 계산하여 브로커 채널에 전송한다
+    ///
+    fn broadcast_skill_group_infos(
+        target_client_id: Option<Uuid>,
+        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        cti_instance: &str,
+        state: &CtiInstanceState,
+    ) {
+        for department_id in Self::client_department_ids(target_client_id, client_subscription_map)
+        {
+            Self::aggregate_skill_group_infos(cti_instance, state, department_id)
+                .into_iter()
+                .for_each(|skill_group_info| {
+                    Self::dispatch_broker_event(
+                        target_client_id,
+                        &broker_event_channel_tx,
+                        client_subscription_map,
+                        |client_subscription| {
+                            client_subscription.get_department_id() == department_id
+                                && client_subscription
+                                    .matches_skill_group(skill_group_info.get_skill_group_id())
+                        },
+                        |client_id| BrokerEvent::BroadCastSkillGroupState {
+                            skill_group_info: skill_group_info.clone(),
+                            client_id,
+                        },
+                    );
+                    log::debug!(
+                        "Broadcasted skill group info event. department_id: {:?}, skill_group_info: {:?}",
+                        department_id,
+                        skill_group_info
+                    );
+                });
+        }
+    }
+
+    ///
+    /// 전체 CTI 서버 인스턴스의 상황판 요약 지표를 부서별로 계산하여 브로커 채널에 전송한다
+    ///
+    fn broadcast_wallboard_summary(
+        target_client_id: Option<Uuid>,
+        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        cti_instance_state_map: &HashMap<String, CtiInstanceState>,
+    ) {
+        for department_id in Self::client_department_ids(target_client_id, client_subscription_map)
+        {
+            let wallboard_summary = Self::wallboard_summary(cti_instance_state_map, department_id);
+
+            Self::dispatch_broker_event(
+                target_client_id,
+                &broker_event_channel_tx,
+                client_subscription_map,
+                |client_subscription| client_subscription.get_department_id() == department_id,
+                |client_id| BrokerEvent::BroadCastWallboardSummary {
+                    wallboard_summary: wallboard_summary.clone(),
+                    client_id,
+                },
+            );
+            log::debug!(
+                "Broadcasted wallboard summary event. department_id: {:?}, wallboard_summary: {:?}",
+                department_id,
+                wallboard_summary
+            );
+        }
+    }
+
+    ///
+    /// 전체 CTI 서버 인스턴스의 상황판 요약 지표를 계산한다
+    ///
+    /// 부서를 지정하면 해당 부서 상담직원만 집계한다.
+    ///
+    fn wallboard_summary(
+        cti_instance_state_map: &HashMap<String, CtiInstanceState>,
+        department_id: Option<i32>,
+    ) -> WallboardSummary {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        WallboardSummary::aggregate(
+            cti_instance_state_map
+                .values()
+                .flat_map(|state| state.agent_info_map.values())
+                .filter(|agent_info| {
+                    Self::in_department(department_id, Some(agent_info.get_department_id()))
+                }),
+            now,
+        )
+    }
+
+    ///
+    /// CTI 서버 인스턴스의 스킬그룹 집계를 계산한다
+    ///
+    /// 부서를 지정하면 해당 부서 상담직원과 그 상담직원이 받은 호만 집계한다.
+    ///
+    fn aggregate_skill_group_infos(
+        cti_instance: &str,
+        state: &CtiInstanceState,
+        department_id: Option<i32>,
+    ) -> Vec<SkillGroupInfo> {
+        let skill_group_agent_map = state
+            .skill_group_agent_map
+            .iter()
+            .map(|(skill_group_id, agent_state_map)| {
+                let agent_state_map = agent_state_map
+                    .iter()
+                    .filter(|(agent_id, _)| {
+                        Self::in_department(
+                            department_id,
+                            state
+                                .agent_info_map
+                                .get(*agent_id)
+                                .map(AgentInfo::get_department_id),
+                        )
+                    })
+                    .map(|(agent_id, skill_group_state)| (agent_id.clone(), *skill_group_state))
+                    .collect();
+                (*skill_group_id, agent_state_map)
+            })
+            .collect();
+        let call_info_map = state
+            .call_info_map
+            .iter()
+            .filter(|(_, call_info)| {
+                Self::in_department(
+                    department_id,
+                    Self::call_department_id(&state.agent_info_map, call_info),
+                )
+            })
+            .map(|(connection_call_id, call_info)| (*connection_call_id, call_info.clone()))
+            .collect();
+
+        let mut skill_group_infos =
+            SkillGroupInfo::aggregate(&skill_group_agent_map, &call_info_map);
+        skill_group_infos
+            .iter_mut()
+            .for_each(|skill_group_info| skill_group_info.set_cti_instance(cti_instance));
+
+        skill_group_infos
+    }
+
+    ///
+    /// 모든 CTI 서버 인스턴스의 팀 구성을 부서별로 인스턴스, 팀 ID 순으로 브로커 채널에 전송한다
+    ///
+    fn broadcast_team_snapshot(
+        target_client_id: Option<Uuid>,
+        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        cti_instance_state_map: &HashMap<String, CtiInstanceState>,
+    ) {
+        for department_id in Self::client_department_ids(target_client_id, client_subscription_map)
+        {
+            let team_infos = Self::team_infos(cti_instance_state_map, department_id);
+
+            log::debug!(
+                "Broadcasted team snapshot event. department_id: {:?}, team_infos: {:?}",
+                department_id,
+                team_infos
+            );
+            Self::dispatch_broker_event(
+                target_client_id,
+                &broker_event_channel_tx,
+                client_subscription_map,
+                |client_subscription| client_subscription.get_department_id() == department_id,
+                |client_id| BrokerEvent::BroadCastTeamSnapshot {
+                    team_infos: team_infos.clone(),
+                    client_id,
+                },
+            );
+        }
+    }
+
+    ///
+    /// 모든 CTI 서버 인스턴스의 팀 구성을 인스턴스, 팀 ID 순으로 반환한다
+    ///
+    /// 부서를 지정하면 해당 부서 팀만 반환한다.
+    ///
+    fn team_infos(
+        cti_instance_state_map: &HashMap<String, CtiInstanceState>,
+        department_id: Option<i32>,
+    ) -> Vec<TeamInfo> {
+        let mut team_infos = cti_instance_state_map
+            .values()
+            .flat_map(|state| state.team_info_map.values())
+            .filter(|team_info| {
+                Self::in_department(department_id, Some(team_info.get_department_id()))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        team_infos.sort_by(|a, b| {
+            (a.get_cti_instance(), a.get_team_id()).cmp(&(b.get_cti_instance(), b.get_team_id()))
+        });
+
+        team_infos
+    }
+
+    ///
+    /// 상담직원 일별 통계를 브로커 채널에 전송한다
+    ///
+    fn broadcast_agent_statistics(
+        target_client_id: Option<Uuid>,
+        broker_event_channel_tx: broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        agent_info: Option<&AgentInfo>,
+        agent_statistics: AgentStatistics,
+    ) {
+        let team_id = agent_info.map(AgentInfo::get_team_id);
+        let department_id = agent_info.map(AgentInfo::get_department_id);
+
+        log::debug!(
+            "Broadcasted agent statistics event. agent_statistics: {:?}",
+            agent_statistics
+        );
+        Self::dispatch_broker_event(
+            target_client_id,
+            &broker_event_channel_tx,
+            client_subscription_map,
+            |client_subscription| {
+                client_subscription.matches_department(department_id)
+                    && client_subscription.matches_agent(agent_statistics.get_agent_id(), team_id)
+            },
+            |client_id| BrokerEvent::BroadCastAgentStatistics {
+                agent_statistics: agent_statistics.clone(),
+                client_id,
+            },
         );
     }
+
+    ///
+    /// 클라이언트 부서에 속한 상담직원인지 확인한다
+    ///
+    /// 부서를 지정한 클라이언트는 다른 부서나 CTM 이 보관하지 않은 상담직원의 정보를 받지 못한다.
+    ///
+    fn matches_client_department(
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        id: Uuid,
+        agent_info: Option<&AgentInfo>,
+    ) -> bool {
+        client_subscription_map
+            .get(&id)
+            .is_none_or(|client_subscription| {
+                client_subscription.matches_department(agent_info.map(AgentInfo::get_department_id))
+            })
+    }
+
+    ///
+    /// 부서별로 집계하여 보낼 부서 목록
+    ///
+    /// 대상 클라이언트가 지정되면 그 클라이언트의 부서만, 그렇지 않으면 접속한 클라이언트의
+    /// 부서와 부서를 지정하지 않은 클라이언트(None)를 반환한다.
+    ///
+    fn client_department_ids(
+        target_client_id: Option<Uuid>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+    ) -> HashSet<Option<i32>> {
+        if let Some(id) = target_client_id {
+            return HashSet::from([client_subscription_map
+                .get(&id)
+                .and_then(ClientSubscription::get_department_id)]);
+        }
+
+        let mut department_ids = client_subscription_map
+            .values()
+            .map(ClientSubscription::get_department_id)
+            .collect::<HashSet<_>>();
+        department_ids.insert(None);
+
+        department_ids
+    }
+
+    ///
+    /// 부서별 집계에 포함할 상담직원인지 확인한다 (department_id 가 None 이면 모든 상담직원)
+    ///
+    fn in_department(department_id: Option<i32>, agent_department_id: Option<i32>) -> bool {
+        department_id.is_none_or(|department_id| agent_department_id == Some(department_id))
+    }
+
+    ///
+    /// 브로커 이벤트를 구독 조건에 맞는 클라이언트에게 전송한다
+    ///
+    /// 대상 클라이언트가 지정되었거나 모든 클라이언트가 전체 구독 상태이면 한 번만 전송하고,
+    /// 그렇지 않으면 조건에 맞는 클라이언트마다 대상을 지정하여 전송한다.
+    ///
+    fn dispatch_broker_event(
+        target_client_id: Option<Uuid>,
+        broker_event_channel_tx: &broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        matches: impl Fn(&ClientSubscription) -> bool,
+        broker_event: impl Fn(Option<Uuid>) -> BrokerEvent,
+    ) {
+        let _entered = tracing::info_span!("broker.broadcast").entered();
+
+        if target_client_id.is_some()
+            || client_subscription_map
+                .values()
+                .all(ClientSubscription::is_all)
+        {
+            broker_event_channel_tx
+                .send(broker_event(target_client_id))
+                .unwrap();
+            return;
+        }
+
+        client_subscription_map
+            .iter()
+            .filter(|(_, client_subscription)| matches(client_subscription))
+            .for_each(|(id, _)| {
+                broker_event_channel_tx
+                    .send(broker_event(Some(*id)))
+                    .unwrap();
+            });
+    }
+
+    ///
+    /// 클라이언트 언어에 따라 표시 이름이 달라지는 브로커 이벤트를 전송한다
+    ///
+    /// 언어를 지정한 클라이언트가 없으면 dispatch_broker_event 와 같고, 있으면 조건에 맞는
+    /// 클라이언트마다 대상과 언어를 지정하여 전송한다.
+    ///
+    fn dispatch_localized_broker_event(
+        target_client_id: Option<Uuid>,
+        broker_event_channel_tx: &broadcast::Sender<BrokerEvent>,
+        client_subscription_map: &HashMap<Uuid, ClientSubscription>,
+        matches: impl Fn(&ClientSubscription) -> bool,
+        broker_event: impl Fn(Option<Uuid>, Option<&str>) -> BrokerEvent,
+    ) {
+        if client_subscription_map
+            .values()
+            .all(|client_subscription| client_subscription.get_locale().is_none())
+        {
+            return Self::dispatch_broker_event(
+                target_client_id,
+                broker_event_channel_tx,
+                client_subscription_map,
+                matches,
+                |client_id| broker_event(client_id, None),
+            );
+        }
+
+        let _entered = tracing::info_span!("broker.broadcast").entered();
+
+        if let Some(id) = target_client_id {
+            let locale = client_subscription_map
+                .get(&id)
+                .and_then(ClientSubscription::get_locale);
+            broker_event_channel_tx
+                .send(broker_event(Some(id), locale))
+                .unwrap();
+            return;
+        }
+
+        client_subscription_map
+            .iter()
+            .filter(|(_, client_subscription)| matches(client_subscription))
+            .for_each(|(id, client_subscription)| {
+                broker_event_channel_tx
+                    .send(broker_event(Some(*id), client_subscription.get_locale()))
+                    .unwrap();
+            });
+    }
+
+    ///
+    /// 구독자에게 상담직원 제거를 알린다
+    ///
+    fn notify_agent_removed(subscribers: &[Arc<dyn CtmSubscriber>], agent_removal: &AgentRemoval) {
+        subscribers
+            .iter()
+            .for_each(|subscriber| subscriber.on_agent_removed(agent_removal));
+    }
+
+    ///
+    /// 구독자에게 상담직원 상태 변경을 알린다
+    ///
+    fn notify_agent_state(subscribers: &[Arc<dyn CtmSubscriber>], agent_info: &AgentInfo) {
+        subscribers
+            .iter()
+            .for_each(|subscriber| subscriber.on_agent_state(agent_info));
+    }
+
+    ///
+    /// 구독자에게 호 상태 변경을 알린다
+    ///
+    fn notify_call_state(subscribers: &[Arc<dyn CtmSubscriber>], call_info: &CallInfo) {
+        subscribers
+            .iter()
+            .for_each(|subscriber| subscriber.on_call_state(call_info));
+    }
+
+    ///
+    /// 구독자에게 호 이벤트 메시지를 알린다
+    ///
+    fn notify_call_event(
+        subscribers: &[Arc<dyn CtmSubscriber>],
+        message_type: &MessageType,
+        data: &[u8],
+    ) {
+        subscribers
+            .iter()
+            .for_each(|subscriber| subscriber.on_call_event(message_type, data));
+    }
+
+    ///
+    /// 구독자에게 시스템 이벤트를 알린다
+    ///
+    fn notify_system_event(subscribers: &[Arc<dyn CtmSubscriber>], event: &SystemEvent) {
+        subscribers
+            .iter()
+            .for_each(|subscriber| subscriber.on_system_event(event));
+    }
 }
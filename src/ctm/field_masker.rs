@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use sha1::{Digest, Sha1};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// 개인정보 필드 마스킹 방식
+///
+pub enum MaskingMode {
+    // 원본 그대로 전송
+    NONE,
+    // 솔트를 포함한 SHA-1 해시로 전송
+    HASH,
+    // 마지막 일부 자리만 남기고 '*' 로 가려서 전송
+    PARTIAL,
+}
+
+impl FromStr for MaskingMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "" | "NONE" => Ok(MaskingMode::NONE),
+            "HASH" => Ok(MaskingMode::HASH),
+            "PARTIAL" => Ok(MaskingMode::PARTIAL),
+            _ => Err(format!("Unknown field masking mode '{}'", s)),
+        }
+    }
+}
+
+///
+/// 클라이언트로 전송하기 전 내선번호, ANI 등 개인정보 필드를 가리는 마스커
+///
+/// 같은 값은 항상 같은 결과로 변환되므로 마스킹 후에도 값 간의 연관 관계는 유지된다.
+///
+#[derive(Debug, Clone)]
+pub struct FieldMasker {
+    masking_mode: MaskingMode,
+    salt: String,
+    visible_digits: usize,
+}
+
+impl Default for FieldMasker {
+    fn default() -> Self {
+        Self::new(MaskingMode::NONE, "", 0)
+    }
+}
+
+impl FieldMasker {
+    pub fn new(masking_mode: MaskingMode, salt: impl Into<String>, visible_digits: usize) -> Self {
+        Self {
+            masking_mode,
+            salt: salt.into(),
+            visible_digits,
+        }
+    }
+
+    pub fn get_masking_mode(&self) -> MaskingMode {
+        self.masking_mode
+    }
+
+    ///
+    /// 필드 값을 마스킹한다
+    ///
+    /// 빈 값은 그대로 반환한다.
+    ///
+    pub fn mask(&self, value: &str) -> String {
+        if value.is_empty() {
+            return "".to_string();
+        }
+
+        match self.masking_mode {
+            MaskingMode::NONE => value.to_string(),
+            MaskingMode::HASH => {
+                let mut hasher = Sha1::new();
+                hasher.update(self.salt.as_bytes());
+                hasher.update(value.as_bytes());
+
+                hasher
+                    .finalize()
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect()
+            }
+            MaskingMode::PARTIAL => {
+                let length = value.chars().count();
+                let masked_length = length.saturating_sub(self.visible_digits);
+
+                value
+                    .chars()
+                    .enumerate()
+                    .map(|(index, char)| if index < masked_length { '*' } else { char })
+                    .collect()
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cisco::{pretty, MessageType};
+
+#[derive(Debug, Clone, Serialize)]
+///
+/// 복호화한 CTI 메시지 원본 (firehose 구독 클라이언트 전용)
+///
+/// 역직렬화를 지원하는 메시지는 fields 에 MHDR 을 제외한 전체 필드를 담고,
+/// 지원하지 않거나 역직렬화에 실패한 메시지는 raw 에 16진수 문자열을 담는다.
+/// timestamp 는 UNIX 시간(밀리초)이다.
+///
+pub struct FirehoseMessage {
+    event: String,
+    cti_instance: String,
+    message_type: String,
+    fields: Option<Value>,
+    raw: Option<String>,
+    decode_error: Option<String>,
+    timestamp: u64,
+}
+
+impl FirehoseMessage {
+    pub fn new(cti_instance: impl Into<String>, message_type: &MessageType, data: &[u8]) -> Self {
+        let (fields, decode_error) = match pretty::decode_message(message_type, data) {
+            Some(Ok(Value::Object(mut fields))) => {
+                fields.remove("mhdr");
+                (Some(Value::Object(fields)), None)
+            }
+            Some(Ok(value)) => (Some(value), None),
+            Some(Err(e)) => (None, Some(e)),
+            None => (None, None),
+        };
+        let raw = match fields {
+            Some(_) => None,
+            None => Some(data.iter().map(|b| format!("{:02x}", b)).collect()),
+        };
+
+        Self {
+            event: "CTI_MESSAGE".to_string(),
+            cti_instance: cti_instance.into(),
+            message_type: format!("{:?}", message_type),
+            fields,
+            raw,
+            decode_error,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn get_message_type(&self) -> &str {
+        &self.message_type
+    }
+
+    pub fn get_fields(&self) -> Option<&Value> {
+        self.fields.as_ref()
+    }
+
+    pub fn get_raw(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+
+    pub fn get_decode_error(&self) -> Option<&str> {
+        self.decode_error.as_deref()
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
@@ -0,0 +1,438 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+};
+
+use async_graphql::{Context, EmptyMutation, Object, Schema, Subscription};
+use futures::{Stream, StreamExt};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::{
+    cisco::{CallType, ConnectionCallId, Direction, SkillGroupAssignment},
+    event::broker_event::BrokerEvent,
+};
+
+use super::{
+    agent_info::AgentInfo,
+    call_info::{CallInfo, CallState},
+    skill_group_info::SkillGroupInfo,
+    team_info::TeamInfo,
+};
+
+async_graphql::scalar!(Direction, "Direction", "Cisco CTI 호 방향");
+async_graphql::scalar!(CallType, "CallType", "Cisco CTI 호 유형");
+async_graphql::scalar!(ConnectionCallId, "ConnectionCallId", "Cisco CTI 호 ID");
+async_graphql::scalar!(CallState, "CallState", "호 상태");
+async_graphql::scalar!(
+    SkillGroupAssignment,
+    "SkillGroupAssignment",
+    "상담직원이 속한 스킬그룹"
+);
+
+///
+/// GraphQL 스키마
+///
+pub type CtmSchema = Schema<QueryRoot, EmptyMutation, SubscriptionRoot>;
+
+///
+/// 상태 변경 이벤트 (구독 전달용)
+///
+#[derive(Debug, Clone)]
+enum StateChange {
+    Agent(AgentInfo),
+    Call(CallInfo),
+    SkillGroup(SkillGroupInfo),
+}
+
+///
+/// GraphQL 요청 클라이언트의 부서
+///
+/// 인증 키에 부서가 지정되면 요청 데이터로 전달하며, 해당 부서 상담직원만 조회, 구독할 수 있다.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientDepartment(pub i32);
+
+impl ClientDepartment {
+    ///
+    /// 요청 클라이언트 부서에 속한 상담직원인지 확인한다 (부서가 없으면 항상 true)
+    ///
+    fn matches(client_department: Option<&ClientDepartment>, agent_info: &AgentInfo) -> bool {
+        client_department
+            .is_none_or(|client_department| agent_info.get_department_id() == client_department.0)
+    }
+}
+
+///
+/// GraphQL 조회용 상태 저장소
+///
+/// 브로커 이벤트를 반영하여 상담직원, 팀, 호, 스킬그룹 상태를 보관한다.
+///
+pub struct GraphQLState {
+    agent_info_map: RwLock<BTreeMap<(String, String), AgentInfo>>,
+    team_infos: RwLock<Vec<TeamInfo>>,
+    call_info_map: RwLock<BTreeMap<(String, u32), CallInfo>>,
+    skill_group_info_map: RwLock<BTreeMap<(String, u32), SkillGroupInfo>>,
+    state_change_tx: broadcast::Sender<StateChange>,
+}
+
+impl Default for GraphQLState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphQLState {
+    pub fn new() -> Self {
+        let (state_change_tx, _) = broadcast::channel(1_024);
+
+        Self {
+            agent_info_map: RwLock::new(BTreeMap::new()),
+            team_infos: RwLock::new(vec![]),
+            call_info_map: RwLock::new(BTreeMap::new()),
+            skill_group_info_map: RwLock::new(BTreeMap::new()),
+            state_change_tx,
+        }
+    }
+
+    ///
+    /// 브로커 이벤트를 상태에 반영한다
+    ///
+    pub fn apply(&self, broker_event: &BrokerEvent) {
+        match broker_event {
+            BrokerEvent::BroadCastAgentState { agent_info, .. } => {
+                self.update_agent_info((**agent_info).clone());
+            }
+            BrokerEvent::BroadCastAgentSnapshot { agent_snapshot, .. } => {
+                agent_snapshot
+                    .get_agent_infos()
+                    .iter()
+                    .for_each(|agent_info| self.update_agent_info(agent_info.clone()));
+            }
+            BrokerEvent::BroadCastAgentDelta {
+                agent_info_delta, ..
+            } => {
+                let key = (
+                    agent_info_delta.get_cti_instance().to_string(),
+                    agent_info_delta.get_agent_id().to_string(),
+                );
+
+                let agent_info =
+                    agent_info_delta.apply(self.agent_info_map.read().unwrap().get(&key));
+                match agent_info {
+                    Ok(agent_info) => self.update_agent_info(agent_info),
+                    Err(e) => log::warn!(
+                        "Unable to apply agent delta. agent_id: {}, error: {}",
+                        agent_info_delta.get_agent_id(),
+                        e
+                    ),
+                }
+            }
+            BrokerEvent::BroadCastAgentRemoved { agent_removal, .. } => {
+                self.agent_info_map.write().unwrap().remove(&(
+                    agent_removal.get_cti_instance().to_string(),
+                    agent_removal.get_agent_id().to_string(),
+                ));
+            }
+            BrokerEvent::BroadCastTeamSnapshot { team_infos, .. } => {
+                *self.team_infos.write().unwrap() = team_infos.clone();
+            }
+            BrokerEvent::BroadCastCallState { call_info, .. } => {
+                let key = (
+                    call_info.get_cti_instance().to_string(),
+                    call_info.get_connection_call_id().0,
+                );
+
+                // 종료된 호는 조회 대상에서 제외한다
+                match call_info.get_call_state() {
+                    CallState::CLEARED => {
+                        self.call_info_map.write().unwrap().remove(&key);
+                    }
+                    _ => {
+                        self.call_info_map
+                            .write()
+                            .unwrap()
+                            .insert(key, call_info.clone());
+                    }
+                }
+                let _ = self
+                    .state_change_tx
+                    .send(StateChange::Call(call_info.clone()));
+            }
+            BrokerEvent::BroadCastSkillGroupState {
+                skill_group_info, ..
+            } => {
+                self.skill_group_info_map.write().unwrap().insert(
+                    (
+                        skill_group_info.get_cti_instance().to_string(),
+                        skill_group_info.get_skill_group_id(),
+                    ),
+                    skill_group_info.clone(),
+                );
+                let _ = self
+                    .state_change_tx
+                    .send(StateChange::SkillGroup(skill_group_info.clone()));
+            }
+            _ => {}
+        }
+    }
+
+    fn update_agent_info(&self, agent_info: AgentInfo) {
+        self.agent_info_map.write().unwrap().insert(
+            (
+                agent_info.get_cti_instance().to_string(),
+                agent_info.get_agent_id().to_string(),
+            ),
+            agent_info.clone(),
+        );
+        let _ = self.state_change_tx.send(StateChange::Agent(agent_info));
+    }
+
+    ///
+    /// 상태 변경 이벤트 스트림
+    ///
+    /// 처리가 늦어 누락된 이벤트는 건너뛴다.
+    ///
+    fn state_changes(&self) -> impl Stream<Item = StateChange> {
+        futures::stream::unfold(
+            self.state_change_tx.subscribe(),
+            |mut state_change_rx| async move {
+                loop {
+                    match state_change_rx.recv().await {
+                        Ok(state_change) => return Some((state_change, state_change_rx)),
+                        Err(RecvError::Lagged(n)) => {
+                            log::warn!("GraphQL subscription lagged. skipped: {}", n)
+                        }
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            },
+        )
+    }
+}
+
+///
+/// GraphQL 스키마 생성
+///
+pub fn build_schema(state: Arc<GraphQLState>) -> CtmSchema {
+    Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .data(state)
+        .finish()
+}
+
+///
+/// GraphQL 조회
+///
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    ///
+    /// 상담직원 목록 조회
+    ///
+    async fn agents(
+        &self,
+        ctx: &Context<'_>,
+        cti_instance: Option<String>,
+        team_id: Option<u32>,
+    ) -> Vec<AgentInfo> {
+        let state = ctx.data_unchecked::<Arc<GraphQLState>>();
+        let client_department = ctx.data_opt::<ClientDepartment>();
+        let agent_info_map = state.agent_info_map.read().unwrap();
+
+        agent_info_map
+            .values()
+            .filter(|agent_info| {
+                ClientDepartment::matches(client_department, agent_info)
+                    && cti_instance
+                        .as_deref()
+                        .is_none_or(|cti_instance| agent_info.get_cti_instance() == cti_instance)
+                    && team_id.is_none_or(|team_id| agent_info.get_team_id() == team_id)
+            })
+            .cloned()
+            .collect()
+    }
+
+    ///
+    /// 상담직원 조회
+    ///
+    async fn agent(
+        &self,
+        ctx: &Context<'_>,
+        cti_instance: Option<String>,
+        agent_id: String,
+    ) -> Option<AgentInfo> {
+        let state = ctx.data_unchecked::<Arc<GraphQLState>>();
+        let client_department = ctx.data_opt::<ClientDepartment>();
+        let agent_info_map = state.agent_info_map.read().unwrap();
+
+        agent_info_map
+            .values()
+            .find(|agent_info| {
+                agent_info.get_agent_id() == agent_id
+                    && ClientDepartment::matches(client_department, agent_info)
+                    && cti_instance
+                        .as_deref()
+                        .is_none_or(|cti_instance| agent_info.get_cti_instance() == cti_instance)
+            })
+            .cloned()
+    }
+
+    ///
+    /// 팀 목록 조회
+    ///
+    async fn teams(&self, ctx: &Context<'_>, cti_instance: Option<String>) -> Vec<TeamInfo> {
+        let state = ctx.data_unchecked::<Arc<GraphQLState>>();
+        let team_infos = state.team_infos.read().unwrap();
+
+        team_infos
+            .iter()
+            .filter(|team_info| {
+                cti_instance
+                    .as_deref()
+                    .is_none_or(|cti_instance| team_info.get_cti_instance() == cti_instance)
+            })
+            .cloned()
+            .collect()
+    }
+
+    ///
+    /// 진행 중인 호 목록 조회
+    ///
+    async fn calls(
+        &self,
+        ctx: &Context<'_>,
+        cti_instance: Option<String>,
+        agent_id: Option<String>,
+    ) -> Vec<CallInfo> {
+        let state = ctx.data_unchecked::<Arc<GraphQLState>>();
+        let call_info_map = state.call_info_map.read().unwrap();
+
+        call_info_map
+            .values()
+            .filter(|call_info| {
+                cti_instance
+                    .as_deref()
+                    .is_none_or(|cti_instance| call_info.get_cti_instance() == cti_instance)
+                    && agent_id
+                        .as_deref()
+                        .is_none_or(|agent_id| call_info.get_agent_id() == agent_id)
+            })
+            .cloned()
+            .collect()
+    }
+
+    ///
+    /// 스킬그룹 목록 조회
+    ///
+    async fn skill_groups(
+        &self,
+        ctx: &Context<'_>,
+        cti_instance: Option<String>,
+    ) -> Vec<SkillGroupInfo> {
+        let state = ctx.data_unchecked::<Arc<GraphQLState>>();
+        let skill_group_info_map = state.skill_group_info_map.read().unwrap();
+
+        skill_group_info_map
+            .values()
+            .filter(|skill_group_info| {
+                cti_instance
+                    .as_deref()
+                    .is_none_or(|cti_instance| skill_group_info.get_cti_instance() == cti_instance)
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+///
+/// GraphQL 구독
+///
+pub struct SubscriptionRoot;
+
+#[Subscription]
+impl SubscriptionRoot {
+    ///
+    /// 상담직원 상태 변경 구독
+    ///
+    async fn agent_state_changed(
+        &self,
+        ctx: &Context<'_>,
+        team_id: Option<u32>,
+        agent_id: Option<String>,
+    ) -> impl Stream<Item = AgentInfo> {
+        let state = ctx.data_unchecked::<Arc<GraphQLState>>();
+        let client_department = ctx.data_opt::<ClientDepartment>().copied();
+
+        state.state_changes().filter_map(move |state_change| {
+            let agent_id = agent_id.clone();
+            async move {
+                match state_change {
+                    StateChange::Agent(agent_info)
+                        if ClientDepartment::matches(client_department.as_ref(), &agent_info)
+                            && team_id
+                                .is_none_or(|team_id| agent_info.get_team_id() == team_id)
+                            && agent_id
+                                .as_deref()
+                                .is_none_or(|agent_id| agent_info.get_agent_id() == agent_id) =>
+                    {
+                        Some(agent_info)
+                    }
+                    _ => None,
+                }
+            }
+        })
+    }
+
+    ///
+    /// 호 상태 변경 구독
+    ///
+    async fn call_state_changed(
+        &self,
+        ctx: &Context<'_>,
+        agent_id: Option<String>,
+    ) -> impl Stream<Item = CallInfo> {
+        let state = ctx.data_unchecked::<Arc<GraphQLState>>();
+
+        state.state_changes().filter_map(move |state_change| {
+            let agent_id = agent_id.clone();
+            async move {
+                match state_change {
+                    StateChange::Call(call_info)
+                        if agent_id
+                            .as_deref()
+                            .is_none_or(|agent_id| call_info.get_agent_id() == agent_id) =>
+                    {
+                        Some(call_info)
+                    }
+                    _ => None,
+                }
+            }
+        })
+    }
+
+    ///
+    /// 스킬그룹 상태 변경 구독
+    ///
+    async fn skill_group_state_changed(
+        &self,
+        ctx: &Context<'_>,
+        skill_group_id: Option<u32>,
+    ) -> impl Stream<Item = SkillGroupInfo> {
+        let state = ctx.data_unchecked::<Arc<GraphQLState>>();
+
+        state
+            .state_changes()
+            .filter_map(move |state_change| async move {
+                match state_change {
+                    StateChange::SkillGroup(skill_group_info)
+                        if skill_group_id.is_none_or(|skill_group_id| {
+                            skill_group_info.get_skill_group_id() == skill_group_id
+                        }) =>
+                    {
+                        Some(skill_group_info)
+                    }
+                    _ => None,
+                }
+            })
+    }
+}
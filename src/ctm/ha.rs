@@ -0,0 +1,466 @@
+use std::{
+    error::Error,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+    time::{interval, timeout, MissedTickBehavior},
+};
+use tokio_util::codec::{Framed, LengthDelimitedCodec};
+
+use super::{
+    acceptor::ip_acl::IpAcl, config::HaConfig, ha_status::HaRole, net_addr::bind_tcp_listener,
+    shutdown::ShutdownController, snapshot::CtmSnapshot,
+};
+
+// 상태 복제 메시지 최대 크기
+const MAX_FRAME_LENGTH: usize = 64 * 1_024 * 1_024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// 노드 하트비트 (역할 선출에 사용)
+///
+pub struct HaHeartbeat {
+    pub node_id: String,
+    pub priority: u8,
+    pub role: HaRole,
+}
+
+///
+/// 노드 사이에 주고받는 메시지 (4바이트 길이 + MessagePack)
+///
+#[derive(Debug, Serialize, Deserialize)]
+enum HaMessage {
+    Heartbeat(HaHeartbeat),
+    // 활성 노드가 대기 노드로 보내는 전체 상태
+    Mirror(CtmSnapshot),
+}
+
+///
+/// 상대 노드의 마지막 하트비트
+///
+#[derive(Debug, Clone)]
+struct HaPeer {
+    heartbeat: HaHeartbeat,
+    last_seen: Instant,
+}
+
+///
+/// 활성/대기 역할 선출
+///
+/// 대기 노드로 시작하며 다음 규칙으로 역할을 정한다.
+/// - 상대 노드의 하트비트를 lease_timeout 동안 받지 못하면 활성 노드가 된다.
+/// - 두 노드가 모두 대기 중이면 우선순위가 높은 노드(같으면 node_id 가 작은 노드)가 활성 노드가 된다.
+/// - 네트워크 단절 후 두 노드가 모두 활성이면 우선순위가 낮은 노드가 대기 노드로 물러난다.
+///
+#[derive(Debug, Clone)]
+pub struct HaElection {
+    node_id: String,
+    priority: u8,
+    role: HaRole,
+    lease_timeout: Duration,
+    started_at: Instant,
+    peer: Option<HaPeer>,
+}
+
+impl HaElection {
+    pub fn new(
+        node_id: impl Into<String>,
+        priority: u8,
+        lease_timeout: Duration,
+        now: Instant,
+    ) -> Self {
+        Self {
+            node_id: node_id.into(),
+            priority,
+            role: HaRole::STANDBY,
+            lease_timeout,
+            started_at: now,
+            peer: None,
+        }
+    }
+
+    pub fn get_role(&self) -> HaRole {
+        self.role
+    }
+
+    pub fn get_peer_node_id(&self) -> Option<&str> {
+        self.peer
+            .as_ref()
+            .map(|peer| peer.heartbeat.node_id.as_str())
+    }
+
+    ///
+    /// 상대 노드에 보낼 하트비트
+    ///
+    pub fn heartbeat(&self) -> HaHeartbeat {
+        HaHeartbeat {
+            node_id: self.node_id.clone(),
+            priority: self.priority,
+            role: self.role,
+        }
+    }
+
+    ///
+    /// 상대 노드의 하트비트를 반영하고, 역할이 바뀌면 새 역할을 반환한다
+    ///
+    pub fn on_heartbeat(&mut self, heartbeat: HaHeartbeat, now: Instant) -> Option<HaRole> {
+        if heartbeat.node_id == self.node_id {
+            log::error!(
+                "HA peer has the same node id. node_id: {}",
+                heartbeat.node_id
+            );
+        }
+
+        let outranks_peer = self.outranks(&heartbeat);
+        let peer_role = heartbeat.role;
+        self.peer = Some(HaPeer {
+            heartbeat,
+            last_seen: now,
+        });
+
+        match (self.role, peer_role) {
+            (HaRole::ACTIVE, HaRole::ACTIVE) if !outranks_peer => self.change_role(HaRole::STANDBY),
+            (HaRole::STANDBY, HaRole::STANDBY) if outranks_peer => self.change_role(HaRole::ACTIVE),
+            _ => None,
+        }
+    }
+
+    ///
+    /// 임대 시간을 확인하고, 역할이 바뀌면 새 역할을 반환한다
+    ///
+    pub fn on_tick(&mut self, now: Instant) -> Option<HaRole> {
+        if self.role == HaRole::ACTIVE {
+            return None;
+        }
+
+        let last_seen = self
+            .peer
+            .as_ref()
+            .map(|peer| peer.last_seen)
+            .unwrap_or(self.started_at);
+        match now.saturating_duration_since(last_seen) >= self.lease_timeout {
+            true => self.change_role(HaRole::ACTIVE),
+            false => None,
+        }
+    }
+
+    fn outranks(&self, heartbeat: &HaHeartbeat) -> bool {
+        self.priority > heartbeat.priority
+            || (self.priority == heartbeat.priority && self.node_id < heartbeat.node_id)
+    }
+
+    fn change_role(&mut self, role: HaRole) -> Option<HaRole> {
+        self.role = role;
+        Some(role)
+    }
+}
+
+///
+/// HA 노드가 CTM 에 알리는 이벤트
+///
+#[derive(Debug)]
+pub enum HaEvent {
+    RoleChanged {
+        role: HaRole,
+        peer_node_id: Option<String>,
+    },
+    // 활성 노드에서 받은 전체 상태
+    Mirror(CtmSnapshot),
+}
+
+///
+/// 활성/대기 HA 노드
+///
+/// HA_PORT 로 상대 노드의 하트비트와 상태를 받고, HA_PEER_ADDRESS 로 자신의 하트비트와 상태를 보낸다.
+/// HA_ALLOWED_IPS 로 상대 노드 주소만 접속하도록 제한할 수 있다.
+///
+pub struct HaNode {
+    config: HaConfig,
+    listener: TcpListener,
+    ip_acl: IpAcl,
+}
+
+impl HaNode {
+    pub async fn bind(config: HaConfig) -> Result<Self, Box<dyn Error>> {
+        let listener = bind_tcp_listener(&config.bind_address, config.port, true).await?;
+
+        Ok(Self {
+            ip_acl: config.allowed_ips.clone(),
+            config,
+            listener,
+        })
+    }
+
+    pub fn get_local_addr(&self) -> Result<SocketAddr, Box<dyn Error>> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    pub fn get_node_id(&self) -> &str {
+        &self.config.node_id
+    }
+
+    ///
+    /// 하트비트 송수신과 역할 선출을 시작한다
+    ///
+    /// 역할이 바뀌거나 상태를 받으면 ha_event_tx 로 알리며,
+    /// 반환된 송신 채널로 보낸 상태는 활성 노드일 때만 상대 노드에 전달된다.
+    ///
+    pub fn start(
+        self,
+        ha_event_tx: mpsc::Sender<HaEvent>,
+        shutdown_controller: ShutdownController,
+    ) -> mpsc::Sender<CtmSnapshot> {
+        let (heartbeat_tx, heartbeat_rx) = mpsc::channel(16);
+        let (mirror_tx, mirror_rx) = mpsc::channel(1);
+
+        log::info!(
+            "HA node started. node_id: {}, port: {}, peer_address: {}",
+            self.config.node_id,
+            self.config.port,
+            self.config.peer_address
+        );
+        tokio::spawn(Self::receive(
+            self.listener,
+            self.ip_acl,
+            heartbeat_tx,
+            ha_event_tx.clone(),
+            shutdown_controller.clone(),
+        ));
+        tokio::spawn(Self::elect(
+            self.config,
+            heartbeat_rx,
+            mirror_rx,
+            ha_event_tx,
+            shutdown_controller,
+        ));
+
+        mirror_tx
+    }
+
+    ///
+    /// 상대 노드 접속을 받아 메시지를 수신한다
+    ///
+    async fn receive(
+        listener: TcpListener,
+        ip_acl: IpAcl,
+        heartbeat_tx: mpsc::Sender<HaHeartbeat>,
+        ha_event_tx: mpsc::Sender<HaEvent>,
+        shutdown_controller: ShutdownController,
+    ) {
+        loop {
+            let (stream, addr) = tokio::select! {
+                accepted = listener.accept() => match accepted {
+                    Ok(accepted) => accepted,
+                    Err(e) => {
+                        log::error!("Unable to accept HA peer. {}", e);
+                        continue;
+                    }
+                },
+                _ = shutdown_controller.wait() => return,
+            };
+            if !ip_acl.allows(addr.ip()) {
+                log::warn!("HA peer rejected by IP ACL. peer_addr: {}", addr);
+                continue;
+            }
+            log::info!("HA peer connected. peer_addr: {}", addr);
+
+            let heartbeat_tx = heartbeat_tx.clone();
+            let ha_event_tx = ha_event_tx.clone();
+            let shutdown_controller = shutdown_controller.clone();
+            tokio::spawn(async move {
+                let mut stream = Framed::new(stream, Self::codec());
+                loop {
+                    let frame = tokio::select! {
+                        frame = stream.next() => frame,
+                        _ = shutdown_controller.wait() => return,
+                    };
+                    let message = match frame {
+                        Some(Ok(frame)) => rmp_serde::from_slice::<HaMessage>(&frame),
+                        Some(Err(e)) => {
+                            log::warn!("HA peer read error. peer_addr: {}, error: {}", addr, e);
+                            return;
+                        }
+                        None => {
+                            log::info!("HA peer disconnected. peer_addr: {}", addr);
+                            return;
+                        }
+                    };
+
+                    let sent = match message {
+                        Ok(HaMessage::Heartbeat(heartbeat)) => {
+                            heartbeat_tx.send(heartbeat).await.is_ok()
+                        }
+                        Ok(HaMessage::Mirror(snapshot)) => {
+                            ha_event_tx.send(HaEvent::Mirror(snapshot)).await.is_ok()
+                        }
+                        Err(e) => {
+                            log::warn!(
+                                "Discarded invalid HA message. peer_addr: {}, error: {}",
+                                addr,
+                                e
+                            );
+                            true
+                        }
+                    };
+                    if !sent {
+                        return;
+                    }
+                }
+            });
+        }
+    }
+
+    ///
+    /// 하트비트를 보내며 역할을 선출한다
+    ///
+    async fn elect(
+        config: HaConfig,
+        mut heartbeat_rx: mpsc::Receiver<HaHeartbeat>,
+        mut mirror_rx: mpsc::Receiver<CtmSnapshot>,
+        ha_event_tx: mpsc::Sender<HaEvent>,
+        shutdown_controller: ShutdownController,
+    ) {
+        let mut election = HaElection::new(
+            config.node_id.clone(),
+            config.priority,
+            config.lease_timeout,
+            Instant::now(),
+        );
+        let mut peer_stream: Option<Framed<TcpStream, LengthDelimitedCodec>> = None;
+        let mut heartbeat_interval = interval(config.heartbeat_interval);
+        heartbeat_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            let role = tokio::select! {
+                _ = heartbeat_interval.tick() => {
+                    let role = election.on_tick(Instant::now());
+                    Self::send(
+                        &config,
+                        &mut peer_stream,
+                        HaMessage::Heartbeat(election.heartbeat()),
+                    )
+                    .await;
+                    role
+                }
+                Some(heartbeat) = heartbeat_rx.recv() => {
+                    election.on_heartbeat(heartbeat, Instant::now())
+                }
+                Some(snapshot) = mirror_rx.recv() => {
+                    if election.get_role() == HaRole::ACTIVE {
+                        Self::send(&config, &mut peer_stream, HaMessage::Mirror(snapshot)).await;
+                    }
+                    None
+                }
+                _ = shutdown_controller.wait() => return,
+            };
+
+            if let Some(role) = role {
+                log::warn!(
+                    "HA role changed. node_id: {}, role: {:?}, peer_node_id: {:?}",
+                    config.node_id,
+                    role,
+                    election.get_peer_node_id()
+                );
+                let role_changed = HaEvent::RoleChanged {
+                    role,
+                    peer_node_id: election.get_peer_node_id().map(str::to_string),
+                };
+                if ha_event_tx.send(role_changed).await.is_err() {
+                    return;
+                }
+                // 역할이 바뀌면 상대 노드가 바로 알 수 있도록 하트비트를 보낸다
+                Self::send(
+                    &config,
+                    &mut peer_stream,
+                    HaMessage::Heartbeat(election.heartbeat()),
+                )
+                .await;
+            }
+        }
+    }
+
+    ///
+    /// 상대 노드에 메시지를 보낸다 (연결이 없으면 접속을 시도한다)
+    ///
+    /// 하트비트 간격 안에 보내지 못하면 연결을 끊고 다음 전송 때 다시 접속한다.
+    ///
+    async fn send(
+        config: &HaConfig,
+        peer_stream: &mut Option<Framed<TcpStream, LengthDelimitedCodec>>,
+        message: HaMessage,
+    ) {
+        let frame = match rmp_serde::to_vec_named(&message) {
+            Ok(frame) => frame,
+            Err(e) => {
+                log::error!("Unable to serialize HA message. {}", e);
+                return;
+            }
+        };
+
+        if peer_stream.is_none() {
+            match timeout(
+                config.heartbeat_interval,
+                TcpStream::connect(&config.peer_address),
+            )
+            .await
+            {
+                Ok(Ok(stream)) => {
+                    let _ = stream.set_nodelay(true);
+                    log::info!(
+                        "Connected to HA peer. peer_address: {}",
+                        config.peer_address
+                    );
+                    *peer_stream = Some(Framed::new(stream, Self::codec()));
+                }
+                Ok(Err(e)) => {
+                    log::debug!(
+                        "Unable to connect to HA peer. peer_address: {}, error: {}",
+                        config.peer_address,
+                        e
+                    );
+                    return;
+                }
+                Err(_) => return,
+            }
+        }
+
+        let Some(stream) = peer_stream.as_mut() else {
+            return;
+        };
+        // 상태 복제는 크기가 클 수 있으므로 임대 시간까지 기다린다
+        let send_timeout = match message {
+            HaMessage::Heartbeat(_) => config.heartbeat_interval,
+            HaMessage::Mirror(_) => config.lease_timeout,
+        };
+        match timeout(send_timeout, stream.send(frame.as_slice())).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                log::warn!(
+                    "HA peer write error. peer_address: {}, error: {}",
+                    config.peer_address,
+                    e
+                );
+                *peer_stream = None;
+            }
+            Err(_) => {
+                log::warn!(
+                    "HA peer write timed out. peer_address: {}",
+                    config.peer_address
+                );
+                *peer_stream = None;
+            }
+        }
+    }
+
+    fn codec() -> LengthDelimitedCodec {
+        LengthDelimitedCodec::builder()
+            .max_frame_length(MAX_FRAME_LENGTH)
+            .new_codec()
+    }
+}
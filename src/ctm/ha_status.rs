@@ -0,0 +1,65 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// HA 노드 역할
+///
+pub enum HaRole {
+    // CTI 세션을 맺고 상태를 갱신하는 노드
+    ACTIVE,
+    // 활성 노드의 상태를 복제받으며 대기하는 노드
+    STANDBY,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// HA 역할 안내 (접속 시, 역할 전환 시 클라이언트에 전송)
+///
+/// 대기 노드로 전환되면 CTI 세션을 닫으므로 클라이언트는 peer_node_id 노드로 옮겨 접속할 수 있다.
+/// timestamp 는 UNIX 시간(밀리초)이다.
+///
+pub struct HaStatus {
+    event: String,
+    node_id: String,
+    role: HaRole,
+    peer_node_id: Option<String>,
+    timestamp: u64,
+}
+
+impl HaStatus {
+    pub fn new(node_id: impl Into<String>, role: HaRole, peer_node_id: Option<String>) -> Self {
+        Self {
+            event: "HA_STATUS".to_string(),
+            node_id: node_id.into(),
+            role,
+            peer_node_id,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+
+    pub fn get_node_id(&self) -> &str {
+        &self.node_id
+    }
+
+    pub fn get_role(&self) -> HaRole {
+        self.role
+    }
+
+    pub fn get_peer_node_id(&self) -> Option<&str> {
+        self.peer_node_id.as_deref()
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
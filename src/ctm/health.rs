@@ -0,0 +1,292 @@
+use std::{
+    collections::BTreeMap,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::event::system_event::SystemEvent;
+
+use super::{ha_status::HaRole, subscriber::CtmSubscriber};
+
+#[derive(Debug, Clone)]
+struct CtiInstanceHealth {
+    connected: bool,
+    is_active: bool,
+    cti_server_host: String,
+    monitor_id: u32,
+    connected_at: Option<Instant>,
+    last_heartbeat_conf: Option<Instant>,
+    last_error: Option<String>,
+}
+
+impl Default for CtiInstanceHealth {
+    fn default() -> Self {
+        Self {
+            connected: false,
+            is_active: true,
+            cti_server_host: "".to_string(),
+            monitor_id: 0,
+            connected_at: None,
+            last_heartbeat_conf: None,
+            last_error: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+///
+/// CTI 서버 이중화 쌍의 상태
+///
+/// side 는 현재 접속 대상(A: 주, B: 예비)이며, 하트비트 응답을 받은 적이 없으면
+/// seconds_since_heartbeat 는 null 이다. monitor_id 는 접속 중인 세션의 이벤트 모니터 ID 이다.
+///
+pub struct CtiInstanceReport {
+    cti_instance: String,
+    connected: bool,
+    side: String,
+    cti_server_host: String,
+    monitor_id: Option<u32>,
+    seconds_since_heartbeat: Option<u64>,
+    last_error: Option<String>,
+    ready: bool,
+}
+
+impl CtiInstanceReport {
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    pub fn get_side(&self) -> &str {
+        &self.side
+    }
+
+    pub fn get_monitor_id(&self) -> Option<u32> {
+        self.monitor_id
+    }
+
+    pub fn get_seconds_since_heartbeat(&self) -> Option<u64> {
+        self.seconds_since_heartbeat
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+///
+/// Acceptor 실행 상태
+///
+pub struct AcceptorReport {
+    name: String,
+    running: bool,
+    error: Option<String>,
+}
+
+impl AcceptorReport {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+///
+/// 준비 상태 보고서
+///
+pub struct HealthReport {
+    ready: bool,
+    // HA 를 사용하지 않으면 null
+    ha_role: Option<HaRole>,
+    cti_instances: Vec<CtiInstanceReport>,
+    acceptors: Vec<AcceptorReport>,
+}
+
+impl HealthReport {
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    pub fn get_ha_role(&self) -> Option<HaRole> {
+        self.ha_role
+    }
+
+    pub fn get_cti_instances(&self) -> &[CtiInstanceReport] {
+        &self.cti_instances
+    }
+
+    pub fn get_acceptors(&self) -> &[AcceptorReport] {
+        &self.acceptors
+    }
+}
+
+///
+/// CTM 상태 추적기
+///
+/// 시스템 이벤트 구독자로 등록되어 CTI 세션과 Acceptor 상태를 기록한다.
+/// 모든 CTI 서버에 접속되어 있고, 마지막 하트비트 응답(없으면 접속 시각)이 heartbeat_timeout 이내이며,
+/// 모든 Acceptor 가 실행 중이면 준비 상태로 판단한다. HA 대기 노드는 CTI 세션이 없으므로 준비 상태가 아니다.
+///
+pub struct HealthState {
+    heartbeat_timeout: Duration,
+    ha_role: RwLock<Option<HaRole>>,
+    cti_instances: RwLock<BTreeMap<String, CtiInstanceHealth>>,
+    acceptors: RwLock<BTreeMap<String, AcceptorReport>>,
+}
+
+impl HealthState {
+    pub fn new(cti_instances: Vec<String>, heartbeat_timeout: Duration) -> Self {
+        Self {
+            heartbeat_timeout,
+            ha_role: RwLock::new(None),
+            cti_instances: RwLock::new(
+                cti_instances
+                    .into_iter()
+                    .map(|cti_instance| (cti_instance, CtiInstanceHealth::default()))
+                    .collect(),
+            ),
+            acceptors: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    ///
+    /// 현재 상태 보고서 생성
+    ///
+    pub fn report(&self) -> HealthReport {
+        let now = Instant::now();
+
+        let cti_instances = self
+            .cti_instances
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(cti_instance, health)| {
+                let last_seen = health.last_heartbeat_conf.or(health.connected_at);
+                CtiInstanceReport {
+                    cti_instance: cti_instance.clone(),
+                    connected: health.connected,
+                    side: match health.is_active {
+                        true => "A".to_string(),
+                        false => "B".to_string(),
+                    },
+                    cti_server_host: health.cti_server_host.clone(),
+                    monitor_id: (health.connected && health.monitor_id != 0)
+                        .then_some(health.monitor_id),
+                    seconds_since_heartbeat: health
+                        .last_heartbeat_conf
+                        .map(|at| now.duration_since(at).as_secs()),
+                    last_error: health.last_error.clone(),
+                    ready: health.connected
+                        && last_seen
+                            .is_some_and(|at| now.duration_since(at) <= self.heartbeat_timeout),
+                }
+            })
+            .collect::<Vec<_>>();
+        let acceptors = self
+            .acceptors
+            .read()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        HealthReport {
+            ready: cti_instances.iter().all(CtiInstanceReport::is_ready)
+                && acceptors.iter().all(AcceptorReport::is_running),
+            ha_role: *self.ha_role.read().unwrap(),
+            cti_instances,
+            acceptors,
+        }
+    }
+}
+
+impl CtmSubscriber for HealthState {
+    fn on_system_event(&self, event: &SystemEvent) {
+        match event {
+            SystemEvent::CtiConnected {
+                cti_instance,
+                cti_server_host,
+                monitor_id,
+            } => {
+                let mut cti_instances = self.cti_instances.write().unwrap();
+                let health = cti_instances.entry(cti_instance.clone()).or_default();
+                health.connected = true;
+                health.cti_server_host = cti_server_host.clone();
+                health.monitor_id = *monitor_id;
+                health.connected_at = Some(Instant::now());
+                health.last_heartbeat_conf = None;
+            }
+            SystemEvent::CtiError {
+                cti_instance,
+                error_cause,
+                ..
+            } => {
+                let mut cti_instances = self.cti_instances.write().unwrap();
+                let health = cti_instances.entry(cti_instance.clone()).or_default();
+                health.connected = false;
+                health.last_error = Some(error_cause.clone());
+            }
+            SystemEvent::Failover {
+                cti_instance,
+                is_active,
+            } => {
+                self.cti_instances
+                    .write()
+                    .unwrap()
+                    .entry(cti_instance.clone())
+                    .or_default()
+                    .is_active = *is_active;
+            }
+            SystemEvent::HeartBeatConf { cti_instance } => {
+                self.cti_instances
+                    .write()
+                    .unwrap()
+                    .entry(cti_instance.clone())
+                    .or_default()
+                    .last_heartbeat_conf = Some(Instant::now());
+            }
+            SystemEvent::HaRoleChanged { role, .. } => {
+                *self.ha_role.write().unwrap() = Some(*role);
+
+                // 대기 노드로 전환되면 CTI 세션을 오류 없이 닫는다
+                if *role == HaRole::STANDBY {
+                    self.cti_instances
+                        .write()
+                        .unwrap()
+                        .values_mut()
+                        .for_each(|health| health.connected = false);
+                }
+            }
+            SystemEvent::AcceptorStarted { name } => {
+                self.acceptors.write().unwrap().insert(
+                    name.clone(),
+                    AcceptorReport {
+                        name: name.clone(),
+                        running: true,
+                        error: None,
+                    },
+                );
+            }
+            SystemEvent::AcceptorStopped { name, error } => {
+                self.acceptors.write().unwrap().insert(
+                    name.clone(),
+                    AcceptorReport {
+                        name: name.clone(),
+                        running: false,
+                        error: error.clone(),
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+}
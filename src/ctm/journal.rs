@@ -0,0 +1,132 @@
+use std::{
+    error::Error,
+    fs::{self, File},
+    io::{BufReader, BufWriter, ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+
+use crate::{cisco::MessageType, ctm::cti_client::DEFAULT_CTI_INSTANCE};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// 이벤트 저널 레코드
+///
+/// 수신한 CTI 메시지 프레임을 수신 시각(밀리초)과 함께 보관한다.
+///
+pub struct JournalRecord {
+    pub timestamp: i64,
+    #[serde(default = "default_cti_instance")]
+    pub cti_instance: String,
+    pub cti_server_host: String,
+    pub message_type: u32,
+    pub data: Vec<u8>,
+}
+
+impl JournalRecord {
+    pub fn new(
+        cti_instance: impl Into<String>,
+        cti_server_host: impl Into<String>,
+        message_type: MessageType,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            timestamp: Local::now().timestamp_millis(),
+            cti_instance: cti_instance.into(),
+            cti_server_host: cti_server_host.into(),
+            message_type: message_type.into(),
+            data,
+        }
+    }
+
+    pub fn get_message_type(&self) -> MessageType {
+        MessageType::from(self.message_type)
+    }
+}
+
+///
+/// 이벤트 저널 기록기
+///
+/// 레코드를 MessagePack 으로 이어 붙여 기록하며, 세그먼트 크기를 넘으면 새 파일을 연다.
+///
+pub struct JournalWriter {
+    directory: PathBuf,
+    segment_size: u64,
+    segment_index: u32,
+    segment_written: u64,
+    writer: Option<BufWriter<File>>,
+}
+
+impl JournalWriter {
+    pub fn new(directory: impl Into<PathBuf>, segment_size: u64) -> Self {
+        Self {
+            directory: directory.into(),
+            segment_size,
+            segment_index: 0,
+            segment_written: 0,
+            writer: None,
+        }
+    }
+
+    ///
+    /// 레코드를 기록한다
+    ///
+    pub fn write(&mut self, record: &JournalRecord) -> Result<(), Box<dyn Error>> {
+        let buf = rmp_serde::to_vec(record)?;
+
+        if self.segment_written + buf.len() as u64 > self.segment_size {
+            self.writer = None;
+        }
+
+        let writer = match self.writer.as_mut() {
+            Some(writer) => writer,
+            None => {
+                fs::create_dir_all(&self.directory)?;
+                self.segment_index += 1;
+                self.segment_written = 0;
+
+                let path = self.directory.join(format!(
+                    "ctm_journal_{}_{:04}.bin",
+                    Local::now().format("%Y%m%d%H%M%S"),
+                    self.segment_index
+                ));
+                log::info!("Opened journal segment. path: {}", path.display());
+                self.writer.insert(BufWriter::new(File::create(path)?))
+            }
+        };
+
+        writer.write_all(&buf)?;
+        writer.flush()?;
+        self.segment_written += buf.len() as u64;
+
+        Ok(())
+    }
+}
+
+///
+/// 저널 파일의 레코드를 모두 읽는다
+///
+pub fn read_journal(path: impl AsRef<Path>) -> Result<Vec<JournalRecord>, Box<dyn Error>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut records = vec![];
+
+    loop {
+        match rmp_serde::from_read::<_, JournalRecord>(&mut reader) {
+            Ok(record) => records.push(record),
+            Err(rmp_serde::decode::Error::InvalidMarkerRead(e))
+                if e.kind() == ErrorKind::UnexpectedEof =>
+            {
+                break
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(records)
+}
+
+fn default_cti_instance() -> String {
+    DEFAULT_CTI_INSTANCE.to_string()
+}
@@ -0,0 +1,98 @@
+use std::{collections::HashMap, error::Error, fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::cisco::pretty::agent_state_name;
+
+///
+/// 한 언어의 표시 이름
+///
+/// 상담직원 상태는 상태 이름(AVAILABLE, NOT_READY 등), 사유코드는 코드 값으로 찾는다.
+///
+#[derive(Debug, Clone, Default, Deserialize)]
+struct LocaleLabels {
+    #[serde(default)]
+    agent_states: HashMap<String, String>,
+    #[serde(default)]
+    reason_codes: HashMap<u16, String>,
+}
+
+///
+/// 클라이언트 언어별 상담직원 상태, 이석 사유코드 표시 이름 사전
+///
+/// LOCALE_FILE 에 지정된 JSON 파일
+/// (`{"ko": {"agent_states": {"AVAILABLE": "대기"}, "reason_codes": {"1": "점심"}}}`)을 읽어 생성한다.
+/// 사전에 없는 이름은 기본 표시 이름(상태 이름, REASON_CODE_FILE 의 이름)을 그대로 사용한다.
+///
+#[derive(Debug, Clone, Default)]
+pub struct LocaleDictionary {
+    locales: HashMap<String, LocaleLabels>,
+}
+
+impl LocaleDictionary {
+    ///
+    /// 설정에 지정된 파일(LOCALE_FILE)로 사전을 생성한다
+    ///
+    /// 파일이 없거나 형식이 잘못된 경우 빈 사전을 반환한다.
+    ///
+    pub fn load(path: &str) -> Self {
+        match Self::from_file(path) {
+            Ok(dictionary) => dictionary,
+            Err(e) => {
+                log::warn!(
+                    "Unable to load locale dictionary. path: {}, error: {}",
+                    path,
+                    e
+                );
+                Self::default()
+            }
+        }
+    }
+
+    ///
+    /// JSON 파일로 사전을 생성한다
+    ///
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)?;
+        let locales = serde_json::from_str::<HashMap<String, LocaleLabels>>(&text)?
+            .into_iter()
+            .map(|(locale, labels)| (locale.to_lowercase(), labels))
+            .collect();
+
+        Ok(Self { locales })
+    }
+
+    ///
+    /// 언어의 표시 이름을 찾는다
+    ///
+    /// 언어 이름은 대소문자를 구분하지 않으며, 지역이 포함된 이름(ko-KR)이 없으면 언어(ko)로 찾는다.
+    ///
+    fn labels(&self, locale: &str) -> Option<&LocaleLabels> {
+        let locale = locale.to_lowercase().replace('_', "-");
+
+        self.locales.get(&locale).or_else(|| {
+            let (language, _) = locale.split_once('-')?;
+            self.locales.get(language)
+        })
+    }
+
+    ///
+    /// 상담직원 상태의 표시 이름을 반환한다
+    ///
+    pub fn agent_state_label(&self, locale: &str, agent_state: u16) -> Option<&str> {
+        self.labels(locale)?
+            .agent_states
+            .get(agent_state_name(agent_state))
+            .map(String::as_str)
+    }
+
+    ///
+    /// 사유코드의 표시 이름을 반환한다
+    ///
+    pub fn reason_code_label(&self, locale: &str, reason_code: u16) -> Option<&str> {
+        self.labels(locale)?
+            .reason_codes
+            .get(&reason_code)
+            .map(String::as_str)
+    }
+}
@@ -0,0 +1,197 @@
+use std::{error::Error, fmt, path::Path, str::FromStr, sync::OnceLock};
+
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_log::AsLog;
+use tracing_subscriber::{
+    filter::EnvFilter,
+    fmt::{format::Writer, time::FormatTime, MakeWriter},
+    layer::{Layered, SubscriberExt},
+    reload,
+    util::SubscriberInitExt,
+    Layer, Registry,
+};
+
+use super::{config::LoggingConfig, telemetry::TelemetryLayer};
+
+///
+/// 연결 단위 span 의 target (클라이언트 연결, CTI 세션)
+///
+/// 연결이 끝날 때까지 유지되므로 OpenTelemetry 로 내보내지 않고 로그 문맥으로만 사용한다.
+///
+pub const CONNECTION_SPAN_TARGET: &str = "ctm::connection";
+
+pub(crate) const DEFAULT_LOG_LEVEL: &str = "info,ctm=debug";
+
+type LogSubscriber = Layered<Option<TelemetryLayer>, Registry>;
+type LogFilterReloader = Box<dyn Fn(EnvFilter) -> Result<(), reload::Error> + Send + Sync>;
+
+///
+/// 로그 필터 재적용 함수와 명령행에서 지정한 로그 레벨
+///
+static LOGGER: OnceLock<(LogFilterReloader, Option<String>)> = OnceLock::new();
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// 로그 출력 형식 (LOG_FORMAT)
+///
+pub enum LogFormat {
+    TEXT,
+    // 한 줄에 하나의 JSON 객체 (span 문맥 포함)
+    JSON,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "" | "text" => Ok(LogFormat::TEXT),
+            "json" => Ok(LogFormat::JSON),
+            _ => Err(format!("Unsupported log format '{}'", s)),
+        }
+    }
+}
+
+///
+/// 로그 시각 (로컬 시간, 밀리초)
+///
+struct LocalTimer;
+
+impl FormatTime for LocalTimer {
+    fn format_time(&self, w: &mut Writer<'_>) -> fmt::Result {
+        write!(
+            w,
+            "{}",
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f")
+        )
+    }
+}
+
+///
+/// tracing 으로 로거를 초기화한다
+///
+/// log 크레이트로 남긴 로그도 tracing 이벤트로 변환되어 현재 span 문맥과 함께 출력된다.
+/// 로그 필터는 명령행 인자(--log-level), LOG_LEVEL 설정, 기본값(info,ctm=debug) 순서로 적용하며
+/// `info,ctm::ctm::cti_client=trace` 처럼 모듈별로 지정할 수 있다.
+/// 표준 출력 외에 LOG_FILE 이 지정되면 일 단위로 교체되는 파일에도 기록한다.
+///
+pub fn init_logging(
+    log_level: Option<String>,
+    config: &LoggingConfig,
+    telemetry_layer: Option<TelemetryLayer>,
+) -> Result<(), Box<dyn Error>> {
+    let log_filter = log_filter(log_level.as_deref().unwrap_or(&config.level))?;
+    let max_level = log_filter.max_level_hint();
+    let (log_filter, reload_handle) = reload::Layer::new(log_filter);
+
+    let mut fmt_layers = vec![fmt_layer(config.format, std::io::stdout, true)];
+    if let Some(log_file) = config.file.as_ref() {
+        fmt_layers.push(fmt_layer(
+            config.format,
+            log_file_appender(log_file, config.file_max_files)?,
+            false,
+        ));
+    }
+
+    tracing_subscriber::registry()
+        .with(telemetry_layer)
+        .with(fmt_layers.with_filter(log_filter))
+        .try_init()?;
+    set_log_max_level(max_level);
+
+    let reloader: LogFilterReloader = Box::new(move |log_filter| reload_handle.reload(log_filter));
+    let _ = LOGGER.set((reloader, log_level));
+
+    Ok(())
+}
+
+///
+/// 다시 읽은 LOG_LEVEL 설정을 로그 필터에 적용한다
+///
+/// 명령행 인자로 로그 레벨을 지정했으면 그대로 유지하며, 로거를 초기화하지 않았으면 아무것도 하지 않는다.
+/// 출력 형식과 로그 파일은 재시작해야 바뀐다.
+///
+pub fn reload_logging(config: &LoggingConfig) -> Result<(), Box<dyn Error>> {
+    if let Some((reloader, log_level)) = LOGGER.get() {
+        let log_filter = log_filter(log_level.as_deref().unwrap_or(&config.level))?;
+        let max_level = log_filter.max_level_hint();
+        let log_filter_text = log_filter.to_string();
+
+        reloader(log_filter)?;
+        set_log_max_level(max_level);
+        log::info!("Reloaded logging configuration. level: {}", log_filter_text);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn log_filter(log_level: &str) -> Result<EnvFilter, String> {
+    EnvFilter::builder()
+        .parse(log_level)
+        .map_err(|e| format!("Invalid log level '{}' ({})", log_level, e))
+}
+
+///
+/// log 크레이트 매크로가 필터보다 상세한 로그를 만들지 않도록 최대 레벨을 맞춘다
+///
+fn set_log_max_level(max_level: Option<tracing::level_filters::LevelFilter>) {
+    log::set_max_level(
+        max_level
+            .map(|max_level| max_level.as_log())
+            .unwrap_or(log::LevelFilter::Trace),
+    );
+}
+
+fn fmt_layer<W>(
+    log_format: LogFormat,
+    writer: W,
+    ansi: bool,
+) -> Box<dyn Layer<LogSubscriber> + Send + Sync>
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_timer(LocalTimer)
+        .with_thread_names(true)
+        .with_line_number(true);
+
+    match log_format {
+        LogFormat::TEXT => fmt_layer.with_ansi(ansi).boxed(),
+        LogFormat::JSON => fmt_layer
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .boxed(),
+    }
+}
+
+///
+/// LOG_FILE(예: ./log/ctm.log) 에 일 단위로 교체되는 로그 파일
+///
+/// 파일 이름에 날짜가 붙으며(ctm.2025-01-01.log), LOG_FILE_MAX_FILES 개까지 보관한다 (0: 모두 보관).
+///
+fn log_file_appender(
+    log_file: &str,
+    max_files: usize,
+) -> Result<RollingFileAppender, Box<dyn Error>> {
+    let path = Path::new(log_file);
+    let directory = path
+        .parent()
+        .filter(|directory| !directory.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+
+    let mut builder = RollingFileAppender::builder().rotation(Rotation::DAILY);
+    if let Some(file_stem) = path.file_stem() {
+        builder = builder.filename_prefix(file_stem.to_string_lossy());
+    }
+    if let Some(extension) = path.extension() {
+        builder = builder.filename_suffix(extension.to_string_lossy());
+    }
+    if max_files > 0 {
+        builder = builder.max_log_files(max_files);
+    }
+
+    Ok(builder.build(directory)?)
+}
@@ -0,0 +1,94 @@
+use std::time::SystemTime;
+
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+
+use crate::cisco::pretty::agent_state_name;
+
+use super::{locale::LocaleDictionary, pg_clock::PgClock};
+
+///
+/// 음성 MRD ID 기본값 (Cisco_Voice)
+///
+pub const DEFAULT_VOICE_MRD_ID: i32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SimpleObject)]
+///
+/// 음성 외 미디어 라우팅 도메인(MRD: 채팅, 이메일 등)의 상담직원 상태
+///
+/// 음성 상태는 AgentInfo 의 agent_state 로 전송하고, 그 밖의 MRD 는 MRD ID 별로 따로 전송한다.
+/// state_duration 은 현재 상태 시작 시각(UNIX 시간, 초)이다.
+///
+pub struct MediaState {
+    mrd_id: i32,
+    agent_state: u16,
+    agent_state_label: String,
+    state_duration: u64,
+    reason_code: u16,
+    num_tasks: u32,
+    max_task_limit: u32,
+}
+
+impl MediaState {
+    pub fn new(mrd_id: i32, agent_state: u16) -> Self {
+        Self {
+            mrd_id,
+            agent_state,
+            agent_state_label: agent_state_name(agent_state).to_string(),
+            state_duration: 0,
+            reason_code: 0,
+            num_tasks: 0,
+            max_task_limit: 0,
+        }
+    }
+
+    pub fn get_mrd_id(&self) -> i32 {
+        self.mrd_id
+    }
+
+    pub fn get_agent_state(&self) -> u16 {
+        self.agent_state
+    }
+
+    pub fn get_reason_code(&self) -> u16 {
+        self.reason_code
+    }
+
+    pub fn get_num_tasks(&self) -> u32 {
+        self.num_tasks
+    }
+
+    pub fn get_max_task_limit(&self) -> u32 {
+        self.max_task_limit
+    }
+
+    pub fn set_state_duration(&mut self, state_duration: u32, pg_clock: &PgClock) {
+        self.state_duration = pg_clock.state_start_time(state_duration, SystemTime::now());
+    }
+
+    pub fn set_reason_code(&mut self, reason_code: u16) {
+        // LOGOUT, NOT_READY 일때만 할당
+        self.reason_code = match self.agent_state {
+            1 | 2 => reason_code,
+            _ => 0,
+        };
+    }
+
+    pub fn set_tasks(&mut self, num_tasks: u32, max_task_limit: u32) {
+        self.num_tasks = num_tasks;
+        self.max_task_limit = max_task_limit;
+    }
+
+    ///
+    /// 새 작업을 받을 수 있는지 여부 (대기 상태이고 최대 작업 수에 이르지 않음)
+    ///
+    pub fn is_available(&self) -> bool {
+        self.agent_state == 3 && (self.max_task_limit == 0 || self.num_tasks < self.max_task_limit)
+    }
+
+    pub fn localize(&mut self, locale_dictionary: &LocaleDictionary, locale: &str) {
+        if let Some(label) = locale_dictionary.agent_state_label(locale, self.agent_state) {
+            self.agent_state_label = label.to_string();
+        }
+    }
+}
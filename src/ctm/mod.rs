@@ -1,6 +1,61 @@
-pub mod cti_client;
-pub mod ctm;
 pub mod acceptor;
+pub mod agent_delta;
+pub mod agent_device_index;
 pub mod agent_info;
+pub mod agent_query;
+pub mod agent_removal;
+pub mod agent_state_datagram;
+pub mod agent_state_history;
+pub mod agent_statistics;
+pub mod bench;
+pub mod buffer_pool;
+pub mod call_batch;
+pub mod call_info;
+pub mod capture;
+pub mod channel_metrics;
+pub mod client_auth;
+pub mod client_heartbeat;
+pub mod client_info;
+pub mod client_subscription;
+pub mod command;
+pub mod config;
+pub mod cti_client;
+pub mod cti_link_status;
+pub mod cti_session;
+pub mod cti_sync_state;
+#[allow(clippy::module_inception)]
+pub mod ctm;
+pub mod field_masker;
+pub mod firehose;
+pub mod graphql;
+pub mod ha;
+pub mod ha_status;
+pub mod health;
+pub mod journal;
+pub mod locale;
+pub mod logging;
+pub mod media_state;
+pub mod net_addr;
+pub mod peer_sync;
+pub mod pending_request;
+pub mod pg_clock;
+pub mod reason_code;
+pub mod rebroadcast;
+pub mod reconnect;
+pub mod service;
+pub mod session_resume;
+pub mod shutdown;
+pub mod simulator;
+pub mod skill_group_info;
+pub mod snapshot;
+pub mod state_refresh;
+pub mod subscriber;
+pub mod supervisor_alert;
+pub mod systemd;
+pub mod team_info;
+pub mod telemetry;
+pub mod wallboard_summary;
+pub mod welcome;
 
-pub use ctm::CTM;
+pub use ctm::{CTMBuilder, CTM};
+pub use subscriber::CtmSubscriber;
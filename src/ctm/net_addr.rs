@@ -0,0 +1,87 @@
+use std::{
+    io,
+    net::{IpAddr, SocketAddr},
+};
+
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::TcpListener;
+
+///
+/// 대기 연결 큐 길이 (tokio TcpListener::bind 기본값과 같다)
+///
+const LISTEN_BACKLOG: i32 = 1024;
+
+///
+/// 대괄호로 감싼 IPv6 주소에서 대괄호를 제거한다 (`[fd00::1]` → `fd00::1`)
+///
+pub fn strip_brackets(host: &str) -> &str {
+    host.trim()
+        .strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host.trim())
+}
+
+///
+/// 호스트와 포트를 `host:port` 로 잇는다
+///
+/// IPv6 주소는 대괄호로 감싼다 (`fd00::1`, 42027 → `[fd00::1]:42027`).
+///
+pub fn join_host_port(host: &str, port: u16) -> String {
+    let host = strip_brackets(host);
+    match host.contains(':') {
+        true => format!("[{}]:{}", host, port),
+        false => format!("{}:{}", host, port),
+    }
+}
+
+///
+/// `host:port` 형식의 접속 주소를 검사한다
+///
+/// IPv6 주소는 포트와 구분할 수 있도록 대괄호로 감싸야 한다 (`[fd00::1]:5130`).
+///
+pub fn check_host_port(address: &str) -> Result<(), String> {
+    let Some((host, port)) = address.trim().rsplit_once(':') else {
+        return Err(format!(
+            "invalid address '{}' (expected host:port)",
+            address
+        ));
+    };
+    if port.parse::<u16>().is_err() {
+        return Err(format!("invalid port in address '{}'", address));
+    }
+    if host.contains(':') && !(host.starts_with('[') && host.ends_with(']')) {
+        return Err(format!(
+            "IPv6 address must be enclosed in brackets '{}' (e.g. [fd00::1]:5130)",
+            address
+        ));
+    }
+
+    Ok(())
+}
+
+///
+/// TCP 리스너 생성
+///
+/// IPv6 주소에 바인드하면 dual_stack 에 따라 IPv4 접속(IPv4-mapped 주소)도 함께 받는다.
+/// 운영체제 기본값(bindv6only)에 관계없이 동작하도록 IPV6_V6ONLY 를 직접 지정한다.
+///
+pub async fn bind_tcp_listener(
+    bind_address: &str,
+    port: u16,
+    dual_stack: bool,
+) -> io::Result<TcpListener> {
+    let host = strip_brackets(bind_address);
+    let Ok(IpAddr::V6(addr)) = host.parse::<IpAddr>() else {
+        return TcpListener::bind((host, port)).await;
+    };
+
+    let socket = Socket::new(Domain::IPV6, Type::STREAM, Some(Protocol::TCP))?;
+    socket.set_only_v6(!dual_stack)?;
+    #[cfg(not(windows))]
+    socket.set_reuse_address(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&SocketAddr::from((addr, port)).into())?;
+    socket.listen(LISTEN_BACKLOG)?;
+
+    TcpListener::from_std(socket.into())
+}
@@ -0,0 +1,475 @@
+use std::{collections::HashMap, error::Error, net::SocketAddr, sync::Arc, time::Duration};
+
+use bytes::Bytes;
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::{broadcast, mpsc},
+    time::{interval, sleep, timeout, MissedTickBehavior},
+};
+use tokio_util::codec::{Framed, LengthDelimitedCodec};
+
+use crate::cisco::ConnectionCallId;
+
+use super::{
+    acceptor::ip_acl::IpAcl,
+    agent_info::AgentInfo,
+    agent_removal::AgentRemoval,
+    call_info::{CallInfo, CallState},
+    config::PeerSyncConfig,
+    net_addr::bind_tcp_listener,
+    shutdown::ShutdownController,
+    snapshot::CtmSnapshot,
+    subscriber::CtmSubscriber,
+};
+
+// 동기화 프레임 최대 크기 (전체 상태 포함)
+const MAX_FRAME_LENGTH: usize = 64 * 1_024 * 1_024;
+
+// 변경이 없을 때 연결 유지를 위해 보내는 하트비트 간격
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
+
+// 이 시간 동안 아무것도 받지 못하면 원본 노드 연결이 끊어진 것으로 본다
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+// 복제 노드별 전송 대기 프레임 수 (넘게 밀린 노드는 연결을 끊고 다시 전체 상태를 받는다)
+const FRAME_CHANNEL_CAPACITY: usize = 4_096;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 노드 간 상태 동기화 메시지
+///
+/// 상담직원/호 상태는 변경될 때마다, 팀 구성과 스킬그룹 상태는 전체 상태로 주기적으로 전송한다.
+/// 종료된 호(CLEARED)는 받은 노드에서 제거한다.
+///
+pub enum PeerSyncMessage {
+    Snapshot(CtmSnapshot),
+    AgentState(AgentInfo),
+    AgentRemoved(AgentRemoval),
+    CallState(CallInfo),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 동기화 프레임 (4바이트 길이 + MessagePack)
+///
+/// 변경마다 일련번호가 1씩 증가하며, 접속 직후에는 현재 일련번호의 전체 상태를 받는다.
+///
+pub enum PeerSyncFrame {
+    Heartbeat,
+    Update {
+        sequence: u64,
+        message: Box<PeerSyncMessage>,
+    },
+}
+
+impl PeerSyncFrame {
+    pub fn encode(&self) -> Result<Bytes, rmp_serde::encode::Error> {
+        Ok(rmp_serde::to_vec_named(self)?.into())
+    }
+
+    pub fn decode(buf: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(buf)
+    }
+}
+
+///
+/// 동기화 원본 노드가 복제 노드에 보낼 현재 상태
+///
+/// 새로 접속한 복제 노드에 보낼 전체 상태를 만들기 위해 보낸 메시지를 모두 반영해 둔다.
+///
+#[derive(Debug, Default)]
+pub struct PeerSyncState {
+    snapshot: CtmSnapshot,
+    agent_infos: HashMap<(String, String), AgentInfo>,
+    call_infos: HashMap<(String, ConnectionCallId), CallInfo>,
+}
+
+impl PeerSyncState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn apply(&mut self, message: &PeerSyncMessage) {
+        match message {
+            PeerSyncMessage::Snapshot(snapshot) => {
+                self.agent_infos = snapshot
+                    .agent_infos
+                    .iter()
+                    .map(|agent_info| (agent_key(agent_info), agent_info.clone()))
+                    .collect();
+                self.call_infos = snapshot
+                    .call_infos
+                    .iter()
+                    .map(|call_info| (call_key(call_info), call_info.clone()))
+                    .collect();
+                self.snapshot = CtmSnapshot {
+                    team_infos: snapshot.team_infos.clone(),
+                    skill_group_agent_map: snapshot.skill_group_agent_map.clone(),
+                    ..Default::default()
+                };
+            }
+            PeerSyncMessage::AgentState(agent_info) => {
+                self.agent_infos
+                    .insert(agent_key(agent_info), agent_info.clone());
+            }
+            PeerSyncMessage::AgentRemoved(agent_removal) => {
+                self.agent_infos.remove(&(
+                    agent_removal.get_cti_instance().to_string(),
+                    agent_removal.get_agent_id().to_string(),
+                ));
+            }
+            PeerSyncMessage::CallState(call_info) => match call_info.get_call_state() {
+                CallState::CLEARED => {
+                    self.call_infos.remove(&call_key(call_info));
+                }
+                _ => {
+                    self.call_infos
+                        .insert(call_key(call_info), call_info.clone());
+                }
+            },
+        }
+    }
+
+    pub fn snapshot(&self) -> CtmSnapshot {
+        CtmSnapshot {
+            agent_infos: self.agent_infos.values().cloned().collect(),
+            call_infos: self.call_infos.values().cloned().collect(),
+            ..self.snapshot.clone()
+        }
+    }
+}
+
+fn agent_key(agent_info: &AgentInfo) -> (String, String) {
+    (
+        agent_info.get_cti_instance().to_string(),
+        agent_info.get_agent_id().to_string(),
+    )
+}
+
+fn call_key(call_info: &CallInfo) -> (String, ConnectionCallId) {
+    (
+        call_info.get_cti_instance().to_string(),
+        call_info.get_connection_call_id(),
+    )
+}
+
+///
+/// CTM 상태 변경을 동기화 서버에 넘기는 구독자
+///
+/// 콜백은 CTM 이벤트 루프에서 호출되므로 대기 없이 채널에 넣기만 한다.
+///
+#[derive(Debug, Clone)]
+pub struct PeerSyncPublisher {
+    message_tx: mpsc::UnboundedSender<PeerSyncMessage>,
+}
+
+impl PeerSyncPublisher {
+    ///
+    /// 전체 상태 전송 (팀 구성, 스킬그룹 상태를 맞추고 누락된 변경을 바로잡는다)
+    ///
+    pub fn publish_snapshot(&self, snapshot: CtmSnapshot) {
+        let _ = self.message_tx.send(PeerSyncMessage::Snapshot(snapshot));
+    }
+}
+
+impl CtmSubscriber for PeerSyncPublisher {
+    fn on_agent_state(&self, agent_info: &AgentInfo) {
+        let _ = self
+            .message_tx
+            .send(PeerSyncMessage::AgentState(agent_info.clone()));
+    }
+
+    fn on_agent_removed(&self, agent_removal: &AgentRemoval) {
+        let _ = self
+            .message_tx
+            .send(PeerSyncMessage::AgentRemoved(agent_removal.clone()));
+    }
+
+    fn on_call_state(&self, call_info: &CallInfo) {
+        let _ = self
+            .message_tx
+            .send(PeerSyncMessage::CallState(call_info.clone()));
+    }
+}
+
+///
+/// 상태 동기화 서버
+///
+/// PEER_SYNC_PORT 로 접속한 복제 노드(HA 대기 노드, 다른 센터의 읽기 전용 노드)에 전체 상태를 보낸 뒤
+/// 상태 변경을 이어서 전송한다. PEER_SYNC_ALLOWED_IPS 로 접속할 수 있는 노드를 제한할 수 있다.
+///
+pub struct PeerSyncServer {
+    listener: TcpListener,
+    ip_acl: IpAcl,
+    publisher: Arc<PeerSyncPublisher>,
+    message_rx: mpsc::UnboundedReceiver<PeerSyncMessage>,
+}
+
+impl PeerSyncServer {
+    pub async fn bind(config: &PeerSyncConfig) -> Result<Self, Box<dyn Error>> {
+        let listener = bind_tcp_listener(&config.bind_address, config.port, true).await?;
+        let (message_tx, message_rx) = mpsc::unbounded_channel();
+
+        Ok(Self {
+            listener,
+            ip_acl: config.allowed_ips.clone(),
+            publisher: Arc::new(PeerSyncPublisher { message_tx }),
+            message_rx,
+        })
+    }
+
+    pub fn get_local_addr(&self) -> Result<SocketAddr, Box<dyn Error>> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    ///
+    /// CTM 구독자로 등록할 상태 변경 발행기
+    ///
+    pub fn get_publisher(&self) -> Arc<PeerSyncPublisher> {
+        self.publisher.clone()
+    }
+
+    ///
+    /// 복제 노드 접속 수신과 상태 전송을 시작한다
+    ///
+    pub fn start(self, shutdown_controller: ShutdownController) {
+        log::info!(
+            "Peer sync server started. local_addr: {:?}",
+            self.listener.local_addr()
+        );
+        tokio::spawn(self.serve(shutdown_controller));
+    }
+
+    async fn serve(mut self, shutdown_controller: ShutdownController) {
+        let mut state = PeerSyncState::new();
+        let mut sequence = 0;
+        let (frame_tx, _) = broadcast::channel::<Bytes>(FRAME_CHANNEL_CAPACITY);
+
+        loop {
+            // 접속 전에 들어온 변경이 전체 상태에 먼저 반영되도록 변경을 우선 처리한다
+            tokio::select! {
+                biased;
+                Some(message) = self.message_rx.recv() => {
+                    state.apply(&message);
+                    sequence += 1;
+
+                    // 복제 노드가 여럿이어도 한 번만 직렬화한다
+                    match (PeerSyncFrame::Update {
+                        sequence,
+                        message: Box::new(message),
+                    })
+                    .encode() {
+                        Ok(frame) => {
+                            let _ = frame_tx.send(frame);
+                        }
+                        Err(e) => log::error!("Unable to serialize peer sync frame. {}", e),
+                    }
+                }
+                accepted = self.listener.accept() => {
+                    let (stream, addr) = match accepted {
+                        Ok(accepted) => accepted,
+                        Err(e) => {
+                            log::error!("Unable to accept peer sync replica. {}", e);
+                            continue;
+                        }
+                    };
+                    if !self.ip_acl.allows(addr.ip()) {
+                        log::warn!("Peer sync replica rejected by IP ACL. peer_addr: {}", addr);
+                        continue;
+                    }
+
+                    // 전체 상태와 이후 변경이 빠짐없이 이어지도록 같은 태스크에서 만든다
+                    let snapshot = PeerSyncFrame::Update {
+                        sequence,
+                        message: Box::new(PeerSyncMessage::Snapshot(state.snapshot())),
+                    };
+                    let snapshot = match snapshot.encode() {
+                        Ok(snapshot) => snapshot,
+                        Err(e) => {
+                            log::error!("Unable to serialize peer sync snapshot. {}", e);
+                            continue;
+                        }
+                    };
+                    log::info!(
+                        "Peer sync replica connected. peer_addr: {}, sequence: {}",
+                        addr,
+                        sequence
+                    );
+                    tokio::spawn(Self::replicate(
+                        stream,
+                        addr,
+                        snapshot,
+                        frame_tx.subscribe(),
+                        shutdown_controller.clone(),
+                    ));
+                }
+                _ = shutdown_controller.wait() => return,
+            }
+        }
+    }
+
+    ///
+    /// 복제 노드에 전체 상태와 상태 변경을 전송한다
+    ///
+    async fn replicate(
+        stream: TcpStream,
+        addr: SocketAddr,
+        snapshot: Bytes,
+        mut frame_rx: broadcast::Receiver<Bytes>,
+        shutdown_controller: ShutdownController,
+    ) {
+        let _ = stream.set_nodelay(true);
+        let mut stream = Framed::new(stream, codec());
+        if let Err(e) = stream.send(snapshot).await {
+            log::warn!(
+                "Unable to send peer sync snapshot. peer_addr: {}, error: {}",
+                addr,
+                e
+            );
+            return;
+        }
+
+        let heartbeat = match PeerSyncFrame::Heartbeat.encode() {
+            Ok(heartbeat) => heartbeat,
+            Err(e) => {
+                log::error!("Unable to serialize peer sync heartbeat. {}", e);
+                return;
+            }
+        };
+        let mut heartbeat_interval = interval(HEARTBEAT_INTERVAL);
+        heartbeat_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            let frame = tokio::select! {
+                frame = frame_rx.recv() => match frame {
+                    Ok(frame) => frame,
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        log::warn!(
+                            "Peer sync replica lagged. Disconnecting to resync. peer_addr: {}, skipped: {}",
+                            addr,
+                            n
+                        );
+                        return;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return,
+                },
+                _ = heartbeat_interval.tick() => heartbeat.clone(),
+                _ = shutdown_controller.wait() => return,
+            };
+
+            if let Err(e) = stream.send(frame).await {
+                log::info!(
+                    "Peer sync replica disconnected. peer_addr: {}, error: {}",
+                    addr,
+                    e
+                );
+                return;
+            }
+        }
+    }
+}
+
+///
+/// 상태 동기화 클라이언트
+///
+/// PEER_SYNC_SOURCES 의 노드에 차례로 접속해 상태를 받는다. 연결이 끊기거나 일련번호가 이어지지 않으면
+/// 다음 노드에 다시 접속해 전체 상태부터 받는다.
+///
+pub struct PeerSyncClient {
+    sources: Vec<String>,
+    reconnect_interval: Duration,
+}
+
+impl PeerSyncClient {
+    pub fn new(config: &PeerSyncConfig) -> Self {
+        Self {
+            sources: config.sources.clone(),
+            reconnect_interval: config.reconnect_interval,
+        }
+    }
+
+    ///
+    /// 받은 동기화 메시지를 peer_sync_tx 로 전달한다
+    ///
+    pub fn start(
+        self,
+        peer_sync_tx: mpsc::Sender<PeerSyncMessage>,
+        shutdown_controller: ShutdownController,
+    ) {
+        log::info!("Peer sync client started. sources: {:?}", self.sources);
+        tokio::spawn(self.follow(peer_sync_tx, shutdown_controller));
+    }
+
+    async fn follow(
+        self,
+        peer_sync_tx: mpsc::Sender<PeerSyncMessage>,
+        shutdown_controller: ShutdownController,
+    ) {
+        for source in self.sources.iter().cycle() {
+            tokio::select! {
+                result = Self::receive(source, self.reconnect_interval, &peer_sync_tx) => {
+                    if let Err(e) = result {
+                        log::warn!("Peer sync source disconnected. source: {}, error: {}", source, e);
+                    }
+                }
+                _ = shutdown_controller.wait() => return,
+            }
+            if peer_sync_tx.is_closed() {
+                return;
+            }
+
+            tokio::select! {
+                _ = sleep(self.reconnect_interval) => {}
+                _ = shutdown_controller.wait() => return,
+            }
+        }
+    }
+
+    async fn receive(
+        source: &str,
+        connect_timeout: Duration,
+        peer_sync_tx: &mpsc::Sender<PeerSyncMessage>,
+    ) -> Result<(), Box<dyn Error>> {
+        let stream = timeout(connect_timeout, TcpStream::connect(source)).await??;
+        let _ = stream.set_nodelay(true);
+        log::info!("Connected to peer sync source. source: {}", source);
+
+        let mut stream = Framed::new(stream, codec());
+        let mut last_sequence: Option<u64> = None;
+        loop {
+            let frame = match timeout(READ_TIMEOUT, stream.next()).await? {
+                Some(frame) => PeerSyncFrame::decode(&frame?)?,
+                None => return Err("connection closed".into()),
+            };
+
+            let PeerSyncFrame::Update { sequence, message } = frame else {
+                continue;
+            };
+            match (message.as_ref(), last_sequence) {
+                (PeerSyncMessage::Snapshot(_), _) | (_, None) => {}
+                (_, Some(last_sequence)) if sequence == last_sequence + 1 => {}
+                (_, Some(last_sequence)) => {
+                    return Err(format!(
+                        "sequence gap (expected: {}, received: {})",
+                        last_sequence + 1,
+                        sequence
+                    )
+                    .into())
+                }
+            }
+            last_sequence = Some(sequence);
+
+            if peer_sync_tx.send(*message).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn codec() -> LengthDelimitedCodec {
+    LengthDelimitedCodec::builder()
+        .max_frame_length(MAX_FRAME_LENGTH)
+        .new_codec()
+}
@@ -0,0 +1,169 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use crate::cisco::{InvokeId, MessageType};
+
+///
+/// 응답을 기다리는 CTI 요청
+///
+#[derive(Debug, Clone)]
+pub struct PendingRequest<T> {
+    message_type: MessageType,
+    request: T,
+    timeout: Duration,
+    deadline: Instant,
+    retries_left: u32,
+}
+
+impl<T> PendingRequest<T> {
+    pub fn get_message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+
+    pub fn get_request(&self) -> &T {
+        &self.request
+    }
+
+    pub fn get_deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    pub fn get_retries_left(&self) -> u32 {
+        self.retries_left
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+///
+/// 응답 대기 시간이 지난 CTI 요청 처리
+///
+pub enum PendingRequestTimeout<T> {
+    // 재시도 횟수가 남아 있어 같은 InvokeID 로 다시 전송해야 함
+    RETRY {
+        invoke_id: InvokeId,
+        message_type: MessageType,
+        request: T,
+    },
+    // 재시도 횟수를 모두 사용하여 더 이상 기다리지 않음
+    TIMED_OUT {
+        invoke_id: InvokeId,
+        message_type: MessageType,
+        request: T,
+    },
+}
+
+///
+/// InvokeID 별 응답 대기 요청 목록
+///
+/// 요청마다 응답 제한 시간과 재시도 횟수를 두고, 응답(CONF)을 받으면 complete 로 제거한다.
+/// expire 는 제한 시간이 지난 요청을 재시도 또는 시간 초과로 분류해 반환한다.
+///
+#[derive(Debug, Clone)]
+pub struct PendingRequests<T> {
+    requests: HashMap<InvokeId, PendingRequest<T>>,
+}
+
+impl<T> Default for PendingRequests<T> {
+    fn default() -> Self {
+        Self {
+            requests: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Clone> PendingRequests<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    pub fn contains(&self, invoke_id: &InvokeId) -> bool {
+        self.requests.contains_key(invoke_id)
+    }
+
+    ///
+    /// 응답 대기 요청 등록
+    ///
+    pub fn insert(
+        &mut self,
+        invoke_id: InvokeId,
+        message_type: MessageType,
+        request: T,
+        timeout: Duration,
+        retries: u32,
+        now: Instant,
+    ) {
+        self.requests.insert(
+            invoke_id,
+            PendingRequest {
+                message_type,
+                request,
+                timeout,
+                deadline: now + timeout,
+                retries_left: retries,
+            },
+        );
+    }
+
+    ///
+    /// 응답을 받은 요청 제거
+    ///
+    pub fn complete(&mut self, invoke_id: &InvokeId) -> Option<PendingRequest<T>> {
+        self.requests.remove(invoke_id)
+    }
+
+    ///
+    /// 응답 대기 시간이 지난 요청 처리
+    ///
+    /// 재시도할 요청은 제한 시간을 새로 잡아 목록에 남기고, 시간 초과된 요청은 목록에서 제거한다.
+    ///
+    pub fn expire(&mut self, now: Instant) -> Vec<PendingRequestTimeout<T>> {
+        let mut expired = Vec::new();
+
+        self.requests.retain(|invoke_id, pending_request| {
+            if now < pending_request.deadline {
+                return true;
+            }
+
+            match pending_request.retries_left {
+                0 => {
+                    expired.push(PendingRequestTimeout::TIMED_OUT {
+                        invoke_id: *invoke_id,
+                        message_type: pending_request.message_type.clone(),
+                        request: pending_request.request.clone(),
+                    });
+                    false
+                }
+                _ => {
+                    pending_request.retries_left -= 1;
+                    pending_request.deadline = now + pending_request.timeout;
+                    expired.push(PendingRequestTimeout::RETRY {
+                        invoke_id: *invoke_id,
+                        message_type: pending_request.message_type.clone(),
+                        request: pending_request.request.clone(),
+                    });
+                    true
+                }
+            }
+        });
+
+        expired
+    }
+
+    ///
+    /// 모든 응답 대기 요청 제거
+    ///
+    pub fn clear(&mut self) {
+        self.requests.clear();
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+///
+/// PG 시계 기준 시각 계산
+///
+/// CTI 서버는 상태 지속 시간(초)만 알려주므로 현재 시각에서 빼서 상태 시작 시각을 구한다.
+/// offset 은 로컬 시계에 더할 보정값(PG 시계 - 로컬 시계, 초)이며, NTP 동기화가 되지 않은
+/// 서버에서 PG 시계에 맞출 때 사용한다. skew_tolerance 를 넘게 PG 시계와 차이가 나면 시계 오차로 본다.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PgClock {
+    offset: i64,
+    skew_tolerance: Option<Duration>,
+}
+
+impl PgClock {
+    pub fn new(offset: i64, skew_tolerance: Option<Duration>) -> Self {
+        Self {
+            offset,
+            skew_tolerance,
+        }
+    }
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
+    }
+
+    ///
+    /// 보정한 현재 시각 (UNIX 시간, 초)
+    ///
+    pub fn epoch(&self, now: SystemTime) -> u64 {
+        now.duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+            .saturating_add_signed(self.offset)
+    }
+
+    ///
+    /// 상태 시작 시각 (UNIX 시간, 초)
+    ///
+    /// 지속 시간이 현재 시각보다 크면 시계 오차로 보고 UNIX 시간 0 으로 맞춘다.
+    ///
+    pub fn state_start_time(&self, state_duration: u32, now: SystemTime) -> u64 {
+        let epoch = self.epoch(now);
+
+        match epoch.checked_sub(state_duration as u64) {
+            Some(state_start_time) => state_start_time,
+            None => {
+                log::warn!(
+                    "State duration exceeds current time. state_duration: {}, epoch: {}, offset: {}",
+                    state_duration,
+                    epoch,
+                    self.offset
+                );
+                0
+            }
+        }
+    }
+
+    ///
+    /// PG 시계와의 차이 (PG 시계 - 보정한 현재 시각, 초)
+    ///
+    pub fn skew(&self, pg_time: u32, now: SystemTime) -> i64 {
+        pg_time as i64 - self.epoch(now).min(i64::MAX as u64) as i64
+    }
+
+    ///
+    /// PG 시계와의 차이가 허용 범위를 넘는지 확인한다 (허용 범위가 없으면 항상 false)
+    ///
+    pub fn is_skewed(&self, pg_time: u32, now: SystemTime) -> bool {
+        self.skew_tolerance.is_some_and(|skew_tolerance| {
+            self.skew(pg_time, now).unsigned_abs() > skew_tolerance.as_secs()
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use std::{collections::HashMap, error::Error, fs, path::Path};
+
+///
+/// 이석 사유코드 → 표시 이름 사전
+///
+/// REASON_CODE_FILE 에 지정된 JSON 파일(`{"1": "Lunch", "2": "Meeting"}`)을 읽어 생성한다.
+///
+#[derive(Debug, Clone, Default)]
+pub struct ReasonCodeDictionary {
+    labels: HashMap<u16, String>,
+}
+
+impl ReasonCodeDictionary {
+    ///
+    /// 설정에 지정된 파일(REASON_CODE_FILE)로 사전을 생성한다
+    ///
+    /// 파일이 없거나 형식이 잘못된 경우 빈 사전을 반환한다.
+    ///
+    pub fn load(path: &str) -> Self {
+        match Self::from_file(path) {
+            Ok(dictionary) => dictionary,
+            Err(e) => {
+                log::warn!(
+                    "Unable to load reason code dictionary. path: {}, error: {}",
+                    path,
+                    e
+                );
+                Self::default()
+            }
+        }
+    }
+
+    ///
+    /// JSON 파일로 사전을 생성한다
+    ///
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)?;
+        let labels = serde_json::from_str::<HashMap<u16, String>>(&text)?;
+
+        Ok(Self { labels })
+    }
+
+    ///
+    /// 사유코드의 표시 이름을 반환한다
+    ///
+    pub fn label(&self, reason_code: u16) -> Option<&str> {
+        self.labels.get(&reason_code).map(String::as_str)
+    }
+}
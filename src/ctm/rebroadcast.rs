@@ -0,0 +1,84 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use uuid::Uuid;
+
+use super::reconnect::jitter_sample;
+
+///
+/// 전체 상태 주기 재전송 일정
+///
+/// 변경분을 놓친 클라이언트도 결국 같은 상태가 되도록 interval 마다 전체 상태를 다시 보낸다.
+/// 분산 전송을 사용하면 클라이언트마다 접속 시각과 임의 지연을 더한 별도 일정으로 보내,
+/// 대규모 환경에서 모든 클라이언트에 한꺼번에 전체 상태를 보내지 않는다.
+///
+#[derive(Debug)]
+pub struct FullStateRebroadcast {
+    interval: Duration,
+    stagger: bool,
+    last_broadcast: Instant,
+    // 클라이언트별 다음 전송 시각 (분산 전송을 사용하지 않으면 값은 사용하지 않는다)
+    next_broadcast_map: HashMap<Uuid, Instant>,
+}
+
+impl FullStateRebroadcast {
+    pub fn new(interval: Duration, stagger: bool, now: Instant) -> Self {
+        Self {
+            interval,
+            stagger,
+            last_broadcast: now,
+            next_broadcast_map: HashMap::new(),
+        }
+    }
+
+    pub fn get_interval(&self) -> Duration {
+        self.interval
+    }
+
+    pub fn is_stagger(&self) -> bool {
+        self.stagger
+    }
+
+    ///
+    /// 접속한 클라이언트를 일정에 추가한다
+    ///
+    /// 접속 시 전체 상태를 받으므로 첫 재전송은 interval 이후, 분산 전송이면 최대 interval 만큼 더 늦게 보낸다.
+    ///
+    pub fn add_client(&mut self, id: Uuid, now: Instant) {
+        let next_broadcast = match self.stagger {
+            true => now + self.interval + self.interval.mul_f64(jitter_sample()),
+            false => now + self.interval,
+        };
+        self.next_broadcast_map.insert(id, next_broadcast);
+    }
+
+    pub fn remove_client(&mut self, id: &Uuid) {
+        self.next_broadcast_map.remove(id);
+    }
+
+    ///
+    /// 전체 상태를 다시 보낼 클라이언트 ID 를 반환하고 다음 전송 시각을 정한다
+    ///
+    pub fn due_clients(&mut self, now: Instant) -> Vec<Uuid> {
+        if !self.stagger {
+            if now.saturating_duration_since(self.last_broadcast) < self.interval {
+                return vec![];
+            }
+            self.last_broadcast = now;
+
+            return self.next_broadcast_map.keys().copied().collect();
+        }
+
+        let interval = self.interval;
+        self.next_broadcast_map
+            .iter_mut()
+            .filter(|(_, next_broadcast)| **next_broadcast <= now)
+            .map(|(id, next_broadcast)| {
+                *next_broadcast = now + interval;
+                *id
+            })
+            .collect()
+    }
+}
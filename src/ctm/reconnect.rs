@@ -0,0 +1,136 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+///
+/// CTI 서버 재접속 정책
+///
+/// 접속 실패가 이어질수록 대기 시간을 initial_delay 부터 두 배씩 늘리며(최대 max_delay),
+/// 여러 CTI 클라이언트가 한꺼번에 재접속하지 않도록 대기 시간의 ±jitter 비율만큼 흔든다.
+/// 같은 쪽(A/B)에서 failover_threshold 번 연속으로 실패하면 반대쪽으로 전환한다.
+///
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    initial_delay: Duration,
+    max_delay: Duration,
+    jitter: f64,
+    failover_threshold: u32,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self::new(
+            Duration::from_millis(500),
+            Duration::from_millis(30_000),
+            0.2,
+            3,
+        )
+    }
+}
+
+impl ReconnectPolicy {
+    pub fn new(
+        initial_delay: Duration,
+        max_delay: Duration,
+        jitter: f64,
+        failover_threshold: u32,
+    ) -> Self {
+        Self {
+            initial_delay,
+            max_delay: max_delay.max(initial_delay),
+            jitter: match jitter.is_finite() {
+                true => jitter.clamp(0.0, 1.0),
+                false => 0.0,
+            },
+            failover_threshold: failover_threshold.max(1),
+        }
+    }
+
+    pub fn get_initial_delay(&self) -> Duration {
+        self.initial_delay
+    }
+
+    pub fn get_max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    pub fn get_jitter(&self) -> f64 {
+        self.jitter
+    }
+
+    pub fn get_failover_threshold(&self) -> u32 {
+        self.failover_threshold
+    }
+
+    ///
+    /// attempt 번째(0 부터) 재접속 전 대기 시간
+    ///
+    /// jitter_sample 은 0.0 ~ 1.0 사이 값이며, 0.5 이면 지터를 적용하지 않은 대기 시간이다.
+    ///
+    pub fn delay(&self, attempt: u32, jitter_sample: f64) -> Duration {
+        let delay = self
+            .initial_delay
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let factor = 1.0 - self.jitter + 2.0 * self.jitter * jitter_sample.clamp(0.0, 1.0);
+
+        delay.mul_f64(factor).min(self.max_delay)
+    }
+}
+
+///
+/// CTI 서버 이중화 쌍의 재접속 상태
+///
+#[derive(Debug, Clone, Default)]
+pub struct ReconnectState {
+    attempt: u32,
+    side_failures: u32,
+}
+
+impl ReconnectState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    pub fn get_side_failures(&self) -> u32 {
+        self.side_failures
+    }
+
+    ///
+    /// 접속 실패 기록
+    ///
+    /// 다음 재접속까지 대기 시간과 반대쪽으로 전환해야 하는지 여부를 반환한다.
+    ///
+    pub fn on_failure(&mut self, reconnect_policy: &ReconnectPolicy) -> (Duration, bool) {
+        let delay = reconnect_policy.delay(self.attempt, jitter_sample());
+        self.attempt = self.attempt.saturating_add(1);
+        self.side_failures += 1;
+
+        let failover = self.side_failures >= reconnect_policy.get_failover_threshold();
+        if failover {
+            self.side_failures = 0;
+        }
+
+        (delay, failover)
+    }
+
+    ///
+    /// 접속 성공 시 상태 초기화
+    ///
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+///
+/// 0.0 ~ 1.0 사이 난수 (지터 용도)
+///
+pub(crate) fn jitter_sample() -> f64 {
+    RandomState::new().build_hasher().finish() as f64 / u64::MAX as f64
+}
@@ -0,0 +1,262 @@
+use std::{error::Error, ffi::OsString};
+
+use super::shutdown::ShutdownController;
+
+///
+/// Windows 서비스 이름 (sc.exe, 서비스 관리자에서 사용)
+///
+pub const SERVICE_NAME: &str = "ctm";
+
+///
+/// 서비스로 실행할 CTM 본체
+///
+/// 서비스 관리자의 중지 요청을 받으면 전달된 종료 제어기로 종료를 요청한다.
+///
+pub type ServiceMain = Box<dyn FnOnce(ShutdownController) -> Result<(), Box<dyn Error>> + Send>;
+
+///
+/// 현재 실행 파일을 Windows 서비스로 등록한다 (자동 시작, 비정상 종료 시 재시작)
+///
+/// 서비스 관리자는 `launch_arguments` 를 붙여 실행 파일을 실행한다.
+///
+pub fn install_service(launch_arguments: Vec<OsString>) -> Result<(), Box<dyn Error>> {
+    platform::install_service(launch_arguments)
+}
+
+///
+/// 서비스를 중지하고 등록을 해제한다
+///
+pub fn uninstall_service() -> Result<(), Box<dyn Error>> {
+    platform::uninstall_service()
+}
+
+pub fn start_service() -> Result<(), Box<dyn Error>> {
+    platform::start_service()
+}
+
+pub fn stop_service() -> Result<(), Box<dyn Error>> {
+    platform::stop_service()
+}
+
+///
+/// 서비스 관리자에 연결해 CTM 을 실행한다
+///
+/// 서비스 관리자가 실행한 프로세스에서만 호출할 수 있으며, 서비스가 중지될 때까지 반환되지 않는다.
+/// 서비스는 System32 에서 실행되므로 실행 파일이 있는 폴더를 작업 폴더로 사용한다 (.env, res/ 경로).
+///
+pub fn run_service(service_main: ServiceMain) -> Result<(), Box<dyn Error>> {
+    platform::run_service(service_main)
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::{error::Error, ffi::OsString, sync::Mutex, time::Duration};
+
+    use windows_service::{
+        define_windows_service,
+        service::{
+            ServiceAccess, ServiceAction, ServiceActionType, ServiceControl, ServiceControlAccept,
+            ServiceErrorControl, ServiceExitCode, ServiceFailureActions, ServiceFailureResetPeriod,
+            ServiceInfo, ServiceStartType, ServiceState, ServiceStatus, ServiceType,
+        },
+        service_control_handler::{self, ServiceControlHandlerResult, ServiceStatusHandle},
+        service_dispatcher,
+        service_manager::{ServiceManager, ServiceManagerAccess},
+    };
+
+    use super::{ServiceMain, SERVICE_NAME};
+    use crate::ctm::shutdown::ShutdownController;
+
+    const SERVICE_DISPLAY_NAME: &str = "CTM";
+
+    const SERVICE_DESCRIPTION: &str = "Cisco CTI agent state monitor";
+
+    // 비정상 종료 후 재시작 대기 시간
+    const RESTART_DELAY: Duration = Duration::from_secs(5);
+
+    // 서비스 관리자가 호출하는 진입점에 넘길 CTM 본체
+    static SERVICE_MAIN: Mutex<Option<ServiceMain>> = Mutex::new(None);
+
+    define_windows_service!(ffi_service_main, service_main);
+
+    pub fn install_service(launch_arguments: Vec<OsString>) -> Result<(), Box<dyn Error>> {
+        let service_manager = ServiceManager::local_computer(
+            None::<&str>,
+            ServiceManagerAccess::CONNECT | ServiceManagerAccess::CREATE_SERVICE,
+        )?;
+        let service_info = ServiceInfo {
+            name: SERVICE_NAME.into(),
+            display_name: SERVICE_DISPLAY_NAME.into(),
+            service_type: ServiceType::OWN_PROCESS,
+            start_type: ServiceStartType::AutoStart,
+            error_control: ServiceErrorControl::Normal,
+            executable_path: std::env::current_exe()?,
+            launch_arguments,
+            dependencies: vec![],
+            // LocalSystem 계정으로 실행
+            account_name: None,
+            account_password: None,
+        };
+        let service = service_manager.create_service(
+            &service_info,
+            ServiceAccess::CHANGE_CONFIG | ServiceAccess::START,
+        )?;
+        service.set_description(SERVICE_DESCRIPTION)?;
+        service.update_failure_actions(ServiceFailureActions {
+            reset_period: ServiceFailureResetPeriod::After(Duration::from_secs(86400)),
+            reboot_msg: None,
+            command: None,
+            actions: Some(vec![
+                ServiceAction {
+                    action_type: ServiceActionType::Restart,
+                    delay: RESTART_DELAY,
+                };
+                3
+            ]),
+        })?;
+        // 오류로 종료 코드를 남기고 멈춘 경우에도 재시작한다
+        service.set_failure_actions_on_non_crash_failures(true)?;
+
+        Ok(())
+    }
+
+    pub fn uninstall_service() -> Result<(), Box<dyn Error>> {
+        let service_manager =
+            ServiceManager::local_computer(None::<&str>, ServiceManagerAccess::CONNECT)?;
+        let service = service_manager.open_service(
+            SERVICE_NAME,
+            ServiceAccess::QUERY_STATUS | ServiceAccess::STOP | ServiceAccess::DELETE,
+        )?;
+
+        // 실행 중이면 중지된 뒤 삭제된다
+        service.delete()?;
+        if service.query_status()?.current_state != ServiceState::Stopped {
+            service.stop()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn start_service() -> Result<(), Box<dyn Error>> {
+        let service_manager =
+            ServiceManager::local_computer(None::<&str>, ServiceManagerAccess::CONNECT)?;
+        let service = service_manager.open_service(SERVICE_NAME, ServiceAccess::START)?;
+        service.start::<&str>(&[])?;
+
+        Ok(())
+    }
+
+    pub fn stop_service() -> Result<(), Box<dyn Error>> {
+        let service_manager =
+            ServiceManager::local_computer(None::<&str>, ServiceManagerAccess::CONNECT)?;
+        let service = service_manager.open_service(SERVICE_NAME, ServiceAccess::STOP)?;
+        service.stop()?;
+
+        Ok(())
+    }
+
+    pub fn run_service(service_main: ServiceMain) -> Result<(), Box<dyn Error>> {
+        if let Some(directory) = std::env::current_exe()?.parent() {
+            std::env::set_current_dir(directory)?;
+        }
+        *SERVICE_MAIN.lock().unwrap() = Some(service_main);
+
+        service_dispatcher::start(SERVICE_NAME, ffi_service_main)?;
+
+        Ok(())
+    }
+
+    fn service_main(_arguments: Vec<OsString>) {
+        if let Err(e) = run_service_main() {
+            log::error!("Windows service stopped with error. {}", e);
+        }
+    }
+
+    fn run_service_main() -> Result<(), Box<dyn Error>> {
+        let shutdown_controller = ShutdownController::new();
+        let event_handler = {
+            let shutdown_controller = shutdown_controller.clone();
+            move |control_event: ServiceControl| match control_event {
+                ServiceControl::Interrogate => ServiceControlHandlerResult::NoError,
+                ServiceControl::Stop | ServiceControl::Shutdown => {
+                    log::info!("Received service stop request. Shutting down gracefully");
+                    shutdown_controller.shutdown();
+                    ServiceControlHandlerResult::NoError
+                }
+                _ => ServiceControlHandlerResult::NotImplemented,
+            }
+        };
+        let status_handle = service_control_handler::register(SERVICE_NAME, event_handler)?;
+        set_service_status(
+            &status_handle,
+            ServiceState::Running,
+            ServiceExitCode::NO_ERROR,
+        )?;
+
+        let service_main = SERVICE_MAIN.lock().unwrap().take();
+        let result = match service_main {
+            Some(service_main) => service_main(shutdown_controller),
+            None => Ok(()),
+        };
+
+        // 오류로 끝난 경우 서비스 관리자가 재시작하도록 종료 코드를 남긴다
+        let exit_code = match &result {
+            Ok(()) => ServiceExitCode::NO_ERROR,
+            Err(_) => ServiceExitCode::ServiceSpecific(1),
+        };
+        set_service_status(&status_handle, ServiceState::Stopped, exit_code)?;
+
+        result
+    }
+
+    fn set_service_status(
+        status_handle: &ServiceStatusHandle,
+        current_state: ServiceState,
+        exit_code: ServiceExitCode,
+    ) -> Result<(), Box<dyn Error>> {
+        let controls_accepted = match current_state {
+            ServiceState::Running => ServiceControlAccept::STOP | ServiceControlAccept::SHUTDOWN,
+            _ => ServiceControlAccept::empty(),
+        };
+        status_handle.set_service_status(ServiceStatus {
+            service_type: ServiceType::OWN_PROCESS,
+            current_state,
+            controls_accepted,
+            exit_code,
+            checkpoint: 0,
+            wait_hint: Duration::default(),
+            process_id: None,
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(not(windows))]
+mod platform {
+    use std::{error::Error, ffi::OsString};
+
+    use super::ServiceMain;
+
+    const UNSUPPORTED: &str = "Windows service is only supported on Windows";
+
+    pub fn install_service(_launch_arguments: Vec<OsString>) -> Result<(), Box<dyn Error>> {
+        Err(UNSUPPORTED.into())
+    }
+
+    pub fn uninstall_service() -> Result<(), Box<dyn Error>> {
+        Err(UNSUPPORTED.into())
+    }
+
+    pub fn start_service() -> Result<(), Box<dyn Error>> {
+        Err(UNSUPPORTED.into())
+    }
+
+    pub fn stop_service() -> Result<(), Box<dyn Error>> {
+        Err(UNSUPPORTED.into())
+    }
+
+    pub fn run_service(_service_main: ServiceMain) -> Result<(), Box<dyn Error>> {
+        Err(UNSUPPORTED.into())
+    }
+}
@@ -0,0 +1,54 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+///
+/// 재접속 클라이언트의 이어 받기 요청
+///
+/// 클라이언트는 접속 안내(WELCOME)의 sequence_epoch 와 마지막으로 받은 상담직원 일련번호를 보내고,
+/// 서버는 보관 중인 변경 기록으로 놓친 변경분만 전송한다. 기록이 없으면 전체 상태를 전송한다.
+///
+pub struct SessionResume {
+    #[serde(rename = "resume_epoch")]
+    epoch: String,
+    #[serde(rename = "resume_sequence")]
+    sequence: u64,
+}
+
+impl SessionResume {
+    pub fn new(epoch: impl Into<String>, sequence: u64) -> Self {
+        Self {
+            epoch: epoch.into(),
+            sequence,
+        }
+    }
+
+    ///
+    /// 접속 후 첫 메시지에서 이어 받기 요청을 찾는다
+    ///
+    /// `{"format": "json", "resume_epoch": "...", "resume_sequence": 123}` 처럼 형식 협상,
+    /// 로그인 메시지에 함께 담은 MessagePack 또는 JSON 으로 수신한다.
+    ///
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        match rmp_serde::from_slice::<SessionResume>(data) {
+            Ok(session_resume) => Some(session_resume),
+            Err(_) => serde_json::from_slice::<SessionResume>(data).ok(),
+        }
+    }
+
+    ///
+    /// 웹 소켓 접속 요청의 resume_epoch, resume_sequence 쿼리 파라미터를 변환한다
+    ///
+    pub fn from_query(epoch: Option<String>, sequence: Option<String>) -> Option<Self> {
+        let sequence = sequence?.trim().parse::<u64>().ok()?;
+
+        Some(Self::new(epoch?, sequence))
+    }
+
+    pub fn get_epoch(&self) -> &str {
+        &self.epoch
+    }
+
+    pub fn get_sequence(&self) -> u64 {
+        self.sequence
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+use tokio::sync::{mpsc, Notify};
+
+#[derive(Debug, Clone, Serialize)]
+///
+/// 서버 종료 안내 (연결 종료 전 클라이언트에 마지막으로 전송)
+///
+/// timestamp 는 UNIX 시간(밀리초)이다.
+///
+pub struct ShutdownNotice {
+    event: String,
+    message: String,
+    timestamp: u64,
+}
+
+impl Default for ShutdownNotice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownNotice {
+    pub fn new() -> Self {
+        Self {
+            event: "SHUTDOWN".to_string(),
+            message: "server shutting down".to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+///
+/// 종료 제어기
+///
+/// 종료가 요청되면 CTM 은 이벤트 처리를 멈추고 다음 순서로 종료한다.
+/// 1. Acceptor 의 신규 접속 수신 중단, 접속 중인 클라이언트에 종료 안내와 웹 소켓 close 프레임 전송
+/// 2. CTI 서버에 CLOSE_REQ 전송
+/// 3. 발행기/기록기의 남은 데이터 전송 및 상태 스냅샷 저장
+///
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownController {
+    is_shutdown: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl ShutdownController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// 종료 요청
+    ///
+    pub fn shutdown(&self) {
+        self.is_shutdown.store(true, Ordering::Release);
+        self.notify.notify_waiters();
+    }
+
+    pub fn is_shutdown(&self) -> bool {
+        self.is_shutdown.load(Ordering::Acquire)
+    }
+
+    ///
+    /// 종료 요청까지 대기
+    ///
+    pub async fn wait(&self) {
+        loop {
+            // 상태 확인 전에 대기를 등록해야 그 사이의 종료 요청을 놓치지 않는다
+            let notified = self.notify.notified();
+            if self.is_shutdown() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    ///
+    /// SIGTERM/SIGINT 수신 시 종료를 요청한다
+    ///
+    /// 종료 중 신호를 한 번 더 받으면 즉시 프로세스를 끝낸다.
+    ///
+    pub fn listen_signals(&self) {
+        let shutdown_controller = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = wait_signal().await {
+                log::error!("Unable to listen shutdown signals. {}", e);
+                return;
+            }
+            log::info!("Received shutdown signal. Shutting down gracefully");
+            shutdown_controller.shutdown();
+
+            if wait_signal().await.is_ok() {
+                log::warn!("Received shutdown signal again. Exiting immediately");
+                std::process::exit(1);
+            }
+        });
+    }
+}
+
+///
+/// SIGHUP 수신 시 설정 재적용을 요청한다
+///
+/// 신호를 받을 때마다 반환된 채널로 알리며, SIGHUP 이 없는 플랫폼에서는 알림이 없다.
+///
+pub fn listen_reload_signal() -> mpsc::Receiver<()> {
+    let (reload_tx, reload_rx) = mpsc::channel(1);
+
+    #[cfg(unix)]
+    tokio::spawn(async move {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut sighup = match signal(SignalKind::hangup()) {
+            Ok(sighup) => sighup,
+            Err(e) => {
+                log::error!("Unable to listen reload signal. {}", e);
+                return;
+            }
+        };
+        while sighup.recv().await.is_some() {
+            log::info!("Received reload signal");
+            // 처리 중인 재적용 요청이 있으면 합친다
+            let _ = reload_tx.try_send(());
+        }
+    });
+    #[cfg(not(unix))]
+    drop(reload_tx);
+
+    reload_rx
+}
+
+#[cfg(unix)]
+async fn wait_signal() -> std::io::Result<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut sigint = signal(SignalKind::interrupt())?;
+    tokio::select! {
+        _ = sigterm.recv() => {}
+        _ = sigint.recv() => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn wait_signal() -> std::io::Result<()> {
+    tokio::signal::ctrl_c().await
+}
@@ -0,0 +1,658 @@
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    error::Error,
+    fs,
+    hash::{BuildHasher, Hasher},
+    io,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::broadcast,
+    time::{sleep_until, Instant},
+};
+use tokio_util::codec::Framed;
+
+use crate::cisco::{
+    client_event::agent_state_event::AgentStateEvent,
+    codec::{CtiCodec, MHDR_LENGTH},
+    control::{
+        query_agent_state_conf::QueryAgentStateConf, query_agent_state_req::QueryAgentStateReq,
+    },
+    session::{
+        change_monitor_mask_conf::ChangeMonitorMaskConf, close_conf::CloseConf,
+        heartbeat_conf::HeartBeatConf, OpenConf,
+    },
+    supervisor::agent_team_config_event::{AgentTeamConfigEvent, AgentTeamConfigEventAgent},
+    CtiMessage, CtiTimestamp, Deserializable, Direction, FloatingField, InvokeId, MessageType,
+    MonitorId, PeripheralId, PeripheralType, Serializable, SkillGroupAssignment, TagValue, MHDR,
+};
+
+use super::config::SimulatorConfig;
+
+///
+/// 무작위 이벤트로 전환할 상담직원 상태 (NOT_READY, AVAILABLE, TALKING, WORK_NOT_READY, WORK_READY)
+///
+const RANDOM_AGENT_STATES: [u16; 5] = [2, 3, 4, 5, 6];
+
+///
+/// 무작위 NOT_READY 이벤트의 최대 이석 사유코드
+///
+const RANDOM_REASON_CODE_MAX: u16 = 3;
+
+///
+/// 상태 변경 알림 채널 크기
+///
+const SIMULATED_EVENT_CHANNEL_CAPACITY: usize = 4_096;
+
+///
+/// OPEN_CONF 로 알려 주는 이벤트 모니터 ID
+///
+pub const SIMULATED_MONITOR_ID: u32 = 1;
+
+#[derive(Debug, Clone, Deserialize)]
+///
+/// 시뮬레이터 상담직원
+///
+pub struct SimulatedAgent {
+    pub agent_id: String,
+    pub agent_extension: String,
+    #[serde(default)]
+    pub skill_group_id: u32,
+    #[serde(default)]
+    pub agent_state: u16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+///
+/// 시뮬레이터 상담직원 팀
+///
+pub struct SimulatedTeam {
+    pub team_id: u32,
+    #[serde(default)]
+    pub team_name: String,
+    pub agents: Vec<SimulatedAgent>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+///
+/// 시나리오 상담직원 상태 변경 이벤트
+///
+/// delay 는 직전 이벤트(첫 이벤트는 OPEN_CONF) 이후 대기 시간(ms)이다.
+///
+pub struct ScriptedEvent {
+    pub delay: u64,
+    pub agent_id: String,
+    pub agent_state: u16,
+    #[serde(default)]
+    pub reason_code: u16,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+///
+/// 시뮬레이터 시나리오
+///
+/// events 가 비어 있으면 event_interval 마다 무작위 상담직원의 상태를 바꾼다.
+/// events 는 마지막 이벤트 이후 처음부터 반복한다.
+///
+pub struct SimulatorScript {
+    pub teams: Vec<SimulatedTeam>,
+    #[serde(default)]
+    pub events: Vec<ScriptedEvent>,
+}
+
+impl SimulatorScript {
+    ///
+    /// JSON 파일로 시나리오를 생성한다
+    ///
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)?;
+
+        Ok(serde_json::from_str::<Self>(&text)?)
+    }
+
+    ///
+    /// 팀 하나에 agent_count 명의 상담직원(1001 ~, 내선 2001 ~)을 가진 시나리오를 생성한다
+    ///
+    pub fn generated(agent_count: u32) -> Self {
+        Self {
+            teams: vec![SimulatedTeam {
+                team_id: 1,
+                team_name: "Simulator".to_string(),
+                agents: (0..agent_count)
+                    .map(|index| SimulatedAgent {
+                        agent_id: (1001 + index).to_string(),
+                        agent_extension: (2001 + index).to_string(),
+                        skill_group_id: 1,
+                        agent_state: 2,
+                    })
+                    .collect(),
+            }],
+            events: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+///
+/// 시뮬레이터가 적용한 상담직원 상태 변경
+///
+/// 부하 테스트에서 이벤트 발생 시각부터 클라이언트 수신까지의 지연을 측정할 때 사용한다.
+///
+pub struct SimulatedEvent {
+    pub agent_id: String,
+    pub agent_state: u16,
+    pub reason_code: u16,
+    pub changed_at: Instant,
+}
+
+#[derive(Debug, Clone)]
+struct SimulatedAgentState {
+    agent_extension: String,
+    skill_group_id: u32,
+    agent_state: u16,
+    changed_at: Instant,
+}
+
+///
+/// 개발/테스트용 CTI 서버 시뮬레이터
+///
+/// OPEN_REQ, HEARTBEAT_REQ, QUERY_AGENT_STATE_REQ, CLOSE_REQ 에 응답하고,
+/// OPEN_CONF 이후 시나리오 또는 무작위 AGENT_STATE_EVENT 를 전송한다.
+/// 상담직원 상태는 접속 간에 공유되어 재접속 후 조회 결과가 이어진다.
+///
+pub struct CtiSimulator {
+    peripheral_id: PeripheralId,
+    event_interval: Duration,
+    teams: Vec<SimulatedTeam>,
+    events: Vec<ScriptedEvent>,
+    agent_state_map: Mutex<HashMap<String, SimulatedAgentState>>,
+    simulated_event_tx: broadcast::Sender<SimulatedEvent>,
+}
+
+impl CtiSimulator {
+    pub fn new(
+        script: SimulatorScript,
+        peripheral_id: PeripheralId,
+        event_interval: Duration,
+    ) -> Self {
+        let now = Instant::now();
+        let agent_state_map = script
+            .teams
+            .iter()
+            .flat_map(|team| team.agents.iter())
+            .map(|agent| {
+                (
+                    agent.agent_id.clone(),
+                    SimulatedAgentState {
+                        agent_extension: agent.agent_extension.clone(),
+                        skill_group_id: agent.skill_group_id,
+                        agent_state: agent.agent_state,
+                        changed_at: now,
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            peripheral_id,
+            event_interval,
+            teams: script.teams,
+            events: script.events,
+            agent_state_map: Mutex::new(agent_state_map),
+            simulated_event_tx: broadcast::channel(SIMULATED_EVENT_CHANNEL_CAPACITY).0,
+        }
+    }
+
+    ///
+    /// 설정으로 시뮬레이터를 생성한다
+    ///
+    /// 시나리오 파일(명령행 인자 또는 CTI_SIM_SCRIPT_FILE)이 지정되면 파일을, 아니면 CTI_SIM_AGENT_COUNT 명의 상담직원을 생성한다.
+    ///
+    pub fn load(
+        script_file: Option<String>,
+        config: &SimulatorConfig,
+    ) -> Result<Self, Box<dyn Error>> {
+        let script = match script_file
+            .or_else(|| config.script_file.clone())
+            .filter(|script_file| !script_file.is_empty())
+        {
+            Some(script_file) => SimulatorScript::from_file(script_file)?,
+            None => SimulatorScript::generated(config.agent_count),
+        };
+
+        Ok(Self::new(
+            script,
+            config.peripheral_id,
+            config.event_interval,
+        ))
+    }
+
+    ///
+    /// 상담직원의 현재 상태
+    ///
+    pub fn get_agent_state(&self, agent_id: &str) -> Option<u16> {
+        self.agent_state_map
+            .lock()
+            .unwrap()
+            .get(agent_id)
+            .map(|agent| agent.agent_state)
+    }
+
+    ///
+    /// 상담직원 상태 변경 알림 구독
+    ///
+    pub fn subscribe_events(&self) -> broadcast::Receiver<SimulatedEvent> {
+        self.simulated_event_tx.subscribe()
+    }
+
+    ///
+    /// 클라이언트 요청 메시지에 대한 응답 메시지 목록을 반환한다
+    ///
+    /// OPEN_REQ 에는 OPEN_CONF 와 팀별 AGENT_TEAM_CONFIG_EVENT 로 응답한다.
+    /// 알 수 없는 상담직원 조회 요청은 응답하지 않는다.
+    ///
+    pub fn handle_request(&self, message_type: &MessageType, data: &mut Vec<u8>) -> Vec<Vec<u8>> {
+        let invoke_id = match data.get(MHDR_LENGTH..MHDR_LENGTH + 4) {
+            Some(invoke_id) => InvokeId::deserialize(&mut invoke_id.to_vec()).1,
+            None => return vec![],
+        };
+
+        match message_type {
+            MessageType::OPEN_REQ => {
+                // ServicesRequested 는 InvokeID, VersionNumber, IdleTimeout, PeripheralID 다음에 위치한다
+                let service_granted = data
+                    .get(MHDR_LENGTH + 16..MHDR_LENGTH + 20)
+                    .map(|services| <u32 as Deserializable>::deserialize(&mut services.to_vec()).1)
+                    .unwrap_or_default();
+
+                let mut responses = vec![self.open_conf(invoke_id, service_granted)];
+                responses.extend(
+                    self.teams
+                        .iter()
+                        .map(|team| self.agent_team_config_event(team)),
+                );
+                responses
+            }
+            MessageType::HEARTBEAT_REQ => vec![HeartBeatConf {
+                mhdr: MHDR {
+                    length: 4,
+                    message_type: MessageType::HEARTBEAT_CONF,
+                },
+                invoke_id,
+            }
+            .serialize()],
+            MessageType::QUERY_AGENT_STATE_REQ => {
+                let query_agent_state_req = match QueryAgentStateReq::try_deserialize(data) {
+                    Ok(query_agent_state_req) => query_agent_state_req,
+                    Err(e) => {
+                        log::warn!("Invalid QUERY_AGENT_STATE_REQ. {}", e);
+                        return vec![];
+                    }
+                };
+
+                match query_agent_state_req
+                    .agent_id
+                    .and_then(|agent_id| self.query_agent_state_conf(invoke_id, &agent_id.data))
+                {
+                    Some(query_agent_state_conf) => vec![query_agent_state_conf],
+                    None => {
+                        log::warn!("Unknown simulated agent. invoke_id: {}", invoke_id);
+                        vec![]
+                    }
+                }
+            }
+            MessageType::CHANGE_MONITOR_MASK_REQ => vec![ChangeMonitorMaskConf {
+                mhdr: MHDR {
+                    length: 4,
+                    message_type: MessageType::CHANGE_MONITOR_MASK_CONF,
+                },
+                invoke_id,
+            }
+            .serialize()],
+            MessageType::CLOSE_REQ => vec![CloseConf {
+                mhdr: MHDR {
+                    length: 4,
+                    message_type: MessageType::CLOSE_CONF,
+                },
+                invoke_id,
+            }
+            .serialize()],
+            message_type => {
+                log::debug!(
+                    "Ignored simulator request. message_type: {:?}",
+                    message_type
+                );
+                vec![]
+            }
+        }
+    }
+
+    ///
+    /// 상담직원 상태를 바꾸고 AGENT_STATE_EVENT 를 반환한다
+    ///
+    pub fn change_agent_state(
+        &self,
+        agent_id: &str,
+        agent_state: u16,
+        reason_code: u16,
+    ) -> Option<Vec<u8>> {
+        let mut agent_state_map = self.agent_state_map.lock().unwrap();
+        let agent = agent_state_map.get_mut(agent_id)?;
+        agent.agent_state = agent_state;
+        agent.changed_at = Instant::now();
+
+        // 구독자가 없으면 알림을 버린다
+        let _ = self.simulated_event_tx.send(SimulatedEvent {
+            agent_id: agent_id.to_string(),
+            agent_state,
+            reason_code,
+            changed_at: agent.changed_at,
+        });
+
+        Some(
+            AgentStateEvent {
+                mhdr: MHDR {
+                    length: 0,
+                    message_type: MessageType::AGENT_STATE_EVENT,
+                },
+                monitor_id: MonitorId(0),
+                peripheral_id: self.peripheral_id,
+                session_id: 0,
+                peripheral_type: PeripheralType::ENTERPRISE_AGENT,
+                skill_group_state: agent_state,
+                state_duration: 0,
+                skill_group_number: agent.skill_group_id,
+                skill_group_id: agent.skill_group_id,
+                skill_group_priority: 0,
+                agent_state,
+                event_reason_code: reason_code,
+                mrd_id: 1,
+                num_tasks: u32::from(agent_state == 4),
+                agent_mode: 0,
+                max_task_limit: 1,
+                icm_agent_id: 0,
+                agent_availability_status: 0,
+                num_flt_skill_groups: 1,
+                department_id: 0,
+                cti_client_signature: None,
+                agent_id: Some(FloatingField::new(
+                    TagValue::AGENT_ID_TAG,
+                    agent_id.to_string(),
+                )),
+                agent_extension: Some(FloatingField::new(
+                    TagValue::AGENT_EXTENSION_TAG,
+                    agent.agent_extension.clone(),
+                )),
+                active_terminal: None,
+                agent_instrument: Some(FloatingField::new(
+                    TagValue::AGENT_INSTRUMENT_TAG,
+                    agent.agent_extension.clone(),
+                )),
+                duration: None,
+                next_agent_state: None,
+                direction: Some(FloatingField::new(
+                    TagValue::DIRECTION_TAG,
+                    match agent_state {
+                        4 => Direction::IN,
+                        _ => Direction::NONE,
+                    },
+                )),
+                skill_groups: vec![SkillGroupAssignment {
+                    skill_group_number: agent.skill_group_id,
+                    skill_group_id: agent.skill_group_id,
+                    skill_group_priority: 0,
+                    skill_group_state: agent_state,
+                }],
+                max_beyond_task_limit: None,
+                unknown_fields: vec![],
+            }
+            .serialize(),
+        )
+    }
+
+    ///
+    /// event_index 번째 이벤트를 전송하기 전 대기 시간
+    ///
+    pub fn event_delay(&self, event_index: usize) -> Duration {
+        match self.events.is_empty() {
+            true => self.event_interval,
+            false => Duration::from_millis(self.events[event_index % self.events.len()].delay),
+        }
+    }
+
+    ///
+    /// event_index 번째 시나리오 이벤트(없으면 무작위 이벤트)를 적용하고 AGENT_STATE_EVENT 를 반환한다
+    ///
+    pub fn next_event(&self, event_index: usize) -> Option<Vec<u8>> {
+        if !self.events.is_empty() {
+            let event = &self.events[event_index % self.events.len()];
+            return self.change_agent_state(&event.agent_id, event.agent_state, event.reason_code);
+        }
+
+        let agent_ids = self
+            .teams
+            .iter()
+            .flat_map(|team| team.agents.iter())
+            .map(|agent| agent.agent_id.clone())
+            .collect::<Vec<_>>();
+        if agent_ids.is_empty() {
+            return None;
+        }
+
+        let agent_id = &agent_ids[random_index(agent_ids.len())];
+        let agent_state = RANDOM_AGENT_STATES[random_index(RANDOM_AGENT_STATES.len())];
+        let reason_code = match agent_state {
+            2 => 1 + random_index(RANDOM_REASON_CODE_MAX as usize) as u16,
+            _ => 0,
+        };
+
+        self.change_agent_state(agent_id, agent_state, reason_code)
+    }
+
+    ///
+    /// 접속을 수락하고 접속마다 세션을 처리한다
+    ///
+    pub async fn serve(self: Arc<Self>, listener: TcpListener) -> io::Result<()> {
+        loop {
+            let (stream, address) = listener.accept().await?;
+            log::info!("Accepted simulator client. address: {}", address);
+
+            let simulator = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = simulator.handle_connection(stream).await {
+                    log::error!(
+                        "Simulator session error. address: {}, error: {}",
+                        address,
+                        e
+                    );
+                }
+                log::info!("Closed simulator client. address: {}", address);
+            });
+        }
+    }
+
+    async fn handle_connection(&self, stream: TcpStream) -> io::Result<()> {
+        stream.set_nodelay(true)?;
+        let mut client_stream = Framed::new(stream, CtiCodec::default());
+
+        let mut is_opened = false;
+        let mut event_index = 0;
+        let mut next_event_at = Instant::now();
+
+        loop {
+            tokio::select! {
+                message = client_stream.next() => match message {
+                    None => return Ok(()),
+                    Some(Err(e)) => return Err(e),
+                    Some(Ok(Err(e))) => log::warn!("Discarded invalid simulator request. {}", e),
+                    Some(Ok(Ok((message_type, mut data)))) => {
+                        log::debug!("Received simulator request. message_type: {:?}", message_type);
+
+                        for response in self.handle_request(&message_type, &mut data) {
+                            client_stream.send(response).await?;
+                        }
+
+                        match message_type {
+                            MessageType::OPEN_REQ => {
+                                is_opened = true;
+                                next_event_at = Instant::now() + self.event_delay(event_index);
+                            }
+                            MessageType::CLOSE_REQ => return Ok(()),
+                            _ => {}
+                        }
+                    }
+                },
+                // OPEN_CONF 이후 상담직원 상태 이벤트 전송
+                _ = sleep_until(next_event_at), if is_opened => {
+                    if let Some(agent_state_event) = self.next_event(event_index) {
+                        client_stream.send(agent_state_event).await?;
+                    }
+                    event_index += 1;
+                    next_event_at = Instant::now() + self.event_delay(event_index);
+                }
+            }
+        }
+    }
+
+    fn open_conf(&self, invoke_id: InvokeId, service_granted: u32) -> Vec<u8> {
+        OpenConf {
+            mhdr: MHDR {
+                length: 0,
+                message_type: MessageType::OPEN_CONF,
+            },
+            invoke_id,
+            service_granted,
+            monitor_id: MonitorId(SIMULATED_MONITOR_ID),
+            pg_status: 0,
+            icm_central_controller_time: CtiTimestamp(chrono::Utc::now().timestamp() as u32),
+            peripheral_online: true,
+            peripheral_type: PeripheralType::ENTERPRISE_AGENT,
+            agent_state: 0,
+            department_id: 0,
+            session_type: 0,
+            agent_extension: None,
+            agent_id: None,
+            agent_instrument: None,
+            num_peripherals: Some(FloatingField::new(TagValue::NUM_PERIPHERALS_TAG, 1)),
+            flt_peripheral_id: Some(FloatingField::new(
+                TagValue::PERIPHERAL_ID_TAG_V11,
+                self.peripheral_id,
+            )),
+            multiline_agent_control: None,
+            unknown_fields: vec![],
+        }
+        .serialize()
+    }
+
+    fn agent_team_config_event(&self, team: &SimulatedTeam) -> Vec<u8> {
+        let agent_state_map = self.agent_state_map.lock().unwrap();
+
+        AgentTeamConfigEvent {
+            mhdr: MHDR {
+                length: 0,
+                message_type: MessageType::AGENT_TEAM_CONFIG_EVENT,
+            },
+            peripheral_id: self.peripheral_id,
+            team_id: team.team_id,
+            number_of_agents: team.agents.len() as u16,
+            config_operation: 1,
+            department_id: 0,
+            agent_team_name: Some(FloatingField::new(
+                TagValue::AGENT_TEAM_NAME_TAG,
+                team.team_name.clone(),
+            )),
+            agents: team
+                .agents
+                .iter()
+                .map(|agent| {
+                    let (agent_state, state_duration) = agent_state_map
+                        .get(&agent.agent_id)
+                        .map(|state| {
+                            (
+                                state.agent_state,
+                                state.changed_at.elapsed().as_secs() as u32,
+                            )
+                        })
+                        .unwrap_or((agent.agent_state, 0));
+
+                    AgentTeamConfigEventAgent {
+                        agent_id: Some(FloatingField::new(
+                            TagValue::ATC_AGENT_ID_TAG,
+                            agent.agent_id.clone(),
+                        )),
+                        agent_flags: Some(FloatingField::new(TagValue::AGENT_FLAGS_TAG, 0)),
+                        agent_state: Some(FloatingField::new(
+                            TagValue::ATC_AGENT_STATE_TAG,
+                            agent_state,
+                        )),
+                        state_duration: Some(FloatingField::new(
+                            TagValue::ATC_AGENT_STATE_DURATION_TAG,
+                            state_duration,
+                        )),
+                    }
+                })
+                .collect(),
+            unknown_fields: vec![],
+        }
+        .serialize()
+    }
+
+    fn query_agent_state_conf(&self, invoke_id: InvokeId, agent_id: &str) -> Option<Vec<u8>> {
+        let agent_state_map = self.agent_state_map.lock().unwrap();
+        let agent = agent_state_map.get(agent_id)?;
+
+        Some(
+            QueryAgentStateConf {
+                mhdr: MHDR {
+                    length: 0,
+                    message_type: MessageType::QUERY_AGENT_STATE_CONF,
+                },
+                invoke_id,
+                agent_state: agent.agent_state,
+                num_skill_groups: 1,
+                mrd_id: 1,
+                num_task: u32::from(agent.agent_state == 4),
+                agent_mode: 0,
+                max_task_limit: 1,
+                icm_agent_id: 0,
+                agent_availability_status: 0,
+                department_id: 0,
+                agent_id: Some(FloatingField::new(
+                    TagValue::AGENT_ID_TAG,
+                    agent_id.to_string(),
+                )),
+                agent_extension: Some(FloatingField::new(
+                    TagValue::AGENT_EXTENSION_TAG,
+                    agent.agent_extension.clone(),
+                )),
+                agent_instrument: Some(FloatingField::new(
+                    TagValue::AGENT_INSTRUMENT_TAG,
+                    agent.agent_extension.clone(),
+                )),
+                skill_groups: vec![SkillGroupAssignment {
+                    skill_group_number: agent.skill_group_id,
+                    skill_group_id: agent.skill_group_id,
+                    skill_group_priority: 0,
+                    skill_group_state: agent.agent_state,
+                }],
+                internal_agent_state: None,
+                max_beyond_task_limit: None,
+                unknown_fields: vec![],
+            }
+            .serialize(),
+        )
+    }
+}
+
+///
+/// 0 ~ len - 1 사이 난수
+///
+fn random_index(len: usize) -> usize {
+    RandomState::new().build_hasher().finish() as usize % len
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+
+use crate::cisco::ConnectionCallId;
+
+use super::call_info::{CallInfo, CallState};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct SkillGroupInfo {
+    cti_instance: String,
+    skill_group_id: u32,
+    agents_ready: u32,
+    agents_not_ready: u32,
+    agents_talking: u32,
+    calls_in_queue: u32,
+}
+
+impl SkillGroupInfo {
+    pub fn new(skill_group_id: u32) -> Self {
+        Self {
+            skill_group_id,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// 스킬그룹별 상담직원 상태와 호 목록으로 스킬그룹 집계를 계산한다
+    ///
+    /// skill_group_agent_map 은 스킬그룹 ID → (상담직원 ID → 스킬그룹 상태) 이며,
+    /// 결과는 스킬그룹 ID 순으로 정렬된다.
+    ///
+    pub fn aggregate(
+        skill_group_agent_map: &HashMap<u32, HashMap<String, u16>>,
+        call_info_map: &HashMap<ConnectionCallId, CallInfo>,
+    ) -> Vec<SkillGroupInfo> {
+        let mut skill_group_info_map = HashMap::<u32, SkillGroupInfo>::new();
+
+        skill_group_agent_map
+            .iter()
+            .for_each(|(skill_group_id, agent_state_map)| {
+                let skill_group_info = skill_group_info_map
+                    .entry(*skill_group_id)
+                    .or_insert_with(|| SkillGroupInfo::new(*skill_group_id));
+
+                agent_state_map
+                    .values()
+                    .for_each(|skill_group_state| match skill_group_state {
+                        // AVAILABLE
+                        3 => skill_group_info.agents_ready += 1,
+                        // NOT_READY, WORK_NOT_READY
+                        2 | 5 => skill_group_info.agents_not_ready += 1,
+                        // TALKING, HOLD
+                        4 | 10 => skill_group_info.agents_talking += 1,
+                        _ => {}
+                    });
+            });
+
+        call_info_map
+            .values()
+            .filter(|call_info| call_info.get_call_state() == CallState::QUEUED)
+            .for_each(|call_info| {
+                skill_group_info_map
+                    .entry(call_info.get_skill_group_id())
+                    .or_insert_with(|| SkillGroupInfo::new(call_info.get_skill_group_id()))
+                    .calls_in_queue += 1;
+            });
+
+        let mut skill_group_infos = skill_group_info_map.into_values().collect::<Vec<_>>();
+        skill_group_infos.sort_by_key(|skill_group_info| skill_group_info.skill_group_id);
+
+        skill_group_infos
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn set_cti_instance(&mut self, cti_instance: impl Into<String>) {
+        self.cti_instance = cti_instance.into();
+    }
+
+    pub fn get_skill_group_id(&self) -> u32 {
+        self.skill_group_id
+    }
+
+    pub fn get_agents_ready(&self) -> u32 {
+        self.agents_ready
+    }
+
+    pub fn get_agents_not_ready(&self) -> u32 {
+        self.agents_not_ready
+    }
+
+    pub fn get_agents_talking(&self) -> u32 {
+        self.agents_talking
+    }
+
+    pub fn get_calls_in_queue(&self) -> u32 {
+        self.calls_in_queue
+    }
+}
@@ -0,0 +1,70 @@
+use std::{collections::HashMap, error::Error, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    agent_info::AgentInfo, call_info::CallInfo, field_masker::FieldMasker, team_info::TeamInfo,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+///
+/// CTM 상태 스냅샷
+///
+/// 재시작 후 다음 이벤트를 받기 전까지 빈 상황판이 표시되지 않도록
+/// 상담직원/호/팀 상태를 MessagePack 파일로 저장하고 복원한다.
+/// 관리 명령 EXPORT_STATE 로 받은 JSON 문서는 --import-state 로 개발 환경에 불러올 수 있다.
+///
+pub struct CtmSnapshot {
+    pub agent_infos: Vec<AgentInfo>,
+    pub call_infos: Vec<CallInfo>,
+    pub team_infos: Vec<TeamInfo>,
+    // CTI 서버 인스턴스 → 스킬그룹 ID → (상담직원 ID → 스킬그룹 상태)
+    pub skill_group_agent_map: HashMap<String, HashMap<u32, HashMap<String, u16>>>,
+}
+
+impl CtmSnapshot {
+    ///
+    /// 파일에서 스냅샷을 읽는다
+    ///
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let buf = fs::read(path)?;
+
+        Ok(rmp_serde::from_slice::<Self>(&buf)?)
+    }
+
+    ///
+    /// JSON 파일에서 스냅샷을 읽는다
+    ///
+    pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)?;
+
+        Ok(serde_json::from_str::<Self>(&text)?)
+    }
+
+    ///
+    /// 상담직원, 호 상태의 개인정보 필드를 마스킹한다
+    ///
+    pub fn mask_fields(&mut self, field_masker: &FieldMasker) {
+        self.agent_infos
+            .iter_mut()
+            .for_each(|agent_info| agent_info.mask_fields(field_masker));
+        self.call_infos
+            .iter_mut()
+            .for_each(|call_info| call_info.mask_fields(field_masker));
+    }
+
+    ///
+    /// 스냅샷을 파일에 저장한다
+    ///
+    /// 저장 도중 종료되어도 기존 파일이 손상되지 않도록 임시 파일에 기록한 뒤 교체한다.
+    ///
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+        let path = path.as_ref();
+        let temp_path = path.with_extension("tmp");
+
+        fs::write(&temp_path, rmp_serde::to_vec_named(self)?)?;
+        fs::rename(&temp_path, path)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// 상태 재조회 범위
+///
+pub enum StateRefreshScope {
+    // 상담직원 한 명
+    AGENT,
+    // 팀 소속 상담직원
+    TEAM,
+    // CTI 서버 인스턴스의 모든 상담직원 (재동기화)
+    ALL,
+}
+
+///
+/// 상태 재조회 요청 빈도 제한
+///
+/// 같은 CTI 서버 인스턴스, 범위, 대상은 min_interval 안에 다시 조회할 수 없다.
+///
+#[derive(Debug)]
+pub struct StateRefreshLimiter {
+    min_interval: Duration,
+    last_refresh_map: HashMap<(String, StateRefreshScope, String), Instant>,
+}
+
+impl StateRefreshLimiter {
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_refresh_map: HashMap::new(),
+        }
+    }
+
+    pub fn get_min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    ///
+    /// 재조회가 가능하면 요청 시각을 기록하고, 제한 중이면 다시 요청할 수 있을 때까지 남은 시간을 반환한다
+    ///
+    pub fn try_acquire(
+        &mut self,
+        cti_instance: &str,
+        scope: StateRefreshScope,
+        target: &str,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let key = (cti_instance.to_string(), scope, target.to_string());
+        if let Some(last_refresh) = self.last_refresh_map.get(&key) {
+            let elapsed = now.saturating_duration_since(*last_refresh);
+            if elapsed < self.min_interval {
+                return Err(self.min_interval - elapsed);
+            }
+        }
+
+        // 제한 시간이 지난 기록은 정리한다
+        let min_interval = self.min_interval;
+        self.last_refresh_map
+            .retain(|_, last_refresh| now.saturating_duration_since(*last_refresh) < min_interval);
+        self.last_refresh_map.insert(key, now);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 상태 재조회 요청 응답
+///
+/// 요청이 받아들여지면 requested_agents 명의 상태를 CTI 서버에 다시 조회하며, 조회 결과는 일반 상담직원
+/// 상태 이벤트로 전송된다. 빈도 제한에 걸리면 retry_after(밀리초) 이후에 다시 요청할 수 있다.
+/// timestamp 는 UNIX 시간(밀리초)이다.
+///
+pub struct StateRefreshResult {
+    event: String,
+    cti_instance: String,
+    scope: StateRefreshScope,
+    target: String,
+    accepted: bool,
+    requested_agents: usize,
+    retry_after: u64,
+    timestamp: u64,
+}
+
+impl StateRefreshResult {
+    pub fn new(
+        cti_instance: impl Into<String>,
+        scope: StateRefreshScope,
+        target: impl Into<String>,
+        accepted: bool,
+        requested_agents: usize,
+        retry_after: Duration,
+    ) -> Self {
+        Self {
+            event: "STATE_REFRESH".to_string(),
+            cti_instance: cti_instance.into(),
+            scope,
+            target: target.into(),
+            accepted,
+            requested_agents,
+            retry_after: retry_after.as_millis() as u64,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn get_scope(&self) -> StateRefreshScope {
+        self.scope
+    }
+
+    pub fn get_target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.accepted
+    }
+
+    pub fn get_requested_agents(&self) -> usize {
+        self.requested_agents
+    }
+
+    pub fn get_retry_after(&self) -> u64 {
+        self.retry_after
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
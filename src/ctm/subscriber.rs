@@ -0,0 +1,36 @@
+use crate::{cisco::MessageType, event::system_event::SystemEvent};
+
+use super::{agent_info::AgentInfo, agent_removal::AgentRemoval, call_info::CallInfo};
+
+///
+/// CTM 이벤트 구독자
+///
+/// CTM 을 라이브러리로 사용할 때 소켓 브로드캐스트와 별도로 애플리케이션 콜백을 받는다.
+/// 콜백은 CTM 이벤트 루프에서 호출되므로 오래 걸리는 작업은 별도 태스크로 넘겨야 한다.
+///
+pub trait CtmSubscriber: Send + Sync {
+    ///
+    /// 상담직원 상태 변경
+    ///
+    fn on_agent_state(&self, _agent_info: &AgentInfo) {}
+
+    ///
+    /// 상담직원 제거 (로그아웃 후 일정 시간 경과, 팀 구성에서 삭제)
+    ///
+    fn on_agent_removed(&self, _agent_removal: &AgentRemoval) {}
+
+    ///
+    /// 호 상태 변경
+    ///
+    fn on_call_state(&self, _call_info: &CallInfo) {}
+
+    ///
+    /// 호 이벤트 메시지 수신
+    ///
+    fn on_call_event(&self, _message_type: &MessageType, _data: &[u8]) {}
+
+    ///
+    /// CTI 서버 연결, 클라이언트 접속 등 시스템 이벤트
+    ///
+    fn on_system_event(&self, _event: &SystemEvent) {}
+}
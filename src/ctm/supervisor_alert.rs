@@ -0,0 +1,142 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cisco::{supervisor::emergency_call_event::EmergencyCallEvent, FloatingField};
+
+use super::{agent_info::AgentInfo, field_masker::FieldMasker};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// 관리자 경보 종류
+///
+pub enum SupervisorAlertType {
+    // 상담직원 긴급 호출 (EMERGENCY_CALL_EVENT)
+    EMERGENCY_CALL,
+    // 상담직원 지원 요청 (SUPERVISOR_ASSIST_EVENT)
+    SUPERVISOR_ASSIST,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 관리자 경보 (긴급 호출, 지원 요청)
+///
+/// 놓치면 안 되는 이벤트이므로 구독 조건과 관계없이 접속한 모든 클라이언트에 전송한다.
+/// 상담직원 이름과 팀은 CTM 이 보관 중인 상담직원 상태에서 채운다. timestamp 는 UNIX 시간(밀리초)이다.
+///
+pub struct SupervisorAlert {
+    event: String,
+    alert_type: SupervisorAlertType,
+    cti_instance: String,
+    peripheral_id: u32,
+    connection_call_id: u32,
+    connection_device_id: String,
+    agent_id: String,
+    agent_name: String,
+    agent_extension: String,
+    team_id: u32,
+    team_name: String,
+    timestamp: u64,
+}
+
+impl SupervisorAlert {
+    pub fn new(
+        alert_type: SupervisorAlertType,
+        cti_instance: impl Into<String>,
+        emergency_call_event: &EmergencyCallEvent,
+    ) -> Self {
+        let data = |field: &Option<FloatingField<String>>| {
+            field
+                .as_ref()
+                .map(|field| field.data.clone())
+                .unwrap_or_default()
+        };
+
+        Self {
+            event: "SUPERVISOR_ALERT".to_string(),
+            alert_type,
+            cti_instance: cti_instance.into(),
+            peripheral_id: emergency_call_event.peripheral_id.0,
+            connection_call_id: emergency_call_event.connection_call_id.0,
+            connection_device_id: data(&emergency_call_event.connection_device_id),
+            agent_id: data(&emergency_call_event.agent_id),
+            agent_name: "".to_string(),
+            agent_extension: data(&emergency_call_event.agent_extension),
+            team_id: 0,
+            team_name: "".to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    ///
+    /// 보관 중인 상담직원 상태로 이름, 팀, (메시지에 없으면) 내선번호를 채운다
+    ///
+    pub fn set_agent_info(&mut self, agent_info: &AgentInfo) {
+        self.agent_name = agent_info.get_agent_name().to_string();
+        self.team_id = agent_info.get_team_id();
+        self.team_name = agent_info.get_team_name().to_string();
+        if self.agent_extension.is_empty() {
+            self.agent_extension = agent_info.get_agent_extension().to_string();
+        }
+    }
+
+    ///
+    /// 내선번호, 통화 장치 ID 를 마스킹한다
+    ///
+    pub fn mask_fields(&mut self, field_masker: &FieldMasker) {
+        self.agent_extension = field_masker.mask(&self.agent_extension);
+        self.connection_device_id = field_masker.mask(&self.connection_device_id);
+    }
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+
+    pub fn get_alert_type(&self) -> SupervisorAlertType {
+        self.alert_type
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn get_peripheral_id(&self) -> u32 {
+        self.peripheral_id
+    }
+
+    pub fn get_connection_call_id(&self) -> u32 {
+        self.connection_call_id
+    }
+
+    pub fn get_connection_device_id(&self) -> &str {
+        &self.connection_device_id
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_agent_name(&self) -> &str {
+        &self.agent_name
+    }
+
+    pub fn get_agent_extension(&self) -> &str {
+        &self.agent_extension
+    }
+
+    pub fn get_team_id(&self) -> u32 {
+        self.team_id
+    }
+
+    pub fn get_team_name(&self) -> &str {
+        &self.team_name
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
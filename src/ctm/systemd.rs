@@ -0,0 +1,155 @@
+use std::time::{Duration, Instant};
+
+///
+/// systemd 서비스 관리자 알림 (Type=notify)
+///
+/// systemd 가 NOTIFY_SOCKET 으로 실행한 경우에만 알리며, 그 외 환경이나 unix 가 아닌 플랫폼에서는 아무것도 하지 않는다.
+/// WatchdogSec 가 설정되면 제한 시간의 절반 간격으로 CTM 이벤트 루프에서 watchdog 신호를 보내므로,
+/// 이벤트 루프가 멈추면 systemd 가 서비스를 재시작한다.
+///
+#[derive(Debug)]
+pub struct SystemdNotifier {
+    watchdog_interval: Option<Duration>,
+    last_watchdog: Instant,
+    is_ready: bool,
+}
+
+impl Default for SystemdNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemdNotifier {
+    pub fn new() -> Self {
+        let watchdog_interval = watchdog_timeout().map(|watchdog_timeout| watchdog_timeout / 2);
+        if let Some(watchdog_interval) = watchdog_interval {
+            log::info!(
+                "systemd watchdog enabled. interval: {:?}",
+                watchdog_interval
+            );
+        }
+
+        Self {
+            watchdog_interval,
+            last_watchdog: Instant::now(),
+            is_ready: false,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.is_ready
+    }
+
+    pub fn get_watchdog_interval(&self) -> Option<Duration> {
+        self.watchdog_interval
+    }
+
+    ///
+    /// 서비스 준비 완료 (CTI 세션 연결, Acceptor 수신 시작)
+    ///
+    pub fn ready(&mut self, status: &str) {
+        if self.is_ready {
+            return;
+        }
+        self.is_ready = true;
+        log::info!("Notifying systemd readiness. status: {}", status);
+        notify(&[Notify::Ready, Notify::Status(status)]);
+    }
+
+    ///
+    /// 서비스 상태 문구 (systemctl status 에 표시된다)
+    ///
+    pub fn status(&self, status: &str) {
+        notify(&[Notify::Status(status)]);
+    }
+
+    ///
+    /// 설정 재적용 시작
+    ///
+    pub fn reloading(&self) {
+        notify(&[Notify::Reloading]);
+    }
+
+    ///
+    /// 설정 재적용 완료
+    ///
+    pub fn reloaded(&self) {
+        if self.is_ready {
+            notify(&[Notify::Ready]);
+        }
+    }
+
+    ///
+    /// 종료 시작
+    ///
+    pub fn stopping(&self) {
+        notify(&[Notify::Stopping, Notify::Status("Shutting down")]);
+    }
+
+    ///
+    /// watchdog 간격이 지났으면 신호를 보낸다
+    ///
+    pub fn watchdog(&mut self) {
+        let Some(watchdog_interval) = self.watchdog_interval else {
+            return;
+        };
+        if self.last_watchdog.elapsed() < watchdog_interval {
+            return;
+        }
+
+        self.last_watchdog = Instant::now();
+        notify(&[Notify::Watchdog]);
+    }
+}
+
+enum Notify<'a> {
+    Ready,
+    Reloading,
+    Stopping,
+    Status(&'a str),
+    Watchdog,
+}
+
+#[cfg(unix)]
+fn notify(states: &[Notify]) {
+    use sd_notify::NotifyState;
+
+    let mut notify_states = vec![];
+    for state in states {
+        match state {
+            Notify::Ready => notify_states.push(NotifyState::Ready),
+            // Type=notify-reload 는 재적용 시작 시각을 함께 받아야 한다
+            Notify::Reloading => {
+                notify_states.push(NotifyState::Reloading);
+                if let Ok(monotonic_usec) = NotifyState::monotonic_usec_now() {
+                    notify_states.push(monotonic_usec);
+                }
+            }
+            Notify::Stopping => notify_states.push(NotifyState::Stopping),
+            Notify::Status(status) => notify_states.push(NotifyState::Status(status)),
+            Notify::Watchdog => notify_states.push(NotifyState::Watchdog),
+        }
+    }
+
+    if let Err(e) = sd_notify::notify(false, &notify_states) {
+        log::warn!("Unable to notify systemd. {}", e);
+    }
+}
+
+#[cfg(not(unix))]
+fn notify(_states: &[Notify]) {}
+
+#[cfg(unix)]
+fn watchdog_timeout() -> Option<Duration> {
+    let mut usec = 0;
+    match sd_notify::watchdog_enabled(false, &mut usec) && usec > 0 {
+        true => Some(Duration::from_micros(usec)),
+        false => None,
+    }
+}
+
+#[cfg(not(unix))]
+fn watchdog_timeout() -> Option<Duration> {
+    None
+}
@@ -0,0 +1,65 @@
+use std::collections::BTreeSet;
+
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct TeamInfo {
+    cti_instance: String,
+    team_id: u32,
+    team_name: String,
+    department_id: i32,
+    agent_ids: BTreeSet<String>,
+}
+
+impl TeamInfo {
+    pub fn new(team_id: u32) -> Self {
+        Self {
+            cti_instance: "".to_string(),
+            team_id,
+            team_name: "".to_string(),
+            department_id: 0,
+            agent_ids: BTreeSet::new(),
+        }
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn set_cti_instance(&mut self, cti_instance: impl Into<String>) {
+        self.cti_instance = cti_instance.into();
+    }
+
+    pub fn get_team_id(&self) -> u32 {
+        self.team_id
+    }
+
+    pub fn get_team_name(&self) -> &str {
+        &self.team_name
+    }
+
+    pub fn get_department_id(&self) -> i32 {
+        self.department_id
+    }
+
+    pub fn set_department_id(&mut self, department_id: i32) {
+        self.department_id = department_id;
+    }
+
+    pub fn get_agent_ids(&self) -> &BTreeSet<String> {
+        &self.agent_ids
+    }
+
+    pub fn set_team_name(&mut self, team_name: impl Into<String>) {
+        self.team_name = team_name.into();
+    }
+
+    pub fn add_agent(&mut self, agent_id: impl Into<String>) {
+        self.agent_ids.insert(agent_id.into());
+    }
+
+    pub fn remove_agent(&mut self, agent_id: &str) {
+        self.agent_ids.remove(agent_id);
+    }
+}
@@ -0,0 +1,96 @@
+use std::error::Error;
+
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+    trace::{SdkTracer, SdkTracerProvider},
+    Resource,
+};
+use tracing::Metadata;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::{
+    filter::{FilterFn, Filtered},
+    Layer, Registry,
+};
+
+use super::{config::TelemetryConfig, logging::CONNECTION_SPAN_TARGET};
+
+///
+/// OpenTelemetry 내보내기 계층
+///
+pub type TelemetryLayer = Filtered<
+    OpenTelemetryLayer<Registry, SdkTracer>,
+    FilterFn<fn(&Metadata<'_>) -> bool>,
+    Registry,
+>;
+
+///
+/// 추적 종료 가드
+///
+/// 드롭 시 내보내지 못한 span 을 전송하고 exporter 를 종료한다.
+///
+pub struct TelemetryGuard {
+    tracer_provider: SdkTracerProvider,
+    endpoint: String,
+}
+
+impl TelemetryGuard {
+    pub fn get_endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Err(e) = self.tracer_provider.shutdown() {
+            log::warn!("Unable to shutdown tracer provider. {}", e);
+        }
+    }
+}
+
+///
+/// 이벤트 처리 구간 추적 초기화
+///
+/// OTEL_ENABLED 가 true 이면 CTI 수신 → 역직렬화 → CTM 처리 → 브로드캐스트 → 클라이언트 전송 구간의
+/// span 을 OTLP(gRPC)로 내보내는 계층을 생성한다. 비활성화 시 None 을 반환한다.
+/// 로그 이벤트와 연결 단위 span 은 내보내지 않으며, 계층은 로거 초기화(init_logging) 시 등록한다.
+///
+/// tokio 런타임 안에서 호출해야 한다.
+///
+pub fn init_telemetry(
+    config: &TelemetryConfig,
+) -> Result<Option<(TelemetryLayer, TelemetryGuard)>, Box<dyn Error>> {
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    let endpoint = config.endpoint.clone();
+    let service_name = config.service_name.clone();
+
+    let span_exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint.clone())
+        .build()?;
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_batch_exporter(span_exporter)
+        .with_resource(Resource::builder().with_service_name(service_name).build())
+        .build();
+
+    let telemetry_layer = tracing_opentelemetry::layer()
+        .with_tracer(tracer_provider.tracer("ctm"))
+        .with_filter(FilterFn::new(
+            is_telemetry_span as fn(&Metadata<'_>) -> bool,
+        ));
+
+    Ok(Some((
+        telemetry_layer,
+        TelemetryGuard {
+            tracer_provider,
+            endpoint,
+        },
+    )))
+}
+
+fn is_telemetry_span(metadata: &Metadata<'_>) -> bool {
+    metadata.is_span() && metadata.target() != CONNECTION_SPAN_TARGET
+}
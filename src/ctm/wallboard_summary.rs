@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use super::agent_info::AgentInfo;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// 상황판 요약 지표
+///
+/// 모든 CTI 서버 인스턴스의 상담직원 상태를 합산하며, 시간 단위는 초이다.
+///
+pub struct WallboardSummary {
+    agents_logged_in: u32,
+    agents_ready: u32,
+    agents_not_ready: u32,
+    agents_talking: u32,
+    longest_not_ready_cti_instance: String,
+    longest_not_ready_agent_id: String,
+    longest_not_ready_duration: u64,
+    average_state_duration: u64,
+}
+
+impl WallboardSummary {
+    ///
+    /// 상담직원 목록으로 상황판 요약 지표를 계산한다
+    ///
+    /// now 는 현재 UNIX 시간(초)이며, 로그아웃/알수없음 상태의 상담직원은 제외한다.
+    ///
+    pub fn aggregate<'a>(agent_infos: impl Iterator<Item = &'a AgentInfo>, now: u64) -> Self {
+        let mut wallboard_summary = Self::default();
+        let mut total_state_duration = 0;
+
+        agent_infos
+            .filter(|agent_info| !matches!(agent_info.get_agent_state(), 1 | 9))
+            .for_each(|agent_info| {
+                let state_duration = now.saturating_sub(agent_info.get_state_duration());
+
+                wallboard_summary.agents_logged_in += 1;
+                total_state_duration += state_duration;
+
+                match agent_info.get_agent_state() {
+                    // AVAILABLE
+                    3 => wallboard_summary.agents_ready += 1,
+                    // NOT_READY
+                    2 => {
+                        wallboard_summary.agents_not_ready += 1;
+
+                        if wallboard_summary.longest_not_ready_agent_id.is_empty()
+                            || state_duration > wallboard_summary.longest_not_ready_duration
+                        {
+                            wallboard_summary.longest_not_ready_cti_instance =
+                                agent_info.get_cti_instance().to_string();
+                            wallboard_summary.longest_not_ready_agent_id =
+                                agent_info.get_agent_id().to_string();
+                            wallboard_summary.longest_not_ready_duration = state_duration;
+                        }
+                    }
+                    // TALKING, HOLD
+                    4 | 10 => wallboard_summary.agents_talking += 1,
+                    _ => {}
+                }
+            });
+
+        if wallboard_summary.agents_logged_in > 0 {
+            wallboard_summary.average_state_duration =
+                total_state_duration / wallboard_summary.agents_logged_in as u64;
+        }
+
+        wallboard_summary
+    }
+
+    pub fn get_agents_logged_in(&self) -> u32 {
+        self.agents_logged_in
+    }
+
+    pub fn get_agents_ready(&self) -> u32 {
+        self.agents_ready
+    }
+
+    pub fn get_agents_not_ready(&self) -> u32 {
+        self.agents_not_ready
+    }
+
+    pub fn get_agents_talking(&self) -> u32 {
+        self.agents_talking
+    }
+
+    pub fn get_longest_not_ready_cti_instance(&self) -> &str {
+        &self.longest_not_ready_cti_instance
+    }
+
+    pub fn get_longest_not_ready_agent_id(&self) -> &str {
+        &self.longest_not_ready_agent_id
+    }
+
+    pub fn get_longest_not_ready_duration(&self) -> u64 {
+        self.longest_not_ready_duration
+    }
+
+    pub fn get_average_state_duration(&self) -> u64 {
+        self.average_state_duration
+    }
+}
@@ -0,0 +1,151 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::acceptor::payload_format::CLIENT_PROTOCOL_VERSION;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// CTI 서버 이중화 쌍의 접속 상태
+///
+pub struct CtiConnectionStatus {
+    cti_instance: String,
+    connected: bool,
+    peripheral_id: u32,
+    // OPEN_CONF 로 받은 주변장치(PG) 온라인 여부
+    peripheral_online: bool,
+}
+
+impl CtiConnectionStatus {
+    pub fn new(
+        cti_instance: impl Into<String>,
+        connected: bool,
+        peripheral_id: u32,
+        peripheral_online: bool,
+    ) -> Self {
+        Self {
+            cti_instance: cti_instance.into(),
+            connected,
+            peripheral_id,
+            peripheral_online,
+        }
+    }
+
+    pub fn get_cti_instance(&self) -> &str {
+        &self.cti_instance
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    pub fn get_peripheral_id(&self) -> u32 {
+        self.peripheral_id
+    }
+
+    pub fn is_peripheral_online(&self) -> bool {
+        self.peripheral_online
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 접속 안내 (접속 직후 전체 상태보다 먼저 전송)
+///
+/// agent_count 는 이어서 전송할 전체 상태에 포함된 상담직원 수로, 클라이언트는 이를 기준으로
+/// 불러오는 중 상태를 표시할 수 있다. 재접속 시 sequence_epoch 와 마지막 일련번호로 이어 받기를
+/// 요청할 수 있으며, resumed 가 true 이면 전체 상태 대신 놓친 변경분이 이어진다.
+/// timestamp 는 UNIX 시간(밀리초)이다.
+///
+pub struct Welcome {
+    event: String,
+    client_id: String,
+    server_version: String,
+    protocol_version: u32,
+    cti_connections: Vec<CtiConnectionStatus>,
+    // 주변장치가 온라인인 접속 중 CTI 서버의 주변장치 ID
+    active_peripheral_ids: Vec<u32>,
+    agent_count: usize,
+    sequence_epoch: String,
+    resumed: bool,
+    timestamp: u64,
+}
+
+impl Welcome {
+    pub fn new(
+        client_id: Uuid,
+        cti_connections: Vec<CtiConnectionStatus>,
+        agent_count: usize,
+    ) -> Self {
+        let active_peripheral_ids = cti_connections
+            .iter()
+            .filter(|status| status.connected && status.peripheral_online)
+            .map(|status| status.peripheral_id)
+            .collect();
+
+        Self {
+            event: "WELCOME".to_string(),
+            client_id: client_id.to_string(),
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            protocol_version: CLIENT_PROTOCOL_VERSION,
+            cti_connections,
+            active_peripheral_ids,
+            agent_count,
+            sequence_epoch: "".to_string(),
+            resumed: false,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    pub fn get_event(&self) -> &str {
+        &self.event
+    }
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn get_server_version(&self) -> &str {
+        &self.server_version
+    }
+
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version
+    }
+
+    pub fn get_cti_connections(&self) -> &[CtiConnectionStatus] {
+        &self.cti_connections
+    }
+
+    pub fn get_active_peripheral_ids(&self) -> &[u32] {
+        &self.active_peripheral_ids
+    }
+
+    pub fn get_agent_count(&self) -> usize {
+        self.agent_count
+    }
+
+    pub fn get_sequence_epoch(&self) -> &str {
+        &self.sequence_epoch
+    }
+
+    pub fn set_sequence_epoch(&mut self, sequence_epoch: impl Into<String>) {
+        self.sequence_epoch = sequence_epoch.into();
+    }
+
+    pub fn is_resumed(&self) -> bool {
+        self.resumed
+    }
+
+    pub fn set_resumed(&mut self, resumed: bool) {
+        self.resumed = resumed;
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
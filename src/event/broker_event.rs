@@ -1,6 +1,33 @@
+use std::sync::Arc;
+
 use uuid::Uuid;
 
-use crate::ctm::agent_info::AgentInfo;
+use crate::{
+    cisco::{session::CallMessageMask, InvokeId, MonitorId, PeripheralId},
+    ctm::{
+        acceptor::payload_format::SharedPayload,
+        agent_delta::{AgentInfoDelta, AgentSnapshot},
+        agent_info::AgentInfo,
+        agent_query::AgentQueryResult,
+        agent_removal::AgentRemoval,
+        agent_state_history::AgentStateHistory,
+        agent_statistics::AgentStatistics,
+        call_info::CallInfo,
+        client_info::ClientInfo,
+        config::Config,
+        cti_link_status::CtiLinkStatus,
+        cti_sync_state::CtiSyncState,
+        firehose::FirehoseMessage,
+        ha_status::HaStatus,
+        skill_group_info::SkillGroupInfo,
+        snapshot::CtmSnapshot,
+        state_refresh::StateRefreshResult,
+        supervisor_alert::SupervisorAlert,
+        team_info::TeamInfo,
+        wallboard_summary::WallboardSummary,
+        welcome::Welcome,
+    },
+};
 
 ///
 /// 서버-클라이언트 브로커 이벤트
@@ -9,11 +36,150 @@ use crate::ctm::agent_info::AgentInfo;
 pub enum BrokerEvent {
     BroadCastAgentState {
         client_id: Option<Uuid>,
-        agent_info: AgentInfo,
+        agent_info: SharedPayload<AgentInfo>,
+    },
+    BroadCastAgentDelta {
+        client_id: Option<Uuid>,
+        agent_info_delta: AgentInfoDelta,
+    },
+    BroadCastAgentSnapshot {
+        client_id: Option<Uuid>,
+        agent_snapshot: AgentSnapshot,
+    },
+    BroadCastAgentRemoved {
+        client_id: Option<Uuid>,
+        agent_removal: AgentRemoval,
+    },
+    BroadCastAgentStateHistory {
+        client_id: Option<Uuid>,
+        agent_state_history: AgentStateHistory,
+    },
+    BroadCastAgentStatistics {
+        client_id: Option<Uuid>,
+        agent_statistics: AgentStatistics,
+    },
+    BroadCastCallState {
+        client_id: Option<Uuid>,
+        call_info: CallInfo,
+    },
+    BroadCastSkillGroupState {
+        client_id: Option<Uuid>,
+        skill_group_info: SkillGroupInfo,
+    },
+    BroadCastTeamSnapshot {
+        client_id: Option<Uuid>,
+        team_infos: Vec<TeamInfo>,
+    },
+    BroadCastWallboardSummary {
+        client_id: Option<Uuid>,
+        wallboard_summary: WallboardSummary,
+    },
+    BroadCastAgentQueryResult {
+        client_id: Option<Uuid>,
+        agent_query_result: AgentQueryResult,
+    },
+    BroadCastClientList {
+        client_id: Option<Uuid>,
+        client_infos: Vec<ClientInfo>,
+    },
+    // CTI 서버 재접속 후 상태 재동기화 시작/완료
+    BroadCastCtiSyncState {
+        client_id: Option<Uuid>,
+        cti_sync_state: CtiSyncState,
+    },
+    // CTI 서버 연결 상태 (주기 전송)
+    BroadCastCtiLinkStatus {
+        client_id: Option<Uuid>,
+        cti_link_status: CtiLinkStatus,
+    },
+    // HA 역할 안내 (접속 시, 역할 전환 시)
+    BroadCastHaStatus {
+        client_id: Option<Uuid>,
+        ha_status: HaStatus,
+    },
+    // 접속 안내 (접속 직후 전체 상태보다 먼저 전송)
+    BroadCastWelcome {
+        client_id: Option<Uuid>,
+        welcome: Welcome,
+    },
+    // 복호화한 CTI 메시지 원본 (firehose 구독 클라이언트 전용)
+    BroadCastFirehoseMessage {
+        client_id: Option<Uuid>,
+        firehose_message: SharedPayload<FirehoseMessage>,
+    },
+    // 상태 재조회 요청 응답
+    BroadCastStateRefreshResult {
+        client_id: Option<Uuid>,
+        state_refresh_result: StateRefreshResult,
+    },
+    // 관리 명령 EXPORT_STATE 응답 (상태 스냅샷)
+    BroadCastStateSnapshot {
+        client_id: Option<Uuid>,
+        snapshot: CtmSnapshot,
+    },
+    // 관리자 경보 (긴급 호출, 지원 요청 / 구독 조건과 관계없이 모든 클라이언트에 전송)
+    BroadCastSupervisorAlert {
+        client_id: Option<Uuid>,
+        supervisor_alert: SharedPayload<SupervisorAlert>,
+    },
+    // 대상 클라이언트 연결 종료 요청
+    DisconnectClient {
+        client_id: Uuid,
     },
     RequestAgentStateEvent {
-        peripheral_id: u32,
+        cti_instance: String,
+        invoke_id: InvokeId,
+        peripheral_id: PeripheralId,
         agent_id: String,
     },
-    RequestHeartBeatReq,
+    RequestHeartBeatReq {
+        cti_instance: String,
+    },
+    // 세션 모니터의 호 이벤트 마스크 변경 (CHANGE_MONITOR_MASK_REQ 전송)
+    RequestChangeMonitorMask {
+        cti_instance: String,
+        invoke_id: InvokeId,
+        monitor_id: MonitorId,
+        call_msg_mask: CallMessageMask,
+    },
+    // CTI 세션 종료 (HA 대기 노드로 전환, CLOSE_REQ 전송 후 재접속하지 않는다)
+    CloseCtiSession,
+    // 설정 재적용 (다시 읽은 설정의 접속 허용 IP 목록 적용, TLS 인증서 재로드)
+    ReloadConfig {
+        config: Arc<Config>,
+    },
+    // 서버 종료 (신규 접속 수신 중단, 클라이언트 연결 종료, CTI 세션 종료)
+    Shutdown,
+}
+
+impl BrokerEvent {
+    ///
+    /// 대상 클라이언트 ID 반환 (None: 전체 클라이언트)
+    ///
+    pub fn get_client_id(&self) -> Option<Uuid> {
+        match self {
+            BrokerEvent::BroadCastAgentState { client_id, .. }
+            | BrokerEvent::BroadCastAgentDelta { client_id, .. }
+            | BrokerEvent::BroadCastAgentSnapshot { client_id, .. }
+            | BrokerEvent::BroadCastAgentRemoved { client_id, .. }
+            | BrokerEvent::BroadCastAgentStateHistory { client_id, .. }
+            | BrokerEvent::BroadCastAgentStatistics { client_id, .. }
+            | BrokerEvent::BroadCastCallState { client_id, .. }
+            | BrokerEvent::BroadCastSkillGroupState { client_id, .. }
+            | BrokerEvent::BroadCastTeamSnapshot { client_id, .. }
+            | BrokerEvent::BroadCastWallboardSummary { client_id, .. }
+            | BrokerEvent::BroadCastAgentQueryResult { client_id, .. }
+            | BrokerEvent::BroadCastClientList { client_id, .. }
+            | BrokerEvent::BroadCastCtiSyncState { client_id, .. }
+            | BrokerEvent::BroadCastCtiLinkStatus { client_id, .. }
+            | BrokerEvent::BroadCastHaStatus { client_id, .. }
+            | BrokerEvent::BroadCastWelcome { client_id, .. }
+            | BrokerEvent::BroadCastFirehoseMessage { client_id, .. }
+            | BrokerEvent::BroadCastStateRefreshResult { client_id, .. }
+            | BrokerEvent::BroadCastStateSnapshot { client_id, .. }
+            | BrokerEvent::BroadCastSupervisorAlert { client_id, .. } => *client_id,
+            BrokerEvent::DisconnectClient { client_id } => Some(*client_id),
+            _ => None,
+        }
+    }
 }
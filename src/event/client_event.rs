@@ -1,12 +1,41 @@
+use std::net::SocketAddr;
+
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
+use crate::{
+    ctm::{client_auth::ClientPermission, session_resume::SessionResume},
+    event::broker_event::BrokerEvent,
+};
+
 #[allow(unused)]
 #[derive(Debug, Clone)]
 ///
 /// 클라이언트 이벤트
 ///
 pub enum ClientEvent {
-    Connect { id: Uuid },
-    Receive { id: Uuid, data: Vec<u8> },
-    Disconnect { id: Uuid },
+    Connect {
+        id: Uuid,
+        permission: ClientPermission,
+        // 인증 키에 지정된 부서 (해당 부서 상담직원 이벤트만 받는다)
+        department_id: Option<i32>,
+        common_name: Option<String>,
+        addr: Option<SocketAddr>,
+        // 클라이언트 전용 이벤트 큐 (접속 시 전체 상태처럼 한 클라이언트 대상 이벤트를 묶어 보낸다)
+        direct_event_tx: Option<mpsc::UnboundedSender<Vec<BrokerEvent>>>,
+        // 재접속 시 놓친 상담직원 변경분만 받기 위한 이어 받기 요청
+        resume: Option<SessionResume>,
+    },
+    Receive {
+        id: Uuid,
+        data: Vec<u8>,
+    },
+    // 처리가 늦어 브로커 이벤트를 놓침 (전체 상태를 다시 받는다)
+    Lagged {
+        id: Uuid,
+        skipped: u64,
+    },
+    Disconnect {
+        id: Uuid,
+    },
 }
@@ -3,16 +3,24 @@ use crate::cisco::MessageType;
 ///
 /// CTI 서버 이벤트
 ///
+/// cti_instance 는 이벤트를 발생시킨 CTI 서버 이중화 쌍의 이름이다.
+/// 수신 메시지의 span 은 CTM 처리 구간의 상위 span 으로 사용된다.
+///
 #[derive(Debug, Clone)]
 pub enum CTIEvent {
     Error {
+        cti_instance: String,
         cti_server_host: String,
         error_cause: String,
     },
     Recevied {
+        cti_instance: String,
         cti_server_host: String,
         message_type: MessageType,
         data: Vec<u8>,
+        span: tracing::Span,
+    },
+    TimeToHeartBeat {
+        cti_instance: String,
     },
-    TimeToHeartBeat,
 }
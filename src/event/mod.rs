@@ -1,3 +1,4 @@
 pub mod broker_event;
 pub mod client_event;
 pub mod cti_event;
+pub mod system_event;
@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::ctm::ha_status::HaRole;
+
+#[derive(Debug, Clone)]
+///
+/// CTM 시스템 이벤트
+///
+pub enum SystemEvent {
+    // CTI 서버 세션 수립 (OPEN_CONF 수신)
+    CtiConnected {
+        cti_instance: String,
+        cti_server_host: String,
+        // OPEN_CONF 로 받은 이벤트 모니터 ID (0: 모니터 없음)
+        monitor_id: u32,
+    },
+    // CTI 서버 연결 오류
+    CtiError {
+        cti_instance: String,
+        cti_server_host: String,
+        error_cause: String,
+    },
+    // CTI 서버 이중화 전환
+    Failover {
+        cti_instance: String,
+        is_active: bool,
+    },
+    // CTI 서버 하트비트 응답 (HEARTBEAT_CONF 수신)
+    HeartBeatConf {
+        cti_instance: String,
+    },
+    // HA 역할 전환
+    HaRoleChanged {
+        node_id: String,
+        role: HaRole,
+    },
+    // Acceptor 실행 시작
+    AcceptorStarted {
+        name: String,
+    },
+    // Acceptor 실행 종료 (오류로 종료된 경우 error 에 원인을 담는다)
+    AcceptorStopped {
+        name: String,
+        error: Option<String>,
+    },
+    ClientConnected {
+        id: Uuid,
+    },
+    ClientDisconnected {
+        id: Uuid,
+    },
+}
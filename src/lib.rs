@@ -0,0 +1,3 @@
+pub mod cisco;
+pub mod ctm;
+pub mod event;
@@ -1,16 +1,224 @@
-use std::error::Error;
+use std::{error::Error, ffi::OsString, path::PathBuf, sync::Arc};
 
-use ctm::CTM;
+use clap::{Parser, Subcommand};
+use ctm::ctm::{
+    config::{load_config_file, Config},
+    logging::init_logging,
+    service::{install_service, run_service, start_service, stop_service, uninstall_service},
+    shutdown::ShutdownController,
+    simulator::CtiSimulator,
+    telemetry::init_telemetry,
+    CTM,
+};
+use tokio::net::TcpListener;
 
-mod cisco;
-mod ctm;
-mod event;
+///
+/// 명령행 인자
+///
+#[derive(Parser)]
+#[command(version, about = "Cisco CTI 상담직원 상태 모니터")]
+struct Args {
+    /// TOML/YAML 설정 파일 (기본값: CTM_CONFIG_FILE, 환경 변수와 .env 값이 우선한다)
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    log4rs::init_file("log4rs.yml", Default::default())?;
+    /// LOG_LEVEL 설정을 덮어쓴다 (off, error, warn, info, debug, trace 또는 `info,ctm=debug` 형식)
+    #[arg(long, value_name = "LEVEL")]
+    log_level: Option<String>,
 
-    let ctm = CTM::new().await?;
+    /// CTI 서버 대신 저널 파일을 재생한다
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["replay_capture", "simulate"])]
+    replay: Option<PathBuf>,
+
+    /// CTI 서버 대신 원본 트래픽 캡처 파일을 재생한다
+    #[arg(long, value_name = "FILE", conflicts_with = "simulate")]
+    replay_capture: Option<PathBuf>,
+
+    /// CTI 서버 대신 내장 시뮬레이터(CTI_SIM_PORT)에 접속한다 (시나리오 파일 지정 가능)
+    #[arg(long, value_name = "SCRIPT_FILE", num_args = 0..=1)]
+    simulate: Option<Option<String>>,
+
+    /// 저장된 상태 스냅샷 대신 EXPORT_STATE 관리 명령으로 받은 JSON 상태 파일로 시작한다
+    #[arg(long, value_name = "FILE")]
+    import_state: Option<PathBuf>,
+
+    /// 설정과 인증서를 검증한 뒤 종료한다
+    #[arg(long)]
+    check_config: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Windows 서비스 관리
+    Service {
+        #[command(subcommand)]
+        action: ServiceAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum ServiceAction {
+    /// 서비스 등록 (--config, --log-level 을 함께 지정하면 서비스 실행 시 사용한다)
+    Install,
+    /// 서비스 중지 및 등록 해제
+    Uninstall,
+    /// 서비스 시작
+    Start,
+    /// 서비스 중지
+    Stop,
+    /// 서비스 관리자가 실행하는 진입점
+    #[command(hide = true)]
+    Run,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = Args::parse();
+
+    match args.command.take() {
+        Some(Command::Service { action }) => match action {
+            ServiceAction::Install => {
+                install_service(service_launch_arguments(&args)?)?;
+                println!("Service installed.");
+                Ok(())
+            }
+            ServiceAction::Uninstall => {
+                uninstall_service()?;
+                println!("Service uninstalled.");
+                Ok(())
+            }
+            ServiceAction::Start => start_service(),
+            ServiceAction::Stop => stop_service(),
+            ServiceAction::Run => run_service(Box::new(move |shutdown_controller| {
+                run(args, Some(shutdown_controller))
+            })),
+        },
+        None => run(args, None),
+    }
+}
+
+///
+/// 서비스 관리자가 실행 파일에 붙일 인자
+///
+/// 서비스의 작업 폴더가 실행 파일 폴더로 바뀌므로 설정 파일은 절대 경로로 넘긴다.
+///
+fn service_launch_arguments(args: &Args) -> Result<Vec<OsString>, Box<dyn Error>> {
+    let mut launch_arguments = vec![];
+    if let Some(config_file) = &args.config {
+        launch_arguments.push("--config".into());
+        launch_arguments.push(std::path::absolute(config_file)?.into_os_string());
+    }
+    if let Some(log_level) = &args.log_level {
+        launch_arguments.push("--log-level".into());
+        launch_arguments.push(log_level.into());
+    }
+    launch_arguments.push("service".into());
+    launch_arguments.push("run".into());
+
+    Ok(launch_arguments)
+}
+
+///
+/// CTM 실행
+///
+/// Windows 서비스로 실행하면 서비스 관리자의 중지 요청을 받는 종료 제어기가 전달된다.
+///
+fn run(args: Args, shutdown_controller: Option<ShutdownController>) -> Result<(), Box<dyn Error>> {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?
+        .block_on(start(args, shutdown_controller))
+}
+
+async fn start(
+    args: Args,
+    shutdown_controller: Option<ShutdownController>,
+) -> Result<(), Box<dyn Error>> {
+    // 설정 파일의 LOG_LEVEL 을 적용할 수 있도록 로거보다 먼저 읽는다
+    if let Some(config_file) = args.config.clone().or_else(|| {
+        dotenv::var("CTM_CONFIG_FILE")
+            .ok()
+            .filter(|config_file| !config_file.is_empty())
+            .map(PathBuf::from)
+    }) {
+        load_config_file(&config_file)?;
+    }
+
+    // 로그 설정도 함께 검증하므로 로거를 초기화하기 전에 읽는다
+    let mut config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // 설정 검증만 할 때는 추적 데이터를 내보내지 않는다
+    let (telemetry_layer, telemetry_guard) = match args.check_config {
+        true => None,
+        false => init_telemetry(&config.telemetry)?,
+    }
+    .unzip();
+    init_logging(args.log_level, &config.logging, telemetry_layer)?;
+    if let Some(telemetry_guard) = telemetry_guard.as_ref() {
+        log::info!(
+            "OpenTelemetry tracing enabled. endpoint: {}",
+            telemetry_guard.get_endpoint()
+        );
+    }
+
+    // 배포 전 검증: 설정 값과 인증서 파일을 확인하고 종료한다
+    if args.check_config {
+        match config.check_certificates() {
+            Ok(()) => {
+                println!("Configuration is valid.");
+                return Ok(());
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // 내장 시뮬레이터를 실행하고 모든 CTI 서버 인스턴스가 시뮬레이터에 접속하도록 한다
+    if let Some(script_file) = args.simulate {
+        let sim_port = config.simulator.port;
+        let simulator = Arc::new(CtiSimulator::load(script_file, &config.simulator)?);
+        let listener = TcpListener::bind(("127.0.0.1", sim_port)).await?;
+        tokio::spawn(simulator.serve(listener));
+        log::info!("CTI simulator listening. port: {}", sim_port);
+
+        for cti_client in config.cti_clients.iter_mut() {
+            cti_client.side_a_address = "127.0.0.1".to_string();
+            cti_client.side_a_port = sim_port;
+            cti_client.side_b_address = "127.0.0.1".to_string();
+            cti_client.side_b_port = sim_port;
+        }
+    }
+
+    let mut builder = CTM::default_builder(config).await;
+    if let Some(shutdown_controller) = shutdown_controller {
+        builder = builder.with_shutdown_controller(shutdown_controller);
+    }
+    if let Some(state_import_file) = args.import_state {
+        builder = builder.with_state_import(state_import_file);
+    }
+    let ctm = match (args.replay, args.replay_capture) {
+        (Some(replay_file), _) => builder.with_replay(replay_file).build().await?,
+        (None, Some(capture_replay_file)) => {
+            builder
+                .with_capture_replay(capture_replay_file)
+                .build()
+                .await?
+        }
+        (None, None) => builder.build().await?,
+    };
+
+    // SIGTERM/SIGINT 수신 시 클라이언트와 CTI 세션을 정리한 뒤 종료한다
+    ctm.get_shutdown_controller().listen_signals();
     ctm.start().await?;
 
     Ok(())
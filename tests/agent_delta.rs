@@ -0,0 +1,175 @@
+use ctm::{
+    ctm::{
+        agent_delta::{AgentDeltaRecord, AgentDeltaTracker, AgentInfoMirror},
+        agent_info::AgentInfo,
+        agent_removal::{AgentRemoval, AgentRemovalReason},
+    },
+    event::broker_event::BrokerEvent,
+};
+use serde_json::json;
+
+#[test]
+fn delta_carries_changed_fields_only() {
+    let mut agent_delta_tracker = AgentDeltaTracker::new();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_cti_instance("ucce1");
+
+    // 처음 보는 상담직원은 모든 필드를 전송한다
+    let agent_info_delta = agent_delta_tracker.track(&agent_info).unwrap();
+    assert_eq!(agent_info_delta.get_sequence(), 1);
+    assert_eq!(agent_info_delta.get_changes()["agent_id"], json!("1001"));
+
+    // 변경이 없으면 일련번호를 증가시키지 않는다
+    assert!(agent_delta_tracker.track(&agent_info).is_none());
+    assert_eq!(agent_delta_tracker.get_sequence(), 1);
+
+    agent_info.set_agent_name("Hong Gildong");
+    let agent_info_delta = agent_delta_tracker.track(&agent_info).unwrap();
+    assert_eq!(agent_info_delta.get_sequence(), 2);
+    assert_eq!(agent_info_delta.get_cti_instance(), "ucce1");
+    assert_eq!(agent_info_delta.get_agent_id(), "1001");
+    assert_eq!(
+        agent_info_delta.get_changes().keys().collect::<Vec<_>>(),
+        vec!["agent_name"]
+    );
+
+    let agent_snapshot = agent_delta_tracker.snapshot();
+    assert_eq!(agent_snapshot.get_sequence(), 2);
+    assert_eq!(agent_snapshot.get_agent_infos().len(), 1);
+
+    agent_delta_tracker.remove("ucce1", "1001");
+    assert!(agent_delta_tracker.snapshot().get_agent_infos().is_empty());
+}
+
+#[test]
+fn identical_agent_info_is_suppressed() {
+    let mut agent_delta_tracker = AgentDeltaTracker::new();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_agent_state(3);
+    agent_info.set_agent_extension("2001");
+    assert!(agent_delta_tracker.track(&agent_info).is_some());
+
+    // QUERY_AGENT_STATE_CONF 가 같은 값을 다시 반영하는 경우
+    let mut duplicated = agent_info.clone();
+    duplicated.set_agent_state(3);
+    duplicated.set_agent_extension("2001");
+    assert!(agent_delta_tracker.track(&duplicated).is_none());
+
+    // 다른 CTI 서버 인스턴스의 같은 상담직원 ID 는 별도로 추적한다
+    duplicated.set_cti_instance("ucce2");
+    assert!(agent_delta_tracker.track(&duplicated).is_some());
+}
+
+#[test]
+fn delta_applies_to_previous_agent_info() {
+    let mut agent_delta_tracker = AgentDeltaTracker::new();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_cti_instance("ucce1");
+    let agent_info_delta = agent_delta_tracker.track(&agent_info).unwrap();
+
+    // 처음 받은 변경분으로 전체 상태를 만든다
+    let received = agent_info_delta.apply(None).unwrap();
+    assert_eq!(received.get_agent_id(), "1001");
+
+    agent_info.set_agent_state(3);
+    let agent_info_delta = agent_delta_tracker.track(&agent_info).unwrap();
+    let received = agent_info_delta.apply(Some(&received)).unwrap();
+    assert_eq!(received.get_cti_instance(), "ucce1");
+    assert_eq!(received.get_agent_state(), 3);
+
+    // 이전 상태 없이 일부 필드만 있으면 실패한다
+    assert!(agent_info_delta.apply(None).is_err());
+}
+
+#[test]
+fn mirror_follows_broker_events() {
+    let mut agent_delta_tracker = AgentDeltaTracker::new();
+    let mut agent_info_mirror = AgentInfoMirror::new();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_cti_instance("ucce1");
+    let agent_info_delta = agent_delta_tracker.track(&agent_info).unwrap();
+    assert_eq!(
+        agent_info_mirror
+            .apply(&BrokerEvent::BroadCastAgentDelta {
+                client_id: None,
+                agent_info_delta,
+            })
+            .len(),
+        1
+    );
+
+    agent_info.set_agent_state(3);
+    let agent_info_delta = agent_delta_tracker.track(&agent_info).unwrap();
+    let agent_infos = agent_info_mirror.apply(&BrokerEvent::BroadCastAgentDelta {
+        client_id: None,
+        agent_info_delta,
+    });
+    assert_eq!(agent_infos[0].get_agent_state(), 3);
+    assert_eq!(
+        agent_info_mirror
+            .get("ucce1", "1001")
+            .unwrap()
+            .get_agent_state(),
+        3
+    );
+
+    // 제거 이벤트는 상태만 삭제한다
+    let agent_infos = agent_info_mirror.apply(&BrokerEvent::BroadCastAgentRemoved {
+        client_id: None,
+        agent_removal: AgentRemoval::new("ucce1", "1001", AgentRemovalReason::LOGOUT_TIMEOUT),
+    });
+    assert!(agent_infos.is_empty());
+    assert!(agent_info_mirror.get("ucce1", "1001").is_none());
+}
+
+#[test]
+fn history_replays_missed_deltas_and_removals() {
+    let mut agent_delta_tracker = AgentDeltaTracker::with_history_size(3);
+    let epoch = agent_delta_tracker.get_epoch().to_string();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_cti_instance("ucce1");
+    agent_info.set_department_id(7);
+    agent_delta_tracker.track(&agent_info);
+    agent_info.set_agent_state(3);
+    agent_delta_tracker.track(&agent_info);
+    agent_delta_tracker.remove_agent(&AgentRemoval::new(
+        "ucce1",
+        "1001",
+        AgentRemovalReason::LOGOUT_TIMEOUT,
+    ));
+
+    // 1 번까지 받은 클라이언트는 2 번 변경분과 제거를 다시 받는다
+    let records = agent_delta_tracker.replay(&epoch, 1).unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(
+        matches!(records[0].record, AgentDeltaRecord::Delta(delta) if delta.get_sequence() == 2)
+    );
+    assert!(matches!(records[1].record, AgentDeltaRecord::Removal(_)));
+    // 구독 조건 확인을 위해 기록 시점의 부서를 함께 반환한다
+    assert!(records.iter().all(|entry| entry.department_id == Some(7)));
+
+    // 마지막 변경분 직후의 제거는 받았는지 알 수 없으므로 다시 보낸다
+    assert_eq!(agent_delta_tracker.replay(&epoch, 2).unwrap().len(), 1);
+
+    // 다른 구간 식별자나 아직 없는 일련번호는 이어 받을 수 없다
+    assert!(agent_delta_tracker.replay("other", 1).is_none());
+    assert!(agent_delta_tracker.replay(&epoch, 3).is_none());
+
+    // 보관 개수를 넘어 지운 변경분이 필요하면 이어 받을 수 없다
+    let mut agent_info = AgentInfo::new("1002");
+    agent_info.set_cti_instance("ucce1");
+    agent_delta_tracker.track(&agent_info);
+    assert!(agent_delta_tracker.replay(&epoch, 0).is_none());
+    assert_eq!(agent_delta_tracker.replay(&epoch, 1).unwrap().len(), 3);
+    assert_eq!(agent_delta_tracker.replay(&epoch, 2).unwrap().len(), 2);
+    assert!(agent_delta_tracker.replay(&epoch, 3).unwrap().is_empty());
+
+    // 사유 없이 제거하면 이전 일련번호로는 이어 받을 수 없다
+    agent_delta_tracker.remove("ucce1", "1002");
+    assert!(agent_delta_tracker.replay(&epoch, 3).is_none());
+}
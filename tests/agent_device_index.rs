@@ -0,0 +1,54 @@
+mod common;
+
+use ctm::ctm::{agent_device_index::AgentDeviceIndex, agent_info::AgentInfo};
+
+use common::agent_info;
+
+///
+/// 공통 상담원 정보에 내선, 단말 번호를 설정한다
+///
+fn device_agent_info(agent_id: &str, agent_extension: &str, agent_instrument: &str) -> AgentInfo {
+    let mut agent_info = agent_info(agent_id, 10, 3);
+    agent_info.set_agent_extension(agent_extension);
+    agent_info.set_agent_instrument(agent_instrument);
+    agent_info
+}
+
+#[test]
+fn find_agent_by_extension_and_instrument() {
+    let mut agent_device_index = AgentDeviceIndex::default();
+    agent_device_index.update(&device_agent_info("1001", "3001", "SEP0001"));
+    agent_device_index.update(&device_agent_info("1002", "3002", "3002"));
+
+    assert_eq!(agent_device_index.get_agent_id("3001"), Some("1001"));
+    assert_eq!(agent_device_index.get_agent_id("SEP0001"), Some("1001"));
+    assert_eq!(agent_device_index.get_agent_id("3002"), Some("1002"));
+    assert_eq!(agent_device_index.get_agent_id("3003"), None);
+    assert_eq!(agent_device_index.len(), 3);
+}
+
+#[test]
+fn reindex_changed_and_reassigned_devices() {
+    let mut agent_device_index = AgentDeviceIndex::default();
+    agent_device_index.update(&device_agent_info("1001", "3001", ""));
+
+    // 내선을 바꾸면 이전 내선은 색인에서 빠진다
+    agent_device_index.update(&device_agent_info("1001", "3005", ""));
+    assert_eq!(agent_device_index.get_agent_id("3001"), None);
+    assert_eq!(agent_device_index.get_agent_id("3005"), Some("1001"));
+
+    // 다른 상담직원이 같은 내선으로 로그인하면 그 상담직원으로 바뀐다
+    agent_device_index.update(&device_agent_info("1002", "3005", ""));
+    assert_eq!(agent_device_index.get_agent_id("3005"), Some("1002"));
+
+    // 이전 상담직원을 제거해도 새 상담직원의 색인은 남는다
+    agent_device_index.remove("1001");
+    assert_eq!(agent_device_index.get_agent_id("3005"), Some("1002"));
+
+    // 로그아웃하면 내선이 비워져 색인에서 빠진다
+    let mut logged_out = device_agent_info("1002", "3005", "");
+    logged_out.set_agent_state(1);
+    logged_out.set_agent_extension("3005");
+    agent_device_index.update(&logged_out);
+    assert!(agent_device_index.is_empty());
+}
@@ -0,0 +1,70 @@
+use ctm::ctm::{
+    agent_info::AgentInfo, agent_query::AgentQueryResult, client_auth::ClientPermission,
+    command::ClientCommand,
+};
+
+#[test]
+fn parse_query_agent_command() {
+    assert_eq!(
+        ClientCommand::parse(
+            br#"{"command": "QUERY_AGENT", "request_id": "r-1", "agent_id": "1001"}"#
+        )
+        .unwrap(),
+        ClientCommand::QUERY_AGENT {
+            request_id: "r-1".to_string(),
+            cti_instance: "default".to_string(),
+            peripheral_id: None,
+            agent_id: "1001".to_string(),
+        }
+    );
+    assert_eq!(
+        ClientCommand::parse(
+            br#"{"command": "QUERY_AGENT", "request_id": "r-2", "cti_instance": "ucce2", "peripheral_id": 5001, "agent_id": "1002"}"#
+        )
+        .unwrap(),
+        ClientCommand::QUERY_AGENT {
+            request_id: "r-2".to_string(),
+            cti_instance: "ucce2".to_string(),
+            peripheral_id: Some(5001),
+            agent_id: "1002".to_string(),
+        }
+    );
+}
+
+#[test]
+fn parse_find_agent_command() {
+    assert_eq!(
+        ClientCommand::parse(
+            br#"{"command": "FIND_AGENT", "request_id": "r-3", "extension": "3001"}"#
+        )
+        .unwrap(),
+        ClientCommand::FIND_AGENT {
+            request_id: "r-3".to_string(),
+            cti_instance: "default".to_string(),
+            extension: "3001".to_string(),
+        }
+    );
+    assert_eq!(
+        ClientCommand::FIND_AGENT {
+            request_id: "r-3".to_string(),
+            cti_instance: "default".to_string(),
+            extension: "3001".to_string(),
+        }
+        .required_permission(),
+        ClientPermission::READ_ONLY
+    );
+}
+
+#[test]
+fn serialize_agent_query_result() {
+    let agent_query_result =
+        AgentQueryResult::new("r-1", "default", "1001", Some(AgentInfo::new("1001")));
+
+    let value = serde_json::to_value(&agent_query_result).unwrap();
+    assert_eq!(value["request_id"], "r-1");
+    assert_eq!(value["agent_info"]["agent_id"], "1001");
+
+    let timed_out = AgentQueryResult::new("r-2", "default", "1002", None);
+    assert!(timed_out.get_agent_info().is_none());
+    assert!(serde_json::to_value(&timed_out).unwrap()["agent_info"].is_null());
+}
@@ -0,0 +1,13 @@
+use ctm::ctm::agent_removal::{AgentRemoval, AgentRemovalReason};
+
+#[test]
+fn agent_removal_msgpack_roundtrip() {
+    let agent_removal = AgentRemoval::new("ucce1", "1001", AgentRemovalReason::LOGOUT_TIMEOUT);
+
+    let buf = rmp_serde::to_vec_named(&agent_removal).unwrap();
+    let decoded: AgentRemoval = rmp_serde::from_slice(&buf).unwrap();
+
+    assert_eq!(decoded.get_cti_instance(), "ucce1");
+    assert_eq!(decoded.get_agent_id(), "1001");
+    assert_eq!(decoded.get_reason(), AgentRemovalReason::LOGOUT_TIMEOUT);
+}
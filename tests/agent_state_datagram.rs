@@ -0,0 +1,54 @@
+mod common;
+
+use ctm::ctm::agent_state_datagram::{AgentStateDatagram, AGENT_STATE_DATAGRAM_VERSION};
+
+use common::agent_info;
+
+#[test]
+fn agent_state_datagram_roundtrip() {
+    let agent_info = agent_info("1001", 10, 3);
+    let datagram = AgentStateDatagram::from(&agent_info);
+    let buffer = datagram.encode();
+
+    assert_eq!(buffer[0], AGENT_STATE_DATAGRAM_VERSION);
+    assert_eq!(&buffer[1..3], &[0x00, 0x03]);
+    assert_eq!(&buffer[5..9], &[0x00, 0x00, 0x00, 0x0a]);
+    assert_eq!(buffer.len(), 13 + 1 + 4 + 1 + 5);
+
+    let decoded = AgentStateDatagram::decode(&buffer).unwrap();
+    assert_eq!(decoded, datagram);
+    assert_eq!(decoded.get_agent_id(), "1001");
+    assert_eq!(decoded.get_cti_instance(), "ucce1");
+    assert_eq!(decoded.get_agent_state(), 3);
+    assert_eq!(decoded.get_team_id(), 10);
+    assert_eq!(
+        decoded.get_state_start_time() as u64,
+        agent_info.get_state_duration()
+    );
+}
+
+#[test]
+fn agent_state_datagram_truncates_long_strings() {
+    let agent_id = "가".repeat(100);
+    let buffer = AgentStateDatagram::from(&agent_info(&agent_id, 10, 3)).encode();
+
+    // UTF-8 문자 경계에서 잘라 255 바이트를 넘지 않는다
+    let decoded = AgentStateDatagram::decode(&buffer).unwrap();
+    assert_eq!(decoded.get_agent_id(), "가".repeat(85));
+}
+
+#[test]
+fn agent_state_datagram_rejects_invalid() {
+    let buffer = AgentStateDatagram::from(&agent_info("1001", 10, 3)).encode();
+
+    assert!(AgentStateDatagram::decode(&[]).is_none());
+    assert!(AgentStateDatagram::decode(&buffer[0..buffer.len() - 1]).is_none());
+
+    let mut unknown_version = buffer.clone();
+    unknown_version[0] = AGENT_STATE_DATAGRAM_VERSION + 1;
+    assert!(AgentStateDatagram::decode(&unknown_version).is_none());
+
+    let mut trailing = buffer;
+    trailing.push(0);
+    assert!(AgentStateDatagram::decode(&trailing).is_none());
+}
@@ -0,0 +1,60 @@
+use ctm::ctm::{agent_state_history::AgentStateHistory, command::ClientCommand};
+use serde_json::json;
+
+#[test]
+fn history_keeps_latest_transitions() {
+    let mut history = AgentStateHistory::new("1001", 3);
+    history.push(0, 2, 0);
+    history.push(2, 3, 1);
+    history.push(3, 4, 0);
+    history.push(4, 2, 5);
+
+    assert_eq!(history.get_agent_id(), "1001");
+    assert_eq!(
+        history
+            .get_transitions()
+            .iter()
+            .map(|transition| (
+                transition.get_old_state(),
+                transition.get_new_state(),
+                transition.get_reason_code()
+            ))
+            .collect::<Vec<_>>(),
+        vec![(2, 3, 1), (3, 4, 0), (4, 2, 5)]
+    );
+}
+
+#[test]
+fn history_with_zero_capacity() {
+    let mut history = AgentStateHistory::new("1001", 0);
+    history.push(0, 2, 0);
+
+    assert!(history.get_transitions().is_empty());
+}
+
+#[test]
+fn parse_client_command() {
+    let command = json!({"command": "AGENT_STATE_HISTORY", "agent_id": "1001"});
+    let expected = ClientCommand::AGENT_STATE_HISTORY {
+        cti_instance: "default".to_string(),
+        agent_id: "1001".to_string(),
+    };
+
+    let msgpack = rmp_serde::to_vec_named(&command).unwrap();
+    assert_eq!(ClientCommand::parse(&msgpack).unwrap(), expected);
+
+    let text = serde_json::to_vec(&command).unwrap();
+    assert_eq!(ClientCommand::parse(&text).unwrap(), expected);
+
+    let command =
+        json!({"command": "AGENT_STATISTICS", "cti_instance": "ucce2", "agent_id": "1001"});
+    assert_eq!(
+        ClientCommand::parse(&serde_json::to_vec(&command).unwrap()).unwrap(),
+        ClientCommand::AGENT_STATISTICS {
+            cti_instance: "ucce2".to_string(),
+            agent_id: "1001".to_string(),
+        }
+    );
+
+    assert!(ClientCommand::parse(br#"{"command": "UNKNOWN"}"#).is_err());
+}
@@ -0,0 +1,51 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use ctm::ctm::agent_statistics::AgentStatistics;
+
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 5, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+#[test]
+fn accumulate_state_durations_and_counters() {
+    let mut agent_statistics = AgentStatistics::new("1001", at(1, 0, 0).date());
+
+    // NOT_READY(점심) → AVAILABLE → TALKING → HOLD → TALKING → NOT_READY(회의)
+    agent_statistics.update_state(2, 1, at(1, 9, 0));
+    agent_statistics.update_state(3, 0, at(1, 9, 30));
+    agent_statistics.update_state(4, 0, at(1, 9, 40));
+    agent_statistics.update_state(10, 0, at(1, 9, 45));
+    agent_statistics.update_state(4, 0, at(1, 9, 46));
+    agent_statistics.update_state(2, 2, at(1, 9, 50));
+
+    assert_eq!(agent_statistics.get_state_duration(2), 1_800);
+    assert_eq!(agent_statistics.get_state_duration(3), 600);
+    assert_eq!(agent_statistics.get_state_duration(4), 540);
+    assert_eq!(agent_statistics.get_state_duration(10), 60);
+    assert_eq!(agent_statistics.get_call_count(), 1);
+    assert_eq!(agent_statistics.get_not_ready_count(1), 1);
+    assert_eq!(agent_statistics.get_not_ready_count(2), 1);
+
+    // 현재 상태 경과 시간은 스냅샷에만 반영된다
+    let snapshot = agent_statistics.snapshot(at(1, 10, 0));
+    assert_eq!(snapshot.get_state_duration(2), 2_400);
+    assert_eq!(agent_statistics.get_state_duration(2), 1_800);
+}
+
+#[test]
+fn roll_over_at_midnight() {
+    let mut agent_statistics = AgentStatistics::new("1001", at(1, 0, 0).date());
+    agent_statistics.update_state(4, 0, at(1, 23, 50));
+
+    assert!(!agent_statistics.roll_over(at(1, 23, 59)));
+    assert!(agent_statistics.roll_over(at(2, 0, 10)));
+    assert_eq!(agent_statistics.get_date(), at(2, 0, 0).date());
+    assert_eq!(agent_statistics.get_call_count(), 0);
+    assert_eq!(agent_statistics.get_state_duration(4), 0);
+
+    // 자정부터 다시 누적한다
+    agent_statistics.update_state(3, 0, at(2, 0, 10));
+    assert_eq!(agent_statistics.get_state_duration(4), 600);
+}
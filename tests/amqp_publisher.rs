@@ -0,0 +1,74 @@
+use ctm::{
+    ctm::{
+        acceptor::amqp_publisher::AmqpPublisher,
+        agent_delta::AgentInfoMirror,
+        agent_info::AgentInfo,
+        agent_removal::{AgentRemoval, AgentRemovalReason},
+        skill_group_info::SkillGroupInfo,
+    },
+    event::broker_event::BrokerEvent,
+};
+
+#[test]
+fn amqp_routing_key() {
+    assert_eq!(
+        AmqpPublisher::routing_key(&["agent", "ucce1", "10", "1001"]),
+        "agent.ucce1.10.1001"
+    );
+    assert_eq!(
+        AmqpPublisher::routing_key(&["agent", "ucce.1", "10", "*#"]),
+        "agent.ucce_1.10.__"
+    );
+}
+
+#[test]
+fn amqp_messages_routed_by_team_and_skill_group() {
+    let mut agent_info_mirror = AgentInfoMirror::new();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_cti_instance("ucce1");
+    agent_info.set_team(10, "Sales");
+    let messages = AmqpPublisher::messages(
+        &mut agent_info_mirror,
+        &BrokerEvent::BroadCastAgentState {
+            client_id: None,
+            agent_info: agent_info.into(),
+        },
+    )
+    .unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].0, "agent.ucce1.10.1001");
+
+    // 제거 이벤트는 마지막 상태의 팀으로 라우팅한다
+    let messages = AmqpPublisher::messages(
+        &mut agent_info_mirror,
+        &BrokerEvent::BroadCastAgentRemoved {
+            client_id: None,
+            agent_removal: AgentRemoval::new("ucce1", "1001", AgentRemovalReason::LOGOUT_TIMEOUT),
+        },
+    )
+    .unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].0, "agent_removed.ucce1.10.1001");
+
+    let mut skill_group_info = SkillGroupInfo::new(7);
+    skill_group_info.set_cti_instance("ucce1");
+    let messages = AmqpPublisher::messages(
+        &mut agent_info_mirror,
+        &BrokerEvent::BroadCastSkillGroupState {
+            client_id: None,
+            skill_group_info,
+        },
+    )
+    .unwrap();
+    assert_eq!(messages[0].0, "skill_group.ucce1.7");
+
+    let messages = AmqpPublisher::messages(
+        &mut agent_info_mirror,
+        &BrokerEvent::RequestHeartBeatReq {
+            cti_instance: "ucce1".to_string(),
+        },
+    )
+    .unwrap();
+    assert!(messages.is_empty());
+}
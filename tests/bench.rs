@@ -0,0 +1,146 @@
+use std::{sync::Arc, time::Duration};
+
+use ctm::{
+    cisco::PeripheralId,
+    ctm::{
+        bench::{
+            bench_script, parse_agent_event, run_bench, BenchConfig, BenchProtocol, LatencyStats,
+        },
+        simulator::CtiSimulator,
+    },
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+#[test]
+fn bench_script_tags_events_with_sequence() {
+    let script = bench_script(3, Duration::from_millis(20));
+
+    assert_eq!(script.teams[0].agents.len(), 3);
+    assert_eq!(script.events.len(), u16::MAX as usize);
+    assert_eq!(script.events[0].delay, 20);
+    assert_eq!(
+        script.events[..4]
+            .iter()
+            .map(|event| (
+                event.agent_id.as_str(),
+                event.agent_state,
+                event.reason_code
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            ("1001", 3, 1),
+            ("1002", 3, 2),
+            ("1003", 3, 3),
+            ("1001", 2, 4)
+        ]
+    );
+    assert!(bench_script(0, Duration::from_millis(20)).events.is_empty());
+}
+
+#[test]
+fn agent_event_is_parsed_from_plain_and_versioned_json() {
+    assert_eq!(
+        parse_agent_event(br#"{"agent_id": "1001", "agent_state": 3, "reason_code": 7}"#),
+        Some(("1001".to_string(), 7))
+    );
+    assert_eq!(
+        parse_agent_event(br#"{"version": 1, "payload": {"agent_id": "1002", "reason_code": 8}}"#),
+        Some(("1002".to_string(), 8))
+    );
+    assert_eq!(parse_agent_event(br#"[{"agent_id": "1001"}]"#), None);
+    assert_eq!(parse_agent_event(b"not json"), None);
+}
+
+#[test]
+fn latency_percentiles() {
+    let mut latency = LatencyStats::default();
+    assert_eq!(latency.percentile(50.0), None);
+
+    for millis in (1..=100).rev() {
+        latency.record(Duration::from_millis(millis));
+    }
+
+    assert_eq!(latency.len(), 100);
+    assert_eq!(latency.min(), Some(Duration::from_millis(1)));
+    assert_eq!(latency.max(), Some(Duration::from_millis(100)));
+    assert_eq!(latency.percentile(50.0), Some(Duration::from_millis(51)));
+    assert_eq!(latency.percentile(99.0), Some(Duration::from_millis(99)));
+    assert_eq!(latency.mean(), Some(Duration::from_micros(50_500)));
+    assert_eq!(BenchProtocol::parse("WS"), Some(BenchProtocol::WEBSOCKET));
+}
+
+#[tokio::test]
+async fn bench_measures_events_relayed_to_tcp_clients() {
+    let simulator = Arc::new(CtiSimulator::new(
+        bench_script(2, Duration::from_millis(10)),
+        PeripheralId(5000),
+        Duration::from_millis(10),
+    ));
+
+    // 시뮬레이터 이벤트를 JSON 줄로 전달하는 TCP 서버 (첫 번째 클라이언트에는 1001 이벤트만 전달)
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    {
+        let simulator = simulator.clone();
+        tokio::spawn(async move {
+            for client_index in 0.. {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut simulated_event_rx = simulator.subscribe_events();
+                tokio::spawn(async move {
+                    let mut buffer = [0_u8; 1_024];
+                    let _ = stream.read(&mut buffer).await;
+
+                    while let Ok(simulated_event) = simulated_event_rx.recv().await {
+                        if client_index == 0 && simulated_event.agent_id != "1001" {
+                            continue;
+                        }
+                        let line = format!(
+                            "{{\"agent_id\":\"{}\",\"reason_code\":{}}}\n",
+                            simulated_event.agent_id, simulated_event.reason_code
+                        );
+                        if stream.write_all(line.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    let events = {
+        let simulator = simulator.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            for (agent_id, reason_code) in [("1001", 1), ("1002", 2), ("1001", 3), ("1002", 4)] {
+                simulator.change_agent_state(agent_id, 3, reason_code);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+    };
+
+    let report = run_bench(
+        BenchConfig {
+            protocol: BenchProtocol::TCP,
+            address: address.to_string(),
+            websocket_path: "/ctmonitor".to_string(),
+            connections: 2,
+            duration: Duration::from_millis(500),
+            drain_timeout: Duration::from_millis(200),
+            team_ids: vec![],
+        },
+        &simulator,
+    )
+    .await;
+    events.await.unwrap();
+
+    assert_eq!(report.connected, 2);
+    assert_eq!(report.sent_events, 4);
+    assert_eq!(report.expected_events, 8);
+    assert_eq!(report.received_events, 6);
+    assert_eq!(report.dropped_events, 2);
+    assert_eq!(report.latency.len(), 6);
+    assert_eq!(report.drop_rate(), 0.25);
+}
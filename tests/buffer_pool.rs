@@ -0,0 +1,35 @@
+use ctm::ctm::buffer_pool::BufferPool;
+
+#[test]
+fn buffer_pool_reuses_returned_buffers() {
+    let buffer_pool = BufferPool::new(1_024, 4_096, 2);
+    assert_eq!(buffer_pool.get_pooled_count(), 0);
+
+    let mut buffer = buffer_pool.get();
+    buffer.extend_from_slice(b"hello");
+    let pointer = buffer.as_ptr();
+    drop(buffer);
+    assert_eq!(buffer_pool.get_pooled_count(), 1);
+
+    // 돌려받은 버퍼는 비워진 채로 다시 빌려준다
+    let buffer = buffer_pool.get();
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.as_ptr(), pointer);
+    assert_eq!(buffer_pool.get_pooled_count(), 0);
+}
+
+#[test]
+fn buffer_pool_limits_retained_buffers() {
+    let buffer_pool = BufferPool::new(1_024, 4_096, 2);
+
+    let buffers = (0..3).map(|_| buffer_pool.get()).collect::<Vec<_>>();
+    drop(buffers);
+    assert_eq!(buffer_pool.get_pooled_count(), 2);
+
+    // 최대 용량을 넘게 커진 버퍼는 보관하지 않는다
+    let buffer_pool = BufferPool::new(1_024, 4_096, 2);
+    let mut buffer = buffer_pool.get();
+    buffer.extend_from_slice(&[0_u8; 8_192]);
+    drop(buffer);
+    assert_eq!(buffer_pool.get_pooled_count(), 0);
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use ctm::cisco::{codec::NetworkByteOrder, Deserializable, Serializable};
+
+use common::load_fixture;
+
+#[test]
+fn numbers_serialize_in_network_byte_order() {
+    assert_eq!(0x12_u8.serialize(), vec![0x12]);
+    assert_eq!((-2_i8).serialize(), vec![0xFE]);
+    assert_eq!(0x1234_u16.serialize(), vec![0x12, 0x34]);
+    assert_eq!((-2_i16).serialize(), vec![0xFF, 0xFE]);
+    assert_eq!(0x1234_5678_u32.serialize(), vec![0x12, 0x34, 0x56, 0x78]);
+    assert_eq!((-2_i32).serialize(), vec![0xFF, 0xFF, 0xFF, 0xFE]);
+    assert_eq!(
+        0x0102_0304_0506_0708_u64.serialize(),
+        vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+    );
+    assert_eq!(
+        (-2_i64).serialize(),
+        vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]
+    );
+    assert_eq!(true.serialize(), vec![0x00, 0x01]);
+    assert_eq!(1.0_f32.serialize(), vec![0x3F, 0x80, 0x00, 0x00]);
+}
+
+#[test]
+fn network_byte_order_matches_serializable() {
+    assert_eq!(u32::LENGTH, 4);
+    assert_eq!(i64::LENGTH, 8);
+    assert_eq!(u32::from_network_bytes(&[0x00, 0x00, 0x00, 0x34, 0xFF]), 52);
+    assert_eq!(
+        0x1234_5678_u32.to_network_bytes(),
+        0x1234_5678_u32.serialize()
+    );
+}
+
+#[test]
+fn open_conf_capture_fields_decode_in_network_byte_order() {
+    // OPEN_CONF 캡처의 고정 필드를 기본 타입으로 차례대로 읽는다
+    let mut buffer = load_fixture("open_conf.hex");
+
+    let (mut buffer, length) = u32::deserialize(&mut buffer);
+    assert_eq!(length, 52);
+    let (mut buffer, message_type) = u32::deserialize(&mut buffer);
+    assert_eq!(message_type, 4);
+    let (mut buffer, invoke_id) = u32::deserialize(&mut buffer);
+    assert_eq!(invoke_id, 1);
+    let (mut buffer, services_granted) = u32::deserialize(&mut buffer);
+    assert_eq!(services_granted, 0x8000_0094);
+    let (mut buffer, monitor_id) = u32::deserialize(&mut buffer);
+    assert_eq!(monitor_id, 7);
+    let (mut buffer, pg_status) = u32::deserialize(&mut buffer);
+    assert_eq!(pg_status, 0);
+    let (mut buffer, icm_central_controller_time) = u32::deserialize(&mut buffer);
+    assert_eq!(icm_central_controller_time, 1_700_000_000);
+    let (mut buffer, peripheral_online) = bool::deserialize(&mut buffer);
+    assert!(peripheral_online);
+    let (mut buffer, peripheral_type) = u16::deserialize(&mut buffer);
+    assert_eq!(peripheral_type, 17);
+    let (mut buffer, agent_state) = u16::deserialize(&mut buffer);
+    assert_eq!(agent_state, 0);
+    let (mut buffer, department_id) = i32::deserialize(&mut buffer);
+    assert_eq!(department_id, -1);
+    let (_, session_type) = u16::deserialize(&mut buffer);
+    assert_eq!(session_type, 0);
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use ctm::{
+    cisco::ConnectionCallId,
+    ctm::{call_batch::CallBroadcastBatch, call_info::CallState},
+};
+
+use common::call_info;
+
+#[test]
+fn call_batch_keeps_last_state_per_call() {
+    let now = Instant::now();
+    let mut call_broadcast_batch = CallBroadcastBatch::new(Duration::from_millis(200), 100, now);
+    assert!(!call_broadcast_batch.is_due(now + Duration::from_secs(1)));
+
+    call_broadcast_batch.push(call_info(1, CallState::BEGIN));
+    call_broadcast_batch.push(call_info(2, CallState::QUEUED));
+    call_broadcast_batch.push(call_info(1, CallState::ESTABLISHED));
+    assert_eq!(call_broadcast_batch.len(), 2);
+    assert!(!call_broadcast_batch.is_due(now + Duration::from_millis(199)));
+    assert!(call_broadcast_batch.is_due(now + Duration::from_millis(200)));
+
+    // 처음 바뀐 순서대로 마지막 상태를 꺼낸다
+    let call_infos = call_broadcast_batch.take(now + Duration::from_millis(200));
+    assert_eq!(
+        call_infos
+            .iter()
+            .map(|call_info| (
+                call_info.get_connection_call_id(),
+                call_info.get_call_state()
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (ConnectionCallId(1), CallState::ESTABLISHED),
+            (ConnectionCallId(2), CallState::QUEUED),
+        ]
+    );
+    assert!(call_broadcast_batch.is_empty());
+
+    call_broadcast_batch.push(call_info(1, CallState::CLEARED));
+    assert!(!call_broadcast_batch.is_due(now + Duration::from_millis(300)));
+    assert_eq!(call_broadcast_batch.len(), 1);
+}
+
+#[test]
+fn call_batch_flushes_when_full() {
+    let now = Instant::now();
+    let mut call_broadcast_batch = CallBroadcastBatch::new(Duration::from_secs(60), 2, now);
+
+    call_broadcast_batch.push(call_info(1, CallState::BEGIN));
+    assert!(!call_broadcast_batch.is_due(now));
+    call_broadcast_batch.push(call_info(2, CallState::BEGIN));
+    assert!(call_broadcast_batch.is_due(now));
+    assert_eq!(call_broadcast_batch.take(now).len(), 2);
+}
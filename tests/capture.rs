@@ -0,0 +1,131 @@
+use std::fs;
+
+use ctm::{
+    cisco::{session::heartbeat_conf::HeartBeatConf, InvokeId, MessageType, Serializable, MHDR},
+    ctm::capture::{
+        read_capture, replay_capture, Capture, CaptureRecord, CaptureStream, CaptureWriter,
+    },
+    event::cti_event::CTIEvent,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::mpsc,
+};
+
+fn heartbeat_conf(invoke_id: u32) -> Vec<u8> {
+    HeartBeatConf {
+        mhdr: MHDR {
+            length: 4,
+            message_type: MessageType::HEARTBEAT_CONF,
+        },
+        invoke_id: InvokeId(invoke_id),
+    }
+    .serialize()
+}
+
+#[test]
+fn capture_write_and_read() {
+    let directory = std::env::temp_dir().join(format!("ctm_capture_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+
+    let mut capture_writer = CaptureWriter::create(&directory, "ucce1", "172.30.1.11").unwrap();
+    capture_writer.write_record(1_000, &[1, 2, 3]).unwrap();
+    capture_writer.write_record(2_500, &[4]).unwrap();
+
+    let capture = read_capture(capture_writer.get_path()).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(
+        capture,
+        Capture {
+            cti_instance: "ucce1".to_string(),
+            cti_server_host: "172.30.1.11".to_string(),
+            records: vec![
+                CaptureRecord {
+                    timestamp: 1_000,
+                    data: vec![1, 2, 3],
+                },
+                CaptureRecord {
+                    timestamp: 2_500,
+                    data: vec![4],
+                },
+            ],
+        }
+    );
+}
+
+#[tokio::test]
+async fn capture_stream_records_received_bytes() {
+    let directory = std::env::temp_dir().join(format!("ctm_capture_stream_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+
+    let capture_writer = CaptureWriter::create(&directory, "ucce1", "172.30.1.11").unwrap();
+    let path = capture_writer.get_path().to_path_buf();
+    let (mut server, client) = tokio::io::duplex(64);
+    let mut client = CaptureStream::new(client, Some(capture_writer));
+
+    let data = [heartbeat_conf(1), heartbeat_conf(2)].concat();
+    server.write_all(&data).await.unwrap();
+    drop(server);
+    let mut received = vec![];
+    client.read_to_end(&mut received).await.unwrap();
+    drop(client);
+
+    let capture = read_capture(&path).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(received, data);
+    assert_eq!(
+        capture
+            .records
+            .into_iter()
+            .flat_map(|record| record.data)
+            .collect::<Vec<_>>(),
+        data
+    );
+}
+
+#[tokio::test]
+async fn capture_replay_reframes_split_messages() {
+    let data = [heartbeat_conf(1), heartbeat_conf(2)].concat();
+
+    // 메시지 경계와 다르게 나뉜 레코드도 코덱을 거쳐 원래 메시지로 재조립된다
+    let capture = Capture {
+        cti_instance: "ucce1".to_string(),
+        cti_server_host: "172.30.1.11".to_string(),
+        records: vec![
+            CaptureRecord {
+                timestamp: 0,
+                data: data[..5].to_vec(),
+            },
+            CaptureRecord {
+                timestamp: 10,
+                data: data[5..15].to_vec(),
+            },
+            CaptureRecord {
+                timestamp: 20,
+                data: data[15..].to_vec(),
+            },
+        ],
+    };
+
+    let (cti_event_channel_tx, mut cti_event_channel_rx) = mpsc::channel(16);
+    replay_capture(capture, 0.0, cti_event_channel_tx).await;
+
+    for invoke_id in 1..=2 {
+        match cti_event_channel_rx.recv().await.unwrap() {
+            CTIEvent::Recevied {
+                cti_instance,
+                message_type,
+                data,
+                ..
+            } => {
+                assert_eq!(cti_instance, "ucce1");
+                assert_eq!(message_type, MessageType::HEARTBEAT_CONF);
+                assert_eq!(data, heartbeat_conf(invoke_id));
+            }
+            event => panic!("Unexpected CTI event. {:?}", event),
+        }
+    }
+    assert!(cti_event_channel_rx.recv().await.is_none());
+}
@@ -0,0 +1,32 @@
+use ctm::ctm::channel_metrics::ChannelMetrics;
+
+#[test]
+fn channel_metrics_record_lagged_and_resync() {
+    let channel_metrics = ChannelMetrics::new();
+    channel_metrics.set_broker_event_capacity(4_096);
+
+    channel_metrics.record_lagged(10);
+    channel_metrics.record_lagged(5);
+    channel_metrics.record_resync();
+
+    assert_eq!(channel_metrics.get_broker_event_capacity(), 4_096);
+    assert_eq!(channel_metrics.get_lagged_total(), 2);
+    assert_eq!(channel_metrics.get_lagged_events_total(), 15);
+    assert_eq!(channel_metrics.get_resync_total(), 1);
+}
+
+#[test]
+fn channel_metrics_to_prometheus() {
+    let channel_metrics = ChannelMetrics::new();
+    channel_metrics.set_broker_event_capacity(1_024);
+    channel_metrics.record_lagged(3);
+
+    let text = channel_metrics.to_prometheus();
+    assert!(text
+        .contains("# TYPE ctm_broker_channel_capacity gauge\nctm_broker_channel_capacity 1024\n"));
+    assert!(text.contains(
+        "# TYPE ctm_broker_channel_lagged_total counter\nctm_broker_channel_lagged_total 1\n"
+    ));
+    assert!(text.contains("ctm_broker_channel_lagged_events_total 3\n"));
+    assert!(text.contains("ctm_client_resync_total 0\n"));
+}
@@ -0,0 +1,145 @@
+mod common;
+
+use std::collections::HashMap;
+
+use ctm::ctm::{
+    client_auth::{ClientAuthenticator, ClientPermission},
+    command::ClientCommand,
+};
+
+use common::fixture_path;
+
+#[test]
+fn client_auth_key_permissions() {
+    let authenticator = ClientAuthenticator::from_file(fixture_path("client_auth.json")).unwrap();
+
+    assert!(authenticator.is_enabled());
+    assert_eq!(
+        authenticator.authenticate(Some("viewer-key")),
+        Some(ClientPermission::READ_ONLY)
+    );
+    assert_eq!(
+        authenticator.authenticate(Some("operator-key")),
+        Some(ClientPermission::CONTROL)
+    );
+    assert_eq!(
+        authenticator.authenticate(Some("admin-key")),
+        Some(ClientPermission::ADMIN)
+    );
+    assert_eq!(authenticator.authenticate(Some("unknown-key")), None);
+    assert_eq!(authenticator.authenticate(None), None);
+}
+
+#[test]
+fn client_auth_disabled_grants_control() {
+    let authenticator = ClientAuthenticator::default();
+
+    assert!(!authenticator.is_enabled());
+    assert_eq!(
+        authenticator.authenticate(None),
+        Some(ClientPermission::CONTROL)
+    );
+}
+
+#[test]
+fn client_auth_enabled_without_keys_rejects() {
+    let authenticator = ClientAuthenticator::new(true, HashMap::new());
+
+    assert_eq!(authenticator.authenticate(Some("viewer-key")), None);
+}
+
+#[test]
+fn client_auth_key_departments() {
+    let authenticator = ClientAuthenticator::from_file(fixture_path("client_auth.json")).unwrap();
+
+    assert_eq!(
+        authenticator.authenticate(Some("sales-viewer-key")),
+        Some(ClientPermission::READ_ONLY)
+    );
+    assert_eq!(
+        authenticator.get_department_id(Some("sales-viewer-key")),
+        Some(7)
+    );
+    assert_eq!(authenticator.get_department_id(Some("viewer-key")), None);
+    assert_eq!(authenticator.get_department_id(None), None);
+}
+
+#[test]
+fn client_auth_department_required() {
+    let authenticator = ClientAuthenticator::from_file(fixture_path("client_auth.json"))
+        .unwrap()
+        .with_department_required(true);
+
+    // 부서가 없는 키는 관리 권한만 허용한다
+    assert_eq!(authenticator.authenticate(Some("viewer-key")), None);
+    assert_eq!(authenticator.authenticate(Some("operator-key")), None);
+    assert_eq!(
+        authenticator.authenticate(Some("admin-key")),
+        Some(ClientPermission::ADMIN)
+    );
+    assert_eq!(
+        authenticator.authenticate(Some("sales-viewer-key")),
+        Some(ClientPermission::READ_ONLY)
+    );
+}
+
+#[test]
+fn client_auth_parse_login() {
+    let msgpack = rmp_serde::to_vec_named(&HashMap::from([("token", "viewer-key")])).unwrap();
+    assert_eq!(
+        ClientAuthenticator::parse_login(&msgpack),
+        Some("viewer-key".to_string())
+    );
+
+    assert_eq!(
+        ClientAuthenticator::parse_login(br#"{"token": "operator-key", "format": "json"}"#),
+        Some("operator-key".to_string())
+    );
+    assert_eq!(
+        ClientAuthenticator::parse_login(br#"{"format": "json"}"#),
+        None
+    );
+}
+
+#[test]
+fn client_command_control() {
+    let query_agent = ClientCommand::parse(
+        br#"{"command": "QUERY_AGENT", "request_id": "1", "agent_id": "1001"}"#,
+    )
+    .unwrap();
+    assert!(query_agent.is_control());
+
+    let snapshot = ClientCommand::parse(br#"{"command": "AGENT_SNAPSHOT"}"#).unwrap();
+    assert!(!snapshot.is_control());
+}
+
+#[test]
+fn client_command_admin() {
+    for data in [
+        br#"{"command": "LIST_CLIENTS"}"#.as_slice(),
+        br#"{"command": "DISCONNECT_CLIENT", "client_id": "0190d7a4-8f2e-7000-8000-000000000001"}"#,
+        br#"{"command": "REBROADCAST"}"#,
+        br#"{"command": "DUMP_AGENTS"}"#,
+        br#"{"command": "EXPORT_STATE"}"#,
+        br#"{"command": "RELOAD_CONFIG"}"#,
+    ] {
+        let command = ClientCommand::parse(data).unwrap();
+        assert!(command.is_admin());
+        assert_eq!(command.required_permission(), ClientPermission::ADMIN);
+    }
+
+    let query_agent = ClientCommand::parse(
+        br#"{"command": "QUERY_AGENT", "request_id": "1", "agent_id": "1001"}"#,
+    )
+    .unwrap();
+    assert_eq!(query_agent.required_permission(), ClientPermission::CONTROL);
+
+    let snapshot = ClientCommand::parse(br#"{"command": "AGENT_SNAPSHOT"}"#).unwrap();
+    assert_eq!(snapshot.required_permission(), ClientPermission::READ_ONLY);
+}
+
+#[test]
+fn client_permission_order() {
+    assert!(ClientPermission::READ_ONLY < ClientPermission::CONTROL);
+    assert!(ClientPermission::CONTROL < ClientPermission::ADMIN);
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use ctm::ctm::acceptor::client_cert::{client_cert_verifier, common_name};
+use rustls::pki_types::{pem::PemObject, CertificateDer};
+
+use common::fixture_path;
+
+#[test]
+fn client_cert_common_name() {
+    let cert = CertificateDer::from_pem_file(fixture_path("client.crt")).unwrap();
+    assert_eq!(common_name(&cert), Some("wallboard-01".to_string()));
+
+    let ca = CertificateDer::from_pem_file(fixture_path("client_ca.crt")).unwrap();
+    assert_eq!(common_name(&ca), Some("ctm-test-ca".to_string()));
+}
+
+#[test]
+fn client_cert_common_name_invalid() {
+    assert_eq!(common_name(&[]), None);
+    assert_eq!(common_name(&[0x30, 0x82, 0xff]), None);
+
+    let cert = CertificateDer::from_pem_file(fixture_path("client.crt")).unwrap();
+    assert_eq!(common_name(&cert[0..cert.len() / 4]), None);
+}
+
+#[test]
+fn client_cert_verifier_ca_file() {
+    assert!(client_cert_verifier(fixture_path("client_ca.crt").to_str().unwrap()).is_ok());
+    assert!(client_cert_verifier(fixture_path("not_found.crt").to_str().unwrap()).is_err());
+}
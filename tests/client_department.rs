@@ -0,0 +1,188 @@
+use std::{error::Error, fs, time::Duration};
+
+use async_trait::async_trait;
+use ctm::{
+    cisco::{
+        supervisor::agent_team_config_event::{AgentTeamConfigEvent, AgentTeamConfigEventAgent},
+        FloatingField, MessageType, PeripheralId, Serializable, TagValue, MHDR,
+    },
+    ctm::{
+        acceptor::Acceptor,
+        client_auth::ClientPermission,
+        journal::{JournalRecord, JournalWriter},
+        CTM,
+    },
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+use tokio::{
+    sync::{broadcast, mpsc},
+    time::timeout,
+};
+use uuid::Uuid;
+
+///
+/// 부서를 지정한 AGENT_TEAM_CONFIG_EVENT (상담직원은 모두 대기 상태)
+///
+fn agent_team_config_event(team_id: u32, department_id: i32, agent_ids: &[&str]) -> Vec<u8> {
+    AgentTeamConfigEvent {
+        mhdr: MHDR {
+            length: 0,
+            message_type: MessageType::AGENT_TEAM_CONFIG_EVENT,
+        },
+        peripheral_id: PeripheralId(5000),
+        team_id,
+        number_of_agents: agent_ids.len() as u16,
+        config_operation: 1,
+        department_id,
+        agent_team_name: Some(FloatingField::new(
+            TagValue::AGENT_TEAM_NAME_TAG,
+            format!("Team {}", team_id),
+        )),
+        agents: agent_ids
+            .iter()
+            .map(|agent_id| AgentTeamConfigEventAgent {
+                agent_id: Some(FloatingField::new(
+                    TagValue::ATC_AGENT_ID_TAG,
+                    agent_id.to_string(),
+                )),
+                agent_state: Some(FloatingField::new(TagValue::ATC_AGENT_STATE_TAG, 3)),
+                ..Default::default()
+            })
+            .collect(),
+        unknown_fields: vec![],
+    }
+    .serialize()
+}
+
+///
+/// 팀 구성이 반영된 뒤 부서가 다른 두 클라이언트로 접속하여 받은 전체 상태를 전달하는 Acceptor
+///
+struct DepartmentProbe {
+    client_state_tx: mpsc::UnboundedSender<(i32, Vec<BrokerEvent>)>,
+}
+
+#[async_trait]
+impl Acceptor for DepartmentProbe {
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        // 두 팀이 모두 반영될 때까지 기다린다
+        loop {
+            if let BrokerEvent::BroadCastTeamSnapshot { team_infos, .. } =
+                broker_event_channel_rx.recv().await?
+            {
+                if team_infos.len() == 2 {
+                    break;
+                }
+            }
+        }
+
+        for department_id in [7, 8] {
+            let (direct_event_tx, mut direct_event_rx) = mpsc::unbounded_channel();
+            client_event_channel_tx
+                .send(ClientEvent::Connect {
+                    id: Uuid::now_v7(),
+                    permission: ClientPermission::READ_ONLY,
+                    department_id: Some(department_id),
+                    common_name: None,
+                    addr: None,
+                    direct_event_tx: Some(direct_event_tx),
+                    resume: None,
+                })
+                .await?;
+
+            if let Some(broker_events) = direct_event_rx.recv().await {
+                self.client_state_tx.send((department_id, broker_events))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn clients_receive_only_their_department_teams_and_summary() {
+    // 부서 7 팀(상담직원 1명)과 부서 8 팀(상담직원 2명)을 재생한다
+    let directory =
+        std::env::temp_dir().join(format!("ctm_client_department_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    let mut journal_writer = JournalWriter::new(&directory, u64::MAX);
+    [
+        agent_team_config_event(10, 7, &["1001"]),
+        agent_team_config_event(20, 8, &["2001", "2002"]),
+    ]
+    .into_iter()
+    .for_each(|data| {
+        journal_writer
+            .write(&JournalRecord::new(
+                "ucce1",
+                "127.0.0.1",
+                MessageType::AGENT_TEAM_CONFIG_EVENT,
+                data,
+            ))
+            .unwrap();
+    });
+    let journal_file = fs::read_dir(&directory)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+
+    let (client_state_tx, mut client_state_rx) = mpsc::unbounded_channel();
+    let ctm = CTM::builder()
+        .with_acceptor(Box::new(DepartmentProbe { client_state_tx }))
+        .with_replay(&journal_file)
+        .build()
+        .await
+        .unwrap();
+    let shutdown_controller = ctm.get_shutdown_controller();
+
+    let (_, client_states) = tokio::join!(timeout(Duration::from_secs(5), ctm.start()), async {
+        let mut client_states = vec![];
+        while client_states.len() < 2 {
+            match timeout(Duration::from_secs(5), client_state_rx.recv()).await {
+                Ok(Some(client_state)) => client_states.push(client_state),
+                _ => break,
+            }
+        }
+        shutdown_controller.shutdown();
+        client_states
+    });
+    let _ = fs::remove_dir_all(&directory);
+
+    assert_eq!(client_states.len(), 2);
+    for (department_id, broker_events) in client_states {
+        let (team_id, agents_logged_in) = match department_id {
+            7 => (10, 1),
+            _ => (20, 2),
+        };
+
+        let team_ids = broker_events
+            .iter()
+            .find_map(|broker_event| match broker_event {
+                BrokerEvent::BroadCastTeamSnapshot { team_infos, .. } => Some(
+                    team_infos
+                        .iter()
+                        .map(|team_info| team_info.get_team_id())
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(team_ids, vec![team_id]);
+
+        let wallboard_summary = broker_events
+            .iter()
+            .find_map(|broker_event| match broker_event {
+                BrokerEvent::BroadCastWallboardSummary {
+                    wallboard_summary, ..
+                } => Some(wallboard_summary),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(wallboard_summary.get_agents_logged_in(), agents_logged_in);
+        assert_eq!(wallboard_summary.get_agents_ready(), agents_logged_in);
+    }
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use ctm::ctm::{
+    acceptor::{ip_acl::IpAcl, tcp_acceptor::TCPAcceptor, Acceptor},
+    client_auth::ClientAuthenticator,
+    client_heartbeat::{ClientHeartbeat, ClientHeartbeatType},
+    config::{ConnectionLimitConfig, TcpAcceptorConfig},
+};
+use serde_json::Value;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    sync::{broadcast, mpsc},
+    time::timeout,
+};
+
+use common::free_port;
+
+#[test]
+fn parse_heartbeat_commands() {
+    assert_eq!(
+        ClientHeartbeat::parse(b"{\"command\": \"PING\"}\n"),
+        Some(ClientHeartbeatType::PING)
+    );
+    assert_eq!(
+        ClientHeartbeat::parse(
+            &rmp_serde::to_vec_named(&serde_json::json!({"command": "PONG"})).unwrap()
+        ),
+        Some(ClientHeartbeatType::PONG)
+    );
+    assert_eq!(ClientHeartbeat::parse(br#"{"command": "SUBSCRIBE"}"#), None);
+
+    let value = serde_json::to_value(ClientHeartbeat::new(ClientHeartbeatType::PING)).unwrap();
+    assert_eq!(value["event"], "PING");
+    assert!(value["timestamp"].as_u64().unwrap() > 0);
+}
+
+async fn next_event(stream: &mut TcpStream) -> Option<String> {
+    let mut buffer = vec![0_u8; 1_024];
+    let n = timeout(Duration::from_secs(5), stream.read(&mut buffer))
+        .await
+        .unwrap()
+        .unwrap();
+    if n == 0 {
+        return None;
+    }
+
+    let value = serde_json::from_slice::<Value>(&buffer[0..n]).unwrap();
+    Some(value["event"].as_str().unwrap().to_string())
+}
+
+#[tokio::test]
+async fn idle_tcp_client_is_pinged_then_disconnected() {
+    let port = free_port();
+    let acceptor = TCPAcceptor::new(
+        &TcpAcceptorConfig {
+            listener: None,
+            enabled: true,
+            bind_address: "127.0.0.1".to_string(),
+            dual_stack: true,
+            port,
+            secure: false,
+            cert_file: "".to_string(),
+            key_file: "".to_string(),
+            client_auth: false,
+            client_ca_file: "".to_string(),
+            handshake_timeout: Duration::from_millis(500),
+            write_timeout: Duration::from_secs(1),
+            ping_interval: Some(Duration::from_millis(100)),
+            pong_timeout: Duration::from_millis(200),
+            allowed_ips: IpAcl::default(),
+            connection_limit: ConnectionLimitConfig::default(),
+            cert_reload_interval: None,
+        },
+        Arc::new(ClientAuthenticator::default()),
+    )
+    .await
+    .unwrap();
+
+    let (broker_event_channel_tx, broker_event_channel_rx) = broadcast::channel(16);
+    let (client_event_channel_tx, _client_event_channel_rx) = mpsc::channel(16);
+    tokio::spawn(async move {
+        acceptor
+            .accept(broker_event_channel_rx, client_event_channel_tx)
+            .await
+    });
+
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    stream.write_all(br#"{"format": "json"}"#).await.unwrap();
+
+    // 수신이 없으면 PING 을 받고, 클라이언트의 PING 에는 PONG 으로 응답한다
+    assert_eq!(next_event(&mut stream).await.as_deref(), Some("PING"));
+    stream.write_all(br#"{"command": "PING"}"#).await.unwrap();
+    assert_eq!(next_event(&mut stream).await.as_deref(), Some("PONG"));
+
+    // PING 에 응답하지 않으면 연결이 끊어진다
+    assert_eq!(next_event(&mut stream).await.as_deref(), Some("PING"));
+    assert_eq!(next_event(&mut stream).await, None);
+
+    drop(broker_event_channel_tx);
+}
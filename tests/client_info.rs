@@ -0,0 +1,36 @@
+use std::net::SocketAddr;
+
+use ctm::ctm::{
+    client_auth::ClientPermission, client_info::ClientInfo, client_subscription::ClientSubscription,
+};
+use uuid::Uuid;
+
+#[test]
+fn client_info_serialize() {
+    let id = Uuid::now_v7();
+    let mut client_info = ClientInfo::new(
+        id,
+        Some("10.0.0.5:50123".parse::<SocketAddr>().unwrap()),
+        ClientPermission::READ_ONLY,
+        Some("wallboard-01".to_string()),
+    );
+    let mut client_subscription = ClientSubscription::default();
+    client_subscription.subscribe(&[10], &[], &[]);
+    client_info.set_subscription(client_subscription);
+
+    let value = serde_json::to_value(&client_info).unwrap();
+    assert_eq!(value["id"], id.to_string());
+    assert_eq!(value["addr"], "10.0.0.5:50123");
+    assert_eq!(value["permission"], "READ_ONLY");
+    assert_eq!(value["common_name"], "wallboard-01");
+    assert_eq!(value["subscription"]["team_ids"][0], 10);
+    assert!(value["connected_at"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn client_info_internal_client() {
+    let client_info = ClientInfo::new(Uuid::now_v7(), None, ClientPermission::READ_ONLY, None);
+
+    assert_eq!(client_info.get_addr(), None);
+    assert!(client_info.get_subscription().is_all());
+}
@@ -0,0 +1,97 @@
+use ctm::{
+    cisco::ConnectionCallId,
+    ctm::{call_info::CallInfo, client_subscription::ClientSubscription, command::ClientCommand},
+};
+
+#[test]
+fn parse_subscription_commands() {
+    assert_eq!(
+        ClientCommand::parse(
+            br#"{"command": "SUBSCRIBE", "team_ids": [10], "agent_ids": ["1001"], "locale": "ko"}"#
+        )
+        .unwrap(),
+        ClientCommand::SUBSCRIBE {
+            team_ids: vec![10],
+            agent_ids: vec!["1001".to_string()],
+            skill_group_ids: vec![],
+            locale: Some("ko".to_string()),
+        }
+    );
+    assert_eq!(
+        ClientCommand::parse(br#"{"command": "UNSUBSCRIBE"}"#).unwrap(),
+        ClientCommand::UNSUBSCRIBE {
+            team_ids: vec![],
+            agent_ids: vec![],
+            skill_group_ids: vec![],
+        }
+    );
+}
+
+#[test]
+fn match_subscription_filters() {
+    let mut client_subscription = ClientSubscription::default();
+    assert!(client_subscription.is_all());
+    assert!(client_subscription.matches_agent("1001", None));
+    assert!(client_subscription.matches_skill_group(5001));
+
+    client_subscription.subscribe(&[10], &["2001".to_string()], &[5001]);
+    assert!(!client_subscription.is_all());
+    assert!(client_subscription.matches_agent("1001", Some(10)));
+    assert!(client_subscription.matches_agent("2001", None));
+    assert!(!client_subscription.matches_agent("1001", Some(20)));
+    assert!(!client_subscription.matches_agent("1001", None));
+    assert!(client_subscription.matches_skill_group(5001));
+    assert!(!client_subscription.matches_skill_group(5002));
+
+    let mut call_info = CallInfo::new(ConnectionCallId(1));
+    call_info.set_skill_group_id(5002);
+    assert!(!client_subscription.matches_call(&call_info));
+    call_info.set_agent("2001", "3001");
+    assert!(client_subscription.matches_call(&call_info));
+}
+
+#[test]
+fn unsubscribe_filters() {
+    let mut client_subscription = ClientSubscription::default();
+    client_subscription.subscribe(&[10, 20], &[], &[]);
+
+    client_subscription.unsubscribe(&[10], &[], &[]);
+    assert!(!client_subscription.matches_agent("1001", Some(10)));
+    assert!(client_subscription.matches_agent("1001", Some(20)));
+
+    client_subscription.unsubscribe(&[], &[], &[]);
+    assert!(client_subscription.is_all());
+}
+
+#[test]
+fn locale_is_kept_after_unsubscribe() {
+    let mut client_subscription = ClientSubscription::default();
+    client_subscription.subscribe(&[10], &[], &[]);
+    client_subscription.set_locale(Some("ko".to_string()));
+
+    client_subscription.unsubscribe(&[], &[], &[]);
+    assert!(client_subscription.is_all());
+    assert_eq!(client_subscription.get_locale(), Some("ko"));
+
+    client_subscription.set_locale(Some(" ".to_string()));
+    assert_eq!(client_subscription.get_locale(), None);
+}
+
+#[test]
+fn department_is_kept_after_unsubscribe() {
+    let mut client_subscription = ClientSubscription::default();
+    assert!(client_subscription.matches_department(None));
+    assert!(client_subscription.matches_department(Some(7)));
+
+    client_subscription.set_department_id(Some(7));
+    assert!(!client_subscription.is_all());
+    assert!(client_subscription.matches_agent("1001", None));
+    assert!(client_subscription.matches_department(Some(7)));
+    assert!(!client_subscription.matches_department(Some(8)));
+    assert!(!client_subscription.matches_department(None));
+
+    client_subscription.subscribe(&[10], &[], &[]);
+    client_subscription.unsubscribe(&[], &[], &[]);
+    assert_eq!(client_subscription.get_department_id(), Some(7));
+    assert!(!client_subscription.is_all());
+}
@@ -0,0 +1,197 @@
+use ctm::cisco::{
+    CallType, CtiTimestamp, Deserializable, Direction, FixedString, FloatingField, InvokeId,
+    MessageType, MonitorId, PeripheralId, PeripheralType, Serializable, TagValue, MHDR,
+};
+use proptest::prelude::*;
+
+///
+/// 직렬화 후 역직렬화한 결과와 잔여 버퍼를 반환한다
+///
+fn round_trip<T>(value: T) -> (Vec<u8>, T)
+where
+    T: Serializable + Deserializable,
+{
+    let mut buffer = value.serialize();
+    T::deserialize(&mut buffer)
+}
+
+proptest! {
+    #[test]
+    fn u8_round_trip(value: u8) {
+        prop_assert_eq!(round_trip(value), (vec![], value));
+    }
+
+    #[test]
+    fn i8_round_trip(value: i8) {
+        prop_assert_eq!(round_trip(value), (vec![], value));
+    }
+
+    #[test]
+    fn byte_array_round_trip(value: [u8; 16]) {
+        prop_assert_eq!(round_trip(value), (vec![], value));
+    }
+
+    #[test]
+    fn fixed_string_round_trip(value in "[a-zA-Z0-9]{0,12}") {
+        let mut buffer = FixedString::<12>(value.clone()).serialize();
+        prop_assert_eq!(buffer.len(), 12);
+
+        let (buffer, result) = FixedString::<12>::deserialize(&mut buffer);
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(result.0, value);
+    }
+
+    #[test]
+    fn i16_round_trip(value: i16) {
+        prop_assert_eq!(round_trip(value), (vec![], value));
+    }
+
+    #[test]
+    fn u16_round_trip(value: u16) {
+        prop_assert_eq!(round_trip(value), (vec![], value));
+    }
+
+    #[test]
+    fn i32_round_trip(value: i32) {
+        prop_assert_eq!(round_trip(value), (vec![], value));
+    }
+
+    #[test]
+    fn u32_round_trip(value: u32) {
+        prop_assert_eq!(round_trip(value), (vec![], value));
+    }
+
+    #[test]
+    fn i64_round_trip(value: i64) {
+        prop_assert_eq!(round_trip(value), (vec![], value));
+    }
+
+    #[test]
+    fn u64_round_trip(value: u64) {
+        prop_assert_eq!(round_trip(value), (vec![], value));
+    }
+
+    #[test]
+    fn f32_round_trip(value: f32) {
+        let (buffer, result) = round_trip(value);
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(result.to_bits(), value.to_bits());
+    }
+
+    #[test]
+    fn string_round_trip(value in "[^\\x00]*") {
+        prop_assert_eq!(round_trip(value.clone()), (vec![], value));
+    }
+
+    #[test]
+    fn timestamp_round_trip(value: u32) {
+        prop_assert_eq!(round_trip(CtiTimestamp(value)), (vec![], CtiTimestamp(value)));
+    }
+
+    #[test]
+    fn id_round_trip(value: u32) {
+        prop_assert_eq!(round_trip(InvokeId(value)), (vec![], InvokeId(value)));
+        prop_assert_eq!(round_trip(MonitorId(value)), (vec![], MonitorId(value)));
+        prop_assert_eq!(round_trip(PeripheralId(value)), (vec![], PeripheralId(value)));
+    }
+
+    #[test]
+    fn message_type_round_trip(value: u32) {
+        let (buffer, result) = round_trip(MessageType::from(value));
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(u32::from(result), value);
+    }
+
+    #[test]
+    fn call_type_round_trip(value: u16) {
+        let (buffer, result) = round_trip(CallType::from(value));
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(u16::from(result), value);
+    }
+
+    #[test]
+    fn direction_round_trip(value: u32) {
+        let (buffer, result) = round_trip(Direction::from(value));
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(u32::from(result), value);
+    }
+
+    #[test]
+    fn peripheral_type_round_trip(value: u16) {
+        let (buffer, result) = round_trip(PeripheralType::from(value));
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(u16::from(result), value);
+    }
+
+    #[test]
+    fn tag_value_round_trip(value: u16) {
+        let tag: TagValue = value.into();
+        let (buffer, result) = round_trip(tag);
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(u16::from(result), value);
+    }
+
+    #[test]
+    fn tag_value_name_lookup(value: u16) {
+        let tag = TagValue::from(value);
+        match tag {
+            TagValue::UNKNOWN(_) => prop_assert_eq!(TagValue::from_name(tag.name()), None),
+            tag => prop_assert_eq!(TagValue::from_name(tag.name()), Some(tag)),
+        }
+    }
+
+    #[test]
+    fn mhdr_round_trip(length: u32, message_type: u32) {
+        let (buffer, result) = round_trip(MHDR {
+            length,
+            message_type: message_type.into(),
+        });
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(result.length, length);
+        prop_assert_eq!(u32::from(result.message_type), message_type);
+    }
+
+    #[test]
+    fn floating_field_round_trip(tag: u16, data: u32) {
+        let (buffer, result) = round_trip(FloatingField {
+            tag: tag.into(),
+            length: 4,
+            data,
+        });
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(u16::from(result.tag), tag);
+        prop_assert_eq!(result.length, 4);
+        prop_assert_eq!(result.data, data);
+    }
+
+    #[test]
+    fn optional_floating_field_round_trip(data in proptest::option::of("[^\\x00]*")) {
+        let field = data.clone().map(|data| FloatingField {
+            tag: TagValue::AGENT_ID_TAG,
+            length: data.len() as u16 + 1,
+            data,
+        });
+        let mut buffer = field.serialize();
+        let (buffer, result) = Option::<FloatingField<String>>::deserialize(&mut buffer);
+        prop_assert!(buffer.is_empty());
+        prop_assert_eq!(result.map(|field| field.data), data);
+    }
+}
+
+#[test]
+fn fixed_string_truncates_and_keeps_remaining_buffer() {
+    let buffer = FixedString::<4>("ABCDEFG".to_string()).serialize();
+    assert_eq!(buffer, b"ABCD".to_vec());
+
+    // 고정 길이 안의 NUL 이후 채움 바이트는 무시하고, 다음 필드는 그대로 남긴다
+    let mut buffer = vec![b'A', b'B', 0, b'X', 0x12, 0x34];
+    let (buffer, result) = FixedString::<4>::deserialize(&mut buffer);
+    assert_eq!(result, FixedString("AB".to_string()));
+    assert_eq!(buffer, vec![0x12, 0x34]);
+}
+
+#[test]
+fn i8_uses_twos_complement() {
+    assert_eq!((-1_i8).serialize(), vec![0xFF]);
+    assert_eq!(i8::deserialize(&mut vec![0x80, 0x01]), (vec![0x01], -128));
+}
@@ -0,0 +1,76 @@
+#![allow(dead_code)]
+
+use std::{fs, path::PathBuf};
+
+use ctm::{
+    cisco::ConnectionCallId,
+    ctm::{
+        agent_info::AgentInfo,
+        call_info::{CallInfo, CallState},
+    },
+};
+
+///
+/// 테스트 픽스처 파일 경로를 반환한다
+///
+pub fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+///
+/// 16진수 덤프 형식의 패킷 픽스처를 읽어 바이트 벡터로 반환한다
+///
+/// 공백과 줄바꿈은 무시하며, '#' 이후는 주석으로 처리한다.
+///
+pub fn load_fixture(name: &str) -> Vec<u8> {
+    let path = fixture_path(name);
+    let text = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Unable to read fixture. path: {:?}, error: {}", path, e));
+
+    let hex = text
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or(""))
+        .flat_map(|line| line.split_whitespace())
+        .collect::<String>();
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+///
+/// 테스트용 상담원 정보를 생성한다 (CTI 인스턴스: ucce1, 팀 이름: "Team {team_id}")
+///
+pub fn agent_info(agent_id: &str, team_id: u32, agent_state: u16) -> AgentInfo {
+    let mut agent_info = AgentInfo::new(agent_id);
+    agent_info.set_cti_instance("ucce1");
+    agent_info.set_team(team_id, format!("Team {}", team_id));
+    agent_info.set_agent_state(agent_state);
+    agent_info
+}
+
+///
+/// 테스트용 호 정보를 생성한다 (CTI 인스턴스: ucce1, 상담원: 1001 / 내선: 5001)
+///
+pub fn call_info(connection_call_id: u32, call_state: CallState) -> CallInfo {
+    let mut call_info = CallInfo::new(ConnectionCallId(connection_call_id));
+    call_info.set_cti_instance("ucce1");
+    call_info.set_agent("1001", "5001");
+    call_info.set_call_state(call_state);
+    call_info
+}
+
+///
+/// 사용 중이지 않은 로컬 TCP 포트를 반환한다
+///
+pub fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
@@ -0,0 +1,219 @@
+use std::fs;
+
+use ctm::ctm::config::{config_var, load_config_file, read_config_file, Config};
+
+#[test]
+fn config_file_flattening() {
+    let directory = std::env::temp_dir().join(format!("ctm_config_{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+
+    let toml_file = directory.join("ctm.toml");
+    fs::write(
+        &toml_file,
+        r#"
+[cti_server]
+instances = ["ucce1", "ucce2"]
+side_a_port = 42027
+
+[ucce2.cti_server]
+side_a_address = "172.30.2.11"
+
+[tcp_acceptor]
+enabled = true
+"#,
+    )
+    .unwrap();
+    let yaml_file = directory.join("ctm.yml");
+    fs::write(
+        &yaml_file,
+        "websocket_acceptor:\n  path: /ctmonitor\n  ping_interval: 30\nsnapshot_file: ~\n",
+    )
+    .unwrap();
+
+    let toml_values = read_config_file(&toml_file).unwrap();
+    let yaml_values = read_config_file(&yaml_file).unwrap();
+    let unsupported = read_config_file(&directory.join("ctm.ini"));
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(toml_values["CTI_SERVER_INSTANCES"], "ucce1,ucce2");
+    assert_eq!(toml_values["CTI_SERVER_SIDE_A_PORT"], "42027");
+    assert_eq!(
+        toml_values["UCCE2_CTI_SERVER_SIDE_A_ADDRESS"],
+        "172.30.2.11"
+    );
+    assert_eq!(toml_values["TCP_ACCEPTOR_ENABLED"], "true");
+    assert_eq!(yaml_values["WEBSOCKET_ACCEPTOR_PATH"], "/ctmonitor");
+    assert_eq!(yaml_values["WEBSOCKET_ACCEPTOR_PING_INTERVAL"], "30");
+    assert_eq!(yaml_values["SNAPSHOT_FILE"], "");
+    assert!(unsupported.is_err());
+}
+
+#[test]
+fn config_file_values_are_overridden_by_env() {
+    let config_file = std::env::temp_dir().join(format!("ctm_config_{}.toml", std::process::id()));
+    fs::write(
+        &config_file,
+        "[config_test]\nfile_only = \"file\"\noverridden = \"file\"\n",
+    )
+    .unwrap();
+    load_config_file(&config_file).unwrap();
+    fs::remove_file(&config_file).unwrap();
+
+    std::env::set_var("CONFIG_TEST_OVERRIDDEN", "env");
+    assert_eq!(config_var("CONFIG_TEST_FILE_ONLY").unwrap(), "file");
+    assert_eq!(config_var("CONFIG_TEST_OVERRIDDEN").unwrap(), "env");
+    assert!(config_var("CONFIG_TEST_MISSING").is_err());
+}
+
+#[test]
+fn config_validation() {
+    // .env 를 먼저 읽은 뒤 환경 변수를 덮어쓴다
+    let _ = config_var("CTI_SERVER_SIDE_A_ADDRESS");
+    std::env::remove_var("CTI_SERVER_INSTANCES");
+    std::env::set_var("CTI_SERVER_SIDE_A_ADDRESS", "172.30.1.11");
+    std::env::set_var("TCP_ACCEPTOR_SECURE", "false");
+    std::env::set_var("TCP_ACCEPTOR_PORT", "5110");
+    std::env::set_var("WEBSOCKET_PING_INTERVAL", "30");
+
+    let config = Config::load().unwrap();
+    assert_eq!(config.cti_clients.len(), 1);
+    assert_eq!(
+        config.get_cti_client("default").unwrap().side_a_address,
+        "172.30.1.11"
+    );
+    assert_eq!(config.tcp_acceptors.len(), 1);
+    assert_eq!(config.tcp_acceptors[0].port, 5110);
+
+    // 잘못된 값은 모두 모아 알린다
+    std::env::set_var("CTI_SERVER_SIDE_A_ADDRESS", "");
+    std::env::set_var("TCP_ACCEPTOR_PORT", "70000");
+    std::env::set_var("TCP_ACCEPTOR_SECURE", "true");
+    std::env::set_var("TCP_ACCEPTOR_SECURE_CERT_FILE", "./res/ssl/missing.crt");
+    std::env::set_var("WEBSOCKET_PING_INTERVAL", "soon");
+    std::env::set_var("HA_PEER_ADDRESS", "fd00::22:5130");
+
+    let errors = Config::load().unwrap_err().get_errors().to_vec();
+    std::env::remove_var("HA_PEER_ADDRESS");
+    std::env::set_var("CTI_SERVER_SIDE_A_ADDRESS", "172.30.1.11");
+    std::env::set_var("TCP_ACCEPTOR_PORT", "5110");
+    std::env::set_var("TCP_ACCEPTOR_SECURE", "false");
+    std::env::set_var("WEBSOCKET_PING_INTERVAL", "30");
+
+    assert!(errors.contains(
+        &"CTI_SERVER_SIDE_A_ADDRESS (cti_instance: default): value is required".to_string()
+    ));
+    assert!(errors.contains(&"TCP_ACCEPTOR_PORT: invalid value '70000' (expected u16)".to_string()));
+    assert!(errors.contains(
+        &"TCP_ACCEPTOR_SECURE_CERT_FILE: file not found './res/ssl/missing.crt'".to_string()
+    ));
+    assert!(errors
+        .contains(&"WEBSOCKET_PING_INTERVAL: invalid value 'soon' (expected u64)".to_string()));
+    assert!(errors.contains(
+        &"HA_PEER_ADDRESS: IPv6 address must be enclosed in brackets 'fd00::22:5130' (e.g. [fd00::1]:5130)"
+            .to_string()
+    ));
+
+    // 인증서 파일은 내용까지 읽어 검증한다
+    let cert_file = std::env::temp_dir().join(format!("ctm_config_{}.crt", std::process::id()));
+    fs::write(&cert_file, "not a certificate").unwrap();
+    let mut config = Config::load().unwrap();
+    assert!(config.check_certificates().is_ok());
+
+    config.tcp_acceptors[0].enabled = true;
+    config.tcp_acceptors[0].secure = true;
+    config.tcp_acceptors[0].cert_file = cert_file.display().to_string();
+    config.tcp_acceptors[0].key_file = cert_file.display().to_string();
+    let errors = config
+        .check_certificates()
+        .unwrap_err()
+        .get_errors()
+        .to_vec();
+    fs::remove_file(&cert_file).unwrap();
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("TCP_ACCEPTOR_SECURE_CERT_FILE: unable to load certificate"));
+
+    // 리스너마다 Acceptor 설정을 만들고, 리스너별 설정이 없으면 공통 설정을 사용한다
+    std::env::set_var("WEBSOCKET_ACCEPTOR_LISTENERS", "plain, tls");
+    std::env::set_var("WEBSOCKET_ACCEPTOR_BIND_ADDRESS", "::");
+    std::env::set_var("WEBSOCKET_ACCEPTOR_PORT", "8085");
+    std::env::set_var("TLS_WEBSOCKET_ACCEPTOR_PORT", "8443");
+    std::env::set_var("TLS_WEBSOCKET_PING_INTERVAL", "never");
+
+    let errors = Config::load().unwrap_err().get_errors().to_vec();
+    std::env::set_var("TLS_WEBSOCKET_PING_INTERVAL", "30");
+    let config = Config::load().unwrap();
+    std::env::remove_var("WEBSOCKET_ACCEPTOR_LISTENERS");
+    std::env::remove_var("TLS_WEBSOCKET_ACCEPTOR_PORT");
+    std::env::remove_var("TLS_WEBSOCKET_PING_INTERVAL");
+    std::env::set_var("WEBSOCKET_ACCEPTOR_BIND_ADDRESS", "0.0.0.0");
+
+    assert_eq!(
+        errors,
+        vec!["WEBSOCKET_PING_INTERVAL (listener: tls): invalid value 'never' (expected u64)"]
+    );
+    let listeners = config
+        .websocket_acceptors
+        .iter()
+        .map(|config| {
+            (
+                config.listener.as_deref(),
+                config.bind_address.as_str(),
+                config.port,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        listeners,
+        vec![(Some("plain"), "::", 8085), (Some("tls"), "::", 8443)]
+    );
+    assert_eq!(config.tcp_acceptors[0].listener, None);
+
+    // 모듈별 세부 설정도 시작 시 함께 검증한다
+    std::env::set_var("CLIENT_DEFAULT_TEAM_IDS", "10, 20");
+    std::env::set_var("CLIENT_DEFAULT_AGENT_IDS", "1001");
+    std::env::set_var("CLIENT_DEFAULT_SKILL_GROUP_IDS", "");
+    let config = Config::load().unwrap();
+    let client_subscription = &config.ctm.default_client_subscription;
+    assert_eq!(client_subscription.get_team_ids().len(), 2);
+    assert!(client_subscription.matches_agent("2001", Some(20)));
+    assert!(client_subscription.matches_agent("1001", None));
+    assert!(client_subscription.get_skill_group_ids().is_empty());
+    assert_eq!(config.health.port, 8087);
+
+    std::env::set_var("CLIENT_DEFAULT_TEAM_IDS", "10,x");
+    std::env::set_var("TCP_ACCEPTOR_ALLOWED_IPS", "fd00::/129");
+    std::env::set_var("HEALTH_PORT", "80a");
+    std::env::set_var("CLIENT_AUTH_DEPARTMENT_REQUIRED", "yes");
+    std::env::set_var("AMQP_MAX_PENDING_MESSAGES", "many");
+    std::env::set_var("MQTT_QOS", "3");
+    std::env::set_var("WEBHOOK_ENABLED", "true");
+    std::env::set_var("WEBHOOK_URLS", "");
+
+    let errors = Config::load().unwrap_err().get_errors().to_vec();
+    std::env::remove_var("CLIENT_DEFAULT_TEAM_IDS");
+    std::env::remove_var("CLIENT_DEFAULT_AGENT_IDS");
+    std::env::remove_var("TCP_ACCEPTOR_ALLOWED_IPS");
+    std::env::set_var("HEALTH_PORT", "8087");
+    std::env::set_var("CLIENT_AUTH_DEPARTMENT_REQUIRED", "false");
+    std::env::set_var("AMQP_MAX_PENDING_MESSAGES", "10000");
+    std::env::set_var("MQTT_QOS", "1");
+    std::env::set_var("WEBHOOK_ENABLED", "false");
+
+    assert!(
+        errors.contains(&"CLIENT_DEFAULT_TEAM_IDS: invalid value 'x' (expected u32)".to_string())
+    );
+    assert!(errors
+        .iter()
+        .any(|error| error.starts_with("TCP_ACCEPTOR_ALLOWED_IPS: invalid value 'fd00::/129'")));
+    assert!(errors.contains(&"HEALTH_PORT: invalid value '80a' (expected u16)".to_string()));
+    assert!(errors.contains(
+        &"CLIENT_AUTH_DEPARTMENT_REQUIRED: invalid value 'yes' (expected bool)".to_string()
+    ));
+    assert!(errors
+        .contains(&"AMQP_MAX_PENDING_MESSAGES: invalid value 'many' (expected usize)".to_string()));
+    assert!(errors.contains(&"MQTT_QOS: must be 0, 1 or 2 (got 3)".to_string()));
+    assert!(errors
+        .contains(&"WEBHOOK_URLS: value is required when WEBHOOK_ENABLED is true".to_string()));
+    assert_eq!(errors.len(), 7);
+}
@@ -0,0 +1,53 @@
+use ctm::ctm::acceptor::connection_limiter::{ConnectionLimiter, ConnectionRejection};
+
+#[test]
+fn connection_limiter_max_clients() {
+    let connection_limiter = ConnectionLimiter::new(2, 0.0, 0);
+
+    let first = connection_limiter.try_acquire().unwrap();
+    let _second = connection_limiter.try_acquire().unwrap();
+    assert_eq!(connection_limiter.get_active_clients(), 2);
+    assert_eq!(
+        connection_limiter.try_acquire().err(),
+        Some(ConnectionRejection::TOO_MANY_CLIENTS)
+    );
+
+    // 연결이 끝나면 다시 접속할 수 있다
+    drop(first);
+    assert_eq!(connection_limiter.get_active_clients(), 1);
+    assert!(connection_limiter.try_acquire().is_ok());
+}
+
+#[test]
+fn connection_limiter_accept_rate() {
+    let connection_limiter = ConnectionLimiter::new(0, 0.001, 3);
+
+    for _ in 0..3 {
+        assert!(connection_limiter.try_acquire().is_ok());
+    }
+    assert_eq!(
+        connection_limiter.try_acquire().err(),
+        Some(ConnectionRejection::RATE_LIMITED)
+    );
+    // 속도 제한으로 거절된 접속은 동시 접속 수에 포함되지 않는다
+    assert_eq!(connection_limiter.get_active_clients(), 0);
+}
+
+#[test]
+fn connection_limiter_unlimited() {
+    let connection_limiter = ConnectionLimiter::new(0, 0.0, 0);
+
+    let permits = (0..1_000)
+        .map(|_| connection_limiter.try_acquire().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(connection_limiter.get_active_clients(), permits.len());
+}
+
+#[test]
+fn connection_rejection_http_response() {
+    let response = ConnectionRejection::TOO_MANY_CLIENTS.to_http_response();
+
+    assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+    assert!(response.contains("Retry-After: 1\r\n"));
+    assert!(response.ends_with(r#"{"error":"TOO_MANY_CLIENTS"}"#));
+}
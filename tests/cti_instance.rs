@@ -0,0 +1,51 @@
+use ctm::ctm::{
+    config::{config_var, cti_instance_var, Config},
+    cti_client::DEFAULT_CTI_INSTANCE,
+};
+
+#[test]
+fn cti_instance_config() {
+    // .env 를 먼저 읽은 뒤 환경 변수를 덮어쓴다
+    let _ = config_var("CTI_SERVER_SIDE_A_ADDRESS");
+    std::env::set_var("CTI_SERVER_SIDE_A_ADDRESS", "172.30.1.11");
+
+    std::env::remove_var("CTI_SERVER_INSTANCES");
+    let config = Config::load().unwrap();
+    assert_eq!(config.cti_clients.len(), 1);
+    assert_eq!(config.cti_clients[0].cti_instance, DEFAULT_CTI_INSTANCE);
+
+    std::env::set_var("CTI_SERVER_INSTANCES", "ucce1, ucce2,");
+    let config = Config::load().unwrap();
+    let cti_instances = config
+        .cti_clients
+        .iter()
+        .map(|cti_client| cti_client.cti_instance.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(cti_instances, vec!["ucce1", "ucce2"]);
+
+    // 인스턴스 설정이 없으면 공통 설정을 사용한다
+    std::env::set_var("CTI_SERVER_SIDE_A_PORT", "42027");
+    std::env::set_var("UCCE2_CTI_SERVER_SIDE_A_PORT", "42028");
+    assert_eq!(
+        cti_instance_var("ucce1", "CTI_SERVER_SIDE_A_PORT").unwrap(),
+        "42027"
+    );
+    assert_eq!(
+        cti_instance_var("ucce2", "CTI_SERVER_SIDE_A_PORT").unwrap(),
+        "42028"
+    );
+
+    // 대소문자만 다른 인스턴스와 인스턴스별 재접속 설정 오류도 시작 시 알린다
+    std::env::set_var("CTI_SERVER_INSTANCES", "ucce1,UCCE1");
+    std::env::set_var("UCCE1_CTI_RECONNECT_JITTER", "wide");
+    let errors = Config::load().unwrap_err().get_errors().to_vec();
+    std::env::remove_var("UCCE1_CTI_RECONNECT_JITTER");
+
+    assert_eq!(
+        errors,
+        vec![
+            "CTI_SERVER_INSTANCES: duplicate instance 'UCCE1'",
+            "CTI_RECONNECT_JITTER (cti_instance: ucce1): invalid value 'wide' (expected f64)",
+        ]
+    );
+}
@@ -0,0 +1,65 @@
+use std::time::{Duration, Instant};
+
+use ctm::ctm::cti_link_status::{CtiLinkStatus, CtiLinkTracker};
+
+#[test]
+fn cti_link_status_tracks_heartbeat_and_reconnects() {
+    let now = Instant::now();
+    let stale_after = Duration::from_secs(30);
+    let mut cti_link = CtiLinkTracker::default();
+
+    // 메시지를 받은 적이 없으면 degraded 이다
+    let cti_link_status = cti_link.status("ucce1", true, true, stale_after, now);
+    assert!(cti_link_status.is_degraded());
+    assert_eq!(cti_link_status.get_seconds_since_last_event(), None);
+    assert_eq!(cti_link_status.get_heartbeat_rtt_ms(), None);
+
+    cti_link.on_event(now);
+    cti_link.on_heartbeat_requested(now);
+    cti_link.on_heartbeat_conf(now + Duration::from_millis(25));
+    let cti_link_status = cti_link.status(
+        "ucce1",
+        true,
+        true,
+        stale_after,
+        now + Duration::from_secs(5),
+    );
+    assert!(!cti_link_status.is_degraded());
+    assert_eq!(cti_link_status.get_side(), "A");
+    assert_eq!(cti_link_status.get_heartbeat_rtt_ms(), Some(25));
+    assert_eq!(cti_link_status.get_seconds_since_last_event(), Some(5));
+
+    // 요청하지 않은 하트비트 응답은 응답 시간을 바꾸지 않는다
+    cti_link.on_heartbeat_conf(now + Duration::from_secs(1));
+    assert_eq!(
+        cti_link
+            .status("ucce1", true, true, stale_after, now)
+            .get_heartbeat_rtt_ms(),
+        Some(25)
+    );
+
+    // 오래 메시지가 없거나 연결이 끊기면 degraded 이다
+    assert!(cti_link
+        .status("ucce1", true, true, stale_after, now + stale_after)
+        .is_degraded());
+
+    cti_link.on_reconnect();
+    let cti_link_status = cti_link.status("ucce1", false, false, stale_after, now);
+    assert!(cti_link_status.is_degraded());
+    assert_eq!(cti_link_status.get_side(), "B");
+    assert_eq!(cti_link_status.get_reconnect_count(), 1);
+}
+
+#[test]
+fn cti_link_status_msgpack_roundtrip() {
+    let now = Instant::now();
+    let mut cti_link = CtiLinkTracker::default();
+    cti_link.on_event(now);
+    let cti_link_status = cti_link.status("ucce1", true, true, Duration::from_secs(30), now);
+
+    let buf = rmp_serde::to_vec_named(&cti_link_status).unwrap();
+    let decoded: CtiLinkStatus = rmp_serde::from_slice(&buf).unwrap();
+    assert_eq!(decoded.get_cti_instance(), "ucce1");
+    assert!(decoded.is_connected());
+    assert!(!decoded.is_degraded());
+}
@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use ctm::{
+    cisco::{
+        session::{
+            change_monitor_mask_conf::ChangeMonitorMaskConf,
+            change_monitor_mask_req::ChangeMonitorMaskReq, CallMessageMask, OpenConf, OpenReq,
+            ServicesRequested,
+        },
+        CtiMessage, InvokeId, MessageType, MonitorId, PeripheralId, Serializable,
+    },
+    ctm::{
+        cti_session::{CtiSession, SessionMode},
+        simulator::{CtiSimulator, SimulatorScript, SIMULATED_MONITOR_ID},
+    },
+};
+
+fn open_conf(simulator: &CtiSimulator) -> OpenConf {
+    let mut open_req = OpenReq::builder()
+        .invoke_id(InvokeId(1))
+        .peripheral_id(PeripheralId(5000))
+        .services_requested(ServicesRequested::ALL_EVENTS)
+        .client_id("ctmonitor_rs")
+        .client_password("")
+        .build()
+        .unwrap()
+        .serialize();
+    let mut responses = simulator.handle_request(&MessageType::OPEN_REQ, &mut open_req);
+
+    OpenConf::try_deserialize(&mut responses[0]).unwrap()
+}
+
+#[test]
+fn session_keeps_monitor_id_from_open_conf() {
+    let simulator = CtiSimulator::new(
+        SimulatorScript::generated(1),
+        PeripheralId(5000),
+        Duration::from_millis(10),
+    );
+    let mut session = CtiSession::new("ucce1", &open_conf(&simulator), CallMessageMask::default());
+    assert_eq!(
+        session.get_monitor_id(),
+        Some(MonitorId(SIMULATED_MONITOR_ID))
+    );
+    assert_eq!(session.get_call_msg_mask(), u32::MAX);
+
+    // 모니터 요청은 세션의 MonitorID 로 보낸다
+    let call_msg_mask = CallMessageMask::CALL_DELIVERED | CallMessageMask::CALL_CLEARED;
+    session.request_call_msg_mask(InvokeId(2), call_msg_mask);
+    let mut change_monitor_mask_req = ChangeMonitorMaskReq::new(
+        InvokeId(2),
+        session.get_monitor_id().unwrap(),
+        call_msg_mask,
+    )
+    .serialize();
+    let decoded =
+        ChangeMonitorMaskReq::try_deserialize(&mut change_monitor_mask_req.clone()).unwrap();
+    assert_eq!(decoded.monitor_id, MonitorId(SIMULATED_MONITOR_ID));
+    assert_eq!(decoded.call_msg_mask, call_msg_mask.bits());
+
+    // 다른 요청의 응답은 무시하고, 기다리던 응답을 받으면 마스크를 적용한다
+    assert_eq!(session.confirm_call_msg_mask(&InvokeId(3)), None);
+    assert_eq!(session.get_call_msg_mask(), u32::MAX);
+
+    let mut responses = simulator.handle_request(
+        &MessageType::CHANGE_MONITOR_MASK_REQ,
+        &mut change_monitor_mask_req,
+    );
+    let change_monitor_mask_conf =
+        ChangeMonitorMaskConf::try_deserialize(&mut responses[0]).unwrap();
+    assert_eq!(
+        session.confirm_call_msg_mask(&change_monitor_mask_conf.invoke_id),
+        Some(call_msg_mask.bits())
+    );
+    assert_eq!(session.get_call_msg_mask(), call_msg_mask.bits());
+}
+
+#[test]
+fn all_events_mode_requests_all_events_service() {
+    assert_eq!("".parse::<SessionMode>(), Ok(SessionMode::CUSTOM));
+    assert_eq!(
+        "ALL_EVENTS".parse::<SessionMode>(),
+        Ok(SessionMode::ALL_EVENTS)
+    );
+    assert_eq!(
+        "client_events".parse::<SessionMode>(),
+        Ok(SessionMode::CLIENT_EVENTS)
+    );
+    assert!("supervisor".parse::<SessionMode>().is_err());
+
+    let services_requested = ServicesRequested::CLIENT_EVENTS | ServicesRequested::CLIENT_CONTROL;
+    assert_eq!(
+        SessionMode::CUSTOM.services_requested(services_requested),
+        services_requested
+    );
+
+    // All Events 와 Client Events 는 함께 요청하지 않는다
+    assert_eq!(
+        SessionMode::ALL_EVENTS.services_requested(services_requested),
+        ServicesRequested::ALL_EVENTS | ServicesRequested::CLIENT_CONTROL
+    );
+}
+
+#[test]
+fn client_events_mode_requests_client_events_service() {
+    let services_requested = ServicesRequested::default();
+    assert!(services_requested.contains(ServicesRequested::ALL_EVENTS));
+
+    let services_requested = SessionMode::CLIENT_EVENTS.services_requested(services_requested);
+    assert!(services_requested.contains(ServicesRequested::CLIENT_EVENTS));
+    assert!(!services_requested.contains(ServicesRequested::ALL_EVENTS));
+    assert!(services_requested.contains(ServicesRequested::CLIENT_CONTROL));
+}
@@ -0,0 +1,14 @@
+use ctm::ctm::cti_sync_state::{CtiSyncState, CtiSyncStatus};
+
+#[test]
+fn cti_sync_state_msgpack_roundtrip() {
+    let cti_sync_state = CtiSyncState::new("ucce1", CtiSyncStatus::RESYNCED, 12, 2);
+
+    let buf = rmp_serde::to_vec_named(&cti_sync_state).unwrap();
+    let decoded: CtiSyncState = rmp_serde::from_slice(&buf).unwrap();
+
+    assert_eq!(decoded.get_cti_instance(), "ucce1");
+    assert_eq!(decoded.get_status(), CtiSyncStatus::RESYNCED);
+    assert_eq!(decoded.get_requested_agents(), 12);
+    assert_eq!(decoded.get_unanswered_agents(), 2);
+}
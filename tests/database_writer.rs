@@ -0,0 +1,66 @@
+use ctm::{
+    cisco::ConnectionCallId,
+    ctm::{
+        acceptor::database_writer::{DatabaseWriter, HistoryRecord, HistoryRecorder},
+        agent_info::AgentInfo,
+        call_info::{CallInfo, CallState},
+    },
+    event::broker_event::BrokerEvent,
+};
+use sqlx::Row;
+
+fn agent_state(agent_state: u16) -> BrokerEvent {
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_cti_instance("ucce1");
+    agent_info.set_agent_state(agent_state);
+
+    BrokerEvent::BroadCastAgentState {
+        client_id: None,
+        agent_info: agent_info.into(),
+    }
+}
+
+#[test]
+fn history_recorder_records_transitions_only() {
+    let mut history_recorder = HistoryRecorder::new();
+
+    assert_eq!(history_recorder.records(&agent_state(3)).len(), 1);
+    assert!(history_recorder.records(&agent_state(3)).is_empty());
+    assert_eq!(history_recorder.records(&agent_state(2)).len(), 1);
+}
+
+#[tokio::test]
+async fn database_writer_inserts_into_sqlite() {
+    let pool = DatabaseWriter::connect("sqlite::memory:", 1).unwrap();
+    DatabaseWriter::create_tables(&pool).await.unwrap();
+
+    let mut history_recorder = HistoryRecorder::new();
+    let mut records = history_recorder.records(&agent_state(3));
+
+    let mut call_info = CallInfo::new(ConnectionCallId(16_777_216));
+    call_info.set_cti_instance("ucce1");
+    call_info.set_call_state(CallState::ESTABLISHED);
+    records.extend(history_recorder.records(&BrokerEvent::BroadCastCallState {
+        client_id: None,
+        call_info,
+    }));
+    assert!(matches!(records[1], HistoryRecord::CallEvent { .. }));
+
+    DatabaseWriter::insert_records(&pool, &records)
+        .await
+        .unwrap();
+
+    let row = sqlx::query("SELECT agent_id, agent_state FROM agent_state_history")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<String, _>(0), "1001");
+    assert_eq!(row.get::<i32, _>(1), 3);
+
+    let row = sqlx::query("SELECT connection_call_id, call_state FROM call_event_history")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<i64, _>(0), 16_777_216);
+    assert_eq!(row.get::<String, _>(1), "ESTABLISHED");
+}
@@ -0,0 +1,37 @@
+use ctm::ctm::{
+    agent_info::AgentInfo,
+    field_masker::{FieldMasker, MaskingMode},
+};
+
+#[test]
+fn mask_field_values() {
+    let field_masker = FieldMasker::new(MaskingMode::NONE, "", 2);
+    assert_eq!(field_masker.mask("5001"), "5001");
+
+    let field_masker = FieldMasker::new(MaskingMode::PARTIAL, "", 2);
+    assert_eq!(field_masker.mask("01012345678"), "*********78");
+    assert_eq!(field_masker.mask("5"), "5");
+    assert_eq!(field_masker.mask(""), "");
+
+    let field_masker = FieldMasker::new(MaskingMode::HASH, "salt", 2);
+    let hashed = field_masker.mask("5001");
+    assert_eq!(hashed.len(), 40);
+    assert_ne!(hashed, "5001");
+    assert_eq!(hashed, field_masker.mask("5001"));
+    assert_ne!(hashed, field_masker.mask("5002"));
+    assert_ne!(
+        hashed,
+        FieldMasker::new(MaskingMode::HASH, "other", 2).mask("5001")
+    );
+}
+
+#[test]
+fn mask_agent_extension() {
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_agent_state(3);
+    agent_info.set_agent_extension("5001");
+
+    agent_info.mask_fields(&FieldMasker::new(MaskingMode::PARTIAL, "", 1));
+
+    assert_eq!(agent_info.get_agent_extension(), "***1");
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use ctm::{
+    cisco::MessageType,
+    ctm::{client_auth::ClientPermission, command::ClientCommand, firehose::FirehoseMessage},
+};
+
+use common::load_fixture;
+
+#[test]
+fn decoded_message_carries_all_fields() {
+    let data = load_fixture("agent_state_event.hex");
+    let firehose_message = FirehoseMessage::new("ucce1", &MessageType::AGENT_STATE_EVENT, &data);
+
+    assert_eq!(firehose_message.get_event(), "CTI_MESSAGE");
+    assert_eq!(firehose_message.get_cti_instance(), "ucce1");
+    assert_eq!(firehose_message.get_message_type(), "AGENT_STATE_EVENT");
+    assert_eq!(firehose_message.get_raw(), None);
+
+    let fields = firehose_message.get_fields().unwrap();
+    assert!(fields.get("mhdr").is_none());
+    assert_eq!(fields["agent_state"], 4);
+}
+
+#[test]
+fn unknown_message_is_sent_as_raw_hex() {
+    let data = [0, 0, 0, 2, 0, 0, 3, 231, 0x41, 0x42];
+    let firehose_message = FirehoseMessage::new("ucce1", &MessageType::from(999), &data);
+
+    assert_eq!(firehose_message.get_message_type(), "UNKNOWN(999)");
+    assert_eq!(firehose_message.get_fields(), None);
+    assert_eq!(firehose_message.get_decode_error(), None);
+    assert_eq!(firehose_message.get_raw(), Some("00000002000003e74142"));
+}
+
+#[test]
+fn broken_message_reports_decode_error() {
+    let data = [0, 0, 0, 2, 0, 0, 0, 30, 0, 1];
+    let firehose_message = FirehoseMessage::new("ucce1", &MessageType::AGENT_STATE_EVENT, &data);
+
+    assert_eq!(firehose_message.get_fields(), None);
+    assert!(firehose_message.get_decode_error().is_some());
+    assert_eq!(firehose_message.get_raw(), Some("000000020000001e0001"));
+}
+
+#[test]
+fn firehose_subscription_requires_admin() {
+    let subscribe = ClientCommand::parse(br#"{"command":"SUBSCRIBE_FIREHOSE"}"#).unwrap();
+    let unsubscribe = ClientCommand::parse(br#"{"command":"UNSUBSCRIBE_FIREHOSE"}"#).unwrap();
+
+    assert_eq!(subscribe.required_permission(), ClientPermission::ADMIN);
+    assert_eq!(
+        unsubscribe.required_permission(),
+        ClientPermission::READ_ONLY
+    );
+}
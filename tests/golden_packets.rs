@@ -0,0 +1,466 @@
+mod common;
+
+use ctm::cisco::{
+    client_event::{
+        agent_state_event::AgentStateEvent, begin_call_event::BeginCallEvent,
+        call_cleared_event::CallClearedEvent, call_established_event::CallEstablishedEvent,
+        call_queued_event::CallQueuedEvent,
+    },
+    config::config_agent_event::ConfigAgentEvent,
+    control::query_agent_state_conf::QueryAgentStateConf,
+    session::OpenConf,
+    supervisor::{
+        agent_team_config_event::AgentTeamConfigEvent, emergency_call_event::EmergencyCallEvent,
+    },
+    CallType, ConnectionCallId, CtiMessage, Direction, InvokeId, MessageType, MonitorId,
+    PeripheralId, PeripheralType, Serializable, SkillGroupAssignment, TagValue,
+};
+
+use common::load_fixture;
+
+#[test]
+fn open_conf() {
+    let mut data = load_fixture("open_conf.hex");
+    let open_conf = OpenConf::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        open_conf.mhdr.message_type,
+        MessageType::OPEN_CONF
+    ));
+    assert_eq!(open_conf.mhdr.length, 52);
+    assert_eq!(open_conf.invoke_id, InvokeId(1));
+    assert_eq!(open_conf.service_granted, 0x8000_0094);
+    assert_eq!(open_conf.monitor_id, MonitorId(7));
+    assert_eq!(open_conf.pg_status, 0);
+    assert_eq!(open_conf.icm_central_controller_time.0, 1_700_000_000);
+    assert!(open_conf.peripheral_online);
+    assert_eq!(open_conf.peripheral_type, PeripheralType::ENTERPRISE_AGENT);
+    assert_eq!(open_conf.agent_state, 0);
+    assert_eq!(open_conf.department_id, -1);
+    assert_eq!(open_conf.session_type, 0);
+    assert!(open_conf.agent_extension.is_none());
+    assert!(open_conf.agent_id.is_none());
+    assert!(open_conf.agent_instrument.is_none());
+    assert_eq!(open_conf.num_peripherals.unwrap().data, 1);
+    assert_eq!(
+        open_conf.flt_peripheral_id.unwrap().data,
+        PeripheralId(5000)
+    );
+    assert_eq!(open_conf.multiline_agent_control.unwrap().data, 0);
+    assert!(open_conf.unknown_fields.is_empty());
+}
+
+#[test]
+fn agent_state_event() {
+    let mut data = load_fixture("agent_state_event.hex");
+    let agent_state_event = AgentStateEvent::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        agent_state_event.mhdr.message_type,
+        MessageType::AGENT_STATE_EVENT
+    ));
+    assert_eq!(agent_state_event.monitor_id, MonitorId(7));
+    assert_eq!(agent_state_event.peripheral_id, PeripheralId(5000));
+    assert_eq!(agent_state_event.session_id, 0);
+    assert_eq!(
+        agent_state_event.peripheral_type,
+        PeripheralType::ENTERPRISE_AGENT
+    );
+    assert_eq!(agent_state_event.skill_group_state, 4);
+    assert_eq!(agent_state_event.state_duration, 35);
+    assert_eq!(agent_state_event.skill_group_number, 1001);
+    assert_eq!(agent_state_event.skill_group_id, 5001);
+    assert_eq!(agent_state_event.skill_group_priority, 1);
+    assert_eq!(agent_state_event.agent_state, 4);
+    assert_eq!(agent_state_event.event_reason_code, 0);
+    assert_eq!(agent_state_event.mrd_id, 1);
+    assert_eq!(agent_state_event.num_tasks, 1);
+    assert_eq!(agent_state_event.agent_mode, 0);
+    assert_eq!(agent_state_event.max_task_limit, 1);
+    assert_eq!(agent_state_event.icm_agent_id, 5123);
+    assert_eq!(agent_state_event.agent_availability_status, 0);
+    assert_eq!(agent_state_event.num_flt_skill_groups, 1);
+    assert_eq!(agent_state_event.department_id, 3);
+    assert_eq!(
+        agent_state_event.cti_client_signature.unwrap().data,
+        "ctmonitor_rs"
+    );
+    assert_eq!(agent_state_event.agent_id.unwrap().data, "1001");
+    assert_eq!(agent_state_event.agent_extension.unwrap().data, "3001");
+    assert!(agent_state_event.active_terminal.is_none());
+    assert_eq!(agent_state_event.agent_instrument.unwrap().data, "3001");
+    assert_eq!(agent_state_event.duration.unwrap().data, 35);
+    assert_eq!(agent_state_event.direction.unwrap().data, Direction::IN);
+    assert_eq!(
+        agent_state_event.skill_groups,
+        vec![SkillGroupAssignment {
+            skill_group_number: 1001,
+            skill_group_id: 5001,
+            skill_group_priority: 1,
+            skill_group_state: 4,
+        }]
+    );
+    assert!(agent_state_event.max_beyond_task_limit.is_none());
+    assert!(agent_state_event.unknown_fields.is_empty());
+}
+
+#[test]
+fn agent_team_config_event() {
+    let mut data = load_fixture("agent_team_config_event.hex");
+    let agent_team_config_event = AgentTeamConfigEvent::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        agent_team_config_event.mhdr.message_type,
+        MessageType::AGENT_TEAM_CONFIG_EVENT
+    ));
+    assert_eq!(agent_team_config_event.peripheral_id, PeripheralId(5000));
+    assert_eq!(agent_team_config_event.team_id, 10);
+    assert_eq!(agent_team_config_event.number_of_agents, 2);
+    assert_eq!(agent_team_config_event.config_operation, 0);
+    assert_eq!(agent_team_config_event.department_id, 3);
+    assert_eq!(
+        agent_team_config_event.agent_team_name.unwrap().data,
+        "Team A"
+    );
+    assert_eq!(agent_team_config_event.agents.len(), 2);
+
+    let expected = [("1001", 0, 3, 120), ("1002", 1, 2, 600)];
+    for (agent, (agent_id, agent_flags, agent_state, state_duration)) in
+        agent_team_config_event.agents.iter().zip(expected)
+    {
+        assert_eq!(agent.agent_id.as_ref().unwrap().data, agent_id);
+        assert_eq!(agent.agent_flags.as_ref().unwrap().data, agent_flags);
+        assert_eq!(agent.agent_state.as_ref().unwrap().data, agent_state);
+        assert_eq!(agent.state_duration.as_ref().unwrap().data, state_duration);
+    }
+    assert!(agent_team_config_event.unknown_fields.is_empty());
+}
+
+#[test]
+fn agent_team_config_event_unordered_agent_tags() {
+    let mut data = load_fixture("agent_team_config_event_unordered.hex");
+    let agent_team_config_event = AgentTeamConfigEvent::try_deserialize(&mut data).unwrap();
+
+    // 상담직원은 ATC_AGENT_ID_TAG 로 나누며, 빠진 태그는 None 으로 남는다
+    let agents = agent_team_config_event
+        .agents
+        .iter()
+        .map(|agent| {
+            (
+                agent.agent_id.as_ref().map(|field| field.data.as_str()),
+                agent.agent_flags.as_ref().map(|field| field.data),
+                agent.agent_state.as_ref().map(|field| field.data),
+                agent.state_duration.as_ref().map(|field| field.data),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        agents,
+        vec![
+            (Some("1001"), Some(0), Some(3), None),
+            (Some("1002"), None, None, Some(600)),
+            (Some("1003"), None, None, None),
+        ]
+    );
+    assert!(agent_team_config_event.unknown_fields.is_empty());
+}
+
+#[test]
+fn query_agent_state_conf() {
+    let mut data = load_fixture("query_agent_state_conf.hex");
+    let query_agent_state_conf = QueryAgentStateConf::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        query_agent_state_conf.mhdr.message_type,
+        MessageType::QUERY_AGENT_STATE_CONF
+    ));
+    assert_eq!(query_agent_state_conf.invoke_id, InvokeId(2));
+    assert_eq!(query_agent_state_conf.agent_state, 3);
+    assert_eq!(query_agent_state_conf.num_skill_groups, 1);
+    assert_eq!(query_agent_state_conf.mrd_id, 1);
+    assert_eq!(query_agent_state_conf.num_task, 0);
+    assert_eq!(query_agent_state_conf.agent_mode, 0);
+    assert_eq!(query_agent_state_conf.max_task_limit, 1);
+    assert_eq!(query_agent_state_conf.icm_agent_id, 5123);
+    assert_eq!(query_agent_state_conf.agent_availability_status, 0);
+    assert_eq!(query_agent_state_conf.department_id, 3);
+    assert_eq!(query_agent_state_conf.agent_id.unwrap().data, "1001");
+    assert_eq!(query_agent_state_conf.agent_extension.unwrap().data, "3001");
+    assert_eq!(
+        query_agent_state_conf.agent_instrument.unwrap().data,
+        "3001"
+    );
+    assert_eq!(
+        query_agent_state_conf.skill_groups,
+        vec![SkillGroupAssignment {
+            skill_group_number: 1001,
+            skill_group_id: 5001,
+            skill_group_priority: 1,
+            skill_group_state: 3,
+        }]
+    );
+    assert_eq!(query_agent_state_conf.internal_agent_state.unwrap().data, 3);
+    assert_eq!(
+        query_agent_state_conf.max_beyond_task_limit.unwrap().data,
+        0
+    );
+    assert!(query_agent_state_conf.unknown_fields.is_empty());
+}
+
+#[test]
+fn query_agent_state_conf_skill_groups() {
+    let mut data = load_fixture("query_agent_state_conf_skill_groups.hex");
+    let query_agent_state_conf = QueryAgentStateConf::try_deserialize(&mut data).unwrap();
+
+    assert_eq!(query_agent_state_conf.num_skill_groups, 2);
+    assert_eq!(
+        query_agent_state_conf.skill_groups,
+        vec![
+            SkillGroupAssignment {
+                skill_group_number: 1001,
+                skill_group_id: 5001,
+                skill_group_priority: 1,
+                skill_group_state: 3,
+            },
+            SkillGroupAssignment {
+                skill_group_number: 1002,
+                skill_group_id: 5002,
+                skill_group_priority: 2,
+                skill_group_state: 2,
+            },
+        ]
+    );
+    assert_eq!(query_agent_state_conf.internal_agent_state.as_ref().unwrap().data, 3);
+    assert!(query_agent_state_conf.unknown_fields.is_empty());
+
+    // 다시 직렬화하면 스킬그룹 순서와 값이 그대로 유지된다
+    let skill_groups = query_agent_state_conf.skill_groups.clone();
+    let mut data = query_agent_state_conf.serialize();
+    let query_agent_state_conf = QueryAgentStateConf::try_deserialize(&mut data).unwrap();
+    assert_eq!(query_agent_state_conf.skill_groups, skill_groups);
+}
+
+#[test]
+fn truncated_packet_is_rejected() {
+    let mut data = load_fixture("agent_state_event.hex");
+    data.truncate(data.len() - 3);
+
+    assert!(AgentStateEvent::try_deserialize(&mut data).is_err());
+}
+
+#[test]
+fn short_numeric_floating_field_is_rejected() {
+    // DURATION_TAG(u32) 의 길이를 2 로 선언한 가변 필드를 덧붙인다
+    let mut data = load_fixture("agent_state_event.hex");
+    data.extend_from_slice(&u16::from(TagValue::DURATION_TAG).to_be_bytes());
+    data.extend_from_slice(&2_u16.to_be_bytes());
+    data.extend_from_slice(&[0, 1]);
+    let length = (data.len() - 8) as u32;
+    data[0..4].copy_from_slice(&length.to_be_bytes());
+
+    assert!(AgentStateEvent::try_deserialize(&mut data).is_err());
+}
+
+#[test]
+fn string_floating_field_stops_at_declared_length() {
+    // NUL 종료 문자 없이 선언된 길이만큼의 문자열 뒤에 DURATION_TAG 를 덧붙인다
+    let mut data = load_fixture("agent_state_event.hex");
+    data.extend_from_slice(&u16::from(TagValue::AGENT_EXTENSION_TAG).to_be_bytes());
+    data.extend_from_slice(&4_u16.to_be_bytes());
+    data.extend_from_slice(b"5001");
+    data.extend_from_slice(&u16::from(TagValue::DURATION_TAG).to_be_bytes());
+    data.extend_from_slice(&4_u16.to_be_bytes());
+    data.extend_from_slice(&30_u32.to_be_bytes());
+    let length = (data.len() - 8) as u32;
+    data[0..4].copy_from_slice(&length.to_be_bytes());
+
+    let agent_state_event = AgentStateEvent::try_deserialize(&mut data).unwrap();
+    assert_eq!(agent_state_event.agent_extension.unwrap().data, "5001");
+    assert_eq!(agent_state_event.duration.unwrap().data, 30);
+}
+
+#[test]
+fn begin_call_event() {
+    let mut data = load_fixture("begin_call_event.hex");
+    let begin_call_event = BeginCallEvent::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        begin_call_event.mhdr.message_type,
+        MessageType::BEGIN_CALL_EVENT
+    ));
+    assert_eq!(begin_call_event.monitor_id, MonitorId(7));
+    assert_eq!(begin_call_event.peripheral_id, PeripheralId(5000));
+    assert_eq!(
+        begin_call_event.peripheral_type,
+        PeripheralType::ENTERPRISE_AGENT
+    );
+    assert_eq!(begin_call_event.call_type, CallType::ACD_IN);
+    assert_eq!(
+        begin_call_event.connection_call_id,
+        ConnectionCallId(0x0100_0001)
+    );
+    assert_eq!(begin_call_event.connection_device_id.unwrap().data, "3001");
+    assert_eq!(begin_call_event.ani.unwrap().data, "01012345678");
+    assert_eq!(begin_call_event.dnis.unwrap().data, "1588");
+    assert_eq!(begin_call_event.dialed_number.unwrap().data, "1588");
+    assert!(begin_call_event.caller_entered_digits.is_none());
+    assert_eq!(begin_call_event.router_call_key_day.unwrap().data, 150_000);
+    assert_eq!(begin_call_event.router_call_key_call_id.unwrap().data, 1111);
+    assert!(begin_call_event.router_call_key_sequence_number.is_none());
+    assert_eq!(begin_call_event.unknown_fields.len(), 1);
+    assert_eq!(
+        begin_call_event.unknown_fields[0].tag,
+        TagValue::CALL_VAR_1_TAG
+    );
+    assert_eq!(begin_call_event.unknown_fields[0].data, b"vip\0");
+}
+
+#[test]
+fn call_established_event() {
+    let mut data = load_fixture("call_established_event.hex");
+    let call_established_event = CallEstablishedEvent::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        call_established_event.mhdr.message_type,
+        MessageType::CALL_ESTABLISHED_EVENT
+    ));
+    assert_eq!(call_established_event.monitor_id, MonitorId(7));
+    assert_eq!(call_established_event.peripheral_id, PeripheralId(5000));
+    assert_eq!(
+        call_established_event.connection_call_id,
+        ConnectionCallId(0x0100_0001)
+    );
+    assert_eq!(call_established_event.service_number, 1);
+    assert_eq!(call_established_event.service_id, 6001);
+    assert_eq!(call_established_event.skill_group_number, 1001);
+    assert_eq!(call_established_event.skill_group_id, 5001);
+    assert_eq!(call_established_event.skill_group_priority, 1);
+    assert_eq!(call_established_event.local_connection_state, 3);
+    assert_eq!(call_established_event.event_cause, 0xFFFF);
+    assert_eq!(
+        call_established_event.connection_device_id.unwrap().data,
+        "3001"
+    );
+    assert_eq!(
+        call_established_event.answering_device_id.unwrap().data,
+        "3001"
+    );
+    assert_eq!(
+        call_established_event.calling_device_id.unwrap().data,
+        "01012345678"
+    );
+    assert_eq!(
+        call_established_event.called_device_id.unwrap().data,
+        "3001"
+    );
+    assert!(call_established_event.last_redirect_device_id.is_none());
+    assert_eq!(call_established_event.trunk_number.unwrap().data, 12);
+    assert_eq!(call_established_event.trunk_group_number.unwrap().data, 2);
+    assert!(call_established_event.unknown_fields.is_empty());
+}
+
+#[test]
+fn call_cleared_event() {
+    let mut data = load_fixture("call_cleared_event.hex");
+    let call_cleared_event = CallClearedEvent::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        call_cleared_event.mhdr.message_type,
+        MessageType::CALL_CLEARED_EVENT
+    ));
+    assert_eq!(
+        call_cleared_event.connection_call_id,
+        ConnectionCallId(0x0100_0001)
+    );
+    assert_eq!(call_cleared_event.local_connection_state, 0);
+    assert_eq!(call_cleared_event.event_cause, 0xFFFF);
+    assert_eq!(
+        call_cleared_event.connection_device_id.unwrap().data,
+        "3001"
+    );
+    assert!(call_cleared_event.unknown_fields.is_empty());
+}
+
+#[test]
+fn call_queued_event() {
+    let mut data = load_fixture("call_queued_event.hex");
+    let call_queued_event = CallQueuedEvent::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        call_queued_event.mhdr.message_type,
+        MessageType::CALL_QUEUED_EVENT
+    ));
+    assert_eq!(
+        call_queued_event.connection_call_id,
+        ConnectionCallId(0x0100_0002)
+    );
+    assert_eq!(call_queued_event.service_id, 6001);
+    assert_eq!(call_queued_event.num_queued, 3);
+    assert_eq!(call_queued_event.num_skill_groups, 1);
+    assert_eq!(call_queued_event.local_connection_state, 5);
+    assert_eq!(call_queued_event.connection_device_id.unwrap().data, "7001");
+    assert_eq!(call_queued_event.queue_device_id.unwrap().data, "7001");
+    assert_eq!(
+        call_queued_event.calling_device_id.unwrap().data,
+        "01012345678"
+    );
+    assert_eq!(call_queued_event.skill_group_numbers.len(), 1);
+    assert_eq!(call_queued_event.skill_group_numbers[0].data, 1001);
+    assert_eq!(call_queued_event.skill_group_ids.len(), 1);
+    assert_eq!(call_queued_event.skill_group_ids[0].data, 5001);
+    assert_eq!(call_queued_event.skill_group_priorities[0].data, 1);
+    assert!(call_queued_event.unknown_fields.is_empty());
+}
+
+#[test]
+fn config_agent_event() {
+    let mut data = load_fixture("config_agent_event.hex");
+    let config_agent_event = ConfigAgentEvent::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        config_agent_event.mhdr.message_type,
+        MessageType::CONFIG_AGENT_EVENT
+    ));
+    assert_eq!(config_agent_event.num_records, 2);
+    assert_eq!(config_agent_event.records.len(), 2);
+
+    let record = &config_agent_event.records[0];
+    assert_eq!(record.record_type.as_ref().unwrap().data, 1);
+    assert_eq!(record.agent_config_key.as_ref().unwrap().data, 16);
+    assert_eq!(record.login_id.as_ref().unwrap().data, "1001");
+    assert_eq!(record.display_name(), "Gildong Hong");
+
+    let record = &config_agent_event.records[1];
+    assert_eq!(record.record_type.as_ref().unwrap().data, 2);
+    assert!(record.agent_config_key.is_none());
+    assert_eq!(record.login_id.as_ref().unwrap().data, "1002");
+    assert_eq!(record.login_name.as_ref().unwrap().data, "kim");
+    assert_eq!(record.display_name(), "Kim");
+
+    assert!(config_agent_event.unknown_fields.is_empty());
+}
+
+#[test]
+fn emergency_call_event() {
+    let mut data = load_fixture("emergency_call_event.hex");
+    let emergency_call_event = EmergencyCallEvent::try_deserialize(&mut data).unwrap();
+
+    assert!(matches!(
+        emergency_call_event.mhdr.message_type,
+        MessageType::EMERGENCY_CALL_EVENT
+    ));
+    assert_eq!(emergency_call_event.peripheral_id, PeripheralId(5000));
+    assert_eq!(
+        emergency_call_event.connection_call_id,
+        ConnectionCallId(0x0100_0001)
+    );
+    assert_eq!(emergency_call_event.session_id, 0);
+    assert_eq!(
+        emergency_call_event.connection_device_id.unwrap().data,
+        "3001"
+    );
+    assert_eq!(emergency_call_event.agent_extension.unwrap().data, "2001");
+    assert_eq!(emergency_call_event.agent_id.unwrap().data, "1001");
+    assert!(emergency_call_event.agent_instrument.is_none());
+    assert!(emergency_call_event.unknown_fields.is_empty());
+}
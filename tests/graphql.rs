@@ -0,0 +1,114 @@
+mod common;
+
+use std::sync::Arc;
+
+use ctm::{
+    ctm::{
+        call_info::CallState,
+        graphql::{build_schema, GraphQLState},
+        team_info::TeamInfo,
+    },
+    event::broker_event::BrokerEvent,
+};
+use futures::StreamExt;
+use serde_json::json;
+
+use common::{agent_info, call_info};
+
+#[tokio::test]
+async fn graphql_query_agents() {
+    let state = Arc::new(GraphQLState::new());
+    state.apply(&BrokerEvent::BroadCastAgentState {
+        client_id: None,
+        agent_info: agent_info("1001", 10, 3).into(),
+    });
+    state.apply(&BrokerEvent::BroadCastAgentState {
+        client_id: None,
+        agent_info: agent_info("1002", 20, 2).into(),
+    });
+    let schema = build_schema(state);
+
+    let response = schema
+        .execute("{ agents(teamId: 10) { agentId teamName agentState } }")
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({"agents": [{"agentId": "1001", "teamName": "Team 10", "agentState": 3}]})
+    );
+
+    let response = schema
+        .execute(
+            r#"{ agent(agentId: "1002") { agentId } missing: agent(agentId: "9999") { agentId } }"#,
+        )
+        .await;
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({"agent": {"agentId": "1002"}, "missing": null})
+    );
+}
+
+#[tokio::test]
+async fn graphql_query_teams_and_calls() {
+    let state = Arc::new(GraphQLState::new());
+    let mut team_info = TeamInfo::new(10);
+    team_info.set_cti_instance("ucce1");
+    team_info.set_team_name("Sales");
+    team_info.add_agent("1001");
+    state.apply(&BrokerEvent::BroadCastTeamSnapshot {
+        client_id: None,
+        team_infos: vec![team_info],
+    });
+    state.apply(&BrokerEvent::BroadCastCallState {
+        client_id: None,
+        call_info: call_info(1, CallState::ESTABLISHED),
+    });
+    state.apply(&BrokerEvent::BroadCastCallState {
+        client_id: None,
+        call_info: call_info(2, CallState::ESTABLISHED),
+    });
+    state.apply(&BrokerEvent::BroadCastCallState {
+        client_id: None,
+        call_info: call_info(2, CallState::CLEARED),
+    });
+    let schema = build_schema(state);
+
+    let response = schema
+        .execute("{ teams { teamName agentIds } calls { connectionCallId callState } }")
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({
+            "teams": [{"teamName": "Sales", "agentIds": ["1001"]}],
+            "calls": [{"connectionCallId": 1, "callState": "ESTABLISHED"}]
+        })
+    );
+}
+
+#[tokio::test]
+async fn graphql_subscription_agent_state_changed() {
+    let state = Arc::new(GraphQLState::new());
+    let schema = build_schema(state.clone());
+
+    let mut stream = schema
+        .execute_stream("subscription { agentStateChanged(teamId: 10) { agentId agentState } }");
+
+    // 구독 스트림이 채널을 구독한 뒤에 이벤트를 반영한다
+    let next = tokio::spawn(async move { stream.next().await });
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    state.apply(&BrokerEvent::BroadCastAgentState {
+        client_id: None,
+        agent_info: agent_info("1002", 20, 2).into(),
+    });
+    state.apply(&BrokerEvent::BroadCastAgentState {
+        client_id: None,
+        agent_info: agent_info("1001", 10, 4).into(),
+    });
+
+    let response = next.await.unwrap().unwrap();
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        json!({"agentStateChanged": {"agentId": "1001", "agentState": 4}})
+    );
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use ctm::ctm::{
+    acceptor::ip_acl::IpAcl,
+    config::HaConfig,
+    ha::{HaElection, HaEvent, HaHeartbeat, HaNode},
+    ha_status::HaRole,
+    shutdown::ShutdownController,
+};
+use tokio::{sync::mpsc, time::timeout};
+
+use common::free_port;
+
+const LEASE_TIMEOUT: Duration = Duration::from_secs(3);
+
+fn heartbeat(node_id: &str, priority: u8, role: HaRole) -> HaHeartbeat {
+    HaHeartbeat {
+        node_id: node_id.to_string(),
+        priority,
+        role,
+    }
+}
+
+#[test]
+fn become_active_when_lease_expires() {
+    let now = Instant::now();
+    let mut election = HaElection::new("ctm1", 100, LEASE_TIMEOUT, now);
+    assert_eq!(election.get_role(), HaRole::STANDBY);
+
+    assert_eq!(election.on_tick(now + Duration::from_secs(1)), None);
+    assert_eq!(election.on_tick(now + LEASE_TIMEOUT), Some(HaRole::ACTIVE));
+    assert_eq!(election.on_tick(now + LEASE_TIMEOUT * 2), None);
+}
+
+#[test]
+fn higher_priority_becomes_active() {
+    let now = Instant::now();
+    let mut primary = HaElection::new("ctm2", 200, LEASE_TIMEOUT, now);
+    let mut secondary = HaElection::new("ctm1", 100, LEASE_TIMEOUT, now);
+
+    assert_eq!(secondary.on_heartbeat(primary.heartbeat(), now), None);
+    assert_eq!(
+        primary.on_heartbeat(secondary.heartbeat(), now),
+        Some(HaRole::ACTIVE)
+    );
+    assert_eq!(primary.get_peer_node_id(), Some("ctm1"));
+
+    // 활성 노드의 하트비트를 받는 동안 대기 노드는 역할을 유지한다
+    let later = now + LEASE_TIMEOUT - Duration::from_millis(1);
+    assert_eq!(secondary.on_heartbeat(primary.heartbeat(), later), None);
+    assert_eq!(secondary.on_tick(later + Duration::from_secs(1)), None);
+    assert_eq!(secondary.get_role(), HaRole::STANDBY);
+}
+
+#[test]
+fn same_priority_prefers_smaller_node_id() {
+    let now = Instant::now();
+    let mut election = HaElection::new("ctm1", 100, LEASE_TIMEOUT, now);
+
+    assert_eq!(
+        election.on_heartbeat(heartbeat("ctm2", 100, HaRole::STANDBY), now),
+        Some(HaRole::ACTIVE)
+    );
+}
+
+#[test]
+fn lower_priority_steps_down_when_both_active() {
+    let now = Instant::now();
+    let mut election = HaElection::new("ctm1", 100, LEASE_TIMEOUT, now);
+    election.on_tick(now + LEASE_TIMEOUT);
+
+    // 우선순위가 낮은 상대 노드는 물러나야 하므로 역할을 유지한다
+    assert_eq!(
+        election.on_heartbeat(heartbeat("ctm2", 50, HaRole::ACTIVE), now),
+        None
+    );
+    assert_eq!(
+        election.on_heartbeat(heartbeat("ctm2", 200, HaRole::ACTIVE), now),
+        Some(HaRole::STANDBY)
+    );
+}
+
+#[test]
+fn take_over_when_active_peer_is_lost() {
+    let now = Instant::now();
+    let mut election = HaElection::new("ctm2", 100, LEASE_TIMEOUT, now);
+    election.on_heartbeat(heartbeat("ctm1", 200, HaRole::ACTIVE), now);
+
+    let last_seen = now + Duration::from_secs(10);
+    election.on_heartbeat(heartbeat("ctm1", 200, HaRole::ACTIVE), last_seen);
+    assert_eq!(election.on_tick(last_seen + Duration::from_secs(1)), None);
+    assert_eq!(
+        election.on_tick(last_seen + LEASE_TIMEOUT),
+        Some(HaRole::ACTIVE)
+    );
+}
+
+fn ha_config(node_id: &str, priority: u8, port: u16, peer_port: u16) -> HaConfig {
+    HaConfig {
+        enabled: true,
+        node_id: node_id.to_string(),
+        priority,
+        bind_address: "127.0.0.1".to_string(),
+        port,
+        peer_address: format!("127.0.0.1:{}", peer_port),
+        heartbeat_interval: Duration::from_millis(50),
+        lease_timeout: Duration::from_millis(300),
+        mirror_interval: Duration::from_secs(1),
+        allowed_ips: IpAcl::default(),
+    }
+}
+
+async fn next_role(ha_event_rx: &mut mpsc::Receiver<HaEvent>) -> (HaRole, Option<String>) {
+    loop {
+        match timeout(Duration::from_secs(5), ha_event_rx.recv())
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            HaEvent::RoleChanged { role, peer_node_id } => return (role, peer_node_id),
+            HaEvent::Mirror(_) => continue,
+        }
+    }
+}
+
+#[tokio::test]
+async fn standby_takes_over_when_active_node_stops() {
+    let (primary_port, secondary_port) = (free_port(), free_port());
+    let primary = HaNode::bind(ha_config("ctm1", 200, primary_port, secondary_port))
+        .await
+        .unwrap();
+    let secondary = HaNode::bind(ha_config("ctm2", 100, secondary_port, primary_port))
+        .await
+        .unwrap();
+
+    let primary_shutdown = ShutdownController::new();
+    let (primary_tx, mut primary_rx) = mpsc::channel(16);
+    let _primary_mirror_tx = primary.start(primary_tx, primary_shutdown.clone());
+
+    let secondary_shutdown = ShutdownController::new();
+    let (secondary_tx, mut secondary_rx) = mpsc::channel(16);
+    let _secondary_mirror_tx = secondary.start(secondary_tx, secondary_shutdown.clone());
+
+    let (role, peer_node_id) = next_role(&mut primary_rx).await;
+    assert_eq!(role, HaRole::ACTIVE);
+    assert_eq!(peer_node_id.as_deref(), Some("ctm2"));
+
+    // 활성 노드가 멈추면 임대 시간이 지난 뒤 대기 노드가 활성 노드가 된다
+    primary_shutdown.shutdown();
+    let (role, _) = next_role(&mut secondary_rx).await;
+    assert_eq!(role, HaRole::ACTIVE);
+
+    secondary_shutdown.shutdown();
+}
@@ -0,0 +1,97 @@
+use std::{thread, time::Duration};
+
+use ctm::{
+    ctm::{health::HealthState, CtmSubscriber},
+    event::system_event::SystemEvent,
+};
+
+fn connect(health_state: &HealthState, cti_instance: &str) {
+    health_state.on_system_event(&SystemEvent::CtiConnected {
+        cti_instance: cti_instance.to_string(),
+        cti_server_host: "10.0.0.1".to_string(),
+        monitor_id: 7,
+    });
+}
+
+#[test]
+fn health_not_ready_until_all_cti_instances_connected() {
+    let health_state = HealthState::new(
+        vec!["ucce1".to_string(), "ucce2".to_string()],
+        Duration::from_secs(30),
+    );
+    assert!(!health_state.report().is_ready());
+
+    connect(&health_state, "ucce1");
+    let report = health_state.report();
+    assert!(!report.is_ready());
+    assert!(report.get_cti_instances()[0].is_ready());
+    assert!(!report.get_cti_instances()[1].is_connected());
+
+    connect(&health_state, "ucce2");
+    assert!(health_state.report().is_ready());
+}
+
+#[test]
+fn health_tracks_failover_and_heartbeat() {
+    let health_state = HealthState::new(vec!["ucce1".to_string()], Duration::from_secs(30));
+    connect(&health_state, "ucce1");
+    assert_eq!(health_state.report().get_cti_instances()[0].get_side(), "A");
+    assert_eq!(
+        health_state.report().get_cti_instances()[0].get_monitor_id(),
+        Some(7)
+    );
+    assert_eq!(
+        health_state.report().get_cti_instances()[0].get_seconds_since_heartbeat(),
+        None
+    );
+
+    health_state.on_system_event(&SystemEvent::HeartBeatConf {
+        cti_instance: "ucce1".to_string(),
+    });
+    assert_eq!(
+        health_state.report().get_cti_instances()[0].get_seconds_since_heartbeat(),
+        Some(0)
+    );
+
+    health_state.on_system_event(&SystemEvent::CtiError {
+        cti_instance: "ucce1".to_string(),
+        cti_server_host: "10.0.0.1".to_string(),
+        error_cause: "Disconnected from server".to_string(),
+    });
+    health_state.on_system_event(&SystemEvent::Failover {
+        cti_instance: "ucce1".to_string(),
+        is_active: false,
+    });
+    let report = health_state.report();
+    assert!(!report.is_ready());
+    assert_eq!(report.get_cti_instances()[0].get_side(), "B");
+    assert_eq!(report.get_cti_instances()[0].get_monitor_id(), None);
+}
+
+#[test]
+fn health_not_ready_when_heartbeat_stale() {
+    let health_state = HealthState::new(vec!["ucce1".to_string()], Duration::from_millis(10));
+    connect(&health_state, "ucce1");
+    assert!(health_state.report().is_ready());
+
+    thread::sleep(Duration::from_millis(20));
+    assert!(!health_state.report().is_ready());
+}
+
+#[test]
+fn health_not_ready_when_acceptor_stopped() {
+    let health_state = HealthState::new(vec![], Duration::from_secs(30));
+    health_state.on_system_event(&SystemEvent::AcceptorStarted {
+        name: "tcp".to_string(),
+    });
+    assert!(health_state.report().is_ready());
+
+    health_state.on_system_event(&SystemEvent::AcceptorStopped {
+        name: "tcp".to_string(),
+        error: Some("Address in use".to_string()),
+    });
+    let report = health_state.report();
+    assert!(!report.is_ready());
+    assert_eq!(report.get_acceptors()[0].get_name(), "tcp");
+    assert!(!report.get_acceptors()[0].is_running());
+}
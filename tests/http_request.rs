@@ -0,0 +1,70 @@
+use ctm::ctm::acceptor::http_request::{HttpRequest, HttpRequestError, HTTP_MAX_HEADER_SIZE};
+
+#[test]
+fn http_request_parse() {
+    let request = HttpRequest::parse(
+        b"GET /ctmonitor?format=msgpack&token=a%2Bb%3D HTTP/1.1\r\nHost: localhost\r\nupgrade: WebSocket\r\nCONNECTION: keep-alive, Upgrade\r\nSec-WebSocket-Key:dGhlIHNhbXBsZSBub25jZQ==\r\n\r\n",
+    )
+    .unwrap();
+
+    assert_eq!(request.get_method(), "GET");
+    assert_eq!(request.get_path(), "/ctmonitor");
+    assert_eq!(request.get_query(), "format=msgpack&token=a%2Bb%3D");
+    assert_eq!(request.get_version(), "HTTP/1.1");
+
+    // 헤더 이름은 대소문자를 구분하지 않는다
+    assert_eq!(request.get_header("Host"), Some("localhost"));
+    assert_eq!(
+        request.get_header("sec-websocket-key"),
+        Some("dGhlIHNhbXBsZSBub25jZQ==")
+    );
+    assert_eq!(request.get_header("Origin"), None);
+    assert!(request.has_header_token("Upgrade", "websocket"));
+    assert!(request.has_header_token("Connection", "upgrade"));
+    assert!(!request.has_header_token("Connection", "close"));
+
+    assert_eq!(
+        request.get_query_param("format"),
+        Some("msgpack".to_string())
+    );
+    assert_eq!(request.get_query_param("token"), Some("a+b=".to_string()));
+    assert_eq!(request.get_query_param("missing"), None);
+}
+
+#[test]
+fn http_request_parse_malformed() {
+    for header in [
+        &b"GET /ctmonitor\r\n\r\n"[..],
+        b"GET ctmonitor HTTP/1.1\r\n\r\n",
+        b"GET /ctmonitor HTTP/1.1 extra\r\n\r\n",
+        b"GET /ctmonitor HTTP/1.1\r\nHost localhost\r\n\r\n",
+        b"GET /ctmonitor HTTP/1.1\r\nHost : localhost\r\n\r\n",
+        b"\xff\xfe /ctmonitor HTTP/1.1\r\n\r\n",
+    ] {
+        assert_eq!(
+            HttpRequest::parse(header).err(),
+            Some(HttpRequestError::MALFORMED)
+        );
+    }
+}
+
+#[test]
+fn http_request_header_length() {
+    // 나누어 받은 요청은 빈 줄을 받을 때까지 기다린다
+    let mut buffer = b"GET /ctmonitor HTTP/1.1\r\nHost: localhost\r\n".to_vec();
+    assert_eq!(HttpRequest::header_length(&buffer), Ok(None));
+
+    buffer.extend_from_slice(b"\r\n\x81\x00");
+    assert_eq!(
+        HttpRequest::header_length(&buffer),
+        Ok(Some(buffer.len() - 2))
+    );
+
+    // 최대 크기를 넘으면 거절한다
+    let mut buffer = b"GET /ctmonitor HTTP/1.1\r\nX-Padding: ".to_vec();
+    buffer.resize(HTTP_MAX_HEADER_SIZE, b'a');
+    assert_eq!(
+        HttpRequest::header_length(&buffer),
+        Err(HttpRequestError::HEADER_TOO_LARGE)
+    );
+}
@@ -0,0 +1,30 @@
+use std::{collections::HashSet, thread};
+
+use ctm::cisco::{InvokeId, InvokeIdGenerator};
+
+#[test]
+fn invoke_id_increments_from_one() {
+    let generator = InvokeIdGenerator::new();
+
+    assert_eq!(generator.next_id(), InvokeId(1));
+    assert_eq!(generator.next_id(), InvokeId(2));
+}
+
+#[test]
+fn invoke_id_is_shared_between_clones() {
+    let generator = InvokeIdGenerator::new();
+    let handles = (0..4)
+        .map(|_| {
+            let generator = generator.clone();
+            thread::spawn(move || (0..100).map(|_| generator.next_id()).collect::<Vec<_>>())
+        })
+        .collect::<Vec<_>>();
+
+    let ids = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect::<HashSet<_>>();
+
+    assert_eq!(ids.len(), 400);
+    assert_eq!(generator.next_id(), InvokeId(401));
+}
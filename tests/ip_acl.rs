@@ -0,0 +1,43 @@
+use std::net::IpAddr;
+
+use ctm::ctm::acceptor::ip_acl::IpAcl;
+
+fn ip(text: &str) -> IpAddr {
+    text.parse().unwrap()
+}
+
+#[test]
+fn empty_acl_allows_all() {
+    let ip_acl = IpAcl::parse(" ").unwrap();
+    assert_eq!(ip_acl.get_network_count(), 0);
+    assert!(ip_acl.allows(ip("10.0.0.1")));
+    assert!(ip_acl.allows(ip("::1")));
+}
+
+#[test]
+fn acl_matches_networks() {
+    let ip_acl = IpAcl::parse("172.30.0.0/16, 10.1.1.1, fd00::/8").unwrap();
+    assert_eq!(ip_acl.get_network_count(), 3);
+
+    assert!(ip_acl.allows(ip("172.30.1.11")));
+    assert!(!ip_acl.allows(ip("172.31.1.11")));
+    assert!(ip_acl.allows(ip("10.1.1.1")));
+    assert!(!ip_acl.allows(ip("10.1.1.2")));
+    assert!(ip_acl.allows(ip("fd12::1")));
+    assert!(!ip_acl.allows(ip("fe80::1")));
+    // IPv4-mapped IPv6 주소는 IPv4 대역으로 비교한다
+    assert!(ip_acl.allows(ip("::ffff:172.30.1.11")));
+
+    // 대괄호로 감싼 IPv6 주소도 허용한다
+    let ip_acl = IpAcl::parse("[fd00::]/8, [::1]").unwrap();
+    assert!(ip_acl.allows(ip("fd12::1")));
+    assert!(ip_acl.allows(ip("::1")));
+
+    assert!(IpAcl::parse("0.0.0.0/0").unwrap().allows(ip("8.8.8.8")));
+}
+
+#[test]
+fn invalid_acl() {
+    assert!(IpAcl::parse("172.30.0.0/33").is_err());
+    assert!(IpAcl::parse("172.30.0.0/16,localhost").is_err());
+}
@@ -0,0 +1,51 @@
+use std::fs;
+
+use ctm::{
+    cisco::MessageType,
+    ctm::journal::{read_journal, JournalRecord, JournalWriter},
+};
+
+#[test]
+fn journal_write_and_read() {
+    let directory = std::env::temp_dir().join(format!("ctm_journal_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+
+    // 세그먼트 크기를 작게 잡아 레코드마다 새 파일이 열리도록 한다
+    let mut journal_writer = JournalWriter::new(&directory, 1);
+    let records = vec![
+        JournalRecord::new(
+            "ucce1",
+            "172.30.1.11",
+            MessageType::AGENT_STATE_EVENT,
+            vec![1, 2, 3],
+        ),
+        JournalRecord::new(
+            "ucce2",
+            "172.30.2.11",
+            MessageType::BEGIN_CALL_EVENT,
+            vec![4, 5],
+        ),
+    ];
+    records
+        .iter()
+        .for_each(|record| journal_writer.write(record).unwrap());
+
+    let mut segments = fs::read_dir(&directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    segments.sort();
+    assert_eq!(segments.len(), 2);
+
+    let replayed = segments
+        .iter()
+        .flat_map(|segment| read_journal(segment).unwrap())
+        .collect::<Vec<_>>();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(replayed, records);
+    assert_eq!(
+        replayed[1].get_message_type(),
+        MessageType::BEGIN_CALL_EVENT
+    );
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use ctm::ctm::{agent_delta::AgentDeltaTracker, agent_info::AgentInfo, locale::LocaleDictionary};
+use serde_json::json;
+
+use common::fixture_path;
+
+#[test]
+fn locale_label_lookup() {
+    let dictionary = LocaleDictionary::from_file(fixture_path("locale.json")).unwrap();
+
+    assert_eq!(dictionary.agent_state_label("ko", 3), Some("대기"));
+    assert_eq!(dictionary.agent_state_label("ko-KR", 2), Some("이석"));
+    assert_eq!(dictionary.agent_state_label("en_US", 2), Some("Not Ready"));
+    assert_eq!(dictionary.agent_state_label("en", 3), None);
+    assert_eq!(dictionary.reason_code_label("ko", 1), Some("점심"));
+    assert_eq!(dictionary.reason_code_label("ja", 1), None);
+    assert!(LocaleDictionary::from_file(fixture_path("not_found.json")).is_err());
+}
+
+#[test]
+fn agent_info_is_localized() {
+    let dictionary = LocaleDictionary::from_file(fixture_path("locale.json")).unwrap();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_agent_state(2);
+    agent_info.set_reason_code(1);
+    agent_info.set_reason_code_label("Lunch");
+    assert_eq!(agent_info.get_agent_state_label(), "NOT_READY");
+
+    // 사전에 없는 언어는 기본 표시 이름을 유지한다
+    let mut default_agent_info = agent_info.clone();
+    default_agent_info.localize(&dictionary, "ja");
+    assert_eq!(default_agent_info.get_agent_state_label(), "NOT_READY");
+    assert_eq!(default_agent_info.get_reason_code_label(), "Lunch");
+
+    agent_info.localize(&dictionary, "ko");
+    assert_eq!(agent_info.get_agent_state_label(), "이석");
+    assert_eq!(agent_info.get_reason_code_label(), "점심");
+}
+
+#[test]
+fn agent_delta_is_localized() {
+    let dictionary = LocaleDictionary::from_file(fixture_path("locale.json")).unwrap();
+    let mut agent_delta_tracker = AgentDeltaTracker::new();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_delta_tracker.track(&agent_info);
+
+    agent_info.set_agent_state(3);
+    let mut agent_info_delta = agent_delta_tracker.track(&agent_info).unwrap();
+    agent_info_delta.localize(&dictionary, "ko");
+    assert_eq!(
+        agent_info_delta.get_changes()["agent_state_label"],
+        json!("대기")
+    );
+    assert!(!agent_info_delta
+        .get_changes()
+        .contains_key("reason_code_label"));
+}
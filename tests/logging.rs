@@ -0,0 +1,9 @@
+use ctm::ctm::logging::LogFormat;
+
+#[test]
+fn parse_log_format() {
+    assert_eq!("".parse::<LogFormat>().unwrap(), LogFormat::TEXT);
+    assert_eq!("text".parse::<LogFormat>().unwrap(), LogFormat::TEXT);
+    assert_eq!(" JSON ".parse::<LogFormat>().unwrap(), LogFormat::JSON);
+    assert!("logfmt".parse::<LogFormat>().is_err());
+}
@@ -0,0 +1,55 @@
+use ctm::ctm::{agent_info::AgentInfo, media_state::MediaState};
+
+fn media_state(mrd_id: i32, agent_state: u16, num_tasks: u32, max_task_limit: u32) -> MediaState {
+    let mut media_state = MediaState::new(mrd_id, agent_state);
+    media_state.set_tasks(num_tasks, max_task_limit);
+    media_state
+}
+
+#[test]
+fn media_states_are_tracked_per_mrd() {
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_agent_state(3);
+
+    // 채팅(5000), 이메일(5001) 상태는 음성 상태와 따로 관리한다
+    agent_info.set_media_state(media_state(5001, 2, 0, 1));
+    agent_info.set_media_state(media_state(5000, 3, 1, 3));
+    assert_eq!(agent_info.get_agent_state(), 3);
+    assert_eq!(
+        agent_info
+            .get_media_states()
+            .iter()
+            .map(|media_state| (media_state.get_mrd_id(), media_state.get_agent_state()))
+            .collect::<Vec<_>>(),
+        vec![(5000, 3), (5001, 2)]
+    );
+
+    agent_info.set_media_state(media_state(5000, 3, 3, 3));
+    assert_eq!(agent_info.get_media_states().len(), 2);
+    assert!(!agent_info.get_media_states()[0].is_available());
+
+    // 로그아웃한 MRD 는 제외한다
+    agent_info.set_media_state(media_state(5001, 1, 0, 1));
+    assert_eq!(agent_info.get_media_states().len(), 1);
+}
+
+#[test]
+fn media_state_availability_follows_task_limit() {
+    assert!(media_state(5000, 3, 0, 0).is_available());
+    assert!(media_state(5000, 3, 2, 3).is_available());
+    assert!(!media_state(5000, 3, 3, 3).is_available());
+    assert!(!media_state(5000, 2, 0, 3).is_available());
+
+    let mut media_state = MediaState::new(5000, 2);
+    media_state.set_reason_code(12);
+    assert_eq!(media_state.get_reason_code(), 12);
+}
+
+#[test]
+fn agent_info_without_media_states_deserializes() {
+    let mut value = serde_json::to_value(AgentInfo::new("1001")).unwrap();
+    value.as_object_mut().unwrap().remove("media_states");
+
+    let agent_info: AgentInfo = serde_json::from_value(value).unwrap();
+    assert!(agent_info.get_media_states().is_empty());
+}
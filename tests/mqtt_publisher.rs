@@ -0,0 +1,22 @@
+use ctm::ctm::acceptor::mqtt_publisher::MqttPublisher;
+use rumqttc::QoS;
+
+#[test]
+fn mqtt_topics() {
+    assert_eq!(
+        MqttPublisher::agent_topic("ctm", "ucce1", "1001"),
+        "ctm/agent/ucce1/1001"
+    );
+    assert_eq!(
+        MqttPublisher::skill_group_topic("ctm", "ucce1", 7),
+        "ctm/skill_group/ucce1/7"
+    );
+}
+
+#[test]
+fn mqtt_parse_qos() {
+    assert_eq!(MqttPublisher::parse_qos(0), QoS::AtMostOnce);
+    assert_eq!(MqttPublisher::parse_qos(1), QoS::AtLeastOnce);
+    assert_eq!(MqttPublisher::parse_qos(2), QoS::ExactlyOnce);
+    assert_eq!(MqttPublisher::parse_qos(9), QoS::AtLeastOnce);
+}
@@ -0,0 +1,33 @@
+use ctm::ctm::net_addr::{bind_tcp_listener, check_host_port, join_host_port, strip_brackets};
+use tokio::net::TcpStream;
+
+#[test]
+fn bracketed_ipv6_addresses() {
+    assert_eq!(strip_brackets("[fd00::1]"), "fd00::1");
+    assert_eq!(strip_brackets("172.30.1.11"), "172.30.1.11");
+
+    assert_eq!(join_host_port("fd00::1", 42027), "[fd00::1]:42027");
+    assert_eq!(join_host_port("[fd00::1]", 42027), "[fd00::1]:42027");
+    assert_eq!(
+        join_host_port("cti-a.example", 42027),
+        "cti-a.example:42027"
+    );
+
+    assert!(check_host_port("[fd00::1]:5130").is_ok());
+    assert!(check_host_port("172.30.1.22:5130").is_ok());
+    assert!(check_host_port("fd00::1:5130").is_err());
+    assert!(check_host_port("172.30.1.22").is_err());
+}
+
+#[tokio::test]
+async fn dual_stack_listener_accepts_ipv4() {
+    // IPv6 를 사용할 수 없는 환경에서는 검사하지 않는다
+    let Ok(listener) = bind_tcp_listener("[::]", 0, true).await else {
+        return;
+    };
+    let port = listener.local_addr().unwrap().port();
+
+    let (client, server) = tokio::join!(TcpStream::connect(("127.0.0.1", port)), listener.accept());
+    client.unwrap();
+    assert!(server.unwrap().1.ip().to_canonical().is_ipv4());
+}
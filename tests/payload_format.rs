@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use ctm::ctm::{
+    acceptor::payload_format::{
+        ClientProtocol, PayloadFormat, SharedPayload, CLIENT_PROTOCOL_VERSION,
+    },
+    agent_info::AgentInfo,
+};
+
+#[test]
+fn parse_format_names() {
+    assert_eq!(PayloadFormat::parse("json"), Some(PayloadFormat::JSON));
+    assert_eq!(
+        PayloadFormat::parse(" MsgPack "),
+        Some(PayloadFormat::MSGPACK)
+    );
+    assert_eq!(PayloadFormat::parse("xml"), None);
+    assert_eq!(PayloadFormat::default(), PayloadFormat::MSGPACK);
+}
+
+#[test]
+fn parse_format_handshake() {
+    assert_eq!(
+        PayloadFormat::parse_handshake(br#"{"format": "json"}"#),
+        Some(PayloadFormat::JSON)
+    );
+
+    let mut buffer = Vec::new();
+    rmp_serde::encode::write_named(
+        &mut buffer,
+        &std::collections::HashMap::from([("format", "msgpack")]),
+    )
+    .unwrap();
+    assert_eq!(
+        PayloadFormat::parse_handshake(&buffer),
+        Some(PayloadFormat::MSGPACK)
+    );
+
+    assert_eq!(
+        PayloadFormat::parse_handshake(br#"{"command": "AGENT_SNAPSHOT"}"#),
+        None
+    );
+}
+
+#[test]
+fn serialize_payload() {
+    let agent_info = AgentInfo::new("1001");
+
+    let json = PayloadFormat::JSON.serialize(&agent_info).unwrap();
+    let value = serde_json::from_slice::<serde_json::Value>(&json).unwrap();
+    assert_eq!(value["agent_id"], "1001");
+
+    let msgpack = PayloadFormat::MSGPACK.serialize(&agent_info).unwrap();
+    let decoded = rmp_serde::from_slice::<AgentInfo>(&msgpack).unwrap();
+    assert_eq!(decoded.get_agent_id(), "1001");
+}
+
+#[test]
+fn parse_client_protocols() {
+    assert_eq!(
+        ClientProtocol::parse("ctm.v1.msgpack"),
+        Some(ClientProtocol::new(PayloadFormat::MSGPACK, Some(1)))
+    );
+    assert_eq!(
+        ClientProtocol::parse(" CTM.v1.JSON "),
+        Some(ClientProtocol::new(PayloadFormat::JSON, Some(1)))
+    );
+    // 버전 없는 형식 이름은 기존 클라이언트 호환용이다
+    assert_eq!(
+        ClientProtocol::parse("json"),
+        Some(ClientProtocol::new(PayloadFormat::JSON, None))
+    );
+    assert_eq!(
+        ClientProtocol::parse(&format!("ctm.v{}.json", CLIENT_PROTOCOL_VERSION + 1)),
+        None
+    );
+    assert_eq!(ClientProtocol::parse("ctm.v0.json"), None);
+    assert_eq!(ClientProtocol::parse("ctm.v1.xml"), None);
+    assert_eq!(ClientProtocol::parse("chat"), None);
+}
+
+#[test]
+fn negotiate_client_protocol() {
+    // 지원하지 않는 프로토콜은 건너뛰고 클라이언트가 먼저 제시한 프로토콜을 선택한다
+    assert_eq!(
+        ClientProtocol::negotiate("ctm.v9.json, ctm.v1.json, ctm.v1.msgpack"),
+        Some((
+            "ctm.v1.json".to_string(),
+            ClientProtocol::new(PayloadFormat::JSON, Some(1))
+        ))
+    );
+    assert_eq!(ClientProtocol::negotiate("chat, superchat"), None);
+}
+
+#[test]
+fn serialize_versioned_payload() {
+    let agent_info = AgentInfo::new("1001");
+
+    let json = ClientProtocol::new(PayloadFormat::JSON, Some(1))
+        .serialize(&agent_info)
+        .unwrap();
+    let value = serde_json::from_slice::<serde_json::Value>(&json).unwrap();
+    assert_eq!(value["version"], 1);
+    assert_eq!(value["payload"]["agent_id"], "1001");
+
+    let msgpack = ClientProtocol::new(PayloadFormat::MSGPACK, Some(1))
+        .serialize(&agent_info)
+        .unwrap();
+    let (version, decoded) = rmp_serde::from_slice::<(u32, AgentInfo)>(&msgpack).unwrap();
+    assert_eq!(version, 1);
+    assert_eq!(decoded.get_agent_id(), "1001");
+
+    // 버전 없이 협상한 클라이언트에는 데이터를 그대로 보낸다
+    let json = ClientProtocol::new(PayloadFormat::JSON, None)
+        .serialize(&agent_info)
+        .unwrap();
+    let value = serde_json::from_slice::<serde_json::Value>(&json).unwrap();
+    assert_eq!(value["agent_id"], "1001");
+}
+
+#[test]
+fn serialize_shared_payload_once() {
+    let agent_info = SharedPayload::new(AgentInfo::new("1001"));
+    let client_protocol = ClientProtocol::new(PayloadFormat::JSON, Some(1));
+
+    // 복제한 데이터는 직렬화 결과를 공유한다
+    let first = agent_info.serialize(client_protocol).unwrap();
+    let second = agent_info.clone().serialize(client_protocol).unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(
+        first.as_slice(),
+        client_protocol.serialize(&AgentInfo::new("1001")).unwrap()
+    );
+
+    // 프로토콜이 다르면 따로 직렬화한다
+    let msgpack = agent_info
+        .serialize(ClientProtocol::new(PayloadFormat::MSGPACK, None))
+        .unwrap();
+    assert!(!Arc::ptr_eq(&first, &msgpack));
+    assert_eq!(agent_info.get_agent_id(), "1001");
+}
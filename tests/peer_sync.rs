@@ -0,0 +1,120 @@
+mod common;
+
+use std::time::Duration;
+
+use ctm::{
+    cisco::ConnectionCallId,
+    ctm::{
+        acceptor::ip_acl::IpAcl,
+        agent_removal::{AgentRemoval, AgentRemovalReason},
+        call_info::CallState,
+        config::PeerSyncConfig,
+        peer_sync::{PeerSyncClient, PeerSyncMessage, PeerSyncServer, PeerSyncState},
+        shutdown::ShutdownController,
+        snapshot::CtmSnapshot,
+        subscriber::CtmSubscriber,
+    },
+};
+use tokio::{sync::mpsc, time::timeout};
+
+use common::{agent_info, call_info};
+
+#[test]
+fn state_applies_changes_on_top_of_snapshot() {
+    let mut state = PeerSyncState::new();
+    state.apply(&PeerSyncMessage::Snapshot(CtmSnapshot {
+        agent_infos: vec![agent_info("1001", 10, 3), agent_info("1002", 10, 2)],
+        call_infos: vec![call_info(1, CallState::QUEUED)],
+        ..Default::default()
+    }));
+
+    state.apply(&PeerSyncMessage::AgentState(agent_info("1001", 10, 4)));
+    state.apply(&PeerSyncMessage::AgentRemoved(AgentRemoval::new(
+        "ucce1",
+        "1002",
+        AgentRemovalReason::LOGOUT_TIMEOUT,
+    )));
+    state.apply(&PeerSyncMessage::CallState(call_info(
+        1,
+        CallState::CLEARED,
+    )));
+    state.apply(&PeerSyncMessage::CallState(call_info(
+        2,
+        CallState::ESTABLISHED,
+    )));
+
+    let snapshot = state.snapshot();
+    assert_eq!(snapshot.agent_infos.len(), 1);
+    assert_eq!(snapshot.agent_infos[0].get_agent_state(), 4);
+    assert_eq!(snapshot.call_infos.len(), 1);
+    assert_eq!(
+        snapshot.call_infos[0].get_connection_call_id(),
+        ConnectionCallId(2)
+    );
+}
+
+async fn next_message(peer_sync_rx: &mut mpsc::Receiver<PeerSyncMessage>) -> PeerSyncMessage {
+    timeout(Duration::from_secs(5), peer_sync_rx.recv())
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn replica_receives_snapshot_then_changes() {
+    let server = PeerSyncServer::bind(&PeerSyncConfig {
+        enabled: true,
+        bind_address: "127.0.0.1".to_string(),
+        port: 0,
+        sources: vec![],
+        read_only: false,
+        reconnect_interval: Duration::from_millis(50),
+        snapshot_interval: Duration::from_secs(60),
+        allowed_ips: IpAcl::default(),
+    })
+    .await
+    .unwrap();
+    let port = server.get_local_addr().unwrap().port();
+    let publisher = server.get_publisher();
+    let shutdown_controller = ShutdownController::new();
+    server.start(shutdown_controller.clone());
+
+    // 복제 노드 접속 전 변경은 전체 상태로 받는다
+    publisher.on_agent_state(&agent_info("1001", 10, 3));
+
+    let (peer_sync_tx, mut peer_sync_rx) = mpsc::channel(16);
+    PeerSyncClient::new(&PeerSyncConfig {
+        enabled: false,
+        bind_address: "127.0.0.1".to_string(),
+        port: 0,
+        sources: vec![format!("127.0.0.1:{}", port)],
+        read_only: true,
+        reconnect_interval: Duration::from_millis(50),
+        snapshot_interval: Duration::from_secs(60),
+        allowed_ips: IpAcl::default(),
+    })
+    .start(peer_sync_tx, shutdown_controller.clone());
+
+    match next_message(&mut peer_sync_rx).await {
+        PeerSyncMessage::Snapshot(snapshot) => {
+            assert_eq!(snapshot.agent_infos.len(), 1);
+            assert_eq!(snapshot.agent_infos[0].get_agent_id(), "1001");
+        }
+        message => panic!("unexpected message: {:?}", message),
+    }
+
+    publisher.on_agent_state(&agent_info("1001", 10, 4));
+    publisher.on_call_state(&call_info(1, CallState::ESTABLISHED));
+    match next_message(&mut peer_sync_rx).await {
+        PeerSyncMessage::AgentState(agent_info) => assert_eq!(agent_info.get_agent_state(), 4),
+        message => panic!("unexpected message: {:?}", message),
+    }
+    match next_message(&mut peer_sync_rx).await {
+        PeerSyncMessage::CallState(call_info) => {
+            assert_eq!(call_info.get_call_state(), CallState::ESTABLISHED)
+        }
+        message => panic!("unexpected message: {:?}", message),
+    }
+
+    shutdown_controller.shutdown();
+}
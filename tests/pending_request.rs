@@ -0,0 +1,78 @@
+use std::time::{Duration, Instant};
+
+use ctm::{
+    cisco::{InvokeId, MessageType},
+    ctm::pending_request::{PendingRequestTimeout, PendingRequests},
+};
+
+#[test]
+fn pending_request_completes_by_invoke_id() {
+    let now = Instant::now();
+    let mut pending_requests = PendingRequests::<()>::new();
+    pending_requests.insert(
+        InvokeId(1),
+        MessageType::OPEN_REQ,
+        (),
+        Duration::from_millis(5_000),
+        0,
+        now,
+    );
+
+    assert!(pending_requests.contains(&InvokeId(1)));
+    assert!(pending_requests.complete(&InvokeId(2)).is_none());
+
+    let pending_request = pending_requests.complete(&InvokeId(1)).unwrap();
+    assert_eq!(pending_request.get_message_type(), &MessageType::OPEN_REQ);
+    assert!(pending_requests.is_empty());
+    assert!(pending_requests
+        .expire(now + Duration::from_millis(10_000))
+        .is_empty());
+}
+
+#[test]
+fn pending_request_retries_before_timing_out() {
+    let now = Instant::now();
+    let timeout = Duration::from_millis(3_000);
+    let mut pending_requests = PendingRequests::<&str>::new();
+    pending_requests.insert(
+        InvokeId(7),
+        MessageType::QUERY_AGENT_STATE_REQ,
+        "1001",
+        timeout,
+        1,
+        now,
+    );
+
+    // 제한 시간 전에는 그대로 기다린다
+    assert!(pending_requests
+        .expire(now + Duration::from_millis(1_000))
+        .is_empty());
+
+    // 첫 시간 초과는 재시도
+    let expired = pending_requests.expire(now + timeout);
+    assert_eq!(expired.len(), 1);
+    assert!(matches!(
+        expired[0],
+        PendingRequestTimeout::RETRY {
+            invoke_id: InvokeId(7),
+            request: "1001",
+            ..
+        }
+    ));
+    assert_eq!(pending_requests.len(), 1);
+
+    // 재시도 횟수를 모두 쓰면 시간 초과로 제거된다
+    assert!(pending_requests
+        .expire(now + timeout + Duration::from_millis(1_000))
+        .is_empty());
+    let expired = pending_requests.expire(now + timeout * 2);
+    assert!(matches!(
+        expired[0],
+        PendingRequestTimeout::TIMED_OUT {
+            invoke_id: InvokeId(7),
+            message_type: MessageType::QUERY_AGENT_STATE_REQ,
+            ..
+        }
+    ));
+    assert!(pending_requests.is_empty());
+}
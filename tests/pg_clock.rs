@@ -0,0 +1,54 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ctm::ctm::pg_clock::PgClock;
+
+fn at(epoch: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(epoch)
+}
+
+#[test]
+fn state_start_time_subtracts_duration() {
+    let pg_clock = PgClock::default();
+
+    assert_eq!(
+        pg_clock.state_start_time(60, at(1_700_000_000)),
+        1_699_999_940
+    );
+}
+
+#[test]
+fn state_start_time_saturates_when_duration_exceeds_clock() {
+    let pg_clock = PgClock::default();
+
+    assert_eq!(pg_clock.state_start_time(120, at(60)), 0);
+}
+
+#[test]
+fn offset_corrects_local_clock() {
+    let pg_clock = PgClock::new(-30, None);
+    assert_eq!(pg_clock.epoch(at(1_700_000_000)), 1_699_999_970);
+    assert_eq!(
+        pg_clock.state_start_time(10, at(1_700_000_000)),
+        1_699_999_960
+    );
+
+    // 보정한 시각이 UNIX 시간 0 보다 작아도 음수가 되지 않는다
+    assert_eq!(PgClock::new(-100, None).epoch(at(10)), 0);
+}
+
+#[test]
+fn detect_clock_skew() {
+    let pg_clock = PgClock::new(0, Some(Duration::from_secs(5)));
+    assert_eq!(pg_clock.skew(1_700_000_010, at(1_700_000_000)), 10);
+    assert_eq!(pg_clock.skew(1_699_999_990, at(1_700_000_000)), -10);
+    assert!(pg_clock.is_skewed(1_700_000_010, at(1_700_000_000)));
+    assert!(pg_clock.is_skewed(1_699_999_990, at(1_700_000_000)));
+    assert!(!pg_clock.is_skewed(1_700_000_005, at(1_700_000_000)));
+
+    // 보정값을 적용하면 오차가 없다
+    let pg_clock = PgClock::new(10, Some(Duration::from_secs(5)));
+    assert!(!pg_clock.is_skewed(1_700_000_010, at(1_700_000_000)));
+
+    // 허용 범위가 없으면 확인하지 않는다
+    assert!(!PgClock::default().is_skewed(0, at(1_700_000_000)));
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use ctm::cisco::{
+    control::query_agent_state_req::QueryAgentStateReq, pretty, InvokeId, MessageType,
+    PeripheralId, Serializable,
+};
+
+use common::load_fixture;
+
+#[test]
+fn agent_state_event_is_rendered_with_names() {
+    let data = load_fixture("agent_state_event.hex");
+    let result = pretty::format_message(&MessageType::AGENT_STATE_EVENT, &data);
+
+    assert!(result.starts_with("AGENT_STATE_EVENT (length: "));
+    assert!(result.contains("  agent_state: 4 (TALKING)\n"));
+    assert!(result.contains("  agent_id [AGENT_ID_TAG]: \"1001\"\n"));
+}
+
+#[test]
+fn unknown_message_is_rendered_as_hex_dump() {
+    let data = [0, 0, 0, 2, 0, 0, 3, 231, 0x41, 0x42];
+    let result = pretty::format_message(&MessageType::from(999), &data);
+
+    assert_eq!(
+        result,
+        "UNKNOWN(999) (length: 10)\n  0000  00 00 00 02 00 00 03 e7 41 42                    ........AB\n"
+    );
+}
+
+#[test]
+fn unhandled_floating_field_is_decoded_by_tag_type() {
+    let mut data = load_fixture("agent_state_event.hex");
+
+    // 처리되지 않는 TEXT_TAG, QUEUE_ID_TAG 가변 필드를 덧붙이고 MHDR 길이를 맞춘다
+    data.extend_from_slice(&[0, 7, 0, 3, b'h', b'i', 0]);
+    data.extend_from_slice(&[0, 130, 0, 4, 0, 0, 0, 42]);
+    let length = (data.len() - 8) as u32;
+    data[0..4].copy_from_slice(&length.to_be_bytes());
+
+    let result = pretty::format_message(&MessageType::AGENT_STATE_EVENT, &data);
+
+    assert!(result.contains("  unknown [TEXT_TAG]: \"hi\"\n"));
+    assert!(result.contains("  unknown [QUEUE_ID_TAG]: 42\n"));
+}
+
+#[test]
+fn hex_dump_text_is_parsed() {
+    assert_eq!(
+        pretty::parse_hex("# header\n0x00 00 00 04  # length\n00 00 00 05\n"),
+        Some(vec![0, 0, 0, 4, 0, 0, 0, 5])
+    );
+    assert_eq!(pretty::parse_hex("00 0"), None);
+    assert_eq!(pretty::parse_hex("zz"), None);
+}
+
+#[test]
+fn consecutive_messages_are_rendered_in_order() {
+    let mut data = load_fixture("open_conf.hex");
+    data.extend(load_fixture("agent_state_event.hex"));
+    data.extend_from_slice(&[0, 0, 0, 10, 0, 0, 0, 30, 1]);
+
+    let result = pretty::format_messages(&data);
+    let open_conf = result.find("OPEN_CONF (length: ").unwrap();
+    let agent_state_event = result.find("AGENT_STATE_EVENT (length: ").unwrap();
+
+    assert!(open_conf < agent_state_event);
+    assert!(result.contains("  agent_state: 4 (TALKING)\n"));
+    assert!(result.contains(" truncated (expected: 18, received: 9)\n"));
+}
+
+#[test]
+fn query_agent_state_req_is_decoded() {
+    let data = QueryAgentStateReq::builder()
+        .invoke_id(InvokeId(2))
+        .peripheral_id(PeripheralId(5000))
+        .agent_id("1001")
+        .build()
+        .unwrap()
+        .serialize();
+
+    let value = pretty::decode_message(&MessageType::QUERY_AGENT_STATE_REQ, &data)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(value["peripheral_id"], 5000);
+    assert_eq!(value["agent_id"]["data"], "1001");
+}
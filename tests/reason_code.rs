@@ -0,0 +1,20 @@
+mod common;
+
+use ctm::ctm::reason_code::ReasonCodeDictionary;
+
+use common::fixture_path;
+
+#[test]
+fn reason_code_label_lookup() {
+    let dictionary = ReasonCodeDictionary::from_file(fixture_path("reason_code.json")).unwrap();
+
+    assert_eq!(dictionary.label(1), Some("Lunch"));
+    assert_eq!(dictionary.label(2), Some("Meeting"));
+    assert_eq!(dictionary.label(999), None);
+}
+
+#[test]
+fn reason_code_missing_file() {
+    assert!(ReasonCodeDictionary::from_file(fixture_path("not_found.json")).is_err());
+    assert_eq!(ReasonCodeDictionary::default().label(1), None);
+}
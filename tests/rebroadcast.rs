@@ -0,0 +1,55 @@
+use std::time::{Duration, Instant};
+
+use ctm::ctm::rebroadcast::FullStateRebroadcast;
+use uuid::Uuid;
+
+#[test]
+fn full_state_is_rebroadcast_to_all_clients_at_once() {
+    let now = Instant::now();
+    let mut full_state_rebroadcast = FullStateRebroadcast::new(Duration::from_secs(60), false, now);
+    let (a, b) = (Uuid::now_v7(), Uuid::now_v7());
+    full_state_rebroadcast.add_client(a, now);
+    full_state_rebroadcast.add_client(b, now);
+
+    assert!(full_state_rebroadcast
+        .due_clients(now + Duration::from_secs(59))
+        .is_empty());
+
+    let mut due_client_ids = full_state_rebroadcast.due_clients(now + Duration::from_secs(60));
+    due_client_ids.sort();
+    assert_eq!(due_client_ids, vec![a, b]);
+
+    // 연결이 끊어진 클라이언트는 제외한다
+    full_state_rebroadcast.remove_client(&a);
+    assert_eq!(
+        full_state_rebroadcast.due_clients(now + Duration::from_secs(120)),
+        vec![b]
+    );
+}
+
+#[test]
+fn staggered_rebroadcast_follows_each_client_schedule() {
+    let now = Instant::now();
+    let interval = Duration::from_secs(60);
+    let mut full_state_rebroadcast = FullStateRebroadcast::new(interval, true, now);
+    let id = Uuid::now_v7();
+    full_state_rebroadcast.add_client(id, now);
+
+    // 첫 재전송은 interval 이후, 최대 interval 만큼 더 늦게 보낸다
+    assert!(full_state_rebroadcast
+        .due_clients(now + interval - Duration::from_millis(1))
+        .is_empty());
+    assert_eq!(
+        full_state_rebroadcast.due_clients(now + interval * 2),
+        vec![id]
+    );
+
+    // 이후에는 전송한 시각부터 interval 마다 보낸다
+    assert!(full_state_rebroadcast
+        .due_clients(now + interval * 2 + Duration::from_secs(1))
+        .is_empty());
+    assert_eq!(
+        full_state_rebroadcast.due_clients(now + interval * 3),
+        vec![id]
+    );
+}
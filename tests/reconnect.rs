@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use ctm::ctm::reconnect::{ReconnectPolicy, ReconnectState};
+
+#[test]
+fn reconnect_policy_backs_off_exponentially_up_to_max_delay() {
+    let reconnect_policy = ReconnectPolicy::new(
+        Duration::from_millis(500),
+        Duration::from_millis(5_000),
+        0.0,
+        3,
+    );
+
+    assert_eq!(reconnect_policy.delay(0, 0.5), Duration::from_millis(500));
+    assert_eq!(reconnect_policy.delay(1, 0.5), Duration::from_millis(1_000));
+    assert_eq!(reconnect_policy.delay(3, 0.5), Duration::from_millis(4_000));
+    assert_eq!(reconnect_policy.delay(4, 0.5), Duration::from_millis(5_000));
+    assert_eq!(
+        reconnect_policy.delay(u32::MAX, 0.5),
+        Duration::from_millis(5_000)
+    );
+}
+
+#[test]
+fn reconnect_policy_applies_jitter() {
+    let reconnect_policy = ReconnectPolicy::new(
+        Duration::from_millis(1_000),
+        Duration::from_millis(30_000),
+        0.2,
+        3,
+    );
+
+    assert_eq!(reconnect_policy.delay(0, 0.0), Duration::from_millis(800));
+    assert_eq!(reconnect_policy.delay(0, 0.5), Duration::from_millis(1_000));
+    assert_eq!(reconnect_policy.delay(0, 1.0), Duration::from_millis(1_200));
+
+    // 지터를 더해도 최대 대기 시간을 넘지 않는다
+    assert_eq!(
+        reconnect_policy.delay(10, 1.0),
+        Duration::from_millis(30_000)
+    );
+
+    // 잘못된 지터 비율은 범위 안으로 제한한다
+    let reconnect_policy = ReconnectPolicy::new(
+        Duration::from_millis(1_000),
+        Duration::from_millis(30_000),
+        f64::NAN,
+        0,
+    );
+    assert_eq!(reconnect_policy.get_jitter(), 0.0);
+    assert_eq!(reconnect_policy.get_failover_threshold(), 1);
+}
+
+#[test]
+fn reconnect_state_fails_over_after_threshold() {
+    let reconnect_policy = ReconnectPolicy::new(
+        Duration::from_millis(100),
+        Duration::from_millis(10_000),
+        0.0,
+        3,
+    );
+    let mut reconnect_state = ReconnectState::new();
+
+    assert_eq!(
+        reconnect_state.on_failure(&reconnect_policy),
+        (Duration::from_millis(100), false)
+    );
+    assert_eq!(
+        reconnect_state.on_failure(&reconnect_policy),
+        (Duration::from_millis(200), false)
+    );
+    // 같은 쪽에서 세 번 연속 실패하면 반대쪽으로 전환한다
+    assert_eq!(
+        reconnect_state.on_failure(&reconnect_policy),
+        (Duration::from_millis(400), true)
+    );
+    assert_eq!(reconnect_state.get_side_failures(), 0);
+
+    // 전환 후에도 대기 시간은 계속 늘어난다
+    assert_eq!(
+        reconnect_state.on_failure(&reconnect_policy),
+        (Duration::from_millis(800), false)
+    );
+
+    reconnect_state.reset();
+    assert_eq!(reconnect_state.get_attempt(), 0);
+    assert_eq!(
+        reconnect_state.on_failure(&reconnect_policy),
+        (Duration::from_millis(100), false)
+    );
+}
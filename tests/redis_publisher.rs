@@ -0,0 +1,36 @@
+use ctm::ctm::{acceptor::redis_publisher::RedisPublisher, agent_info::AgentInfo};
+
+#[test]
+fn redis_agent_key() {
+    assert_eq!(
+        RedisPublisher::agent_key("", "ucce1", "1001"),
+        "agent:ucce1:1001"
+    );
+    assert_eq!(
+        RedisPublisher::agent_key("ctm:", "ucce1", "1001"),
+        "ctm:agent:ucce1:1001"
+    );
+}
+
+#[test]
+fn redis_agent_fields() {
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_cti_instance("ucce1");
+    agent_info.set_team(10, "Sales");
+    agent_info.set_agent_state(3);
+
+    let fields = RedisPublisher::agent_fields(&agent_info);
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    };
+
+    // 문자열은 그대로, 숫자는 JSON 문자열로 저장한다
+    assert_eq!(field("agent_id"), Some("1001"));
+    assert_eq!(field("team_name"), Some("Sales"));
+    assert_eq!(field("team_id"), Some("10"));
+    assert_eq!(field("agent_state"), Some("3"));
+    assert_eq!(field("direction"), Some("NONE"));
+}
@@ -0,0 +1,65 @@
+use ctm::cisco::{RepeatedGroups, SkillGroupAssignment, TagValue};
+
+#[test]
+fn start_tag_begins_new_group() {
+    let mut skill_groups =
+        RepeatedGroups::<SkillGroupAssignment>::new(TagValue::SKILL_GROUP_NUMBER_TAG);
+    skill_groups
+        .group(TagValue::SKILL_GROUP_NUMBER_TAG)
+        .skill_group_number = 1001;
+    skill_groups
+        .group(TagValue::SKILL_GROUP_STATE_TAG)
+        .skill_group_state = 3;
+    skill_groups
+        .group(TagValue::SKILL_GROUP_ID_TAG)
+        .skill_group_id = 5001;
+    skill_groups
+        .group(TagValue::SKILL_GROUP_NUMBER_TAG)
+        .skill_group_number = 1002;
+    skill_groups
+        .group(TagValue::SKILL_GROUP_ID_TAG)
+        .skill_group_id = 5002;
+
+    assert_eq!(skill_groups.len(), 2);
+    assert_eq!(
+        skill_groups.into_vec(),
+        vec![
+            SkillGroupAssignment {
+                skill_group_number: 1001,
+                skill_group_id: 5001,
+                skill_group_priority: 0,
+                skill_group_state: 3,
+            },
+            SkillGroupAssignment {
+                skill_group_number: 1002,
+                skill_group_id: 5002,
+                skill_group_priority: 0,
+                skill_group_state: 0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn tag_without_start_tag_begins_empty_group() {
+    let mut skill_groups =
+        RepeatedGroups::<SkillGroupAssignment>::new(TagValue::SKILL_GROUP_NUMBER_TAG);
+    assert!(skill_groups.is_empty());
+
+    skill_groups
+        .group(TagValue::SKILL_GROUP_ID_TAG)
+        .skill_group_id = 5001;
+    skill_groups
+        .group(TagValue::SKILL_GROUP_PRIORITY_TAG)
+        .skill_group_priority = 1;
+
+    assert_eq!(
+        skill_groups.into_vec(),
+        vec![SkillGroupAssignment {
+            skill_group_number: 0,
+            skill_group_id: 5001,
+            skill_group_priority: 1,
+            skill_group_state: 0,
+        }]
+    );
+}
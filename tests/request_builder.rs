@@ -0,0 +1,116 @@
+use ctm::cisco::{
+    control::query_agent_state_req::QueryAgentStateReq,
+    session::{
+        close_req::{CloseReq, CLOSE_STATUS_NO_ERROR},
+        OpenReq,
+    },
+    BuildError, InvokeId, MessageType, PeripheralId, Serializable, MHDR,
+};
+
+#[test]
+fn open_req_computes_mhdr_length() {
+    let open_req = OpenReq::builder()
+        .invoke_id(InvokeId(1))
+        .version(24)
+        .peripheral_id(PeripheralId(5000))
+        .client_id("ctmonitor_rs")
+        .client_password("")
+        .build()
+        .unwrap();
+
+    assert_eq!(open_req.version_number, 24);
+    assert_eq!(open_req.reserved1, 0);
+    assert_eq!(open_req.client_id.as_ref().unwrap().length, 13);
+
+    let length = open_req.mhdr.length as usize;
+    let buffer = open_req.serialize();
+
+    assert_eq!(buffer.len(), 8 + length);
+    assert_eq!(&buffer[0..4], &(length as u32).to_be_bytes());
+    assert_eq!(&buffer[4..8], &3_u32.to_be_bytes());
+}
+
+#[test]
+fn open_req_requires_client_id() {
+    let result = OpenReq::builder()
+        .invoke_id(InvokeId(1))
+        .peripheral_id(PeripheralId(5000))
+        .client_password("")
+        .build();
+
+    assert_eq!(
+        result.err(),
+        Some(BuildError::MissingField {
+            message_type: MessageType::OPEN_REQ,
+            field: "client_id",
+        })
+    );
+}
+
+#[test]
+fn open_req_rejects_long_client_id() {
+    let result = OpenReq::builder()
+        .invoke_id(InvokeId(1))
+        .peripheral_id(PeripheralId(5000))
+        .client_id("x".repeat(65))
+        .client_password("")
+        .build();
+
+    assert!(matches!(
+        result,
+        Err(BuildError::FieldTooLong {
+            field: "client_id",
+            length: 65,
+            max_length: 64,
+        })
+    ));
+}
+
+#[test]
+fn query_agent_state_req_computes_mhdr_length() {
+    let query_agent_state_req = QueryAgentStateReq::builder()
+        .invoke_id(InvokeId(2))
+        .peripheral_id(PeripheralId(5000))
+        .agent_id("1001")
+        .build()
+        .unwrap();
+
+    // 고정 필드 16 바이트 + AgentID 가변 필드 (태그 2, 길이 2, "1001\0")
+    assert_eq!(query_agent_state_req.mhdr.length, 16 + 4 + 5);
+    assert_eq!(query_agent_state_req.serialize().len(), 8 + 16 + 4 + 5);
+}
+
+#[test]
+fn query_agent_state_req_requires_agent() {
+    let result = QueryAgentStateReq::builder()
+        .invoke_id(InvokeId(2))
+        .peripheral_id(PeripheralId(5000))
+        .build();
+
+    assert!(matches!(
+        result,
+        Err(BuildError::MissingField {
+            message_type: MessageType::QUERY_AGENT_STATE_REQ,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn close_req_serialize() {
+    let close_req = CloseReq {
+        mhdr: MHDR {
+            length: 8,
+            message_type: MessageType::CLOSE_REQ,
+        },
+        invoke_id: InvokeId(7),
+        status: CLOSE_STATUS_NO_ERROR,
+    };
+    let buffer = close_req.serialize();
+
+    assert_eq!(buffer.len(), 16);
+    assert_eq!(&buffer[0..4], &8_u32.to_be_bytes());
+    assert_eq!(&buffer[4..8], &7_u32.to_be_bytes());
+    assert_eq!(&buffer[8..12], &7_u32.to_be_bytes());
+    assert_eq!(&buffer[12..16], &0_u32.to_be_bytes());
+}
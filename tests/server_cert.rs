@@ -0,0 +1,157 @@
+mod common;
+
+use std::{fs, sync::Arc, time::Duration};
+
+use ctm::ctm::acceptor::{client_cert::common_name, server_cert::ServerCertResolver};
+use rustls::{
+    pki_types::{pem::PemObject, CertificateDer, ServerName},
+    ClientConfig, RootCertStore, ServerConfig,
+};
+use tokio_rustls::{TlsAcceptor, TlsConnector};
+
+use common::fixture_path;
+
+fn fixture(name: &str) -> String {
+    fixture_path(name).to_string_lossy().to_string()
+}
+
+fn server_cert_resolver(
+    cert_file: &str,
+    key_file: &str,
+    sni_certs: &str,
+) -> Arc<ServerCertResolver> {
+    Arc::new(
+        ServerCertResolver::new(
+            ServerConfig::builder().crypto_provider().clone(),
+            cert_file,
+            key_file,
+            &ServerCertResolver::parse_sni_certs(sni_certs).unwrap(),
+        )
+        .unwrap(),
+    )
+}
+
+///
+/// 서버 이름으로 TLS 접속하여 서버 인증서의 CN 을 반환한다
+///
+async fn handshake(server_cert_resolver: Arc<ServerCertResolver>, server_name: &str) -> String {
+    let tls_acceptor = TlsAcceptor::from(Arc::new(
+        ServerConfig::builder()
+            .with_no_client_auth()
+            .with_cert_resolver(server_cert_resolver),
+    ));
+
+    let mut root_cert_store = RootCertStore::empty();
+    root_cert_store
+        .add(CertificateDer::from_pem_file(fixture_path("server_ca.crt")).unwrap())
+        .unwrap();
+    let tls_connector = TlsConnector::from(Arc::new(
+        ClientConfig::builder()
+            .with_root_certificates(root_cert_store)
+            .with_no_client_auth(),
+    ));
+
+    let (client_stream, server_stream) = tokio::io::duplex(16_384);
+    let server_name = ServerName::try_from(server_name.to_string()).unwrap();
+    let (client, server) = tokio::join!(
+        tls_connector.connect(server_name, client_stream),
+        tls_acceptor.accept(server_stream)
+    );
+    server.unwrap();
+
+    let client = client.unwrap();
+    let cert = client.get_ref().1.peer_certificates().unwrap()[0].clone();
+    common_name(&cert).unwrap()
+}
+
+#[test]
+fn parse_sni_certs() {
+    let sni_certs = ServerCertResolver::parse_sni_certs(
+        " a.example.com=./a.crt,./a.key ; *.example.com=./b.crt, ./b.key;",
+    )
+    .unwrap();
+
+    assert_eq!(
+        sni_certs,
+        vec![
+            (
+                "a.example.com".to_string(),
+                "./a.crt".to_string(),
+                "./a.key".to_string()
+            ),
+            (
+                "*.example.com".to_string(),
+                "./b.crt".to_string(),
+                "./b.key".to_string()
+            ),
+        ]
+    );
+    assert!(ServerCertResolver::parse_sni_certs("").unwrap().is_empty());
+    assert!(ServerCertResolver::parse_sni_certs("a.example.com=./a.crt").is_err());
+    assert!(ServerCertResolver::parse_sni_certs("./a.crt,./a.key").is_err());
+}
+
+#[tokio::test]
+async fn server_cert_sni() {
+    let server_cert_resolver = server_cert_resolver(
+        &fixture("server.crt"),
+        &fixture("server.key"),
+        &format!(
+            "ws.example.com={},{}",
+            fixture("sni_server.crt"),
+            fixture("sni_server.key")
+        ),
+    );
+
+    assert_eq!(
+        handshake(server_cert_resolver.clone(), "ws.example.com").await,
+        "ws.example.com"
+    );
+    assert_eq!(
+        handshake(server_cert_resolver, "ctm.example.com").await,
+        "ctm.example.com"
+    );
+}
+
+#[tokio::test]
+async fn server_cert_reload() {
+    let directory = std::env::temp_dir().join(format!("ctm_server_cert_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    let cert_file = directory.join("server.crt");
+    let key_file = directory.join("server.key");
+    fs::copy(fixture_path("server.crt"), &cert_file).unwrap();
+    fs::copy(fixture_path("server.key"), &key_file).unwrap();
+
+    let server_cert_resolver = server_cert_resolver(
+        &cert_file.to_string_lossy(),
+        &key_file.to_string_lossy(),
+        "",
+    );
+    assert_eq!(server_cert_resolver.reload(), 0);
+    assert_eq!(
+        handshake(server_cert_resolver.clone(), "ctm.example.com").await,
+        "ctm.example.com"
+    );
+
+    // 수정 시각이 바뀌도록 잠시 기다린 뒤 인증서를 교체한다
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    fs::copy(fixture_path("sni_server.crt"), &cert_file).unwrap();
+    fs::copy(fixture_path("sni_server.key"), &key_file).unwrap();
+    assert_eq!(server_cert_resolver.reload(), 1);
+    assert_eq!(
+        handshake(server_cert_resolver.clone(), "ws.example.com").await,
+        "ws.example.com"
+    );
+
+    // 잘못된 인증서로 교체되면 기존 인증서를 계속 사용한다
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    fs::write(&cert_file, b"invalid").unwrap();
+    assert_eq!(server_cert_resolver.reload(), 0);
+    assert_eq!(
+        handshake(server_cert_resolver, "ws.example.com").await,
+        "ws.example.com"
+    );
+
+    let _ = fs::remove_dir_all(&directory);
+}
@@ -0,0 +1,39 @@
+use ctm::ctm::session_resume::SessionResume;
+
+#[test]
+fn parse_resume_from_first_message() {
+    assert_eq!(
+        SessionResume::parse(
+            br#"{"format": "json", "resume_epoch": "epoch-1", "resume_sequence": 42}"#
+        ),
+        Some(SessionResume::new("epoch-1", 42))
+    );
+    assert_eq!(
+        SessionResume::parse(
+            &rmp_serde::to_vec_named(&serde_json::json!({
+                "token": "secret",
+                "resume_epoch": "epoch-1",
+                "resume_sequence": 7,
+            }))
+            .unwrap()
+        ),
+        Some(SessionResume::new("epoch-1", 7))
+    );
+    assert_eq!(SessionResume::parse(br#"{"format": "json"}"#), None);
+}
+
+#[test]
+fn parse_resume_from_query() {
+    assert_eq!(
+        SessionResume::from_query(Some("epoch-1".to_string()), Some("42".to_string())),
+        Some(SessionResume::new("epoch-1", 42))
+    );
+    assert_eq!(
+        SessionResume::from_query(Some("epoch-1".to_string()), Some("x".to_string())),
+        None
+    );
+    assert_eq!(
+        SessionResume::from_query(None, Some("42".to_string())),
+        None
+    );
+}
@@ -0,0 +1,93 @@
+use std::{
+    error::Error,
+    fs,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use ctm::{
+    ctm::{
+        acceptor::Acceptor,
+        shutdown::{ShutdownController, ShutdownNotice},
+        CTM,
+    },
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+use tokio::{
+    sync::{broadcast, mpsc},
+    time::{sleep, timeout},
+};
+
+///
+/// 종료 이벤트를 받으면 종료되는 Acceptor
+///
+struct ShutdownProbe {
+    stopped: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl Acceptor for ShutdownProbe {
+    async fn accept(
+        &self,
+        mut broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        _client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        loop {
+            if let Ok(BrokerEvent::Shutdown) = broker_event_channel_rx.recv().await {
+                self.stopped.store(true, Ordering::Release);
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[test]
+fn shutdown_controller() {
+    let shutdown_controller = ShutdownController::new();
+    let cloned = shutdown_controller.clone();
+    assert!(!cloned.is_shutdown());
+
+    shutdown_controller.shutdown();
+    assert!(cloned.is_shutdown());
+}
+
+#[test]
+fn shutdown_notice_serialize() {
+    let value = serde_json::to_value(ShutdownNotice::new()).unwrap();
+
+    assert_eq!(value["event"], "SHUTDOWN");
+    assert_eq!(value["message"], "server shutting down");
+    assert!(value["timestamp"].as_u64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn ctm_shutdown_stops_acceptors() {
+    // 빈 저널을 재생하여 CTI 서버 없이 실행한다
+    let journal_file =
+        std::env::temp_dir().join(format!("ctm_shutdown_{}.journal", std::process::id()));
+    fs::write(&journal_file, b"").unwrap();
+
+    let stopped = Arc::new(AtomicBool::new(false));
+    let ctm = CTM::builder()
+        .with_acceptor(Box::new(ShutdownProbe {
+            stopped: stopped.clone(),
+        }))
+        .with_replay(&journal_file)
+        .build()
+        .await
+        .unwrap();
+    let shutdown_controller = ctm.get_shutdown_controller();
+
+    let (result, _) = tokio::join!(timeout(Duration::from_secs(5), ctm.start()), async {
+        sleep(Duration::from_millis(100)).await;
+        shutdown_controller.shutdown();
+    });
+    let _ = fs::remove_file(&journal_file);
+
+    assert!(result.unwrap().is_ok());
+    assert!(stopped.load(Ordering::Acquire));
+}
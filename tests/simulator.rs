@@ -0,0 +1,173 @@
+use std::{sync::Arc, time::Duration};
+
+use ctm::{
+    cisco::{
+        client_event::agent_state_event::AgentStateEvent,
+        codec::CtiCodec,
+        control::{
+            query_agent_state_conf::QueryAgentStateConf, query_agent_state_req::QueryAgentStateReq,
+        },
+        session::{heartbeat_req::HeartBeatReq, OpenConf, OpenReq, ServicesRequested},
+        supervisor::agent_team_config_event::AgentTeamConfigEvent,
+        CtiMessage, InvokeId, MessageType, PeripheralId, Serializable, MHDR,
+    },
+    ctm::simulator::{CtiSimulator, ScriptedEvent, SimulatorScript},
+};
+use futures::{SinkExt, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::codec::Framed;
+
+fn simulator(events: Vec<ScriptedEvent>) -> CtiSimulator {
+    let mut script = SimulatorScript::generated(2);
+    script.events = events;
+
+    CtiSimulator::new(script, PeripheralId(5000), Duration::from_millis(10))
+}
+
+fn open_req(invoke_id: u32) -> OpenReq {
+    OpenReq::builder()
+        .invoke_id(InvokeId(invoke_id))
+        .peripheral_id(PeripheralId(5000))
+        .services_requested(ServicesRequested::ALL_EVENTS)
+        .client_id("ctmonitor_rs")
+        .client_password("")
+        .build()
+        .unwrap()
+}
+
+fn query_agent_state_req(invoke_id: u32, agent_id: &str) -> QueryAgentStateReq {
+    QueryAgentStateReq::builder()
+        .invoke_id(InvokeId(invoke_id))
+        .peripheral_id(PeripheralId(5000))
+        .agent_id(agent_id)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn simulator_answers_open_req_with_open_conf_and_team_config() {
+    let simulator = simulator(vec![]);
+
+    let mut responses =
+        simulator.handle_request(&MessageType::OPEN_REQ, &mut open_req(1).serialize());
+    assert_eq!(responses.len(), 2);
+
+    let open_conf = OpenConf::try_deserialize(&mut responses[0]).unwrap();
+    assert_eq!(open_conf.invoke_id, InvokeId(1));
+    assert_eq!(
+        open_conf.service_granted,
+        ServicesRequested::ALL_EVENTS.bits()
+    );
+    assert!(open_conf.peripheral_online);
+
+    let agent_team_config_event = AgentTeamConfigEvent::try_deserialize(&mut responses[1]).unwrap();
+    assert_eq!(agent_team_config_event.team_id, 1);
+    assert_eq!(
+        agent_team_config_event
+            .agents
+            .iter()
+            .map(|agent| agent.agent_id.clone().unwrap().data)
+            .collect::<Vec<_>>(),
+        vec!["1001".to_string(), "1002".to_string()]
+    );
+    assert_eq!(
+        agent_team_config_event.agents[0]
+            .agent_state
+            .clone()
+            .unwrap()
+            .data,
+        2
+    );
+}
+
+#[test]
+fn simulator_answers_query_agent_state_req_with_current_state() {
+    let simulator = simulator(vec![ScriptedEvent {
+        delay: 0,
+        agent_id: "1002".to_string(),
+        agent_state: 4,
+        reason_code: 0,
+    }]);
+
+    let mut agent_state_event = simulator.next_event(0).unwrap();
+    let agent_state_event = AgentStateEvent::try_deserialize(&mut agent_state_event).unwrap();
+    assert_eq!(agent_state_event.agent_id.unwrap().data, "1002");
+    assert_eq!(agent_state_event.agent_extension.unwrap().data, "2002");
+    assert_eq!(agent_state_event.agent_state, 4);
+    assert_eq!(simulator.get_agent_state("1002"), Some(4));
+
+    let mut responses = simulator.handle_request(
+        &MessageType::QUERY_AGENT_STATE_REQ,
+        &mut query_agent_state_req(7, "1002").serialize(),
+    );
+    let query_agent_state_conf = QueryAgentStateConf::try_deserialize(&mut responses[0]).unwrap();
+    assert_eq!(query_agent_state_conf.invoke_id, InvokeId(7));
+    assert_eq!(query_agent_state_conf.agent_state, 4);
+    assert_eq!(query_agent_state_conf.skill_groups.len(), 1);
+    assert_eq!(query_agent_state_conf.skill_groups[0].skill_group_id, 1);
+
+    // 알 수 없는 상담직원은 응답하지 않는다
+    assert!(simulator
+        .handle_request(
+            &MessageType::QUERY_AGENT_STATE_REQ,
+            &mut query_agent_state_req(8, "9999").serialize(),
+        )
+        .is_empty());
+}
+
+#[test]
+fn simulator_random_events_target_known_agents() {
+    let simulator = simulator(vec![]);
+
+    for event_index in 0..20 {
+        let mut agent_state_event = simulator.next_event(event_index).unwrap();
+        let agent_state_event = AgentStateEvent::try_deserialize(&mut agent_state_event).unwrap();
+        let agent_id = agent_state_event.agent_id.unwrap().data;
+
+        assert_eq!(
+            simulator.get_agent_state(&agent_id),
+            Some(agent_state_event.agent_state)
+        );
+        assert_eq!(
+            simulator.event_delay(event_index),
+            Duration::from_millis(10)
+        );
+    }
+}
+
+#[tokio::test]
+async fn simulator_session_sends_agent_state_events_after_open_conf() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(Arc::new(simulator(vec![])).serve(listener));
+
+    let stream = TcpStream::connect(address).await.unwrap();
+    let mut client_stream = Framed::new(stream, CtiCodec::default());
+    client_stream.send(open_req(1)).await.unwrap();
+    client_stream
+        .send(HeartBeatReq {
+            mhdr: MHDR {
+                length: 4,
+                message_type: MessageType::HEARTBEAT_REQ,
+            },
+            invoke_id: InvokeId(2),
+        })
+        .await
+        .unwrap();
+
+    let mut message_types = vec![];
+    while !message_types.contains(&MessageType::AGENT_STATE_EVENT)
+        || !message_types.contains(&MessageType::HEARTBEAT_CONF)
+    {
+        let (message_type, _) = tokio::time::timeout(Duration::from_secs(5), client_stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        message_types.push(message_type);
+    }
+
+    assert_eq!(message_types[0], MessageType::OPEN_CONF);
+    assert_eq!(message_types[1], MessageType::AGENT_TEAM_CONFIG_EVENT);
+}
@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use ctm::{
+    cisco::{ConnectionCallId, SkillGroupAssignment},
+    ctm::{
+        agent_info::AgentInfo,
+        call_info::{CallInfo, CallState},
+        skill_group_info::SkillGroupInfo,
+    },
+};
+
+#[test]
+fn aggregate_agents_and_queued_calls() {
+    let skill_group_agent_map = HashMap::from([
+        (
+            5001,
+            HashMap::from([
+                ("1001".to_string(), 3),
+                ("1002".to_string(), 3),
+                ("1003".to_string(), 2),
+                ("1004".to_string(), 4),
+                ("1005".to_string(), 10),
+                ("1006".to_string(), 6),
+            ]),
+        ),
+        (5002, HashMap::from([("1001".to_string(), 5)])),
+    ]);
+
+    let mut call_info_map = HashMap::new();
+    for (connection_call_id, call_state, skill_group_id) in [
+        (1, CallState::QUEUED, 5001),
+        (2, CallState::QUEUED, 5001),
+        (3, CallState::QUEUED, 5003),
+        (4, CallState::ESTABLISHED, 5001),
+    ] {
+        let mut call_info = CallInfo::new(ConnectionCallId(connection_call_id));
+        call_info.set_call_state(call_state);
+        call_info.set_skill_group_id(skill_group_id);
+        call_info_map.insert(ConnectionCallId(connection_call_id), call_info);
+    }
+
+    let skill_group_infos = SkillGroupInfo::aggregate(&skill_group_agent_map, &call_info_map);
+
+    let summary = skill_group_infos
+        .iter()
+        .map(|skill_group_info| {
+            (
+                skill_group_info.get_skill_group_id(),
+                skill_group_info.get_agents_ready(),
+                skill_group_info.get_agents_not_ready(),
+                skill_group_info.get_agents_talking(),
+                skill_group_info.get_calls_in_queue(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        summary,
+        vec![(5001, 2, 1, 2, 2), (5002, 0, 1, 0, 0), (5003, 0, 0, 0, 1)]
+    );
+}
+
+#[test]
+fn aggregate_empty() {
+    assert!(SkillGroupInfo::aggregate(&HashMap::new(), &HashMap::new()).is_empty());
+}
+
+#[test]
+fn agent_skill_group_membership() {
+    let skill_groups = vec![
+        SkillGroupAssignment {
+            skill_group_number: 1001,
+            skill_group_id: 5001,
+            skill_group_priority: 1,
+            skill_group_state: 3,
+        },
+        SkillGroupAssignment {
+            skill_group_number: 1002,
+            skill_group_id: 5002,
+            skill_group_priority: 2,
+            skill_group_state: 3,
+        },
+    ];
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_agent_state(3);
+    agent_info.set_skill_groups(skill_groups.clone());
+    assert_eq!(agent_info.get_skill_groups(), skill_groups.as_slice());
+
+    // 스킬그룹 목록이 없는 이벤트는 기존 목록을 유지한다
+    agent_info.set_skill_groups(vec![]);
+    assert_eq!(agent_info.get_skill_groups().len(), 2);
+
+    let value = serde_json::to_value(&agent_info).unwrap();
+    assert_eq!(value["skill_groups"][1]["skill_group_id"], 5002);
+
+    // 로그아웃하면 비운다
+    agent_info.set_agent_state(1);
+    agent_info.set_skill_groups(skill_groups);
+    assert!(agent_info.get_skill_groups().is_empty());
+}
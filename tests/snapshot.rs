@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use ctm::{
+    cisco::ConnectionCallId,
+    ctm::{
+        agent_info::AgentInfo,
+        call_info::CallInfo,
+        field_masker::{FieldMasker, MaskingMode},
+        snapshot::CtmSnapshot,
+        team_info::TeamInfo,
+    },
+};
+
+#[test]
+fn snapshot_save_and_restore() {
+    let path = std::env::temp_dir().join(format!("ctm_snapshot_{}.msgpack", std::process::id()));
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_team(10, "Team A");
+    let mut team_info = TeamInfo::new(10);
+    team_info.add_agent("1001");
+    let snapshot = CtmSnapshot {
+        agent_infos: vec![agent_info],
+        call_infos: vec![CallInfo::new(ConnectionCallId(7))],
+        team_infos: vec![team_info.clone()],
+        skill_group_agent_map: HashMap::from([(
+            "ucce1".to_string(),
+            HashMap::from([(200, HashMap::from([("1001".to_string(), 3)]))]),
+        )]),
+    };
+
+    snapshot.save(&path).unwrap();
+    let restored = CtmSnapshot::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(restored.agent_infos.len(), 1);
+    assert_eq!(restored.agent_infos[0].get_agent_id(), "1001");
+    assert_eq!(restored.agent_infos[0].get_team_id(), 10);
+    assert_eq!(
+        restored.call_infos[0].get_connection_call_id(),
+        ConnectionCallId(7)
+    );
+    assert_eq!(restored.team_infos, vec![team_info]);
+    assert_eq!(restored.skill_group_agent_map["ucce1"][&200]["1001"], 3);
+}
+
+#[test]
+fn snapshot_missing_file() {
+    assert!(CtmSnapshot::from_file("./res/not_exists.msgpack").is_err());
+}
+
+#[test]
+fn snapshot_import_json() {
+    let path = std::env::temp_dir().join(format!("ctm_state_{}.json", std::process::id()));
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_cti_instance("ucce1");
+    agent_info.set_agent_state(3);
+    let snapshot = CtmSnapshot {
+        agent_infos: vec![agent_info],
+        call_infos: vec![CallInfo::new(ConnectionCallId(7))],
+        team_infos: vec![TeamInfo::new(10)],
+        skill_group_agent_map: HashMap::from([(
+            "ucce1".to_string(),
+            HashMap::from([(200, HashMap::from([("1001".to_string(), 3)]))]),
+        )]),
+    };
+
+    // EXPORT_STATE 응답을 JSON 으로 받은 문서
+    std::fs::write(&path, serde_json::to_vec_pretty(&snapshot).unwrap()).unwrap();
+    let restored = CtmSnapshot::from_json_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(restored.agent_infos[0].get_agent_id(), "1001");
+    assert_eq!(restored.agent_infos[0].get_agent_state(), 3);
+    assert_eq!(
+        restored.call_infos[0].get_connection_call_id(),
+        ConnectionCallId(7)
+    );
+    assert_eq!(restored.team_infos, vec![TeamInfo::new(10)]);
+    assert_eq!(restored.skill_group_agent_map["ucce1"][&200]["1001"], 3);
+
+    assert!(CtmSnapshot::from_json_file("./res/not_exists.json").is_err());
+}
+
+#[test]
+fn snapshot_mask_fields() {
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_agent_extension("5001");
+    let mut snapshot = CtmSnapshot {
+        agent_infos: vec![agent_info],
+        ..Default::default()
+    };
+
+    snapshot.mask_fields(&FieldMasker::new(MaskingMode::PARTIAL, "", 2));
+    assert_eq!(snapshot.agent_infos[0].get_agent_extension(), "**01");
+}
@@ -0,0 +1,76 @@
+use std::time::{Duration, Instant};
+
+use ctm::ctm::{
+    client_auth::ClientPermission,
+    command::ClientCommand,
+    state_refresh::{StateRefreshLimiter, StateRefreshResult, StateRefreshScope},
+};
+
+#[test]
+fn refresh_state_requires_control_permission() {
+    let command = ClientCommand::parse(br#"{"command":"REFRESH_STATE","team_id":10}"#).unwrap();
+
+    assert_eq!(
+        command,
+        ClientCommand::REFRESH_STATE {
+            cti_instance: "default".to_string(),
+            agent_id: None,
+            team_id: Some(10),
+        }
+    );
+    assert_eq!(command.required_permission(), ClientPermission::CONTROL);
+}
+
+#[test]
+fn refresh_of_same_target_is_rate_limited() {
+    let now = Instant::now();
+    let mut state_refresh_limiter = StateRefreshLimiter::new(Duration::from_secs(30));
+
+    assert!(state_refresh_limiter
+        .try_acquire("ucce1", StateRefreshScope::TEAM, "10", now)
+        .is_ok());
+    assert_eq!(
+        state_refresh_limiter.try_acquire(
+            "ucce1",
+            StateRefreshScope::TEAM,
+            "10",
+            now + Duration::from_secs(10)
+        ),
+        Err(Duration::from_secs(20))
+    );
+
+    // 다른 대상과 범위는 따로 제한한다
+    assert!(state_refresh_limiter
+        .try_acquire("ucce1", StateRefreshScope::TEAM, "20", now)
+        .is_ok());
+    assert!(state_refresh_limiter
+        .try_acquire("ucce1", StateRefreshScope::ALL, "", now)
+        .is_ok());
+
+    assert!(state_refresh_limiter
+        .try_acquire(
+            "ucce1",
+            StateRefreshScope::TEAM,
+            "10",
+            now + Duration::from_secs(30)
+        )
+        .is_ok());
+}
+
+#[test]
+fn rejected_refresh_reports_retry_after() {
+    let state_refresh_result = StateRefreshResult::new(
+        "ucce1",
+        StateRefreshScope::AGENT,
+        "1001",
+        false,
+        0,
+        Duration::from_millis(1_500),
+    );
+
+    let value = serde_json::to_value(&state_refresh_result).unwrap();
+    assert_eq!(value["event"], "STATE_REFRESH");
+    assert_eq!(value["scope"], "AGENT");
+    assert_eq!(value["accepted"], false);
+    assert_eq!(value["retry_after"], 1_500);
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use ctm::{
+    cisco::{supervisor::emergency_call_event::EmergencyCallEvent, CtiMessage},
+    ctm::{
+        agent_info::AgentInfo,
+        field_masker::{FieldMasker, MaskingMode},
+        supervisor_alert::{SupervisorAlert, SupervisorAlertType},
+    },
+};
+
+use common::load_fixture;
+
+#[test]
+fn supervisor_alert_carries_agent_and_team() {
+    let mut data = load_fixture("emergency_call_event.hex");
+    let emergency_call_event = EmergencyCallEvent::try_deserialize(&mut data).unwrap();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_agent_name("Hong Gildong");
+    agent_info.set_team(10, "Sales");
+
+    let mut supervisor_alert = SupervisorAlert::new(
+        SupervisorAlertType::EMERGENCY_CALL,
+        "ucce1",
+        &emergency_call_event,
+    );
+    supervisor_alert.set_agent_info(&agent_info);
+    supervisor_alert.mask_fields(&FieldMasker::new(MaskingMode::PARTIAL, "", 2));
+
+    assert_eq!(supervisor_alert.get_agent_id(), "1001");
+    assert_eq!(supervisor_alert.get_agent_name(), "Hong Gildong");
+    assert_eq!(supervisor_alert.get_team_id(), 10);
+    assert_eq!(supervisor_alert.get_peripheral_id(), 5000);
+    assert_eq!(supervisor_alert.get_agent_extension(), "**01");
+    assert_eq!(supervisor_alert.get_connection_device_id(), "**01");
+
+    let value = serde_json::to_value(&supervisor_alert).unwrap();
+    assert_eq!(value["event"], "SUPERVISOR_ALERT");
+    assert_eq!(value["alert_type"], "EMERGENCY_CALL");
+    assert_eq!(value["team_name"], "Sales");
+}
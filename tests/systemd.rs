@@ -0,0 +1,43 @@
+#![cfg(unix)]
+
+use std::{os::unix::net::UnixDatagram, time::Duration};
+
+use ctm::ctm::systemd::SystemdNotifier;
+
+#[test]
+fn notify_ready_and_watchdog() {
+    let socket_path = std::env::temp_dir().join(format!("ctm-notify-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&socket_path);
+    let socket = UnixDatagram::bind(&socket_path).unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(1)))
+        .unwrap();
+
+    std::env::set_var("NOTIFY_SOCKET", &socket_path);
+    std::env::set_var("WATCHDOG_USEC", "2000");
+    std::env::set_var("WATCHDOG_PID", std::process::id().to_string());
+
+    let mut notifier = SystemdNotifier::new();
+    assert_eq!(
+        notifier.get_watchdog_interval(),
+        Some(Duration::from_millis(1))
+    );
+
+    let mut buffer = [0u8; 256];
+    notifier.ready("CTI sessions opened. cti_instances: 1");
+    let len = socket.recv(&mut buffer).unwrap();
+    let message = String::from_utf8_lossy(&buffer[..len]);
+    assert!(message.contains("READY=1"));
+    assert!(message.contains("STATUS=CTI sessions opened. cti_instances: 1"));
+
+    // 준비 완료는 한 번만 알린다
+    notifier.ready("again");
+    assert!(notifier.is_ready());
+
+    std::thread::sleep(Duration::from_millis(2));
+    notifier.watchdog();
+    let len = socket.recv(&mut buffer).unwrap();
+    assert_eq!(&buffer[..len], b"WATCHDOG=1\n");
+
+    let _ = std::fs::remove_file(&socket_path);
+}
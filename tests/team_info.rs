@@ -0,0 +1,25 @@
+use ctm::ctm::team_info::TeamInfo;
+
+#[test]
+fn team_membership_delta() {
+    let mut team_info = TeamInfo::new(10);
+    team_info.set_team_name("Team A");
+    team_info.add_agent("1002");
+    team_info.add_agent("1001");
+    team_info.add_agent("1001");
+
+    assert_eq!(team_info.get_team_id(), 10);
+    assert_eq!(team_info.get_team_name(), "Team A");
+    assert_eq!(
+        team_info.get_agent_ids().iter().collect::<Vec<_>>(),
+        vec!["1001", "1002"]
+    );
+
+    team_info.remove_agent("1001");
+    team_info.remove_agent("9999");
+
+    assert_eq!(
+        team_info.get_agent_ids().iter().collect::<Vec<_>>(),
+        vec!["1002"]
+    );
+}
@@ -0,0 +1,47 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ctm::ctm::{agent_info::AgentInfo, pg_clock::PgClock, wallboard_summary::WallboardSummary};
+
+#[test]
+fn aggregate_wallboard_summary() {
+    let mut agent_infos = vec![];
+    for (agent_id, agent_state, state_duration) in [
+        ("1001", 3, 60),
+        ("1002", 2, 300),
+        ("1003", 2, 900),
+        ("1004", 4, 120),
+        ("1005", 10, 30),
+        ("1006", 1, 5000),
+    ] {
+        let mut agent_info = AgentInfo::new(agent_id);
+        agent_info.set_cti_instance("ucce1");
+        agent_info.set_agent_state(agent_state);
+        agent_info.set_state_duration(state_duration, &PgClock::default());
+        agent_infos.push(agent_info);
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let wallboard_summary = WallboardSummary::aggregate(agent_infos.iter(), now);
+
+    assert_eq!(wallboard_summary.get_agents_logged_in(), 5);
+    assert_eq!(wallboard_summary.get_agents_ready(), 1);
+    assert_eq!(wallboard_summary.get_agents_not_ready(), 2);
+    assert_eq!(wallboard_summary.get_agents_talking(), 2);
+    assert_eq!(
+        wallboard_summary.get_longest_not_ready_cti_instance(),
+        "ucce1"
+    );
+    assert_eq!(wallboard_summary.get_longest_not_ready_agent_id(), "1003");
+    assert!((900..=901).contains(&wallboard_summary.get_longest_not_ready_duration()));
+    assert!((282..=283).contains(&wallboard_summary.get_average_state_duration()));
+}
+
+#[test]
+fn aggregate_empty_wallboard_summary() {
+    let wallboard_summary = WallboardSummary::aggregate(std::iter::empty(), 0);
+
+    assert_eq!(wallboard_summary, WallboardSummary::default());
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use ctm::{
+    ctm::{
+        acceptor::webhook_publisher::{WebhookEndpoint, WebhookPublisher},
+        agent_delta::AgentInfoMirror,
+        agent_info::AgentInfo,
+        agent_removal::{AgentRemoval, AgentRemovalReason},
+    },
+    event::broker_event::BrokerEvent,
+};
+
+#[test]
+fn webhook_endpoint_parse() {
+    let endpoint = WebhookEndpoint::parse("https://hooks.example.com/ctm/events").unwrap();
+    assert!(endpoint.is_secure());
+    assert_eq!(endpoint.get_host(), "hooks.example.com");
+    assert_eq!(endpoint.get_port(), 443);
+    assert_eq!(endpoint.get_path(), "/ctm/events");
+
+    let endpoint = WebhookEndpoint::parse("http://127.0.0.1:8080").unwrap();
+    assert!(!endpoint.is_secure());
+    assert_eq!(endpoint.get_host(), "127.0.0.1");
+    assert_eq!(endpoint.get_port(), 8080);
+    assert_eq!(endpoint.get_path(), "/");
+
+    let endpoint = WebhookEndpoint::parse("http://[::1]/hook").unwrap();
+    assert_eq!(endpoint.get_host(), "[::1]");
+    assert_eq!(endpoint.get_port(), 80);
+
+    assert!(WebhookEndpoint::parse("ftp://example.com/").is_err());
+    assert!(WebhookEndpoint::parse("http://example.com:http/").is_err());
+}
+
+#[test]
+fn webhook_sign() {
+    // echo -n '1700000000.{"events":[]}' | openssl dgst -sha256 -hmac secret
+    assert_eq!(
+        WebhookPublisher::sign("secret", 1_700_000_000, br#"{"events":[]}"#),
+        "3947de27ec923573170fccda604ddfb25583ff98dc51e96cca2e11c59545026a"
+    );
+}
+
+#[test]
+fn webhook_retry_delay() {
+    let base = Duration::from_millis(500);
+    assert_eq!(WebhookPublisher::retry_delay(base, 0), base);
+    assert_eq!(
+        WebhookPublisher::retry_delay(base, 3),
+        Duration::from_millis(4_000)
+    );
+    assert_eq!(
+        WebhookPublisher::retry_delay(base, 40),
+        Duration::from_secs(60)
+    );
+}
+
+#[test]
+fn webhook_events() {
+    let mut agent_info_mirror = AgentInfoMirror::new();
+
+    let mut agent_info = AgentInfo::new("1001");
+    agent_info.set_cti_instance("ucce1");
+    let events = WebhookPublisher::events(
+        &mut agent_info_mirror,
+        &BrokerEvent::BroadCastAgentState {
+            client_id: None,
+            agent_info: agent_info.into(),
+        },
+    )
+    .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event"], "agent_state");
+    assert_eq!(events[0]["data"]["agent_id"], "1001");
+
+    let events = WebhookPublisher::events(
+        &mut agent_info_mirror,
+        &BrokerEvent::BroadCastAgentRemoved {
+            client_id: None,
+            agent_removal: AgentRemoval::new("ucce1", "1001", AgentRemovalReason::LOGOUT_TIMEOUT),
+        },
+    )
+    .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event"], "agent_removed");
+    assert!(agent_info_mirror.get("ucce1", "1001").is_none());
+}
@@ -0,0 +1,196 @@
+use bytes::BytesMut;
+use ctm::ctm::acceptor::websocket_frame::{
+    encode_frame_header, WebsocketFrame, WebsocketFrameError, WebsocketFrameReader,
+    WebsocketMessage, WEBSOCKET_CLOSE_MESSAGE_TOO_BIG, WEBSOCKET_CLOSE_PROTOCOL_ERROR,
+    WEBSOCKET_OP_CODE_BINARY_FRAME, WEBSOCKET_OP_CODE_CLOSE_FRAME,
+    WEBSOCKET_OP_CODE_CONTINUATION_FRAME, WEBSOCKET_OP_CODE_PING_FRAME,
+    WEBSOCKET_OP_CODE_TEXT_FRAME,
+};
+
+const MASK: [u8; 4] = [0x37, 0xFA, 0x21, 0x3D];
+
+///
+/// 클라이언트 프레임(마스킹 적용)을 만든다
+///
+fn client_frame(fin: bool, op_code: u8, payload: &[u8]) -> Vec<u8> {
+    let mut buffer = WebsocketFrame::new(fin, op_code, payload).encode();
+    let offset = buffer.len() - payload.len();
+    buffer[1] |= 0x80;
+
+    let masked_payload = buffer
+        .split_off(offset)
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| byte ^ MASK[i % 4])
+        .collect::<Vec<_>>();
+    buffer.extend_from_slice(&MASK);
+    buffer.extend_from_slice(&masked_payload);
+
+    buffer
+}
+
+#[test]
+fn encode_server_frame_lengths() {
+    let frame = WebsocketFrame::new(true, WEBSOCKET_OP_CODE_BINARY_FRAME, vec![0_u8; 125]);
+    assert_eq!(frame.encode()[..2], [0x82, 125]);
+
+    let frame = WebsocketFrame::new(true, WEBSOCKET_OP_CODE_BINARY_FRAME, vec![0_u8; 65_535]);
+    assert_eq!(frame.encode()[..4], [0x82, 126, 0xFF, 0xFF]);
+
+    let frame = WebsocketFrame::new(true, WEBSOCKET_OP_CODE_BINARY_FRAME, vec![0_u8; 65_536]);
+    assert_eq!(frame.encode()[..10], [0x82, 127, 0, 0, 0, 0, 0, 1, 0, 0]);
+}
+
+#[test]
+fn encode_frame_header_matches_encoded_frame() {
+    for length in [0, 125, 126, 65_535, 65_536] {
+        let payload = vec![7_u8; length];
+        let frame = WebsocketFrame::new(true, WEBSOCKET_OP_CODE_TEXT_FRAME, payload.clone());
+
+        // 헤더 뒤에 페이로드를 이어 쓰면 프레임 직렬화 결과와 같다
+        let mut buffer = BytesMut::new();
+        encode_frame_header(true, WEBSOCKET_OP_CODE_TEXT_FRAME, length, &mut buffer);
+        buffer.extend_from_slice(&payload);
+        assert_eq!(buffer[..], frame.encode()[..]);
+
+        let mut buffer = BytesMut::new();
+        frame.encode_into(&mut buffer);
+        assert_eq!(buffer[..], frame.encode()[..]);
+    }
+}
+
+#[test]
+fn client_side_frames_round_trip() {
+    let frame = WebsocketFrame::new(true, WEBSOCKET_OP_CODE_TEXT_FRAME, b"Hello".to_vec());
+    assert_eq!(
+        frame.encode_masked(MASK),
+        client_frame(true, WEBSOCKET_OP_CODE_TEXT_FRAME, b"Hello")
+    );
+
+    // 서버 프레임은 마스킹 없이 읽는다
+    let buffer = frame.encode();
+    assert_eq!(WebsocketFrame::decode_server(&buffer[..4], 1_024), Ok(None));
+    assert_eq!(
+        WebsocketFrame::decode_server(&buffer, 1_024),
+        Ok(Some((frame, buffer.len())))
+    );
+}
+
+#[test]
+fn read_masked_frame_in_pieces() {
+    let buffer = client_frame(true, WEBSOCKET_OP_CODE_TEXT_FRAME, b"Hello");
+    let mut frame_reader = WebsocketFrameReader::new(1_024);
+
+    frame_reader.extend(&buffer[..3]);
+    assert_eq!(frame_reader.read_message(), Ok(None));
+
+    frame_reader.extend(&buffer[3..]);
+    assert_eq!(
+        frame_reader.read_message(),
+        Ok(Some(WebsocketMessage::Data {
+            op_code: WEBSOCKET_OP_CODE_TEXT_FRAME,
+            payload: b"Hello".to_vec(),
+        }))
+    );
+    assert_eq!(frame_reader.read_message(), Ok(None));
+}
+
+#[test]
+fn reassemble_fragments_with_interleaved_ping() {
+    let mut frame_reader = WebsocketFrameReader::new(1_024);
+    frame_reader.extend(&client_frame(false, WEBSOCKET_OP_CODE_BINARY_FRAME, b"AB"));
+    frame_reader.extend(&client_frame(true, WEBSOCKET_OP_CODE_PING_FRAME, b"ping"));
+    frame_reader.extend(&client_frame(
+        false,
+        WEBSOCKET_OP_CODE_CONTINUATION_FRAME,
+        b"CD",
+    ));
+    frame_reader.extend(&client_frame(
+        true,
+        WEBSOCKET_OP_CODE_CONTINUATION_FRAME,
+        b"EF",
+    ));
+
+    assert_eq!(
+        frame_reader.read_message(),
+        Ok(Some(WebsocketMessage::Ping {
+            payload: b"ping".to_vec()
+        }))
+    );
+    assert_eq!(
+        frame_reader.read_message(),
+        Ok(Some(WebsocketMessage::Data {
+            op_code: WEBSOCKET_OP_CODE_BINARY_FRAME,
+            payload: b"ABCDEF".to_vec(),
+        }))
+    );
+}
+
+#[test]
+fn read_close_with_status_code() {
+    let mut payload = 1_001_u16.to_be_bytes().to_vec();
+    payload.extend_from_slice(b"bye");
+
+    let mut frame_reader = WebsocketFrameReader::new(1_024);
+    frame_reader.extend(&client_frame(true, WEBSOCKET_OP_CODE_CLOSE_FRAME, &payload));
+
+    assert_eq!(
+        frame_reader.read_message(),
+        Ok(Some(WebsocketMessage::Close {
+            status_code: Some(1_001),
+            reason: "bye".to_string(),
+        }))
+    );
+}
+
+#[test]
+fn reject_protocol_violations() {
+    // 마스킹되지 않은 클라이언트 프레임
+    let mut frame_reader = WebsocketFrameReader::new(1_024);
+    frame_reader.extend(&WebsocketFrame::new(true, WEBSOCKET_OP_CODE_TEXT_FRAME, "Hi").encode());
+    let error = frame_reader.read_message().unwrap_err();
+    assert_eq!(error.get_status_code(), WEBSOCKET_CLOSE_PROTOCOL_ERROR);
+
+    // 시작 프레임 없는 CONTINUATION 프레임
+    let mut frame_reader = WebsocketFrameReader::new(1_024);
+    frame_reader.extend(&client_frame(
+        true,
+        WEBSOCKET_OP_CODE_CONTINUATION_FRAME,
+        b"Hi",
+    ));
+    assert_eq!(
+        frame_reader.read_message(),
+        Err(WebsocketFrameError::UnexpectedContinuation)
+    );
+
+    // 분할된 제어 프레임
+    let mut frame_reader = WebsocketFrameReader::new(1_024);
+    frame_reader.extend(&client_frame(false, WEBSOCKET_OP_CODE_PING_FRAME, b"Hi"));
+    assert!(matches!(
+        frame_reader.read_message(),
+        Err(WebsocketFrameError::InvalidControlFrame { .. })
+    ));
+
+    // 예약된 CLOSE 상태 코드
+    let mut frame_reader = WebsocketFrameReader::new(1_024);
+    frame_reader.extend(&client_frame(
+        true,
+        WEBSOCKET_OP_CODE_CLOSE_FRAME,
+        &1_005_u16.to_be_bytes(),
+    ));
+    assert!(matches!(
+        frame_reader.read_message(),
+        Err(WebsocketFrameError::InvalidCloseStatus { .. })
+    ));
+
+    // 최대 크기를 넘는 분할 메시지
+    let mut frame_reader = WebsocketFrameReader::new(4);
+    frame_reader.extend(&client_frame(false, WEBSOCKET_OP_CODE_TEXT_FRAME, b"ABC"));
+    frame_reader.extend(&client_frame(
+        true,
+        WEBSOCKET_OP_CODE_CONTINUATION_FRAME,
+        b"DE",
+    ));
+    let error = frame_reader.read_message().unwrap_err();
+    assert_eq!(error.get_status_code(), WEBSOCKET_CLOSE_MESSAGE_TOO_BIG);
+}
@@ -0,0 +1,46 @@
+use ctm::ctm::acceptor::websocket_acceptor::{is_allowed_origin, parse_allowed_origins};
+
+#[test]
+fn parse_origins() {
+    assert_eq!(
+        parse_allowed_origins(" https://Dashboard.example.com/ , http://localhost:3000,,"),
+        vec![
+            "https://dashboard.example.com".to_string(),
+            "http://localhost:3000".to_string()
+        ]
+    );
+    assert!(parse_allowed_origins("").is_empty());
+}
+
+#[test]
+fn allowed_origins() {
+    let allowed_origins =
+        parse_allowed_origins("https://dashboard.example.com,http://localhost:3000");
+
+    assert!(is_allowed_origin(
+        &allowed_origins,
+        Some("https://dashboard.example.com")
+    ));
+    assert!(is_allowed_origin(
+        &allowed_origins,
+        Some("HTTPS://DASHBOARD.EXAMPLE.COM")
+    ));
+    assert!(is_allowed_origin(
+        &allowed_origins,
+        Some("http://localhost:3000")
+    ));
+    assert!(!is_allowed_origin(
+        &allowed_origins,
+        Some("https://evil.example.com")
+    ));
+    assert!(!is_allowed_origin(&allowed_origins, Some("null")));
+    // Origin 헤더가 없는 요청(브라우저가 아닌 클라이언트)은 허용한다
+    assert!(is_allowed_origin(&allowed_origins, None));
+
+    // 목록이 비어 있거나 * 이면 모두 허용한다
+    assert!(is_allowed_origin(&[], Some("https://evil.example.com")));
+    assert!(is_allowed_origin(
+        &parse_allowed_origins("*"),
+        Some("https://evil.example.com")
+    ));
+}
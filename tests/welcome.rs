@@ -0,0 +1,109 @@
+use std::{error::Error, fs, time::Duration};
+
+use async_trait::async_trait;
+use ctm::{
+    ctm::{
+        acceptor::Acceptor,
+        client_auth::ClientPermission,
+        welcome::{CtiConnectionStatus, Welcome},
+        CTM,
+    },
+    event::{broker_event::BrokerEvent, client_event::ClientEvent},
+};
+use tokio::{
+    sync::{broadcast, mpsc},
+    time::timeout,
+};
+use uuid::Uuid;
+
+#[test]
+fn welcome_lists_active_peripherals() {
+    let id = Uuid::now_v7();
+    let welcome = Welcome::new(
+        id,
+        vec![
+            CtiConnectionStatus::new("ucce1", true, 5000, true),
+            CtiConnectionStatus::new("ucce2", true, 5001, false),
+            CtiConnectionStatus::new("ucce3", false, 5002, true),
+        ],
+        42,
+    );
+
+    assert_eq!(welcome.get_active_peripheral_ids(), &[5000]);
+
+    let value = serde_json::to_value(&welcome).unwrap();
+    assert_eq!(value["event"], "WELCOME");
+    assert_eq!(value["client_id"], id.to_string());
+    assert_eq!(value["server_version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(value["protocol_version"], 1);
+    assert_eq!(value["cti_connections"][1]["peripheral_online"], false);
+    assert_eq!(value["agent_count"], 42);
+}
+
+///
+/// 접속한 클라이언트가 처음 받은 이벤트를 전달하는 Acceptor
+///
+struct WelcomeProbe {
+    first_event_tx: mpsc::UnboundedSender<BrokerEvent>,
+}
+
+#[async_trait]
+impl Acceptor for WelcomeProbe {
+    async fn accept(
+        &self,
+        _broker_event_channel_rx: broadcast::Receiver<BrokerEvent>,
+        client_event_channel_tx: mpsc::Sender<ClientEvent>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (direct_event_tx, mut direct_event_rx) = mpsc::unbounded_channel();
+        client_event_channel_tx
+            .send(ClientEvent::Connect {
+                id: Uuid::now_v7(),
+                permission: ClientPermission::READ_ONLY,
+                department_id: None,
+                common_name: None,
+                addr: None,
+                direct_event_tx: Some(direct_event_tx),
+                resume: None,
+            })
+            .await?;
+
+        if let Some(broker_events) = direct_event_rx.recv().await {
+            if let Some(broker_event) = broker_events.into_iter().next() {
+                self.first_event_tx.send(broker_event)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn connected_client_is_welcomed_first() {
+    // 빈 저널을 재생하여 CTI 서버 없이 실행한다
+    let journal_file =
+        std::env::temp_dir().join(format!("ctm_welcome_{}.journal", std::process::id()));
+    fs::write(&journal_file, b"").unwrap();
+
+    let (first_event_tx, mut first_event_rx) = mpsc::unbounded_channel();
+    let ctm = CTM::builder()
+        .with_acceptor(Box::new(WelcomeProbe { first_event_tx }))
+        .with_replay(&journal_file)
+        .build()
+        .await
+        .unwrap();
+    let shutdown_controller = ctm.get_shutdown_controller();
+
+    let (_, first_event) = tokio::join!(timeout(Duration::from_secs(5), ctm.start()), async {
+        let first_event = timeout(Duration::from_secs(5), first_event_rx.recv()).await;
+        shutdown_controller.shutdown();
+        first_event
+    });
+    let _ = fs::remove_file(&journal_file);
+
+    match first_event.unwrap().unwrap() {
+        BrokerEvent::BroadCastWelcome { welcome, .. } => {
+            assert_eq!(welcome.get_agent_count(), 0);
+            assert!(welcome.get_active_peripheral_ids().is_empty());
+        }
+        broker_event => panic!("unexpected broker event: {:?}", broker_event),
+    }
+}
@@ -0,0 +1,36 @@
+use std::{io, time::Duration};
+
+use ctm::ctm::acceptor::write_all_timeout;
+use tokio::io::{duplex, AsyncReadExt};
+
+#[tokio::test]
+async fn write_all_timeout_writes_whole_buffer() {
+    let (mut client, mut server) = duplex(16);
+    let buffer = vec![7_u8; 1_000];
+
+    // 버퍼보다 큰 데이터도 상대가 읽는 동안 나누어 모두 전송한다
+    let reader = tokio::spawn(async move {
+        let mut received = vec![];
+        server.read_to_end(&mut received).await.unwrap();
+        received
+    });
+    write_all_timeout(&mut client, &buffer, Duration::from_secs(5))
+        .await
+        .unwrap();
+    drop(client);
+
+    assert_eq!(reader.await.unwrap(), buffer);
+}
+
+#[tokio::test]
+async fn write_all_timeout_times_out_when_peer_stops_reading() {
+    let (mut client, _server) = duplex(16);
+
+    let e = write_all_timeout(&mut client, &[0_u8; 1_000], Duration::from_millis(50))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        e.downcast_ref::<io::Error>().map(io::Error::kind),
+        Some(io::ErrorKind::TimedOut)
+    );
+}